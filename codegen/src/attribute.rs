@@ -10,12 +10,14 @@
 
 //! `#[given]`, `#[when]` and `#[then]` attribute macros implementation.
 
-use std::{iter, mem};
+use std::{iter, mem, ops::Range};
 
-use cucumber_expressions::{Expression, Parameter, SingleExpression, Spanned};
+use cucumber_expressions::{
+    parse, Expression, Parameter, SingleExpression, Spanned,
+};
 use inflections::case::to_pascal_case;
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
 use regex::Regex;
 use syn::{
     parse::{Parse, ParseStream},
@@ -49,11 +51,14 @@ struct Step {
     /// Function the attribute is applied to.
     func: syn::ItemFn,
 
-    /// Name of the function argument representing a [`gherkin::Step`]
-    /// reference.
+    /// Name of the function argument representing a [`gherkin::Step`] (or a
+    /// whole `step::Context`) reference.
     ///
     /// [`gherkin::Step`]: https://bit.ly/3j42hcd
     arg_name_of_step_context: Option<syn::Ident>,
+
+    /// Indicator whether the function is marked with a `#[wip]` attribute.
+    wip: bool,
 }
 
 impl Step {
@@ -65,6 +70,7 @@ impl Step {
     ) -> syn::Result<Self> {
         let attr_arg = syn::parse2::<AttributeArgument>(attr)?;
         let mut func = syn::parse2::<syn::ItemFn>(body)?;
+        let wip = remove_wip_attr_if_needed(&mut func);
 
         let step_arg_name = {
             let (arg_marked_as_step, _) =
@@ -98,6 +104,7 @@ impl Step {
             attr_arg,
             func,
             arg_name_of_step_context: step_arg_name,
+            wip,
         })
     }
 
@@ -117,43 +124,168 @@ impl Step {
         let unwrapping = (!self.returns_unit())
             .then(|| quote! { .unwrap_or_else(|e| panic!("{}", e)) });
 
+        let loc = quote! {
+            ::cucumber::step::Location {
+                path: ::std::file!(),
+                line: ::std::line!(),
+                column: ::std::column!(),
+            }
+        };
+        let regex = quote! {
+            || {
+                static LAZY: ::std::sync::LazyLock<
+                    ::cucumber::codegen::Regex
+                > = ::std::sync::LazyLock::new(|| { #regex });
+                LAZY.clone()
+            }
+        };
+        let wip = self.wip.then(|| {
+            quote! {
+                #[automatically_derived]
+                ::cucumber::codegen::submit!(
+                    ::cucumber::codegen::WipStep(#loc)
+                );
+            }
+        });
+        let expression = self.gen_expression_submission(&loc);
+        let lint_attrs = self.lint_attrs();
+        let step_fn = quote! {
+            |__cucumber_world, __cucumber_ctx| {
+                let f = async move {
+                    #addon_parsing
+                    let _ = #func_name(__cucumber_world, #func_args)
+                        #awaiting
+                        #unwrapping;
+                };
+                ::std::boxed::Box::pin(f)
+            }
+        };
+
+        if self.is_generic_over(world)? {
+            let attr_name = format_ident!("{}", self.attr_name);
+            let vis = &func.vis;
+            let (impl_gens, _, where_clause) =
+                func.sig.generics.split_for_impl();
+
+            return Ok(quote! {
+                #func
+
+                #wip
+
+                #expression
+
+                #[automatically_derived]
+                #[allow(non_camel_case_types)]
+                #[doc(hidden)]
+                #vis struct #func_name {}
+
+                #[automatically_derived]
+                #( #lint_attrs )*
+                impl #impl_gens ::cucumber::codegen::GenericStep<#world>
+                     for #func_name #where_clause
+                {
+                    fn register(
+                        steps: ::cucumber::step::Collection<#world>,
+                    ) -> ::cucumber::step::Collection<#world> {
+                        let regex: ::cucumber::codegen::LazyRegex = #regex;
+                        steps.#attr_name(
+                            ::std::option::Option::Some(#loc),
+                            regex(),
+                            #step_fn,
+                        )
+                    }
+                }
+            });
+        }
+
         Ok(quote! {
             #func
 
+            #wip
+
+            #expression
+
+            // Wrapped into a `const` item, as attributes are ignored on macro
+            // invocations.
+            #( #lint_attrs )*
+            const _: () = {
+                #[automatically_derived]
+                ::cucumber::codegen::submit!({
+                    // TODO: Remove this, once
+                    //       `#![feature(more_qualified_paths)]` is stabilized:
+                    //       https://github.com/rust-lang/rust/issues/86935
+                    type StepAlias = <
+                        #world as ::cucumber::codegen::WorldInventory
+                    >::#step_type;
+
+                    StepAlias {
+                        loc: #loc,
+                        regex: #regex,
+                        func: #step_fn,
+                    }
+                });
+            };
+        })
+    }
+
+    /// Generates code submitting an [`AttributeArgument::Expression`] along
+    /// with the provided `loc`ation of this [`Step`] into `inventory`, so it
+    /// may be validated at runtime.
+    fn gen_expression_submission(
+        &self,
+        loc: &TokenStream,
+    ) -> Option<TokenStream> {
+        let AttributeArgument::Expression(expr) = &self.attr_arg else {
+            return None;
+        };
+        Some(quote! {
             #[automatically_derived]
-            ::cucumber::codegen::submit!({
-                // TODO: Remove this, once `#![feature(more_qualified_paths)]`
-                //       is stabilized:
-                //       https://github.com/rust-lang/rust/issues/86935
-                type StepAlias =
-                    <#world as ::cucumber::codegen::WorldInventory>::#step_type;
-
-                StepAlias {
-                    loc: ::cucumber::step::Location {
-                        path: ::std::file!(),
-                        line: ::std::line!(),
-                        column: ::std::column!(),
-                    },
-                    regex: || {
-                        static LAZY: ::std::sync::LazyLock<
-                            ::cucumber::codegen::Regex
-                        > = ::std::sync::LazyLock::new(|| { #regex });
-                        LAZY.clone()
-                    },
-                    func: |__cucumber_world, __cucumber_ctx| {
-                        let f = async move {
-                            #addon_parsing
-                            let _ = #func_name(__cucumber_world, #func_args)
-                                #awaiting
-                                #unwrapping;
-                        };
-                        ::std::boxed::Box::pin(f)
-                    },
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::StepExpression {
+                    location: #loc,
+                    expression: #expr,
                 }
-            });
+            );
         })
     }
 
+    /// Returns `#[allow]` attributes of this [`Step::func`] to be forwarded
+    /// onto the generated code, so the lints allowed for the function (like
+    /// `deprecated` for its arguments' types) are allowed for the code calling
+    /// it too.
+    ///
+    /// Other attributes (like `#[tracing::instrument]`) are applied to the
+    /// function only, as the generated code calls it as is.
+    fn lint_attrs(&self) -> impl Iterator<Item = &syn::Attribute> {
+        self.func
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("allow"))
+    }
+
+    /// Indicates whether this [`Step::func`] is generic over the provided
+    /// `World` type (being its only generic parameter).
+    ///
+    /// # Errors
+    ///
+    /// If this [`Step::func`] is generic over the `World` type, but has other
+    /// generic parameters too.
+    fn is_generic_over(&self, world: &syn::TypePath) -> syn::Result<bool> {
+        let generics = &self.func.sig.generics;
+        let is_generic = world.qself.is_none()
+            && world.path.get_ident().is_some_and(|ident| {
+                generics.type_params().any(|p| &p.ident == ident)
+            });
+
+        if is_generic && generics.params.len() > 1 {
+            return Err(syn::Error::new(
+                generics.span(),
+                "generic step function may be generic only over its `World`",
+            ));
+        }
+        Ok(is_generic)
+    }
+
     /// Indicates whether this [`Step::func`] return type is `()`.
     fn returns_unit(&self) -> bool {
         match &self.func.sig.output {
@@ -184,11 +316,10 @@ impl Step {
             if let Some(elem_ty) = find_first_slice(&func.sig) {
                 let addon_parsing = Some(quote! {
                     let mut __cucumber_matches = ::std::vec::Vec::with_capacity(
-                        __cucumber_ctx.matches.len().saturating_sub(1),
+                        __cucumber_ctx.matches().len().saturating_sub(1),
                     );
                     let mut __cucumber_iter = __cucumber_ctx
-                        .matches
-                        .iter()
+                        .matches()
                         .skip(1)
                         .enumerate();
                     while let Some((i, (cap_name, s))) =
@@ -197,7 +328,6 @@ impl Step {
                         // Special handling of `cucumber-expressions`
                         // `parameter` with multiple capturing groups.
                         let prefix = cap_name
-                            .as_ref()
                             .filter(|n| n.starts_with("__"))
                             .map(|n| {
                                 let num_len = n
@@ -214,18 +344,18 @@ impl Step {
                             .clone()
                             .take_while(|(_, (n, _))| {
                                 prefix
-                                    .zip(n.as_ref())
+                                    .zip(*n)
                                     .filter(|(prefix, n)| n.starts_with(prefix))
                                     .is_some()
                             })
                             .count();
 
-                        let s = ::std::iter::once(s.as_str())
+                        let s = ::std::iter::once(s)
                             .chain(
                                 __cucumber_iter
                                     .by_ref()
                                     .take(to_take)
-                                    .map(|(_, (_, s))| s.as_str()),
+                                    .map(|(_, (_, s))| s),
                             )
                             .fold(None, |acc, s| {
                                 acc.or_else(|| (!s.is_empty()).then_some(s))
@@ -250,19 +380,18 @@ impl Step {
 
                 Ok((func_args, addon_parsing))
             } else {
+                let mut spans = self.parameter_spans()?.into_iter();
                 let (idents, parsings): (Vec<_>, Vec<_>) =
                     itertools::process_results(
-                        func.sig
-                            .inputs
-                            .iter()
-                            .skip(1)
-                            .map(|arg| self.arg_ident_and_parse_code(arg)),
+                        func.sig.inputs.iter().skip(1).map(|arg| {
+                            self.arg_ident_and_parse_code(arg, &mut spans)
+                        }),
                         |i| i.unzip(),
                     )?;
 
                 let addon_parsing = Some(quote! {
                     let mut __cucumber_iter = __cucumber_ctx
-                        .matches.iter()
+                        .matches()
                         .skip(1);
                     #( #parsings )*
                 });
@@ -274,7 +403,7 @@ impl Step {
             }
         } else if self.arg_name_of_step_context.is_some() {
             Ok((
-                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx.step), },
+                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx), },
                 None,
             ))
         } else {
@@ -282,6 +411,27 @@ impl Step {
         }
     }
 
+    /// Returns [`ParameterProvider::span`]s of the [`Parameter`]s, in order of
+    /// the function's arguments they correspond to, if this [`Step`] has an
+    /// [`AttributeArgument::Expression`].
+    ///
+    /// # Errors
+    ///
+    /// If [`Parameters::new()`] errors.
+    fn parameter_spans(&self) -> syn::Result<Vec<Span>> {
+        let AttributeArgument::Expression(lit) = &self.attr_arg else {
+            return Ok(vec![]);
+        };
+        let expr = lit.value();
+        let params = Parameters::new(
+            &expr,
+            lit,
+            &self.func,
+            self.arg_name_of_step_context.as_ref(),
+        )?;
+        Ok(params.0.iter().map(|par| par.span).collect())
+    }
+
     /// Composes a name of the `cucumber::codegen::WorldInventory` associated
     /// type to wire this [`Step`] with.
     fn step_type(&self) -> syn::Ident {
@@ -291,13 +441,16 @@ impl Step {
     /// Returns [`syn::Ident`] and parsing code of the given function's
     /// argument.
     ///
-    /// Function's argument type have to implement [`FromStr`].
+    /// Function's argument type have to implement [`FromStr`], which is
+    /// reported at the next of the provided [`Parameter`] `spans`, if any, or
+    /// at the argument's type otherwise.
     ///
     /// [`FromStr`]: std::str::FromStr
     /// [`syn::Ident`]: struct@syn::Ident
     fn arg_ident_and_parse_code<'a>(
         &self,
         arg: &'a syn::FnArg,
+        spans: &mut impl Iterator<Item = Span>,
     ) -> syn::Result<(&'a syn::Ident, TokenStream)> {
         let (ident, ty) = parse_fn_arg(arg)?;
 
@@ -308,7 +461,7 @@ impl Step {
         let decl = if is_ctx_arg {
             quote! {
                 let #ident =
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step);
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx);
             }
         } else {
             let syn::Type::Path(ty) = ty else {
@@ -326,6 +479,14 @@ impl Step {
                     })?
                     .ident,
             );
+            // Spanned to report a missing `FromStr` implementation at the
+            // parameter placeholder (or the argument's type) rather than at
+            // the whole attribute.
+            let span = spans.next().unwrap_or_else(|| ty.span());
+            let ty = respan(quote! { #ty }, span);
+            let parsing = quote_spanned! { span =>
+                let #ident = #ident.parse::<#ty>().expect(#parsing_err);
+            };
 
             quote! {
                 let #ident = {
@@ -335,7 +496,6 @@ impl Step {
                     // Special handling of `cucumber-expressions` `parameter`
                     // with multiple capturing groups.
                     let prefix = cap_name
-                        .as_ref()
                         .filter(|n| n.starts_with("__"))
                         .map(|n| {
                             let num_len = n
@@ -351,25 +511,25 @@ impl Step {
                     let to_take = __cucumber_iter
                         .clone()
                         .take_while(|(n, _)| {
-                            prefix.zip(n.as_ref())
+                            prefix.zip(*n)
                                 .filter(|(prefix, n)| n.starts_with(prefix))
                                 .is_some()
                         })
                         .count();
 
-                    ::std::iter::once(s.as_str())
+                    ::std::iter::once(s)
                         .chain(
                             __cucumber_iter
                                 .by_ref()
                                 .take(to_take)
-                                .map(|(_, s)| s.as_str()),
+                                .map(|(_, s)| s),
                         )
                         .fold(None, |acc, s| {
                             acc.or_else(|| (!s.is_empty()).then_some(s))
                         })
                         .unwrap_or_default()
                 };
-                let #ident = #ident.parse::<#ty>().expect(#parsing_err);
+                #parsing
             }
         };
 
@@ -388,7 +548,7 @@ impl Step {
             let (ident, _) = parse_fn_arg(arg)?;
             if name == ident {
                 return Ok(quote! {
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step),
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx),
                 });
             }
         }
@@ -439,11 +599,12 @@ impl Step {
     /// If [`Parameters::new()`] errors.
     fn gen_expression_regex(
         &self,
-        expr: &syn::LitStr,
+        lit: &syn::LitStr,
     ) -> syn::Result<TokenStream> {
-        let expr = expr.value();
+        let expr = lit.value();
         let params = Parameters::new(
             &expr,
+            lit,
             &self.func,
             self.arg_name_of_step_context.as_ref(),
         )?;
@@ -485,6 +646,10 @@ struct ParameterProvider<'p> {
 
     /// [`syn::Type`] of the [`fn`] argument corresponding to the [`Parameter`].
     ty: syn::Type,
+
+    /// [`Span`] of the [`Parameter`] placeholder inside the attribute's
+    /// literal, if it can be narrowed, or of the whole literal otherwise.
+    span: Span,
 }
 
 /// Collection of [`ParameterProvider`]s.
@@ -495,23 +660,24 @@ impl<'p> Parameters<'p> {
     ///
     /// # Errors
     ///
-    /// - If [`Expression::parse()`] errors.
+    /// - If [`Expression::parse()`] errors, which is reported at the provided
+    ///   `lit`eral.
     /// - If [`parse_fn_arg()`] on one of the `func`'s arguments errors.
+    /// - If `func` has more arguments than the [`Parameter`]s of the `expr`
+    ///   (and doesn't capture them into a slice).
     /// - If non-default [`Parameter`] doesn't have the corresponding `func`'s
-    ///   argument.
+    ///   argument, which is reported at its placeholder in the `lit`eral.
     fn new(
         expr: &'p str,
+        lit: &syn::LitStr,
         func: &syn::ItemFn,
         step: Option<&syn::Ident>,
     ) -> syn::Result<Self> {
-        let expr = Expression::parse(expr).map_err(|e| {
-            syn::Error::new(
-                expr.span(),
-                format!("invalid Cucumber Expression: {e}"),
-            )
+        let parsed = Expression::parse(expr).map_err(|e| {
+            syn::Error::new(lit.span(), render_expression_error(expr, &e))
         })?;
 
-        let param_tys = func
+        let param_args = func
             .sig
             .inputs
             .iter()
@@ -522,11 +688,12 @@ impl<'p> Parameters<'p> {
                     Err(err) => return Some(Err(err)),
                 };
                 let is_step = step.is_some_and(|s| s == ident);
-                (!is_step).then_some(Ok(ty))
+                (!is_step).then_some(Ok((ident, ty)))
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
-        expr.0
+        let params = parsed
+            .0
             .into_iter()
             .filter_map(|e| match e {
                 SingleExpression::Parameter(par) => Some(par),
@@ -535,24 +702,54 @@ impl<'p> Parameters<'p> {
                 | SingleExpression::Text(_)
                 | SingleExpression::Whitespaces(_) => None,
             })
-            .zip(param_tys.into_iter().map(Some).chain(iter::repeat(None)))
-            .filter_map(|(ast, param_ty)| {
+            .collect::<Vec<_>>();
+
+        if find_first_slice(&func.sig).is_none() {
+            if let Some((ident, _)) = param_args.get(params.len()) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "function argument `{ident}` has no corresponding \
+                         parameter in the Cucumber Expression. Consider \
+                         adding one or removing the argument:\n{}",
+                        mark_expression(expr, expr.len()..expr.len()),
+                    ),
+                ));
+            }
+        }
+
+        params
+            .into_iter()
+            .zip(param_args.into_iter().map(Some).chain(iter::repeat(None)))
+            .filter_map(|(ast, param_arg)| {
+                let range = placeholder_range(&ast);
+                let span = subspan(lit, range.clone());
+                let param_ty = param_arg.map(|(_, ty)| ty.clone());
                 if DEFAULT_PARAMETERS.iter().any(|s| s == &**ast) {
                     // If parameter is default, it's OK if there is no type
                     // corresponding to it, as we know its regex.
-                    param_ty
-                        .cloned()
-                        .map(|ty| Ok(ParameterProvider { param: ast, ty }))
-                } else if let Some(ty) = param_ty.cloned() {
-                    Some(Ok(ParameterProvider { param: ast, ty }))
+                    param_ty.map(|ty| {
+                        Ok(ParameterProvider {
+                            param: ast,
+                            ty,
+                            span,
+                        })
+                    })
+                } else if let Some(ty) = param_ty {
+                    Some(Ok(ParameterProvider {
+                        param: ast,
+                        ty,
+                        span,
+                    }))
                 } else {
                     Some(Err(syn::Error::new(
-                        func.sig.inputs.span(),
+                        span,
                         format!(
                             "function argument corresponding to the `{{{p}}}` \
                              parameter isn't found. Consider adding \
                              argument implementing a `Parameter` trait with \
-                             `Parameter::NAME == {p}`.",
+                             `Parameter::NAME == {p}`.\n{}",
+                            mark_expression(expr, range),
                             p = *ast,
                         ),
                     )))
@@ -740,6 +937,26 @@ impl Parse for AttributeArgument {
     }
 }
 
+/// Checks whether the given function is marked with a `#[wip]` attribute, and
+/// removes it in case there are no more `#[given]`, `#[when]` or `#[then]`
+/// attributes.
+fn remove_wip_attr_if_needed(func: &mut syn::ItemFn) -> bool {
+    let is_step_attr = |attr: &syn::Attribute| {
+        attr.meta.path().segments.last().is_some_and(|segment| {
+            ["given", "when", "then"]
+                .iter()
+                .any(|step| segment.ident == step)
+        })
+    };
+    let is_wip_attr = |attr: &syn::Attribute| attr.path().is_ident("wip");
+
+    let wip = func.attrs.iter().any(is_wip_attr);
+    if wip && !func.attrs.iter().any(is_step_attr) {
+        func.attrs.retain(|attr| !is_wip_attr(attr));
+    }
+    wip
+}
+
 /// Removes all `#[attr_arg]` attributes from the given function signature and
 /// returns these attributes along with the corresponding function's arguments
 /// in case there are no more `#[given]`, `#[when]` or `#[then]` attributes.
@@ -835,6 +1052,95 @@ fn parse_fn_arg(arg: &syn::FnArg) -> syn::Result<(&syn::Ident, &syn::Type)> {
     Ok((ident, arg.ty.as_ref()))
 }
 
+/// Renders the given [`parse::Error`] of the provided Cucumber Expression as a
+/// diagnostic message, pointing to the exact position of the error.
+fn render_expression_error(
+    expr: &str,
+    err: &parse::Error<Spanned<'_>>,
+) -> String {
+    use parse::Error as E;
+
+    let input = match err {
+        E::NestedParameter(i)
+        | E::OptionalInParameter(i)
+        | E::UnfinishedParameter(i)
+        | E::NestedOptional(i)
+        | E::ParameterInOptional(i)
+        | E::EmptyOptional(i)
+        | E::AlternationInOptional(i)
+        | E::UnfinishedOptional(i)
+        | E::EmptyAlternation(i)
+        | E::OnlyOptionalInAlternation(i)
+        | E::UnescapedReservedCharacter(i)
+        | E::EscapedNonReservedCharacter(i)
+        | E::EscapedEndOfLine(i)
+        | E::Other(i, _) => i,
+        E::Needed(_) => return format!("invalid Cucumber Expression: {err}"),
+    };
+
+    let msg = err.to_string();
+    let reason = msg
+        .strip_prefix(input.fragment())
+        .map_or(msg.as_str(), str::trim_start);
+    let start = input.location_offset();
+    let column = expr.get(..start).map_or(0, |s| s.chars().count());
+
+    format!(
+        "invalid Cucumber Expression at column {}:\n{}\n{reason}",
+        column + 1,
+        mark_expression(expr, start..start + input.fragment().len()),
+    )
+}
+
+/// Renders the provided Cucumber Expression with the given byte `range` of it
+/// marked on the next line.
+fn mark_expression(expr: &str, range: Range<usize>) -> String {
+    let column = expr.get(..range.start).map_or(0, |s| s.chars().count());
+    let width = expr.get(range).map_or(0, |s| s.chars().count()).max(1);
+    format!("{expr}\n{}{}", " ".repeat(column), "^".repeat(width))
+}
+
+/// Returns the byte range of the provided [`Parameter`] placeholder (including
+/// its braces) inside the Cucumber Expression it's parsed from.
+fn placeholder_range(param: &Parameter<Spanned<'_>>) -> Range<usize> {
+    let start = param.input.location_offset().saturating_sub(1);
+    start..param.input.location_offset() + param.input.fragment().len() + 1
+}
+
+/// Sets the provided [`Span`] to all the given `tokens`.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(g) = &tt {
+                let mut group =
+                    Group::new(g.delimiter(), respan(g.stream(), span));
+                group.set_span(span);
+                tt = group.into();
+            } else {
+                tt.set_span(span);
+            }
+            tt
+        })
+        .collect()
+}
+
+/// Narrows the [`Span`] of the provided [`syn::LitStr`] to the given byte
+/// `range` of its value.
+///
+/// Falls back to the [`Span`] of the whole [`syn::LitStr`] if it cannot be
+/// narrowed (on stable Rust, or when the literal contains escapes).
+fn subspan(lit: &syn::LitStr, range: Range<usize>) -> Span {
+    let token = lit.token();
+    let repr = token.to_string();
+    let value = lit.value();
+    repr.find('"')
+        .map(|quote| quote + 1)
+        .filter(|&start| repr.get(start..start + value.len()) == Some(&value))
+        .and_then(|start| token.subspan(start + range.start..start + range.end))
+        .unwrap_or_else(|| lit.span())
+}
+
 /// Parses type of a first slice element of the given function signature.
 fn find_first_slice(sig: &syn::Signature) -> Option<&syn::TypePath> {
     sig.inputs.iter().find_map(|arg| {
@@ -922,3 +1228,59 @@ fn to_literal(expr: syn::Expr) -> syn::Result<syn::Lit> {
         Err(syn::Error::new(expr.span(), "expected literal"))
     }
 }
+
+#[cfg(test)]
+mod spec {
+    use cucumber_expressions::Expression;
+    use quote::quote;
+
+    #[test]
+    fn renders_expression_error_position() {
+        let expr = "I have (a/few) cucumbers";
+        let err = Expression::parse(expr).unwrap_err();
+
+        assert_eq!(
+            super::render_expression_error(expr, &err),
+            "invalid Cucumber Expression at column 10:\n\
+             I have (a/few) cucumbers\n\
+             \x20        ^\n\
+             An alternation can not be used inside an optional.\n\
+             You can use '\\/' to escape the '/'.",
+        );
+    }
+
+    #[test]
+    fn errors_on_argument_without_parameter() {
+        let err = super::step(
+            "given",
+            quote! { expr = "only {int}" },
+            quote! { fn extra(w: &mut World, n: i32, m: i32) {} },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "function argument `m` has no corresponding parameter in the \
+             Cucumber Expression. Consider adding one or removing the \
+             argument:\n\
+             only {int}\n\
+             \x20         ^",
+        );
+    }
+
+    #[test]
+    fn marks_parameter_without_argument() {
+        let err = super::step(
+            "given",
+            quote! { expr = "{int} {custom}" },
+            quote! { fn missing(w: &mut World, n: i32) {} },
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .ends_with("\n{int} {custom}\n      ^^^^^^^^"),
+            "{err}",
+        );
+    }
+}
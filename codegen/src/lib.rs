@@ -235,11 +235,16 @@ macro_rules! step_attribute {
         /// # Function arguments
         ///
         /// - First argument has to be mutable reference to the [`World`]
-        ///   deriver.
+        ///   deriver. It may also be a type parameter (bounded by some trait),
+        ///   being the only generic parameter of the function, so the step is
+        ///   monomorphized for every [`World`] listing it in its
+        ///   `#[world(steps(...))]` attribute.
         /// - Other argument's types have to implement [`FromStr`] or it has to
         ///   be a slice where the element type also implements [`FromStr`].
         /// - To use [`gherkin::Step`], name the argument as `step`,
-        ///   **or** mark the argument with a `#[step]` attribute.
+        ///   **or** mark the argument with a `#[step]` attribute. The same way
+        ///   the whole `step::Context` may be used instead (to access its
+        ///   artifacts directory, for example).
         ///
         /// ```rust
         /// # use std::convert::Infallible;
@@ -272,6 +277,62 @@ macro_rules! step_attribute {
         /// to implement [`Display`], so returning it will cause the step to
         /// fail.
         ///
+        /// # Work-in-progress steps
+        ///
+        /// Marking a function with a `#[wip]` attribute (placed after all the
+        /// step attributes) makes any scenario using it work-in-progress, the
+        /// same way a `@wip` tag does: its failures don't fail the run, while
+        /// its passing fails the run with a `--wip` CLI option.
+        ///
+        /// ```rust
+        /// # use cucumber::{given, World};
+        /// #
+        /// # #[derive(Debug, Default, World)]
+        /// # struct MyWorld;
+        /// #
+        /// #[given("an unfinished feature")]
+        /// #[wip]
+        /// fn unfinished(_: &mut MyWorld) {
+        ///     unimplemented!("not yet")
+        /// }
+        /// #
+        /// # #[tokio::main]
+        /// # async fn main() {
+        /// #     MyWorld::run("./tests/features/doctests.feature").await;
+        /// # }
+        /// ```
+        ///
+        /// # Other attributes
+        ///
+        /// Other attributes of the function (like `#[tracing::instrument]`)
+        /// are kept on it as is, so they may be placed both before and after
+        /// the step attributes. As the generated code calls the function
+        /// itself, an instrumented function enters its span on every step run.
+        ///
+        /// `#[allow]` attributes are forwarded onto the generated code too, so
+        /// the lints allowed for the function (like `deprecated` for its
+        /// arguments' types) don't fire in the macro expansion either.
+        ///
+        /// ```rust
+        /// # use cucumber::{given, World};
+        /// #
+        /// # #[derive(Debug, Default, World)]
+        /// # struct MyWorld;
+        /// #
+        /// #[deprecated]
+        /// #[derive(derive_more::FromStr)]
+        /// struct Legacy(u32);
+        ///
+        /// #[given(expr = "{int} legacy items")]
+        /// #[allow(deprecated)]
+        /// fn legacy(_: &mut MyWorld, _items: Legacy) {}
+        /// #
+        /// # #[tokio::main]
+        /// # async fn main() {
+        /// #     MyWorld::run("./tests/features/doctests.feature").await;
+        /// # }
+        /// ```
+        ///
         /// [`Display`]: std::fmt::Display
         /// [`FromStr`]: std::str::FromStr
         /// [`Regex`]: regex::Regex
@@ -322,6 +383,39 @@ steps!(given, when, then);
 ///   (return [`Result`]) or infallible (return [`World`] itself). In case no
 ///   function is specified, the [`Default::default()`] will be used for
 ///   construction.
+///
+/// - `#[world(from_cli = path::to::fn)]`
+///
+///   Path to a sync function to be used for a [`World`] instance construction
+///   from custom CLI options, set via `Cucumber::custom_cli()`. Specified
+///   function accepts a reference to the custom CLI options and can be either
+///   fallible (return [`Result`]) or infallible (return [`World`] itself).
+///
+/// - `#[world(snapshot)]`
+///
+///   Makes writers output a `WorldSnapshot` of a [`World`] instance (instead of
+///   its full `Debug` output) on failures. Requires the type to implement a
+///   `WorldSnapshot` trait.
+///
+/// - `#[world(teardown)]`
+///
+///   Makes runners execute a `WorldTeardown::teardown()` of a [`World`]
+///   instance once its scenario finishes (even if it fails). Requires the
+///   type to implement a `WorldTeardown` trait.
+///
+/// - `#[world(steps(path::to::step_fn, ...))]`
+///
+///   Paths to step functions generic over a [`World`] (see [`macro@given`]),
+///   to be monomorphized for this [`World`]. Allows reusing step libraries
+///   written against a trait rather than a concrete [`World`].
+///
+/// # Field attribute arguments
+///
+/// - `#[world(nested)]`
+///
+///   Marks the field as a nested sub-world (itself deriving a [`World`]), so
+///   all the steps declared for its type are automatically adapted to this
+///   [`World`]. Allows reusing step libraries across different [`World`]s.
 #[proc_macro_derive(World, attributes(world))]
 pub fn world(input: TokenStream) -> TokenStream {
     world::derive(input.into())
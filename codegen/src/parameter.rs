@@ -98,6 +98,14 @@ impl Definition {
                 const REGEX: &'static str = #regex;
                 const NAME: &'static str = #name;
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: #name,
+                    regex: #regex,
+                }
+            );
         }
     }
 }
@@ -120,6 +128,14 @@ mod spec {
                 const REGEX: &'static str = "cat|dog";
                 const NAME: &'static str = "custom";
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: "custom",
+                    regex: "cat|dog",
+                }
+            );
         };
 
         assert_eq!(
@@ -141,6 +157,14 @@ mod spec {
                 const REGEX: &'static str = "cat|dog";
                 const NAME: &'static str = "animal";
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: "animal",
+                    regex: "cat|dog",
+                }
+            );
         };
 
         assert_eq!(
@@ -162,6 +186,14 @@ mod spec {
                 const REGEX: &'static str = "(cat)|(dog)";
                 const NAME: &'static str = "animal";
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: "animal",
+                    regex: "(cat)|(dog)",
+                }
+            );
         };
 
         assert_eq!(
@@ -183,6 +215,14 @@ mod spec {
                 const REGEX: &'static str = "cat|dog";
                 const NAME: &'static str = "custom";
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: "custom",
+                    regex: "cat|dog",
+                }
+            );
         };
 
         assert_eq!(
@@ -204,6 +244,14 @@ mod spec {
                 const REGEX: &'static str = "cat|dog(?:s)?";
                 const NAME: &'static str = "custom";
             }
+
+            #[automatically_derived]
+            ::cucumber::codegen::submit!(
+                ::cucumber::codegen::CustomParameter {
+                    name: "custom",
+                    regex: "cat|dog(?:s)?",
+                }
+            );
         };
 
         assert_eq!(
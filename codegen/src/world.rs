@@ -37,12 +37,57 @@ struct Attrs {
     /// If [`None`] then [`Default::default()`] will be used.
     #[parse(value)]
     init: Option<syn::ExprPath>,
+
+    /// Function to be used for a `World` construction from custom CLI options.
+    ///
+    /// If [`None`] then `World::from_cli()` won't be overridden.
+    #[parse(value)]
+    from_cli: Option<syn::ExprPath>,
+
+    /// Indicator whether this `World` implements a `WorldSnapshot` trait to
+    /// be used by writers.
+    #[parse(ident)]
+    snapshot: Option<syn::Ident>,
+
+    /// Indicator whether this `World` implements a `WorldTeardown` trait to
+    /// be executed by runners.
+    #[parse(ident)]
+    teardown: Option<syn::Ident>,
+
+    /// Step functions generic over a `World` to be monomorphized for this
+    /// `World`.
+    #[parse(value, alias = step)]
+    steps: Vec<syn::Path>,
+}
+
+/// Helper attributes of a `#[derive(World)]` macro placed on a field.
+#[derive(Debug, Default, ParseAttrs)]
+struct FieldAttrs {
+    /// Indicator whether this field is a nested sub-world, whose steps should
+    /// be adapted to this `World`.
+    #[parse(ident)]
+    nested: Option<syn::Ident>,
+}
+
+/// Field of a `World` holding a nested sub-world.
+#[derive(Debug)]
+struct NestedField {
+    /// [`syn::Member`] to access this field with.
+    member: syn::Member,
+
+    /// [`syn::Type`] of the nested sub-world.
+    ty: syn::Type,
 }
 
 /// Representation of a type implementing a `World` trait, used for code
 /// generation.
 #[derive(Debug, ToTokens)]
-#[to_tokens(append(impl_world_inventory, impl_world, impl_step_constructors))]
+#[to_tokens(append(
+    impl_world_inventory,
+    impl_world,
+    impl_step_constructors,
+    impl_nested,
+))]
 struct Definition {
     /// Name of this type.
     ident: syn::Ident,
@@ -58,6 +103,26 @@ struct Definition {
     /// Function, which is used to construct `World`. Uses [`Default`] impl, in
     /// case no value is provided.
     init: Option<syn::ExprPath>,
+
+    /// Function, which is used to construct `World` from custom CLI options.
+    /// Uses the default `World::from_cli()` impl, in case no value is
+    /// provided.
+    from_cli: Option<syn::ExprPath>,
+
+    /// Indicator whether `World::as_snapshot()` should return this `World` as
+    /// a `WorldSnapshot`.
+    snapshot: bool,
+
+    /// Indicator whether `World::as_teardown()` should return a `Future` of
+    /// `WorldTeardown::teardown()` of this `World`.
+    teardown: bool,
+
+    /// Fields of this `World` holding nested sub-worlds.
+    nested: Vec<NestedField>,
+
+    /// Step functions generic over a `World` to be monomorphized for this
+    /// `World`.
+    steps: Vec<syn::Path>,
 }
 
 impl TryFrom<syn::DeriveInput> for Definition {
@@ -66,11 +131,34 @@ impl TryFrom<syn::DeriveInput> for Definition {
     fn try_from(input: syn::DeriveInput) -> syn::Result<Self> {
         let attrs: Attrs = Attrs::parse_attrs("world", &input)?;
 
+        let fields = match &input.data {
+            syn::Data::Struct(data) => data.fields.iter().collect(),
+            syn::Data::Enum(_) | syn::Data::Union(_) => vec![],
+        };
+        let mut nested = vec![];
+        for (i, field) in fields.into_iter().enumerate() {
+            let field_attrs = FieldAttrs::parse_attrs("world", field)?;
+            if field_attrs.nested.is_some() {
+                nested.push(NestedField {
+                    member: field.ident.clone().map_or_else(
+                        || syn::Member::Unnamed(i.into()),
+                        syn::Member::Named,
+                    ),
+                    ty: field.ty.clone(),
+                });
+            }
+        }
+
         Ok(Self {
             ident: input.ident,
             generics: input.generics,
             vis: input.vis,
             init: attrs.init,
+            from_cli: attrs.from_cli,
+            snapshot: attrs.snapshot.is_some(),
+            teardown: attrs.teardown.is_some(),
+            nested,
+            steps: attrs.steps,
         })
     }
 }
@@ -95,6 +183,34 @@ impl Definition {
             .collect_tuple()
             .unwrap_or_else(|| unreachable!("{:?}", Self::EXACTLY_3_STEPS));
 
+        let nested_steps = (!self.nested.is_empty()).then(|| {
+            let tys = self.nested.iter().map(|f| &f.ty);
+            quote! {
+                fn nested_steps(
+                    steps: ::cucumber::step::Collection<Self>,
+                ) -> ::cucumber::step::Collection<Self> {
+                    #( let steps = ::cucumber::codegen::nest::<Self, #tys>(
+                        steps,
+                    ); )*
+                    steps
+                }
+            }
+        });
+
+        let generic_steps = (!self.steps.is_empty()).then(|| {
+            let steps = &self.steps;
+            quote! {
+                fn generic_steps(
+                    steps: ::cucumber::step::Collection<Self>,
+                ) -> ::cucumber::step::Collection<Self> {
+                    #( let steps = <#steps as ::cucumber::codegen::GenericStep<
+                        Self,
+                    >>::register(steps); )*
+                    steps
+                }
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl #impl_gens ::cucumber::codegen::WorldInventory
@@ -104,6 +220,10 @@ impl Definition {
                 type Given = #given_ty;
                 type When = #when_step_ty;
                 type Then = #then_ty;
+
+                #nested_steps
+
+                #generic_steps
             }
         }
     }
@@ -117,6 +237,48 @@ impl Definition {
             || parse_quote! { <Self as ::std::default::Default>::default },
         );
 
+        let from_cli = self.from_cli.as_ref().map(|from_cli| {
+            quote! {
+                async fn from_cli(
+                    cli: &(
+                        dyn ::std::any::Any
+                            + ::std::marker::Send
+                            + ::std::marker::Sync
+                    ),
+                ) -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::IntoWorldResult as _;
+
+                    ::cucumber::codegen::world_from_cli(cli, #from_cli)?
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        });
+
+        let as_snapshot = self.snapshot.then(|| {
+            quote! {
+                fn as_snapshot(
+                    &self,
+                ) -> ::std::option::Option<&dyn ::cucumber::WorldSnapshot> {
+                    ::std::option::Option::Some(self)
+                }
+            }
+        });
+
+        let as_teardown = self.teardown.then(|| {
+            quote! {
+                fn as_teardown(
+                    &mut self,
+                ) -> ::std::option::Option<
+                    ::cucumber::codegen::LocalBoxFuture<'_, ()>,
+                > {
+                    ::std::option::Option::Some(::std::boxed::Box::pin(
+                        <Self as ::cucumber::WorldTeardown>::teardown(self),
+                    ))
+                }
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl #impl_gens ::cucumber::World for #world #ty_gens
@@ -139,6 +301,12 @@ impl Definition {
                         .into_world_result()
                         .map_err(::std::convert::Into::into)
                 }
+
+                #from_cli
+
+                #as_snapshot
+
+                #as_teardown
             }
         }
     }
@@ -188,6 +356,30 @@ impl Definition {
             .collect()
     }
 
+    /// Generates code implementing a `Nested` trait for every sub-world of
+    /// this `World`.
+    fn impl_nested(&self) -> TokenStream {
+        let world = &self.ident;
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        self.nested
+            .iter()
+            .map(|NestedField { member, ty }| {
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_gens ::cucumber::codegen::Nested<#ty>
+                         for #world #ty_gens
+                         #where_clause
+                    {
+                        fn nested_mut(&mut self) -> &mut #ty {
+                            &mut self.#member
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Generates [`syn::Ident`]s of generic types for private trait impl.
     ///
     /// [`syn::Ident`]: struct@syn::Ident
@@ -332,6 +524,155 @@ mod spec {
         );
     }
 
+    #[test]
+    fn derives_impl_with_nested() {
+        let input = parse_quote! {
+            pub struct World {
+                #[world(nested)]
+                http: HttpWorld,
+                id: u64,
+            }
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl ::cucumber::codegen::WorldInventory for World {
+                type Given = CucumberGivenWorld;
+                type When = CucumberWhenWorld;
+                type Then = CucumberThenWorld;
+
+                fn nested_steps(
+                    steps: ::cucumber::step::Collection<Self>,
+                ) -> ::cucumber::step::Collection<Self> {
+                    let steps = ::cucumber::codegen::nest::<Self, HttpWorld>(
+                        steps,
+                    );
+                    steps
+                }
+            }
+
+            #[automatically_derived]
+            impl ::cucumber::World for World {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::{
+                        IntoWorldResult as _, ToWorldFuture as _,
+                    };
+
+                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                        v
+                    }
+
+                    (&as_fn_ptr(<Self as ::std::default::Default>::default))
+                        .to_world_future()
+                        .await
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberGivenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl ::cucumber::codegen::StepConstructor<World> for
+                CucumberGivenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberGivenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberWhenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl ::cucumber::codegen::StepConstructor<World> for
+                CucumberWhenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberWhenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberThenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl ::cucumber::codegen::StepConstructor<World> for
+                CucumberThenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberThenWorld);
+
+            #[automatically_derived]
+            impl ::cucumber::codegen::Nested<HttpWorld> for World {
+                fn nested_mut(&mut self) -> &mut HttpWorld {
+                    &mut self.http
+                }
+            }
+        };
+
+        assert_eq!(
+            super::derive(input).unwrap().to_string(),
+            output.to_string(),
+        );
+    }
+
     #[test]
     fn derives_impl_with_generics() {
         let input = parse_quote! {
@@ -590,4 +931,424 @@ mod spec {
             output.to_string(),
         );
     }
+
+    #[test]
+    fn derives_impl_with_from_cli_fn() {
+        let input = parse_quote! {
+            #[world(init = Self::custom, from_cli = Self::from_opts)]
+            pub struct World<T>(T);
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::WorldInventory for World<T> {
+                type Given = CucumberGivenWorld;
+                type When = CucumberWhenWorld;
+                type Then = CucumberThenWorld;
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::World for World<T> {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::{
+                        IntoWorldResult as _, ToWorldFuture as _,
+                    };
+
+                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                        v
+                    }
+
+                    (&as_fn_ptr(Self::custom))
+                        .to_world_future()
+                        .await
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+
+                async fn from_cli(
+                    cli: &(
+                        dyn ::std::any::Any
+                            + ::std::marker::Send
+                            + ::std::marker::Sync
+                    ),
+                ) -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::IntoWorldResult as _;
+
+                    ::cucumber::codegen::world_from_cli(cli, Self::from_opts)?
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberGivenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberGivenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberGivenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberWhenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberWhenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberWhenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberThenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberThenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberThenWorld);
+        };
+
+        assert_eq!(
+            super::derive(input).unwrap().to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn derives_impl_with_snapshot() {
+        let input = parse_quote! {
+            #[world(init = Self::custom, snapshot)]
+            pub struct World<T>(T);
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::WorldInventory for World<T> {
+                type Given = CucumberGivenWorld;
+                type When = CucumberWhenWorld;
+                type Then = CucumberThenWorld;
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::World for World<T> {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::{
+                        IntoWorldResult as _, ToWorldFuture as _,
+                    };
+
+                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                        v
+                    }
+
+                    (&as_fn_ptr(Self::custom))
+                        .to_world_future()
+                        .await
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+
+                fn as_snapshot(
+                    &self,
+                ) -> ::std::option::Option<&dyn ::cucumber::WorldSnapshot> {
+                    ::std::option::Option::Some(self)
+                }
+            }
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberGivenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberGivenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberGivenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberWhenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberWhenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberWhenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberThenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberThenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberThenWorld);
+        };
+
+        assert_eq!(
+            super::derive(input).unwrap().to_string(),
+            output.to_string(),
+        );
+    }
+
+    #[test]
+    fn derives_impl_with_teardown() {
+        let input = parse_quote! {
+            #[world(init = Self::custom, teardown)]
+            pub struct World<T>(T);
+        };
+
+        let output = quote! {
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::WorldInventory for World<T> {
+                type Given = CucumberGivenWorld;
+                type When = CucumberWhenWorld;
+                type Then = CucumberThenWorld;
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::World for World<T> {
+                type Error = ::cucumber::codegen::anyhow::Error;
+
+                async fn new() -> ::std::result::Result<Self, Self::Error> {
+                    use ::cucumber::codegen::{
+                        IntoWorldResult as _, ToWorldFuture as _,
+                    };
+
+                    fn as_fn_ptr<T>(v: fn() -> T) -> fn() -> T {
+                        v
+                    }
+
+                    (&as_fn_ptr(Self::custom))
+                        .to_world_future()
+                        .await
+                        .into_world_result()
+                        .map_err(::std::convert::Into::into)
+                }
+
+                fn as_teardown(
+                    &mut self,
+                ) -> ::std::option::Option<
+                    ::cucumber::codegen::LocalBoxFuture<'_, ()>,
+                > {
+                    ::std::option::Option::Some(::std::boxed::Box::pin(
+                        <Self as ::cucumber::WorldTeardown>::teardown(self),
+                    ))
+                }
+            }
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberGivenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberGivenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberGivenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberWhenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberWhenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberWhenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberThenWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberThenWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberThenWorld);
+        };
+
+        assert_eq!(
+            super::derive(input).unwrap().to_string(),
+            output.to_string(),
+        );
+    }
 }
@@ -0,0 +1,295 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Per-[`Scenario`] artifacts (screenshots, dumps, etc.) management.
+//!
+//! Every [`Scenario`] gets its own artifacts [`Dir`], available in [`Step`]s
+//! via [`step::Context::artifact_dir()`]. Files written there are reported as
+//! [`event::Scenario::Artifact`]s once the [`Scenario`] finishes, and then kept
+//! or removed according to the configured [`Retention`] policy.
+//!
+//! Additionally, [`Artifact`]s may be produced by an [`on_step_failed`] hook
+//! (like a screenshot of a browser), being reported right after the failed
+//! [`Step`].
+//!
+//! [`on_step_failed`]: crate::Cucumber::on_step_failed()
+//! [`Scenario`]: gherkin::Scenario
+//! [`Step`]: crate::Step
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+
+use derive_more::with_trait::Display;
+
+use crate::{
+    event::{self, Retries, Source},
+    step,
+};
+
+/// Policy of keeping [`Scenario`]s artifacts once they finish.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default, Display, Eq, PartialEq)]
+pub enum Retention {
+    /// Keeping artifacts of all the [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("all")]
+    All,
+
+    /// Keeping artifacts of the failed [`Scenario`]s only.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[default]
+    #[display("failed")]
+    Failed,
+
+    /// Removing all the artifacts.
+    #[display("none")]
+    None,
+}
+
+impl Retention {
+    /// Indicates whether artifacts of a [`Scenario`] should be kept, based on
+    /// whether it `is_failed`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn keeps(self, is_failed: bool) -> bool {
+        match self {
+            Self::All => true,
+            Self::Failed => is_failed,
+            Self::None => false,
+        }
+    }
+}
+
+impl FromStr for Retention {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "failed" => Ok(Self::Failed),
+            "none" => Ok(Self::None),
+            _ => Err("possible options: all, failed, none"),
+        }
+    }
+}
+
+/// Returns the default root directory for artifacts of all [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[must_use]
+pub fn default_root() -> PathBuf {
+    env::temp_dir().join("cucumber-artifacts")
+}
+
+/// Artifacts directory of a single [`Scenario`].
+///
+/// It's not created until [`Dir::create()`] is called.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dir {
+    /// Path of this [`Dir`].
+    path: PathBuf,
+}
+
+impl Dir {
+    /// Creates a new [`Dir`] of the provided [`Scenario`] under the `root`
+    /// directory.
+    ///
+    /// Every retry attempt of the [`Scenario`] gets its own [`Dir`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn new(
+        root: &Path,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        retries: Option<Retries>,
+    ) -> Self {
+        let feature_name = feature
+            .path
+            .as_ref()
+            .and_then(|p| p.file_stem())
+            .and_then(|s| s.to_str())
+            .unwrap_or(&feature.name);
+        let retry = retries
+            .filter(|r| r.current > 0)
+            .map(|r| format!("_retry{}", r.current))
+            .unwrap_or_default();
+        let scenario_name = format!(
+            "{}_{}{retry}",
+            sanitize(&scenario.name),
+            scenario.position.line,
+        );
+
+        Self {
+            path: root.join(sanitize(feature_name)).join(scenario_name),
+        }
+    }
+
+    /// Returns the path of this [`Dir`] (which may not exist yet).
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Creates this [`Dir`] (if it doesn't exist yet) and returns its path.
+    ///
+    /// # Errors
+    ///
+    /// If failed to create this [`Dir`].
+    pub fn create(&self) -> io::Result<&Path> {
+        fs::create_dir_all(&self.path)?;
+        Ok(&self.path)
+    }
+
+    /// Returns all the files contained in this [`Dir`] (recursively), sorted
+    /// by their paths.
+    pub(crate) fn files(&self) -> Vec<PathBuf> {
+        fn collect(dir: &Path, out: &mut Vec<PathBuf>) {
+            for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    collect(&path, out);
+                } else {
+                    out.push(path);
+                }
+            }
+        }
+
+        let mut files = vec![];
+        collect(&self.path, &mut files);
+        files.sort();
+        files
+    }
+
+    /// Writes the provided [`Artifact`] into this [`Dir`] (creating it, if
+    /// required), returning the path of the written file.
+    ///
+    /// # Errors
+    ///
+    /// If failed to create this [`Dir`] or to write the [`Artifact`].
+    pub(crate) fn keep(&self, artifact: &Artifact) -> io::Result<PathBuf> {
+        let path = self.create()?.join(sanitize(&artifact.name));
+        fs::write(&path, &artifact.content)?;
+        Ok(path)
+    }
+
+    /// Removes this [`Dir`] with all its contents (if it exists).
+    pub(crate) fn remove(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Artifact produced by an [`on_step_failed`] hook, to be kept in the [`Dir`]
+/// of the failed [`Scenario`].
+///
+/// [`on_step_failed`]: crate::Cucumber::on_step_failed()
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Artifact {
+    /// Name of the file to keep this [`Artifact`] in (like `screenshot.png`).
+    ///
+    /// Characters not safe to be used in a file name are replaced with `_`.
+    pub name: String,
+
+    /// Content of this [`Artifact`].
+    pub content: Vec<u8>,
+}
+
+impl Artifact {
+    /// Creates a new [`Artifact`] with the provided `name` and `content`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// Information about a failed [`Step`], provided to an [`on_step_failed`]
+/// hook.
+///
+/// [`on_step_failed`]: crate::Cucumber::on_step_failed()
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct FailureInfo {
+    /// [`Feature`] of the failed [`Step`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    pub feature: Source<gherkin::Feature>,
+
+    /// [`Rule`] of the failed [`Step`], if any.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    pub rule: Option<Source<gherkin::Rule>>,
+
+    /// [`Scenario`] of the failed [`Step`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub scenario: Source<gherkin::Scenario>,
+
+    /// Failed [`Step`] itself.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub step: Source<gherkin::Step>,
+
+    /// Indicator whether the failed [`Step`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub is_background: bool,
+
+    /// [`event::StepError`] the [`Step`] failed with.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub error: event::StepError,
+
+    /// [`Retries`] of the [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub retries: Option<Retries>,
+
+    /// [`step::Outcome`]s of the [`Step`]s executed in the [`Scenario`] so
+    /// far (including the failed one), in their execution order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub prior_steps: Arc<[step::Outcome]>,
+}
+
+/// Replaces all the characters of the provided `name`, which are not safe to
+/// be used in a file name, with `_`.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,190 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Capturing of [`io::stdout()`] and [`io::stderr()`] output.
+//!
+//! Output is captured on the file descriptors level, and only while the
+//! wrapped [`Future`] is being polled. As file descriptors are process-wide,
+//! output of other threads is captured too, so [`runner::Basic`] runs
+//! [`Scenario`]s one-by-one on its own thread while capturing.
+//!
+//! > __NOTE__: Capturing is supported on Unix platforms only, being a no-op on
+//! >           others.
+//!
+//! [`runner::Basic`]: crate::runner::Basic
+//! [`Scenario`]: gherkin::Scenario
+
+use std::{
+    env,
+    fs::{self, File},
+    future::Future,
+    io::{self, Seek as _, Write as _},
+    pin::Pin,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+    task,
+};
+
+use pin_project::pin_project;
+#[cfg(unix)]
+use rustix::{fd::OwnedFd, io::dup, stdio};
+
+use crate::event;
+
+/// Storage of the captured [`io::stdout()`] and [`io::stderr()`] output.
+#[derive(Debug)]
+pub(crate) struct Capture {
+    /// Anonymous [`File`] capturing [`io::stdout()`].
+    stdout: File,
+
+    /// Anonymous [`File`] capturing [`io::stderr()`].
+    stderr: File,
+}
+
+impl Capture {
+    /// Creates a new empty [`Capture`].
+    ///
+    /// # Errors
+    ///
+    /// If failed to create anonymous [`File`]s to capture output into, or
+    /// capturing is not supported on the current platform.
+    pub(crate) fn new() -> io::Result<Self> {
+        if cfg!(not(unix)) {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
+        Ok(Self {
+            stdout: anonymous_file()?,
+            stderr: anonymous_file()?,
+        })
+    }
+
+    /// Wraps the provided [`Future`] to capture its output on every poll.
+    pub(crate) const fn wrap<F>(&self, fut: F) -> Captured<'_, F> {
+        Captured { fut, capture: self }
+    }
+
+    /// Takes the captured [`event::Output`] out of this [`Capture`] (if
+    /// anything has been captured), so it may be reused for capturing again.
+    pub(crate) fn take(&self) -> Option<event::Output> {
+        let read = |mut file: &File| {
+            let mut buf = Vec::new();
+            _ = file
+                .rewind()
+                .and_then(|()| io::copy(&mut file, &mut buf))
+                .and_then(|_| file.set_len(0))
+                .and_then(|()| file.rewind())
+                .ok();
+            String::from_utf8_lossy(&buf).into_owned()
+        };
+
+        let output = event::Output {
+            stdout: read(&self.stdout),
+            stderr: read(&self.stderr),
+        };
+        (!output.stdout.is_empty() || !output.stderr.is_empty())
+            .then_some(output)
+    }
+
+    /// Redirects [`io::stdout()`] and [`io::stderr()`] into this [`Capture`]
+    /// until the returned [`Redirect`] is dropped.
+    ///
+    /// Returns [`None`] if redirecting is not possible.
+    #[cfg(unix)]
+    fn redirect(&self) -> Option<Redirect> {
+        _ = io::stdout().flush().ok();
+        _ = io::stderr().flush().ok();
+
+        let stdout = dup(io::stdout()).ok()?;
+        let stderr = dup(io::stderr()).ok()?;
+        stdio::dup2_stdout(&self.stdout).ok()?;
+        if stdio::dup2_stderr(&self.stderr).is_err() {
+            _ = stdio::dup2_stdout(&stdout).ok();
+            return None;
+        }
+        Some(Redirect { stdout, stderr })
+    }
+
+    /// Redirecting is not supported on non-Unix platforms.
+    #[cfg(not(unix))]
+    #[expect(clippy::unused_self, reason = "platform-specific")]
+    const fn redirect(&self) -> Option<Redirect> {
+        None
+    }
+}
+
+/// Guard restoring the original [`io::stdout()`] and [`io::stderr()`] once
+/// dropped.
+#[derive(Debug)]
+struct Redirect {
+    /// Duplicate of the original [`io::stdout()`] descriptor.
+    #[cfg(unix)]
+    stdout: OwnedFd,
+
+    /// Duplicate of the original [`io::stderr()`] descriptor.
+    #[cfg(unix)]
+    stderr: OwnedFd,
+}
+
+impl Drop for Redirect {
+    fn drop(&mut self) {
+        _ = io::stdout().flush().ok();
+        _ = io::stderr().flush().ok();
+
+        #[cfg(unix)]
+        {
+            _ = stdio::dup2_stdout(&self.stdout).ok();
+            _ = stdio::dup2_stderr(&self.stderr).ok();
+        }
+    }
+}
+
+/// [`Future`] capturing the output of the wrapped one into a [`Capture`].
+#[derive(Debug)]
+#[pin_project]
+pub(crate) struct Captured<'c, F> {
+    /// Wrapped [`Future`].
+    #[pin]
+    fut: F,
+
+    /// [`Capture`] to capture output into.
+    capture: &'c Capture,
+}
+
+impl<F: Future> Future for Captured<'_, F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Self::Output> {
+        let this = self.project();
+        let _redirect = this.capture.redirect();
+        this.fut.poll(cx)
+    }
+}
+
+/// Creates a new anonymous [`File`] (removed from the file system right
+/// away).
+fn anonymous_file() -> io::Result<File> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let path = env::temp_dir().join(format!(
+        "cucumber-capture-{}-{}",
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+    ));
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    _ = fs::remove_file(&path).ok();
+    Ok(file)
+}
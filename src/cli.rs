@@ -27,10 +27,18 @@
 //! [`Writer`]: crate::Writer
 //! [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
 
-use gherkin::tagexpr::TagOperation;
+use std::{
+    env,
+    ffi::OsString,
+    fmt,
+    path::{Component, PathBuf},
+    str::FromStr,
+};
+
+use derive_more::with_trait::{Display, Error};
 use regex::Regex;
 
-use crate::writer::Coloring;
+use crate::{feature::Example, tag, writer::Coloring};
 
 pub use clap::{Args, Parser};
 
@@ -84,6 +92,10 @@ pub use clap::{Args, Parser};
     about = "Run the tests, pet a dog!",
     long_about = "Run the tests, pet a dog!"
 )]
+#[expect( // intentional
+    clippy::struct_excessive_bools,
+    reason = "independent options, not a state machine"
+)]
 pub struct Opts<Parser, Runner, Writer, Custom = Empty>
 where
     Parser: Args,
@@ -97,11 +109,23 @@ where
         long = "name",
         short = 'n',
         value_name = "regex",
-        visible_alias = "scenario-name",
+        env = "CUCUMBER_NAME",
         global = true
     )]
     pub re_filter: Option<Regex>,
 
+    /// Regex to filter scenarios by their name, composable with `--tags` and
+    /// `PATH:LINE` selectors.
+    #[arg(
+        id = "scenario-name",
+        long = "scenario-name",
+        value_name = "regex",
+        env = "CUCUMBER_SCENARIO_NAME",
+        conflicts_with = "name",
+        global = true
+    )]
+    pub scenario_name_filter: Option<Regex>,
+
     /// Tag expression to filter scenarios by.
     ///
     /// Note: Tags from Feature, Rule and Scenario are merged together on
@@ -111,10 +135,94 @@ where
         long = "tags",
         short = 't',
         value_name = "tagexpr",
+        env = "CUCUMBER_TAGS",
         conflicts_with = "name",
         global = true
     )]
-    pub tags_filter: Option<TagOperation>,
+    pub tags_filter: Option<tag::Expr>,
+
+    /// Selectors of scenarios by their location in the form of
+    /// `PATH:LINE[:LINE...]`.
+    ///
+    /// A line may point to a Feature, a Rule, a Scenario, one of its Steps, an
+    /// Examples block or one of its rows.
+    #[arg(
+        id = "select",
+        long = "select",
+        value_name = "PATH:LINE",
+        global = true
+    )]
+    pub line_filter: Vec<LineSelector>,
+
+    /// List the scenarios to be run (along with their execution details)
+    /// without running them.
+    #[arg(
+        long,
+        env = "CUCUMBER_LIST",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub list: bool,
+
+    /// Keep re-running changed feature files once they're run.
+    ///
+    /// Requires `watch` feature and `Cucumber::run_and_watch()` to be used,
+    /// having no effect otherwise.
+    #[arg(
+        long,
+        env = "CUCUMBER_WATCH",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true,
+        hide = cfg!(not(feature = "watch"))
+    )]
+    pub watch: bool,
+
+    /// Export the living documentation of the executed features (with their
+    /// descriptions, tags and last run statuses) as a Markdown tree into the
+    /// provided directory.
+    #[arg(
+        long,
+        value_name = "dir",
+        env = "CUCUMBER_EXPORT_DOCS",
+        global = true
+    )]
+    pub export_docs: Option<PathBuf>,
+
+    /// Output a JSON report of which steps matched which step definitions,
+    /// and which step definitions were never matched, into the provided file.
+    ///
+    /// Requires `output-coverage` feature, having no effect otherwise.
+    #[arg(
+        long,
+        value_name = "file",
+        env = "CUCUMBER_STEP_COVERAGE",
+        global = true,
+        hide = cfg!(not(feature = "output-coverage"))
+    )]
+    pub step_coverage: Option<PathBuf>,
+
+    /// Print the effective configuration (builder values merged with
+    /// environment variables and CLI options) in TOML format and exit without
+    /// running anything.
+    #[arg(
+        long,
+        env = "CUCUMBER_PRINT_CONFIG",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub print_config: bool,
+
+    /// Validate the registered step definitions (detecting conflicting ones,
+    /// invalid Cucumber Expressions and unknown or conflicting custom
+    /// parameters), print the found problems and exit without running
+    /// anything, failing if there are any.
+    #[arg(
+        long,
+        env = "CUCUMBER_VALIDATE",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub validate: bool,
 
     /// [`Parser`] CLI options.
     ///
@@ -152,6 +260,317 @@ where
     pub fn parsed() -> Self {
         <Self as clap::Parser>::parse()
     }
+
+    /// Parses [`Opts`] from the command line arguments, leaving everything
+    /// after the first standalone `--` separator to an application.
+    ///
+    /// Exits the process with a [`clap`] error message, if parsing fails.
+    ///
+    /// See [`Opts::try_parse_with_rest_from()`] for details.
+    #[must_use]
+    pub fn parsed_with_rest() -> (Self, Vec<OsString>) {
+        Self::try_parse_with_rest_from(env::args_os())
+            .unwrap_or_else(|e| e.exit())
+    }
+
+    /// Parses [`Opts`] from the provided `args`, leaving everything after the
+    /// first standalone `--` separator to an application.
+    ///
+    /// This allows binaries having their own flags (target URL, credentials,
+    /// etc.) to not fight [`clap`] for the command line:
+    /// ```bash
+    /// cargo test --test e2e -- --tags=@smoke -- --target=http://localhost
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If [`Opts`] fail to parse from the arguments before the separator.
+    pub fn try_parse_with_rest_from<I, T>(
+        args: I,
+    ) -> Result<(Self, Vec<OsString>), clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+        let own = args.by_ref().take_while(|a| a != "--").collect::<Vec<_>>();
+        let rest = args.collect();
+        Ok((<Self as clap::Parser>::try_parse_from(own)?, rest))
+    }
+
+    /// Parses the command line arguments over these [`Opts`], treating them
+    /// as defaults.
+    ///
+    /// Exits the process with a [`clap`] error message, if parsing fails.
+    ///
+    /// See [`Opts::try_parse_over_from()`] for details.
+    #[must_use]
+    pub fn parsed_over(self) -> Self {
+        self.try_parse_over_from(env::args_os())
+            .unwrap_or_else(|e| e.exit())
+    }
+
+    /// Parses the provided `args` over these [`Opts`], treating them as
+    /// defaults.
+    ///
+    /// Only the options explicitly specified in the `args` (or via their
+    /// environment variables) are overridden, while [`clap`]'s own default
+    /// values are ignored. This allows programmatically configured runs
+    /// (embedded into other binaries, for example) to still be tweaked from
+    /// the command line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::cli;
+    /// #
+    /// // Use CLIs of the actual `Parser`, `Runner` and `Writer` here instead.
+    /// type Opts = cli::Opts<cli::Empty, cli::Empty, cli::Empty>;
+    ///
+    /// let defaults = Opts {
+    ///     list: true,
+    ///     re_filter: "^smoke".parse().ok(),
+    ///     ..Opts::default()
+    /// };
+    /// let opts = defaults
+    ///     .try_parse_over_from(["e2e", "--name", "^regress"])
+    ///     .unwrap();
+    ///
+    /// assert!(opts.list);
+    /// assert_eq!(opts.re_filter.unwrap().as_str(), "^regress");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If [`Opts`] fail to parse from the `args`.
+    pub fn try_parse_over_from<I, T>(
+        mut self,
+        args: I,
+    ) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let mut cmd = <Self as clap::CommandFactory>::command();
+        // Building first, so the default values implied by `ArgAction`s are
+        // cleared too, and aren't re-applied.
+        cmd.build();
+        let matches = cmd
+            .mut_args(|arg| arg.default_value(None))
+            .try_get_matches_from(args)?;
+        <Self as clap::FromArgMatches>::update_from_arg_matches(
+            &mut self, &matches,
+        )?;
+        Ok(self)
+    }
+
+    /// Augments the provided application's [`clap::Command`] with these
+    /// [`Opts`], so they may be parsed as a part of it.
+    ///
+    /// Use [`Opts::from_matches()`] to extract [`Opts`] afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::cli;
+    /// #
+    /// // Use CLIs of the actual `Parser`, `Runner` and `Writer` here instead.
+    /// type Opts = cli::Opts<cli::Empty, cli::Empty, cli::Empty>;
+    ///
+    /// let cmd = clap::Command::new("e2e")
+    ///     .arg(clap::Arg::new("target").long("target").required(true));
+    /// let matches = Opts::augment(cmd)
+    ///     .try_get_matches_from(["e2e", "--target=localhost", "--tags=@ok"])
+    ///     .unwrap();
+    ///
+    /// let target = matches.get_one::<String>("target").unwrap();
+    /// let opts = Opts::from_matches(&matches).unwrap();
+    ///
+    /// assert_eq!(target, "localhost");
+    /// assert!(opts.tags_filter.is_some());
+    /// ```
+    #[must_use]
+    pub fn augment(cmd: clap::Command) -> clap::Command {
+        <Self as Args>::augment_args(cmd)
+    }
+
+    /// Extracts [`Opts`] from the [`clap::ArgMatches`] of an application's
+    /// [`clap::Command`] previously augmented via [`Opts::augment()`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`clap::ArgMatches`] don't contain valid [`Opts`].
+    pub fn from_matches(
+        matches: &clap::ArgMatches,
+    ) -> Result<Self, clap::Error> {
+        <Self as clap::FromArgMatches>::from_arg_matches(matches)
+    }
+}
+
+/// Selector of [`Scenario`]s by their location in the form of
+/// `PATH:LINE[:LINE...]`.
+///
+/// The `PATH` is matched against the trailing components of a [`Feature`]
+/// path, so may be relative.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineSelector {
+    /// Path to the `.feature` file.
+    pub path: PathBuf,
+
+    /// Lines in the `.feature` file.
+    pub lines: Vec<usize>,
+}
+
+impl LineSelector {
+    /// Checks whether the given [`Scenario`] is selected by this
+    /// [`LineSelector`].
+    ///
+    /// A [`Scenario`] is selected if any of the [`LineSelector::lines`] points
+    /// to its [`Feature`], [`Rule`], the [`Scenario`] itself, one of its
+    /// [`Step`]s, or the [`Examples`] block (or its row) it has been expanded
+    /// from.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn matches(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> bool {
+        if !feature
+            .path
+            .as_ref()
+            .is_some_and(|p| p.ends_with(&self.path))
+        {
+            return false;
+        }
+
+        // Expanded `Scenario Outline` keeps only the `Examples` block it's
+        // expanded from, resolving into the row it's expanded from.
+        let is_expanded = Example::of(scenario).is_some();
+
+        self.lines.iter().any(|&line| {
+            line == feature.position.line
+                || rule.is_some_and(|r| r.position.line == line)
+                || scenario.position.line == line
+                || scenario.steps.iter().any(|s| s.position.line == line)
+                || (is_expanded
+                    && scenario
+                        .examples
+                        .iter()
+                        .any(|ex| ex.position.line == line))
+        })
+    }
+}
+
+impl FromStr for LineSelector {
+    type Err = LineSelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':').collect::<Vec<_>>();
+
+        let mut lines = Vec::new();
+        while let Some(line) = parts.last().and_then(|l| l.parse().ok()) {
+            lines.push(line);
+            _ = parts.pop();
+        }
+        lines.reverse();
+
+        let path = PathBuf::from(parts.join(":"));
+        let path = path
+            .components()
+            .filter(|c| !matches!(c, Component::CurDir))
+            .collect::<PathBuf>();
+
+        if lines.is_empty() || path.as_os_str().is_empty() {
+            return Err(LineSelectorError(s.to_owned()));
+        }
+        Ok(Self { path, lines })
+    }
+}
+
+impl Display for LineSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        for line in &self.lines {
+            write!(f, ":{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of parsing a [`LineSelector`].
+#[derive(Clone, Debug, Display, Error)]
+#[display("Invalid selector `{_0}`, expected `PATH:LINE[:LINE...]` format")]
+pub struct LineSelectorError(#[error(not(source))] pub String);
+
+/// Resolved value of a configuration option, printed via `--print-config`.
+///
+/// [`Display`]s as a TOML value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigValue {
+    /// Option isn't set.
+    Unset,
+
+    /// Boolean option.
+    Bool(bool),
+
+    /// Integer option.
+    Int(usize),
+
+    /// String option.
+    Str(String),
+
+    /// Option having multiple string values.
+    List(Vec<String>),
+}
+
+impl ConfigValue {
+    /// Creates a [`ConfigValue::Str`] out of the [`Display`]ed `value`, if
+    /// any, or a [`ConfigValue::Unset`] otherwise.
+    #[must_use]
+    pub fn display<T: Display>(value: Option<T>) -> Self {
+        value.map_or(Self::Unset, |v| Self::Str(v.to_string()))
+    }
+}
+
+#[expect(clippy::use_debug, reason = "escapes strings the TOML way")]
+impl Display for ConfigValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unset => write!(f, "<unset>"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::Str(s) => write!(f, "{s:?}"),
+            Self::List(l) => write!(f, "{l:?}"),
+        }
+    }
+}
+
+impl From<bool> for ConfigValue {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+impl From<usize> for ConfigValue {
+    fn from(i: usize) -> Self {
+        Self::Int(i)
+    }
+}
+
+impl From<Option<usize>> for ConfigValue {
+    fn from(i: Option<usize>) -> Self {
+        i.map_or(Self::Unset, Self::Int)
+    }
 }
 
 /// Indication whether a [`Writer`] using CLI options supports colored output.
@@ -10,9 +10,16 @@
 
 //! Helper type-level glue for [`cucumber_codegen`] crate.
 
-use std::{convert::Infallible, future::Future};
+use std::{
+    any::{self, Any},
+    collections::HashMap,
+    convert::Infallible,
+    future::Future,
+};
 
+use cucumber_expressions::SingleExpression;
 use futures::future;
+use itertools::Itertools as _;
 
 use crate::{step, Step, World};
 
@@ -40,6 +47,99 @@ pub trait WorldInventory: World {
     ///
     /// [`then`]: crate::then
     type Then: inventory::Collect + StepConstructor<Self>;
+
+    /// Adds [`Step`]s of all the [`Nested`] sub-worlds of this [`World`] to
+    /// the provided [`step::Collection`].
+    ///
+    /// Generated for the fields marked with a `#[world(nested)]` attribute.
+    #[must_use]
+    fn nested_steps(steps: step::Collection<Self>) -> step::Collection<Self> {
+        steps
+    }
+
+    /// Adds all the [`GenericStep`]s monomorphized for this [`World`] to the
+    /// provided [`step::Collection`].
+    ///
+    /// Generated for the `#[world(steps(...))]` attribute.
+    #[must_use]
+    fn generic_steps(steps: step::Collection<Self>) -> step::Collection<Self> {
+        steps
+    }
+}
+
+/// [`Step`] function generic over a [`World`], being monomorphized for the
+/// concrete [`World`] `W`.
+///
+/// Should be implemented only via [`given`], [`when`] and [`then`] attributes
+/// placed on a function generic over its [`World`], and registered via a
+/// `#[world(steps(...))]` attribute of a [`#[derive(World)]`](macro@World)
+/// macro.
+///
+/// [`given`]: crate::given
+/// [`when`]: crate::when
+/// [`then`]: crate::then
+pub trait GenericStep<W> {
+    /// Adds this [`Step`] monomorphized for the [`World`] `W` to the provided
+    /// [`step::Collection`].
+    #[must_use]
+    fn register(steps: step::Collection<W>) -> step::Collection<W>;
+}
+
+/// [`World`] composed of a smaller reusable sub-world `S`, to which all the
+/// [`Step`]s declared for `S` are adapted.
+///
+/// Should be implemented only via a `#[world(nested)]` attribute of a
+/// [`#[derive(World)]`](macro@World) macro.
+pub trait Nested<S>: World {
+    /// Returns a mutable reference to the sub-world `S` of this [`World`].
+    fn nested_mut(&mut self) -> &mut S;
+}
+
+/// Adds all the [`Step`]s declared for the sub-world `S` to the provided
+/// [`step::Collection`] of the composite [`World`] `W`.
+#[must_use]
+pub fn nest<W, S>(mut steps: step::Collection<W>) -> step::Collection<W>
+where
+    W: Nested<S>,
+    S: WorldInventory,
+{
+    for given in inventory::iter::<S::Given> {
+        let (loc, regex, _) = given.inner();
+        steps = steps.given(Some(loc), regex(), nested::<W, S, S::Given>);
+    }
+
+    for when in inventory::iter::<S::When> {
+        let (loc, regex, _) = when.inner();
+        steps = steps.when(Some(loc), regex(), nested::<W, S, S::When>);
+    }
+
+    for then in inventory::iter::<S::Then> {
+        let (loc, regex, _) = then.inner();
+        steps = steps.then(Some(loc), regex(), nested::<W, S, S::Then>);
+    }
+
+    steps
+}
+
+/// [`Step`] of the composite [`World`] `W`, running the [`Step`] of the
+/// sub-world `S` matched by the same [`Regex`].
+fn nested<W, S, T>(world: &mut W, ctx: step::Context) -> LocalBoxFuture<'_, ()>
+where
+    W: Nested<S>,
+    S: WorldInventory,
+    T: inventory::Collect + StepConstructor<S>,
+{
+    let func = inventory::iter::<T>
+        .into_iter()
+        .map(StepConstructor::inner)
+        .find_map(|(_, regex, func)| {
+            (regex().as_str() == ctx.regex.as_str()).then_some(func)
+        })
+        .unwrap_or_else(|| {
+            unreachable!("`Step` of a nested `World` cannot disappear")
+        });
+
+    func(world.nested_mut(), ctx)
 }
 
 /// Alias for a [`fn`] returning a [`Regex`].
@@ -56,6 +156,177 @@ pub trait StepConstructor<W> {
     fn inner(&self) -> (step::Location, LazyRegex, Step<W>);
 }
 
+/// [`step::Location`] of a [`Step`] function marked with a `#[wip]` attribute,
+/// [`submit`]ted in a [`given`], [`when`] or [`then`] macro.
+///
+/// [`given`]: crate::given
+/// [`when`]: crate::when
+/// [`then`]: crate::then
+#[derive(Clone, Copy, Debug)]
+pub struct WipStep(pub step::Location);
+
+collect!(WipStep);
+
+/// Indicates whether the [`Step`] function at the provided [`step::Location`]
+/// is marked with a `#[wip]` attribute.
+#[must_use]
+pub fn is_wip_step(loc: step::Location) -> bool {
+    inventory::iter::<WipStep>
+        .into_iter()
+        .any(|wip| wip.0 == loc)
+}
+
+/// [Cucumber Expression] of a [`Step`] function, [`submit`]ted in a
+/// [`given`], [`when`] or [`then`] macro.
+///
+/// [`given`]: crate::given
+/// [`when`]: crate::when
+/// [`then`]: crate::then
+/// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+#[derive(Clone, Copy, Debug)]
+pub struct StepExpression {
+    /// [`step::Location`] of the [`Step`] function.
+    pub location: step::Location,
+
+    /// [Cucumber Expression] of the [`Step`] function.
+    ///
+    /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+    pub expression: &'static str,
+}
+
+collect!(StepExpression);
+
+/// Custom [`Parameter`], [`submit`]ted in a
+/// [`Parameter`](macro@crate::Parameter) derive macro.
+#[derive(Clone, Copy, Debug)]
+pub struct CustomParameter {
+    /// [`Parameter::NAME`] of the custom [`Parameter`].
+    pub name: &'static str,
+
+    /// [`Parameter::REGEX`] of the custom [`Parameter`].
+    pub regex: &'static str,
+}
+
+collect!(CustomParameter);
+
+/// Validates all the [`submit`]ted [`StepExpression`]s and [`CustomParameter`]s
+/// at runtime.
+///
+/// Catches the problems not detectable during a single macro expansion, like
+/// [`CustomParameter`]s with the same name declared in different crates.
+#[must_use]
+pub fn validate_expressions() -> Vec<step::ValidationError> {
+    let params = inventory::iter::<CustomParameter>
+        .into_iter()
+        .into_group_map_by(|p| p.name);
+
+    let mut errors = Vec::new();
+    for (name, declared) in params.iter().sorted_by_key(|(name, _)| **name) {
+        let regexes = declared.iter().map(|p| p.regex).unique().collect_vec();
+        errors.extend(regexes.iter().filter_map(|re| {
+            Regex::new(re).err().map(|e| {
+                step::ValidationError::InvalidParameter {
+                    name,
+                    error: e.to_string(),
+                }
+            })
+        }));
+        if regexes.len() > 1 {
+            errors.push(step::ValidationError::ParameterConflict {
+                name,
+                regexes,
+            });
+        }
+    }
+
+    let params = params
+        .into_iter()
+        .map(|(name, p)| (name, p[0].regex))
+        .collect::<HashMap<_, _>>();
+    errors.extend(
+        inventory::iter::<StepExpression>
+            .into_iter()
+            .sorted_by_key(|e| (e.location, e.expression))
+            .filter_map(|e| {
+                Expression::regex_with_parameters(e.expression, &params)
+                    .err()
+                    .map(|err| step::ValidationError::InvalidExpression {
+                        location: e.location,
+                        expression: e.expression,
+                        error: err.to_string(),
+                    })
+            }),
+    );
+
+    errors
+}
+
+/// Returns the [`submit`]ted [Cucumber Expression] the provided
+/// [`step::Definition`] is expanded from, if any.
+///
+/// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+pub(crate) fn expression_of(def: &step::Definition) -> Option<&'static str> {
+    let location = def.location?;
+    let params = inventory::iter::<CustomParameter>
+        .into_iter()
+        .map(|p| (p.name, p.regex))
+        .collect::<HashMap<_, _>>();
+    inventory::iter::<StepExpression>
+        .into_iter()
+        .filter(|e| e.location == location)
+        .find(|e| {
+            Expression::regex_with_parameters(e.expression, &params)
+                .is_ok_and(|re| re.as_str() == def.regex.as_str())
+        })
+        .map(|e| e.expression)
+}
+
+/// Renders the provided [Cucumber Expression] into a human-readable signature
+/// of its parameters.
+///
+/// Returns [`None`] if the `expression` cannot be parsed.
+///
+/// ```rust
+/// # use cucumber::codegen::expression_signature;
+/// #
+/// assert_eq!(
+///     expression_signature("{word} is {int}").as_deref(),
+///     Some("word, int parameters"),
+/// );
+/// assert_eq!(
+///     expression_signature("I have {} cucumber(s)").as_deref(),
+///     Some("anonymous parameter"),
+/// );
+/// assert_eq!(
+///     expression_signature("nothing happens").as_deref(),
+///     Some("no parameters"),
+/// );
+/// ```
+///
+/// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+#[must_use]
+pub fn expression_signature(expression: &str) -> Option<String> {
+    let names = Expression::parse(expression)
+        .ok()?
+        .0
+        .into_iter()
+        .filter_map(|e| match e {
+            SingleExpression::Parameter(p) => Some(*p.input.fragment()),
+            SingleExpression::Alternation(_)
+            | SingleExpression::Optional(_)
+            | SingleExpression::Text(_)
+            | SingleExpression::Whitespaces(_) => None,
+        })
+        .map(|name| if name.is_empty() { "anonymous" } else { name })
+        .collect::<Vec<_>>();
+
+    Some(match names.as_slice() {
+        [] => "no parameters".into(),
+        [name] => format!("{name} parameter"),
+        names => format!("{} parameters", names.join(", ")),
+    })
+}
+
 /// Custom parameter of a [Cucumber Expression].
 ///
 /// Should be implemented only with via [`Parameter`] derive macro.
@@ -235,3 +506,22 @@ impl<W: World, E> IntoWorldResult for Result<W, E> {
         self
     }
 }
+
+/// Calls the function of a `#[world(from_cli)]` attribute of a
+/// [`#[derive(World)]`](macro@World) macro with the custom CLI options
+/// downcasted to its argument type.
+///
+/// # Errors
+///
+/// If the custom CLI options are of another type than the function accepts.
+pub fn world_from_cli<Cli: Any, R>(
+    cli: &(dyn Any + Send + Sync),
+    func: fn(&Cli) -> R,
+) -> anyhow::Result<R> {
+    cli.downcast_ref::<Cli>().map(func).ok_or_else(|| {
+        anyhow::anyhow!(
+            "custom CLI options are not of `{}` type",
+            any::type_name::<Cli>(),
+        )
+    })
+}
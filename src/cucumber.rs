@@ -12,20 +12,44 @@
 //!
 //! [Cucumber]: https://cucumber.io
 
-use std::{borrow::Cow, marker::PhantomData, mem, path::Path, time::Duration};
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::Cell,
+    collections::HashSet,
+    fmt::Write as _,
+    future::Future,
+    io::{self, Write as _},
+    marker::PhantomData,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use derive_more::with_trait::Debug;
-use futures::{future::LocalBoxFuture, StreamExt as _};
-use gherkin::tagexpr::TagOperation;
+use derive_more::with_trait::{Debug, Display};
+use futures::{
+    future::{self, Either, LocalBoxFuture},
+    FutureExt as _, StreamExt as _,
+};
 use regex::Regex;
 
 use crate::{
-    cli, event, parser,
+    artifacts,
+    cli::{self, ConfigValue},
+    event,
+    feature::{self, Ext as _},
+    fixtures, mask, parser,
     runner::{self, basic::RetryOptions},
-    step,
-    tag::Ext as _,
-    writer, Event, Parser, Runner, ScenarioType, Step, World, Writer,
-    WriterExt as _,
+    shared, step, tag,
+    writer::{
+        self,
+        basic::{coerce_error, trim_path},
+    },
+    Event, Parser, Runner, ScenarioType, Step, World, Writer, WriterExt as _,
 };
 
 /// Top-level [Cucumber] executor.
@@ -71,6 +95,16 @@ where
     /// If empty, then will be parsed from a command line.
     cli: Option<cli::Opts<P::Cli, R::Cli, Wr::Cli, Cli>>,
 
+    /// [`ExitCodes`] policy to terminate the process with in
+    /// [`Cucumber::run_and_exit()`].
+    ///
+    /// If [`None`], then [`Cucumber::run_and_exit()`] panics on failures.
+    exit_codes: Option<ExitCodes>,
+
+    /// [`mask::Secrets`] to be masked in events before any [`Writer`] sees
+    /// them.
+    secrets: mask::Secrets,
+
     /// Type of the [`World`] this [`Cucumber`] run on.
     #[debug(ignore)]
     _world: PhantomData<W>,
@@ -97,6 +131,8 @@ where
             runner,
             writer,
             cli: None,
+            exit_codes: None,
+            secrets: mask::Secrets::new(),
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -117,6 +153,8 @@ where
             runner,
             writer,
             cli: None,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -137,6 +175,8 @@ where
             runner,
             writer,
             cli: None,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -157,11 +197,78 @@ where
             runner,
             writer,
             cli: None,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 
+    /// Makes [`Cucumber::run_and_exit()`] terminate the process with the
+    /// provided [`ExitCodes`] instead of panicking on failures, so the kind of
+    /// a failure may be distinguished by the exit code.
+    ///
+    /// # Example
+    ///
+    /// Distinguish infrastructure problems from real test failures:
+    /// ```rust
+    /// # use cucumber::{ExitCodes, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .exit_codes(ExitCodes {
+    ///         parsing_errors: 2,
+    ///         hook_errors: 3,
+    ///         ..ExitCodes::default()
+    ///     })
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn exit_codes(mut self, codes: ExitCodes) -> Self {
+        self.exit_codes = Some(codes);
+        self
+    }
+
+    /// Masks the provided `secret` (a literal value or a [`Regex`]) with
+    /// [`mask::MASK`] in [`Step`] texts (along with their doc strings and
+    /// data tables), [`World`] outputs, captured logs and output, and panic
+    /// payloads before any [`Writer`] sees them.
+    ///
+    /// May be called multiple times to mask multiple secrets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// # use regex::Regex;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// # let api_key = "s3cr3t";
+    /// MyWorld::cucumber()
+    ///     .mask_secret(api_key)
+    ///     .mask_secret(Regex::new(r"Bearer \S+").unwrap())
+    ///     .run("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn mask_secret(mut self, secret: impl Into<mask::Secret>) -> Self {
+        self.secrets = self.secrets.with(secret);
+        self
+    }
+
     /// Re-outputs [`Skipped`] steps for easier navigation.
     ///
     /// # Example
@@ -208,6 +315,8 @@ where
             runner: self.runner,
             writer: self.writer.repeat_skipped(),
             cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -273,6 +382,59 @@ where
             runner: self.runner,
             writer: self.writer.repeat_failed(),
             cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Re-outputs the whole [`Scenario`]s failed in the end, grouped by their
+    /// [`Feature`]s and [`Rule`]s, for easier navigation.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn repeat_failed_scenarios(
+        self,
+    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
+    where
+        Wr: writer::NonTransforming,
+    {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.repeat_failed_scenarios(),
+            cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Re-outputs the whole [`Scenario`]s having [`Skipped`] steps, grouped by
+    /// their [`Feature`]s and [`Rule`]s, for easier navigation.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    #[must_use]
+    pub fn repeat_skipped_scenarios(
+        self,
+    ) -> Cucumber<W, P, I, R, writer::Repeat<W, Wr>, Cli>
+    where
+        Wr: writer::NonTransforming,
+    {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.repeat_skipped_scenarios(),
+            cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -332,7 +494,11 @@ where
     ///                             event: Scenario::Step(_, Step::Failed(..))
     ///                                 | Scenario::Background(
     ///                                     _,
-    ///                                     Step::Failed(_, _, _, _),
+    ///                                     Step::Failed(_, _, _, _, _),
+    ///                                 )
+    ///                                 | Scenario::RuleBackground(
+    ///                                     _,
+    ///                                     Step::Failed(_, _, _, _, _),
     ///                                 ),
     ///                             retries: _
     ///                         }
@@ -341,7 +507,11 @@ where
     ///                     _,
     ///                     RetryableScenario {
     ///                         event: Scenario::Step(_, Step::Failed(..))
-    ///                             | Scenario::Background(_, Step::Failed(..)),
+    ///                             | Scenario::Background(_, Step::Failed(..))
+    ///                             | Scenario::RuleBackground(
+    ///                                 _,
+    ///                                 Step::Failed(..),
+    ///                             ),
     ///                         retries: _
     ///                     }
     ///                 )
@@ -374,6 +544,8 @@ where
             runner: self.runner,
             writer: self.writer.repeat_if(filter),
             cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -445,6 +617,8 @@ where
             runner: self.runner,
             writer: self.writer.fail_on_skipped(),
             cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -535,6 +709,74 @@ where
             runner: self.runner,
             writer: self.writer.fail_on_skipped_with(filter),
             cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Consider [`Skipped`] [`Background`] or regular [`Step`]s as [`Failed`]
+    /// unless their [`Scenario`] matches the provided `allowed` tags
+    /// expression.
+    ///
+    /// Tags are inherited, so the expression is evaluated against the tags of
+    /// the [`Feature`], [`Rule`] and [`Scenario`] altogether.
+    ///
+    /// # Example
+    ///
+    /// Adjust [`Cucumber`] to fail on all [`Skipped`] steps, but the ones
+    /// marked with a `@dog` or `@wip` tag:
+    /// ```rust,should_panic
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .fail_on_skipped_allowing("@dog or @wip".parse().unwrap())
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Failed`]: event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[expect(clippy::type_complexity, reason = "`impl Fn` cannot be aliased")]
+    #[must_use]
+    pub fn fail_on_skipped_allowing(
+        self,
+        allowed: tag::Expr,
+    ) -> Cucumber<
+        W,
+        P,
+        I,
+        R,
+        writer::FailOnSkipped<
+            Wr,
+            impl Fn(
+                    &gherkin::Feature,
+                    Option<&gherkin::Rule>,
+                    &gherkin::Scenario,
+                ) -> bool
+                + Clone,
+        >,
+        Cli,
+    > {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.fail_on_skipped_allowing(allowed),
+            cli: self.cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -559,6 +801,66 @@ where
         self.filter_run(input, |_, _, _| true).await
     }
 
+    /// Replays the [`Cucumber`] events stream recorded by a
+    /// [`writer::Record`] from the given `input`, feeding it to the
+    /// [`Writer`] instead of running anything.
+    ///
+    /// Only [`Writer`] CLI options are taken into account, while [`Parser`]
+    /// and [`Runner`] are not used at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::{fs, io};
+    /// #
+    /// # use cucumber::{writer, World, WriterExt as _};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// // Record the events stream while running...
+    /// let file = fs::File::create("events.jsonl").unwrap();
+    /// MyWorld::cucumber()
+    ///     .with_writer(
+    ///         writer::Basic::stdout()
+    ///             .summarized()
+    ///             .tee::<MyWorld, _>(writer::Record::for_tee(file))
+    ///             .normalized(),
+    ///     )
+    ///     .run("tests/features/readme")
+    ///     .await;
+    ///
+    /// // ...and replay it later through any other `Writer`.
+    /// let file = fs::File::open("events.jsonl").unwrap();
+    /// MyWorld::cucumber::<&str>()
+    ///     .replay(io::BufReader::new(file))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If failed to read or decode the recorded events stream.
+    #[cfg(feature = "record")]
+    pub async fn replay(
+        self,
+        input: impl io::BufRead,
+    ) -> Result<Wr, parser::replay::Error> {
+        let cli::Opts {
+            writer: writer_cli, ..
+        } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::parsed);
+        let mut writer = self.writer;
+
+        for ev in parser::Replay::<W, _>::new(input) {
+            writer.handle_event(ev?, &writer_cli).await;
+        }
+        writer.finalize().await;
+        Ok(writer)
+    }
+
     /// Consumes already parsed [`cli::Opts`].
     ///
     /// This method allows to pre-parse [`cli::Opts`] for custom needs before
@@ -642,6 +944,8 @@ where
             runner,
             writer,
             cli: Some(cli),
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -660,6 +964,47 @@ where
         self
     }
 
+    /// Uses the provided [`cli::Opts`] as defaults, overridden by the options
+    /// explicitly specified in the command line (or via their environment
+    /// variables).
+    ///
+    /// Use [`Cucumber::with_cli()`] instead to omit parsing the command line
+    /// at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{cli, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let defaults = cli::Opts::<_, _, _, cli::Empty> {
+    ///     tags_filter: "not @slow".parse().ok(),
+    ///     ..cli::Opts::default()
+    /// };
+    ///
+    /// MyWorld::cucumber()
+    ///     .with_cli_defaults(defaults)
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// See [`cli::Opts::try_parse_over_from()`] for details.
+    #[must_use]
+    pub fn with_cli_defaults<CustomCli>(
+        self,
+        cli: cli::Opts<P::Cli, R::Cli, Wr::Cli, CustomCli>,
+    ) -> Cucumber<W, P, I, R, Wr, CustomCli>
+    where
+        CustomCli: clap::Args,
+    {
+        self.with_cli(cli.parsed_over())
+    }
+
     /// Runs [`Cucumber`] with [`Scenario`]s filter.
     ///
     /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
@@ -714,73 +1059,319 @@ where
                 &gherkin::Scenario,
             ) -> bool
             + 'static,
+    {
+        self.filter_run_inspected(input, sync_filter(filter), |_| {})
+            .await
+    }
+
+    /// Runs [`Cucumber`] with an asynchronous [`Scenario`]s filter.
+    ///
+    /// Similar to [`Cucumber::filter_run()`], but the provided predicate may
+    /// consult some external state (a feature-flag service, a test impact
+    /// analysis API, etc.) to decide whether a [`Scenario`] should be run.
+    /// Predicates of all the [`Scenario`]s of a single [`Feature`] are
+    /// awaited concurrently.
+    ///
+    /// The returned [`Future`] cannot borrow the predicate arguments, so
+    /// anything it needs should be cloned before an `async move` block.
+    ///
+    /// Excluded [`Scenario`]s are reported as filtered out via
+    /// [`event::Cucumber::ParsingFinished::filtered_scenarios`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # async fn is_impacted(scenario: String) -> bool {
+    /// #     scenario.contains("cat")
+    /// # }
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .filter_run_async("tests/features/readme", |_, _, sc| {
+    ///         is_impacted(sc.name.clone())
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`event::Cucumber::ParsingFinished::filtered_scenarios`]: event::Cucumber::ParsingFinished
+    pub async fn filter_run_async<F, Fut>(self, input: I, filter: F) -> Wr
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> Fut
+            + 'static,
+        Fut: Future<Output = bool> + 'static,
+    {
+        self.filter_run_inspected(input, filter, |_| {}).await
+    }
+
+    /// Runs [`Cucumber`] with [`Scenario`]s filter, passing every produced
+    /// event to the provided `inspect` function before handling it by the
+    /// [`Writer`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
+    async fn filter_run_inspected<F, Fut, Insp>(
+        self,
+        input: I,
+        filter: F,
+        mut inspect: Insp,
+    ) -> Wr
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> Fut
+            + 'static,
+        Fut: Future<Output = bool> + 'static,
+        Insp: FnMut(&parser::Result<Event<event::Cucumber<W>>>),
     {
         let cli::Opts {
             re_filter,
+            scenario_name_filter,
             tags_filter,
+            line_filter,
+            list,
+            export_docs,
+            step_coverage,
+            print_config,
+            validate,
             parser: parser_cli,
             runner: runner_cli,
             writer: writer_cli,
             ..
         } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::parsed);
 
-        let filter = move |feat: &gherkin::Feature,
-                           rule: Option<&gherkin::Rule>,
-                           scenario: &gherkin::Scenario| {
-            re_filter.as_ref().map_or_else(
-                || {
-                    tags_filter.as_ref().map_or_else(
-                        || filter(feat, rule, scenario),
-                        |tags| {
-                            // The order `Feature` -> `Rule` -> `Scenario`
-                            // matters here.
-                            tags.eval(
-                                feat.tags
-                                    .iter()
-                                    .chain(rule.iter().flat_map(|r| &r.tags))
-                                    .chain(scenario.tags.iter()),
-                            )
-                        },
-                    )
-                },
-                |re| re.is_match(&scenario.name),
-            )
-        };
-
         let Self {
             parser,
             runner,
             mut writer,
+            secrets,
             ..
         } = self;
+        secrets.register();
+        let mut masker = mask::Masker::new(secrets);
 
-        let features = parser.parse(input, parser_cli);
+        if print_config {
+            let cucumber = [
+                ("name", ConfigValue::display(re_filter.as_ref())),
+                (
+                    "scenario-name",
+                    ConfigValue::display(scenario_name_filter.as_ref()),
+                ),
+                ("tags", ConfigValue::display(tags_filter.as_ref())),
+                (
+                    "select",
+                    ConfigValue::List(
+                        line_filter.iter().map(ToString::to_string).collect(),
+                    ),
+                ),
+                (
+                    "export-docs",
+                    ConfigValue::display(
+                        export_docs.as_ref().map(|p| p.display()),
+                    ),
+                ),
+                (
+                    "step-coverage",
+                    ConfigValue::display(
+                        step_coverage.as_ref().map(|p| p.display()),
+                    ),
+                ),
+            ];
+            let config = format_config(&[
+                ("cucumber", &cucumber),
+                ("runner", &runner.resolved_config(&runner_cli)),
+            ]);
+            io::stdout()
+                .lock()
+                .write_all(config.as_bytes())
+                .unwrap_or_else(|e| {
+                    panic!("Failed to write configuration: {e}")
+                });
+            return writer;
+        }
 
-        let filtered = features.map(move |feature| {
-            let mut feature = feature?;
-            let feat_scenarios = mem::take(&mut feature.scenarios);
-            feature.scenarios = feat_scenarios
-                .into_iter()
-                .filter(|s| filter(&feature, None, s))
-                .collect();
-
-            let mut rules = mem::take(&mut feature.rules);
-            for r in &mut rules {
-                let rule_scenarios = mem::take(&mut r.scenarios);
-                r.scenarios = rule_scenarios
-                    .into_iter()
-                    .filter(|s| filter(&feature, Some(r), s))
-                    .collect();
+        if validate {
+            let definitions = runner.step_definitions();
+            let errors = step::validate(&definitions);
+
+            let mut out = format!(
+                "Validated {} step definition{}, found {} problem{}\n",
+                definitions.len(),
+                if definitions.len() == 1 { "" } else { "s" },
+                errors.len(),
+                if errors.len() == 1 { "" } else { "s" },
+            );
+            for e in &errors {
+                _ = writeln!(out, "\n{e}");
+            }
+            io::stdout()
+                .lock()
+                .write_all(out.as_bytes())
+                .unwrap_or_else(|e| {
+                    panic!("Failed to write validation report: {e}")
+                });
+
+            assert!(
+                errors.is_empty(),
+                "Step definitions validation failed: {} problem{} found",
+                errors.len(),
+                if errors.len() == 1 { "" } else { "s" },
+            );
+            return writer;
+        }
+
+        // Tags and names are filtered at parse time, so `Examples` of the
+        // filtered out `Scenario Outline`s (and their rows) aren't even
+        // expanded.
+        let parse_filter = parser::Filter {
+            tags: re_filter.is_none().then(|| tags_filter.clone()).flatten(),
+            name: re_filter.clone().or_else(|| scenario_name_filter.clone()),
+        };
+        let features = parser.parse_filtered(input, parser_cli, &parse_filter);
+
+        let filter = move |feat: &gherkin::Feature,
+                           rule: Option<&gherkin::Rule>,
+                           scenario: &gherkin::Scenario| {
+            let is_selected = scenario_name_filter
+                .as_ref()
+                .is_none_or(|re| re.is_match(&scenario.name))
+                && (line_filter.is_empty()
+                    || line_filter
+                        .iter()
+                        .any(|s| s.matches(feat, rule, scenario)));
+
+            if !is_selected {
+                return Either::Left(future::ready(false));
+            }
+            match (&re_filter, &tags_filter) {
+                (Some(re), _) => {
+                    Either::Left(future::ready(re.is_match(&scenario.name)))
+                }
+                (None, Some(tags)) => Either::Left(future::ready(
+                    tags.matches(feat, rule, scenario),
+                )),
+                (None, None) => Either::Right(filter(feat, rule, scenario)),
             }
-            feature.rules = rules;
+        };
+        let filter = Rc::new(filter);
+        let filtered_out = Rc::new(Cell::new(0));
+
+        let filtered = {
+            let filtered_out = Rc::clone(&filtered_out);
+            features.filter_map(move |res| {
+                let filter = Rc::clone(&filter);
+                let filtered_out = Rc::clone(&filtered_out);
+                async move {
+                    let Ok(mut feature) = res else {
+                        return Some(res);
+                    };
+                    let total = feature.count_scenarios();
+
+                    let feat_scenarios = mem::take(&mut feature.scenarios);
+                    feature.scenarios = select_scenarios(feat_scenarios, |s| {
+                        filter(&feature, None, s)
+                    })
+                    .await;
+
+                    // `Rule`s and `Feature`s having all their `Scenario`s
+                    // filtered out are omitted, so they aren't counted in the
+                    // `event::Cucumber::ParsingFinished`.
+                    let rules = mem::take(&mut feature.rules);
+                    let mut selected = Vec::with_capacity(rules.len());
+                    for mut r in rules {
+                        let rule_scenarios = mem::take(&mut r.scenarios);
+                        let was_empty = rule_scenarios.is_empty();
+                        r.scenarios = select_scenarios(rule_scenarios, |s| {
+                            filter(&feature, Some(&r), s)
+                        })
+                        .await;
+                        if was_empty || !r.scenarios.is_empty() {
+                            selected.push(r);
+                        }
+                    }
+                    feature.rules = selected;
 
-            Ok(feature)
+                    let left = feature.count_scenarios();
+                    filtered_out.set(filtered_out.get() + total - left);
+                    (left > 0 || total == 0).then_some(Ok(feature))
+                }
+            })
+        };
+
+        if list {
+            let planned = filtered.collect::<Vec<_>>().await;
+            io::stdout()
+                .lock()
+                .write_all(
+                    format_plan(&runner, &planned, &runner_cli).as_bytes(),
+                )
+                .unwrap_or_else(|e| {
+                    panic!("Failed to write execution plan: {e}")
+                });
+            return writer;
+        }
+
+        let mut docs = export_docs.map(writer::Docs::raw);
+        #[cfg(feature = "output-coverage")]
+        let mut coverage = step_coverage.map(|path| {
+            writer::Coverage::create(&path, runner.step_definitions())
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to create step coverage file {}: {e}",
+                        path.display(),
+                    )
+                })
         });
 
         let events_stream = runner.run(filtered, runner_cli);
         futures::pin_mut!(events_stream);
-        while let Some(ev) = events_stream.next().await {
-            writer.handle_event(ev, &writer_cli).await;
+        let handled = AssertUnwindSafe(async {
+            while let Some(ev) = events_stream.next().await {
+                let ev = ev.map(|e| {
+                    e.map(|mut c| {
+                        if let event::Cucumber::ParsingFinished {
+                            filtered_scenarios,
+                            ..
+                        } = &mut c
+                        {
+                            *filtered_scenarios += filtered_out.get();
+                        }
+                        masker.mask(c)
+                    })
+                });
+                inspect(&ev);
+                if let Some(docs) = docs.as_mut() {
+                    docs.handle_event(ev.clone(), &cli::Empty).await;
+                }
+                #[cfg(feature = "output-coverage")]
+                if let Some(coverage) = coverage.as_mut() {
+                    coverage.handle_event(ev.clone(), &cli::Empty).await;
+                }
+                writer.handle_event(ev, &writer_cli).await;
+            }
+        })
+        .catch_unwind()
+        .await;
+
+        // `Writer`s are finalized even if the events stream has ended abruptly,
+        // so they don't leave their outputs truncated.
+        writer.finalize().await;
+        if let Err(panic) = handled {
+            panic::resume_unwind(panic);
         }
         writer
     }
@@ -805,25 +1396,165 @@ where
             runner: self.runner.clone(),
             writer: self.writer.clone(),
             cli: self.cli.clone(),
+            exit_codes: self.exit_codes,
+            secrets: self.secrets.clone(),
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 }
 
-/// Shortcut for the [`Cucumber`] type returned by its [`Default`] impl.
-pub(crate) type DefaultCucumber<W, I> = Cucumber<
-    W,
-    parser::Basic,
-    I,
-    runner::Basic<W>,
-    writer::Summarize<writer::Normalize<W, writer::Basic>>,
->;
+/// Wraps the provided synchronous [`Scenario`]s filter into an asynchronous
+/// one, as expected by [`Cucumber::filter_run_async()`].
+///
+/// [`Scenario`]: gherkin::Scenario
+fn sync_filter<F>(
+    filter: F,
+) -> impl Fn(
+    &gherkin::Feature,
+    Option<&gherkin::Rule>,
+    &gherkin::Scenario,
+) -> future::Ready<bool>
+       + 'static
+where
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> bool
+        + 'static,
+{
+    move |feat, rule, scenario| future::ready(filter(feat, rule, scenario))
+}
+
+/// Retains only the [`Scenario`]s selected by the provided asynchronous
+/// predicate, awaiting it concurrently for all the [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+async fn select_scenarios<Fut>(
+    scenarios: Vec<gherkin::Scenario>,
+    is_selected: impl Fn(&gherkin::Scenario) -> Fut,
+) -> Vec<gherkin::Scenario>
+where
+    Fut: Future<Output = bool>,
+{
+    let verdicts = future::join_all(scenarios.iter().map(is_selected)).await;
+    scenarios
+        .into_iter()
+        .zip(verdicts)
+        .filter_map(|(s, selected)| selected.then_some(s))
+        .collect()
+}
+
+/// Formats the provided resolved configuration `sections` in TOML format, as
+/// printed with `--print-config` CLI option.
+///
+/// Unset options are commented out.
+fn format_config(sections: &[(&str, &[(&str, ConfigValue)])]) -> String {
+    let mut out = String::new();
+    for (i, (name, options)) in sections.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        _ = writeln!(out, "[{name}]");
+        for (key, val) in *options {
+            let comment = if matches!(val, ConfigValue::Unset) {
+                "# "
+            } else {
+                ""
+            };
+            _ = writeln!(out, "{comment}{key} = {val}");
+        }
+    }
+    out
+}
+
+/// Formats the execution plan of the provided [`Feature`]s by the provided
+/// [`Runner`], as printed with `--list` CLI option.
+///
+/// [`Feature`]: gherkin::Feature
+fn format_plan<W, R: Runner<W>>(
+    runner: &R,
+    features: &[parser::Result<gherkin::Feature>],
+    cli: &R::Cli,
+) -> String {
+    let mut out = String::new();
+    let mut scenarios = 0;
+    for feature in features {
+        let feature = match feature {
+            Ok(f) => f,
+            Err(e) => {
+                _ = writeln!(out, "Failed to parse: {e}");
+                continue;
+            }
+        };
+        _ = write!(out, "{}: {}", feature.keyword, feature.name);
+        if let Some(path) = &feature.path {
+            _ = write!(out, " ({})", trim_path(&path.display().to_string()));
+        }
+        out.push('\n');
+
+        let plan = |buf: &mut String,
+                    indent: &str,
+                    rule: Option<&gherkin::Rule>,
+                    sc: &gherkin::Scenario| {
+            _ = write!(
+                buf,
+                "{indent}{}: {} (line {})",
+                sc.keyword, sc.name, sc.position.line,
+            );
+            if let Some(p) = runner.scenario_plan(feature, rule, sc, cli) {
+                let ty = match p.ty {
+                    ScenarioType::Serial => "serial",
+                    ScenarioType::Concurrent => "concurrent",
+                };
+                _ = write!(buf, " [{ty}");
+                if let Some(retry) = p.retries {
+                    _ = write!(buf, ", {} retries", retry.retries.left);
+                    if let Some(after) = retry.after {
+                        _ = write!(
+                            buf,
+                            " after {}",
+                            humantime::format_duration(after),
+                        );
+                    }
+                }
+                buf.push(']');
+            }
+            buf.push('\n');
+        };
+        for sc in &feature.scenarios {
+            plan(&mut out, "  ", None, sc);
+            scenarios += 1;
+        }
+        for rule in &feature.rules {
+            _ = writeln!(out, "  {}: {}", rule.keyword, rule.name);
+            for sc in &rule.scenarios {
+                plan(&mut out, "    ", Some(rule), sc);
+                scenarios += 1;
+            }
+        }
+    }
+    _ = writeln!(
+        out,
+        "{scenarios} scenario{} to run",
+        if scenarios == 1 { "" } else { "s" },
+    );
+    out
+}
+
+/// Shortcut for the [`Cucumber`] type returned by its [`Default`] impl.
+pub(crate) type DefaultCucumber<W, I> =
+    Cucumber<W, parser::Basic, I, runner::Basic<W>, DefaultWriter<W>>;
+
+/// Shortcut for the [`Writer`] type of the [`DefaultCucumber`].
+pub(crate) type DefaultWriter<W> =
+    writer::Summarize<writer::Normalize<W, writer::Basic>>;
 
 impl<W, I> Default for DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     fn default() -> Self {
         Self::custom(
@@ -837,7 +1568,7 @@ where
 impl<W, I> DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Creates a default [`Cucumber`] executor.
     ///
@@ -869,7 +1600,7 @@ where
     R: Runner<W>,
     Wr: Writer<W>,
     Cli: clap::Args,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Sets the provided language of [`gherkin`] files.
     ///
@@ -883,10 +1614,172 @@ where
         self.parser = self.parser.language(name)?;
         Ok(self)
     }
+
+    /// Sets the function transforming [`Examples`] of
+    /// [`Scenario Outline`][1]s before they're expanded row by row into
+    /// separate [`Scenario`]s.
+    ///
+    /// See [`feature::ExamplesExpansionFn`] for details.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn with_example_expansion<F>(mut self, func: F) -> Self
+    where
+        F: Fn(
+                Option<&Path>,
+                &gherkin::Scenario,
+                gherkin::Examples,
+            ) -> gherkin::Examples
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.parser = self.parser.example_expansion(func);
+        self
+    }
+
+    /// Sets the [`feature::Interpolation`] of `${ENV_VAR}` and `{{config.key}}`
+    /// placeholders in texts, doc strings and data tables of [`Step`]s.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn interpolate(
+        mut self,
+        interpolation: feature::Interpolation,
+    ) -> Self {
+        self.parser = self.parser.interpolate(interpolation);
+        self
+    }
+
+    /// Sets whether `<placeholders>` of [`Scenario Outline`][1]s should be
+    /// substituted inside doc strings and data tables of [`Step`]s, not only in
+    /// their text.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn substitute_step_arguments(mut self, enabled: bool) -> Self {
+        self.parser = self.parser.substitute_step_arguments(enabled);
+        self
+    }
 }
 
-impl<W, I, P, Wr, F, B, A, Cli>
-    Cucumber<W, P, I, runner::Basic<W, F, B, A>, Wr, Cli>
+#[cfg(feature = "watch")]
+impl<W, I, R, Wr, Cli> Cucumber<W, parser::Basic, I, R, Wr, Cli>
+where
+    W: World,
+    I: parser::basic::IntoInputs,
+    R: Runner<W> + Clone,
+    Wr: writer::Stats<W> + writer::Normalized,
+    Cli: clap::Args + Clone,
+    R::Cli: Clone,
+    Wr::Cli: Clone,
+{
+    /// Runs [`Cucumber`], and then, if `--watch` CLI option is specified,
+    /// keeps re-running the changed (or newly created) [`Feature`] files of the
+    /// `input`, until the process is terminated.
+    ///
+    /// Every re-run reuses the already built [`Runner`] (along with its
+    /// [`Step`]s), while its events are handled by a new [`Writer`] created
+    /// with the provided `writer` function. Failures don't terminate the
+    /// process in this mode.
+    ///
+    /// If `--watch` CLI option is not specified, behaves exactly as
+    /// [`Cucumber::run_and_exit()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use cucumber::{writer, World, WriterExt as _};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .run_and_watch("tests/features/readme", || {
+    ///         writer::Basic::stdout().summarized()
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `--watch` CLI option is not specified, in the same cases as
+    /// [`Cucumber::run_and_exit()`] does, or if the `input` cannot be watched
+    /// for changes otherwise.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    pub async fn run_and_watch<F>(mut self, input: I, mut writer: F)
+    where
+        F: FnMut() -> Wr,
+    {
+        use crate::watch::Watcher;
+        use parser::basic::{self, Inputs};
+
+        let cli = self.cli.take().unwrap_or_else(cli::Opts::parsed);
+        if !cli.watch {
+            self.cli = Some(cli);
+            return self.run_and_exit(input).await;
+        }
+
+        let input = input.into_inputs();
+        let mut watcher = Watcher::new(&basic::roots(&input, &cli.parser))
+            .unwrap_or_else(|e| {
+                panic!("Failed to watch `.feature` files: {e}")
+            });
+
+        let Self {
+            parser,
+            runner,
+            writer: first_writer,
+            exit_codes,
+            secrets,
+            ..
+        } = self;
+        let run = |inputs: Inputs, wr: Wr, opts| {
+            Cucumber {
+                parser: parser.clone(),
+                runner: runner.clone(),
+                writer: wr,
+                cli: Some(opts),
+                exit_codes,
+                secrets: secrets.clone(),
+                _world: PhantomData,
+                _parser_input: PhantomData,
+            }
+            .run(inputs)
+        };
+
+        drop(run(input.clone(), first_writer, cli.clone()).await);
+
+        // Changed files are passed as inputs directly.
+        let mut rerun_cli = cli.clone();
+        rerun_cli.parser.features = None;
+        while let Some(changed) = watcher.changed().await {
+            let files = basic::files(&input, &cli.parser)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|f| changed.contains(f))
+                .collect::<Vec<_>>();
+            if !files.is_empty() {
+                drop(run(files.into(), writer(), rerun_cli.clone()).await);
+            }
+        }
+    }
+}
+
+impl<W, I, P, Wr, F, B, A, S, Cli>
+    Cucumber<W, P, I, runner::Basic<W, F, B, A, S>, Wr, Cli>
 where
     W: World,
     P: Parser<I>,
@@ -913,6 +1806,7 @@ where
             Option<&'a mut W>,
         ) -> LocalBoxFuture<'a, ()>
         + 'static,
+    S: runner::basic::SpawnScenarios<W, B, A>,
 {
     /// If `max` is [`Some`] number of concurrently executed [`Scenario`]s will
     /// be limited.
@@ -927,6 +1821,26 @@ where
         self
     }
 
+    /// Sets the [`runner::Order`] of scheduling [`Feature`]s for running
+    /// ([`runner::Order::AsDiscovered`] by default).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn feature_order(mut self, order: runner::Order) -> Self {
+        self.runner = self.runner.feature_order(order);
+        self
+    }
+
+    /// Sets the [`runner::Timings`] of [`Scenario`]s from a previous run, so
+    /// the longest ones are scheduled first.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn timings(mut self, timings: runner::Timings) -> Self {
+        self.runner = self.runner.timings(timings);
+        self
+    }
+
     /// Makes failed [`Scenario`]s being retried the specified number of times.
     ///
     /// [`Scenario`]: gherkin::Scenario
@@ -951,47 +1865,257 @@ where
         self
     }
 
-    /// Makes failed [`Scenario`]s being retried after the specified
-    /// [`Duration`] passes.
+    /// Makes the most specific [`Step`] (with the longest literal prefix of its
+    /// [`Regex`]) win in case a [`gherkin::Step`] matches multiple ones,
+    /// instead of failing with an [`AmbiguousMatchError`].
+    ///
+    /// [`AmbiguousMatchError`]: step::AmbiguousMatchError
+    /// [`Step`]: step::Step
+    #[must_use]
+    pub fn most_specific_step_wins(mut self) -> Self {
+        self.runner = self.runner.most_specific_step_wins();
+        self
+    }
+
+    /// Makes keywords of [`Step`]s being strictly honored, so `*` bullet
+    /// [`Step`]s inherit the keyword of the preceding [`Step`], and [`Step`]s
+    /// matching only [`Step`] functions of another keyword fail with an
+    /// [`event::StepError::KeywordMismatch`] (instead of being skipped).
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn strict_keywords(mut self) -> Self {
+        self.runner = self.runner.strict_keywords();
+        self
+    }
+
+    /// Sets the custom CLI options (like [`cli::Opts::custom`]) to create
+    /// [`World`]s with via [`World::from_cli()`] instead of [`World::new()`].
+    ///
+    /// This way [`World`]s, and so hooks and [`Step`]s, may be configured from
+    /// command line flags without any global statics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{cli, World};
+    /// #
+    /// #[derive(cli::Args, Clone)]
+    /// struct CustomCli {
+    ///     /// URL of the tested application.
+    ///     #[arg(long, default_value = "http://localhost")]
+    ///     target: String,
+    /// }
+    ///
+    /// #[derive(Debug, Default, World)]
+    /// #[world(from_cli = Self::from_custom_cli)]
+    /// struct MyWorld {
+    ///     target: String,
+    /// }
+    ///
+    /// impl MyWorld {
+    ///     fn from_custom_cli(cli: &CustomCli) -> Self {
+    ///         Self {
+    ///             target: cli.target.clone(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let opts = cli::Opts::<_, _, _, CustomCli>::parsed();
+    ///
+    /// MyWorld::cucumber()
+    ///     .custom_cli(opts.custom.clone())
+    ///     .with_cli(opts)
+    ///     .run_and_exit("tests/features/readme")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn custom_cli<C>(mut self, cli: C) -> Self
+    where
+        C: Any + Send + Sync,
+    {
+        self.runner = self.runner.custom_cli(cli);
+        self
+    }
+
+    /// Sets the root directory for [`Scenario`]s' artifacts, available in
+    /// [`Step`]s via [`step::Context::artifact_dir()`].
     ///
     /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn retry_after(mut self, after: impl Into<Option<Duration>>) -> Self {
-        self.runner = self.runner.retry_after(after);
+    pub fn artifacts_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.runner = self.runner.artifacts_dir(dir);
         self
     }
 
-    /// Makes failed [`Scenario`]s being retried only if they're matching the
-    /// specified `tag_expression`.
+    /// Sets the [`artifacts::Retention`] policy of [`Scenario`]s' artifacts.
     ///
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn retry_filter(
-        mut self,
-        tag_expression: impl Into<Option<TagOperation>>,
-    ) -> Self {
-        self.runner = self.runner.retry_filter(tag_expression);
+    pub fn keep_artifacts(mut self, retention: artifacts::Retention) -> Self {
+        self.runner = self.runner.keep_artifacts(retention);
         self
     }
 
-    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
-    /// a [`Serial`] one.
+    /// Sets the [`BudgetPolicy`] of [`Scenario`]s exceeding their time budget
+    /// (set via a `@budget(<duration>)` tag), [`BudgetPolicy::Fail`] by
+    /// default.
     ///
-    /// [`Concurrent`]: ScenarioType::Concurrent
-    /// [`Serial`]: ScenarioType::Serial
+    /// [`BudgetPolicy`]: runner::BudgetPolicy
+    /// [`BudgetPolicy::Fail`]: runner::BudgetPolicy::Fail
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn which_scenario<Which>(
+    pub fn budget_policy(mut self, policy: runner::BudgetPolicy) -> Self {
+        self.runner = self.runner.budget_policy(policy);
+        self
+    }
+
+    /// Makes passed work-in-progress [`Scenario`]s (marked with a `@wip` tag
+    /// or using [`Step`] functions marked with a `#[wip]` attribute) fail the
+    /// run, the same way a `--wip` CLI option does.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: crate::Step
+    #[must_use]
+    pub fn strict_wip(mut self) -> Self {
+        self.runner = self.runner.strict_wip();
+        self
+    }
+
+    /// Makes a watchdog reporting [`Step`]s making no progress for longer than
+    /// the provided `timeout` as [stalled], so hung [`Scenario`]s don't stall
+    /// silently.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [stalled]: event::Scenario::Stalled
+    #[must_use]
+    pub fn watchdog(mut self, timeout: Duration) -> Self {
+        self.runner = self.runner.watchdog(timeout);
+        self
+    }
+
+    /// Limits the whole execution to the provided `timeout`, after which no
+    /// more [`Scenario`]s are started, the running ones are interrupted (still
+    /// running their [`after`] hooks and teardowns), and the remaining ones
+    /// are reported as [not run], finishing the execution properly, so the
+    /// [`Writer`]s output complete reports.
+    ///
+    /// See [`runner::Basic::global_timeout()`] for more details.
+    ///
+    /// [`after`]: Self::after
+    /// [`Scenario`]: gherkin::Scenario
+    /// [not run]: event::Scenario::NotRun
+    #[must_use]
+    pub fn global_timeout(mut self, timeout: Duration) -> Self {
+        self.runner = self.runner.global_timeout(timeout);
+        self
+    }
+
+    /// Makes [stalled] [`Step`]s being reported along with the
+    /// [`WorldSnapshot`] of their [`World`] taken right before they've been
+    /// started (if the [`World`] provides one).
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    /// [stalled]: event::Scenario::Stalled
+    #[must_use]
+    pub fn dump_stalled_world(mut self, dump: bool) -> Self {
+        self.runner = self.runner.dump_stalled_world(dump);
+        self
+    }
+
+    /// Makes stdout/stderr output of [`Step`]s being captured, so it's shown
+    /// only for failed [`Step`]s (unless `--show-output` CLI option is
+    /// specified).
+    ///
+    /// __NOTE__: Output is captured on the file descriptors level, so includes
+    ///           output of other threads too, while a [`Step`] is being
+    ///           polled. That's why [`Scenario`]s are run one-by-one on the
+    ///           current thread while capturing (ignoring concurrency and
+    ///           [`Cucumber::spawn_blocking_scenarios()`]-like options).
+    ///           Supported on Unix platforms only, being a no-op (not
+    ///           affecting the concurrency) on the other ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn capture_output(mut self) -> Self {
+        self.runner = self.runner.capture_output();
+        self
+    }
+
+    /// Makes [`Feature`]s having duplicate names across the whole run, and
+    /// [`Scenario`]s having duplicate names inside a single [`Feature`], being
+    /// reported (as warnings, not failing the run), since report consumers
+    /// keying on names (like JUnit or Cucumber JSON ones) silently merge their
+    /// results otherwise.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn lint_duplicate_names(mut self) -> Self {
+        self.runner = self.runner.lint_duplicate_names();
+        self
+    }
+
+    /// Sets the maximum number of events buffered before being handled by the
+    /// [`Writer`], so a slow [`Writer`] pauses execution of [`Scenario`]s
+    /// instead of letting events pile up in memory.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn max_buffered_events(mut self, max: usize) -> Self {
+        self.runner = self.runner.max_buffered_events(max);
+        self
+    }
+
+    /// Sets the [`PanicHookStrategy`] to apply while running [`Scenario`]s
+    /// ([`PanicHookStrategy::Suppress`] by default).
+    ///
+    /// [`PanicHookStrategy`]: runner::PanicHookStrategy
+    /// [`PanicHookStrategy::Suppress`]: runner::PanicHookStrategy::Suppress
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn panic_hook(mut self, strategy: runner::PanicHookStrategy) -> Self {
+        self.runner = self.runner.panic_hook(strategy);
+        self
+    }
+
+    /// Makes each [`Scenario`] run on its own dedicated thread with its own
+    /// lightweight executor, so [`World`]s using blocking clients don't stall
+    /// the other [`Scenario`]s.
+    ///
+    /// Only the code provided by a user ([`World`] creation, [`Step`]s, the
+    /// [`before`] and [`after`] hooks) is run on the dedicated threads, so the
+    /// [`World`] should be [`Send`], and the hooks should be [`Send`] and
+    /// [`Sync`].
+    ///
+    /// [`after`]: Self::after()
+    /// [`before`]: Self::before()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn spawn_blocking_scenarios(
         self,
-        func: Which,
-    ) -> Cucumber<W, P, I, runner::Basic<W, Which, B, A>, Wr, Cli>
+    ) -> Cucumber<
+        W,
+        P,
+        I,
+        runner::Basic<W, F, B, A, runner::basic::BlockingScenarios>,
+        Wr,
+        Cli,
+    >
     where
-        Which: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-            ) -> ScenarioType
-            + 'static,
+        W: Send,
+        B: Send + Sync,
+        A: Send + Sync,
     {
         let Self {
             parser,
@@ -1002,18 +2126,167 @@ where
         } = self;
         Cucumber {
             parser,
-            runner: runner.which_scenario(func),
+            runner: runner.spawn_blocking_scenarios(),
             writer,
             cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 
-    /// Function determining [`Scenario`]'s [`RetryOptions`].
+    /// Makes each [`Scenario`] run as a task on a pool of threads (one per
+    /// runtime worker) driven by the current multi-threaded [`tokio`] runtime,
+    /// so CPU-bound [`Scenario`]s utilize multiple cores.
     ///
-    /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
+    /// Only the code provided by a user ([`World`] creation, [`Step`]s, the
+    /// [`before`] and [`after`] hooks) is run on the [`tokio`] threads, so the
+    /// [`World`] should be [`Send`], and the hooks should be [`Send`] and
+    /// [`Sync`].
+    ///
+    /// __NOTE__: [`Cucumber::run()`] must be polled inside a multi-threaded
+    ///           [`tokio`] runtime, otherwise panics.
+    ///
+    /// [`after`]: Self::after()
+    /// [`before`]: Self::before()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn spawn_tokio_scenarios(
+        self,
+    ) -> Cucumber<
+        W,
+        P,
+        I,
+        runner::Basic<W, F, B, A, runner::basic::TokioScenarios>,
+        Wr,
+        Cli,
+    >
+    where
+        W: Send,
+        B: Send + Sync,
+        A: Send + Sync,
+    {
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            ..
+        } = self;
+        Cucumber {
+            parser,
+            runner: runner.spawn_tokio_scenarios(),
+            writer,
+            cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Sets the [`HookFailurePolicy`] of the [`before`] hook
+    /// ([`HookFailurePolicy::FailScenario`] by default).
+    ///
+    /// [`before`]: Self::before()
+    /// [`HookFailurePolicy`]: runner::HookFailurePolicy
+    /// [`HookFailurePolicy::FailScenario`]: runner::HookFailurePolicy::FailScenario
+    #[must_use]
+    pub fn before_hook_failure(
+        mut self,
+        policy: runner::HookFailurePolicy,
+    ) -> Self {
+        self.runner = self.runner.before_hook_failure(policy);
+        self
+    }
+
+    /// Sets the [`HookFailurePolicy`] of the [`after`] hook
+    /// ([`HookFailurePolicy::FailScenario`] by default).
+    ///
+    /// Use [`HookFailurePolicy::Ignore`] to make [`after`] hook failures
+    /// non-fatal.
+    ///
+    /// [`after`]: Self::after()
+    /// [`HookFailurePolicy`]: runner::HookFailurePolicy
+    /// [`HookFailurePolicy::FailScenario`]: runner::HookFailurePolicy::FailScenario
+    /// [`HookFailurePolicy::Ignore`]: runner::HookFailurePolicy::Ignore
+    #[must_use]
+    pub fn after_hook_failure(
+        mut self,
+        policy: runner::HookFailurePolicy,
+    ) -> Self {
+        self.runner = self.runner.after_hook_failure(policy);
+        self
+    }
+
+    /// Makes failed [`Scenario`]s being retried after the specified
+    /// [`Duration`] passes.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_after(mut self, after: impl Into<Option<Duration>>) -> Self {
+        self.runner = self.runner.retry_after(after);
+        self
+    }
+
+    /// Makes failed [`Scenario`]s being retried only if they're matching the
+    /// specified `tag_expression`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_filter(
+        mut self,
+        tag_expression: Option<impl Into<tag::Expr>>,
+    ) -> Self {
+        self.runner = self.runner.retry_filter(tag_expression);
+        self
+    }
+
+    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
+    /// a [`Serial`] one.
+    ///
+    /// [`Concurrent`]: ScenarioType::Concurrent
+    /// [`Serial`]: ScenarioType::Serial
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn which_scenario<Which>(
+        self,
+        func: Which,
+    ) -> Cucumber<W, P, I, runner::Basic<W, Which, B, A, S>, Wr, Cli>
+    where
+        Which: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> ScenarioType
+            + 'static,
+    {
+        let Self {
+            parser,
+            runner,
+            writer,
+            cli,
+            ..
+        } = self;
+        Cucumber {
+            parser,
+            runner: runner.which_scenario(func),
+            writer,
+            cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
+    /// Function determining [`Scenario`]'s [`RetryOptions`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
     pub fn retry_options<Retry>(mut self, func: Retry) -> Self
     where
         Retry: Fn(
@@ -1038,7 +2311,7 @@ where
     pub fn before<Before>(
         self,
         func: Before,
-    ) -> Cucumber<W, P, I, runner::Basic<W, F, Before, A>, Wr, Cli>
+    ) -> Cucumber<W, P, I, runner::Basic<W, F, Before, A, S>, Wr, Cli>
     where
         Before: for<'a> Fn(
                 &'a gherkin::Feature,
@@ -1047,6 +2320,7 @@ where
                 &'a mut W,
             ) -> LocalBoxFuture<'a, ()>
             + 'static,
+        S: runner::basic::SpawnScenarios<W, Before, A>,
     {
         let Self {
             parser,
@@ -1060,6 +2334,8 @@ where
             runner: runner.before(func),
             writer,
             cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
@@ -1080,7 +2356,7 @@ where
     pub fn after<After>(
         self,
         func: After,
-    ) -> Cucumber<W, P, I, runner::Basic<W, F, B, After>, Wr, Cli>
+    ) -> Cucumber<W, P, I, runner::Basic<W, F, B, After, S>, Wr, Cli>
     where
         After: for<'a> Fn(
                 &'a gherkin::Feature,
@@ -1090,6 +2366,7 @@ where
                 Option<&'a mut W>,
             ) -> LocalBoxFuture<'a, ()>
             + 'static,
+        S: runner::basic::SpawnScenarios<W, B, After>,
     {
         let Self {
             parser,
@@ -1103,11 +2380,105 @@ where
             runner: runner.after(func),
             writer,
             cli,
+            exit_codes: self.exit_codes,
+            secrets: self.secrets,
             _world: PhantomData,
             _parser_input: PhantomData,
         }
     }
 
+    /// Sets a hook, executed once before running any [`Scenario`], filling
+    /// the run-scoped [`shared::State`] accessible in [`Step`]s via
+    /// [`step::Context::shared`].
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before_all<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(&'a mut shared::State) -> LocalBoxFuture<'a, ()>
+            + 'static,
+    {
+        self.runner = self.runner.before_all(func);
+        self
+    }
+
+    /// Sets a hook, executed once for each [`Feature`] before running any of
+    /// its [`Scenario`]s, filling the [`Feature`]-scoped [`shared::State`]
+    /// accessible in [`Step`]s via [`step::Context::shared`].
+    ///
+    /// The run-scoped [`shared::State`] (filled by [`before_all`] hook) is
+    /// provided for reading.
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`before_all`]: Self::before_all()
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before_feature<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(
+                &'a gherkin::Feature,
+                &'a shared::State,
+                &'a mut shared::State,
+            ) -> LocalBoxFuture<'a, ()>
+            + 'static,
+    {
+        self.runner = self.runner.before_feature(func);
+        self
+    }
+
+    /// Sets a hook, executed on each failed [`Step`] (before the [`after`]
+    /// hook), producing [`Artifact`]s (like browser screenshots) to be
+    /// attached to the failed [`Step`] as [`event::Scenario::Artifact`]s.
+    ///
+    /// The hook isn't executed if artifacts aren't kept at all (see
+    /// [`artifacts::Retention::None`]), or there is no [`World`] at the time
+    /// of the failure.
+    ///
+    /// This is the only hook executed after a [`Step`], so it's also the one
+    /// seeing the [`artifacts::FailureInfo::prior_steps`] (including the
+    /// failed [`Step`] itself).
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`after`]: Self::after()
+    /// [`Artifact`]: artifacts::Artifact
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn on_step_failed<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(
+                &'a W,
+                &'a artifacts::FailureInfo,
+            )
+                -> LocalBoxFuture<'a, Vec<artifacts::Artifact>>
+            + 'static,
+    {
+        self.runner = self.runner.on_step_failed(func);
+        self
+    }
+
+    /// Registers the provided [`Fixture`], to be set up for (and torn down
+    /// after) every [`Scenario`] marked with its `@fixture(<name>)` tag.
+    ///
+    /// See the [`fixtures`] module for more details.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn fixture<Fx>(mut self, fixture: Fx) -> Self
+    where
+        Fx: fixtures::Fixture<W> + Send + Sync + 'static,
+    {
+        self.runner = self.runner.fixture(fixture);
+        self
+    }
+
     /// Replaces [`Collection`] of [`Step`]s.
     ///
     /// [`Collection`]: step::Collection
@@ -1154,6 +2525,183 @@ where
     Wr: writer::Stats<W> + writer::Normalized,
     Cli: clap::Args,
 {
+    /// Runs [`Cucumber`] and returns the [`RunResult`] of the execution,
+    /// without panicking or terminating the process on failures.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let result = MyWorld::cucumber()
+    ///     .run_with_result("tests/features/readme")
+    ///     .await;
+    ///
+    /// assert!(!result.execution_has_failed());
+    /// assert!(result.failed_scenarios.is_empty());
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub async fn run_with_result(self, input: I) -> RunResult {
+        self.filter_run_with_result(input, |_, _, _| true).await
+    }
+
+    /// Runs [`Cucumber`] with [`Scenario`]s filter and returns the
+    /// [`RunResult`] of the execution, without panicking or terminating the
+    /// process on failures.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub async fn filter_run_with_result<F>(
+        self,
+        input: I,
+        filter: F,
+    ) -> RunResult
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + 'static,
+    {
+        let started = Instant::now();
+
+        let (writer, failed_scenarios) =
+            self.filter_run_and_collect(input, filter).await;
+
+        RunResult {
+            steps: writer::summarize::Stats {
+                passed: writer.passed_steps(),
+                skipped: writer.skipped_steps(),
+                failed: writer.failed_steps(),
+                retried: writer.retried_steps(),
+            },
+            scenarios: writer::summarize::Stats {
+                passed: writer.passed_scenarios(),
+                skipped: writer.skipped_scenarios(),
+                failed: writer.failed_scenarios(),
+                retried: writer.flaky_scenarios(),
+            },
+            parsing_errors: writer.parsing_errors(),
+            hook_errors: writer.hook_errors(),
+            budget_errors: writer.budget_errors(),
+            wip_errors: writer.wip_errors(),
+            timed_out: writer.timed_out(),
+            failed_scenarios,
+            duration: started.elapsed(),
+        }
+    }
+
+    /// Runs [`Cucumber`] and returns its [`Writer`] (to inspect [`Stats`]
+    /// from) along with all the [`FailedScenario`]s, without panicking or
+    /// terminating the process on failures.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{StatsWriter as _, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let (writer, failed) = MyWorld::cucumber()
+    ///     .run_and_collect("tests/features/readme")
+    ///     .await;
+    ///
+    /// assert!(!writer.execution_has_failed());
+    /// for f in failed {
+    ///     eprintln!("{}: {}", f.scenario.name, f.error);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Stats`]: writer::Stats
+    pub async fn run_and_collect(self, input: I) -> (Wr, Vec<FailedScenario>) {
+        self.filter_run_and_collect(input, |_, _, _| true).await
+    }
+
+    /// Runs [`Cucumber`] with [`Scenario`]s filter and returns its [`Writer`]
+    /// (to inspect [`Stats`] from) along with all the [`FailedScenario`]s,
+    /// without panicking or terminating the process on failures.
+    ///
+    /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
+    /// produces events handled by a [`Writer`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Stats`]: writer::Stats
+    pub async fn filter_run_and_collect<F>(
+        self,
+        input: I,
+        filter: F,
+    ) -> (Wr, Vec<FailedScenario>)
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + 'static,
+    {
+        let mut failed_scenarios = Vec::<FailedScenario>::new();
+        let mut failed_paths = HashSet::new();
+        let mut wip_scenarios = Vec::new();
+
+        let writer = self
+            .filter_run_inspected(input, sync_filter(filter), |ev| {
+                if let Some((f, r, sc, ev)) = scenario_event(ev) {
+                    if matches!(
+                        ev.event,
+                        event::Scenario::Wip(event::Wip::Marked),
+                    ) {
+                        wip_scenarios.push((f.clone(), r.cloned(), sc.clone()));
+                    }
+                }
+                if let Some(failed) = FailedScenario::from_event(ev) {
+                    // Failed `Step`s of work-in-progress `Scenario`s are
+                    // expected, so don't fail the execution.
+                    let is_wip =
+                        matches!(failed.error, ScenarioFailure::Step(_))
+                            && wip_scenarios.iter().any(|(f, r, sc)| {
+                                failed.feature == *f
+                                    && failed.rule == *r
+                                    && failed.scenario == *sc
+                            });
+                    if is_wip {
+                        return;
+                    }
+                    // Only the first failure of a `Scenario` is collected (a
+                    // failed `after` hook may follow a failed `Step`, for
+                    // example).
+                    if failed_paths.insert(failed.path()) {
+                        failed_scenarios.push(failed);
+                    }
+                }
+            })
+            .await;
+
+        (writer, failed_scenarios)
+    }
+
     /// Runs [`Cucumber`].
     ///
     /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
@@ -1162,7 +2710,9 @@ where
     /// # Panics
     ///
     /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// [`Step`] [`Failed`], unless [`Cucumber::exit_codes()`] are specified, in
+    /// which case the process is terminated with the corresponding exit code
+    /// instead.
     ///
     /// [`Failed`]: event::Step::Failed
     /// [`Feature`]: gherkin::Feature
@@ -1179,7 +2729,9 @@ where
     /// # Panics
     ///
     /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// [`Step`] [`Failed`], unless [`Cucumber::exit_codes()`] are specified, in
+    /// which case the process is terminated with the corresponding exit code
+    /// instead.
     ///
     /// # Example
     ///
@@ -1232,6 +2784,17 @@ where
             ) -> bool
             + 'static,
     {
+        if let Some(codes) = self.exit_codes {
+            let code = self
+                .filter_run_with_result(input, filter)
+                .await
+                .exit_code(codes);
+            if code != 0 {
+                process::exit(code);
+            }
+            return;
+        }
+
         let writer = self.filter_run(input, filter).await;
         if writer.execution_has_failed() {
             let mut msg = Vec::with_capacity(3);
@@ -1260,7 +2823,459 @@ where
                 ));
             }
 
+            let budget_errors = writer.budget_errors();
+            if budget_errors > 0 {
+                msg.push(format!(
+                    "{budget_errors} exceeded budget{}",
+                    if budget_errors > 1 { "s" } else { "" },
+                ));
+            }
+
+            let wip_errors = writer.wip_errors();
+            if wip_errors > 0 {
+                msg.push(format!(
+                    "{wip_errors} work-in-progress scenario{} passed",
+                    if wip_errors > 1 { "s" } else { "" },
+                ));
+            }
+
+            if writer.timed_out() {
+                msg.push("execution timed out".to_owned());
+            }
+
             panic!("{}", msg.join(", "));
         }
     }
 }
+
+/// Exit codes policy of [`Cucumber::run_and_exit()`], allowing to distinguish
+/// different kinds of failures (like infrastructure problems from real test
+/// failures) by the exit code of the process.
+///
+/// If several kinds of failures happened, then the exit code of the first one
+/// in the order of the fields declaration (having a non-zero code) is used.
+///
+/// Set via [`Cucumber::exit_codes()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExitCodes {
+    /// Exit code on [`Parser`] errors.
+    ///
+    /// `1` by default.
+    pub parsing_errors: i32,
+
+    /// Exit code on failed [`Scenario`] hooks.
+    ///
+    /// `1` by default.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub hook_errors: i32,
+
+    /// Exit code on [`Failed`] [`Step`]s (and [`Scenario`]s passing while
+    /// being work-in-progress), unless categorized as
+    /// [`FailureCategory::Timeout`] or [`FailureCategory::Infrastructure`].
+    ///
+    /// `1` by default.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`FailureCategory::Infrastructure`]: event::FailureCategory::Infrastructure
+    /// [`FailureCategory::Timeout`]: event::FailureCategory::Timeout
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub step_failures: i32,
+
+    /// Exit code on [`Failed`] [`Step`]s categorized as
+    /// [`FailureCategory::Timeout`] (and [`Scenario`]s exceeding their time
+    /// budget, or the whole execution exceeding its global timeout).
+    ///
+    /// `1` by default.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`FailureCategory::Timeout`]: event::FailureCategory::Timeout
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub timeouts: i32,
+
+    /// Exit code on [`Failed`] [`Step`]s categorized as
+    /// [`FailureCategory::Infrastructure`].
+    ///
+    /// `1` by default.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`FailureCategory::Infrastructure`]: event::FailureCategory::Infrastructure
+    /// [`Step`]: gherkin::Step
+    pub infrastructure_failures: i32,
+
+    /// Exit code on undefined [`Step`]s (ones not matching any [`Regex`], and
+    /// so being [`Skipped`]).
+    ///
+    /// `0` by default (undefined [`Step`]s don't fail the execution, unless
+    /// [`Cucumber::fail_on_skipped()`] is used, making them [`Failed`]).
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    pub undefined_steps: i32,
+}
+
+impl Default for ExitCodes {
+    fn default() -> Self {
+        Self {
+            parsing_errors: 1,
+            hook_errors: 1,
+            step_failures: 1,
+            timeouts: 1,
+            infrastructure_failures: 1,
+            undefined_steps: 0,
+        }
+    }
+}
+
+/// Result of a [`Cucumber`] execution.
+///
+/// Returned by [`Cucumber::run_with_result()`] and
+/// [`Cucumber::filter_run_with_result()`].
+///
+/// [`Stats`]: writer::summarize::Stats
+#[derive(Clone, Debug)]
+pub struct RunResult {
+    /// [`Step`]s [`Stats`] of the execution.
+    ///
+    /// The `skipped` number counts all the [`Skipped`] [`Step`]s: the ones not
+    /// matching any [`Regex`], and the first [`Step`]s of the [`Scenario`]s
+    /// skipped as a whole (by `@skip`/`@ignore` tags, or by a failed
+    /// [`before`] hook with [`HookFailurePolicy::SkipScenario`]), along with
+    /// the failed [`Step`]s of work-in-progress [`Scenario`]s. The [`Step`]s
+    /// following a failed or skipped one aren't run, so aren't counted at all.
+    ///
+    /// [`before`]: Cucumber::before()
+    /// [`HookFailurePolicy::SkipScenario`]: runner::HookFailurePolicy::SkipScenario
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Stats`]: writer::summarize::Stats
+    /// [`Step`]: gherkin::Step
+    pub steps: writer::summarize::Stats,
+
+    /// [`Scenario`]s [`Stats`] of the execution.
+    ///
+    /// The `retried` number represents the flaky [`Scenario`]s, which have
+    /// passed only after being retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Stats`]: writer::summarize::Stats
+    pub scenarios: writer::summarize::Stats,
+
+    /// Number of [`Parser`] errors.
+    pub parsing_errors: usize,
+
+    /// Number of failed [`Scenario`] hooks.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub hook_errors: usize,
+
+    /// Number of [`Scenario`]s failed due to exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub budget_errors: usize,
+
+    /// Number of work-in-progress [`Scenario`]s failed due to passing in a
+    /// strict mode.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub wip_errors: usize,
+
+    /// Indicator whether the execution has been stopped due to exceeding its
+    /// [global timeout][1].
+    ///
+    /// [1]: Cucumber::global_timeout()
+    pub timed_out: bool,
+
+    /// [`FailedScenario`]s in the order they've failed.
+    ///
+    /// Retried [`Scenario`]s are listed only if they've failed after all the
+    /// retry attempts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub failed_scenarios: Vec<FailedScenario>,
+
+    /// Wall-clock [`Duration`] of the whole execution.
+    pub duration: Duration,
+}
+
+impl RunResult {
+    /// Indicates whether there were failures/errors during the execution.
+    #[must_use]
+    pub const fn execution_has_failed(&self) -> bool {
+        self.steps.failed > 0
+            || self.scenarios.failed > 0
+            || self.parsing_errors > 0
+            || self.hook_errors > 0
+            || self.budget_errors > 0
+            || self.wip_errors > 0
+            || self.timed_out
+    }
+
+    /// Returns the exit code for this [`RunResult`] according to the provided
+    /// [`ExitCodes`] policy.
+    ///
+    /// Returns `0` if nothing has failed.
+    #[must_use]
+    pub fn exit_code(&self, codes: ExitCodes) -> i32 {
+        use event::FailureCategory as Category;
+
+        let failed = |category| {
+            self.failed_scenarios
+                .iter()
+                .filter(|f| f.error.category() == Some(category))
+                .count()
+        };
+        [
+            (self.parsing_errors, codes.parsing_errors),
+            (self.hook_errors, codes.hook_errors),
+            (
+                failed(Category::Assertion) + failed(Category::Undefined),
+                codes.step_failures,
+            ),
+            (
+                failed(Category::Timeout) + usize::from(self.timed_out),
+                codes.timeouts,
+            ),
+            (
+                failed(Category::Infrastructure),
+                codes.infrastructure_failures,
+            ),
+            // Failures not collected into `failed_scenarios`, if any.
+            (self.steps.failed, codes.step_failures),
+            (self.budget_errors, codes.timeouts),
+            (self.wip_errors, codes.step_failures),
+            (self.scenarios.failed, codes.step_failures),
+            (self.steps.skipped, codes.undefined_steps),
+        ]
+        .into_iter()
+        .find_map(|(n, code)| (n > 0 && code != 0).then_some(code))
+        .unwrap_or_default()
+    }
+}
+
+/// [`Scenario`] failed during a [`Cucumber`] execution.
+///
+/// Returned by [`Cucumber::run_and_collect()`] and
+/// [`Cucumber::filter_run_and_collect()`], and as a part of a [`RunResult`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub struct FailedScenario {
+    /// [`Feature`] containing the failed [`Scenario`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub feature: event::Source<gherkin::Feature>,
+
+    /// [`Rule`] containing the failed [`Scenario`], if any.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub rule: Option<event::Source<gherkin::Rule>>,
+
+    /// Failed [`Scenario`] itself.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub scenario: event::Source<gherkin::Scenario>,
+
+    /// Failed [`Step`] of the [`Scenario`] (or of its [`Background`]), if the
+    /// [`Scenario`] hasn't failed in a hook.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub step: Option<event::Source<gherkin::Step>>,
+
+    /// [`ScenarioFailure`] the [`Scenario`] has failed with.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub error: ScenarioFailure,
+
+    /// [`Retries`] of the failed [`Scenario`] attempt, if it was retried.
+    ///
+    /// [`Retries`]: event::Retries
+    /// [`Scenario`]: gherkin::Scenario
+    pub retries: Option<event::Retries>,
+}
+
+/// Error a [`FailedScenario`] has failed with.
+#[derive(Clone, Debug, Display)]
+pub enum ScenarioFailure {
+    /// [`Step`] has failed.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[display("{_0}")]
+    Step(event::StepError),
+
+    /// [`Scenario`] hook has failed.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("{_0} hook failed: {}", coerce_error(_1))]
+    Hook(event::HookType, event::Info),
+
+    /// [`Scenario`] has exceeded its time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("{_0}")]
+    Budget(event::Budget),
+
+    /// Work-in-progress [`Scenario`] has passed in a strict mode.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("{_0}")]
+    Wip(event::Wip),
+}
+
+impl ScenarioFailure {
+    /// Returns the [`event::FailureCategory`] of this [`ScenarioFailure`], if
+    /// it's not a hook one.
+    ///
+    /// Exceeded time budget is considered as an
+    /// [`event::FailureCategory::Timeout`], while a passed work-in-progress
+    /// [`Scenario`] as an [`event::FailureCategory::Assertion`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn category(&self) -> Option<event::FailureCategory> {
+        match self {
+            Self::Step(e) => Some(e.category()),
+            Self::Hook(..) => None,
+            Self::Budget(_) => Some(event::FailureCategory::Timeout),
+            Self::Wip(_) => Some(event::FailureCategory::Assertion),
+        }
+    }
+}
+
+impl FailedScenario {
+    /// Extracts a [`FailedScenario`] from the provided event, if it
+    /// represents a final (not going to be retried) failure of a [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn from_event<W>(
+        ev: &parser::Result<Event<event::Cucumber<W>>>,
+    ) -> Option<Self> {
+        use event::{Hook, Scenario, Step, StepError};
+
+        let (feature, rule, scenario, ev) = scenario_event(ev)?;
+
+        let (step, error) = match &ev.event {
+            Scenario::Hook(ty, Hook::Failed(_, info)) => {
+                // A `Scenario` with a failed hook is retried too.
+                if ev.retries.is_some_and(|r| r.left > 0) {
+                    return None;
+                }
+                (None, ScenarioFailure::Hook(*ty, Arc::clone(info)))
+            }
+            Scenario::Background(st, Step::Failed(_, _, _, err, _))
+            | Scenario::RuleBackground(st, Step::Failed(_, _, _, err, _))
+            | Scenario::Step(st, Step::Failed(_, _, _, err, _)) => {
+                // Mirrors the `writer::Summarize` logic: a `Step` failed with
+                // retries left is going to be retried, unless it's undefined.
+                let is_final = ev.retries.is_none_or(|r| r.left == 0)
+                    || matches!(err, StepError::NotFound);
+                if !is_final {
+                    return None;
+                }
+                (Some(st.clone()), ScenarioFailure::Step(err.clone()))
+            }
+            Scenario::BudgetExceeded(budget) => {
+                if !budget.failed || ev.retries.is_some_and(|r| r.left > 0) {
+                    return None;
+                }
+                (None, ScenarioFailure::Budget(*budget))
+            }
+            Scenario::Wip(wip @ event::Wip::Passed) => {
+                (None, ScenarioFailure::Wip(*wip))
+            }
+            Scenario::Started(_)
+            | Scenario::Wip(event::Wip::Marked)
+            | Scenario::Hook(
+                _,
+                Hook::Started | Hook::Passed | Hook::Ignored(_),
+            )
+            | Scenario::Background(
+                _,
+                Step::Started | Step::Skipped(_) | Step::Passed(..),
+            )
+            | Scenario::RuleBackground(
+                _,
+                Step::Started | Step::Skipped(_) | Step::Passed(..),
+            )
+            | Scenario::Step(
+                _,
+                Step::Started | Step::Skipped(_) | Step::Passed(..),
+            )
+            | Scenario::Log(_)
+            | Scenario::Artifact(_)
+            | Scenario::Output(_)
+            | Scenario::Stalled(_)
+            | Scenario::NotRun
+            | Scenario::Finished => return None,
+        };
+
+        Some(Self {
+            feature: feature.clone(),
+            rule: rule.cloned(),
+            scenario: scenario.clone(),
+            step,
+            error,
+            retries: ev.retries,
+        })
+    }
+
+    /// Returns the path of the failed [`Scenario`], identifying it.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn path(
+        &self,
+    ) -> (
+        event::Source<gherkin::Feature>,
+        Option<event::Source<gherkin::Rule>>,
+        event::Source<gherkin::Scenario>,
+    ) {
+        (
+            self.feature.clone(),
+            self.rule.clone(),
+            self.scenario.clone(),
+        )
+    }
+}
+
+/// Extracts the [`event::RetryableScenario`] along with its [`Scenario`] path
+/// from the provided event, if it represents one.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[expect(clippy::type_complexity, reason = "still readable")]
+fn scenario_event<W>(
+    ev: &parser::Result<Event<event::Cucumber<W>>>,
+) -> Option<(
+    &event::Source<gherkin::Feature>,
+    Option<&event::Source<gherkin::Rule>>,
+    &event::Source<gherkin::Scenario>,
+    &event::RetryableScenario<W>,
+)> {
+    use event::{Cucumber, Feature, Rule};
+
+    match ev.as_deref().ok()? {
+        Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+            Some((f, Some(r), sc, ev))
+        }
+        Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+            Some((f, None, sc, ev))
+        }
+        Cucumber::Started
+        | Cucumber::ParsingFinished { .. }
+        | Cucumber::DuplicateName(_)
+        | Cucumber::Finished { .. }
+        | Cucumber::Feature(
+            _,
+            Feature::Started
+            | Feature::Aborted
+            | Feature::Finished
+            | Feature::Rule(_, Rule::Started | Rule::Finished),
+        ) => None,
+    }
+}
@@ -23,16 +23,24 @@
 use std::time::SystemTime;
 use std::{
     any::Any,
+    backtrace::Backtrace,
+    fmt,
     hash::{Hash, Hasher},
+    panic,
+    path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
 use derive_more::with_trait::{
-    AsRef, Debug, Deref, DerefMut, Display, Error, From, Into,
+    AsRef, Debug, Deref, DerefMut, Display, Error, From, FromStr, Into,
 };
 use ref_cast::RefCast;
 
-use crate::{step, writer::basic::coerce_error};
+use crate::{
+    parser, step,
+    writer::{basic::coerce_error, PathStyle},
+};
 
 /// Alias for a [`catch_unwind()`] error.
 ///
@@ -153,6 +161,60 @@ impl Retries {
     }
 }
 
+/// ID of a [`Scenario`] attempt, identifying it deterministically.
+///
+/// It's derived from the [`Feature`]'s path (or its name, if there is no
+/// path), the [`Rule`] and [`Scenario`] positions, and the current retry
+/// attempt, so the same [`Scenario`] gets the same [`ScenarioId`] across
+/// different processes and runs (allowing external systems to correlate their
+/// artifacts with it).
+///
+/// **NOTE**: Retried [`Scenario`] has a different ID from a failed one.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Display, Eq, FromStr, Hash, PartialEq)]
+pub struct ScenarioId(pub(crate) u64);
+
+impl ScenarioId {
+    /// Computes the [`ScenarioId`] of the provided [`Scenario`] attempt.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn of(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        retries: Option<Retries>,
+    ) -> Self {
+        // FNV-1a is used, as its output is stable across platforms and Rust
+        // versions, unlike `std::hash::DefaultHasher`.
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let feature = feature.path.as_ref().map_or_else(
+            || feature.name.clone(),
+            |p| p.to_string_lossy().into_owned(),
+        );
+        let positions = [
+            rule.map_or(0, |r| r.position.line),
+            scenario.position.line,
+            scenario.position.col,
+            retries.map_or(0, |r| r.current),
+        ];
+
+        let id = feature
+            .bytes()
+            .chain(positions.into_iter().flat_map(|n| {
+                // Fixed width keeps IDs the same on 32-bit and 64-bit targets.
+                u64::try_from(n).unwrap_or(u64::MAX).to_le_bytes()
+            }))
+            .fold(OFFSET, |hash, b| (hash ^ u64::from(b)).wrapping_mul(PRIME));
+        Self(id)
+    }
+}
+
 /// Top-level [Cucumber] run event.
 ///
 /// [Cucumber]: https://cucumber.io
@@ -192,10 +254,60 @@ pub enum Cucumber<World> {
         ///
         /// [`Parser`]: crate::Parser
         parser_errors: usize,
+
+        /// Number of [`Scenario`]s filtered out from running by a
+        /// [`Cucumber::filter_run()`] (or similar) predicate.
+        ///
+        /// [`Cucumber::filter_run()`]: crate::Cucumber::filter_run
+        /// [`Scenario`]: gherkin::Scenario
+        filtered_scenarios: usize,
     },
 
+    /// Name shared by several [`Feature`]s or [`Scenario`]s has been detected
+    /// (see [`runner::Basic::lint_duplicate_names()`]).
+    ///
+    /// Emitted right before the [`Cucumber::ParsingFinished`] event.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`runner::Basic::lint_duplicate_names()`]: crate::runner::Basic::lint_duplicate_names
+    /// [`Scenario`]: gherkin::Scenario
+    DuplicateName(DuplicateName),
+
     /// [`Cucumber`] execution being finished.
-    Finished,
+    Finished {
+        /// Number of passed [`Scenario`]s.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        passed: usize,
+
+        /// Number of failed [`Scenario`]s.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        failed: usize,
+
+        /// Number of skipped [`Scenario`]s.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        skipped: usize,
+
+        /// Number of happened [`Scenario`]s' retries.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        retried: usize,
+
+        /// Indicator whether the execution has been stopped due to exceeding
+        /// its global timeout (see [`runner::Basic::global_timeout()`]), so the
+        /// running [`Scenario`]s have been interrupted, and the remaining ones
+        /// have been reported as [not run].
+        ///
+        /// [`runner::Basic::global_timeout()`]: crate::runner::Basic::global_timeout
+        /// [`Scenario`]: gherkin::Scenario
+        /// [not run]: Scenario::NotRun
+        timed_out: bool,
+
+        /// Wall time of the whole execution.
+        duration: Duration,
+    },
 }
 
 // Implemented manually to omit redundant `World: Clone` trait bound, imposed by
@@ -211,14 +323,31 @@ impl<World> Clone for Cucumber<World> {
                 scenarios,
                 steps,
                 parser_errors,
+                filtered_scenarios,
             } => Self::ParsingFinished {
                 features: *features,
                 rules: *rules,
                 scenarios: *scenarios,
                 steps: *steps,
                 parser_errors: *parser_errors,
+                filtered_scenarios: *filtered_scenarios,
+            },
+            Self::DuplicateName(dup) => Self::DuplicateName(dup.clone()),
+            Self::Finished {
+                passed,
+                failed,
+                skipped,
+                retried,
+                duration,
+                timed_out,
+            } => Self::Finished {
+                passed: *passed,
+                failed: *failed,
+                skipped: *skipped,
+                retried: *retried,
+                duration: *duration,
+                timed_out: *timed_out,
             },
-            Self::Finished => Self::Finished,
         }
     }
 }
@@ -251,6 +380,14 @@ impl<World> Cucumber<World> {
         Self::Feature(feat.into(), Feature::Finished)
     }
 
+    /// Constructs an event of a [`Feature`] being aborted.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn feature_aborted(feat: impl Into<Source<gherkin::Feature>>) -> Self {
+        Self::Feature(feat.into(), Feature::Aborted)
+    }
+
     /// Constructs an event of a [`Rule`] being finished.
     ///
     /// [`Rule`]: gherkin::Rule
@@ -281,6 +418,180 @@ impl<World> Cucumber<World> {
     }
 }
 
+/// Structured failure of parsing a [`Feature`], describing a [`parser::Error`]
+/// delivered to [`Writer`]s instead of a [`Cucumber`] event.
+///
+/// Allows [`Writer`]s to attribute the failure to its exact location without
+/// inspecting the [`parser::Error`] details.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Writer`]: crate::Writer
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display(
+    "{}{message}",
+    self.location(PathStyle::Trimmed)
+        .map(|l| format!("{l}: "))
+        .unwrap_or_default(),
+)]
+pub struct ParsingFailure {
+    /// Path to the file failed to be parsed, if any.
+    pub path: Option<PathBuf>,
+
+    /// Position of the failure in the file, if known.
+    pub position: Option<gherkin::LineCol>,
+
+    /// Message describing the failure.
+    pub message: String,
+}
+
+impl ParsingFailure {
+    /// Formats the location of this [`ParsingFailure`] as
+    /// `{path}:{line}:{column}`, omitting its unknown parts, with the
+    /// [`ParsingFailure::path`] rendered in the provided [`PathStyle`].
+    ///
+    /// [`None`] is returned if neither [`ParsingFailure::path`] nor
+    /// [`ParsingFailure::position`] is known.
+    #[must_use]
+    pub fn location(&self, path_style: PathStyle) -> Option<String> {
+        let path = self
+            .path
+            .as_deref()
+            .and_then(|p| path_style.render(p))
+            .filter(|p| !p.is_empty());
+        let pos = self.position.map(|p| format!("{}:{}", p.line, p.col));
+        match (path, pos) {
+            (Some(path), Some(pos)) => Some(format!("{path}:{pos}")),
+            (Some(path), None) => Some(path.into_owned()),
+            (None, pos) => pos,
+        }
+    }
+}
+
+impl From<&parser::Error> for ParsingFailure {
+    fn from(err: &parser::Error) -> Self {
+        match err {
+            parser::Error::Parsing(e) => Self {
+                path: Some(e.path().to_path_buf()),
+                position: e.position(),
+                message: e.message(),
+            },
+            parser::Error::ExampleExpansion(e) => Self {
+                path: e.path.clone(),
+                position: Some(e.pos),
+                message: format!("failed to resolve <{}>", e.name),
+            },
+        }
+    }
+}
+
+/// Name shared by several [`Feature`]s of the whole run, or by several
+/// [`Scenario`]s of a single [`Feature`].
+///
+/// Report consumers keying on names (like [JUnit] or [Cucumber JSON] ones)
+/// silently merge results of such [`Feature`]s or [`Scenario`]s.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+/// [Cucumber JSON]: https://github.com/cucumber/cucumber-json-schema
+/// [JUnit]: https://llg.cubic.org/docs/junit
+#[derive(Clone, Debug)]
+pub enum DuplicateName {
+    /// Several [`Feature`]s share the same name.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    Features(Vec<Source<gherkin::Feature>>),
+
+    /// Several [`Scenario`]s of a single [`Feature`] (including the ones of
+    /// its [`Rule`]s) share the same name.
+    ///
+    /// [`Scenario`]s expanded from the same [Scenario Outline] are not
+    /// considered as duplicates.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [Scenario Outline]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    Scenarios {
+        /// [`Feature`] containing the [`Scenario`]s.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Scenario`]: gherkin::Scenario
+        feature: Source<gherkin::Feature>,
+
+        /// Name shared by the [`Scenario`]s.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        name: String,
+
+        /// Positions of the [`Scenario`]s in the [`Feature`] file.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Scenario`]: gherkin::Scenario
+        positions: Vec<gherkin::LineCol>,
+    },
+}
+
+impl DuplicateName {
+    /// Returns the name being shared.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Features(features) => {
+                features.first().map_or("", |f| f.name.as_str())
+            }
+            Self::Scenarios { name, .. } => name,
+        }
+    }
+}
+
+impl Display for DuplicateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = |feat: &gherkin::Feature| {
+            feat.path
+                .as_deref()
+                .and_then(|p| PathStyle::Trimmed.render(p))
+                .map_or_else(|| feat.name.clone(), Into::into)
+        };
+
+        match self {
+            Self::Features(features) => write!(
+                f,
+                "Feature name `{}` is shared by: {}",
+                self.name(),
+                features
+                    .iter()
+                    .map(|feat| format!(
+                        "{}:{}:{}",
+                        path(feat),
+                        feat.position.line,
+                        feat.position.col,
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            Self::Scenarios {
+                feature,
+                name,
+                positions,
+            } => write!(
+                f,
+                "Scenario name `{name}` is shared in feature `{}` by: {}",
+                feature.name,
+                positions
+                    .iter()
+                    .map(|pos| format!(
+                        "{}:{}:{}",
+                        path(feature),
+                        pos.line,
+                        pos.col,
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        }
+    }
+}
+
 /// Event specific to a particular [Feature].
 ///
 /// [Feature]: https://cucumber.io/docs/gherkin/reference#feature
@@ -297,6 +608,16 @@ pub enum Feature<World> {
     /// [`Scenario`] event.
     Scenario(Source<gherkin::Scenario>, RetryableScenario<World>),
 
+    /// [`Feature`] execution being aborted before all its [`Scenario`]s have
+    /// been run (due to `--fail-fast` CLI option, for example).
+    ///
+    /// Emitted right before the [`Feature::Finished`] event, after all the
+    /// [`Scenario::NotRun`] events of the [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    Aborted,
+
     /// [`Feature`] execution being finished.
     ///
     /// [`Feature`]: gherkin::Feature
@@ -311,6 +632,7 @@ impl<World> Clone for Feature<World> {
             Self::Started => Self::Started,
             Self::Rule(r, ev) => Self::Rule(r.clone(), ev.clone()),
             Self::Scenario(s, ev) => Self::Scenario(s.clone(), ev.clone()),
+            Self::Aborted => Self::Aborted,
             Self::Finished => Self::Finished,
         }
     }
@@ -357,7 +679,7 @@ pub enum Step<World> {
     /// [`Step`]: gherkin::Step
     Started,
 
-    /// [`Step`] being skipped.
+    /// [`Step`] being skipped, along with the [`Duration`] it took.
     ///
     /// That means there is no [`Regex`] matching [`Step`] in a
     /// [`step::Collection`].
@@ -365,14 +687,14 @@ pub enum Step<World> {
     /// [`Regex`]: regex::Regex
     /// [`Step`]: gherkin::Step
     /// [`step::Collection`]: crate::step::Collection
-    Skipped,
+    Skipped(Duration),
 
-    /// [`Step`] passed.
+    /// [`Step`] passed, along with the [`Duration`] of its execution.
     ///
     /// [`Step`]: gherkin::Step
-    Passed(regex::CaptureLocations, Option<step::Location>),
+    Passed(regex::CaptureLocations, Option<step::Location>, Duration),
 
-    /// [`Step`] failed.
+    /// [`Step`] failed, along with the [`Duration`] of its execution.
     ///
     /// [`Step`]: gherkin::Step
     Failed(
@@ -380,6 +702,7 @@ pub enum Step<World> {
         Option<step::Location>,
         Option<Arc<World>>,
         StepError,
+        Duration,
     ),
 }
 
@@ -389,11 +712,33 @@ impl<World> Clone for Step<World> {
     fn clone(&self) -> Self {
         match self {
             Self::Started => Self::Started,
-            Self::Skipped => Self::Skipped,
-            Self::Passed(captures, loc) => Self::Passed(captures.clone(), *loc),
-            Self::Failed(captures, loc, w, info) => {
-                Self::Failed(captures.clone(), *loc, w.clone(), info.clone())
+            Self::Skipped(dur) => Self::Skipped(*dur),
+            Self::Passed(captures, loc, dur) => {
+                Self::Passed(captures.clone(), *loc, *dur)
             }
+            Self::Failed(captures, loc, w, info, dur) => Self::Failed(
+                captures.clone(),
+                *loc,
+                w.clone(),
+                info.clone(),
+                *dur,
+            ),
+        }
+    }
+}
+
+impl<World> Step<World> {
+    /// Returns the [`Duration`] of the [`Step`] execution, unless it's
+    /// [`Step::Started`] only.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::Started => None,
+            Self::Skipped(dur)
+            | Self::Passed(_, _, dur)
+            | Self::Failed(_, _, _, _, dur) => Some(*dur),
         }
     }
 }
@@ -420,11 +765,346 @@ pub enum StepError {
     #[display("Step match is ambiguous: {_0}")]
     AmbiguousMatch(step::AmbiguousMatchError),
 
-    /// [`Step`] panicked.
+    /// [`Step`] doesn't match any [`Regex`] of its keyword, but matches the
+    /// one of the contained [`StepType`].
+    ///
+    /// It's emitted only with [`strict_keywords()`] used.
+    ///
+    /// [`Regex`]: regex::Regex
+    /// [`Step`]: gherkin::Step
+    /// [`StepType`]: gherkin::StepType
+    /// [`strict_keywords()`]: crate::runner::Basic::strict_keywords()
+    #[display("Step matches only a `{_0:?}` function")]
+    #[from(ignore)]
+    KeywordMismatch(#[error(not(source))] gherkin::StepType),
+
+    /// [`Step`] panicked, along with the [`Backtrace`] captured at the moment
+    /// of panicking (if any).
     ///
     /// [`Step`]: gherkin::Step
     #[display("Step panicked. Captured output: {}", coerce_error(_0))]
-    Panic(#[error(not(source))] Info),
+    #[from(ignore)]
+    Panic(
+        #[error(not(source))] Info,
+        #[debug(skip)]
+        #[error(not(source))]
+        Option<Arc<Backtrace>>,
+    ),
+
+    /// [`Step`] has failed with the contained [`StepError`] declaring its
+    /// [`FailureCategory`] via [`FailureCategory::fail()`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[display("[{_0}] {_1}")]
+    #[from(ignore)]
+    Categorized(
+        #[error(not(source))] FailureCategory,
+        #[error(not(source))] Box<Self>,
+    ),
+}
+
+impl From<Info> for StepError {
+    fn from(info: Info) -> Self {
+        Self::Panic(info, None)
+    }
+}
+
+impl StepError {
+    /// Returns the [`Backtrace`] captured on a [`StepError::Panic`], if any.
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            Self::Panic(_, bt) => bt.as_deref(),
+            Self::Categorized(_, e) => e.backtrace(),
+            Self::NotFound
+            | Self::AmbiguousMatch(_)
+            | Self::KeywordMismatch(_) => None,
+        }
+    }
+
+    /// Returns the [`FailureCategory`] of this [`StepError`].
+    ///
+    /// Unless declared explicitly via [`FailureCategory::fail()`], a
+    /// [`StepError::Panic`] is considered as a
+    /// [`FailureCategory::Assertion`], while a [`Step`] not matching
+    /// exactly one function is considered as a
+    /// [`FailureCategory::Undefined`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn category(&self) -> FailureCategory {
+        match self {
+            Self::NotFound
+            | Self::AmbiguousMatch(_)
+            | Self::KeywordMismatch(_) => FailureCategory::Undefined,
+            Self::Panic(..) => FailureCategory::Assertion,
+            Self::Categorized(category, _) => *category,
+        }
+    }
+}
+
+/// Category of a [`Step`] failure.
+///
+/// Allows to distinguish different kinds of failures in reports and by
+/// [exit codes][1] (so CI could, for example, retry infrastructure failures,
+/// but not assertion ones).
+///
+/// [`Step`]: gherkin::Step
+/// [1]: crate::ExitCodes
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FailureCategory {
+    /// Checked expectation doesn't hold.
+    #[display("assertion")]
+    Assertion,
+
+    /// Environment the [`Step`] depends on (like a database, a network or a
+    /// filesystem) is unavailable or misbehaves.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[display("infrastructure")]
+    Infrastructure,
+
+    /// Awaited operation hasn't completed in time.
+    #[display("timeout")]
+    Timeout,
+
+    /// [`Step`] isn't properly defined (it doesn't match exactly one
+    /// function, or the function is incomplete).
+    ///
+    /// [`Step`]: gherkin::Step
+    #[display("undefined")]
+    Undefined,
+}
+
+impl FailureCategory {
+    /// Fails the currently executed [`Step`] with the provided `reason`,
+    /// declaring this [`FailureCategory`] of the failure.
+    ///
+    /// The [`Step`] is reported as [`Failed`] with a
+    /// [`StepError::Categorized`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use cucumber::{event::FailureCategory, given, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # fn connect() -> Result<(), std::io::Error> {
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// #[given("a database")]
+    /// fn database(_: &mut MyWorld) {
+    ///     connect()
+    ///         .unwrap_or_else(|e| FailureCategory::Infrastructure.fail(e));
+    /// }
+    /// ```
+    ///
+    /// [`Failed`]: Step::Failed
+    /// [`Step`]: gherkin::Step
+    pub fn fail(self, reason: impl Display) -> ! {
+        panic::panic_any(CategorizedPanic {
+            category: self,
+            message: reason.to_string(),
+        })
+    }
+}
+
+/// Panic payload of a [`FailureCategory::fail()`].
+#[derive(Clone, Debug)]
+pub(crate) struct CategorizedPanic {
+    /// [`FailureCategory`] of the failure.
+    pub(crate) category: FailureCategory,
+
+    /// Message describing the failure.
+    pub(crate) message: String,
+}
+
+/// [`io::stdout()`] and [`io::stderr()`] output captured during a [`Step`]
+/// execution.
+///
+/// [`io::stderr()`]: std::io::stderr()
+/// [`io::stdout()`]: std::io::stdout()
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Output {
+    /// Captured [`io::stdout()`] output.
+    ///
+    /// [`io::stdout()`]: std::io::stdout()
+    pub stdout: String,
+
+    /// Captured [`io::stderr()`] output.
+    ///
+    /// [`io::stderr()`]: std::io::stderr()
+    pub stderr: String,
+}
+
+/// Log entry emitted during a [`Scenario`] execution.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Log {
+    /// Fully formatted message of this [`Log`] entry.
+    pub message: String,
+
+    /// Structured fields of this [`Log`] entry (in their recording order),
+    /// if it's emitted via [`tracing`] integration.
+    ///
+    /// Contains the `message` field too, unless the entry has no message.
+    ///
+    /// [`tracing`]: https://docs.rs/tracing
+    pub fields: Vec<(String, LogValue)>,
+}
+
+impl Log {
+    /// Indicates whether this [`Log`] entry has any structured
+    /// [`Log::fields`] besides the `message` one.
+    #[must_use]
+    pub fn is_structured(&self) -> bool {
+        self.fields.iter().any(|(name, _)| name != "message")
+    }
+}
+
+impl From<String> for Log {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            fields: vec![],
+        }
+    }
+}
+
+/// Value of a structured [`Log`] field.
+#[derive(Clone, Debug, Display, From, PartialEq)]
+pub enum LogValue {
+    /// Boolean value.
+    Bool(bool),
+
+    /// Signed integer value.
+    I64(i64),
+
+    /// Unsigned integer value.
+    U64(u64),
+
+    /// Floating-point value.
+    F64(f64),
+
+    /// Textual value (or a [`Debug`] representation of a non-primitive one).
+    Str(String),
+}
+
+/// Time budget of a [`Scenario`] exceeded by its execution.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Budget {
+    /// Time budget of the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub limit: Duration,
+
+    /// Time the [`Scenario`] execution actually took.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub elapsed: Duration,
+
+    /// Indicator whether exceeding the budget fails the [`Scenario`] (or is
+    /// just a warning otherwise).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub failed: bool,
+}
+
+impl Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Sub-millisecond precision is just a noise here.
+        let elapsed = Duration::new(
+            self.elapsed.as_secs(),
+            self.elapsed.subsec_millis() * 1_000_000,
+        );
+        write!(
+            f,
+            "Scenario exceeded its budget of {}, taking {}",
+            humantime::format_duration(self.limit),
+            humantime::format_duration(elapsed),
+        )
+    }
+}
+
+/// Work-in-progress state of a [`Scenario`], marked with a `@wip` tag or using
+/// a [`Step`] function marked with a `#[wip]` attribute.
+///
+/// Failures of work-in-progress [`Scenario`]'s [`Step`]s don't fail the run.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Wip {
+    /// [`Scenario`] is work-in-progress.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("Scenario is work in progress")]
+    Marked,
+
+    /// Work-in-progress [`Scenario`] has passed, which fails the run in a
+    /// strict mode (enabled via `--wip` CLI option).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("Work-in-progress scenario passed")]
+    Passed,
+}
+
+/// [`Step`] of a [`Scenario`] making no progress for too long, as detected by
+/// a watchdog of a [`Runner`].
+///
+/// [`Runner`]: crate::Runner
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct Stall {
+    /// [`Step`] being stuck.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub step: Source<gherkin::Step>,
+
+    /// Indicator whether the stuck [`Step`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub is_background: bool,
+
+    /// Time passed since the [`Step`] has been started.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub elapsed: Duration,
+
+    /// [`WorldSnapshot`] of the [`World`] taken right before the [`Step`] has
+    /// been started, if the [`World`] provides one (and the watchdog is
+    /// configured to dump it).
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`World`]: crate::World
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    pub world: Option<String>,
+}
+
+impl Display for Stall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Sub-millisecond precision is just a noise here.
+        let elapsed = Duration::new(
+            self.elapsed.as_secs(),
+            self.elapsed.subsec_millis() * 1_000_000,
+        );
+        write!(
+            f,
+            "Step `{}{}` made no progress for {}",
+            self.step.keyword,
+            self.step.value,
+            humantime::format_duration(elapsed),
+        )
+    }
 }
 
 /// Type of hook executed before or after all [`Scenario`]'s [`Step`]s.
@@ -461,6 +1141,13 @@ pub enum Hook<World> {
 
     /// Hook failed.
     Failed(Option<Arc<World>>, Info),
+
+    /// Hook failed, but its failure is ignored according to the configured
+    /// [`HookFailurePolicy`], so doesn't fail the [`Scenario`].
+    ///
+    /// [`HookFailurePolicy`]: crate::runner::HookFailurePolicy
+    /// [`Scenario`]: gherkin::Scenario
+    Ignored(Info),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -471,6 +1158,7 @@ impl<World> Clone for Hook<World> {
             Self::Started => Self::Started,
             Self::Passed => Self::Passed,
             Self::Failed(w, i) => Self::Failed(w.clone(), Arc::clone(i)),
+            Self::Ignored(i) => Self::Ignored(Arc::clone(i)),
         }
     }
 }
@@ -480,24 +1168,85 @@ impl<World> Clone for Hook<World> {
 /// [Scenario]: https://cucumber.io/docs/gherkin/reference#example
 #[derive(Debug)]
 pub enum Scenario<World> {
-    /// [`Scenario`] execution being started.
+    /// [`Scenario`] execution being started, along with the [`ScenarioId`] of
+    /// this attempt.
     ///
     /// [`Scenario`]: gherkin::Scenario
-    Started,
+    Started(ScenarioId),
 
     /// [`Hook`] event.
     Hook(HookType, Hook<World>),
 
-    /// [`Background`] [`Step`] event.
+    /// [`Feature`]'s [`Background`] [`Step`] event.
     ///
     /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
     Background(Source<gherkin::Step>, Step<World>),
 
+    /// [`Rule`]'s [`Background`] [`Step`] event.
+    ///
+    /// Emitted after all the [`Scenario::Background`] events of the [`Feature`]
+    /// the [`Rule`] belongs to.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    RuleBackground(Source<gherkin::Step>, Step<World>),
+
     /// [`Step`] event.
     Step(Source<gherkin::Step>, Step<World>),
 
-    /// [`Scenario`]'s log entry is emitted.
-    Log(String),
+    /// [`Scenario`]'s [`Log`] entry is emitted.
+    Log(Log),
+
+    /// [`Scenario`]'s artifact file is kept in its [`artifacts::Dir`].
+    ///
+    /// [`artifacts::Dir`]: crate::artifacts::Dir
+    /// [`Scenario`]: gherkin::Scenario
+    Artifact(PathBuf),
+
+    /// [`Output`] captured during a [`Step`] execution.
+    ///
+    /// Emitted before the [`Step`] (or [`Background`] [`Step`]) it's captured
+    /// for is [passed] or [failed].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [failed]: Step::Failed
+    /// [passed]: Step::Passed
+    Output(Output),
+
+    /// [`Step`] of the [`Scenario`] made no progress for longer than the
+    /// configured watchdog timeout.
+    ///
+    /// Emitted after the [`Step::Started`] event of the stuck [`Step`] (and
+    /// before its finishing one), repeatedly for each elapsed timeout.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    Stalled(Stall),
+
+    /// [`Scenario`] not being run at all, as the execution has been stopped
+    /// before reaching it (due to `--fail-fast` CLI option, for example).
+    ///
+    /// Emitted right after the [`Scenario::Started`] event, instead of any
+    /// [`Hook`] or [`Step`] events, and followed by the [`Scenario::Finished`]
+    /// event.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    NotRun,
+
+    /// [`Scenario`] execution exceeded its time budget (set via a
+    /// `@budget(<duration>)` tag).
+    ///
+    /// Emitted right before the [`Scenario::Finished`] event.
+    BudgetExceeded(Budget),
+
+    /// [`Scenario`] being work-in-progress.
+    ///
+    /// [`Wip::Marked`] is emitted right after the [`Scenario::Started`] event,
+    /// while [`Wip::Passed`] is emitted right before the [`Scenario::Finished`]
+    /// event.
+    Wip(Wip),
 
     /// [`Scenario`] execution being finished.
     ///
@@ -510,13 +1259,22 @@ pub enum Scenario<World> {
 impl<World> Clone for Scenario<World> {
     fn clone(&self) -> Self {
         match self {
-            Self::Started => Self::Started,
+            Self::Started(id) => Self::Started(*id),
             Self::Hook(ty, ev) => Self::Hook(*ty, ev.clone()),
             Self::Background(bg, ev) => {
                 Self::Background(bg.clone(), ev.clone())
             }
+            Self::RuleBackground(bg, ev) => {
+                Self::RuleBackground(bg.clone(), ev.clone())
+            }
             Self::Step(st, ev) => Self::Step(st.clone(), ev.clone()),
-            Self::Log(msg) => Self::Log(msg.clone()),
+            Self::Log(log) => Self::Log(log.clone()),
+            Self::Artifact(path) => Self::Artifact(path.clone()),
+            Self::Output(out) => Self::Output(out.clone()),
+            Self::Stalled(stall) => Self::Stalled(stall.clone()),
+            Self::NotRun => Self::NotRun,
+            Self::BudgetExceeded(budget) => Self::BudgetExceeded(*budget),
+            Self::Wip(wip) => Self::Wip(*wip),
             Self::Finished => Self::Finished,
         }
     }
@@ -551,6 +1309,15 @@ impl<World> Scenario<World> {
         Self::Hook(which, Hook::Failed(world, info))
     }
 
+    /// Constructs an event of a failed [`Scenario`] hook, which failure is
+    /// ignored.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn hook_ignored(which: HookType, info: Info) -> Self {
+        Self::Hook(which, Hook::Ignored(info))
+    }
+
     /// Constructs an event of a [`Step`] being started.
     ///
     /// [`Step`]: gherkin::Step
@@ -570,6 +1337,19 @@ impl<World> Scenario<World> {
         Self::Background(step.into(), Step::Started)
     }
 
+    /// Constructs an event of a [`Rule`]'s [`Background`] [`Step`] being
+    /// started.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn rule_background_step_started(
+        step: impl Into<Source<gherkin::Step>>,
+    ) -> Self {
+        Self::RuleBackground(step.into(), Step::Started)
+    }
+
     /// Constructs an event of a passed [`Step`].
     ///
     /// [`Step`]: gherkin::Step
@@ -578,8 +1358,9 @@ impl<World> Scenario<World> {
         step: impl Into<Source<gherkin::Step>>,
         captures: regex::CaptureLocations,
         loc: Option<step::Location>,
+        duration: Duration,
     ) -> Self {
-        Self::Step(step.into(), Step::Passed(captures, loc))
+        Self::Step(step.into(), Step::Passed(captures, loc, duration))
     }
 
     /// Constructs an event of a passed [`Background`] [`Step`].
@@ -591,16 +1372,35 @@ impl<World> Scenario<World> {
         step: impl Into<Source<gherkin::Step>>,
         captures: regex::CaptureLocations,
         loc: Option<step::Location>,
+        duration: Duration,
     ) -> Self {
-        Self::Background(step.into(), Step::Passed(captures, loc))
+        Self::Background(step.into(), Step::Passed(captures, loc, duration))
+    }
+
+    /// Constructs an event of a passed [`Rule`]'s [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn rule_background_step_passed(
+        step: impl Into<Source<gherkin::Step>>,
+        captures: regex::CaptureLocations,
+        loc: Option<step::Location>,
+        duration: Duration,
+    ) -> Self {
+        Self::RuleBackground(step.into(), Step::Passed(captures, loc, duration))
     }
 
     /// Constructs an event of a skipped [`Step`].
     ///
     /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn step_skipped(step: impl Into<Source<gherkin::Step>>) -> Self {
-        Self::Step(step.into(), Step::Skipped)
+    pub fn step_skipped(
+        step: impl Into<Source<gherkin::Step>>,
+        duration: Duration,
+    ) -> Self {
+        Self::Step(step.into(), Step::Skipped(duration))
     }
     /// Constructs an event of a skipped [`Background`] [`Step`].
     ///
@@ -609,8 +1409,22 @@ impl<World> Scenario<World> {
     #[must_use]
     pub fn background_step_skipped(
         step: impl Into<Source<gherkin::Step>>,
+        duration: Duration,
     ) -> Self {
-        Self::Background(step.into(), Step::Skipped)
+        Self::Background(step.into(), Step::Skipped(duration))
+    }
+
+    /// Constructs an event of a skipped [`Rule`]'s [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn rule_background_step_skipped(
+        step: impl Into<Source<gherkin::Step>>,
+        duration: Duration,
+    ) -> Self {
+        Self::RuleBackground(step.into(), Step::Skipped(duration))
     }
 
     /// Constructs an event of a failed [`Step`].
@@ -623,8 +1437,12 @@ impl<World> Scenario<World> {
         loc: Option<step::Location>,
         world: Option<Arc<World>>,
         info: impl Into<StepError>,
+        duration: Duration,
     ) -> Self {
-        Self::Step(step.into(), Step::Failed(captures, loc, world, info.into()))
+        Self::Step(
+            step.into(),
+            Step::Failed(captures, loc, world, info.into(), duration),
+        )
     }
 
     /// Constructs an event of a failed [`Background`] [`Step`].
@@ -638,10 +1456,31 @@ impl<World> Scenario<World> {
         loc: Option<step::Location>,
         world: Option<Arc<World>>,
         info: impl Into<StepError>,
+        duration: Duration,
     ) -> Self {
         Self::Background(
             step.into(),
-            Step::Failed(captures, loc, world, info.into()),
+            Step::Failed(captures, loc, world, info.into(), duration),
+        )
+    }
+
+    /// Constructs an event of a failed [`Rule`]'s [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn rule_background_step_failed(
+        step: impl Into<Source<gherkin::Step>>,
+        captures: Option<regex::CaptureLocations>,
+        loc: Option<step::Location>,
+        world: Option<Arc<World>>,
+        info: impl Into<StepError>,
+        duration: Duration,
+    ) -> Self {
+        Self::RuleBackground(
+            step.into(),
+            Step::Failed(captures, loc, world, info.into(), duration),
         )
     }
 
@@ -11,16 +11,19 @@
 //! [`gherkin::Feature`] extension.
 
 use std::{
-    iter, mem,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    env, iter, mem,
     path::{Path, PathBuf},
-    sync::LazyLock,
+    sync::{Arc, LazyLock},
 };
 
-use derive_more::with_trait::{Display, Error};
+use derive_more::with_trait::{Debug, Display, Error};
+use gherkin::StepType;
 use regex::Regex;
 use sealed::sealed;
 
-use crate::writer::basic::trim_path;
+use crate::{tag, writer::basic::trim_path};
 
 /// Helper methods to operate on [`gherkin::Feature`]s.
 #[sealed]
@@ -68,15 +71,101 @@ pub trait Ext: Sized {
     ///       |    20 |   4 |   16 |
     /// ```
     ///
+    /// Each expanded [`Scenario`] inherits the tags of the [`Examples`][2]
+    /// block it's expanded from, and keeps only this block (with its table
+    /// reduced to the header and the expanded row) in its
+    /// [`Scenario::examples`], so tag filtering may select a single
+    /// [`Examples`][2] table of a [`Scenario Outline`][1].
+    ///
     /// # Errors
     ///
     /// Errors if the [`Examples`][2] cannot be expanded.
     /// See [`ExpandExamplesError`] for details.
     ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::examples`]: gherkin::Scenario::examples
     /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
     /// [2]: https://cucumber.io/docs/gherkin/reference#examples
     fn expand_examples(self) -> Result<Self, ExpandExamplesError>;
 
+    /// Expands [`Scenario Outline`][1] [`Examples`][2] the same way
+    /// [`Ext::expand_examples()`] does, but according to the provided
+    /// [`ExpandOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`Examples`][2] cannot be expanded.
+    /// See [`ExpandExamplesError`] for details.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    fn expand_examples_with(
+        self,
+        opts: &ExpandOptions,
+    ) -> Result<Self, ExpandExamplesError>;
+
+    /// Expands [`Scenario Outline`][1] [`Examples`][2] the same way
+    /// [`Ext::expand_examples_with()`] does, but only the rows producing
+    /// [`Scenario`]s with names matching the provided `name` [`Regex`].
+    ///
+    /// Rows not matching the `name` are never expanded, while [`Scenario`]s
+    /// (and [`Rule`]s) remaining without any [`Scenario`]s are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`Examples`][2] cannot be expanded.
+    /// See [`ExpandExamplesError`] for details.
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    fn expand_examples_matching(
+        self,
+        opts: &ExpandOptions,
+        name: &Regex,
+    ) -> Result<Self, ExpandExamplesError>;
+
+    /// Omits [`Scenario`]s (including [`Rule`]s inside) not matching the
+    /// provided `tags` expression, and [`Examples`] of
+    /// [`Scenario Outline`][1]s not matching it.
+    ///
+    /// Tags are inherited in the `Feature` -> `Rule` -> `Scenario` ->
+    /// `Examples` order, the same way they are for the [`Scenario`]s produced
+    /// by [`Ext::expand_examples()`], so this may be used before expanding
+    /// [`Examples`] to avoid expanding the ones not matching the `tags` at
+    /// all.
+    ///
+    /// [`Rule`]s and [`Scenario Outline`][1]s having all their [`Scenario`]s
+    /// and [`Examples`] respectively filtered out are omitted too.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    fn filter_by_tags(self, tags: &tag::Expr) -> Self;
+
+    /// Substitutes `${ENV_VAR}` and `{{config.key}}` placeholders in all the
+    /// [`Step`]s (including [`Background`] ones) texts, doc strings and data
+    /// tables according to the provided [`Interpolation`].
+    ///
+    /// Placeholders, which cannot be resolved, are left as is.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn interpolate(self, interpolation: &Interpolation) -> Self;
+
+    /// Resolves the effective keywords of `*` bullet [`Step`]s (along with
+    /// conjunctions following them), making them inherit the [`StepType`] of
+    /// the preceding [`Step`] instead of always being `Given` ones.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`StepType`]: gherkin::StepType
+    #[must_use]
+    fn resolve_step_keywords(self) -> Self;
+
     /// Counts all the [`Feature`]'s [`Scenario`]s, including [`Rule`]s inside.
     ///
     /// [`Feature`]: gherkin::Feature
@@ -95,21 +184,151 @@ pub trait Ext: Sized {
 
 #[sealed]
 impl Ext for gherkin::Feature {
-    fn expand_examples(mut self) -> Result<Self, ExpandExamplesError> {
-        let path = self.path.clone();
-        let expand = |scenarios: Vec<gherkin::Scenario>| -> Result<_, _> {
+    fn expand_examples(self) -> Result<Self, ExpandExamplesError> {
+        self.expand_examples_with(&ExpandOptions::default())
+    }
+
+    fn expand_examples_with(
+        self,
+        opts: &ExpandOptions,
+    ) -> Result<Self, ExpandExamplesError> {
+        expand_feature(self, opts, None)
+    }
+
+    fn expand_examples_matching(
+        self,
+        opts: &ExpandOptions,
+        name: &Regex,
+    ) -> Result<Self, ExpandExamplesError> {
+        let mut feature = expand_feature(self, opts, Some(name))?;
+        feature.rules.retain(|r| !r.scenarios.is_empty());
+        Ok(feature)
+    }
+
+    fn filter_by_tags(mut self, tags: &tag::Expr) -> Self {
+        let matches = |rule: Option<&gherkin::Rule>,
+                       sc: &gherkin::Scenario,
+                       ex: Option<&gherkin::Examples>| {
+            // The order `Feature` -> `Rule` -> `Scenario` -> `Examples`
+            // matters here.
+            tags.eval(
+                self.tags
+                    .iter()
+                    .chain(rule.iter().flat_map(|r| &r.tags))
+                    .chain(&sc.tags)
+                    .chain(ex.iter().flat_map(|e| &e.tags)),
+            )
+        };
+        let filter = |rule: Option<&gherkin::Rule>,
+                      scenarios: Vec<gherkin::Scenario>| {
             scenarios
                 .into_iter()
-                .flat_map(|s| expand_scenario(s, path.as_ref()))
-                .collect()
+                .filter_map(|mut sc| {
+                    if sc.examples.is_empty() {
+                        return matches(rule, &sc, None).then_some(sc);
+                    }
+                    let examples = mem::take(&mut sc.examples);
+                    sc.examples = examples
+                        .into_iter()
+                        .filter(|ex| matches(rule, &sc, Some(ex)))
+                        .collect();
+                    (!sc.examples.is_empty()).then_some(sc)
+                })
+                .collect::<Vec<_>>()
         };
 
-        for r in &mut self.rules {
-            r.scenarios = expand(mem::take(&mut r.scenarios))?;
+        let scenarios = filter(None, mem::take(&mut self.scenarios));
+        let rules = mem::take(&mut self.rules)
+            .into_iter()
+            .filter_map(|mut r| {
+                let rule_scenarios = mem::take(&mut r.scenarios);
+                let was_empty = rule_scenarios.is_empty();
+                r.scenarios = filter(Some(&r), rule_scenarios);
+                (was_empty || !r.scenarios.is_empty()).then_some(r)
+            })
+            .collect();
+        self.scenarios = scenarios;
+        self.rules = rules;
+
+        self
+    }
+
+    fn interpolate(mut self, interpolation: &Interpolation) -> Self {
+        let rules = self
+            .rules
+            .iter_mut()
+            .flat_map(|r| steps_mut(r.background.as_mut(), &mut r.scenarios));
+
+        for step in steps_mut(self.background.as_mut(), &mut self.scenarios)
+            .chain(rules)
+        {
+            for value in
+                iter::once(&mut step.value)
+                    .chain(step.docstring.iter_mut())
+                    .chain(step.table.iter_mut().flat_map(|t| {
+                        t.rows.iter_mut().flat_map(|r| r.iter_mut())
+                    }))
+            {
+                if let Cow::Owned(v) = interpolation.apply(value) {
+                    *value = v;
+                }
+            }
         }
-        self.scenarios = expand(mem::take(&mut self.scenarios))?;
 
-        Ok(self)
+        self
+    }
+
+    fn resolve_step_keywords(mut self) -> Self {
+        let is_bullet = |st: &gherkin::Step| st.keyword.trim() == "*";
+
+        // `gherkin` parses conjunctions following a `*` bullet as `Given`
+        // ones, so keywords of explicit `Given` steps are collected to tell
+        // them apart.
+        let given_keywords =
+            step_lists(self.background.as_ref(), &self.scenarios)
+                .chain(self.rules.iter().flat_map(|r| {
+                    step_lists(r.background.as_ref(), &r.scenarios)
+                }))
+                .flat_map(|steps| {
+                    iter::once(None)
+                        .chain(steps.iter().map(Some))
+                        .zip(steps)
+                        .filter(|(prev, st)| {
+                            st.ty == StepType::Given
+                                && !is_bullet(st)
+                                && prev.is_none_or(|p| {
+                                    !is_bullet(p) && p.ty != StepType::Given
+                                })
+                        })
+                        .map(|(_, st)| st.keyword.clone())
+                })
+                .collect::<HashSet<_>>();
+
+        let rules = self.rules.iter_mut().flat_map(|r| {
+            step_lists_mut(r.background.as_mut(), &mut r.scenarios)
+        });
+        for steps in
+            step_lists_mut(self.background.as_mut(), &mut self.scenarios)
+                .chain(rules)
+        {
+            let mut last = None;
+            let mut after_bullet = false;
+            for st in steps {
+                if is_bullet(st)
+                    || (after_bullet
+                        && st.ty == StepType::Given
+                        && !given_keywords.contains(&st.keyword))
+                {
+                    st.ty = last.unwrap_or(st.ty);
+                    after_bullet = true;
+                } else {
+                    after_bullet = false;
+                }
+                last = Some(st.ty);
+            }
+        }
+
+        self
     }
 
     fn count_scenarios(&self) -> usize {
@@ -128,7 +347,83 @@ impl Ext for gherkin::Feature {
     }
 }
 
-/// Expands [`Scenario`] [`Examples`], if any.
+/// Returns the [`Step`]s lists of the provided [`Background`] (if any) and
+/// [`Scenario`]s.
+///
+/// [`Background`]: gherkin::Background
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+fn step_lists<'a>(
+    background: Option<&'a gherkin::Background>,
+    scenarios: &'a [gherkin::Scenario],
+) -> impl Iterator<Item = &'a Vec<gherkin::Step>> {
+    background
+        .into_iter()
+        .map(|b| &b.steps)
+        .chain(scenarios.iter().map(|s| &s.steps))
+}
+
+/// Mutable version of [`step_lists()`].
+fn step_lists_mut<'a>(
+    background: Option<&'a mut gherkin::Background>,
+    scenarios: &'a mut [gherkin::Scenario],
+) -> impl Iterator<Item = &'a mut Vec<gherkin::Step>> {
+    background
+        .into_iter()
+        .map(|b| &mut b.steps)
+        .chain(scenarios.iter_mut().map(|s| &mut s.steps))
+}
+
+/// Returns all the [`Step`]s of the provided [`Background`] (if any) and
+/// [`Scenario`]s.
+///
+/// [`Background`]: gherkin::Background
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+fn steps_mut<'a>(
+    background: Option<&'a mut gherkin::Background>,
+    scenarios: &'a mut [gherkin::Scenario],
+) -> impl Iterator<Item = &'a mut gherkin::Step> {
+    background
+        .into_iter()
+        .flat_map(|b| b.steps.iter_mut())
+        .chain(scenarios.iter_mut().flat_map(|s| s.steps.iter_mut()))
+}
+
+/// Expands [`Examples`] of all the [`Feature`]'s [`Scenario`]s, omitting the
+/// ones with names not matching the provided `name` [`Regex`] (if any).
+///
+/// # Errors
+///
+/// See [`ExpandExamplesError`] for details.
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+fn expand_feature(
+    mut feature: gherkin::Feature,
+    opts: &ExpandOptions,
+    name: Option<&Regex>,
+) -> Result<gherkin::Feature, ExpandExamplesError> {
+    let path = feature.path.clone();
+    let expand = |scenarios: Vec<gherkin::Scenario>| -> Result<_, _> {
+        scenarios
+            .into_iter()
+            .flat_map(|s| expand_scenario(s, path.as_ref(), opts, name))
+            .collect()
+    };
+
+    for r in &mut feature.rules {
+        r.scenarios = expand(mem::take(&mut r.scenarios))?;
+    }
+    feature.scenarios = expand(mem::take(&mut feature.scenarios))?;
+
+    Ok(feature)
+}
+
+/// Expands [`Scenario`] [`Examples`], if any, omitting the [`Scenario`]s with
+/// names not matching the provided `name` [`Regex`] (if any) before expanding
+/// them.
 ///
 /// # Errors
 ///
@@ -139,6 +434,8 @@ impl Ext for gherkin::Feature {
 fn expand_scenario(
     scenario: gherkin::Scenario,
     path: Option<&PathBuf>,
+    opts: &ExpandOptions,
+    name_filter: Option<&Regex>,
 ) -> Vec<Result<gherkin::Scenario, ExpandExamplesError>> {
     /// [`Regex`] matching placeholders [`Examples`] should expand into.
     ///
@@ -149,12 +446,30 @@ fn expand_scenario(
     static TEMPLATE_REGEX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"<([^>\s]+)>").unwrap());
 
+    let is_matching = |n: &str| name_filter.is_none_or(|re| re.is_match(n));
+
     if scenario.examples.is_empty() {
-        return vec![Ok(scenario)];
+        return if is_matching(&scenario.name) {
+            vec![Ok(scenario)]
+        } else {
+            vec![]
+        };
     }
 
-    scenario
-        .examples
+    let examples = opts.expansion.as_ref().map_or(
+        Cow::Borrowed(scenario.examples.as_slice()),
+        |expand| {
+            scenario
+                .examples
+                .iter()
+                .map(|ex| {
+                    expand(path.map(PathBuf::as_path), &scenario, ex.clone())
+                })
+                .collect()
+        },
+    );
+
+    examples
         .iter()
         .filter_map(|ex| {
             ex.table
@@ -164,12 +479,10 @@ fn expand_scenario(
                 .map(|(h, v)| (h, v, ex))
         })
         .flat_map(|(header, vals, example)| {
-            vals.iter()
-                .map(|v| header.iter().zip(v))
-                .enumerate()
-                .zip(iter::repeat((example.position, example.tags.iter())))
+            vals.iter().enumerate().zip(iter::repeat((header, example)))
         })
-        .map(|((id, row), (position, tags))| {
+        .map(|((id, values), (header, example))| {
+            let row = header.iter().zip(values);
             let replace_templates = |str: &str, pos| {
                 let mut err = None;
                 let replaced = TEMPLATE_REGEX
@@ -199,33 +512,325 @@ fn expand_scenario(
                 err.map_or_else(|| Ok(replaced), Err)
             };
 
-            let mut expanded = scenario.clone();
-
             // This is done to differentiate `Hash`es of
             // scenario outlines with the same examples.
+            let mut position = example.position;
+            position.line += id + 2;
+
+            // Name is resolved first, so the rows not matching it aren't
+            // expanded at all.
+            let expanded_name = replace_templates(&scenario.name, position)?;
+            if !is_matching(&expanded_name) {
+                return Ok(None);
+            }
+
+            let mut expanded = scenario.clone();
             expanded.position = position;
-            expanded.position.line += id + 2;
+            expanded.name = expanded_name;
 
-            expanded.tags.extend(tags.cloned());
+            // Only the `Examples` this `Scenario` is expanded from are kept,
+            // so their tags and position aren't lost.
+            expanded.tags.extend(example.tags.iter().cloned());
+            expanded.examples = vec![reduce_examples(example, header, values)];
 
-            expanded.name =
-                replace_templates(&expanded.name, expanded.position)?;
             for s in &mut expanded.steps {
-                for value in iter::once(&mut s.value)
-                    .chain(s.docstring.iter_mut())
-                    .chain(s.table.iter_mut().flat_map(|t| {
-                        t.rows.iter_mut().flat_map(|r| r.iter_mut())
-                    }))
+                s.value = replace_templates(&s.value, s.position)?;
+                if !opts.substitute_step_arguments {
+                    continue;
+                }
+                for value in
+                    s.docstring.iter_mut().chain(s.table.iter_mut().flat_map(
+                        |t| t.rows.iter_mut().flat_map(|r| r.iter_mut()),
+                    ))
                 {
                     *value = replace_templates(value, s.position)?;
                 }
             }
 
-            Ok(expanded)
+            Ok(Some(expanded))
         })
+        .filter_map(Result::transpose)
         .collect()
 }
 
+/// Clones the provided [`Examples`] with its table reduced to the provided
+/// `header` and `row`, so the whole table isn't copied into each expanded
+/// [`Scenario`].
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Scenario`]: gherkin::Scenario
+fn reduce_examples(
+    examples: &gherkin::Examples,
+    header: &[String],
+    row: &[String],
+) -> gherkin::Examples {
+    gherkin::Examples {
+        keyword: examples.keyword.clone(),
+        name: examples.name.clone(),
+        description: examples.description.clone(),
+        table: examples.table.as_ref().map(|t| gherkin::Table {
+            rows: vec![header.to_vec(), row.to_vec()],
+            span: t.span,
+            position: t.position,
+        }),
+        tags: examples.tags.clone(),
+        span: examples.span,
+        position: examples.position,
+    }
+}
+
+/// Options of [`Scenario Outline`][1] [`Examples`][2] expansion via
+/// [`Ext::expand_examples_with()`].
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+/// [2]: https://cucumber.io/docs/gherkin/reference#examples
+#[derive(Clone, Debug)]
+pub struct ExpandOptions {
+    /// Indicator whether `<placeholders>` are substituted inside doc strings
+    /// and data tables of [`Step`]s, not only in their text.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub substitute_step_arguments: bool,
+
+    /// Optional [`ExamplesExpansionFn`] to transform [`Examples`][2] with
+    /// before expanding them row by row.
+    ///
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    #[debug(ignore)]
+    pub expansion: Option<ExamplesExpansionFn>,
+}
+
+impl Default for ExpandOptions {
+    fn default() -> Self {
+        Self {
+            substitute_step_arguments: true,
+            expansion: None,
+        }
+    }
+}
+
+/// Function transforming [`Examples`][2] of a [`Scenario Outline`][1] before
+/// they're expanded row by row into separate [`Scenario`]s.
+///
+/// Accepts the [`Feature::path`] (if any), the [`Scenario Outline`][1] itself
+/// and its [`Examples`][2] to transform, so may be used for generating rows
+/// (like pairwise combinations), injecting rows from environment, or loading
+/// them from external sources (like CSV files).
+///
+/// # Example
+///
+/// Duplicating each row of the [`Examples`][2]:
+/// ```rust
+/// # use std::sync::Arc;
+/// #
+/// # use cucumber::feature::ExamplesExpansionFn;
+/// #
+/// let expansion: ExamplesExpansionFn = Arc::new(|_, _, mut examples| {
+///     if let Some(table) = examples.table.as_mut() {
+///         let rows = table.rows.iter().skip(1).cloned().collect::<Vec<_>>();
+///         table.rows.extend(rows);
+///     }
+///     examples
+/// });
+/// ```
+///
+/// [`Feature::path`]: gherkin::Feature::path
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+/// [2]: https://cucumber.io/docs/gherkin/reference#examples
+pub type ExamplesExpansionFn = Arc<
+    dyn Fn(
+            Option<&Path>,
+            &gherkin::Scenario,
+            gherkin::Examples,
+        ) -> gherkin::Examples
+        + Send
+        + Sync,
+>;
+
+/// Row of [`Examples`][2] a [`Scenario Outline`][1] has been expanded from by
+/// [`Ext::expand_examples()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::feature::{Example, Ext as _};
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "Feature: F\n  Scenario Outline: S <n>\n    Given <n> cucumbers\n\
+///      \n    Examples: small\n      | n  |\n      | 12 |\n      | 20 |\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap()
+/// .expand_examples()
+/// .unwrap();
+///
+/// let example = Example::of(&feature.scenarios[1]).unwrap();
+/// assert_eq!(example.name.as_deref(), Some("small"));
+/// assert_eq!(example.index, 1);
+/// assert_eq!(example.get("n"), Some("20"));
+/// ```
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+/// [2]: https://cucumber.io/docs/gherkin/reference#examples
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    /// Name of the [`Examples`][2] block the row belongs to, if any.
+    ///
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    pub name: Option<String>,
+
+    /// Index of the row in the [`Examples`][2] block (excluding its header).
+    ///
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    pub index: usize,
+
+    /// Header of the [`Examples`][2] table.
+    ///
+    /// [2]: https://cucumber.io/docs/gherkin/reference#examples
+    pub header: Vec<String>,
+
+    /// Values of the row, in the order of the [`Example::header`].
+    pub values: Vec<String>,
+}
+
+impl Example {
+    /// Returns the [`Example`] row the provided [`Scenario`] has been expanded
+    /// from by [`Ext::expand_examples()`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn of(scenario: &gherkin::Scenario) -> Option<Self> {
+        let [examples] = scenario.examples.as_slice() else {
+            return None;
+        };
+        // Expanded `Scenario Outline` is positioned at its `Examples` row,
+        // while its `Examples` table is reduced to the header and this row.
+        let index = scenario
+            .position
+            .line
+            .checked_sub(examples.position.line + 2)?;
+        let [header, values] = examples.table.as_ref()?.rows.as_slice() else {
+            return None;
+        };
+        Some(Self {
+            name: examples.name.clone(),
+            index,
+            header: header.clone(),
+            values: values.clone(),
+        })
+    }
+
+    /// Returns the value of this [`Example`] row in the column with the
+    /// provided `name`, if any.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.iter().find_map(|(k, v)| (k == name).then_some(v))
+    }
+
+    /// Iterates over the header names along with the values of this
+    /// [`Example`] row.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> + Clone {
+        self.header
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter().map(String::as_str))
+    }
+}
+
+/// Interpolation of `${ENV_VAR}` and `{{config.key}}` placeholders in
+/// [`Step`]s, allowing `.feature` files to be environment-agnostic:
+/// ```gherkin
+/// When I open ${BASE_URL}/login
+/// And I sign in as {{config.user}}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{feature::Interpolation, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .interpolate(Interpolation::new().config("user", "admin"))
+///     .run_and_exit("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug, Default)]
+pub struct Interpolation {
+    /// Indicator whether `${ENV_VAR}` placeholders are substituted with the
+    /// values of environment variables.
+    pub env: bool,
+
+    /// Values of `{{config.key}}` placeholders by their keys.
+    pub config: HashMap<String, String>,
+}
+
+impl Interpolation {
+    /// Creates a new [`Interpolation`] of `${ENV_VAR}` placeholders only.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            env: true,
+            config: HashMap::new(),
+        }
+    }
+
+    /// Adds the provided `value` of a `{{config.key}}` placeholder.
+    #[must_use]
+    pub fn config(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        drop(self.config.insert(key.into(), value.into()));
+        self
+    }
+
+    /// Disables substitution of `${ENV_VAR}` placeholders.
+    #[must_use]
+    pub const fn without_env(mut self) -> Self {
+        self.env = false;
+        self
+    }
+
+    /// Substitutes all the resolvable placeholders in the provided `text`.
+    #[must_use]
+    pub fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        /// [`Regex`] matching `${ENV_VAR}` and `{{config.key}}` placeholders.
+        // TODO: Switch back to `lazy-regex::regex!()` once it migrates to
+        //       `std`: https://github.com/Canop/lazy-regex/issues/10
+        #[expect(clippy::unwrap_used, reason = "regex is valid")]
+        static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(concat!(
+                r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}",
+                r"|\{\{\s*config\.([^{}\s]+)\s*\}\}",
+            ))
+            .unwrap()
+        });
+
+        PLACEHOLDER_REGEX.replace_all(text, |cap: &regex::Captures<'_>| {
+            let value = cap.get(1).map_or_else(
+                || {
+                    cap.get(2)
+                        .and_then(|key| self.config.get(key.as_str()).cloned())
+                },
+                |var| self.env.then(|| env::var(var.as_str()).ok()).flatten(),
+            );
+            value.unwrap_or_else(|| cap[0].to_owned())
+        })
+    }
+}
+
 /// Error of [`Scenario Outline`][1] expansion encountering an unknown template.
 ///
 /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
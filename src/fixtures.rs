@@ -0,0 +1,360 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reusable [`Scenario`] resources (like HTTP mocks or database fixtures)
+//! with a managed lifecycle.
+//!
+//! A [`Fixture`] is registered once via [`runner::Basic::fixture()`] (or
+//! [`Cucumber::fixture()`]), and is required by [`Scenario`]s via a
+//! `@fixture(<name>)` tag (directly or via [inheritance][1]).
+//!
+//! For every [`Scenario`] requiring it, [`runner::Basic`] sets the
+//! [`Fixture`] up right after creating a [`World`] (before the [`Before`
+//! hook][2]), setting its dependencies up first. Once the [`Scenario`]
+//! finishes, [`Fixture`]s are torn down in the reverse order (after the
+//! [`After` hook][3], but before the [`WorldTeardown`]), even if the
+//! [`Scenario`] has failed.
+//!
+//! Errors of a [`Fixture`] setup are reported as the [`Before` hook][2]
+//! failures, and panics of its teardown as the [`After` hook][3] failures.
+//!
+//! [`Cucumber::fixture()`]: crate::Cucumber::fixture()
+//! [`Scenario`]: gherkin::Scenario
+//! [`World`]: crate::World
+//! [`WorldTeardown`]: crate::WorldTeardown
+//! [1]: crate::tag::inherited
+//! [2]: https://cucumber.io/docs/cucumber/api#before
+//! [3]: https://cucumber.io/docs/cucumber/api#after
+
+use std::{collections::HashMap, error, future::Future, sync::Arc};
+
+use derive_more::with_trait::Display;
+use futures::future::{self, FutureExt as _, LocalBoxFuture};
+
+#[cfg(doc)]
+use crate::runner;
+
+/// Error of a [`Fixture::setup()`].
+pub type Error = Box<dyn error::Error + Send + Sync>;
+
+/// Resource of a [`Scenario`] with an asynchronous setup and teardown,
+/// injected into its [`World`].
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{fixtures::{self, Fixture}, World};
+/// #
+/// # #[derive(Debug)]
+/// # struct MockServer;
+/// #
+/// # impl MockServer {
+/// #     async fn start() -> Result<Self, std::io::Error> {
+/// #         Ok(Self)
+/// #     }
+/// #
+/// #     async fn shutdown(self) {}
+/// # }
+/// #
+/// #[derive(Debug, Default, World)]
+/// struct MyWorld {
+///     server: Option<MockServer>,
+/// }
+///
+/// struct HttpMock;
+///
+/// impl Fixture<MyWorld> for HttpMock {
+///     fn name(&self) -> &str {
+///         "http-mock"
+///     }
+///
+///     async fn setup(&self, world: &mut MyWorld) -> Result<(), fixtures::Error> {
+///         world.server = Some(MockServer::start().await?);
+///         Ok(())
+///     }
+///
+///     async fn teardown(&self, world: &mut MyWorld) {
+///         if let Some(server) = world.server.take() {
+///             server.shutdown().await;
+///         }
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .fixture(HttpMock)
+///     .run("tests/features/readme")
+///     .await;
+/// # }
+/// ```
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`World`]: crate::World
+pub trait Fixture<W> {
+    /// Returns the unique name of this [`Fixture`], used in a
+    /// `@fixture(<name>)` tag.
+    #[must_use]
+    fn name(&self) -> &str;
+
+    /// Returns names of the other [`Fixture`]s this one depends on, so should
+    /// be set up before it (and torn down after it).
+    #[must_use]
+    fn dependencies(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Sets this [`Fixture`] up, injecting it into the provided `world`.
+    ///
+    /// # Errors
+    ///
+    /// If this [`Fixture`] cannot be set up, failing the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn setup(&self, world: &mut W) -> impl Future<Output = Result<(), Error>>;
+
+    /// Tears this [`Fixture`] down once its [`Scenario`] finishes.
+    ///
+    /// Does nothing by default.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn teardown(&self, world: &mut W) -> impl Future<Output = ()> {
+        _ = world;
+        future::ready(())
+    }
+}
+
+/// Object-safe version of a [`Fixture`].
+pub(crate) trait DynFixture<W> {
+    /// Returns the [`Fixture::name()`].
+    fn name(&self) -> &str;
+
+    /// Returns the [`Fixture::dependencies()`].
+    fn dependencies(&self) -> &[&str];
+
+    /// Returns the boxed [`Fixture::setup()`].
+    fn setup<'a>(
+        &'a self,
+        world: &'a mut W,
+    ) -> LocalBoxFuture<'a, Result<(), Error>>;
+
+    /// Returns the boxed [`Fixture::teardown()`].
+    fn teardown<'a>(&'a self, world: &'a mut W) -> LocalBoxFuture<'a, ()>;
+}
+
+impl<W, F: Fixture<W>> DynFixture<W> for F {
+    fn name(&self) -> &str {
+        Fixture::name(self)
+    }
+
+    fn dependencies(&self) -> &[&str] {
+        Fixture::dependencies(self)
+    }
+
+    fn setup<'a>(
+        &'a self,
+        world: &'a mut W,
+    ) -> LocalBoxFuture<'a, Result<(), Error>> {
+        Fixture::setup(self, world).boxed_local()
+    }
+
+    fn teardown<'a>(&'a self, world: &'a mut W) -> LocalBoxFuture<'a, ()> {
+        Fixture::teardown(self, world).boxed_local()
+    }
+}
+
+/// Shared type-erased [`Fixture`].
+pub(crate) type Shared<W> = Arc<dyn DynFixture<W> + Send + Sync>;
+
+/// Registered [`Fixture`]s, keyed by their [names](Fixture::name).
+pub(crate) struct Registry<W> {
+    /// [`Fixture`]s of this [`Registry`].
+    fixtures: HashMap<String, Shared<W>>,
+}
+
+// Implemented manually to omit redundant `W: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<W> Clone for Registry<W> {
+    fn clone(&self) -> Self {
+        Self {
+            fixtures: self.fixtures.clone(),
+        }
+    }
+}
+
+// Implemented manually to omit redundant `W: Default` trait bound, imposed by
+// `#[derive(Default)]`.
+impl<W> Default for Registry<W> {
+    fn default() -> Self {
+        Self {
+            fixtures: HashMap::new(),
+        }
+    }
+}
+
+impl<W> Registry<W> {
+    /// Registers the provided [`Fixture`], replacing the one with the same
+    /// [name](Fixture::name), if any.
+    pub(crate) fn register<F>(&mut self, fixture: F)
+    where
+        F: Fixture<W> + Send + Sync + 'static,
+    {
+        drop(
+            self.fixtures
+                .insert(Fixture::name(&fixture).to_owned(), Arc::new(fixture)),
+        );
+    }
+
+    /// Resolves the [`Fixture`]s of the provided `names` along with all their
+    /// dependencies, in the order they should be set up.
+    pub(crate) fn resolve<I, S>(
+        &self,
+        names: I,
+    ) -> Result<Vec<Shared<W>>, ResolveError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut resolved = Vec::new();
+        let mut path = Vec::new();
+        for name in names {
+            self.visit(name.as_ref(), &mut path, &mut resolved)?;
+        }
+        Ok(resolved)
+    }
+
+    /// Resolves the [`Fixture`] of the provided `name` after its dependencies,
+    /// unless it has been resolved already.
+    fn visit(
+        &self,
+        name: &str,
+        path: &mut Vec<String>,
+        resolved: &mut Vec<Shared<W>>,
+    ) -> Result<(), ResolveError> {
+        if resolved.iter().any(|f| f.name() == name) {
+            return Ok(());
+        }
+        if let Some(pos) = path.iter().position(|n| n == name) {
+            let mut cycle = path.split_off(pos);
+            cycle.push(name.to_owned());
+            return Err(ResolveError::Cycle(cycle));
+        }
+        let fixture = self
+            .fixtures
+            .get(name)
+            .ok_or_else(|| ResolveError::Unknown(name.to_owned()))?;
+
+        path.push(name.to_owned());
+        for dep in fixture.dependencies() {
+            self.visit(dep, path, resolved)?;
+        }
+        drop(path.pop());
+
+        resolved.push(Arc::clone(fixture));
+        Ok(())
+    }
+}
+
+/// Error of resolving [`Fixture`]s required by a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub(crate) enum ResolveError {
+    /// [`Fixture`] with the given name isn't registered.
+    #[display("unknown fixture `{_0}`")]
+    Unknown(String),
+
+    /// [`Fixture`]s depend on each other cyclically.
+    #[display("cyclic fixture dependencies: {}", _0.join(" -> "))]
+    Cycle(Vec<String>),
+}
+
+#[cfg(test)]
+mod spec {
+    use super::{Error, Fixture, Registry, ResolveError};
+
+    struct Named(&'static str, &'static [&'static str]);
+
+    impl Fixture<()> for Named {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn dependencies(&self) -> &[&str] {
+            self.1
+        }
+
+        async fn setup(&self, (): &mut ()) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    fn registry(fixtures: Vec<Named>) -> Registry<()> {
+        let mut registry = Registry::default();
+        for f in fixtures {
+            registry.register(f);
+        }
+        registry
+    }
+
+    fn resolve(
+        registry: &Registry<()>,
+        names: &[&str],
+    ) -> Result<Vec<String>, ResolveError> {
+        registry.resolve(names.iter().copied()).map(|fs| {
+            fs.iter().map(|f| f.name().to_owned()).collect::<Vec<_>>()
+        })
+    }
+
+    #[test]
+    fn resolves_dependencies_first() {
+        let registry = registry(vec![
+            Named("api", &["db", "cache"]),
+            Named("cache", &["db"]),
+            Named("db", &[]),
+            Named("mail", &[]),
+        ]);
+
+        assert_eq!(
+            resolve(&registry, &["mail", "api", "db"]),
+            Ok(vec![
+                "mail".into(),
+                "db".into(),
+                "cache".into(),
+                "api".into()
+            ]),
+        );
+    }
+
+    #[test]
+    fn errors_on_unknown() {
+        let registry = registry(vec![Named("api", &["db"])]);
+
+        assert_eq!(
+            resolve(&registry, &["api"]),
+            Err(ResolveError::Unknown("db".into())),
+        );
+    }
+
+    #[test]
+    fn errors_on_cycle() {
+        let registry = registry(vec![
+            Named("a", &["b"]),
+            Named("b", &["c"]),
+            Named("c", &["b"]),
+        ]);
+
+        let err = resolve(&registry, &["a"]).unwrap_err();
+
+        assert_eq!(err.to_string(), "cyclic fixture dependencies: b -> c -> b",);
+    }
+}
@@ -166,13 +166,18 @@
     variant_size_differences
 )]
 
+pub mod artifacts;
+pub(crate) mod capture;
 pub mod cli;
 mod cucumber;
 pub mod event;
 pub mod feature;
+pub mod fixtures;
 pub(crate) mod future;
+pub mod mask;
 pub mod parser;
 pub mod runner;
+pub mod shared;
 pub mod step;
 pub mod tag;
 pub mod writer;
@@ -181,6 +186,8 @@ pub mod writer;
 pub mod codegen;
 #[cfg(feature = "tracing")]
 pub mod tracing;
+#[cfg(feature = "watch")]
+pub(crate) mod watch;
 
 // TODO: Remove once tests run without complains about it.
 #[cfg(test)]
@@ -191,13 +198,15 @@ mod actually_used_crates_in_doc_tests_and_book {
 }
 
 #[cfg(feature = "macros")]
-use std::{fmt::Debug, path::Path};
-use std::{fmt::Display, future::Future};
+use std::fmt::Debug;
+use std::{any::Any, fmt::Display, future::Future};
+
+use futures::future::LocalBoxFuture;
 
 #[cfg(feature = "macros")]
 use self::{
     codegen::{StepConstructor as _, WorldInventory},
-    cucumber::DefaultCucumber,
+    cucumber::{DefaultCucumber, DefaultWriter},
 };
 
 pub use gherkin;
@@ -211,10 +220,13 @@ pub use cucumber_codegen::{given, then, when, Parameter, World};
 
 #[doc(inline)]
 pub use self::{
-    cucumber::Cucumber,
+    cucumber::{
+        Cucumber, ExitCodes, FailedScenario, RunResult, ScenarioFailure,
+    },
     event::Event,
     parser::Parser,
     runner::{Runner, ScenarioType},
+    shared::State as SharedState,
     step::Step,
     writer::{
         Arbitrary as ArbitraryWriter, Ext as WriterExt, Stats as StatsWriter,
@@ -225,14 +237,14 @@ pub use self::{
 /// Represents a shared user-defined state for a [Cucumber] run.
 /// It lives on per-[scenario][0] basis.
 ///
-/// This crate doesn't provide out-of-box solution for managing state shared
-/// across [scenarios][0], because we want some friction there to avoid tests
-/// being dependent on each other. If your workflow needs a way to share state
-/// between [scenarios][0] (ex. database connection pool), we recommend using
-/// a [`std::sync::LazyLock`] or organize it other way via [shared state][1].
+/// State shared across [scenarios][0] is deliberately kept out of a [`World`],
+/// to avoid tests being dependent on each other. If your workflow needs a way
+/// to share state between [scenarios][0] (ex. database connection pool), use
+/// the [`shared`] module facilities: fill a [`SharedState`] in
+/// [`runner::Basic::before_all()`] or [`runner::Basic::before_feature()`]
+/// hooks, and read it in steps via [`step::Context::shared`].
 ///
 /// [0]: https://cucumber.io/docs/gherkin/reference#descriptions
-/// [1]: https://doc.rust-lang.org/book/ch16-03-shared-state.html
 /// [Cucumber]: https://cucumber.io
 pub trait World: Sized + 'static {
     /// Error of creating a new [`World`] instance.
@@ -241,6 +253,87 @@ pub trait World: Sized + 'static {
     /// Creates a new [`World`] instance.
     fn new() -> impl Future<Output = Result<Self, Self::Error>>;
 
+    /// Creates a new [`World`] instance from the custom CLI options, set via
+    /// [`Cucumber::custom_cli()`].
+    ///
+    /// Default implementation ignores the provided `cli` and just calls
+    /// [`World::new()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::{any::Any, convert::Infallible};
+    /// #
+    /// # use cucumber::{cli, World};
+    /// #
+    /// #[derive(cli::Args, Clone)]
+    /// struct CustomCli {
+    ///     /// URL of the tested application.
+    ///     #[arg(long, default_value = "http://localhost")]
+    ///     target: String,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct MyWorld {
+    ///     target: String,
+    /// }
+    ///
+    /// impl World for MyWorld {
+    ///     type Error = Infallible;
+    ///
+    ///     async fn new() -> Result<Self, Infallible> {
+    ///         Ok(Self {
+    ///             target: "http://localhost".into(),
+    ///         })
+    ///     }
+    ///
+    ///     async fn from_cli(
+    ///         cli: &(dyn Any + Send + Sync),
+    ///     ) -> Result<Self, Infallible> {
+    ///         match cli.downcast_ref::<CustomCli>() {
+    ///             Some(cli) => Ok(Self {
+    ///                 target: cli.target.clone(),
+    ///             }),
+    ///             None => Self::new().await,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Cucumber::custom_cli()`]: crate::Cucumber::custom_cli
+    fn from_cli(
+        cli: &(dyn Any + Send + Sync),
+    ) -> impl Future<Output = Result<Self, Self::Error>> {
+        _ = cli;
+        Self::new()
+    }
+
+    /// Returns a [`WorldSnapshot`] of this [`World`], if it provides one.
+    ///
+    /// Writers use it instead of a full [`Debug`] output of this [`World`]
+    /// when [`Verbosity::ShowWorld`] is enabled.
+    ///
+    /// Default implementation returns [`None`].
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    /// [`Verbosity::ShowWorld`]: writer::Verbosity::ShowWorld
+    fn as_snapshot(&self) -> Option<&dyn WorldSnapshot> {
+        None
+    }
+
+    /// Returns a [`Future`] of [`WorldTeardown::teardown()`] of this
+    /// [`World`], if it implements one.
+    ///
+    /// [`Runner`]s execute it once a [scenario][0] finishes, even if it has
+    /// failed.
+    ///
+    /// Default implementation returns [`None`].
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference#example
+    fn as_teardown(&mut self) -> Option<LocalBoxFuture<'_, ()>> {
+        None
+    }
+
     #[cfg(feature = "macros")]
     /// Returns runner for tests with auto-wired steps marked by [`given`],
     /// [`when`] and [`then`] attributes.
@@ -266,19 +359,97 @@ pub trait World: Sized + 'static {
             out = out.then(Some(loc), regex(), fun);
         }
 
-        out
+        Self::generic_steps(Self::nested_steps(out))
     }
 
     #[cfg(feature = "macros")]
     /// Returns default [`Cucumber`] with all the auto-wired [`Step`]s.
     #[must_use]
-    fn cucumber<I: AsRef<Path>>() -> DefaultCucumber<Self, I>
+    fn cucumber<I>() -> DefaultCucumber<Self, I>
     where
         Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
     {
         Cucumber::new().steps(Self::collection())
     }
 
+    #[cfg(feature = "macros")]
+    /// Returns default [`Cucumber`] with all the auto-wired [`Step`]s, but
+    /// outputting via the provided [`Writer`] instead of the default one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{writer, World, WriterExt as _};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber_with_writer(
+    ///     writer::Basic::stdout().summarized().normalized(),
+    /// )
+    /// .run_and_exit("tests/features/readme")
+    /// .await;
+    /// # }
+    /// ```
+    #[must_use]
+    fn cucumber_with_writer<I, Wr>(
+        writer: Wr,
+    ) -> Cucumber<Self, parser::Basic, I, runner::Basic<Self>, Wr>
+    where
+        Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
+        Wr: Writer<Self>,
+    {
+        Self::cucumber().with_writer(writer)
+    }
+
+    #[cfg(feature = "macros")]
+    /// Returns default [`Cucumber`] running the provided [`runner::Basic`]
+    /// instead of the default one, with all the auto-wired [`Step`]s set to it.
+    ///
+    /// __NOTE__: [`Step`]s already set to the provided [`runner::Basic`] are
+    ///           replaced with the auto-wired ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{runner, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber_with_runner(
+    ///     runner::Basic::default()
+    ///         .max_concurrent_scenarios(1)
+    ///         .fail_fast(),
+    /// )
+    /// .run_and_exit("tests/features/readme")
+    /// .await;
+    /// # }
+    /// ```
+    #[must_use]
+    fn cucumber_with_runner<I, Which, Before, After, Spawn>(
+        runner: runner::Basic<Self, Which, Before, After, Spawn>,
+    ) -> Cucumber<
+        Self,
+        parser::Basic,
+        I,
+        runner::Basic<Self, Which, Before, After, Spawn>,
+        DefaultWriter<Self>,
+    >
+    where
+        Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
+        runner::Basic<Self, Which, Before, After, Spawn>: Runner<Self>,
+    {
+        Cucumber::new().with_runner(runner.steps(Self::collection()))
+    }
+
     #[cfg(feature = "macros")]
     /// Runs [`Cucumber`].
     ///
@@ -291,9 +462,10 @@ pub trait World: Sized + 'static {
     /// [`Step`] panicked.
     ///
     /// [`Feature`]: gherkin::Feature
-    fn run<I: AsRef<Path>>(input: I) -> impl Future<Output = ()>
+    fn run<I>(input: I) -> impl Future<Output = ()>
     where
         Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
     {
         Self::cucumber().run_and_exit(input)
     }
@@ -315,7 +487,7 @@ pub trait World: Sized + 'static {
     fn filter_run<I, F>(input: I, filter: F) -> impl Future<Output = ()>
     where
         Self: Debug + WorldInventory,
-        I: AsRef<Path>,
+        parser::Basic: Parser<I>,
         F: Fn(
                 &gherkin::Feature,
                 Option<&gherkin::Rule>,
@@ -325,4 +497,166 @@ pub trait World: Sized + 'static {
     {
         Self::cucumber().filter_run_and_exit(input, filter)
     }
+
+    #[cfg(feature = "macros")]
+    /// Runs [`Cucumber`] and returns the [`RunResult`] of the execution,
+    /// without panicking or terminating the process on failures.
+    ///
+    /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
+    /// later produces events handled by [`Writer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let result = MyWorld::try_run("tests/features/readme").await;
+    ///
+    /// assert!(!result.execution_has_failed());
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn try_run<I>(input: I) -> impl Future<Output = RunResult>
+    where
+        Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
+    {
+        Self::cucumber().run_with_result(input)
+    }
+
+    #[cfg(feature = "macros")]
+    /// Runs [`Cucumber`] configured by the provided `configure` function
+    /// (applied to the [default one][`World::cucumber()`]) and returns the
+    /// [`RunResult`] of the execution, without panicking or terminating the
+    /// process on failures.
+    ///
+    /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
+    /// later produces events handled by [`Writer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let result = MyWorld::run_with("tests/features/readme", |c| {
+    ///     c.max_concurrent_scenarios(1).fail_fast()
+    /// })
+    /// .await;
+    ///
+    /// assert!(!result.execution_has_failed());
+    /// # }
+    /// ```
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn run_with<I, P, R, Wr, Cli, F>(
+        input: I,
+        configure: F,
+    ) -> impl Future<Output = RunResult>
+    where
+        Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
+        P: Parser<I>,
+        R: Runner<Self>,
+        Wr: writer::Stats<Self> + writer::Normalized,
+        Cli: clap::Args,
+        F: FnOnce(DefaultCucumber<Self, I>) -> Cucumber<Self, P, I, R, Wr, Cli>,
+    {
+        configure(Self::cucumber()).run_with_result(input)
+    }
+}
+
+/// Asynchronous teardown of a [`World`] (like rolling back a database
+/// transaction), which cannot be done in a [`Drop`] implementation.
+///
+/// To be executed by [`Runner`]s, it should be returned from the
+/// [`World::as_teardown()`] method (or via `#[world(teardown)]` attribute of
+/// `#[derive(World)]` macro). [`runner::Basic`] executes it right after the
+/// [`After` hook][1] of every [scenario][0], even if the [scenario][0] (or the
+/// [`After` hook][1] itself) has failed, and reports its panics as the
+/// [`After` hook][1] failures.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{World, WorldTeardown};
+/// #
+/// #[derive(Debug, Default, World)]
+/// #[world(teardown)]
+/// struct MyWorld {
+///     transaction: Option<String>,
+/// }
+///
+/// impl WorldTeardown for MyWorld {
+///     async fn teardown(&mut self) {
+///         if let Some(tx) = self.transaction.take() {
+///             // tx.rollback().await
+///             drop(tx);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference#example
+/// [1]: https://cucumber.io/docs/cucumber/api#after
+pub trait WorldTeardown {
+    /// Tears down this [`World`] once its [scenario][0] finishes.
+    ///
+    /// [0]: https://cucumber.io/docs/gherkin/reference#example
+    fn teardown(&mut self) -> impl Future<Output = ()>;
+}
+
+/// Focused representation of a [`World`] state, shown by writers on failures
+/// instead of its full [`Debug`] output.
+///
+/// To be used by writers, it should be returned from the
+/// [`World::as_snapshot()`] method (or via `#[world(snapshot)]` attribute of
+/// `#[derive(World)]` macro).
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{gherkin, World, WorldSnapshot};
+/// #
+/// #[derive(Debug, Default, World)]
+/// #[world(snapshot)]
+/// struct MyWorld {
+///     user: Option<String>,
+///     cart: Vec<String>,
+///     // ...hundreds of lines of other state...
+/// }
+///
+/// impl WorldSnapshot for MyWorld {
+///     fn snapshot(&self, step: Option<&gherkin::Step>) -> String {
+///         match step {
+///             Some(s) if s.value.contains("cart") => {
+///                 format!("cart: {:?}", self.cart)
+///             }
+///             _ => format!("user: {:?}", self.user),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Debug`]: std::fmt::Debug
+pub trait WorldSnapshot {
+    /// Returns a snapshot of this [`World`] relevant to the provided failed
+    /// [`Step`].
+    ///
+    /// [`None`] is provided for failures not caused by a [`Step`] (like
+    /// hooks).
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn snapshot(&self, step: Option<&gherkin::Step>) -> String;
 }
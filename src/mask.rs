@@ -0,0 +1,404 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Masking of secrets (API keys, tokens, passwords, etc.) in [`Cucumber`]
+//! events before any [`Writer`] sees them.
+//!
+//! Registered via [`Cucumber::mask_secret()`], [`Secret`]s are replaced with
+//! [`MASK`] in [`Step`] texts (along with their doc strings and data tables),
+//! [`event::Scenario::Log`]s, [`event::Scenario::Output`]s, panic payloads and
+//! [`World`] outputs.
+//!
+//! [`Cucumber`]: event::Cucumber
+//! [`Cucumber::mask_secret()`]: crate::Cucumber::mask_secret
+//! [`Step`]: gherkin::Step
+//! [`World`]: crate::World
+//! [`Writer`]: crate::Writer
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use regex::{CaptureLocations, Regex};
+
+use crate::{
+    event::{self, Source},
+    writer::basic::coerce_error,
+};
+
+/// Replacement of every masked [`Secret`].
+pub const MASK: &str = "***";
+
+/// Secret to be masked.
+#[derive(Clone, Debug)]
+pub enum Secret {
+    /// Literal value to be masked.
+    Literal(String),
+
+    /// [`Regex`] matches of which should be masked.
+    Regex(Regex),
+}
+
+impl From<&str> for Secret {
+    fn from(s: &str) -> Self {
+        Self::Literal(s.to_owned())
+    }
+}
+
+impl From<String> for Secret {
+    fn from(s: String) -> Self {
+        Self::Literal(s)
+    }
+}
+
+impl From<Regex> for Secret {
+    fn from(re: Regex) -> Self {
+        Self::Regex(re)
+    }
+}
+
+/// Collection of [`Secret`]s to be masked.
+#[derive(Clone, Debug, Default)]
+pub struct Secrets(Vec<Secret>);
+
+impl Secrets {
+    /// Creates new empty [`Secrets`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the provided [`Secret`] to these [`Secrets`].
+    ///
+    /// Empty [`Secret::Literal`]s are ignored.
+    #[must_use]
+    pub fn with(mut self, secret: impl Into<Secret>) -> Self {
+        let secret = secret.into();
+        if !matches!(&secret, Secret::Literal(s) if s.is_empty()) {
+            self.0.push(secret);
+        }
+        self
+    }
+
+    /// Indicates whether there are no [`Secret`]s to be masked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Replaces all the [`Secret`]s in the provided `text` with [`MASK`].
+    #[must_use]
+    pub fn mask<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.0.iter().fold(Cow::Borrowed(text), |acc, secret| {
+            let masked = match secret {
+                Secret::Literal(s) => {
+                    if !acc.contains(s.as_str()) {
+                        return acc;
+                    }
+                    acc.replace(s.as_str(), MASK)
+                }
+                Secret::Regex(re) => match re.replace_all(&acc, MASK) {
+                    Cow::Borrowed(_) => return acc,
+                    Cow::Owned(s) => s,
+                },
+            };
+            Cow::Owned(masked)
+        })
+    }
+
+    /// Registers these [`Secrets`] to be masked in [`World`] outputs by all the
+    /// [`Writer`]s of this process.
+    ///
+    /// [`World`]: crate::World
+    /// [`Writer`]: crate::Writer
+    pub(crate) fn register(&self) {
+        if self.is_empty() {
+            return;
+        }
+        REGISTERED
+            .write()
+            .unwrap_or_else(|e| panic!("Failed to register secrets: {e}"))
+            .0
+            .extend(self.0.iter().cloned());
+    }
+}
+
+/// [`Secrets`] registered to be masked in [`World`] outputs.
+///
+/// [`World`]: crate::World
+static REGISTERED: LazyLock<RwLock<Secrets>> = LazyLock::new(RwLock::default);
+
+/// Replaces all the registered [`Secrets`] in the provided `text` with
+/// [`MASK`].
+pub(crate) fn mask_registered(text: String) -> String {
+    let secrets = REGISTERED
+        .read()
+        .unwrap_or_else(|e| panic!("Failed to read secrets: {e}"));
+    match secrets.mask(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(masked) => masked,
+    }
+}
+
+/// Masker of [`Secrets`] in [`event::Cucumber`]s.
+///
+/// Keeps track of the masked [`gherkin::Step`]s, so the same masked [`Source`]
+/// is used for all the events of the same [`gherkin::Step`].
+#[derive(Debug)]
+pub(crate) struct Masker {
+    /// [`Secrets`] to be masked.
+    secrets: Secrets,
+
+    /// Already masked [`gherkin::Step`]s, if they required masking.
+    steps: HashMap<Source<gherkin::Step>, Option<Source<gherkin::Step>>>,
+}
+
+impl Masker {
+    /// Creates a new [`Masker`] of the provided [`Secrets`].
+    pub(crate) fn new(secrets: Secrets) -> Self {
+        Self {
+            secrets,
+            steps: HashMap::new(),
+        }
+    }
+
+    /// Masks [`Secrets`] in the provided [`event::Cucumber`].
+    pub(crate) fn mask<W>(
+        &mut self,
+        ev: event::Cucumber<W>,
+    ) -> event::Cucumber<W> {
+        use event::{Cucumber, Feature, Rule};
+
+        if self.secrets.is_empty() {
+            return ev;
+        }
+        match ev {
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+                let ev = self.scenario(ev);
+                Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev)))
+            }
+            Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                Cucumber::Feature(f, Feature::Scenario(sc, self.scenario(ev)))
+            }
+            Cucumber::Started
+            | Cucumber::Feature(..)
+            | Cucumber::ParsingFinished { .. }
+            | Cucumber::DuplicateName(_)
+            | Cucumber::Finished { .. } => ev,
+        }
+    }
+
+    /// Masks [`Secrets`] in the provided [`event::RetryableScenario`].
+    fn scenario<W>(
+        &mut self,
+        ev: event::RetryableScenario<W>,
+    ) -> event::RetryableScenario<W> {
+        use event::{Hook, Scenario};
+
+        let event::RetryableScenario { event, retries } = ev;
+        let event = match event {
+            Scenario::Hook(ty, Hook::Failed(w, info)) => {
+                Scenario::Hook(ty, Hook::Failed(w, self.info(info)))
+            }
+            Scenario::Hook(ty, Hook::Ignored(info)) => {
+                Scenario::Hook(ty, Hook::Ignored(self.info(info)))
+            }
+            Scenario::Background(st, st_ev) => {
+                let (st, st_ev) = self.step(st, st_ev);
+                Scenario::Background(st, st_ev)
+            }
+            Scenario::RuleBackground(st, st_ev) => {
+                let (st, st_ev) = self.step(st, st_ev);
+                Scenario::RuleBackground(st, st_ev)
+            }
+            Scenario::Step(st, st_ev) => {
+                let (st, st_ev) = self.step(st, st_ev);
+                Scenario::Step(st, st_ev)
+            }
+            Scenario::Log(log) => Scenario::Log(self.log(log)),
+            Scenario::Output(out) => Scenario::Output(event::Output {
+                stdout: self.secrets.mask(&out.stdout).into_owned(),
+                stderr: self.secrets.mask(&out.stderr).into_owned(),
+            }),
+            Scenario::Stalled(stall) => Scenario::Stalled(event::Stall {
+                world: stall.world.map(|w| self.secrets.mask(&w).into_owned()),
+                ..stall
+            }),
+            other @ (Scenario::Started(_)
+            | Scenario::Hook(..)
+            | Scenario::Artifact(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_)
+            | Scenario::NotRun
+            | Scenario::Finished) => other,
+        };
+        event::RetryableScenario { event, retries }
+    }
+
+    /// Masks [`Secrets`] in the provided [`gherkin::Step`] and its
+    /// [`event::Step`].
+    fn step<W>(
+        &mut self,
+        step: Source<gherkin::Step>,
+        ev: event::Step<W>,
+    ) -> (Source<gherkin::Step>, event::Step<W>) {
+        use event::Step;
+
+        let masked = self
+            .steps
+            .entry(step.clone())
+            .or_insert_with(|| mask_step(&self.secrets, &step))
+            .clone();
+        let Some(masked) = masked else {
+            let ev = match ev {
+                Step::Failed(c, l, w, err, d) => {
+                    Step::Failed(c, l, w, self.error(err), d)
+                }
+                ev @ (Step::Started | Step::Skipped(_) | Step::Passed(..)) => {
+                    ev
+                }
+            };
+            return (step, ev);
+        };
+
+        // Captures point into the original `Step` text, so cannot be used for
+        // the masked one.
+        let ev = match ev {
+            Step::Passed(_, loc, dur) => Step::Passed(no_captures(), loc, dur),
+            Step::Failed(c, loc, w, err, dur) => Step::Failed(
+                c.map(|_| no_captures()),
+                loc,
+                w,
+                self.error(err),
+                dur,
+            ),
+            ev @ (Step::Started | Step::Skipped(_)) => ev,
+        };
+        (masked, ev)
+    }
+
+    /// Masks [`Secrets`] in the provided [`event::Log`] message and its
+    /// textual fields.
+    fn log(&self, log: event::Log) -> event::Log {
+        let fields = log
+            .fields
+            .into_iter()
+            .map(|(name, val)| match val {
+                event::LogValue::Str(s) => {
+                    (name, self.secrets.mask(&s).into_owned().into())
+                }
+                val @ (event::LogValue::Bool(_)
+                | event::LogValue::I64(_)
+                | event::LogValue::U64(_)
+                | event::LogValue::F64(_)) => (name, val),
+            })
+            .collect();
+        event::Log {
+            message: self.secrets.mask(&log.message).into_owned(),
+            fields,
+        }
+    }
+
+    /// Masks [`Secrets`] in the panic payload of the provided
+    /// [`event::StepError`].
+    fn error(&self, err: event::StepError) -> event::StepError {
+        match err {
+            event::StepError::Panic(info, bt) => {
+                event::StepError::Panic(self.info(info), bt)
+            }
+            event::StepError::Categorized(category, e) => {
+                event::StepError::Categorized(
+                    category,
+                    Box::new(self.error(*e)),
+                )
+            }
+            e @ (event::StepError::NotFound
+            | event::StepError::AmbiguousMatch(_)
+            | event::StepError::KeywordMismatch(_)) => e,
+        }
+    }
+
+    /// Masks [`Secrets`] in the provided panic payload.
+    fn info(&self, info: event::Info) -> event::Info {
+        let msg = coerce_error(&info);
+        match self.secrets.mask(&msg) {
+            Cow::Borrowed(_) => info,
+            Cow::Owned(masked) => Arc::new(masked),
+        }
+    }
+}
+
+/// Masks [`Secrets`] in the provided [`gherkin::Step`], returning [`None`] if
+/// it doesn't contain any.
+fn mask_step(
+    secrets: &Secrets,
+    step: &gherkin::Step,
+) -> Option<Source<gherkin::Step>> {
+    let mut changed = false;
+    let mut mask = |s: &str| {
+        let masked = secrets.mask(s);
+        changed |= matches!(masked, Cow::Owned(_));
+        masked.into_owned()
+    };
+
+    let masked = gherkin::Step {
+        value: mask(&step.value),
+        docstring: step.docstring.as_deref().map(&mut mask),
+        table: step.table.as_ref().map(|t| gherkin::Table {
+            rows: t
+                .rows
+                .iter()
+                .map(|row| row.iter().map(|c| mask(c)).collect())
+                .collect(),
+            ..t.clone()
+        }),
+        ..step.clone()
+    };
+    changed.then(|| Source::new(masked))
+}
+
+/// Returns empty [`CaptureLocations`].
+fn no_captures() -> CaptureLocations {
+    #[expect(clippy::trivial_regex, reason = "no captures required")]
+    Regex::new("")
+        .unwrap_or_else(|e| unreachable!("empty `Regex` is valid: {e}"))
+        .capture_locations()
+}
+
+#[cfg(test)]
+mod spec {
+    use regex::Regex;
+
+    use super::{Secrets, MASK};
+
+    #[test]
+    fn masks_literals_and_regexes() {
+        let secrets = Secrets::default()
+            .with("s3cr3t")
+            .with(Regex::new(r"token-\d+").unwrap());
+
+        assert_eq!(
+            secrets.mask("key s3cr3t and token-42, token-7"),
+            format!("key {MASK} and {MASK}, {MASK}"),
+        );
+    }
+
+    #[test]
+    fn borrows_unchanged() {
+        let secrets = Secrets::default().with("s3cr3t").with("");
+
+        assert!(matches!(
+            secrets.mask("nothing here"),
+            super::Cow::Borrowed(_)
+        ));
+    }
+}
@@ -12,8 +12,12 @@
 
 use std::{
     borrow::Cow,
+    collections::HashSet,
+    ffi::OsString,
+    fs, iter,
     path::{Path, PathBuf},
     str::FromStr,
+    sync::Arc,
     vec,
 };
 
@@ -23,9 +27,14 @@ use gherkin::GherkinEnv;
 use globwalk::{GlobWalker, GlobWalkerBuilder};
 use itertools::Itertools as _;
 
-use crate::feature::Ext as _;
+use crate::{
+    feature::{ExpandOptions, Ext as _, Interpolation},
+    tag,
+};
 
-use super::{Error as ParseError, Parser};
+use super::{
+    join_continued_lines, Error as ParseError, Filter, Parser, ParsingError,
+};
 
 /// CLI options of a [`Basic`] [`Parser`].
 #[derive(clap::Args, Clone, Debug, Default)]
@@ -38,6 +47,7 @@ pub struct Cli {
         long = "input",
         short = 'i',
         value_name = "glob",
+        env = "CUCUMBER_INPUT",
         global = true
     )]
     pub features: Option<Walker>,
@@ -53,94 +63,187 @@ pub struct Basic {
     ///
     /// Default is English.
     language: Option<Cow<'static, str>>,
+
+    /// [`ExpandOptions`] of [`Scenario Outline`][1]s.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    expand_options: ExpandOptions,
+
+    /// Optional [`Interpolation`] of placeholders in [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    interpolation: Option<Interpolation>,
+
+    /// Indicator whether lines ending with a `\\` are joined with the
+    /// following ones before parsing.
+    ///
+    /// See [`join_continued_lines()`] for details.
+    line_continuations: bool,
 }
 
-impl<I: AsRef<Path>> Parser<I> for Basic {
+impl<I: IntoInputs> Parser<I> for Basic {
     type Cli = Cli;
 
     type Output =
         stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
 
     fn parse(self, input: I, cli: Self::Cli) -> Self::Output {
-        let walk = |walker: GlobWalker| {
-            walker
-                .filter_map(Result::ok)
-                .sorted_by(|l, r| Ord::cmp(l.path(), r.path()))
-                .map(|file| {
-                    let env = self
-                        .language
-                        .as_ref()
-                        .and_then(|l| GherkinEnv::new(l).ok())
-                        .unwrap_or_default();
-                    gherkin::Feature::parse_path(file.path(), env)
-                })
-                .collect::<Vec<_>>()
-        };
-
-        let get_features_path = || {
-            let path = input.as_ref();
-            path.canonicalize()
-                .or_else(|_| {
-                    let buf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(
-                        path.strip_prefix("/")
-                            .or_else(|_| path.strip_prefix("./"))
-                            .unwrap_or(path),
-                    );
-                    buf.as_path().canonicalize()
-                })
-                .map_err(|e| gherkin::ParseFileError::Reading {
-                    path: path.to_path_buf(),
-                    source: e,
-                })
-        };
+        self.parse_inputs(&input.into_inputs(), &cli, &Filter::default())
+    }
+
+    fn parse_with_tags(
+        self,
+        input: I,
+        cli: Self::Cli,
+        tags: &tag::Expr,
+    ) -> Self::Output {
+        self.parse_inputs(&input.into_inputs(), &cli, &Filter::by_tags(tags))
+    }
 
-        let features = || {
-            let features = if let Some(walker) = cli.features {
-                walk(globwalk::glob(walker.0).unwrap_or_else(|e| {
+    fn parse_filtered(
+        self,
+        input: I,
+        cli: Self::Cli,
+        filter: &Filter,
+    ) -> Self::Output {
+        self.parse_inputs(&input.into_inputs(), &cli, filter)
+    }
+}
+
+/// Collects paths of all the files to be parsed for the provided [`Inputs`]
+/// and [`Cli`] options.
+pub(crate) fn files(
+    input: &Inputs,
+    cli: &Cli,
+) -> Vec<Result<PathBuf, gherkin::ParseFileError>> {
+    cli.features.as_ref().map_or_else(
+        || input.0.iter().flat_map(|root| root_files(root)).collect(),
+        |walker| {
+            walk(
+                globwalk::glob(&walker.0).unwrap_or_else(|e| {
                     unreachable!("invalid glob pattern: {e}")
-                }))
-            } else {
-                let feats_path = match get_features_path() {
-                    Ok(p) => p,
-                    Err(e) => return vec![Err(e.into())],
-                };
-
-                if feats_path.is_file() {
-                    let env = self
-                        .language
-                        .as_ref()
-                        .and_then(|l| GherkinEnv::new(l).ok())
-                        .unwrap_or_default();
-                    vec![gherkin::Feature::parse_path(feats_path, env)]
-                } else {
-                    let w = GlobWalkerBuilder::new(feats_path, "*.feature")
-                        .case_insensitive(true)
-                        .build()
-                        .unwrap_or_else(|e| {
-                            unreachable!("`GlobWalkerBuilder` panicked: {e}")
-                        });
-                    walk(w)
-                }
-            };
+                }),
+            )
+        },
+    )
+}
 
-            features
-                .into_iter()
-                .map(|f| match f {
-                    Ok(f) => f.expand_examples().map_err(ParseError::from),
-                    Err(e) => Err(e.into()),
-                })
-                .collect()
-        };
+/// Collects root directories (or files) of the provided [`Inputs`] and [`Cli`]
+/// options, containing all the files to be parsed.
+#[cfg(feature = "watch")]
+pub(crate) fn roots(input: &Inputs, cli: &Cli) -> Vec<PathBuf> {
+    cli.features
+        .as_ref()
+        .map_or_else(
+            || input.0.clone(),
+            |walker| vec![PathBuf::from(&walker.0)],
+        )
+        .into_iter()
+        .filter_map(|root| resolve_path(&split_glob(&root).0).ok())
+        .collect()
+}
 
-        stream::iter(features())
+/// Collects paths of all the files yielded by the provided [`GlobWalker`],
+/// sorted by path.
+fn walk(walker: GlobWalker) -> Vec<Result<PathBuf, gherkin::ParseFileError>> {
+    walker
+        .filter_map(Result::ok)
+        .sorted_by(|l, r| Ord::cmp(l.path(), r.path()))
+        .map(|file| Ok(file.into_path()))
+        .collect()
+}
+
+/// Collects paths of all the `.feature` files of the provided `root`, sorted
+/// by path.
+///
+/// The `root` may be a path to a single file, a directory to look for
+/// `*.feature`s in (recursively), or a glob pattern (like
+/// `features/**/smoke_*.feature`).
+fn root_files(root: &Path) -> Vec<Result<PathBuf, gherkin::ParseFileError>> {
+    let (base, pattern) = split_glob(root);
+
+    let base = match resolve_path(&base) {
+        Ok(p) => p,
+        Err(e) => return vec![Err(e)],
+    };
+
+    if let Some(pattern) = pattern {
+        let w = GlobWalkerBuilder::new(base, pattern)
+            .build()
+            .unwrap_or_else(|e| {
+                unreachable!("`GlobWalkerBuilder` panicked: {e}")
+            });
+        walk(w)
+    } else if base.is_file() {
+        vec![Ok(base)]
+    } else {
+        let w = GlobWalkerBuilder::new(base, "*.feature")
+            .case_insensitive(true)
+            .build()
+            .unwrap_or_else(|e| {
+                unreachable!("`GlobWalkerBuilder` panicked: {e}")
+            });
+        walk(w)
     }
 }
 
+/// Splits the provided `path` into a base directory and an optional glob
+/// pattern, starting from the first [`Component`] containing glob
+/// metacharacters.
+///
+/// [`Component`]: std::path::Component
+fn split_glob(path: &Path) -> (PathBuf, Option<String>) {
+    let is_glob = |c: &Path| c.to_string_lossy().contains(['*', '?', '[', '{']);
+
+    let mut base = PathBuf::new();
+    let mut components = path.components();
+    while let Some(c) = components.next() {
+        if is_glob(c.as_ref()) {
+            let pattern = iter::once(c)
+                .chain(components)
+                .map(|rest| rest.as_os_str().to_string_lossy())
+                .join("/");
+            if base.as_os_str().is_empty() {
+                base.push(".");
+            }
+            return (base, Some(pattern));
+        }
+        base.push(c);
+    }
+    (base, None)
+}
+
+/// Canonicalizes the provided `path`, falling back to resolving it relatively
+/// to the `CARGO_MANIFEST_DIR`.
+fn resolve_path(path: &Path) -> Result<PathBuf, gherkin::ParseFileError> {
+    path.canonicalize()
+        .or_else(|_| {
+            let buf = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(
+                path.strip_prefix("/")
+                    .or_else(|_| path.strip_prefix("./"))
+                    .unwrap_or(path),
+            );
+            buf.as_path().canonicalize()
+        })
+        .map_err(|e| gherkin::ParseFileError::Reading {
+            path: path.to_path_buf(),
+            source: e,
+        })
+}
+
 impl Basic {
     /// Creates a new [`Basic`] [`Parser`].
     #[must_use]
     pub const fn new() -> Self {
-        Self { language: None }
+        Self {
+            language: None,
+            expand_options: ExpandOptions {
+                substitute_step_arguments: true,
+                expansion: None,
+            },
+            interpolation: None,
+            line_continuations: false,
+        }
     }
 
     /// Sets the provided language to parse [`gherkin`] files with instead of
@@ -160,6 +263,162 @@ impl Basic {
         self.language = Some(name);
         Ok(self)
     }
+
+    /// Sets whether `<placeholders>` of [`Scenario Outline`][1]s should be
+    /// substituted inside doc strings and data tables of [`Step`]s, not only in
+    /// their text.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub const fn substitute_step_arguments(mut self, enabled: bool) -> Self {
+        self.expand_options.substitute_step_arguments = enabled;
+        self
+    }
+
+    /// Sets the function transforming [`Examples`] of
+    /// [`Scenario Outline`][1]s before they're expanded row by row into
+    /// separate [`Scenario`]s.
+    ///
+    /// See [`ExamplesExpansionFn`] for details.
+    ///
+    /// [`ExamplesExpansionFn`]: crate::feature::ExamplesExpansionFn
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn example_expansion<F>(mut self, func: F) -> Self
+    where
+        F: Fn(
+                Option<&Path>,
+                &gherkin::Scenario,
+                gherkin::Examples,
+            ) -> gherkin::Examples
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.expand_options.expansion = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets the [`Interpolation`] of `${ENV_VAR}` and `{{config.key}}`
+    /// placeholders in texts, doc strings and data tables of [`Step`]s.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn interpolate(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// Sets whether lines ending with a `\\` should be joined with the
+    /// following ones before parsing, so long [`Step`]s may be wrapped in
+    /// [Gherkin] files.
+    ///
+    /// See [`join_continued_lines()`] for details.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    #[must_use]
+    pub const fn line_continuations(mut self, enabled: bool) -> Self {
+        self.line_continuations = enabled;
+        self
+    }
+
+    /// Parses the given [`Inputs`] into [`Feature`]s, omitting the
+    /// [`Scenario`]s not matching the provided `tags` expression (if any)
+    /// before expanding their [`Examples`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn parse_inputs(
+        self,
+        input: &Inputs,
+        cli: &Cli,
+        filter: &Filter,
+    ) -> <Self as Parser<Inputs>>::Output {
+        let files = files(input, cli);
+
+        let mut seen = HashSet::new();
+        let features = files
+            .into_iter()
+            .filter(|f| f.as_ref().map_or(true, |p| seen.insert(p.clone())))
+            .map(|f| {
+                let env = self
+                    .language
+                    .as_ref()
+                    .and_then(|l| GherkinEnv::new(l).ok())
+                    .unwrap_or_default();
+                f.and_then(|path| {
+                    if self.line_continuations {
+                        parse_continued(path, env)
+                    } else {
+                        gherkin::Feature::parse_path(path, env)
+                    }
+                })
+            })
+            .filter_map(|f| match f {
+                Ok(f) => filter
+                    .expand(f, &self.expand_options)
+                    .map(|feat| {
+                        feat.map(|feat| match &self.interpolation {
+                            Some(i) => feat.interpolate(i),
+                            None => feat,
+                        })
+                    })
+                    .map_err(ParseError::from)
+                    .transpose(),
+                Err(e) => {
+                    // `gherkin` doesn't expose the source it has failed to
+                    // parse, so it's re-read for diagnostics.
+                    let source = match &e {
+                        gherkin::ParseFileError::Parsing { path, .. } => {
+                            fs::read_to_string(path).ok()
+                        }
+                        gherkin::ParseFileError::Reading { .. } => None,
+                    };
+                    Some(Err(ParsingError::new(e, source.as_deref()).into()))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        stream::iter(features)
+    }
+}
+
+/// Parses a [`gherkin::Feature`] from the provided file `path`, having its
+/// lines ending with a `\\` joined with the following ones.
+///
+/// See [`join_continued_lines()`] for details.
+fn parse_continued(
+    path: PathBuf,
+    env: GherkinEnv,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    let content = fs::read_to_string(&path).map_err(|source| {
+        gherkin::ParseFileError::Reading {
+            path: path.clone(),
+            source,
+        }
+    })?;
+    let mut feature =
+        gherkin::Feature::parse(join_continued_lines(&content), env).map_err(
+            |source| gherkin::ParseFileError::Parsing {
+                path: path.clone(),
+                error: None,
+                source,
+            },
+        )?;
+    feature.path = Some(path);
+    Ok(feature)
 }
 
 /// Error of [`gherkin`] not supporting keywords in some language.
@@ -180,3 +439,112 @@ impl FromStr for Walker {
         globwalk::glob(s).map(|_| Self(s.to_owned()))
     }
 }
+
+/// Multiple inputs of a [`Basic`] [`Parser`].
+///
+/// Each input may be a path to a single `.feature` file, a directory to look
+/// for `*.feature`s in (recursively), or a glob pattern (like
+/// `features/**/smoke_*.feature`).
+///
+/// Inputs are processed in the provided order, with files of a single input
+/// being sorted by path. Files matched by several inputs are parsed only once,
+/// at the position of their first occurrence.
+///
+/// Arrays and [`Vec`]s of paths are converted into [`Inputs`] implicitly (see
+/// [`IntoInputs`]).
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::World;
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .run_and_exit([
+///         "tests/features/readme",
+///         "tests/features/readme/*.feature",
+///     ])
+///     .await;
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Inputs(Vec<PathBuf>);
+
+impl<P: AsRef<Path>, const N: usize> From<[P; N]> for Inputs {
+    fn from(paths: [P; N]) -> Self {
+        paths.into_iter().collect()
+    }
+}
+
+impl<P: AsRef<Path>> From<Vec<P>> for Inputs {
+    fn from(paths: Vec<P>) -> Self {
+        paths.into_iter().collect()
+    }
+}
+
+impl<P: AsRef<Path>> FromIterator<P> for Inputs {
+    fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+        Self(iter.into_iter().map(|p| p.as_ref().to_path_buf()).collect())
+    }
+}
+
+/// Conversion into [`Inputs`] of a [`Basic`] [`Parser`].
+///
+/// Implemented for single paths (references to anything being [`AsRef`]
+/// [`Path`], along with owned [`String`]s, [`PathBuf`]s and [`OsString`]s), and
+/// for arrays and [`Vec`]s of them.
+///
+/// __NOTE__: Cannot be implemented for everything being [`AsRef`] [`Path`]
+///           directly, as it would conflict with the implementation for
+///           arrays.
+pub trait IntoInputs {
+    /// Converts this value into [`Inputs`].
+    #[must_use]
+    fn into_inputs(self) -> Inputs;
+}
+
+impl IntoInputs for Inputs {
+    fn into_inputs(self) -> Inputs {
+        self
+    }
+}
+
+impl<P: AsRef<Path> + ?Sized> IntoInputs for &P {
+    fn into_inputs(self) -> Inputs {
+        Inputs::from([self])
+    }
+}
+
+impl IntoInputs for String {
+    fn into_inputs(self) -> Inputs {
+        Inputs::from([self])
+    }
+}
+
+impl IntoInputs for PathBuf {
+    fn into_inputs(self) -> Inputs {
+        Inputs::from([self])
+    }
+}
+
+impl IntoInputs for OsString {
+    fn into_inputs(self) -> Inputs {
+        Inputs::from([self])
+    }
+}
+
+impl<P: AsRef<Path>, const N: usize> IntoInputs for [P; N] {
+    fn into_inputs(self) -> Inputs {
+        self.into()
+    }
+}
+
+impl<P: AsRef<Path>> IntoInputs for Vec<P> {
+    fn into_inputs(self) -> Inputs {
+        self.into()
+    }
+}
@@ -0,0 +1,533 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Human-readable diagnostics of [Gherkin] parsing errors.
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{fmt, path::Path, sync::LazyLock};
+
+use derive_more::with_trait::{Display, Error as StdError};
+use itertools::Itertools as _;
+use regex::Regex;
+
+use crate::writer::basic::trim_path;
+
+/// Failed to parse a [`Feature`] file, along with the [`Diagnostic`] of the
+/// failure.
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Debug, StdError)]
+pub struct ParsingError {
+    /// Original [`gherkin`] error.
+    #[error(source)]
+    pub error: gherkin::ParseFileError,
+
+    /// [`Diagnostic`] of the [`ParsingError::error`].
+    ///
+    /// [`None`] if the source of the [`Feature`] isn't available, or the
+    /// [`ParsingError::error`] is the one of reading it.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub diagnostic: Option<Diagnostic>,
+}
+
+impl ParsingError {
+    /// Creates a new [`ParsingError`] out of the given [`gherkin`] `error`,
+    /// diagnosing it with the provided `source` (if any) of the failed
+    /// [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn new(error: gherkin::ParseFileError, source: Option<&str>) -> Self {
+        let diagnostic = source.and_then(|src| Diagnostic::new(&error, src));
+        Self { error, diagnostic }
+    }
+
+    /// Returns the position of the failure in the [`Feature`] file, if known.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn position(&self) -> Option<gherkin::LineCol> {
+        self.diagnostic
+            .as_ref()
+            .map(|d| d.pos)
+            .or_else(|| match &self.error {
+                gherkin::ParseFileError::Parsing { source, .. } => {
+                    let ((line, col), _) = reported(source);
+                    Some(gherkin::LineCol { line, col })
+                }
+                gherkin::ParseFileError::Reading { .. } => None,
+            })
+    }
+
+    /// Returns the message describing the failure, without its location.
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.diagnostic.as_ref().map_or_else(
+            || match &self.error {
+                gherkin::ParseFileError::Parsing { source, .. } => {
+                    source.to_string()
+                }
+                gherkin::ParseFileError::Reading { source, .. } => {
+                    source.to_string()
+                }
+            },
+            |d| d.message.clone(),
+        )
+    }
+
+    /// Returns the [`Path`] of the [`Feature`] file failed to be parsed.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match &self.error {
+            gherkin::ParseFileError::Reading { path, .. }
+            | gherkin::ParseFileError::Parsing { path, .. } => path,
+        }
+    }
+}
+
+impl From<gherkin::ParseFileError> for ParsingError {
+    fn from(error: gherkin::ParseFileError) -> Self {
+        Self::new(error, None)
+    }
+}
+
+impl Display for ParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(diag) = &self.diagnostic {
+            let path = self.path().to_str().map(trim_path).unwrap_or_default();
+            write!(f, "\n{}", diag.render(path))?;
+        }
+        Ok(())
+    }
+}
+
+/// Diagnostic of a [`gherkin::ParseFileError`], pointing to the offending line
+/// of the source and noting common mistakes.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("{}", self.render(""))]
+pub struct Diagnostic {
+    /// Position of the failure in the source.
+    pub pos: gherkin::LineCol,
+
+    /// Offending line of the source.
+    pub line: String,
+
+    /// Message of the failure.
+    pub message: String,
+
+    /// [`Hint`]s about the failure.
+    pub hints: Vec<Hint>,
+}
+
+impl Diagnostic {
+    /// Diagnoses the provided [`gherkin::ParseFileError`] with the `source` of
+    /// the [`Feature`] failed to be parsed.
+    ///
+    /// [`None`] is returned for [`gherkin::ParseFileError::Reading`] errors.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn new(err: &gherkin::ParseFileError, source: &str) -> Option<Self> {
+        let gherkin::ParseFileError::Parsing {
+            error,
+            source: parse_err,
+            ..
+        } = err
+        else {
+            return None;
+        };
+
+        let (reported, expected) = reported(parse_err);
+        let unknown_keyword = expected.iter().any(|t| t == "unknown keyword");
+        // Tokens, which aren't quoted literals, describe the failure itself.
+        let (literals, descriptions): (Vec<_>, Vec<_>) =
+            expected.into_iter().partition(|t| t.starts_with('"'));
+
+        let lines = source.lines().collect::<Vec<_>>();
+
+        let (line, message) = match error {
+            Some(gherkin::EnvError::UnknownKeyword(kw)) => {
+                let kw = kw.lines().next().unwrap_or_default().trim();
+                (
+                    lines.get(..reported.0.min(lines.len())).and_then(|ls| {
+                        ls.iter().rposition(|l| {
+                            !kw.is_empty() && l.trim().starts_with(kw)
+                        })
+                    }),
+                    if kw.starts_with('@') {
+                        "tags are not followed by anything to tag".into()
+                    } else {
+                        format!(
+                            "unknown keyword `{}`",
+                            kw.trim_matches('\u{feff}'),
+                        )
+                    },
+                )
+            }
+            Some(e @ gherkin::EnvError::InconsistentCellCount(_)) => {
+                (None, e.to_string().to_lowercase())
+            }
+            Some(e @ gherkin::EnvError::UnsupportedLanguage(_)) => {
+                (None, e.to_string())
+            }
+            None if !descriptions.is_empty() => (None, descriptions.join("; ")),
+            None if literals.is_empty() => (None, "unexpected input".into()),
+            None => (
+                None,
+                format!(
+                    "expected one of: {}",
+                    literals.iter().map(|t| format!("`{t}`")).join(", "),
+                ),
+            ),
+        };
+        // `gherkin` reports the position after the unknown keyword (consuming
+        // at least 2 characters of it), or may report it at the start of the
+        // next line or at the end of the input, so the closest non-empty line
+        // above is considered to be the offending one.
+        let line = line.or_else(|| {
+            (0..reported.0.min(lines.len())).rev().find(|&n| {
+                let l = lines[n];
+                let trimmed = l.trim().trim_start_matches('\u{feff}');
+                let is_candidate = !unknown_keyword
+                    || !trimmed.starts_with('#')
+                        && (n + 1 != reported.0
+                            || indent(l).chars().count() + 3 <= reported.1);
+                !trimmed.is_empty() && is_candidate
+            })
+        });
+        let pos = line.map_or(gherkin::LineCol { line: 1, col: 1 }, |n| {
+            let indent = indent(lines[n]).chars().count() + 1;
+            let col = if n + 1 == reported.0 && !unknown_keyword {
+                reported.1.max(indent)
+            } else {
+                indent
+            };
+            gherkin::LineCol { line: n + 1, col }
+        });
+        let line = line.map(|n| lines[n]).unwrap_or_default();
+
+        Some(Self {
+            pos,
+            line: line.to_owned(),
+            message,
+            hints: Hint::collect(source, &lines, pos, line),
+        })
+    }
+
+    /// Renders this [`Diagnostic`] as a `rustc`-like snippet of the source,
+    /// referring the provided `path` to it.
+    fn render(&self, path: &str) -> String {
+        let line_no = self.pos.line.to_string();
+        let pad = " ".repeat(line_no.len());
+        let line = self.line.trim_start_matches('\u{feff}');
+        let caret_pad = line
+            .chars()
+            .take(self.pos.col.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        format!(
+            "{pad}--> {path}{}{}:{}\n\
+             {pad} |\n\
+             {line_no} | {line}\n\
+             {pad} | {caret_pad}^ {}{}",
+            if path.is_empty() { "" } else { ":" },
+            self.pos.line,
+            self.pos.col,
+            self.message,
+            self.hints
+                .iter()
+                .map(|hint| format!("\n{pad} = hint: {hint}"))
+                .join(""),
+        )
+    }
+}
+
+/// Note about a common mistake, which may have caused a [`Diagnostic`]ed
+/// failure.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Hint {
+    /// Source starts with a byte order mark.
+    #[display("remove the byte order mark (BOM) from the start of the file")]
+    ByteOrderMark,
+
+    /// Offending line is indented with tabs.
+    #[display("offending line is indented with tabs, consider using spaces")]
+    TabIndentation,
+
+    /// Source doesn't start with a `Feature:` header.
+    #[display("every `.feature` file should start with a `Feature:` header")]
+    MissingFeatureHeader,
+
+    /// Unknown keyword is similar to a known one.
+    #[display("did you mean `{_0}`?")]
+    DidYouMean(&'static str),
+}
+
+impl Hint {
+    /// English [Gherkin] keywords to suggest in [`Hint::DidYouMean`].
+    ///
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    const KEYWORDS: &'static [&'static str] = &[
+        "Feature:",
+        "Rule:",
+        "Background:",
+        "Scenario:",
+        "Scenario Outline:",
+        "Examples:",
+        "Given",
+        "When",
+        "Then",
+        "And",
+        "But",
+    ];
+
+    /// Collects [`Hint`]s for the failure at the provided `pos` in the
+    /// `source` (split into `lines`), with the offending `line`.
+    fn collect(
+        source: &str,
+        lines: &[&str],
+        pos: gherkin::LineCol,
+        line: &str,
+    ) -> Vec<Self> {
+        let mut hints = vec![];
+
+        if source.starts_with('\u{feff}') {
+            hints.push(Self::ByteOrderMark);
+        }
+        if indent(line).contains('\t') {
+            hints.push(Self::TabIndentation);
+        }
+
+        let is_first = lines
+            .iter()
+            .position(|l| {
+                let l = l.trim().trim_start_matches('\u{feff}');
+                !l.is_empty() && !l.starts_with('#') && !l.starts_with('@')
+            })
+            .is_some_and(|n| n + 1 == pos.line);
+        let has_language = lines
+            .iter()
+            .any(|l| l.trim_start().starts_with("# language:"));
+        let trimmed = line.trim().trim_start_matches('\u{feff}');
+        let word = trimmed
+            .split_inclusive([':', ' '])
+            .next()
+            .unwrap_or_default()
+            .trim();
+        let suggestion = (!has_language && !word.is_empty())
+            .then(|| {
+                Self::KEYWORDS
+                    .iter()
+                    .map(|kw| (*kw, distance(word, kw)))
+                    .filter(|(kw, d)| *d > 0 && *d <= (kw.len() / 4).max(1))
+                    .min_by_key(|(_, d)| *d)
+                    .map(|(kw, _)| kw)
+            })
+            .flatten();
+
+        if let Some(kw) = suggestion {
+            hints.push(Self::DidYouMean(kw));
+        }
+        if is_first
+            && !has_language
+            && !trimmed.starts_with("Feature:")
+            && suggestion != Some("Feature:")
+        {
+            hints.push(Self::MissingFeatureHeader);
+        }
+
+        hints
+    }
+}
+
+/// Extracts the reported position (line and column) and the expected tokens
+/// from the provided [`gherkin::ParseError`], as they're not exposed otherwise
+/// than via its message.
+fn reported(err: &gherkin::ParseError) -> ((usize, usize), Vec<String>) {
+    /// [`Regex`] parsing a [`gherkin::ParseError`] message.
+    static PARSE_ERROR: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^Error at (\d+):(\d+): \{(.*)\}$").unwrap_or_else(|e| {
+            unreachable!("`PARSE_ERROR` regex is invalid: {e}")
+        })
+    });
+
+    let msg = err.to_string();
+    PARSE_ERROR.captures(&msg).map_or_else(
+        || ((1, 1), vec![]),
+        |c| {
+            let num =
+                |i| c.get(i).and_then(|m| m.as_str().parse().ok()).unwrap_or(1);
+            let expected = c
+                .get(3)
+                .map(|m| {
+                    m.as_str()
+                        .split(", ")
+                        .filter_map(|t| t.strip_prefix('"')?.strip_suffix('"'))
+                        .map(|t| t.replace("\\\"", "\"").replace("\\\\", "\\"))
+                        .sorted()
+                        .collect()
+                })
+                .unwrap_or_default();
+            ((num(1), num(2)), expected)
+        },
+    )
+}
+
+/// Returns the leading whitespace of the provided `line`.
+fn indent(line: &str) -> &str {
+    let line = line.trim_start_matches('\u{feff}');
+    line.strip_suffix(line.trim_start()).unwrap_or_default()
+}
+
+/// Calculates the [Levenshtein distance][1] between the provided strings.
+///
+/// [1]: https://en.wikipedia.org/wiki/Levenshtein_distance
+fn distance(l: &str, r: &str) -> usize {
+    let r = r.chars().collect::<Vec<_>>();
+    let mut prev = (0..=r.len()).collect::<Vec<_>>();
+    for (i, lc) in l.chars().enumerate() {
+        let mut curr = vec![i + 1];
+        for (j, rc) in r.iter().enumerate() {
+            let cost = usize::from(lc != *rc);
+            curr.push((prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1));
+        }
+        prev = curr;
+    }
+    prev[r.len()]
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::{event, parser, writer::PathStyle};
+
+    use super::{Diagnostic, Hint, ParsingError};
+
+    /// Parses the provided `source`, expecting it to fail.
+    fn fail(source: &str) -> gherkin::ParseFileError {
+        let source_err = gherkin::Feature::parse(source, Default::default())
+            .map(drop)
+            .expect_err("should fail");
+        gherkin::ParseFileError::Parsing {
+            path: "test.feature".into(),
+            error: None,
+            source: source_err,
+        }
+    }
+
+    /// Diagnoses the provided `source`, expecting it to fail.
+    fn diagnose(source: &str) -> Diagnostic {
+        Diagnostic::new(&fail(source), source).expect("should diagnose")
+    }
+
+    #[test]
+    fn missing_feature_header() {
+        let diag = diagnose("not allowed\nFeature: invalid\n");
+
+        assert_eq!(diag.pos, gherkin::LineCol { line: 1, col: 1 });
+        assert_eq!(diag.line, "not allowed");
+        assert_eq!(diag.hints, [Hint::MissingFeatureHeader]);
+
+        let diag = diagnose("# comment\n  Scenario: no feature\n    Given x\n");
+
+        assert_eq!(diag.pos, gherkin::LineCol { line: 2, col: 3 });
+        assert_eq!(diag.hints, [Hint::MissingFeatureHeader]);
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let diag = diagnose("\u{feff}Feature: BOM\n  Scenario: a\n");
+
+        assert_eq!(diag.pos, gherkin::LineCol { line: 1, col: 1 });
+        assert_eq!(diag.hints, [Hint::ByteOrderMark]);
+    }
+
+    #[test]
+    fn did_you_mean() {
+        let diag = diagnose("Featur: typo\n  Scenario: a\n");
+
+        assert_eq!(diag.hints, [Hint::DidYouMean("Feature:")]);
+    }
+
+    #[test]
+    fn tab_indentation() {
+        let diag = diagnose(
+            "Feature: tabs\n\
+             \tScenario: a\n\
+             \t\tGiven x\n\
+             \t\t| a | b |\n\
+             \t\t| c |\n",
+        );
+
+        assert_eq!(diag.pos, gherkin::LineCol { line: 5, col: 3 });
+        assert_eq!(diag.line, "\t\t| c |");
+        assert_eq!(diag.message, "inconsistent table row sizes");
+        assert_eq!(diag.hints, [Hint::TabIndentation]);
+    }
+
+    #[test]
+    fn renders_with_caret() {
+        let src = "Feature: table\n\
+                   \x20 Scenario: a\n\
+                   \x20   Given x\n\
+                   \x20     | a | b |\n\
+                   \x20     | c |\n";
+        let err = ParsingError::new(fail(src), Some(src));
+
+        assert_eq!(
+            err.to_string(),
+            "Could not parse feature file: test.feature\n \
+             --> test.feature:5:7\n  \
+             |\n\
+             5 |       | c |\n  \
+             |       ^ inconsistent table row sizes",
+        );
+    }
+
+    #[test]
+    fn converts_into_parsing_failure() {
+        let src = "Feature: table\n\
+                   \x20 Scenario: a\n\
+                   \x20   Given x\n\
+                   \x20     | a | b |\n\
+                   \x20     | c |\n";
+        let err = parser::Error::from(ParsingError::new(fail(src), Some(src)));
+        let failure = event::ParsingFailure::from(&err);
+
+        assert_eq!(
+            failure.position,
+            Some(gherkin::LineCol { line: 5, col: 7 })
+        );
+        assert_eq!(
+            failure.location(PathStyle::Trimmed).as_deref(),
+            Some("test.feature:5:7"),
+        );
+        assert_eq!(
+            failure.to_string(),
+            "test.feature:5:7: inconsistent table row sizes",
+        );
+    }
+
+    #[test]
+    fn no_diagnostic_without_source() {
+        let err = ParsingError::from(fail("not allowed\n"));
+
+        assert!(err.diagnostic.is_none(), "{err}");
+        assert_eq!(
+            err.to_string(),
+            "Could not parse feature file: test.feature"
+        );
+    }
+}
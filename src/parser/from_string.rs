@@ -0,0 +1,345 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Parser`] of in-memory [Gherkin] sources.
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::Arc,
+    vec,
+};
+
+use futures::stream;
+use gherkin::GherkinEnv;
+
+use crate::{
+    cli,
+    feature::{ExpandOptions, Ext as _, Interpolation},
+    tag,
+};
+
+use super::{
+    basic::UnsupportedLanguageError, join_continued_lines, Error as ParseError,
+    Filter, Parser, ParsingError,
+};
+
+/// [`Parser`] of [Gherkin] sources already loaded into memory.
+///
+/// Accepts anything iterable over items convertible into a [`Text`], so raw
+/// feature texts (`String`s and `&str`s) and `(name, text)` pairs may be used
+/// as an input. The `name` is set as the [`Feature::path`], so it's used in
+/// reports instead of a real file path.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::{parser, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber::<&str>() // aiding type inference
+///     .with_parser(parser::FromString::new())
+///     .run_and_exit([(
+///         "generated.feature",
+///         "Feature: Generated\n  Scenario: Empty\n",
+///     )])
+///     .await;
+/// # }
+/// ```
+///
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+/// [`Feature::path`]: gherkin::Feature::path
+#[derive(Clone, Debug, Default)]
+pub struct FromString {
+    /// Optional custom language of [`gherkin`] keywords.
+    ///
+    /// Default is English.
+    language: Option<Cow<'static, str>>,
+
+    /// [`ExpandOptions`] of [`Scenario Outline`][1]s.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    expand_options: ExpandOptions,
+
+    /// Optional [`Interpolation`] of placeholders in [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    interpolation: Option<Interpolation>,
+
+    /// Indicator whether lines ending with a `\\` are joined with the
+    /// following ones before parsing.
+    ///
+    /// See [`join_continued_lines()`] for details.
+    line_continuations: bool,
+}
+
+impl<I> Parser<I> for FromString
+where
+    I: IntoIterator,
+    I::Item: Into<Text>,
+{
+    type Cli = cli::Empty;
+
+    type Output =
+        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, input: I, _: Self::Cli) -> Self::Output {
+        self.parse_texts(input, &Filter::default())
+    }
+
+    fn parse_with_tags(
+        self,
+        input: I,
+        _: Self::Cli,
+        tags: &tag::Expr,
+    ) -> Self::Output {
+        self.parse_texts(input, &Filter::by_tags(tags))
+    }
+
+    fn parse_filtered(
+        self,
+        input: I,
+        _: Self::Cli,
+        filter: &Filter,
+    ) -> Self::Output {
+        self.parse_texts(input, filter)
+    }
+}
+
+impl FromString {
+    /// Creates a new [`FromString`] [`Parser`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            language: None,
+            expand_options: ExpandOptions {
+                substitute_step_arguments: true,
+                expansion: None,
+            },
+            interpolation: None,
+            line_continuations: false,
+        }
+    }
+
+    /// Sets the provided language to parse [`gherkin`] sources with instead of
+    /// the default one (English).
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        let name = name.into();
+        if !gherkin::is_language_supported(&name) {
+            return Err(UnsupportedLanguageError(name));
+        }
+        self.language = Some(name);
+        Ok(self)
+    }
+
+    /// Sets whether `<placeholders>` of [`Scenario Outline`][1]s should be
+    /// substituted inside doc strings and data tables of [`Step`]s, not only in
+    /// their text.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub const fn substitute_step_arguments(mut self, enabled: bool) -> Self {
+        self.expand_options.substitute_step_arguments = enabled;
+        self
+    }
+
+    /// Sets the function transforming [`Examples`] of
+    /// [`Scenario Outline`][1]s before they're expanded row by row into
+    /// separate [`Scenario`]s.
+    ///
+    /// See [`ExamplesExpansionFn`] for details.
+    ///
+    /// [`ExamplesExpansionFn`]: crate::feature::ExamplesExpansionFn
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn example_expansion<F>(mut self, func: F) -> Self
+    where
+        F: Fn(
+                Option<&Path>,
+                &gherkin::Scenario,
+                gherkin::Examples,
+            ) -> gherkin::Examples
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.expand_options.expansion = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets the [`Interpolation`] of `${ENV_VAR}` and `{{config.key}}`
+    /// placeholders in texts, doc strings and data tables of [`Step`]s.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn interpolate(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// Sets whether lines ending with a `\\` should be joined with the
+    /// following ones before parsing, so long [`Step`]s may be wrapped in
+    /// [Gherkin] sources.
+    ///
+    /// See [`join_continued_lines()`] for details.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    #[must_use]
+    pub const fn line_continuations(mut self, enabled: bool) -> Self {
+        self.line_continuations = enabled;
+        self
+    }
+
+    /// Parses the given [`Text`]s into [`Feature`]s, omitting the
+    /// [`Scenario`]s not matching the provided [`Filter`] before expanding
+    /// their [`Examples`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn parse_texts<I>(
+        self,
+        input: I,
+        filter: &Filter,
+    ) -> stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<Text>,
+    {
+        let features = input
+            .into_iter()
+            .filter_map(|text| self.parse_text(text.into(), filter))
+            .collect::<Vec<_>>();
+
+        stream::iter(features)
+    }
+
+    /// Parses the given [`Text`] into a [`Feature`], omitting the
+    /// [`Scenario`]s not matching the provided [`Filter`] before expanding
+    /// their [`Examples`].
+    ///
+    /// Returns [`None`] if the provided [`Filter`] has omitted all the
+    /// [`Scenario`]s of the [`Feature`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub(super) fn parse_text(
+        &self,
+        text: Text,
+        filter: &Filter,
+    ) -> Option<Result<gherkin::Feature, ParseError>> {
+        let Text { name, content } = text;
+        let content = if self.line_continuations {
+            join_continued_lines(&content).into_owned()
+        } else {
+            content
+        };
+        let env = self
+            .language
+            .as_ref()
+            .and_then(|l| GherkinEnv::new(l).ok())
+            .unwrap_or_default();
+
+        let mut feature = match gherkin::Feature::parse(&content, env) {
+            Ok(f) => f,
+            Err(source) => {
+                let err = gherkin::ParseFileError::Parsing {
+                    path: name.unwrap_or_default(),
+                    error: None,
+                    source,
+                };
+                return Some(
+                    Err(ParsingError::new(err, Some(&content)).into()),
+                );
+            }
+        };
+        feature.path = name;
+
+        filter
+            .expand(feature, &self.expand_options)
+            .map(|f| {
+                f.map(|f| match &self.interpolation {
+                    Some(i) => f.interpolate(i),
+                    None => f,
+                })
+            })
+            .map_err(ParseError::from)
+            .transpose()
+    }
+}
+
+/// In-memory [Gherkin] source to be parsed by a [`FromString`] [`Parser`].
+///
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+#[derive(Clone, Debug)]
+pub struct Text {
+    /// Optional name of this [`Text`], used as a [`Feature::path`].
+    ///
+    /// [`Feature::path`]: gherkin::Feature::path
+    pub name: Option<PathBuf>,
+
+    /// [Gherkin] content of this [`Text`].
+    ///
+    /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+    pub content: String,
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Self {
+        Self {
+            name: None,
+            content,
+        }
+    }
+}
+
+impl From<&str> for Text {
+    fn from(content: &str) -> Self {
+        content.to_owned().into()
+    }
+}
+
+impl<N, C> From<(N, C)> for Text
+where
+    N: Into<PathBuf>,
+    C: Into<String>,
+{
+    fn from((name, content): (N, C)) -> Self {
+        Self {
+            name: Some(name.into()),
+            content: content.into(),
+        }
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Parser`] of remote [Gherkin] sources, downloaded via HTTP.
+//!
+//! [Gherkin]: https://cucumber.io/docs/gherkin/reference
+
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+    path::Path,
+    time::Duration,
+    vec,
+};
+
+use flate2::read::GzDecoder;
+use futures::stream;
+
+use crate::{cli, feature, tag};
+
+use super::{
+    basic::UnsupportedLanguageError,
+    from_string::{FromString, Text},
+    Error as ParseError, Filter, Parser,
+};
+
+/// [`Parser`] downloading [Gherkin] sources via HTTP before parsing them.
+///
+/// Accepts anything iterable over URLs. Each URL may point either to a single
+/// `.feature` file, or to a `.zip`, `.tar`, `.tar.gz` (`.tgz`) archive, from
+/// which all the `*.feature` files are extracted (sorted by their path inside
+/// the archive). Parsed [`Feature::path`]s are the URLs (with the path inside
+/// the archive appended, if any), so they're used in reports.
+///
+/// Failing to download or to extract a source is reported as a
+/// [`gherkin::ParseFileError::Reading`] error of the URL.
+///
+/// As there is no async runtime-agnostic way to interact with IO, this
+/// [`Parser`] is blocking.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use cucumber::{parser, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber::<&str>() // aiding type inference
+///     .with_parser(parser::Http::new())
+///     .run_and_exit([
+///         "https://example.com/specs/eating.feature",
+///         "https://example.com/specs/all.tar.gz",
+///     ])
+///     .await;
+/// # }
+/// ```
+///
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+/// [`Feature::path`]: gherkin::Feature::path
+#[derive(Clone, Debug, Default)]
+pub struct Http {
+    /// [`FromString`] [`Parser`] to parse the downloaded sources with.
+    parser: FromString,
+
+    /// Optional timeout of a single download.
+    ///
+    /// No timeout by default.
+    timeout: Option<Duration>,
+}
+
+impl<I> Parser<I> for Http
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    type Cli = cli::Empty;
+
+    type Output =
+        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, input: I, _: Self::Cli) -> Self::Output {
+        self.parse_urls(input, &Filter::default())
+    }
+
+    fn parse_with_tags(
+        self,
+        input: I,
+        _: Self::Cli,
+        tags: &tag::Expr,
+    ) -> Self::Output {
+        self.parse_urls(input, &Filter::by_tags(tags))
+    }
+
+    fn parse_filtered(
+        self,
+        input: I,
+        _: Self::Cli,
+        filter: &Filter,
+    ) -> Self::Output {
+        self.parse_urls(input, filter)
+    }
+}
+
+impl Http {
+    /// Creates a new [`Http`] [`Parser`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            parser: FromString::new(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the provided language to parse [`gherkin`] sources with instead of
+    /// the default one (English).
+    ///
+    /// # Errors
+    ///
+    /// If the provided language isn't supported.
+    pub fn language(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, UnsupportedLanguageError> {
+        self.parser = self.parser.language(name)?;
+        Ok(self)
+    }
+
+    /// Sets whether `<placeholders>` of [`Scenario Outline`][1]s should be
+    /// substituted inside doc strings and data tables of [`Step`]s, not only in
+    /// their text.
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn substitute_step_arguments(mut self, enabled: bool) -> Self {
+        self.parser = self.parser.substitute_step_arguments(enabled);
+        self
+    }
+
+    /// Sets the function transforming [`Examples`] of
+    /// [`Scenario Outline`][1]s before they're expanded row by row into
+    /// separate [`Scenario`]s.
+    ///
+    /// See [`ExamplesExpansionFn`] for details.
+    ///
+    /// [`ExamplesExpansionFn`]: crate::feature::ExamplesExpansionFn
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[must_use]
+    pub fn example_expansion<F>(mut self, func: F) -> Self
+    where
+        F: Fn(
+                Option<&Path>,
+                &gherkin::Scenario,
+                gherkin::Examples,
+            ) -> gherkin::Examples
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.parser = self.parser.example_expansion(func);
+        self
+    }
+
+    /// Sets the [`feature::Interpolation`] of `${ENV_VAR}` and `{{config.key}}`
+    /// placeholders in texts, doc strings and data tables of [`Step`]s.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn interpolate(
+        mut self,
+        interpolation: feature::Interpolation,
+    ) -> Self {
+        self.parser = self.parser.interpolate(interpolation);
+        self
+    }
+
+    /// Sets the provided `timeout` for downloading a single source.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Downloads and parses the sources from the given URLs into [`Feature`]s,
+    /// omitting the [`Scenario`]s not matching the provided [`Filter`] before
+    /// expanding their [`Examples`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn parse_urls<I>(
+        self,
+        input: I,
+        filter: &Filter,
+    ) -> stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut agent = ureq::AgentBuilder::new();
+        if let Some(timeout) = self.timeout {
+            agent = agent.timeout(timeout);
+        }
+        let agent = agent.build();
+
+        let features = input
+            .into_iter()
+            .flat_map(|url| {
+                let url = url.as_ref();
+                download(&agent, url)
+                    .map_err(|e| gherkin::ParseFileError::Reading {
+                        path: url.into(),
+                        source: e,
+                    })
+                    .map_or_else(
+                        |e| vec![Err(e.into())],
+                        |texts| {
+                            texts
+                                .into_iter()
+                                .filter_map(|t| {
+                                    self.parser.parse_text(t, filter)
+                                })
+                                .collect()
+                        },
+                    )
+            })
+            .collect::<Vec<_>>();
+
+        stream::iter(features)
+    }
+}
+
+/// Downloads the source from the given `url`, extracting `*.feature` files
+/// from it, if it's an archive.
+///
+/// # Errors
+///
+/// If failed to download the source or to extract it.
+fn download(agent: &ureq::Agent, url: &str) -> io::Result<Vec<Text>> {
+    let mut bytes = Vec::new();
+    _ = agent
+        .get(url)
+        .call()
+        .map_err(io::Error::other)?
+        .into_reader()
+        .read_to_end(&mut bytes)?;
+
+    let path = Path::new(url.split(['?', '#']).next().unwrap_or(url));
+    let is_tar_gz = has_extension(path, "tgz")
+        || (has_extension(path, "gz")
+            && path
+                .file_stem()
+                .is_some_and(|s| has_extension(Path::new(s), "tar")));
+
+    let mut texts = if has_extension(path, "zip") {
+        unzip(bytes, url)?
+    } else if is_tar_gz {
+        untar(GzDecoder::new(bytes.as_slice()), url)?
+    } else if has_extension(path, "tar") {
+        untar(bytes.as_slice(), url)?
+    } else {
+        let content = String::from_utf8(bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        return Ok(vec![Text {
+            name: Some(url.into()),
+            content,
+        }]);
+    };
+
+    texts.sort_by(|l, r| l.name.cmp(&r.name));
+    Ok(texts)
+}
+
+/// Extracts all the `*.feature` files from the given `.zip` archive `bytes`
+/// downloaded from the given `url`.
+///
+/// # Errors
+///
+/// If failed to read the archive.
+fn unzip(bytes: Vec<u8>, url: &str) -> io::Result<Vec<Text>> {
+    let mut archive = zip::ZipArchive::new(io::Cursor::new(bytes))
+        .map_err(io::Error::other)?;
+
+    let mut texts = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(io::Error::other)?;
+        if !file.is_file() || !has_extension(Path::new(file.name()), "feature")
+        {
+            continue;
+        }
+
+        let mut content = String::new();
+        _ = file.read_to_string(&mut content)?;
+        texts.push(Text {
+            name: Some(format!("{url}/{}", file.name()).into()),
+            content,
+        });
+    }
+    Ok(texts)
+}
+
+/// Extracts all the `*.feature` files from the given `.tar` archive `reader`
+/// downloaded from the given `url`.
+///
+/// # Errors
+///
+/// If failed to read the archive.
+fn untar(reader: impl Read, url: &str) -> io::Result<Vec<Text>> {
+    let mut archive = tar::Archive::new(reader);
+
+    let mut texts = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().into_owned();
+        if !entry.header().entry_type().is_file()
+            || !has_extension(Path::new(&path), "feature")
+        {
+            continue;
+        }
+
+        let mut content = String::new();
+        _ = entry.read_to_string(&mut content)?;
+        texts.push(Text {
+            name: Some(format!("{url}/{path}").into()),
+            content,
+        });
+    }
+    Ok(texts)
+}
+
+/// Checks whether the given `path` has the provided file extension
+/// (case-insensitively).
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+}
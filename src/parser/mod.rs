@@ -13,16 +13,36 @@
 //! [Gherkin]: https://cucumber.io/docs/gherkin/reference
 
 pub mod basic;
+pub mod diagnostic;
+pub mod from_string;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "record")]
+pub mod replay;
 
-use std::sync::Arc;
+use std::{borrow::Cow, iter, result, sync::Arc};
 
 use derive_more::with_trait::{Display, Error as StdError};
 use futures::Stream;
+use regex::Regex;
 
-use crate::feature::ExpandExamplesError;
+use crate::{
+    feature::{ExpandExamplesError, ExpandOptions, Ext as _},
+    tag,
+};
 
+#[cfg(feature = "http")]
 #[doc(inline)]
-pub use self::basic::Basic;
+pub use self::http::Http;
+#[cfg(feature = "record")]
+#[doc(inline)]
+pub use self::replay::Replay;
+#[doc(inline)]
+pub use self::{
+    basic::Basic,
+    diagnostic::{Diagnostic, ParsingError},
+    from_string::FromString,
+};
 
 /// Source of parsed [`Feature`]s.
 ///
@@ -48,13 +68,225 @@ pub trait Parser<I> {
     ///
     /// [`Feature`]: gherkin::Feature
     fn parse(self, input: I, cli: Self::Cli) -> Self::Output;
+
+    /// Parses the given `input` into a [`Stream`] of [`Feature`]s, allowing to
+    /// omit the [`Scenario`]s not matching the provided `tags` expression.
+    ///
+    /// [`Cucumber`] filters the parsed [`Feature`]s by `tags` anyway, so
+    /// omitting [`Scenario`]s here is just an optimization, allowing to avoid
+    /// expanding [`Examples`] of the ones, which won't be run. Default
+    /// implementation ignores the provided `tags` and just [`Parser::parse`]s
+    /// the `input`.
+    ///
+    /// [`Cucumber`]: crate::Cucumber
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn parse_with_tags(
+        self,
+        input: I,
+        cli: Self::Cli,
+        tags: &tag::Expr,
+    ) -> Self::Output
+    where
+        Self: Sized,
+    {
+        _ = tags;
+        self.parse(input, cli)
+    }
+
+    /// Parses the given `input` into a [`Stream`] of [`Feature`]s, allowing to
+    /// omit the [`Scenario`]s not matching the provided [`Filter`].
+    ///
+    /// [`Cucumber`] filters the parsed [`Feature`]s anyway, so omitting
+    /// [`Scenario`]s here is just an optimization, allowing to avoid expanding
+    /// [`Examples`] of the ones, which won't be run. Default implementation
+    /// uses [`Parser::parse_with_tags()`] if [`Filter::tags`] are specified, or
+    /// just [`Parser::parse`]s the `input` otherwise.
+    ///
+    /// [`Cucumber`]: crate::Cucumber
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn parse_filtered(
+        self,
+        input: I,
+        cli: Self::Cli,
+        filter: &Filter,
+    ) -> Self::Output
+    where
+        Self: Sized,
+    {
+        match &filter.tags {
+            Some(tags) => self.parse_with_tags(input, cli, tags),
+            None => self.parse(input, cli),
+        }
+    }
+}
+
+/// Filter of [`Scenario`]s to be omitted by a [`Parser`] before expanding their
+/// [`Examples`].
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    /// [`tag::Expr`] the [`Scenario`]s (or their [`Examples`]) should match.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    pub tags: Option<tag::Expr>,
+
+    /// [`Regex`] names of the [`Scenario`]s (including the expanded ones)
+    /// should match.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub name: Option<Regex>,
+}
+
+impl Filter {
+    /// Creates a new [`Filter`] by the provided `tags` only.
+    pub(crate) fn by_tags(tags: &tag::Expr) -> Self {
+        Self {
+            tags: Some(tags.clone()),
+            name: None,
+        }
+    }
+
+    /// Omits the [`Scenario`]s not matching this [`Filter`] from the provided
+    /// [`Feature`] and expands [`Examples`] of the remaining ones.
+    ///
+    /// Returns [`None`] if this [`Filter`] has omitted all the [`Scenario`]s of
+    /// the provided [`Feature`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`Examples`] cannot be expanded.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn expand(
+        &self,
+        mut feature: gherkin::Feature,
+        opts: &ExpandOptions,
+    ) -> result::Result<Option<gherkin::Feature>, ExpandExamplesError> {
+        let total = feature.count_scenarios();
+        if let Some(tags) = &self.tags {
+            feature = feature.filter_by_tags(tags);
+        }
+        let feature = match &self.name {
+            Some(name) => feature.expand_examples_matching(opts, name)?,
+            None => feature.expand_examples_with(opts)?,
+        };
+        let is_filtered = self.tags.is_some() || self.name.is_some();
+        Ok(
+            (!is_filtered || total == 0 || feature.count_scenarios() > 0)
+                .then_some(feature),
+        )
+    }
+}
+
+/// Joins the lines of the provided [Gherkin] `source` ending with a `\\` with
+/// the following ones, so long [`Step`]s may be wrapped.
+///
+/// The continuation lines are trimmed and joined with a single space, while
+/// being replaced with empty lines afterwards, so the positions of everything
+/// else in the `source` stay the same. Doc strings and comments are left
+/// intact.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::parser;
+/// #
+/// let source = "Feature: F
+///   Scenario: S
+///     Given a step \\
+///       wrapped \\
+///       twice
+///     Then another one
+/// ";
+///
+/// assert_eq!(
+///     parser::join_continued_lines(source),
+///     "Feature: F
+///   Scenario: S
+///     Given a step wrapped twice
+///
+///
+///     Then another one
+/// ",
+/// );
+/// ```
+///
+/// [`Step`]: gherkin::Step
+/// [Gherkin]: https://cucumber.io/docs/gherkin/reference
+#[must_use]
+pub fn join_continued_lines(source: &str) -> Cow<'_, str> {
+    let continues = |line: &str| {
+        !line.trim_start().starts_with('#') && line.trim_end().ends_with('\\')
+    };
+    if !source.lines().any(continues) {
+        return Cow::Borrowed(source);
+    }
+
+    let mut out = String::with_capacity(source.len());
+    let mut docstring = None;
+    let mut joined = None::<String>;
+    let mut swallowed = 0;
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if joined.is_none() {
+            if let Some(delimiter) = docstring {
+                if trimmed.starts_with(delimiter) {
+                    docstring = None;
+                }
+                out.push_str(line);
+                out.push('\n');
+                continue;
+            }
+            docstring = ["\"\"\"", "```"]
+                .into_iter()
+                .find(|d| trimmed.starts_with(d));
+        }
+
+        let is_continued = docstring.is_none() && continues(line);
+        let text = if is_continued {
+            line.trim_end().trim_end_matches('\\')
+        } else {
+            line
+        };
+        if let Some(j) = joined.as_mut() {
+            j.push(' ');
+            j.push_str(text.trim());
+            swallowed += 1;
+        } else if is_continued {
+            joined = Some(text.trim_end().to_owned());
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+
+        if !is_continued {
+            if let Some(j) = joined.take() {
+                out.push_str(&j);
+                out.extend(iter::repeat_n('\n', swallowed + 1));
+                swallowed = 0;
+            }
+        }
+    }
+    if let Some(j) = joined {
+        out.push_str(&j);
+        out.extend(iter::repeat_n('\n', swallowed + 1));
+    }
+    Cow::Owned(out)
 }
 
 /// Result of parsing [Gherkin] files.
 ///
 /// [Gherkin]: https://cucumber.io/docs/gherkin/reference
-#[expect(clippy::absolute_paths, reason = "one liner")]
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = result::Result<T, Error>;
 
 /// [`Parser`] error.
 #[derive(Clone, Debug, Display, StdError)]
@@ -63,7 +295,7 @@ pub enum Error {
     ///
     /// [`Feature`]: gherkin::Feature
     #[display("Failed to parse feature: {_0}")]
-    Parsing(Arc<gherkin::ParseFileError>),
+    Parsing(Arc<ParsingError>),
 
     /// Failed to expand [`Examples`]
     ///
@@ -74,6 +306,12 @@ pub enum Error {
 
 impl From<gherkin::ParseFileError> for Error {
     fn from(e: gherkin::ParseFileError) -> Self {
+        ParsingError::from(e).into()
+    }
+}
+
+impl From<ParsingError> for Error {
+    fn from(e: ParsingError) -> Self {
         Self::Parsing(Arc::new(e))
     }
 }
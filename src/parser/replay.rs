@@ -0,0 +1,513 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Replaying of a [`Cucumber`] events stream recorded by a [`writer::Record`].
+//!
+//! [`Cucumber`]: event::Cucumber
+//! [`writer::Record`]: crate::writer::Record
+
+#[cfg(not(feature = "timestamps"))]
+use std::time::SystemTime;
+use std::{collections::HashMap, io, marker::PhantomData, sync::Arc};
+
+use derive_more::with_trait::{Display, Error as StdError, From};
+use regex::{CaptureLocations, Regex};
+
+use crate::{
+    event::{self, Source},
+    feature::ExpandExamplesError,
+    parser,
+    step::{self, AmbiguousMatchError},
+    writer::record::{
+        Captures, CucumberEvent, DuplicateNameEvent, FeatureEvent, HookEvent,
+        HookType, Line, Location, RuleEvent, ScenarioEvent, ScenarioEventKind,
+        StepError, StepEvent,
+    },
+    Event,
+};
+
+/// Error of replaying a recorded [`Cucumber`] events stream.
+///
+/// [`Cucumber`]: event::Cucumber
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Failed to read the recorded events stream.
+    #[display("Failed to read recorded events: {_0}")]
+    Io(io::Error),
+
+    /// Failed to decode a recorded line.
+    #[display("Failed to decode recorded event: {_0}")]
+    Decode(serde_json::Error),
+
+    /// Recorded event refers a [`gherkin`] source not recorded before.
+    #[display("Recorded event refers unknown source `{_0}`")]
+    #[from(ignore)]
+    UnknownSource(#[error(not(source))] u64),
+}
+
+/// Replayer of a [`Cucumber`] events stream recorded by a
+/// [`writer::Record`], yielding the recorded events in the recorded order.
+///
+/// Replayed events don't contain [`World`]s and [`Backtrace`]s, while panic
+/// payloads are replayed as their [`String`] messages only, and
+/// [`parser::Error::Parsing`]s are replayed as the ones of reading a file
+/// (without any [`Diagnostic`]).
+///
+/// [`Diagnostic`]: parser::Diagnostic
+/// [`Backtrace`]: std::backtrace::Backtrace
+/// [`Cucumber`]: event::Cucumber
+/// [`World`]: crate::World
+/// [`writer::Record`]: crate::writer::Record
+#[derive(Debug)]
+pub struct Replay<W, In> {
+    /// [`io::BufRead`] implementor to read recorded events from.
+    input: In,
+
+    /// Already replayed [`gherkin::Feature`]s by their IDs.
+    features: HashMap<u64, Source<gherkin::Feature>>,
+
+    /// Already replayed [`gherkin::Rule`]s by their IDs.
+    rules: HashMap<u64, Source<gherkin::Rule>>,
+
+    /// Already replayed [`gherkin::Scenario`]s by their IDs.
+    scenarios: HashMap<u64, Source<gherkin::Scenario>>,
+
+    /// Already replayed [`gherkin::Step`]s by their IDs.
+    steps: HashMap<u64, Source<gherkin::Step>>,
+
+    /// Already replayed [`step::Location::path`]s.
+    paths: HashMap<String, &'static str>,
+
+    /// Type of [`World`] the replayed events are produced for.
+    ///
+    /// [`World`]: crate::World
+    _world: PhantomData<W>,
+}
+
+impl<W, In: io::BufRead> Replay<W, In> {
+    /// Creates a new [`Replay`] reading recorded events from the given
+    /// `input`.
+    #[must_use]
+    pub fn new(input: In) -> Self {
+        Self {
+            input,
+            features: HashMap::new(),
+            rules: HashMap::new(),
+            scenarios: HashMap::new(),
+            steps: HashMap::new(),
+            paths: HashMap::new(),
+            _world: PhantomData,
+        }
+    }
+
+    /// Reads the next recorded event, if any.
+    ///
+    /// # Errors
+    ///
+    /// If failed to read or decode the recorded event.
+    fn next_event(
+        &mut self,
+    ) -> Result<Option<parser::Result<Event<event::Cucumber<W>>>>, Error> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            if self.input.read_line(&mut buf)? == 0 {
+                return Ok(None);
+            }
+            if buf.trim().is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str(&buf)? {
+                Line::Feature { id, feature } => {
+                    drop(self.features.insert(id, Source::new(feature)));
+                }
+                Line::Rule { id, rule } => {
+                    drop(self.rules.insert(id, Source::new(rule)));
+                }
+                Line::Scenario { id, scenario } => {
+                    drop(self.scenarios.insert(id, Source::new(scenario)));
+                }
+                Line::Step { id, step } => {
+                    drop(self.steps.insert(id, Source::new(step)));
+                }
+                Line::ParsingError { path, message } => {
+                    let err = gherkin::ParseFileError::Reading {
+                        path,
+                        source: io::Error::other(message),
+                    };
+                    return Ok(Some(Err(err.into())));
+                }
+                Line::ExampleExpansionError { pos, name, path } => {
+                    let err = ExpandExamplesError { pos, name, path };
+                    return Ok(Some(Err(err.into())));
+                }
+                Line::Event { at, event } => {
+                    let ev = Event::new(self.cucumber_event(event)?);
+                    #[cfg(feature = "timestamps")]
+                    let ev = Event {
+                        at: at.unwrap_or(ev.at),
+                        ..ev
+                    };
+                    #[cfg(not(feature = "timestamps"))]
+                    let _: Option<SystemTime> = at;
+                    return Ok(Some(Ok(ev)));
+                }
+            }
+        }
+    }
+
+    /// Restores the recorded [`event::Cucumber`].
+    fn cucumber_event(
+        &mut self,
+        ev: CucumberEvent,
+    ) -> Result<event::Cucumber<W>, Error> {
+        Ok(match ev {
+            CucumberEvent::Started => event::Cucumber::Started,
+            CucumberEvent::Feature(feature_id, ev) => {
+                let feature = source(&self.features, feature_id)?;
+                let ev = match ev {
+                    FeatureEvent::Started => event::Feature::Started,
+                    FeatureEvent::Rule(rule_id, ev) => {
+                        let rule = source(&self.rules, rule_id)?;
+                        let ev = match ev {
+                            RuleEvent::Started => event::Rule::Started,
+                            RuleEvent::Scenario(sc_id, ev) => {
+                                let (sc, ev) =
+                                    self.scenario_event(sc_id, ev)?;
+                                event::Rule::Scenario(sc, ev)
+                            }
+                            RuleEvent::Finished => event::Rule::Finished,
+                        };
+                        event::Feature::Rule(rule, ev)
+                    }
+                    FeatureEvent::Scenario(sc_id, ev) => {
+                        let (sc, ev) = self.scenario_event(sc_id, ev)?;
+                        event::Feature::Scenario(sc, ev)
+                    }
+                    FeatureEvent::Aborted => event::Feature::Aborted,
+                    FeatureEvent::Finished => event::Feature::Finished,
+                };
+                event::Cucumber::Feature(feature, ev)
+            }
+            CucumberEvent::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios,
+            } => event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios,
+            },
+            CucumberEvent::DuplicateName(dup) => {
+                event::Cucumber::DuplicateName(match dup {
+                    DuplicateNameEvent::Features(ids) => {
+                        event::DuplicateName::Features(
+                            ids.into_iter()
+                                .map(|id| source(&self.features, id))
+                                .collect::<Result<_, _>>()?,
+                        )
+                    }
+                    DuplicateNameEvent::Scenarios {
+                        feature,
+                        name,
+                        positions,
+                    } => event::DuplicateName::Scenarios {
+                        feature: source(&self.features, feature)?,
+                        name,
+                        positions,
+                    },
+                })
+            }
+            CucumberEvent::Finished {
+                passed,
+                failed,
+                skipped,
+                retried,
+                duration,
+                timed_out,
+            } => event::Cucumber::Finished {
+                passed,
+                failed,
+                skipped,
+                retried,
+                duration,
+                timed_out,
+            },
+        })
+    }
+
+    /// Restores the recorded [`event::RetryableScenario`] along with its
+    /// [`gherkin::Scenario`].
+    fn scenario_event(
+        &mut self,
+        scenario_id: u64,
+        ev: ScenarioEvent,
+    ) -> Result<(Source<gherkin::Scenario>, event::RetryableScenario<W>), Error>
+    {
+        use event::Scenario;
+
+        let scenario = source(&self.scenarios, scenario_id)?;
+        let event = match ev.event {
+            ScenarioEventKind::Started(id) => {
+                Scenario::Started(event::ScenarioId(id))
+            }
+            ScenarioEventKind::Hook(ty, ev) => {
+                let ty = match ty {
+                    HookType::Before => event::HookType::Before,
+                    HookType::After => event::HookType::After,
+                };
+                let ev = match ev {
+                    HookEvent::Started => event::Hook::Started,
+                    HookEvent::Passed => event::Hook::Passed,
+                    HookEvent::Failed { message } => {
+                        event::Hook::Failed(None, Arc::new(message))
+                    }
+                    HookEvent::Ignored { message } => {
+                        event::Hook::Ignored(Arc::new(message))
+                    }
+                };
+                Scenario::Hook(ty, ev)
+            }
+            ScenarioEventKind::Background(step_id, ev) => {
+                let step = source(&self.steps, step_id)?;
+                let ev = self.step_event(&step, ev);
+                Scenario::Background(step, ev)
+            }
+            ScenarioEventKind::RuleBackground(step_id, ev) => {
+                let step = source(&self.steps, step_id)?;
+                let ev = self.step_event(&step, ev);
+                Scenario::RuleBackground(step, ev)
+            }
+            ScenarioEventKind::Step(step_id, ev) => {
+                let step = source(&self.steps, step_id)?;
+                let ev = self.step_event(&step, ev);
+                Scenario::Step(step, ev)
+            }
+            ScenarioEventKind::Log { message, fields } => {
+                Scenario::Log(event::Log {
+                    message,
+                    fields: fields
+                        .into_iter()
+                        .map(|(name, val)| (name, val.into()))
+                        .collect(),
+                })
+            }
+            ScenarioEventKind::Artifact(path) => Scenario::Artifact(path),
+            ScenarioEventKind::Output { stdout, stderr } => {
+                Scenario::Output(event::Output { stdout, stderr })
+            }
+            ScenarioEventKind::Stalled {
+                step,
+                is_background,
+                elapsed,
+                world,
+            } => Scenario::Stalled(event::Stall {
+                step: source(&self.steps, step)?,
+                is_background,
+                elapsed,
+                world,
+            }),
+            ScenarioEventKind::BudgetExceeded {
+                limit,
+                elapsed,
+                failed,
+            } => Scenario::BudgetExceeded(event::Budget {
+                limit,
+                elapsed,
+                failed,
+            }),
+            ScenarioEventKind::Wip { passed } => Scenario::Wip(if passed {
+                event::Wip::Passed
+            } else {
+                event::Wip::Marked
+            }),
+            ScenarioEventKind::NotRun => Scenario::NotRun,
+            ScenarioEventKind::Finished => Scenario::Finished,
+        };
+        let retries = ev
+            .retries
+            .map(|(current, left)| event::Retries { current, left });
+        Ok((scenario, event::RetryableScenario { event, retries }))
+    }
+
+    /// Restores the recorded [`event::Step`] of the provided
+    /// [`gherkin::Step`].
+    fn step_event(
+        &mut self,
+        step: &gherkin::Step,
+        ev: StepEvent,
+    ) -> event::Step<W> {
+        match ev {
+            StepEvent::Started => event::Step::Started,
+            StepEvent::Skipped { duration } => event::Step::Skipped(duration),
+            StepEvent::Passed {
+                captures,
+                location,
+                duration,
+            } => event::Step::Passed(
+                capture_locations(&step.value, &captures),
+                location.map(|l| self.location(l)),
+                duration,
+            ),
+            StepEvent::Failed {
+                captures,
+                location,
+                error,
+                duration,
+            } => {
+                let error = self.step_error(error);
+                event::Step::Failed(
+                    captures.map(|c| capture_locations(&step.value, &c)),
+                    location.map(|l| self.location(l)),
+                    None,
+                    error,
+                    duration,
+                )
+            }
+        }
+    }
+
+    /// Restores the recorded [`event::StepError`].
+    fn step_error(&mut self, error: StepError) -> event::StepError {
+        match error {
+            StepError::NotFound => event::StepError::NotFound,
+            StepError::AmbiguousMatch(matches) => {
+                event::StepError::AmbiguousMatch(AmbiguousMatchError::new(
+                    matches.into_iter().filter_map(|(re, loc)| {
+                        let re = Regex::new(&re)
+                            .or_else(|_| Regex::new(&regex::escape(&re)))
+                            .ok()?;
+                        Some((re.into(), loc.map(|l| self.location(l))))
+                    }),
+                ))
+            }
+            StepError::KeywordMismatch(ty) => {
+                event::StepError::KeywordMismatch(ty)
+            }
+            StepError::Panic(message) => {
+                event::StepError::Panic(Arc::new(message), None)
+            }
+            StepError::Categorized(category, e) => {
+                event::StepError::Categorized(
+                    category.into(),
+                    Box::new(self.step_error(*e)),
+                )
+            }
+        }
+    }
+
+    /// Restores the recorded [`step::Location`].
+    fn location(&mut self, loc: Location) -> step::Location {
+        let path = *self
+            .paths
+            .entry(loc.path)
+            .or_insert_with_key(|p| Box::leak(p.clone().into_boxed_str()));
+        step::Location {
+            path,
+            line: loc.line,
+            column: loc.column,
+        }
+    }
+}
+
+impl<W, In: io::BufRead> Iterator for Replay<W, In> {
+    type Item = Result<parser::Result<Event<event::Cucumber<W>>>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+/// Looks up the already replayed [`Source`] by its `id`.
+///
+/// # Errors
+///
+/// If no [`Source`] has been replayed with the provided `id`.
+fn source<T>(
+    sources: &HashMap<u64, Source<T>>,
+    id: u64,
+) -> Result<Source<T>, Error> {
+    sources.get(&id).cloned().ok_or(Error::UnknownSource(id))
+}
+
+/// Restores [`CaptureLocations`] of the recorded [`Captures`] in the provided
+/// `text`.
+///
+/// As [`CaptureLocations`] cannot be constructed directly, a literal [`Regex`]
+/// with capturing groups at the recorded spans is matched against the `text`
+/// instead. If the recorded spans cannot be represented this way, empty
+/// [`CaptureLocations`] are returned.
+fn capture_locations(text: &str, captures: &Captures) -> CaptureLocations {
+    literal_regex(text, captures)
+        .and_then(|(re, start)| {
+            let mut locs = re.capture_locations();
+            _ = re.captures_read_at(&mut locs, text, start)?;
+            (0..locs.len())
+                .all(|i| captures.0.get(i).copied().flatten() == locs.get(i))
+                .then_some(locs)
+        })
+        .unwrap_or_else(|| {
+            #[expect(clippy::trivial_regex, reason = "no captures required")]
+            Regex::new("")
+                .unwrap_or_else(|e| unreachable!("empty `Regex` is valid: {e}"))
+                .capture_locations()
+        })
+}
+
+/// Builds a literal [`Regex`] matching the provided `text` with capturing
+/// groups at the recorded [`Captures`] spans, returning it along with the
+/// position in the `text` to start matching from.
+fn literal_regex(text: &str, captures: &Captures) -> Option<(Regex, usize)> {
+    let (&whole, groups) = captures.0.split_first()?;
+    let (start, end) = whole?;
+
+    let mut pattern = String::new();
+    let mut cursor = start;
+    let mut opened = vec![(start, end)];
+    let literal = |buf: &mut String, from: usize, to: usize| {
+        buf.push_str(&regex::escape(text.get(from..to)?));
+        Some(to)
+    };
+    for group in groups {
+        let Some((s, e)) = *group else {
+            pattern.push_str("(){0}");
+            continue;
+        };
+        while let Some(&(ps, pe)) = opened.last() {
+            if ps <= s && e <= pe {
+                break;
+            }
+            cursor = literal(&mut pattern, cursor, pe)?;
+            pattern.push(')');
+            _ = opened.pop();
+        }
+        if opened.is_empty() || s < cursor {
+            return None;
+        }
+        cursor = literal(&mut pattern, cursor, s)?;
+        pattern.push('(');
+        opened.push((s, e));
+    }
+    while let Some((_, pe)) = opened.pop() {
+        cursor = literal(&mut pattern, cursor, pe)?;
+        if !opened.is_empty() {
+            pattern.push(')');
+        }
+    }
+
+    Some((Regex::new(&pattern).ok()?, start))
+}
@@ -10,64 +10,107 @@
 
 //! Default [`Runner`] implementation.
 
+#[cfg(feature = "tokio")]
+use std::cell::{Cell, OnceCell};
 use std::{
     any::Any,
+    backtrace::{Backtrace, BacktraceStatus},
+    cell::RefCell,
     cmp,
     collections::HashMap,
-    iter, mem,
+    env,
+    ffi::OsString,
+    future::Future,
+    iter,
+    marker::PhantomData,
+    mem,
     ops::ControlFlow,
     panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    str::FromStr,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex as SyncMutex, OnceLock, PoisonError,
     },
     thread,
     time::{Duration, Instant},
 };
+#[cfg(feature = "output-json")]
+use std::{
+    fs,
+    io::{self, BufReader},
+    path::Path,
+};
 
 #[cfg(feature = "tracing")]
 use crossbeam_utils::atomic::AtomicCell;
-use derive_more::with_trait::{Debug, Display, FromStr};
+use derive_more::with_trait::{Debug, Display};
 use drain_filter_polyfill::VecExt;
 use futures::{
     channel::{mpsc, oneshot},
+    executor,
     future::{self, Either, LocalBoxFuture},
     lock::Mutex,
     pin_mut,
     stream::{self, LocalBoxStream},
-    FutureExt as _, Stream, StreamExt as _, TryFutureExt as _,
+    FutureExt as _, SinkExt as _, Stream, StreamExt as _, TryFutureExt as _,
     TryStreamExt as _,
 };
-use gherkin::tagexpr::TagOperation;
 use itertools::Itertools as _;
 use regex::{CaptureLocations, Regex};
+use sealed::sealed;
+#[cfg(feature = "tokio")]
+use tokio::{runtime, task};
 
+#[cfg(feature = "macros")]
+use crate::codegen;
 #[cfg(feature = "tracing")]
 use crate::tracing::{Collector as TracingCollector, SpanCloseWaiter};
 use crate::{
-    event::{self, HookType, Info, Retries, Source},
-    feature::Ext as _,
+    artifacts, capture,
+    cli::ConfigValue,
+    event::{self, HookType, Info, Retries, ScenarioId, Source},
+    feature::{Example, Ext as _},
+    fixtures,
     future::{select_with_biased_first, FutureExt as _},
-    parser, step,
-    tag::Ext as _,
+    parser,
+    runner::ScenarioPlan,
+    shared, step, tag,
+    writer::basic::coerce_error,
     Event, Runner, Step, World,
 };
 
 /// CLI options of a [`Basic`] [`Runner`].
 #[derive(clap::Args, Clone, Debug, Default)]
 #[group(skip)]
+#[expect( // intentional
+    clippy::struct_excessive_bools,
+    reason = "independent options, not a state machine"
+)]
 pub struct Cli {
     /// Number of scenarios to run concurrently. If not specified, uses the
     /// value configured in tests runner, or 64 by default.
-    #[arg(long, short, value_name = "int", global = true)]
+    #[arg(
+        long,
+        short,
+        value_name = "int",
+        env = "CUCUMBER_CONCURRENCY",
+        global = true
+    )]
     pub concurrency: Option<usize>,
 
     /// Run tests until the first failure.
-    #[arg(long, global = true, visible_alias = "ff")]
+    #[arg(
+        long,
+        env = "CUCUMBER_FAIL_FAST",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true,
+        visible_alias = "ff"
+    )]
     pub fail_fast: bool,
 
     /// Number of times a scenario will be retried in case of a failure.
-    #[arg(long, value_name = "int", global = true)]
+    #[arg(long, value_name = "int", env = "CUCUMBER_RETRY", global = true)]
     pub retry: Option<usize>,
 
     /// Delay between each scenario retry attempt.
@@ -83,14 +126,174 @@ pub struct Cli {
         long,
         value_name = "duration",
         value_parser = humantime::parse_duration,
+        env = "CUCUMBER_RETRY_AFTER",
         verbatim_doc_comment,
         global = true,
     )]
     pub retry_after: Option<Duration>,
 
     /// Tag expression to filter retried scenarios.
-    #[arg(long, value_name = "tagexpr", global = true)]
-    pub retry_tag_filter: Option<TagOperation>,
+    #[arg(
+        long,
+        value_name = "tagexpr",
+        env = "CUCUMBER_RETRY_TAG_FILTER",
+        global = true
+    )]
+    pub retry_tag_filter: Option<tag::Expr>,
+
+    /// Tag expression of scenarios to run sequentially, regardless of the
+    /// configured `which_scenario` function. Takes precedence over
+    /// `--concurrent-tags`.
+    #[arg(
+        long,
+        value_name = "tagexpr",
+        env = "CUCUMBER_SERIAL_TAGS",
+        global = true
+    )]
+    pub serial_tags: Option<tag::Expr>,
+
+    /// Tag expression of scenarios to run concurrently, regardless of the
+    /// configured `which_scenario` function.
+    #[arg(
+        long,
+        value_name = "tagexpr",
+        env = "CUCUMBER_CONCURRENT_TAGS",
+        global = true
+    )]
+    pub concurrent_tags: Option<tag::Expr>,
+
+    /// Root directory for scenarios' artifacts. If not specified, uses the
+    /// value configured in tests runner, or a `cucumber-artifacts` directory
+    /// inside the system's temporary one by default.
+    #[arg(
+        long,
+        value_name = "dir",
+        env = "CUCUMBER_ARTIFACTS_DIR",
+        global = true
+    )]
+    pub artifacts_dir: Option<PathBuf>,
+
+    /// Which scenarios' artifacts to keep once they finish. If not specified,
+    /// uses the value configured in tests runner, or `failed` by default.
+    #[arg(
+        long,
+        value_name = "all|failed|none",
+        env = "CUCUMBER_KEEP_ARTIFACTS",
+        global = true
+    )]
+    pub keep_artifacts: Option<artifacts::Retention>,
+
+    /// What to do with scenarios exceeding their `@budget(<duration>)` tag. If
+    /// not specified, uses the value configured in tests runner, or `fail` by
+    /// default.
+    #[arg(
+        long,
+        value_name = "fail|warn",
+        env = "CUCUMBER_BUDGET_POLICY",
+        global = true
+    )]
+    pub budget_policy: Option<BudgetPolicy>,
+
+    /// Fail the run if any work-in-progress scenario (marked with a `@wip` tag
+    /// or using `#[wip]` steps) passes. Failures of work-in-progress scenarios
+    /// never fail the run.
+    #[arg(
+        long,
+        env = "CUCUMBER_WIP",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub wip: bool,
+
+    /// Warn about steps making no progress for longer than the specified
+    /// duration. If not specified, uses the value configured in tests runner,
+    /// or doesn't watch steps at all by default.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        env = "CUCUMBER_WATCHDOG",
+        global = true
+    )]
+    pub watchdog: Option<Duration>,
+
+    /// Stop the whole execution once it takes longer than the specified
+    /// duration, reporting the remaining scenarios as not run. If not
+    /// specified, uses the value configured in tests runner, or doesn't limit
+    /// the execution at all by default.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        env = "CUCUMBER_GLOBAL_TIMEOUT",
+        global = true
+    )]
+    pub global_timeout: Option<Duration>,
+
+    /// Capture stdout/stderr of steps, so writers output it only for failed
+    /// steps, running scenarios one-by-one (supported on Unix only).
+    #[arg(
+        long,
+        env = "CUCUMBER_CAPTURE_OUTPUT",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub capture_output: bool,
+
+    /// Report features having duplicate names across the suite, and scenarios
+    /// having duplicate names inside a feature, as report consumers keying on
+    /// names merge their results.
+    #[arg(
+        long,
+        env = "CUCUMBER_LINT_DUPLICATE_NAMES",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub lint_duplicate_names: bool,
+
+    /// Path to a JSON report of a previous run (written by `--output-json`)
+    /// to schedule the longest scenarios first by.
+    #[cfg(feature = "output-json")]
+    #[arg(
+        long,
+        value_name = "path",
+        value_parser = |path: &str| Timings::from_json_report(path),
+        env = "CUCUMBER_TIMINGS",
+        global = true
+    )]
+    pub timings: Option<Timings>,
+}
+
+impl Cli {
+    /// Returns the [`ScenarioType`] of the provided [`Scenario`] forced by its
+    /// `@env` tags, the [`Cli::serial_tags`] or [`Cli::concurrent_tags`], if
+    /// any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn forced_scenario_type(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Option<ScenarioType> {
+        let matches = |expr: &Option<tag::Expr>| {
+            expr.as_ref()
+                .is_some_and(|e| e.matches(feature, rule, scenario))
+        };
+
+        // Environment variables are process-wide, so setting them can't be
+        // done concurrently with other `Scenario`s, which `Serial` ones are
+        // isolated from.
+        let sets_env = !tag::env(feature, rule, scenario).is_empty();
+
+        if sets_env || matches(&self.serial_tags) {
+            Some(ScenarioType::Serial)
+        } else if matches(&self.concurrent_tags) {
+            Some(ScenarioType::Concurrent)
+        } else {
+            None
+        }
+    }
 }
 
 /// Type determining whether [`Scenario`]s should run concurrently or
@@ -99,7 +302,8 @@ pub struct Cli {
 /// [`Scenario`]: gherkin::Scenario
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum ScenarioType {
-    /// Run [`Scenario`]s sequentially (one-by-one).
+    /// Run [`Scenario`]s sequentially (one-by-one), in isolation from any
+    /// other [`Scenario`]s.
     ///
     /// [`Scenario`]: gherkin::Scenario
     Serial,
@@ -110,412 +314,841 @@ pub enum ScenarioType {
     Concurrent,
 }
 
-/// Options for retrying [`Scenario`]s.
+/// Way of running [`Scenario`]s by a [`Basic`] [`Runner`].
 ///
 /// [`Scenario`]: gherkin::Scenario
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct RetryOptions {
-    /// Number of [`Retries`].
-    pub retries: Retries,
-
-    /// Delay before next retry attempt will be executed.
-    pub after: Option<Duration>,
-}
-
-impl RetryOptions {
-    /// Returns [`Some`], in case next retry attempt is available, or [`None`]
-    /// otherwise.
-    #[must_use]
-    pub fn next_try(self) -> Option<Self> {
-        self.retries.next_try().map(|num| Self {
-            retries: num,
-            after: self.after,
-        })
-    }
-
-    /// Parses [`RetryOptions`] from [`Feature`]'s, [`Rule`]'s, [`Scenario`]'s
-    /// tags and [`Cli`] options.
+#[sealed]
+pub trait SpawnScenarios<World, Before, After> {
+    /// Returns the function spawning a thread to run the user code of a
+    /// [`Scenario`] on, outside the thread running a [`Runner`], if any.
     ///
-    /// [`Feature`]: gherkin::Feature
-    /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub fn parse_from_tags(
-        feature: &gherkin::Feature,
-        rule: Option<&gherkin::Rule>,
-        scenario: &gherkin::Scenario,
-        cli: &Cli,
-    ) -> Option<Self> {
-        #[expect(clippy::shadow_unrelated, reason = "actually related")]
-        let parse_tags = |tags: &[String]| {
-            tags.iter().find_map(|tag| {
-                tag.strip_prefix("retry").map(|retries| {
-                    let (num, rest) = retries
-                        .strip_prefix('(')
-                        .and_then(|s| {
-                            s.split_once(')').and_then(|(num, rest)| {
-                                num.parse::<usize>()
-                                    .ok()
-                                    .map(|num| (Some(num), rest))
-                            })
-                        })
-                        .unwrap_or((None, retries));
-
-                    let after = rest.strip_prefix(".after").and_then(|after| {
-                        after.strip_prefix('(').and_then(|after| {
-                            let (dur, _) = after.split_once(')')?;
-                            humantime::parse_duration(dur).ok()
-                        })
-                    });
+    #[expect(private_interfaces, reason = "sealed trait")]
+    fn spawner() -> Option<SpawnScenarioFn<World, Before, After>>;
+}
 
-                    (num, after)
-                })
-            })
-        };
+/// [`SpawnScenarios`] running [`Scenario`]s on the thread running a [`Basic`]
+/// [`Runner`] (default).
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InPlaceScenarios;
 
-        let apply_cli = |options: Option<_>| {
-            let matched = cli.retry_tag_filter.as_ref().map_or_else(
-                || cli.retry.is_some() || cli.retry_after.is_some(),
-                |op| {
-                    op.eval(scenario.tags.iter().chain(
-                        rule.iter().flat_map(|r| &r.tags).chain(&feature.tags),
-                    ))
-                },
-            );
+#[sealed]
+impl<World, Before, After> SpawnScenarios<World, Before, After>
+    for InPlaceScenarios
+{
+    #[expect(private_interfaces, reason = "sealed trait")]
+    fn spawner() -> Option<SpawnScenarioFn<World, Before, After>> {
+        None
+    }
+}
 
-            (options.is_some() || matched).then(|| Self {
-                retries: Retries::initial(
-                    options.and_then(|(r, _)| r).or(cli.retry).unwrap_or(1),
-                ),
-                after: options.and_then(|(_, a)| a).or(cli.retry_after),
-            })
-        };
+/// [`SpawnScenarios`] running each [`Scenario`] on its own dedicated thread.
+///
+/// See [`Basic::spawn_blocking_scenarios()`] for details.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BlockingScenarios;
 
-        apply_cli(
-            parse_tags(&scenario.tags)
-                .or_else(|| rule.and_then(|r| parse_tags(&r.tags)))
-                .or_else(|| parse_tags(&feature.tags)),
-        )
+#[sealed]
+impl<W, Before, After> SpawnScenarios<W, Before, After> for BlockingScenarios
+where
+    W: World + Send,
+    Before: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + Send
+        + Sync
+        + 'static,
+    After: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + Send
+        + Sync
+        + 'static,
+{
+    #[expect(private_interfaces, reason = "sealed trait")]
+    fn spawner() -> Option<SpawnScenarioFn<W, Before, After>> {
+        Some(Box::new(|| ScenarioThread {
+            tasks: spawn_blocking_thread(),
+            into_task: Job::into_task,
+        }))
     }
+}
 
-    /// Constructs [`RetryOptionsWithDeadline`], that will reschedule
-    /// [`Scenario`] [`after`] delay.
-    ///
-    /// [`after`]: RetryOptions::after
-    /// [`Scenario`]: gherkin::Scenario
-    fn with_deadline(self, now: Instant) -> RetryOptionsWithDeadline {
-        RetryOptionsWithDeadline {
-            retries: self.retries,
-            after: self.after.map(|at| (at, Some(now))),
+/// [`SpawnScenarios`] running [`Scenario`]s on the current multi-threaded
+/// [`tokio`] runtime.
+///
+/// See [`Basic::spawn_tokio_scenarios()`] for details.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioScenarios;
+
+#[cfg(feature = "tokio")]
+#[sealed]
+impl<W, Before, After> SpawnScenarios<W, Before, After> for TokioScenarios
+where
+    W: World + Send,
+    Before: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + Send
+        + Sync
+        + 'static,
+    After: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + Send
+        + Sync
+        + 'static,
+{
+    #[expect(private_interfaces, reason = "sealed trait")]
+    fn spawner() -> Option<SpawnScenarioFn<W, Before, After>> {
+        // Checked eagerly (if possible), as the panic hook, replaced while
+        // running `Scenario`s, cannot be restored from a panicking thread.
+        if let Ok(rt) = runtime::Handle::try_current() {
+            TokioScenariosPool::assert_multi_threaded(&rt);
         }
+        // Spawned lazily, as requires being inside a `tokio` runtime.
+        let pool = OnceCell::new();
+        Some(Box::new(move || ScenarioThread {
+            tasks: pool.get_or_init(TokioScenariosPool::new).next_thread(),
+            into_task: Job::into_task,
+        }))
     }
+}
 
-    /// Constructs [`RetryOptionsWithDeadline`], that will reschedule
-    /// [`Scenario`] immediately, ignoring [`RetryOptions::after`]. Used for
-    /// initial [`Scenario`] run, where we don't need to wait for the delay.
+/// Strategy of dealing with the panic hook while running [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PanicHookStrategy {
+    /// Replace the panic hook with the one only capturing [`Backtrace`]s of
+    /// panics, so no "thread 'main' panicked at ..." messages are printed, and
+    /// restore the original one afterwards.
+    #[default]
+    Suppress,
+
+    /// Capture [`Backtrace`]s of panics and call the original panic hook
+    /// afterwards (to keep [`color-eyre`] or other test harnesses working, for
+    /// example).
     ///
-    /// [`Scenario`]: gherkin::Scenario
-    fn without_deadline(self) -> RetryOptionsWithDeadline {
-        RetryOptionsWithDeadline {
-            retries: self.retries,
-            after: self.after.map(|at| (at, None)),
-        }
-    }
+    /// [`color-eyre`]: https://docs.rs/color-eyre
+    Chain,
+
+    /// Leave the panic hook untouched.
+    ///
+    /// __NOTE__: [`Backtrace`]s of panics are not captured in this case (unless
+    ///           another concurrent run installs its panic hook).
+    Untouched,
 }
 
-/// [`RetryOptions`] with an [`Option`]al [`Instant`] to determine, whether
-/// [`Scenario`] should be already rescheduled or not.
+/// Order of scheduling [`Feature`]s' [`Scenario`]s for running.
+///
+/// Every order, except the [`Order::AsDiscovered`] one, waits for all the
+/// [`Feature`]s being parsed before scheduling any of them.
 ///
+/// [`Feature`]: gherkin::Feature
 /// [`Scenario`]: gherkin::Scenario
-#[derive(Clone, Copy, Debug)]
-pub struct RetryOptionsWithDeadline {
-    /// Number of [`Retries`].
-    pub retries: Retries,
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Order {
+    /// Schedule [`Feature`]s as soon as they're parsed, in order of their
+    /// discovery by a [`Parser`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Parser`]: crate::Parser
+    #[default]
+    AsDiscovered,
 
-    /// Delay before next retry attempt will be executed.
-    pub after: Option<(Duration, Option<Instant>)>,
+    /// Schedule [`Feature`]s alphabetically by their [`path`]s, and then by
+    /// their [`name`]s (so the ones without a [`path`] go first).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`name`]: gherkin::Feature::name
+    /// [`path`]: gherkin::Feature::path
+    Alphabetical,
+
+    /// Schedule [`Feature`]s with more [`Scenario`]s first, so the longest
+    /// ones don't end up running alone at the end, when a concurrency limit is
+    /// set.
+    ///
+    /// [`Feature`]s with the same number of [`Scenario`]s are scheduled in
+    /// order of their discovery.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    LargestFirst,
+
+    /// Schedule [`Feature`]s in order determined by the provided comparison
+    /// function.
+    ///
+    /// [`Feature`]s comparing as equal are scheduled in order of their
+    /// discovery.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    Custom(fn(&gherkin::Feature, &gherkin::Feature) -> cmp::Ordering),
 }
 
-impl From<RetryOptionsWithDeadline> for RetryOptions {
-    fn from(v: RetryOptionsWithDeadline) -> Self {
-        Self {
-            retries: v.retries,
-            after: v.after.map(|(at, _)| at),
+impl Order {
+    /// Sorts the provided `features` in this [`Order`].
+    fn sort(self, features: &mut [Source<gherkin::Feature>]) {
+        match self {
+            Self::AsDiscovered => {}
+            Self::Alphabetical => {
+                features.sort_by(|a, b| {
+                    (&a.path, &a.name).cmp(&(&b.path, &b.name))
+                });
+            }
+            Self::LargestFirst => {
+                features.sort_by_key(|f| cmp::Reverse(f.count_scenarios()));
+            }
+            Self::Custom(cmp) => features.sort_by(|a, b| cmp(a, b)),
         }
     }
 }
 
-impl RetryOptionsWithDeadline {
-    /// Returns [`Duration`] after which a [`Scenario`] could be retried. If
-    /// [`None`], then [`Scenario`] is ready for the retry.
+/// Historical durations of [`Scenario`]s, making the [`Basic`] [`Runner`]
+/// schedule the longest ones first, so they don't end up running alone at the
+/// end, when a concurrency limit is set.
+///
+/// [`Scenario`]s without a known duration are scheduled after the ones having
+/// it, in their [`Order`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    /// Durations of [`Scenario`]s by their lines and paths of their
+    /// [`Feature`]s.
     ///
+    /// [`Feature`]: gherkin::Feature
     /// [`Scenario`]: gherkin::Scenario
-    fn left_until_retry(&self) -> Option<Duration> {
-        let (dur, instant) = self.after?;
-        dur.checked_sub(instant?.elapsed())
+    durations: HashMap<usize, Vec<(Option<PathBuf>, Duration)>>,
+}
+
+impl Timings {
+    /// Creates new empty [`Timings`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the `duration` of a [`Scenario`] located at the provided `line`
+    /// of a [`Feature`] with the provided `path`.
+    ///
+    /// A relative `path` matches any [`Feature::path`] ending with it. If the
+    /// same [`Scenario`] is recorded several times, the longest duration wins.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Feature::path`]: gherkin::Feature::path
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn with(
+        mut self,
+        path: Option<PathBuf>,
+        line: usize,
+        duration: Duration,
+    ) -> Self {
+        let recorded = self.durations.entry(line).or_default();
+        if let Some((_, dur)) = recorded.iter_mut().find(|(p, _)| *p == path) {
+            *dur = cmp::max(*dur, duration);
+        } else {
+            recorded.push((path, duration));
+        }
+        self
+    }
+
+    /// Reads [`Timings`] from a [Cucumber JSON][1] report of a previous run
+    /// (see [`writer::Json`]).
+    ///
+    /// Durations of [`Background`]s are accounted into the durations of the
+    /// [`Scenario`]s following them.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a [Cucumber JSON][1] report.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`writer::Json`]: crate::writer::Json
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[cfg(feature = "output-json")]
+    pub fn from_json_report(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = BufReader::new(fs::File::open(path)?);
+        let features: Vec<report::Feature> = serde_json::from_reader(file)?;
+
+        Ok(features.into_iter().fold(Self::new(), |timings, f| {
+            let uri = f.uri.map(PathBuf::from);
+            let mut background = Duration::ZERO;
+            f.elements.into_iter().fold(timings, |timings, el| {
+                let took = background + el.duration();
+                if el.r#type == "background" {
+                    background = took;
+                    timings
+                } else {
+                    background = Duration::ZERO;
+                    timings.with(uri.clone(), el.line, took)
+                }
+            })
+        }))
+    }
+
+    /// Indicates whether no durations are recorded in these [`Timings`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.durations.is_empty()
+    }
+
+    /// Returns the recorded duration of the provided [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn get(
+        &self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+    ) -> Option<Duration> {
+        self.durations
+            .get(&scenario.position.line)?
+            .iter()
+            .find_map(|(path, dur)| {
+                match (path, &feature.path) {
+                    (Some(p), Some(f)) => f.ends_with(p),
+                    (None, None) => true,
+                    (Some(_), None) | (None, Some(_)) => false,
+                }
+                .then_some(*dur)
+            })
     }
 }
 
-/// Alias for [`fn`] used to determine whether a [`Scenario`] is [`Concurrent`]
-/// or a [`Serial`] one.
+#[cfg(feature = "output-json")]
+/// Parts of a [Cucumber JSON][1] report required for reading [`Timings`].
 ///
-/// [`Concurrent`]: ScenarioType::Concurrent
-/// [`Serial`]: ScenarioType::Serial
-/// [`Scenario`]: gherkin::Scenario
-pub type WhichScenarioFn = fn(
-    &gherkin::Feature,
-    Option<&gherkin::Rule>,
-    &gherkin::Scenario,
-) -> ScenarioType;
+/// [1]: https://github.com/cucumber/cucumber-json-schema
+mod report {
+    use std::time::Duration;
 
-/// Alias for [`Arc`]ed [`Fn`] used to determine [`Scenario`]'s
-/// [`RetryOptions`].
-///
-/// [`Scenario`]: gherkin::Scenario
-pub type RetryOptionsFn = Arc<
-    dyn Fn(
-        &gherkin::Feature,
-        Option<&gherkin::Rule>,
-        &gherkin::Scenario,
-        &Cli,
-    ) -> Option<RetryOptions>,
->;
+    use serde::Deserialize;
 
-/// Alias for [`fn`] executed on each [`Scenario`] before running all [`Step`]s.
-///
-/// [`Scenario`]: gherkin::Scenario
-/// [`Step`]: gherkin::Step
-pub type BeforeHookFn<World> = for<'a> fn(
-    &'a gherkin::Feature,
-    Option<&'a gherkin::Rule>,
-    &'a gherkin::Scenario,
-    &'a mut World,
-) -> LocalBoxFuture<'a, ()>;
+    /// Reported [`gherkin::Feature`].
+    #[derive(Deserialize)]
+    pub(super) struct Feature {
+        /// Path of the [`gherkin::Feature`].
+        pub(super) uri: Option<String>,
 
-/// Alias for [`fn`] executed on each [`Scenario`] after running all [`Step`]s.
-///
-/// [`Scenario`]: gherkin::Scenario
-/// [`Step`]: gherkin::Step
-pub type AfterHookFn<World> = for<'a> fn(
-    &'a gherkin::Feature,
-    Option<&'a gherkin::Rule>,
-    &'a gherkin::Scenario,
-    &'a event::ScenarioFinished,
-    Option<&'a mut World>,
-) -> LocalBoxFuture<'a, ()>;
+        /// Reported [`gherkin::Background`]s and [`gherkin::Scenario`]s.
+        #[serde(default)]
+        pub(super) elements: Vec<Element>,
+    }
 
-/// Alias for a failed [`Scenario`].
-///
-/// [`Scenario`]: gherkin::Scenario
-type IsFailed = bool;
+    /// Reported [`gherkin::Background`] or [`gherkin::Scenario`].
+    #[derive(Deserialize)]
+    pub(super) struct Element {
+        /// Either `background` or `scenario`.
+        pub(super) r#type: String,
 
-/// Alias for a retried [`Scenario`].
-///
-/// [`Scenario`]: gherkin::Scenario
-type IsRetried = bool;
+        /// Line of the [`gherkin::Background`] or [`gherkin::Scenario`].
+        pub(super) line: usize,
 
-/// Default [`Runner`] implementation which follows [_order guarantees_][1] from
-/// the [`Runner`] trait docs.
-///
-/// Executes [`Scenario`]s concurrently based on the custom function, which
-/// returns [`ScenarioType`]. Also, can limit maximum number of concurrent
-/// [`Scenario`]s.
+        /// Results of [`Before`] hooks.
+        ///
+        /// [`Before`]: crate::event::HookType::Before
+        #[serde(default)]
+        before: Vec<Executed>,
+
+        /// Results of [`gherkin::Step`]s.
+        #[serde(default)]
+        steps: Vec<Executed>,
+
+        /// Results of [`After`] hooks.
+        ///
+        /// [`After`]: crate::event::HookType::After
+        #[serde(default)]
+        after: Vec<Executed>,
+    }
+
+    impl Element {
+        /// Returns the total duration of this [`Element`].
+        pub(super) fn duration(&self) -> Duration {
+            self.before
+                .iter()
+                .chain(&self.steps)
+                .chain(&self.after)
+                .filter_map(|e| e.result.as_ref())
+                .map(|r| Duration::from_nanos(r.duration))
+                .sum()
+        }
+    }
+
+    /// Reported [`gherkin::Step`] or hook.
+    #[derive(Deserialize)]
+    struct Executed {
+        /// Result of the execution.
+        result: Option<Result>,
+    }
+
+    /// Result of a [`gherkin::Step`] or hook execution.
+    #[derive(Deserialize)]
+    struct Result {
+        /// Duration of the execution in nanoseconds.
+        #[serde(default)]
+        duration: u64,
+    }
+}
+
+/// Policy of dealing with failures of [`Scenario`]'s hooks.
 ///
-/// [1]: Runner#order-guarantees
 /// [`Scenario`]: gherkin::Scenario
-#[derive(Debug)]
-pub struct Basic<
-    World,
-    F = WhichScenarioFn,
-    Before = BeforeHookFn<World>,
-    After = AfterHookFn<World>,
-> {
-    /// Optional number of concurrently executed [`Scenario`]s.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HookFailurePolicy {
+    /// Fail the [`Scenario`].
     ///
     /// [`Scenario`]: gherkin::Scenario
-    max_concurrent_scenarios: Option<usize>,
+    #[default]
+    FailScenario,
 
-    /// Optional number of retries of failed [`Scenario`]s.
+    /// Skip the [`Scenario`], emitting an [`event::Hook::Ignored`] instead of
+    /// an [`event::Hook::Failed`].
+    ///
+    /// For a [`HookType::Before`] its remaining [`Step`]s are not run, while
+    /// for a [`HookType::After`] this is the same as [`Ignore`].
     ///
+    /// [`Ignore`]: Self::Ignore
     /// [`Scenario`]: gherkin::Scenario
-    retries: Option<usize>,
+    /// [`Step`]: gherkin::Step
+    SkipScenario,
 
-    /// Optional [`Duration`] between retries of failed [`Scenario`]s.
+    /// Fail the [`Scenario`] and retry it once, in case no retries are
+    /// configured for it otherwise.
+    ///
+    /// __NOTE__: As the retries are configured before the [`Scenario`] is
+    ///           run, any its failure (not only the hook one) is retried.
     ///
     /// [`Scenario`]: gherkin::Scenario
-    retry_after: Option<Duration>,
+    RetryScenario,
 
-    /// Optional [`TagOperation`] filter for retries of failed [`Scenario`]s.
+    /// Fail the [`Scenario`] and don't start any new ones, similarly to the
+    /// [`Basic::fail_fast()`].
     ///
     /// [`Scenario`]: gherkin::Scenario
-    retry_filter: Option<TagOperation>,
+    AbortRun,
 
-    /// [`Collection`] of functions to match [`Step`]s.
+    /// Don't fail the [`Scenario`], emitting an [`event::Hook::Ignored`]
+    /// instead of an [`event::Hook::Failed`].
     ///
-    /// [`Collection`]: step::Collection
-    steps: step::Collection<World>,
-
-    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
-    /// a [`Serial`] one.
+    /// For a [`HookType::Before`] the [`Scenario`]'s [`Step`]s are run as if
+    /// it has passed.
     ///
-    /// [`Concurrent`]: ScenarioType::Concurrent
-    /// [`Serial`]: ScenarioType::Serial
     /// [`Scenario`]: gherkin::Scenario
-    #[debug(ignore)]
-    which_scenario: F,
+    /// [`Step`]: gherkin::Step
+    Ignore,
+}
 
-    /// Function determining [`Scenario`]'s [`RetryOptions`].
+impl HookFailurePolicy {
+    /// Indicates whether a hook failure under this [`HookFailurePolicy`]
+    /// doesn't fail the [`Scenario`].
     ///
     /// [`Scenario`]: gherkin::Scenario
-    #[debug(ignore)]
-    retry_options: RetryOptionsFn,
+    const fn is_ignoring(self) -> bool {
+        matches!(self, Self::SkipScenario | Self::Ignore)
+    }
 
-    /// Function, executed on each [`Scenario`] before running all [`Step`]s,
-    /// including [`Background`] ones.
+    /// Returns [`RetryOptions`] retrying a [`Scenario`] once, in case any of
+    /// the provided [`HookFailurePolicy`]s is a [`Self::RetryScenario`].
     ///
-    /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Step`]: gherkin::Step
-    #[debug(ignore)]
-    before_hook: Option<Before>,
+    fn retry_options(policies: [Self; 2]) -> Option<RetryOptions> {
+        policies
+            .contains(&Self::RetryScenario)
+            .then_some(RetryOptions {
+                retries: Retries::initial(1),
+                after: None,
+                reuse_world: false,
+            })
+    }
 
-    /// Function, executed on each [`Scenario`] after running all [`Step`]s.
+    /// Wraps the provided [`RetryOptionsFn`] to fall back to the
+    /// [`HookFailurePolicy::retry_options()`], in case no retries are
+    /// configured for a [`Scenario`] otherwise.
     ///
-    /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Step`]: gherkin::Step
-    #[debug(ignore)]
-    after_hook: Option<After>,
+    #[expect( // intentional
+        clippy::arc_with_non_send_sync,
+        reason = "`RetryOptionsFn` is not required to be `Send`"
+    )]
+    fn wrap_retry_options(
+        policies: [Self; 2],
+        retry_options: RetryOptionsFn,
+    ) -> RetryOptionsFn {
+        let Some(fallback) = Self::retry_options(policies) else {
+            return retry_options;
+        };
+        Arc::new(move |feature, rule, scenario, cli| {
+            retry_options(feature, rule, scenario, cli).or(Some(fallback))
+        })
+    }
+}
 
-    /// Indicates whether execution should be stopped after the first failure.
-    fail_fast: bool,
+/// Policy of dealing with [`Scenario`]s exceeding their time budget (set via
+/// a `@budget(<duration>)` tag).
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default, Display, Eq, Hash, PartialEq)]
+pub enum BudgetPolicy {
+    /// Fail the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[default]
+    #[display("fail")]
+    Fail,
 
-    #[cfg(feature = "tracing")]
-    /// [`TracingCollector`] for [`event::Scenario::Log`]s forwarding.
-    #[debug(ignore)]
-    pub(crate) logs_collector: Arc<AtomicCell<Box<Option<TracingCollector>>>>,
+    /// Only warn about the exceeded budget, without failing the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[display("warn")]
+    Warn,
 }
 
-#[cfg(feature = "tracing")]
-/// Assertion that [`Basic::logs_collector`] [`AtomicCell::is_lock_free`].
-const _: () = {
-    assert!(
-        AtomicCell::<Box<Option<TracingCollector>>>::is_lock_free(),
-        "`AtomicCell::<Box<Option<TracingCollector>>>` is not lock-free",
-    );
-};
+impl FromStr for BudgetPolicy {
+    type Err = &'static str;
 
-// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
-// `#[derive(Clone)]`.
-impl<World, F: Clone, B: Clone, A: Clone> Clone for Basic<World, F, B, A> {
-    fn clone(&self) -> Self {
-        Self {
-            max_concurrent_scenarios: self.max_concurrent_scenarios,
-            retries: self.retries,
-            retry_after: self.retry_after,
-            retry_filter: self.retry_filter.clone(),
-            steps: self.steps.clone(),
-            which_scenario: self.which_scenario.clone(),
-            retry_options: Arc::clone(&self.retry_options),
-            before_hook: self.before_hook.clone(),
-            after_hook: self.after_hook.clone(),
-            fail_fast: self.fail_fast,
-            #[cfg(feature = "tracing")]
-            logs_collector: Arc::clone(&self.logs_collector),
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fail" => Ok(Self::Fail),
+            "warn" => Ok(Self::Warn),
+            _ => Err("possible options: fail, warn"),
         }
     }
 }
 
-impl<World> Default for Basic<World> {
-    fn default() -> Self {
-        let which_scenario: WhichScenarioFn = |feature, rule, scenario| {
-            scenario
-                .tags
-                .iter()
-                .chain(rule.iter().flat_map(|r| &r.tags))
-                .chain(&feature.tags)
-                .find(|tag| *tag == "serial")
-                .map_or(ScenarioType::Concurrent, |_| ScenarioType::Serial)
-        };
+/// Options for retrying [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryOptions {
+    /// Number of [`Retries`].
+    pub retries: Retries,
 
-        Self {
-            max_concurrent_scenarios: Some(64),
-            retries: None,
-            retry_after: None,
-            retry_filter: None,
-            steps: step::Collection::new(),
-            which_scenario,
-            retry_options: Arc::new(RetryOptions::parse_from_tags),
-            before_hook: None,
-            after_hook: None,
-            fail_fast: false,
-            #[cfg(feature = "tracing")]
-            logs_collector: Arc::new(AtomicCell::new(Box::new(None))),
-        }
-    }
-}
+    /// Delay before next retry attempt will be executed.
+    pub after: Option<Duration>,
 
-impl<World, Which, Before, After> Basic<World, Which, Before, After> {
-    /// If `max` is [`Some`], then number of concurrently executed [`Scenario`]s
-    /// will be limited.
+    /// Indicates whether the [`World`] of a failed attempt should be handed to
+    /// the next retry attempt, instead of constructing a new one.
     ///
-    /// [`Scenario`]: gherkin::Scenario
+    /// In such case, neither [`HookType::After`] is executed for the failed
+    /// attempt, nor [`HookType::Before`] is executed for the next one. Also,
+    /// the failure events of the attempt don't carry the [`World`].
+    pub reuse_world: bool,
+}
+
+impl RetryOptions {
+    /// Returns [`Some`], in case next retry attempt is available, or [`None`]
+    /// otherwise.
     #[must_use]
-    pub fn max_concurrent_scenarios(
-        mut self,
-        max: impl Into<Option<usize>>,
-    ) -> Self {
-        self.max_concurrent_scenarios = max.into();
-        self
+    pub fn next_try(self) -> Option<Self> {
+        self.retries.next_try().map(|num| Self {
+            retries: num,
+            after: self.after,
+            reuse_world: self.reuse_world,
+        })
     }
 
-    /// If `retries` is [`Some`], then failed [`Scenario`]s will be retried
-    /// specified number of times.
+    /// Parses [`RetryOptions`] from [`Feature`]'s, [`Rule`]'s, [`Scenario`]'s
+    /// tags and [`Cli`] options.
     ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub fn retries(mut self, retries: impl Into<Option<usize>>) -> Self {
-        self.retries = retries.into();
-        self
+    pub fn parse_from_tags(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        cli: &Cli,
+    ) -> Option<Self> {
+        #[expect(clippy::shadow_unrelated, reason = "actually related")]
+        let parse_tags = |tags: &[String]| {
+            tags.iter().find_map(|tag| {
+                tag.strip_prefix("retry").map(|retries| {
+                    let (num, rest) = retries
+                        .strip_prefix('(')
+                        .and_then(|s| {
+                            s.split_once(')').and_then(|(num, rest)| {
+                                num.parse::<usize>()
+                                    .ok()
+                                    .map(|num| (Some(num), rest))
+                            })
+                        })
+                        .unwrap_or((None, retries));
+
+                    let (reuse_world, rest) = rest
+                        .strip_prefix(".reuse_world")
+                        .map_or((false, rest), |rest| (true, rest));
+
+                    let after = rest.strip_prefix(".after").and_then(|after| {
+                        after.strip_prefix('(').and_then(|after| {
+                            let (dur, rest) = after.split_once(')')?;
+                            humantime::parse_duration(dur)
+                                .ok()
+                                .map(|dur| (dur, rest))
+                        })
+                    });
+                    let reuse_world = reuse_world
+                        || after.is_some_and(|(_, r)| r == ".reuse_world");
+
+                    (num, after.map(|(dur, _)| dur), reuse_world)
+                })
+            })
+        };
+
+        let apply_cli = |options: Option<_>| {
+            let matched = cli.retry_tag_filter.as_ref().map_or_else(
+                || cli.retry.is_some() || cli.retry_after.is_some(),
+                |op| op.matches(feature, rule, scenario),
+            );
+
+            (options.is_some() || matched).then(|| Self {
+                retries: Retries::initial(
+                    options.and_then(|(r, _, _)| r).or(cli.retry).unwrap_or(1),
+                ),
+                after: options.and_then(|(_, a, _)| a).or(cli.retry_after),
+                reuse_world: options.is_some_and(|(_, _, reuse)| reuse),
+            })
+        };
+
+        apply_cli(
+            parse_tags(&scenario.tags)
+                .or_else(|| rule.and_then(|r| parse_tags(&r.tags)))
+                .or_else(|| parse_tags(&feature.tags)),
+        )
     }
 
-    /// If `after` is [`Some`], then failed [`Scenario`]s will be retried after
-    /// the specified [`Duration`].
+    /// Constructs [`RetryOptionsWithDeadline`], that will reschedule
+    /// [`Scenario`] [`after`] delay.
     ///
+    /// [`after`]: RetryOptions::after
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub fn retry_after(mut self, after: impl Into<Option<Duration>>) -> Self {
-        self.retry_after = after.into();
-        self
+    fn with_deadline(self, now: Instant) -> RetryOptionsWithDeadline {
+        RetryOptionsWithDeadline {
+            retries: self.retries,
+            after: self.after.map(|at| (at, Some(now))),
+            reuse_world: self.reuse_world,
+        }
     }
 
-    /// If `filter` is [`Some`], then failed [`Scenario`]s will be retried only
-    /// if they're matching the specified `tag_expression`.
+    /// Constructs [`RetryOptionsWithDeadline`], that will reschedule
+    /// [`Scenario`] immediately, ignoring [`RetryOptions::after`]. Used for
+    /// initial [`Scenario`] run, where we don't need to wait for the delay.
     ///
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub fn retry_filter(
-        mut self,
-        tag_expression: impl Into<Option<TagOperation>>,
-    ) -> Self {
-        self.retry_filter = tag_expression.into();
-        self
+    fn without_deadline(self) -> RetryOptionsWithDeadline {
+        RetryOptionsWithDeadline {
+            retries: self.retries,
+            after: self.after.map(|at| (at, None)),
+            reuse_world: self.reuse_world,
+        }
     }
+}
 
-    /// Makes stop running tests on the first failure.
+/// [`RetryOptions`] with an [`Option`]al [`Instant`] to determine, whether
+/// [`Scenario`] should be already rescheduled or not.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug)]
+pub struct RetryOptionsWithDeadline {
+    /// Number of [`Retries`].
+    pub retries: Retries,
+
+    /// Delay before next retry attempt will be executed.
+    pub after: Option<(Duration, Option<Instant>)>,
+
+    /// Indicates whether the [`World`] of a failed attempt should be handed to
+    /// the next retry attempt.
+    pub reuse_world: bool,
+}
+
+impl From<RetryOptionsWithDeadline> for RetryOptions {
+    fn from(v: RetryOptionsWithDeadline) -> Self {
+        Self {
+            retries: v.retries,
+            after: v.after.map(|(at, _)| at),
+            reuse_world: v.reuse_world,
+        }
+    }
+}
+
+impl RetryOptionsWithDeadline {
+    /// Returns [`Duration`] after which a [`Scenario`] could be retried. If
+    /// [`None`], then [`Scenario`] is ready for the retry.
     ///
-    /// __NOTE__: All the already started [`Scenario`]s at the moment of failure
-    ///           will be finished.
+    /// [`Scenario`]: gherkin::Scenario
+    fn left_until_retry(&self) -> Option<Duration> {
+        let (dur, instant) = self.after?;
+        dur.checked_sub(instant?.elapsed())
+    }
+}
+
+/// Alias for [`fn`] used to determine whether a [`Scenario`] is [`Concurrent`]
+/// or a [`Serial`] one.
+///
+/// [`Concurrent`]: ScenarioType::Concurrent
+/// [`Serial`]: ScenarioType::Serial
+/// [`Scenario`]: gherkin::Scenario
+pub type WhichScenarioFn = fn(
+    &gherkin::Feature,
+    Option<&gherkin::Rule>,
+    &gherkin::Scenario,
+) -> ScenarioType;
+
+/// Alias for [`Arc`]ed [`Fn`] used to determine [`Scenario`]'s
+/// [`RetryOptions`].
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type RetryOptionsFn = Arc<
+    dyn Fn(
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+        &Cli,
+    ) -> Option<RetryOptions>,
+>;
+
+/// Alias for [`fn`] executed on each [`Scenario`] before running all [`Step`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+pub type BeforeHookFn<World> = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a mut World,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Alias for [`fn`] executed on each [`Scenario`] after running all [`Step`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+pub type AfterHookFn<World> = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a event::ScenarioFinished,
+    Option<&'a mut World>,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Alias for an [`Arc`]ed [`Fn`] executed once before running any
+/// [`Scenario`], filling the run-scoped [`shared::State`].
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type BeforeAllHookFn =
+    Arc<dyn for<'a> Fn(&'a mut shared::State) -> LocalBoxFuture<'a, ()>>;
+
+/// Alias for an [`Arc`]ed [`Fn`] executed once for each [`Feature`] before
+/// running any of its [`Scenario`]s.
+///
+/// Fills the [`Feature`]-scoped [`shared::State`], having the run-scoped one
+/// available for reading.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+pub type BeforeFeatureHookFn = Arc<
+    dyn for<'a> Fn(
+        &'a gherkin::Feature,
+        &'a shared::State,
+        &'a mut shared::State,
+    ) -> LocalBoxFuture<'a, ()>,
+>;
+
+/// Alias for an [`Arc`]ed [`Fn`] executed on each failed [`Step`], producing
+/// [`Artifact`]s to be attached to it.
+///
+/// [`Artifact`]: artifacts::Artifact
+/// [`Step`]: gherkin::Step
+pub type StepFailedHookFn<World> = Arc<
+    dyn for<'a> Fn(
+        &'a World,
+        &'a artifacts::FailureInfo,
+    ) -> LocalBoxFuture<'a, Vec<artifacts::Artifact>>,
+>;
+
+/// Alias for a failed [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+type IsFailed = bool;
+
+/// Alias for a skipped [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+type IsSkipped = bool;
+
+/// Alias for a retried [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+type IsRetried = bool;
+
+/// Default [`Runner`] implementation which follows [_order guarantees_][1] from
+/// the [`Runner`] trait docs.
+///
+/// Executes [`Scenario`]s concurrently based on the custom function, which
+/// returns [`ScenarioType`]. Also, can limit maximum number of concurrent
+/// [`Scenario`]s.
+///
+/// [1]: Runner#order-guarantees
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug)]
+#[expect( // intentional
+    clippy::struct_excessive_bools,
+    reason = "independent options, not a state machine"
+)]
+pub struct Basic<
+    World,
+    F = WhichScenarioFn,
+    Before = BeforeHookFn<World>,
+    After = AfterHookFn<World>,
+    Spawn = InPlaceScenarios,
+> {
+    /// Optional number of concurrently executed [`Scenario`]s.
     ///
-    /// __NOTE__: Retried [`Scenario`]s are considered as failed, only in case
-    ///           they exhaust all retry attempts and still fail.
+    /// [`Scenario`]: gherkin::Scenario
+    max_concurrent_scenarios: Option<usize>,
+
+    /// Optional number of retries of failed [`Scenario`]s.
     ///
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub const fn fail_fast(mut self) -> Self {
-        self.fail_fast = true;
-        self
-    }
+    retries: Option<usize>,
+
+    /// Optional [`Duration`] between retries of failed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retry_after: Option<Duration>,
+
+    /// Optional [`tag::Expr`] filter for retries of failed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retry_filter: Option<tag::Expr>,
+
+    /// [`Collection`] of functions to match [`Step`]s.
+    ///
+    /// [`Collection`]: step::Collection
+    steps: step::Collection<World>,
 
     /// Function determining whether a [`Scenario`] is [`Concurrent`] or
     /// a [`Serial`] one.
@@ -523,146 +1156,699 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// [`Concurrent`]: ScenarioType::Concurrent
     /// [`Serial`]: ScenarioType::Serial
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub fn which_scenario<F>(self, func: F) -> Basic<World, F, Before, After>
-    where
-        F: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-            ) -> ScenarioType
-            + 'static,
-    {
-        let Self {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
-            retry_options,
-            before_hook,
-            after_hook,
-            fail_fast,
-            #[cfg(feature = "tracing")]
-            logs_collector,
-            ..
-        } = self;
-        Basic {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
-            which_scenario: func,
-            retry_options,
-            before_hook,
-            after_hook,
-            fail_fast,
-            #[cfg(feature = "tracing")]
-            logs_collector,
-        }
-    }
+    #[debug(ignore)]
+    which_scenario: F,
 
     /// Function determining [`Scenario`]'s [`RetryOptions`].
     ///
     /// [`Scenario`]: gherkin::Scenario
-    #[must_use]
-    pub fn retry_options<R>(mut self, func: R) -> Self
-    where
-        R: Fn(
-                &gherkin::Feature,
-                Option<&gherkin::Rule>,
-                &gherkin::Scenario,
-                &Cli,
-            ) -> Option<RetryOptions>
-            + 'static,
-    {
-        self.retry_options = Arc::new(func);
-        self
-    }
+    #[debug(ignore)]
+    retry_options: RetryOptionsFn,
 
-    /// Sets a hook, executed on each [`Scenario`] before running all its
-    /// [`Step`]s, including [`Background`] ones.
+    /// Function, executed on each [`Scenario`] before running all [`Step`]s,
+    /// including [`Background`] ones.
     ///
     /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
-    #[must_use]
-    pub fn before<Func>(self, func: Func) -> Basic<World, Which, Func, After>
-    where
-        Func: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a mut World,
-        ) -> LocalBoxFuture<'a, ()>,
-    {
-        let Self {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
-            which_scenario,
-            retry_options,
-            after_hook,
-            fail_fast,
+    #[debug(ignore)]
+    before_hook: Option<Before>,
+
+    /// Function, executed on each [`Scenario`] after running all [`Step`]s.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[debug(ignore)]
+    after_hook: Option<After>,
+
+    /// Function, executed once before running any [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[debug(ignore)]
+    before_all_hook: Option<BeforeAllHookFn>,
+
+    /// Function, executed once for each [`Feature`] before running any of its
+    /// [`Scenario`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[debug(ignore)]
+    before_feature_hook: Option<BeforeFeatureHookFn>,
+
+    /// Function, executed on each failed [`Step`], producing [`Artifact`]s
+    /// to be attached to it.
+    ///
+    /// [`Artifact`]: artifacts::Artifact
+    /// [`Step`]: gherkin::Step
+    #[debug(ignore)]
+    step_failed_hook: Option<StepFailedHookFn<World>>,
+
+    /// [`Fixture`]s required by [`Scenario`]s via `@fixture(<name>)` tags.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
+    /// [`Scenario`]: gherkin::Scenario
+    #[debug(ignore)]
+    fixtures: fixtures::Registry<World>,
+
+    /// [`SpawnScenarios`] used for running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    spawn: PhantomData<Spawn>,
+
+    /// [`HookFailurePolicy`] of the [`before`] hook.
+    ///
+    /// [`before`]: Self::before()
+    before_hook_failure: HookFailurePolicy,
+
+    /// [`HookFailurePolicy`] of the [`after`] hook.
+    ///
+    /// [`after`]: Self::after()
+    after_hook_failure: HookFailurePolicy,
+
+    /// Indicates whether execution should be stopped after the first failure.
+    fail_fast: bool,
+
+    /// Indicates whether the most specific [`Step`] wins in case of an
+    /// ambiguous match.
+    ///
+    /// [`Step`]: step::Step
+    most_specific_step_wins: bool,
+
+    /// Indicates whether [`Step`]s keywords should be strictly matched.
+    ///
+    /// [`Step`]: gherkin::Step
+    strict_keywords: bool,
+
+    /// Custom CLI options to create [`World`]s from via [`World::from_cli()`].
+    #[debug(ignore)]
+    custom_cli: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// Optional root directory for [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    artifacts_dir: Option<PathBuf>,
+
+    /// Optional [`artifacts::Retention`] policy of [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    keep_artifacts: Option<artifacts::Retention>,
+
+    /// Optional [`BudgetPolicy`] of [`Scenario`]s exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    budget_policy: Option<BudgetPolicy>,
+
+    /// Indicates whether passed work-in-progress [`Scenario`]s should fail the
+    /// run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    strict_wip: bool,
+
+    /// Optional timeout of [`Step`]s making no progress, after which they're
+    /// reported as [stalled].
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [stalled]: event::Scenario::Stalled
+    watchdog: Option<Duration>,
+
+    /// Optional timeout of the whole execution, after which the remaining
+    /// [`Scenario`]s are reported as [not run].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [not run]: event::Scenario::NotRun
+    global_timeout: Option<Duration>,
+
+    /// Indicates whether [`event::Stall`]s should carry a [`WorldSnapshot`].
+    ///
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    dump_stalled_world: bool,
+
+    /// Indicates whether stdout/stderr output of [`Step`]s should be captured.
+    ///
+    /// [`Step`]: gherkin::Step
+    capture_output: bool,
+
+    /// Indicates whether duplicate names of [`Feature`]s and [`Scenario`]s
+    /// should be reported.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    lint_duplicate_names: bool,
+
+    /// Maximum number of events buffered before being handled by a [`Writer`].
+    ///
+    /// [`Writer`]: crate::Writer
+    max_buffered_events: usize,
+
+    /// [`PanicHookStrategy`] to apply while running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    panic_hook: PanicHookStrategy,
+
+    /// [`Order`] of scheduling [`Feature`]s for running.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    feature_order: Order,
+
+    /// [`Timings`] of [`Scenario`]s to schedule the longest ones first by.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    timings: Timings,
+
+    #[cfg(feature = "tracing")]
+    /// [`TracingCollector`] for [`event::Scenario::Log`]s forwarding.
+    #[debug(ignore)]
+    pub(crate) logs_collector: Arc<AtomicCell<Box<Option<TracingCollector>>>>,
+}
+
+#[cfg(feature = "tracing")]
+/// Assertion that [`Basic::logs_collector`] [`AtomicCell::is_lock_free`].
+const _: () = {
+    assert!(
+        AtomicCell::<Box<Option<TracingCollector>>>::is_lock_free(),
+        "`AtomicCell::<Box<Option<TracingCollector>>>` is not lock-free",
+    );
+};
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<World, F: Clone, B: Clone, A: Clone, S> Clone
+    for Basic<World, F, B, A, S>
+{
+    fn clone(&self) -> Self {
+        Self {
+            max_concurrent_scenarios: self.max_concurrent_scenarios,
+            retries: self.retries,
+            retry_after: self.retry_after,
+            retry_filter: self.retry_filter.clone(),
+            steps: self.steps.clone(),
+            which_scenario: self.which_scenario.clone(),
+            retry_options: Arc::clone(&self.retry_options),
+            before_hook: self.before_hook.clone(),
+            after_hook: self.after_hook.clone(),
+            before_all_hook: self.before_all_hook.clone(),
+            before_feature_hook: self.before_feature_hook.clone(),
+            step_failed_hook: self.step_failed_hook.clone(),
+            fixtures: self.fixtures.clone(),
+            spawn: PhantomData,
+            before_hook_failure: self.before_hook_failure,
+            after_hook_failure: self.after_hook_failure,
+            fail_fast: self.fail_fast,
+            most_specific_step_wins: self.most_specific_step_wins,
+            strict_keywords: self.strict_keywords,
+            custom_cli: self.custom_cli.clone(),
+            artifacts_dir: self.artifacts_dir.clone(),
+            keep_artifacts: self.keep_artifacts,
+            budget_policy: self.budget_policy,
+            strict_wip: self.strict_wip,
+            watchdog: self.watchdog,
+            global_timeout: self.global_timeout,
+            dump_stalled_world: self.dump_stalled_world,
+            capture_output: self.capture_output,
+            lint_duplicate_names: self.lint_duplicate_names,
+            max_buffered_events: self.max_buffered_events,
+            panic_hook: self.panic_hook,
+            feature_order: self.feature_order,
+            timings: self.timings.clone(),
             #[cfg(feature = "tracing")]
-            logs_collector,
-            ..
-        } = self;
-        Basic {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
+            logs_collector: Arc::clone(&self.logs_collector),
+        }
+    }
+}
+
+impl<World> Default for Basic<World> {
+    fn default() -> Self {
+        let which_scenario: WhichScenarioFn = |feature, rule, scenario| {
+            tag::inherited(feature, rule, scenario)
+                .find(|tag| *tag == "serial")
+                .map_or(ScenarioType::Concurrent, |_| ScenarioType::Serial)
+        };
+
+        Self {
+            max_concurrent_scenarios: Some(64),
+            retries: None,
+            retry_after: None,
+            retry_filter: None,
+            steps: step::Collection::new(),
             which_scenario,
-            retry_options,
-            before_hook: Some(func),
-            after_hook,
-            fail_fast,
+            retry_options: Arc::new(RetryOptions::parse_from_tags),
+            before_hook: None,
+            after_hook: None,
+            before_all_hook: None,
+            before_feature_hook: None,
+            step_failed_hook: None,
+            fixtures: fixtures::Registry::default(),
+            spawn: PhantomData,
+            before_hook_failure: HookFailurePolicy::default(),
+            after_hook_failure: HookFailurePolicy::default(),
+            fail_fast: false,
+            most_specific_step_wins: false,
+            strict_keywords: false,
+            custom_cli: None,
+            artifacts_dir: None,
+            keep_artifacts: None,
+            budget_policy: None,
+            strict_wip: false,
+            watchdog: None,
+            global_timeout: None,
+            dump_stalled_world: false,
+            capture_output: false,
+            lint_duplicate_names: false,
+            max_buffered_events: 1024,
+            panic_hook: PanicHookStrategy::default(),
+            feature_order: Order::default(),
+            timings: Timings::default(),
             #[cfg(feature = "tracing")]
-            logs_collector,
+            logs_collector: Arc::new(AtomicCell::new(Box::new(None))),
         }
     }
+}
 
-    /// Sets hook, executed on each [`Scenario`] after running all its
-    /// [`Step`]s, even after [`Skipped`] of [`Failed`] ones.
+impl<World, Which, Before, After, Spawn>
+    Basic<World, Which, Before, After, Spawn>
+{
+    /// If `max` is [`Some`], then number of concurrently executed [`Scenario`]s
+    /// will be limited.
     ///
-    /// Last `World` argument is supplied to the function, in case it was
-    /// initialized before by running [`before`] hook or any [`Step`].
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn max_concurrent_scenarios(
+        mut self,
+        max: impl Into<Option<usize>>,
+    ) -> Self {
+        self.max_concurrent_scenarios = max.into();
+        self
+    }
+
+    /// If `retries` is [`Some`], then failed [`Scenario`]s will be retried
+    /// specified number of times.
     ///
-    /// [`before`]: Self::before()
-    /// [`Failed`]: event::Step::Failed
     /// [`Scenario`]: gherkin::Scenario
-    /// [`Skipped`]: event::Step::Skipped
-    /// [`Step`]: gherkin::Step
     #[must_use]
-    pub fn after<Func>(self, func: Func) -> Basic<World, Which, Before, Func>
-    where
-        Func: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a event::ScenarioFinished,
-            Option<&'a mut World>,
-        ) -> LocalBoxFuture<'a, ()>,
-    {
-        let Self {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
-            which_scenario,
-            retry_options,
-            before_hook,
-            fail_fast,
-            #[cfg(feature = "tracing")]
-            logs_collector,
-            ..
-        } = self;
+    pub fn retries(mut self, retries: impl Into<Option<usize>>) -> Self {
+        self.retries = retries.into();
+        self
+    }
+
+    /// If `after` is [`Some`], then failed [`Scenario`]s will be retried after
+    /// the specified [`Duration`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_after(mut self, after: impl Into<Option<Duration>>) -> Self {
+        self.retry_after = after.into();
+        self
+    }
+
+    /// If `filter` is [`Some`], then failed [`Scenario`]s will be retried only
+    /// if they're matching the specified `tag_expression`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_filter(
+        mut self,
+        tag_expression: Option<impl Into<tag::Expr>>,
+    ) -> Self {
+        self.retry_filter = tag_expression.map(Into::into);
+        self
+    }
+
+    /// Makes stop running tests on the first failure.
+    ///
+    /// __NOTE__: All the already started [`Scenario`]s at the moment of failure
+    ///           will be finished.
+    ///
+    /// __NOTE__: Retried [`Scenario`]s are considered as failed, only in case
+    ///           they exhaust all retry attempts and still fail.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn fail_fast(mut self) -> Self {
+        self.fail_fast = true;
+        self
+    }
+
+    /// Sets the [`HookFailurePolicy`] of the [`before`] hook
+    /// ([`HookFailurePolicy::FailScenario`] by default).
+    ///
+    /// [`before`]: Self::before()
+    #[must_use]
+    pub const fn before_hook_failure(
+        mut self,
+        policy: HookFailurePolicy,
+    ) -> Self {
+        self.before_hook_failure = policy;
+        self
+    }
+
+    /// Sets the [`HookFailurePolicy`] of the [`after`] hook (including
+    /// [`World::as_teardown()`]) ([`HookFailurePolicy::FailScenario`] by
+    /// default).
+    ///
+    /// [`after`]: Self::after()
+    #[must_use]
+    pub const fn after_hook_failure(
+        mut self,
+        policy: HookFailurePolicy,
+    ) -> Self {
+        self.after_hook_failure = policy;
+        self
+    }
+
+    /// Makes the most specific [`Step`] (with the longest literal prefix of its
+    /// [`Regex`]) win in case a [`gherkin::Step`] matches multiple ones,
+    /// instead of failing with an [`AmbiguousMatchError`].
+    ///
+    /// See [`step::Collection::most_specific_wins()`] for details.
+    ///
+    /// [`AmbiguousMatchError`]: step::AmbiguousMatchError
+    /// [`Step`]: step::Step
+    #[must_use]
+    pub const fn most_specific_step_wins(mut self) -> Self {
+        self.most_specific_step_wins = true;
+        self
+    }
+
+    /// Makes keywords of [`Step`]s being strictly honored:
+    /// - `*` bullet [`Step`]s (along with conjunctions following them) inherit
+    ///   the keyword of the preceding [`Step`], instead of always being `Given`
+    ///   ones (see [`resolve_step_keywords()`][1]);
+    /// - [`Step`]s not matching any [`Step`] function of their keyword, but
+    ///   matching the one of another keyword, fail with an
+    ///   [`event::StepError::KeywordMismatch`], instead of being skipped.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [1]: crate::feature::Ext::resolve_step_keywords()
+    #[must_use]
+    pub const fn strict_keywords(mut self) -> Self {
+        self.strict_keywords = true;
+        self
+    }
+
+    /// Sets the custom CLI options (like [`cli::Opts::custom`]) to create
+    /// [`World`]s with via [`World::from_cli()`] instead of [`World::new()`].
+    ///
+    /// This way [`World`]s, and so hooks and [`Step`]s, may be configured from
+    /// command line flags without any global statics.
+    ///
+    /// [`cli::Opts::custom`]: crate::cli::Opts::custom
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn custom_cli<C>(mut self, cli: C) -> Self
+    where
+        C: Any + Send + Sync,
+    {
+        self.custom_cli = Some(Arc::new(cli));
+        self
+    }
+
+    /// Sets the root directory for [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn artifacts_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.artifacts_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the [`artifacts::Retention`] policy of [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn keep_artifacts(
+        mut self,
+        retention: artifacts::Retention,
+    ) -> Self {
+        self.keep_artifacts = Some(retention);
+        self
+    }
+
+    /// Sets the [`BudgetPolicy`] of [`Scenario`]s exceeding their time budget
+    /// (set via a `@budget(<duration>)` tag).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn budget_policy(mut self, policy: BudgetPolicy) -> Self {
+        self.budget_policy = Some(policy);
+        self
+    }
+
+    /// Makes passed work-in-progress [`Scenario`]s (marked with a `@wip` tag
+    /// or using [`Step`] functions marked with a `#[wip]` attribute) fail the
+    /// run, so the fixed behavior doesn't go unnoticed.
+    ///
+    /// Failures of work-in-progress [`Scenario`]s never fail the run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: crate::Step
+    #[must_use]
+    pub const fn strict_wip(mut self) -> Self {
+        self.strict_wip = true;
+        self
+    }
+
+    /// Makes a watchdog reporting [`Step`]s making no progress for longer than
+    /// the provided `timeout` as [stalled] (repeatedly, for each elapsed
+    /// `timeout`), so hung [`Scenario`]s don't stall silently.
+    ///
+    /// __NOTE__: [`Step`]s blocking the thread they're running on are detected
+    ///           only when [`Scenario`]s are spawned on dedicated threads (see
+    ///           [`Basic::spawn_blocking_scenarios()`]), as the watchdog runs
+    ///           on the thread executing the [`Runner`] itself.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [stalled]: event::Scenario::Stalled
+    #[must_use]
+    pub const fn watchdog(mut self, timeout: Duration) -> Self {
+        self.watchdog = Some(timeout);
+        self
+    }
+
+    /// Limits the whole execution to the provided `timeout`.
+    ///
+    /// Once it's exceeded, no more [`Scenario`]s are started, and the running
+    /// ones are interrupted (at their next `.await` point): their currently
+    /// executed [`Step`] (or [`before`] hook) is [failed] with a
+    /// [`FailureCategory::Timeout`], while their [`after`] hook and teardowns
+    /// are still run. The remaining [`Scenario`]s are reported as [not run],
+    /// followed by the [`Cucumber::Finished`] event (having its `timed_out`
+    /// field set), so the [`Writer`]s still output complete reports.
+    ///
+    /// __NOTE__: User code run on dedicated threads (see
+    ///           [`Basic::spawn_blocking_scenarios()`]) cannot be interrupted,
+    ///           so keeps running until its completion, while the [`World`] it
+    ///           operates on is not passed to the [`after`] hook anymore.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`Cucumber::Finished`]: event::Cucumber::Finished
+    /// [`FailureCategory::Timeout`]: event::FailureCategory::Timeout
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`Writer`]: crate::Writer
+    /// [failed]: event::Step::Failed
+    /// [not run]: event::Scenario::NotRun
+    #[must_use]
+    pub const fn global_timeout(mut self, timeout: Duration) -> Self {
+        self.global_timeout = Some(timeout);
+        self
+    }
+
+    /// Makes [stalled] [`Step`]s being reported along with the
+    /// [`WorldSnapshot`] of their [`World`] taken right before they've been
+    /// started (if the [`World`] provides one).
+    ///
+    /// __NOTE__: This takes a [`WorldSnapshot`] before each [`Step`], so may
+    ///           slow down the execution.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    /// [stalled]: event::Scenario::Stalled
+    #[must_use]
+    pub const fn dump_stalled_world(mut self, dump: bool) -> Self {
+        self.dump_stalled_world = dump;
+        self
+    }
+
+    /// Makes stdout/stderr output of [`Step`]s being captured and emitted as
+    /// [`event::Scenario::Output`]s, so [`Writer`]s may show it only for
+    /// failed [`Step`]s.
+    ///
+    /// __NOTE__: Output is captured on the file descriptors level, so includes
+    ///           output of other threads too, while a [`Step`] is being
+    ///           polled. That's why [`Scenario`]s are run one-by-one on the
+    ///           thread running this [`Runner`] while capturing (ignoring
+    ///           [`Basic::max_concurrent_scenarios()`],
+    ///           [`Basic::spawn_blocking_scenarios()`] and
+    ///           [`Basic::spawn_tokio_scenarios()`]). Supported on Unix
+    ///           platforms only, being a no-op (not affecting the
+    ///           concurrency) on the other ones.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`Writer`]: crate::Writer
+    #[must_use]
+    pub const fn capture_output(mut self) -> Self {
+        self.capture_output = true;
+        self
+    }
+
+    /// Makes [`Feature`]s having duplicate names across the whole run, and
+    /// [`Scenario`]s having duplicate names inside a single [`Feature`], being
+    /// reported as [`event::Cucumber::DuplicateName`]s.
+    ///
+    /// Report consumers keying on names (like JUnit or Cucumber JSON ones)
+    /// silently merge results of such [`Feature`]s or [`Scenario`]s otherwise.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn lint_duplicate_names(mut self) -> Self {
+        self.lint_duplicate_names = true;
+        self
+    }
+
+    /// Sets the maximum number of events buffered before being handled by a
+    /// [`Writer`] (`1024` by default).
+    ///
+    /// Once reached, execution of [`Scenario`]s is paused until the [`Writer`]
+    /// catches up, so a slow [`Writer`] exerts backpressure instead of letting
+    /// events pile up in memory.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Writer`]: crate::Writer
+    #[must_use]
+    pub const fn max_buffered_events(mut self, max: usize) -> Self {
+        self.max_buffered_events = max;
+        self
+    }
+
+    /// Sets the [`PanicHookStrategy`] to apply while running [`Scenario`]s
+    /// ([`PanicHookStrategy::Suppress`] by default).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn panic_hook(mut self, strategy: PanicHookStrategy) -> Self {
+        self.panic_hook = strategy;
+        self
+    }
+
+    /// Sets the [`Order`] of scheduling [`Feature`]s for running
+    /// ([`Order::AsDiscovered`] by default).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub const fn feature_order(mut self, order: Order) -> Self {
+        self.feature_order = order;
+        self
+    }
+
+    /// Sets the [`Timings`] of [`Scenario`]s from a previous run, so the
+    /// longest ones are scheduled first.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn timings(mut self, timings: Timings) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    /// Makes each [`Scenario`] run on its own dedicated thread with its own
+    /// lightweight executor, so [`World`]s using blocking clients (like
+    /// `diesel` or a sync HTTP client) don't stall the other [`Scenario`]s,
+    /// sharing a single thread otherwise.
+    ///
+    /// Only the code provided by a user ([`World`] creation, [`Step`]s, the
+    /// [`before`] and [`after`] hooks) is run on the dedicated threads, so the
+    /// [`World`] should be [`Send`], and the hooks should be [`Send`] and
+    /// [`Sync`]. All the events are still emitted on the thread running this
+    /// [`Runner`].
+    ///
+    /// [`after`]: Self::after()
+    /// [`before`]: Self::before()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn spawn_blocking_scenarios(
+        self,
+    ) -> Basic<World, Which, Before, After, BlockingScenarios>
+    where
+        World: Send + 'static,
+    {
+        self.spawn_scenarios()
+    }
+
+    /// Makes each [`Scenario`] run as a task on a pool of threads (one per
+    /// runtime worker) driven by the current multi-threaded [`tokio`] runtime,
+    /// so CPU-bound [`Scenario`]s utilize multiple cores, while having access
+    /// to the [`tokio`] runtime resources (like timers and I/O).
+    ///
+    /// Only the code provided by a user ([`World`] creation, [`Step`]s, the
+    /// [`before`] and [`after`] hooks) is run on the [`tokio`] threads, so the
+    /// [`World`] should be [`Send`], and the hooks should be [`Send`] and
+    /// [`Sync`].
+    ///
+    /// __NOTE__: [`Runner::run()`] must be polled inside a multi-threaded
+    ///           [`tokio`] runtime, otherwise panics.
+    ///
+    /// [`after`]: Self::after()
+    /// [`before`]: Self::before()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn spawn_tokio_scenarios(
+        self,
+    ) -> Basic<World, Which, Before, After, TokioScenarios>
+    where
+        World: Send + 'static,
+    {
+        self.spawn_scenarios()
+    }
+
+    /// Switches the [`SpawnScenarios`] used for running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn spawn_scenarios<S>(self) -> Basic<World, Which, Before, After, S> {
+        let Self {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            before_hook,
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+            ..
+        } = self;
         Basic {
             max_concurrent_scenarios,
             retries,
@@ -672,410 +1858,1926 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             which_scenario,
             retry_options,
             before_hook,
-            after_hook: Some(func),
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            spawn: PhantomData,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+        }
+    }
+
+    /// Function determining whether a [`Scenario`] is [`Concurrent`] or
+    /// a [`Serial`] one.
+    ///
+    /// [`Concurrent`]: ScenarioType::Concurrent
+    /// [`Serial`]: ScenarioType::Serial
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn which_scenario<F>(
+        self,
+        func: F,
+    ) -> Basic<World, F, Before, After, Spawn>
+    where
+        F: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> ScenarioType
+            + 'static,
+    {
+        let Self {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            retry_options,
+            before_hook,
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+            ..
+        } = self;
+        Basic {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario: func,
+            retry_options,
+            before_hook,
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            spawn: PhantomData,
+            before_hook_failure,
+            after_hook_failure,
             fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+        }
+    }
+
+    /// Function determining [`Scenario`]'s [`RetryOptions`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn retry_options<R>(mut self, func: R) -> Self
+    where
+        R: Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+                &Cli,
+            ) -> Option<RetryOptions>
+            + 'static,
+    {
+        self.retry_options = Arc::new(func);
+        self
+    }
+
+    /// Sets a hook, executed on each [`Scenario`] before running all its
+    /// [`Step`]s, including [`Background`] ones.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before<Func>(
+        self,
+        func: Func,
+    ) -> Basic<World, Which, Func, After, Spawn>
+    where
+        Func: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut World,
+        ) -> LocalBoxFuture<'a, ()>,
+    {
+        let Self {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+            ..
+        } = self;
+        Basic {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            before_hook: Some(func),
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            spawn: PhantomData,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+        }
+    }
+
+    /// Sets hook, executed on each [`Scenario`] after running all its
+    /// [`Step`]s, even after [`Skipped`] of [`Failed`] ones.
+    ///
+    /// Last `World` argument is supplied to the function, in case it was
+    /// initialized before by running [`before`] hook or any [`Step`].
+    ///
+    /// [`before`]: Self::before()
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn after<Func>(
+        self,
+        func: Func,
+    ) -> Basic<World, Which, Before, Func, Spawn>
+    where
+        Func: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut World>,
+        ) -> LocalBoxFuture<'a, ()>,
+    {
+        let Self {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            before_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+            ..
+        } = self;
+        Basic {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            before_hook,
+            after_hook: Some(func),
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            spawn: PhantomData,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            #[cfg(feature = "tracing")]
+            logs_collector,
+        }
+    }
+
+    /// Sets a hook, executed once before running any [`Scenario`], filling
+    /// the run-scoped [`shared::State`] accessible in [`Step`]s via
+    /// [`step::Context::shared`].
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before_all<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(&'a mut shared::State) -> LocalBoxFuture<'a, ()>
+            + 'static,
+    {
+        self.before_all_hook = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a hook, executed once for each [`Feature`] before running any of
+    /// its [`Scenario`]s, filling the [`Feature`]-scoped [`shared::State`]
+    /// accessible in [`Step`]s via [`step::Context::shared`].
+    ///
+    /// The run-scoped [`shared::State`] (filled by [`before_all`] hook) is
+    /// provided for reading.
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`before_all`]: Self::before_all()
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn before_feature<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(
+                &'a gherkin::Feature,
+                &'a shared::State,
+                &'a mut shared::State,
+            ) -> LocalBoxFuture<'a, ()>
+            + 'static,
+    {
+        self.before_feature_hook = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a hook, executed on each failed [`Step`] (right after its failure,
+    /// before the [`after`] hook), producing [`Artifact`]s (like screenshots)
+    /// to be attached to the failed [`Step`].
+    ///
+    /// The [`Artifact`]s are kept in the [`artifacts::Dir`] of the
+    /// [`Scenario`] and reported as [`event::Scenario::Artifact`]s right after
+    /// the failed [`Step`]. The hook isn't executed if artifacts aren't kept
+    /// at all (see [`artifacts::Retention::None`]), or there is no [`World`]
+    /// at the time of the failure.
+    ///
+    /// This is the only hook executed after a [`Step`], so it's also the one
+    /// seeing the [`artifacts::FailureInfo::prior_steps`] (including the
+    /// failed [`Step`] itself).
+    ///
+    /// __NOTE__: Panicking in this hook aborts the whole run.
+    ///
+    /// [`after`]: Self::after()
+    /// [`Artifact`]: artifacts::Artifact
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn on_step_failed<Func>(mut self, func: Func) -> Self
+    where
+        Func: for<'a> Fn(
+                &'a World,
+                &'a artifacts::FailureInfo,
+            )
+                -> LocalBoxFuture<'a, Vec<artifacts::Artifact>>
+            + 'static,
+    {
+        self.step_failed_hook = Some(Arc::new(func));
+        self
+    }
+
+    /// Registers the provided [`Fixture`], to be set up for (and torn down
+    /// after) every [`Scenario`] marked with its `@fixture(<name>)` tag.
+    ///
+    /// Replaces the already registered [`Fixture`] with the same name, if any.
+    ///
+    /// See the [`fixtures`] module for more details.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn fixture<Fx>(mut self, fixture: Fx) -> Self
+    where
+        Fx: fixtures::Fixture<World> + Send + Sync + 'static,
+    {
+        self.fixtures.register(fixture);
+        self
+    }
+
+    /// Sets the given [`Collection`] of [`Step`]s to this [`Runner`].
+    ///
+    /// [`Collection`]: step::Collection
+    #[must_use]
+    pub fn steps(mut self, steps: step::Collection<World>) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Adds a [Given] [`Step`] matching the given `regex`.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).given(None, regex, step);
+        self
+    }
+
+    /// Adds a [When] [`Step`] matching the given `regex`.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn when(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).when(None, regex, step);
+        self
+    }
+
+    /// Adds a [Then] [`Step`] matching the given `regex`.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then(mut self, regex: Regex, step: Step<World>) -> Self {
+        self.steps = mem::take(&mut self.steps).then(None, regex, step);
+        self
+    }
+}
+
+impl<W, Which, Before, After, Spawn> Runner<W>
+    for Basic<W, Which, Before, After, Spawn>
+where
+    W: World,
+    Which: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+    Before: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    After: for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    Spawn: SpawnScenarios<W, Before, After>,
+{
+    type Cli = Cli;
+
+    type EventStream =
+        LocalBoxStream<'static, parser::Result<Event<event::Cucumber<W>>>>;
+
+    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
+    fn run<S>(self, features: S, mut cli: Cli) -> Self::EventStream
+    where
+        S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        let logs_collector = *self.logs_collector.swap(Box::new(None));
+        let Self {
+            max_concurrent_scenarios,
+            retries,
+            retry_after,
+            retry_filter,
+            steps,
+            which_scenario,
+            retry_options,
+            before_hook,
+            after_hook,
+            before_all_hook,
+            before_feature_hook,
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            fail_fast,
+            most_specific_step_wins,
+            strict_keywords,
+            custom_cli,
+            artifacts_dir,
+            keep_artifacts,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            global_timeout,
+            dump_stalled_world,
+            capture_output,
+            lint_duplicate_names,
+            max_buffered_events,
+            panic_hook,
+            feature_order,
+            timings,
+            ..
+        } = self;
+
+        cli.retry = cli.retry.or(retries);
+        cli.retry_after = cli.retry_after.or(retry_after);
+        cli.retry_tag_filter = cli.retry_tag_filter.or(retry_filter);
+        cli.lint_duplicate_names |= lint_duplicate_names;
+        let fail_fast = cli.fail_fast || fail_fast;
+        let steps = if most_specific_step_wins {
+            steps.most_specific_wins()
+        } else {
+            steps
+        };
+        let artifacts_root = cli
+            .artifacts_dir
+            .take()
+            .or(artifacts_dir)
+            .unwrap_or_else(artifacts::default_root);
+        let artifacts_retention =
+            cli.keep_artifacts.or(keep_artifacts).unwrap_or_default();
+        let budget_policy =
+            cli.budget_policy.or(budget_policy).unwrap_or_default();
+        let strict_wip = cli.wip || strict_wip;
+        let watchdog = cli.watchdog.or(watchdog);
+        let global_timeout = cli.global_timeout.or(global_timeout);
+        // Output can be captured on Unix platforms only, so `Scenario`s aren't
+        // serialized for nothing on the other ones.
+        let capture_output =
+            cfg!(unix) && (cli.capture_output || capture_output);
+        // Output is captured process-wide, so `Scenario`s are run one-by-one on
+        // the current thread while capturing.
+        let concurrency = if capture_output {
+            Some(1)
+        } else {
+            cli.concurrency.or(max_concurrent_scenarios)
+        };
+        let spawn_scenario = Spawn::spawner().filter(|_| !capture_output);
+        #[cfg(feature = "output-json")]
+        let timings = cli.timings.take().unwrap_or(timings);
+
+        let buffer = Features::default();
+        let (sender, receiver) = mpsc::channel(max_buffered_events);
+
+        let features = features.map(move |res| {
+            res.map(|f| {
+                if strict_keywords {
+                    f.resolve_step_keywords()
+                } else {
+                    f
+                }
+            })
+        });
+        let insert = insert_features(
+            buffer.clone(),
+            features,
+            which_scenario,
+            HookFailurePolicy::wrap_retry_options(
+                [before_hook_failure, after_hook_failure],
+                retry_options,
+            ),
+            sender.clone(),
+            cli,
+            fail_fast,
+            (feature_order, timings),
+        );
+        let execute = execute(
+            buffer,
+            steps,
+            sender,
+            before_hook,
+            after_hook,
+            spawn_scenario,
+            ExecuteConfig {
+                max_concurrent_scenarios: concurrency,
+                before_all_hook,
+                before_feature_hook,
+                step_failed_hook,
+                fixtures,
+                before_hook_failure,
+                after_hook_failure,
+                fail_fast,
+                global_timeout,
+                custom_cli,
+                artifacts_root,
+                artifacts_retention,
+                budget_policy,
+                strict_wip,
+                watchdog,
+                dump_stalled_world,
+                capture_output,
+                strict_keywords,
+                panic_hook,
+            },
+            #[cfg(feature = "tracing")]
+            logs_collector,
+        );
+
+        stream::select(
+            receiver.map(Either::Left),
+            future::join(insert, execute)
+                .into_stream()
+                .map(Either::Right),
+        )
+        .filter_map(|r| async {
+            match r {
+                Either::Left(ev) => Some(ev),
+                Either::Right(_) => None,
+            }
+        })
+        .boxed_local()
+    }
+
+    fn scenario_plan(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        cli: &Cli,
+    ) -> Option<ScenarioPlan> {
+        // Mirrors the `Cli` resolution of `Runner::run()`.
+        let cli = Cli {
+            retry: cli.retry.or(self.retries),
+            retry_after: cli.retry_after.or(self.retry_after),
+            retry_tag_filter: cli
+                .retry_tag_filter
+                .clone()
+                .or_else(|| self.retry_filter.clone()),
+            ..cli.clone()
+        };
+        Some(ScenarioPlan {
+            ty: cli
+                .forced_scenario_type(feature, rule, scenario)
+                .unwrap_or_else(|| {
+                    (self.which_scenario)(feature, rule, scenario)
+                }),
+            retries: (self.retry_options)(feature, rule, scenario, &cli)
+                .or_else(|| {
+                    HookFailurePolicy::retry_options([
+                        self.before_hook_failure,
+                        self.after_hook_failure,
+                    ])
+                }),
+        })
+    }
+
+    fn step_definitions(&self) -> Vec<step::Definition> {
+        self.steps.definitions()
+    }
+
+    fn resolved_config(&self, cli: &Cli) -> Vec<(&'static str, ConfigValue)> {
+        // Mirrors the `Cli` resolution of `Runner::run()`.
+        let duration = |d: Option<Duration>| {
+            ConfigValue::display(d.map(humantime::format_duration))
+        };
+        vec![
+            (
+                "concurrency",
+                if cfg!(unix) && (cli.capture_output || self.capture_output) {
+                    Some(1)
+                } else {
+                    cli.concurrency.or(self.max_concurrent_scenarios)
+                }
+                .into(),
+            ),
+            ("fail-fast", (cli.fail_fast || self.fail_fast).into()),
+            ("retry", cli.retry.or(self.retries).into()),
+            (
+                "retry-after",
+                duration(cli.retry_after.or(self.retry_after)),
+            ),
+            (
+                "retry-tag-filter",
+                ConfigValue::display(
+                    cli.retry_tag_filter
+                        .as_ref()
+                        .or(self.retry_filter.as_ref()),
+                ),
+            ),
+            (
+                "serial-tags",
+                ConfigValue::display(cli.serial_tags.as_ref()),
+            ),
+            (
+                "concurrent-tags",
+                ConfigValue::display(cli.concurrent_tags.as_ref()),
+            ),
+            (
+                "artifacts-dir",
+                ConfigValue::display(Some(
+                    cli.artifacts_dir
+                        .clone()
+                        .or_else(|| self.artifacts_dir.clone())
+                        .unwrap_or_else(artifacts::default_root)
+                        .display(),
+                )),
+            ),
+            (
+                "keep-artifacts",
+                ConfigValue::display(Some(
+                    cli.keep_artifacts
+                        .or(self.keep_artifacts)
+                        .unwrap_or_default(),
+                )),
+            ),
+            (
+                "budget-policy",
+                ConfigValue::display(Some(
+                    cli.budget_policy
+                        .or(self.budget_policy)
+                        .unwrap_or_default(),
+                )),
+            ),
+            ("wip", (cli.wip || self.strict_wip).into()),
+            ("watchdog", duration(cli.watchdog.or(self.watchdog))),
+            (
+                "global-timeout",
+                duration(cli.global_timeout.or(self.global_timeout)),
+            ),
+            (
+                "capture-output",
+                (cli.capture_output || self.capture_output).into(),
+            ),
+            (
+                "lint-duplicate-names",
+                (cli.lint_duplicate_names || self.lint_duplicate_names).into(),
+            ),
+        ]
+    }
+}
+
+/// Stores [`Feature`]s for later use by [`execute()`] in the provided
+/// [`Order`], scheduling the longest [`Scenario`]s first according to the
+/// provided [`Timings`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+#[expect(clippy::too_many_arguments, reason = "needs refactoring")]
+async fn insert_features<W, S, F>(
+    into: Features,
+    features_stream: S,
+    which_scenario: F,
+    retries: RetryOptionsFn,
+    mut sender: mpsc::Sender<parser::Result<Event<event::Cucumber<W>>>>,
+    cli: Cli,
+    fail_fast: bool,
+    (order, timings): (Order, Timings),
+) where
+    S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
+    F: Fn(
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ) -> ScenarioType
+        + 'static,
+{
+    let mut features = 0;
+    let mut rules = 0;
+    let mut scenarios = 0;
+    let mut steps = 0;
+    let mut parser_errors = 0;
+    let mut ordered = vec![];
+    let mut linted = vec![];
+
+    pin_mut!(features_stream);
+    while let Some(feat) = features_stream.next().await {
+        match feat {
+            Ok(f) => {
+                features += 1;
+                rules += f.rules.len();
+                scenarios += f.count_scenarios();
+                steps += f.count_steps();
+
+                let f = Source::new(f);
+                if cli.lint_duplicate_names {
+                    linted.push(f.clone());
+                }
+
+                if matches!(order, Order::AsDiscovered) && timings.is_empty() {
+                    into.insert([f], &which_scenario, &retries, &cli, &timings)
+                        .await;
+                } else {
+                    ordered.push(f);
+                }
+            }
+            Err(e) => {
+                parser_errors += 1;
+
+                // If the receiver end is dropped, then no one listens for the
+                // events, so we can just stop from here.
+                if sender.send(Err(e)).await.is_err() || fail_fast {
+                    break;
+                }
+            }
+        }
+    }
+
+    order.sort(&mut ordered);
+    into.insert(ordered, &which_scenario, &retries, &cli, &timings)
+        .await;
+
+    send_all_events(
+        &mut sender,
+        duplicate_names(&linted)
+            .into_iter()
+            .map(event::Cucumber::DuplicateName),
+    )
+    .await;
+
+    drop(
+        sender
+            .send(Ok(Event::new(event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios: 0,
+            })))
+            .await,
+    );
+
+    into.finish();
+}
+
+/// Detects names shared by several of the provided [`Feature`]s, or by several
+/// [`Scenario`]s of a single [`Feature`].
+///
+/// [`Scenario`]s expanded from the same [Scenario Outline] are distinguished by
+/// their [`Span`], and so are not considered as duplicates.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+/// [`Span`]: gherkin::Span
+/// [Scenario Outline]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+fn duplicate_names(
+    features: &[Source<gherkin::Feature>],
+) -> Vec<event::DuplicateName> {
+    /// Groups the provided `items` by their names, preserving the order of the
+    /// first occurrence, and leaving only the groups of several items.
+    fn duplicates<'a, T: 'a>(
+        items: impl IntoIterator<Item = &'a T>,
+        name: impl Fn(&T) -> &str,
+    ) -> Vec<Vec<&'a T>> {
+        let mut groups = Vec::<Vec<&T>>::new();
+        let mut indices = HashMap::new();
+        for item in items {
+            let i = *indices.entry(name(item)).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[i].push(item);
+        }
+        groups.retain(|g| g.len() > 1);
+        groups
+    }
+
+    let features_dups =
+        duplicates(features, |f| &f.name).into_iter().map(|group| {
+            event::DuplicateName::Features(group.into_iter().cloned().collect())
+        });
+
+    let scenarios_dups = features.iter().flat_map(|feature| {
+        let scenarios = feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+            .unique_by(|sc| sc.span);
+        duplicates(scenarios, |sc| &sc.name)
+            .into_iter()
+            .map(|group| event::DuplicateName::Scenarios {
+                feature: feature.clone(),
+                name: group[0].name.clone(),
+                positions: group.into_iter().map(|sc| sc.position).collect(),
+            })
+    });
+
+    features_dups.chain(scenarios_dups).collect()
+}
+
+/// Configuration of [`execute()`] and its [`Executor`], resolved by a
+/// [`Basic`] [`Runner`] out of its options and [`Cli`].
+#[expect( // intentional
+    clippy::struct_excessive_bools,
+    reason = "independent options, not a state machine"
+)]
+struct ExecuteConfig<W> {
+    /// Max number of concurrently run [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    max_concurrent_scenarios: Option<usize>,
+
+    /// [`BeforeAllHookFn`] executed once before running any [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    before_all_hook: Option<BeforeAllHookFn>,
+
+    /// [`BeforeFeatureHookFn`] executed before running the first [`Scenario`]
+    /// of each [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    before_feature_hook: Option<BeforeFeatureHookFn>,
+
+    /// [`StepFailedHookFn`] executed on each failed [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    step_failed_hook: Option<StepFailedHookFn<W>>,
+
+    /// [`fixtures::Registry`] of the [`World`] fixtures.
+    fixtures: fixtures::Registry<W>,
+
+    /// [`HookFailurePolicy`] of the `before` hook.
+    before_hook_failure: HookFailurePolicy,
+
+    /// [`HookFailurePolicy`] of the `after` hook.
+    after_hook_failure: HookFailurePolicy,
+
+    /// Indicator whether execution should be stopped after the first failure.
+    fail_fast: bool,
+
+    /// Timeout of the whole run, if any.
+    global_timeout: Option<Duration>,
+
+    /// Custom CLI options to create [`World`]s from via [`World::from_cli()`].
+    custom_cli: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// Root directory of [`Scenario`]s artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    artifacts_root: PathBuf,
+
+    /// [`artifacts::Retention`] policy of [`Scenario`]s artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    artifacts_retention: artifacts::Retention,
+
+    /// [`BudgetPolicy`] of [`Scenario`]s exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    budget_policy: BudgetPolicy,
+
+    /// Indicator whether passed work-in-progress [`Scenario`]s fail the run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    strict_wip: bool,
+
+    /// Timeout of [`Step`]s making no progress, after which they're reported
+    /// as [stalled], if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [stalled]: event::Scenario::Stalled
+    watchdog: Option<Duration>,
+
+    /// Indicator whether [`event::Stall`]s carry a [`WorldSnapshot`].
+    ///
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    dump_stalled_world: bool,
+
+    /// Indicator whether stdout/stderr output of [`Step`]s is captured.
+    ///
+    /// [`Step`]: gherkin::Step
+    capture_output: bool,
+
+    /// Indicator whether [`Step`]s keywords are strictly matched.
+    ///
+    /// [`Step`]: gherkin::Step
+    strict_keywords: bool,
+
+    /// [`PanicHookStrategy`] of the run.
+    panic_hook: PanicHookStrategy,
+}
+
+/// Retrieves [`Feature`]s and executes them.
+///
+/// # Events
+///
+/// - [`Scenario`] events are emitted by [`Executor`].
+/// - If [`Scenario`] was first or last for particular [`Rule`] or [`Feature`],
+///   emits starting or finishing events for them.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+// TODO: Needs refactoring.
+#[expect(clippy::too_many_lines, reason = "needs refactoring")]
+#[cfg_attr(
+    feature = "tracing",
+    expect(clippy::too_many_arguments, reason = "needs refactoring")
+)]
+async fn execute<W, Before, After>(
+    features: Features,
+    collection: step::Collection<W>,
+    mut event_sender: mpsc::Sender<parser::Result<Event<event::Cucumber<W>>>>,
+    before_hook: Option<Before>,
+    after_hook: Option<After>,
+    spawn_scenario: Option<SpawnScenarioFn<W, Before, After>>,
+    mut config: ExecuteConfig<W>,
+    #[cfg(feature = "tracing")] mut logs_collector: Option<TracingCollector>,
+) where
+    W: World,
+    Before: 'static
+        + for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a mut W,
+        ) -> LocalBoxFuture<'a, ()>,
+    After: 'static
+        + for<'a> Fn(
+            &'a gherkin::Feature,
+            Option<&'a gherkin::Rule>,
+            &'a gherkin::Scenario,
+            &'a event::ScenarioFinished,
+            Option<&'a mut W>,
+        ) -> LocalBoxFuture<'a, ()>,
+{
+    // Those panic hook shenanigans are done to avoid console messages like
+    // "thread 'main' panicked at ..."
+    //
+    // 1. We obtain the current panic hook and replace it with the one
+    //    capturing a `Backtrace` of the panic (and chaining to the original
+    //    one, if required by the `PanicHookStrategy`).
+    // 2. We run tests, which can panic. In that case we pass all panic info
+    //    (along with the captured `Backtrace`) down the line to the Writer,
+    //    which will print it at a right time.
+    // 3. We restore original panic hook once the guard is dropped (even if
+    //    this `Future` is dropped or panics), because suppressing all panics
+    //    doesn't sound like a very good idea.
+    let panic_hook = PanicHookGuard::install(config.panic_hook);
+    let started_at = Instant::now();
+
+    let ExecuteConfig {
+        max_concurrent_scenarios,
+        fail_fast,
+        global_timeout,
+        watchdog,
+        ..
+    } = config;
+    let before_feature_hook = config.before_feature_hook.take();
+
+    let mut run_state = shared::State::new();
+    if let Some(hook) = config.before_all_hook.take() {
+        hook(&mut run_state).await;
+    }
+    let run_state = Arc::new(run_state);
+    let mut feature_states = HashMap::new();
+
+    // The global timeout is tracked by a separate thread, so fires even if the
+    // running `Scenario`s don't wake up.
+    // TODO: Replace `thread::spawn` with async runtime agnostic sleep, once
+    //       it's available.
+    let deadline = global_timeout
+        .map_or_else(
+            || future::pending().boxed_local(),
+            |timeout| {
+                let (sender, receiver) = oneshot::channel();
+                drop(thread::spawn(move || {
+                    thread::sleep(timeout);
+                    sender.send(())
+                }));
+                receiver.map(drop).boxed_local()
+            },
+        )
+        .shared();
+    let mut timed_out = false;
+
+    let (finished_sender, finished_receiver) = mpsc::unbounded();
+    let mut storage = FinishedRulesAndFeatures::new(finished_receiver);
+    let executor = Executor::new(
+        collection,
+        before_hook,
+        after_hook,
+        spawn_scenario,
+        deadline.clone(),
+        // `Scenario`s are sending their events via a separate `mpsc::Sender`,
+        // so waiting for its buffer slot in this loop doesn't block the one
+        // locked (for the same purpose) by a not polled `Scenario`.
+        event_sender.clone(),
+        finished_sender,
+        features.clone(),
+        config,
+    );
+
+    // The watchdog is woken up periodically by a separate thread, so checks
+    // stalled `Step`s even if they don't wake up their `Scenario`s.
+    // TODO: Replace `thread::spawn` with async runtime agnostic sleep, once
+    //       it's available.
+    let mut watchdog_ticks = watchdog.map(|timeout| {
+        let (sender, receiver) = mpsc::unbounded();
+        let period = (timeout / 4).max(Duration::from_millis(1));
+        drop(thread::spawn(move || {
+            while sender.unbounded_send(()).is_ok() {
+                thread::sleep(period);
+            }
+        }));
+        receiver
+    });
+
+    send_all_events(&mut event_sender, [event::Cucumber::Started]).await;
+
+    #[cfg(feature = "tracing")]
+    let waiter = logs_collector
+        .as_ref()
+        .map(TracingCollector::scenario_span_event_waiter);
+
+    let mut started_scenarios = ControlFlow::Continue(max_concurrent_scenarios);
+    let (mut passed, mut failed, mut skipped, mut retried) = (0, 0, 0, 0);
+    let mut run_scenarios = stream::FuturesUnordered::new();
+    let mut is_serial_running = false;
+    loop {
+        // `Serial` `Scenario` is run in isolation, so has finished once nothing
+        // is running.
+        is_serial_running &= !run_scenarios.is_empty();
+        let (runnable, sleep) = features
+            .get(
+                started_scenarios
+                    .continue_value()
+                    .filter(|_| !is_serial_running)
+                    .unwrap_or(Some(0)),
+                run_scenarios.is_empty(),
+            )
+            .await;
+        if run_scenarios.is_empty() && runnable.is_empty() {
+            if timed_out
+                || features.is_finished(started_scenarios.is_break()).await
+            {
+                break;
+            }
+
+            // To avoid busy-polling of `Features::get()`, in case there are no
+            // scenarios that are running or scheduled for execution, we spawn a
+            // thread, that sleeps for minimal deadline of all retried
+            // scenarios.
+            // TODO: Replace `thread::spawn` with async runtime agnostic sleep,
+            //       once it's available.
+            if let Some(dur) = sleep {
+                let (sender, receiver) = oneshot::channel();
+                drop(thread::spawn(move || {
+                    thread::sleep(dur);
+                    sender.send(())
+                }));
+                timed_out = matches!(
+                    future::select(receiver, deadline.clone()).await,
+                    Either::Right(_),
+                );
+            } else {
+                // Yield to let the `Feature`s stream (which may be awaiting
+                // something, like an asynchronous filter) make progress.
+                future::ready(()).then_yield().await;
+                timed_out = deadline.clone().now_or_never().is_some();
+            }
+
+            if timed_out {
+                break;
+            }
+            continue;
+        }
+
+        for feature in runnable.iter().map(|(_, f, ..)| f).dedup() {
+            if !feature_states.contains_key(feature) {
+                let mut state = shared::State::new();
+                if let Some(hook) = before_feature_hook.as_ref() {
+                    hook(feature, &run_state, &mut state).await;
+                }
+                drop(feature_states.insert(feature.clone(), Arc::new(state)));
+            }
+        }
+
+        let started = storage.start_scenarios(&runnable);
+        send_all_events(&mut event_sender, started).await;
+
+        {
+            #[cfg(feature = "tracing")]
+            let forward_logs = {
+                if let Some(coll) = logs_collector.as_mut() {
+                    coll.start_scenarios(&runnable);
+                }
+                async {
+                    loop {
+                        while let Some(logs) = logs_collector
+                            .as_mut()
+                            .and_then(TracingCollector::emitted_logs)
+                        {
+                            executor.send_all_events(logs).await;
+                        }
+                        future::ready(()).then_yield().await;
+                    }
+                }
+            };
+            #[cfg(feature = "tracing")]
+            pin_mut!(forward_logs);
+            #[cfg(not(feature = "tracing"))]
+            let forward_logs = future::pending();
+
+            let watch_stalls = async {
+                if let Some(ticks) = watchdog_ticks.as_mut() {
+                    while ticks.next().await.is_some() {
+                        executor.emit_stalled_steps().await;
+                    }
+                }
+                future::pending().await
+            };
+            pin_mut!(watch_stalls);
+            let background = future::select(forward_logs, watch_stalls)
+                .map(|either| either.factor_first().0);
+
+            if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios {
+                *sc -= runnable.len();
+            }
+
+            for (id, f, r, s, ty, retries) in runnable {
+                is_serial_running |= ty == ScenarioType::Serial;
+                let shared = shared::Scopes {
+                    run: Arc::clone(&run_state),
+                    feature: feature_states
+                        .get(&f)
+                        .map(Arc::clone)
+                        .unwrap_or_default(),
+                };
+                run_scenarios.push(
+                    executor
+                        .run_scenario(
+                            id,
+                            f,
+                            r,
+                            s,
+                            ty,
+                            retries,
+                            shared,
+                            #[cfg(feature = "tracing")]
+                            waiter.as_ref(),
+                        )
+                        .then_yield(),
+                );
+            }
+
+            // Once the global timeout is exceeded, the running `Scenario`s
+            // are interrupted by the `Executor`, so are just awaited to finish
+            // their `HookType::After`s and teardowns.
+            let deadline = if timed_out {
+                future::pending().right_future()
+            } else {
+                deadline.clone().left_future()
+            };
+            let next_finished = future::select(run_scenarios.next(), deadline)
+                .map(|either| match either {
+                    Either::Left((finished, _)) => finished.map(Ok),
+                    Either::Right(((), _)) => Some(Err(())),
+                });
+            let (finished_scenario, _) =
+                select_with_biased_first(background, next_finished)
+                    .await
+                    .factor_first();
+            timed_out |= finished_scenario == Some(Err(()));
+            if finished_scenario == Some(Ok(())) {
+                if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios
+                {
+                    *sc += 1;
+                }
+            }
+        }
+
+        while let Ok(Some((
+            id,
+            feat,
+            rule,
+            scenario_failed,
+            scenario_skipped,
+            scenario_retried,
+        ))) = storage.finished_receiver.try_next()
+        {
+            if scenario_retried {
+                retried += 1;
+            } else if scenario_failed {
+                failed += 1;
+            } else if scenario_skipped {
+                skipped += 1;
+            } else {
+                passed += 1;
+            }
+
+            if let Some(rule) = rule {
+                if let Some(f) = storage.rule_scenario_finished(
+                    feat.clone(),
+                    rule,
+                    scenario_retried,
+                ) {
+                    send_all_events(&mut event_sender, [f]).await;
+                }
+            }
+            if let Some(f) = storage
+                .feature_scenario_finished(feat.clone(), scenario_retried)
+            {
+                drop(feature_states.remove(&feat));
+                send_all_events(&mut event_sender, [f]).await;
+            }
             #[cfg(feature = "tracing")]
-            logs_collector,
-        }
-    }
+            {
+                if let Some(coll) = logs_collector.as_mut() {
+                    coll.finish_scenario(id);
+                }
+            }
+            #[cfg(not(feature = "tracing"))]
+            let _: ScenarioId = id;
 
-    /// Sets the given [`Collection`] of [`Step`]s to this [`Runner`].
-    ///
-    /// [`Collection`]: step::Collection
-    #[must_use]
-    pub fn steps(mut self, steps: step::Collection<World>) -> Self {
-        self.steps = steps;
-        self
-    }
+            if (fail_fast && scenario_failed && !scenario_retried)
+                || executor.aborted.load(Ordering::SeqCst)
+            {
+                started_scenarios = ControlFlow::Break(());
+            }
+        }
 
-    /// Adds a [Given] [`Step`] matching the given `regex`.
-    ///
-    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
-    #[must_use]
-    pub fn given(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).given(None, regex, step);
-        self
+        if timed_out {
+            started_scenarios = ControlFlow::Break(());
+        }
     }
 
-    /// Adds a [When] [`Step`] matching the given `regex`.
-    ///
-    /// [When]: https://cucumber.io/docs/gherkin/reference#given
-    #[must_use]
-    pub fn when(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).when(None, regex, step);
-        self
-    }
+    // This is done in case of `fail_fast: true` or an exceeded global timeout,
+    // when not all `Scenario`s might be executed.
+    let not_run = features.take_remaining().await;
+    let started = storage.start_scenarios(&not_run);
+    send_all_events(&mut event_sender, started).await;
+    send_all_events(
+        &mut event_sender,
+        not_run.into_iter().flat_map(|(id, f, r, s, _, ret)| {
+            [
+                event::Scenario::Started(id),
+                event::Scenario::NotRun,
+                event::Scenario::Finished,
+            ]
+            .map(|ev| {
+                event::Cucumber::scenario(
+                    f.clone(),
+                    r.clone(),
+                    s.clone(),
+                    ev.with_retries(ret.map(|opts| opts.retries)),
+                )
+            })
+        }),
+    )
+    .await;
+    send_all_events(&mut event_sender, storage.finish_all_rules_and_features())
+        .await;
 
-    /// Adds a [Then] [`Step`] matching the given `regex`.
-    ///
-    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
-    #[must_use]
-    pub fn then(mut self, regex: Regex, step: Step<World>) -> Self {
-        self.steps = mem::take(&mut self.steps).then(None, regex, step);
-        self
-    }
+    send_all_events(
+        &mut event_sender,
+        [event::Cucumber::Finished {
+            passed,
+            failed,
+            skipped,
+            retried,
+            duration: started_at.elapsed(),
+            timed_out,
+        }],
+    )
+    .await;
+
+    drop(panic_hook);
 }
 
-impl<W, Which, Before, After> Runner<W> for Basic<W, Which, Before, After>
-where
-    W: World,
-    Which: Fn(
-            &gherkin::Feature,
-            Option<&gherkin::Rule>,
-            &gherkin::Scenario,
-        ) -> ScenarioType
-        + 'static,
-    Before: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a mut W,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-    After: for<'a> Fn(
-            &'a gherkin::Feature,
-            Option<&'a gherkin::Rule>,
-            &'a gherkin::Scenario,
-            &'a event::ScenarioFinished,
-            Option<&'a mut W>,
-        ) -> LocalBoxFuture<'a, ()>
-        + 'static,
-{
-    type Cli = Cli;
+/// Alias for a [`Future`] resolving once the global timeout is exceeded.
+type Deadline = future::Shared<LocalBoxFuture<'static, ()>>;
 
-    type EventStream =
-        LocalBoxStream<'static, parser::Result<Event<event::Cucumber<W>>>>;
+/// Alias for a function spawning a [`ScenarioThread`] for a [`Scenario`] to be
+/// run.
+///
+/// [`Scenario`]: gherkin::Scenario
+type SpawnScenarioFn<W, Before, After> =
+    Box<dyn Fn() -> ScenarioThread<W, Before, After>>;
 
-    fn run<S>(self, features: S, mut cli: Cli) -> Self::EventStream
-    where
-        S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
-    {
-        #[cfg(feature = "tracing")]
-        let logs_collector = *self.logs_collector.swap(Box::new(None));
-        let Self {
-            max_concurrent_scenarios,
-            retries,
-            retry_after,
-            retry_filter,
-            steps,
-            which_scenario,
-            retry_options,
-            before_hook,
-            after_hook,
-            fail_fast,
-            ..
-        } = self;
+/// Alias for a [`Job`] prepared to be run on a [`ScenarioThread`].
+type Task = Box<dyn FnOnce() -> LocalBoxFuture<'static, ()> + Send>;
 
-        cli.retry = cli.retry.or(retries);
-        cli.retry_after = cli.retry_after.or(retry_after);
-        cli.retry_tag_filter = cli.retry_tag_filter.or(retry_filter);
-        let fail_fast = cli.fail_fast || fail_fast;
-        let concurrency = cli.concurrency.or(max_concurrent_scenarios);
+/// Alias for a caught panic payload, along with the [`Backtrace`] of the
+/// panic (if captured).
+type CaughtPanic = (Box<dyn Any + Send>, Option<Arc<Backtrace>>);
 
-        let buffer = Features::default();
-        let (sender, receiver) = mpsc::unbounded();
+/// Thread running the user code ([`World`] creation, [`Step`]s and hooks) of
+/// a [`Scenario`], outside the thread running a [`Runner`].
+///
+/// All the events are still emitted on the thread running a [`Runner`], so
+/// they're not required to be [`Send`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+struct ScenarioThread<W, Before, After> {
+    /// Sender of [`Task`]s to this [`ScenarioThread`].
+    tasks: mpsc::UnboundedSender<Task>,
 
-        let insert = insert_features(
-            buffer.clone(),
-            features,
-            which_scenario,
-            retry_options,
-            sender.clone(),
-            cli,
-            fail_fast,
-        );
-        let execute = execute(
-            buffer,
-            concurrency,
-            steps,
-            sender,
-            before_hook,
-            after_hook,
-            fail_fast,
-            #[cfg(feature = "tracing")]
-            logs_collector,
-        );
+    /// Function converting a [`Job`] into a [`Task`].
+    ///
+    /// Provided by a [`SpawnScenarios`] implementation, as a [`Job`] is
+    /// [`Send`] only if the [`World`] and the hooks are.
+    into_task: fn(Job<W, Before, After>) -> Task,
+}
 
-        stream::select(
-            receiver.map(Either::Left),
-            future::join(insert, execute)
-                .into_stream()
-                .map(Either::Right),
-        )
-        .filter_map(|r| async {
-            match r {
-                Either::Left(ev) => Some(ev),
-                Either::Right(_) => None,
-            }
-        })
-        .boxed_local()
+impl<W, Before, After> ScenarioThread<W, Before, After> {
+    /// Runs the [`Job`] created by the provided function on this
+    /// [`ScenarioThread`], resolving into its result.
+    ///
+    /// # Panics
+    ///
+    /// If this [`ScenarioThread`] has panicked.
+    async fn run<T>(
+        &self,
+        job: impl FnOnce(oneshot::Sender<T>) -> Job<W, Before, After>,
+    ) -> T {
+        let (sender, receiver) = oneshot::channel();
+        self.tasks
+            .unbounded_send((self.into_task)(job(sender)))
+            .unwrap_or_else(|_| panic!("`Scenario` thread has panicked"));
+        receiver
+            .await
+            .unwrap_or_else(|_| panic!("`Scenario` thread has panicked"))
     }
 }
 
-/// Stores [`Feature`]s for later use by [`execute()`].
+/// Spawns a dedicated thread running the sent [`Task`]s one-by-one with its
+/// own lightweight executor.
 ///
-/// [`Feature`]: gherkin::Feature
-async fn insert_features<W, S, F>(
-    into: Features,
-    features_stream: S,
-    which_scenario: F,
-    retries: RetryOptionsFn,
-    sender: mpsc::UnboundedSender<parser::Result<Event<event::Cucumber<W>>>>,
-    cli: Cli,
-    fail_fast: bool,
-) where
-    S: Stream<Item = parser::Result<gherkin::Feature>> + 'static,
-    F: Fn(
-            &gherkin::Feature,
-            Option<&gherkin::Rule>,
-            &gherkin::Scenario,
-        ) -> ScenarioType
-        + 'static,
-{
-    let mut features = 0;
-    let mut rules = 0;
-    let mut scenarios = 0;
-    let mut steps = 0;
-    let mut parser_errors = 0;
+/// The thread finishes once the returned [`mpsc::UnboundedSender`] is dropped.
+fn spawn_blocking_thread() -> mpsc::UnboundedSender<Task> {
+    let (sender, mut receiver) = mpsc::unbounded::<Task>();
+    drop(thread::spawn(move || {
+        executor::block_on(async move {
+            while let Some(task) = receiver.next().await {
+                task().await;
+            }
+        });
+    }));
+    sender
+}
 
-    pin_mut!(features_stream);
-    while let Some(feat) = features_stream.next().await {
-        match feat {
-            Ok(f) => {
-                features += 1;
-                rules += f.rules.len();
-                scenarios += f.count_scenarios();
-                steps += f.count_steps();
+#[cfg(feature = "tokio")]
+/// Pool of threads (one per worker of the current [`tokio`] runtime) running
+/// [`Task`]s as local tasks driven by the runtime.
+///
+/// [`Task`]s cannot be spawned with [`tokio::spawn()`] directly, as [`Step`]s
+/// are not required to be [`Send`].
+///
+/// [`Step`]: gherkin::Step
+#[derive(Debug)]
+struct TokioScenariosPool {
+    /// Senders of [`Task`]s to the threads of this pool.
+    workers: Vec<mpsc::UnboundedSender<Task>>,
 
-                into.insert(f, &which_scenario, &retries, &cli).await;
-            }
-            Err(e) => {
-                parser_errors += 1;
+    /// Index of the thread to send [`Task`]s of the next [`Scenario`] to.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    next: Cell<usize>,
+}
 
-                // If the receiver end is dropped, then no one listens for the
-                // events, so we can just stop from here.
-                if sender.unbounded_send(Err(e)).is_err() || fail_fast {
-                    break;
-                }
-            }
+#[cfg(feature = "tokio")]
+impl TokioScenariosPool {
+    /// Spawns a new [`TokioScenariosPool`] driven by the current [`tokio`]
+    /// runtime.
+    ///
+    /// # Panics
+    ///
+    /// If called outside a multi-threaded [`tokio`] runtime.
+    fn new() -> Self {
+        let rt = runtime::Handle::current();
+        Self::assert_multi_threaded(&rt);
+        let workers = iter::repeat_with(|| {
+            let (sender, mut receiver) = mpsc::unbounded::<Task>();
+            let rt = rt.clone();
+            // The thread finishes once this pool and all the `ScenarioThread`s
+            // are dropped, as all the sent `Task`s are awaited by the `Runner`
+            // before.
+            drop(thread::spawn(move || {
+                let tasks = task::LocalSet::new();
+                rt.block_on(tasks.run_until(async move {
+                    while let Some(task) = receiver.next().await {
+                        drop(task::spawn_local(task()));
+                    }
+                }));
+            }));
+            sender
+        })
+        .take(rt.metrics().num_workers())
+        .collect();
+        Self {
+            workers,
+            next: Cell::new(0),
         }
     }
 
-    drop(sender.unbounded_send(Ok(Event::new(
-        event::Cucumber::ParsingFinished {
-            features,
-            rules,
-            scenarios,
-            steps,
-            parser_errors,
-        },
-    ))));
+    /// Asserts the provided [`tokio`] runtime being a multi-threaded one.
+    ///
+    /// # Panics
+    ///
+    /// If the provided [`tokio`] runtime is not a multi-threaded one.
+    fn assert_multi_threaded(rt: &runtime::Handle) {
+        assert_eq!(
+            rt.runtime_flavor(),
+            runtime::RuntimeFlavor::MultiThread,
+            "`spawn_tokio_scenarios()` requires a multi-threaded `tokio` \
+             runtime",
+        );
+    }
 
-    into.finish();
+    /// Returns the sender of [`Task`]s to the next thread of this
+    /// [`TokioScenariosPool`].
+    fn next_thread(&self) -> mpsc::UnboundedSender<Task> {
+        let next = self.next.get();
+        self.next.set((next + 1) % self.workers.len());
+        self.workers[next].clone()
+    }
 }
 
-/// Retrieves [`Feature`]s and executes them.
-///
-/// # Events
-///
-/// - [`Scenario`] events are emitted by [`Executor`].
-/// - If [`Scenario`] was first or last for particular [`Rule`] or [`Feature`],
-///   emits starting or finishing events for them.
+/// User code of a [`Scenario`] to be run on a [`ScenarioThread`], along with
+/// the [`oneshot::Sender`] of its result.
 ///
-/// [`Feature`]: gherkin::Feature
-/// [`Rule`]: gherkin::Rule
 /// [`Scenario`]: gherkin::Scenario
-// TODO: Needs refactoring.
-#[expect(clippy::too_many_lines, reason = "needs refactoring")]
-#[cfg_attr(
-    feature = "tracing",
-    expect(clippy::too_many_arguments, reason = "needs refactoring")
-)]
-async fn execute<W, Before, After>(
-    features: Features,
-    max_concurrent_scenarios: Option<usize>,
-    collection: step::Collection<W>,
-    event_sender: mpsc::UnboundedSender<
-        parser::Result<Event<event::Cucumber<W>>>,
-    >,
-    before_hook: Option<Before>,
-    after_hook: Option<After>,
-    fail_fast: bool,
-    #[cfg(feature = "tracing")] mut logs_collector: Option<TracingCollector>,
-) where
+#[expect(clippy::large_enum_variant, reason = "short-living")]
+enum Job<W, Before, After> {
+    /// Creating a new [`World`] via [`init_world()`].
+    NewWorld {
+        /// Custom CLI options to create the [`World`] from.
+        cli: Option<Arc<dyn Any + Send + Sync>>,
+
+        /// Sender of the created [`World`].
+        out: oneshot::Sender<Result<Result<W, String>, CaughtPanic>>,
+    },
+
+    /// Running the [`HookType::Before`] via [`set_up_scenario()`].
+    BeforeHook {
+        /// [`World`] to run the [`HookType::Before`] on.
+        world: W,
+
+        /// [`Fixture`]s to be set up before the [`HookType::Before`].
+        ///
+        /// [`Fixture`]: fixtures::Fixture
+        fixtures: Vec<fixtures::Shared<W>>,
+
+        /// [`HookType::Before`] to run, if any.
+        hook: Option<Arc<Before>>,
+
+        /// [`Feature`], [`Rule`] and [`Scenario`] the [`HookType::Before`]
+        /// is run for.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Rule`]: gherkin::Rule
+        /// [`Scenario`]: gherkin::Scenario
+        sources: ScenarioSources,
+
+        /// Sender of the [`World`] along with the set up [`Fixture`]s.
+        ///
+        /// [`Fixture`]: fixtures::Fixture
+        #[expect(clippy::type_complexity, reason = "still readable")]
+        out: oneshot::Sender<(
+            W,
+            Vec<fixtures::Shared<W>>,
+            Result<Result<(), String>, CaughtPanic>,
+        )>,
+    },
+
+    /// Calling a [`Step`] function.
+    Step {
+        /// [`World`] to call the [`Step`] function on.
+        world: W,
+
+        /// [`Step`] function to call.
+        step_fn: Step<W>,
+
+        /// [`step::Context`] to call the [`Step`] function with.
+        ctx: step::Context,
+
+        /// Sender of the [`World`] after the [`Step`] function is called.
+        out: oneshot::Sender<(W, Result<(), CaughtPanic>)>,
+    },
+
+    /// Running the [`HookType::After`] via [`tear_down_scenario()`].
+    AfterHook {
+        /// [`World`] to run the [`HookType::After`] on, if any.
+        world: Option<W>,
+
+        /// Set up [`Fixture`]s to be torn down after the [`HookType::After`].
+        ///
+        /// [`Fixture`]: fixtures::Fixture
+        fixtures: Vec<fixtures::Shared<W>>,
+
+        /// [`HookType::After`] to run, if any.
+        hook: Option<Arc<After>>,
+
+        /// [`Feature`], [`Rule`] and [`Scenario`] the [`HookType::After`] is
+        /// run for.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Rule`]: gherkin::Rule
+        /// [`Scenario`]: gherkin::Scenario
+        sources: ScenarioSources,
+
+        /// Function creating the [`event::ScenarioFinished`] to pass into the
+        /// [`HookType::After`] (see [`sendable_scenario_finished()`]).
+        ev: Box<dyn FnOnce() -> event::ScenarioFinished + Send>,
+
+        /// Sender of the [`World`] after the [`HookType::After`] is run.
+        out: oneshot::Sender<(Option<W>, Result<(), CaughtPanic>)>,
+    },
+}
+
+impl<W, Before, After> Job<W, Before, After>
+where
     W: World,
-    Before: 'static
-        + for<'a> Fn(
+    Before: for<'a> Fn(
             &'a gherkin::Feature,
             Option<&'a gherkin::Rule>,
             &'a gherkin::Scenario,
             &'a mut W,
-        ) -> LocalBoxFuture<'a, ()>,
-    After: 'static
-        + for<'a> Fn(
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
+    After: for<'a> Fn(
             &'a gherkin::Feature,
             Option<&'a gherkin::Rule>,
             &'a gherkin::Scenario,
             &'a event::ScenarioFinished,
             Option<&'a mut W>,
-        ) -> LocalBoxFuture<'a, ()>,
+        ) -> LocalBoxFuture<'a, ()>
+        + 'static,
 {
-    // Those panic hook shenanigans are done to avoid console messages like
-    // "thread 'main' panicked at ..."
-    //
-    // 1. We obtain the current panic hook and replace it with an empty one.
-    // 2. We run tests, which can panic. In that case we pass all panic info
-    //    down the line to the Writer, which will print it at a right time.
-    // 3. We restore original panic hook, because suppressing all panics doesn't
-    //    sound like a very good idea.
-    let hook = panic::take_hook();
-    panic::set_hook(Box::new(|_| {}));
-
-    let (finished_sender, finished_receiver) = mpsc::unbounded();
-    let mut storage = FinishedRulesAndFeatures::new(finished_receiver);
-    let executor = Executor::new(
-        collection,
-        before_hook,
-        after_hook,
-        event_sender,
-        finished_sender,
-        features.clone(),
-    );
-
-    executor.send_event(event::Cucumber::Started);
-
-    #[cfg(feature = "tracing")]
-    let waiter = logs_collector
-        .as_ref()
-        .map(TracingCollector::scenario_span_event_waiter);
+    /// Converts this [`Job`] into a [`Task`] to be run inside the current
+    /// [`tracing::Span`], so the logs of the user code are attributed to it.
+    fn into_task(self) -> Task
+    where
+        Self: Send,
+    {
+        #[cfg(feature = "tracing")]
+        let span = tracing::Span::current();
+        Box::new(move || {
+            let run = self.run();
+            #[cfg(feature = "tracing")]
+            let run = tracing::Instrument::instrument(run, span);
+            run.boxed_local()
+        })
+    }
 
-    let mut started_scenarios = ControlFlow::Continue(max_concurrent_scenarios);
-    let mut run_scenarios = stream::FuturesUnordered::new();
-    loop {
-        let (runnable, sleep) = features
-            .get(started_scenarios.continue_value().unwrap_or(Some(0)))
-            .await;
-        if run_scenarios.is_empty() && runnable.is_empty() {
-            if features.is_finished(started_scenarios.is_break()).await {
-                break;
+    /// Runs this [`Job`], sending its result.
+    async fn run(self) {
+        match self {
+            Self::NewWorld { cli, out } => {
+                drop(out.send(init_world(cli.as_deref()).await));
             }
-
-            // To avoid busy-polling of `Features::get()`, in case there are no
-            // scenarios that are running or scheduled for execution, we spawn a
-            // thread, that sleeps for minimal deadline of all retried
-            // scenarios.
-            // TODO: Replace `thread::spawn` with async runtime agnostic sleep,
-            //       once it's available.
-            if let Some(dur) = sleep {
-                let (sender, receiver) = oneshot::channel();
-                drop(thread::spawn(move || {
-                    thread::sleep(dur);
-                    sender.send(())
-                }));
-                _ = receiver.await.ok();
+            Self::BeforeHook {
+                mut world,
+                fixtures,
+                hook,
+                sources: (feature, rule, scenario),
+                out,
+            } => {
+                let (set_up, res) = set_up_scenario(
+                    &mut world,
+                    fixtures,
+                    hook.as_deref(),
+                    (&feature, rule.as_deref(), &scenario),
+                )
+                .await;
+                drop(out.send((world, set_up, res)));
+            }
+            Self::Step {
+                mut world,
+                step_fn,
+                ctx,
+                out,
+            } => {
+                let res = catch_panic(step_fn(&mut world, ctx)).await;
+                drop(out.send((world, res)));
+            }
+            Self::AfterHook {
+                mut world,
+                fixtures,
+                hook,
+                sources: (feature, rule, scenario),
+                ev,
+                out,
+            } => {
+                let res = tear_down_scenario(
+                    world.as_mut(),
+                    &fixtures,
+                    hook.as_deref(),
+                    (&feature, rule.as_deref(), &scenario),
+                    &ev(),
+                )
+                .await;
+                drop(out.send((world, res)));
             }
-
-            continue;
         }
+    }
+}
 
-        let started = storage.start_scenarios(&runnable);
-        executor.send_all_events(started);
+/// Alias for the [`Feature`], [`Rule`] and [`Scenario`] a [`Job`] is run for.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioSources = (
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+);
+
+/// Polls the provided [`Future`] to completion, catching its panic, if any.
+async fn catch_panic<T>(
+    fut: impl Future<Output = T>,
+) -> Result<T, CaughtPanic> {
+    AssertUnwindSafe(fut)
+        .catch_unwind()
+        .await
+        .map_err(|payload| (payload, take_panic_backtrace()))
+}
 
-        {
-            #[cfg(feature = "tracing")]
-            let forward_logs = {
-                if let Some(coll) = logs_collector.as_mut() {
-                    coll.start_scenarios(&runnable);
-                }
-                async {
-                    #[expect( // intentional
-                        clippy::infinite_loop,
-                        reason = "cannot annotate `async` block with `-> !"
-                    )]
-                    loop {
-                        while let Some(logs) = logs_collector
-                            .as_mut()
-                            .and_then(TracingCollector::emitted_logs)
-                        {
-                            executor.send_all_events(logs);
-                        }
-                        future::ready(()).then_yield().await;
-                    }
-                }
-            };
-            #[cfg(feature = "tracing")]
-            pin_mut!(forward_logs);
-            #[cfg(not(feature = "tracing"))]
-            let forward_logs = future::pending();
+/// Creates a new [`World`] either [from the custom CLI options][1], if
+/// they're provided, or via [`World::new()`] otherwise.
+///
+/// [1]: World::from_cli()
+async fn init_world<W: World>(
+    cli: Option<&(dyn Any + Send + Sync)>,
+) -> Result<Result<W, String>, CaughtPanic> {
+    catch_panic(async {
+        match cli {
+            Some(cli) => W::from_cli(cli).await,
+            None => W::new().await,
+        }
+        .map_err(|e| e.to_string())
+    })
+    .await
+}
 
-            if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios {
-                *sc -= runnable.len();
-            }
+/// Sets up the provided [`Fixture`]s in order, and runs the provided
+/// [`HookType::Before`] (if any) on the given [`World`].
+///
+/// Returns the set up [`Fixture`]s (even if the [`HookType::Before`] fails),
+/// to be torn down by the [`tear_down_scenario()`].
+///
+/// [`Fixture`]: fixtures::Fixture
+async fn set_up_scenario<W, Before>(
+    world: &mut W,
+    fixtures: Vec<fixtures::Shared<W>>,
+    hook: Option<&Before>,
+    (feature, rule, scenario): (
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ),
+) -> (
+    Vec<fixtures::Shared<W>>,
+    Result<Result<(), String>, CaughtPanic>,
+)
+where
+    Before: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a mut W,
+    ) -> LocalBoxFuture<'a, ()>,
+{
+    let mut set_up = Vec::with_capacity(fixtures.len());
+    let res = catch_panic(async {
+        for fixture in fixtures {
+            fixture.setup(world).await.map_err(|e| {
+                format!("failed to set up `{}` fixture: {e}", fixture.name())
+            })?;
+            set_up.push(fixture);
+        }
+        if let Some(hook) = hook {
+            (hook)(feature, rule, scenario, world).await;
+        }
+        Ok(())
+    })
+    .await;
+    (set_up, res)
+}
 
-            for (id, f, r, s, ty, retries) in runnable {
-                run_scenarios.push(
-                    executor
-                        .run_scenario(
-                            id,
-                            f,
-                            r,
-                            s,
-                            ty,
-                            retries,
-                            #[cfg(feature = "tracing")]
-                            waiter.as_ref(),
-                        )
-                        .then_yield(),
-                );
-            }
+/// Runs the provided [`HookType::After`] (if any), tears down the provided
+/// set up [`Fixture`]s in reverse order, and runs the [`World::as_teardown()`]
+/// (each of them even if the previous one panics).
+///
+/// Returns the first caught panic, if any.
+///
+/// [`Fixture`]: fixtures::Fixture
+async fn tear_down_scenario<W: World, After>(
+    mut world: Option<&mut W>,
+    fixtures: &[fixtures::Shared<W>],
+    hook: Option<&After>,
+    (feature, rule, scenario): (
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ),
+    ev: &event::ScenarioFinished,
+) -> Result<(), CaughtPanic>
+where
+    After: for<'a> Fn(
+        &'a gherkin::Feature,
+        Option<&'a gherkin::Rule>,
+        &'a gherkin::Scenario,
+        &'a event::ScenarioFinished,
+        Option<&'a mut W>,
+    ) -> LocalBoxFuture<'a, ()>,
+{
+    let hook = catch_panic(async {
+        if let Some(hook) = hook {
+            (hook)(feature, rule, scenario, ev, world.as_deref_mut()).await;
+        }
+    })
+    .await;
+
+    let mut fixtures_teardown = Ok(());
+    for fixture in fixtures.iter().rev() {
+        if let Some(w) = world.as_deref_mut() {
+            let res = catch_panic(fixture.teardown(w)).await;
+            fixtures_teardown = fixtures_teardown.and(res);
+        }
+    }
 
-            let (finished_scenario, _) =
-                select_with_biased_first(forward_logs, run_scenarios.next())
-                    .await
-                    .factor_first();
-            if finished_scenario.is_some() {
-                if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios
-                {
-                    *sc += 1;
-                }
-            }
+    let teardown = catch_panic(async {
+        if let Some(teardown) = world.and_then(W::as_teardown) {
+            teardown.await;
         }
+    })
+    .await;
 
-        while let Ok(Some((id, feat, rule, scenario_failed, retried))) =
-            storage.finished_receiver.try_next()
-        {
-            if let Some(rule) = rule {
-                if let Some(f) =
-                    storage.rule_scenario_finished(feat.clone(), rule, retried)
-                {
-                    executor.send_event(f);
-                }
-            }
-            if let Some(f) = storage.feature_scenario_finished(feat, retried) {
-                executor.send_event(f);
-            }
-            #[cfg(feature = "tracing")]
-            {
-                if let Some(coll) = logs_collector.as_mut() {
-                    coll.finish_scenario(id);
-                }
-            }
-            #[cfg(not(feature = "tracing"))]
-            let _: ScenarioId = id;
+    hook.and(fixtures_teardown).and(teardown)
+}
 
-            if fail_fast && scenario_failed && !retried {
-                started_scenarios = ControlFlow::Break(());
-            }
+/// Converts the provided [`event::ScenarioFinished`] into a function
+/// re-creating it on a [`ScenarioThread`].
+///
+/// [`Info`]s are not required to be [`Sync`], so cannot be sent to another
+/// thread, and are re-created out of their textual representation instead.
+fn sendable_scenario_finished(
+    ev: &event::ScenarioFinished,
+) -> Box<dyn FnOnce() -> event::ScenarioFinished + Send> {
+    use event::ScenarioFinished as Ev;
+
+    match ev {
+        Ev::BeforeHookFailed(info) => {
+            let msg = coerce_error(info).into_owned();
+            Box::new(move || Ev::BeforeHookFailed(coerce_into_info(msg)))
+        }
+        Ev::StepPassed => Box::new(|| Ev::StepPassed),
+        Ev::StepSkipped => Box::new(|| Ev::StepSkipped),
+        Ev::StepFailed(captures, loc, err) => {
+            let (captures, loc) = (captures.clone(), *loc);
+            let err = sendable_step_error(err);
+            Box::new(move || Ev::StepFailed(captures, loc, err()))
         }
     }
+}
 
-    // This is done in case of `fail_fast: true`, when not all `Scenario`s might
-    // be executed.
-    executor.send_all_events(storage.finish_all_rules_and_features());
-
-    executor.send_event(event::Cucumber::Finished);
-
-    panic::set_hook(hook);
+/// Converts the provided [`event::StepError`] into a function re-creating it
+/// on a [`ScenarioThread`].
+///
+/// See [`sendable_scenario_finished()`] for details.
+fn sendable_step_error(
+    err: &event::StepError,
+) -> Box<dyn FnOnce() -> event::StepError + Send> {
+    use event::StepError as E;
+
+    match err {
+        E::NotFound => Box::new(|| E::NotFound),
+        E::AmbiguousMatch(e) => {
+            let e = e.clone();
+            Box::new(move || E::AmbiguousMatch(e))
+        }
+        E::KeywordMismatch(ty) => {
+            let ty = *ty;
+            Box::new(move || E::KeywordMismatch(ty))
+        }
+        E::Panic(info, bt) => {
+            let (msg, bt) = (coerce_error(info).into_owned(), bt.clone());
+            Box::new(move || E::Panic(coerce_into_info(msg), bt))
+        }
+        E::Categorized(category, e) => {
+            let (category, e) = (*category, sendable_step_error(e));
+            Box::new(move || E::Categorized(category, Box::new(e())))
+        }
+    }
 }
 
 /// Runs [`Scenario`]s and notifies about their state of completion.
@@ -1093,20 +3795,65 @@ struct Executor<W, Before, After> {
     /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
-    before_hook: Option<Before>,
+    before_hook: Option<Arc<Before>>,
 
     /// Function, executed on each [`Scenario`] after running all [`Step`]s.
     ///
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
-    after_hook: Option<After>,
+    after_hook: Option<Arc<After>>,
+
+    /// Function spawning a [`ScenarioThread`] to run the user code of each
+    /// [`Scenario`] on, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    spawn_thread: Option<SpawnScenarioFn<W, Before, After>>,
+
+    /// Function, executed on each failed [`Step`], producing
+    /// [`artifacts::Artifact`]s to be attached to it.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_failed_hook: Option<StepFailedHookFn<W>>,
+
+    /// [`Fixture`]s required by [`Scenario`]s via `@fixture(<name>)` tags.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
+    /// [`Scenario`]: gherkin::Scenario
+    fixtures: fixtures::Registry<W>,
+
+    /// [`Fixture`]s set up for the currently running [`Scenario`]s, in the
+    /// order of their setup.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
+    /// [`Scenario`]: gherkin::Scenario
+    active_fixtures:
+        Mutex<HashMap<Source<gherkin::Scenario>, Vec<fixtures::Shared<W>>>>,
+
+    /// [`HookFailurePolicy`] of the [`HookType::Before`].
+    before_hook_failure: HookFailurePolicy,
+
+    /// [`HookFailurePolicy`] of the [`HookType::After`].
+    after_hook_failure: HookFailurePolicy,
+
+    /// Indicates whether the run has been aborted due to the
+    /// [`HookFailurePolicy::AbortRun`].
+    aborted: AtomicBool,
+
+    /// [`Deadline`] of the global timeout, interrupting the running
+    /// [`Scenario`]s once exceeded.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    deadline: Deadline,
 
     /// Sender for [`Scenario`] [events][1].
     ///
+    /// Locked while sending, as the [`mpsc::Sender`] is bounded and so should
+    /// be the only one waiting for the buffer to be drained.
+    ///
     /// [`Scenario`]: gherkin::Scenario
     /// [1]: event::Scenario
     event_sender:
-        mpsc::UnboundedSender<parser::Result<Event<event::Cucumber<W>>>>,
+        Mutex<mpsc::Sender<parser::Result<Event<event::Cucumber<W>>>>>,
 
     /// Sender for notifying of [`Scenario`]s completion.
     ///
@@ -1117,6 +3864,61 @@ struct Executor<W, Before, After> {
     ///
     /// [`Scenario`]: gherkin::Scenario
     storage: Features,
+    /// Custom CLI options to create [`World`]s from via [`World::from_cli()`].
+    custom_cli: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// Root directory for [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    artifacts_root: PathBuf,
+
+    /// [`artifacts::Retention`] policy of [`Scenario`]s' artifacts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    artifacts_retention: artifacts::Retention,
+
+    /// [`BudgetPolicy`] of [`Scenario`]s exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    budget_policy: BudgetPolicy,
+
+    /// Indicates whether passed work-in-progress [`Scenario`]s should fail the
+    /// run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    strict_wip: bool,
+
+    /// Timeout of [`Step`]s making no progress, after which they're reported
+    /// as [stalled].
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [stalled]: event::Scenario::Stalled
+    watchdog: Option<Duration>,
+
+    /// Indicates whether [`event::Stall`]s should carry a [`WorldSnapshot`].
+    ///
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    dump_stalled_world: bool,
+
+    /// [`RunningScenario`]s watched by the [`Executor::watchdog`].
+    running_scenarios: Mutex<HashMap<ScenarioId, RunningScenario>>,
+
+    /// [`World`]s of failed [`Scenario`]s, handed to their next retry attempts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    reused_worlds: Mutex<HashMap<Source<gherkin::Scenario>, W>>,
+
+    /// [`capture::Capture`] of stdout/stderr output of [`Step`]s, if it should
+    /// be captured.
+    ///
+    /// [`Step`]: gherkin::Step
+    capture: Option<capture::Capture>,
+
+    /// Indicates whether [`Step`]s matching only [`Step`] functions of other
+    /// keywords should fail.
+    ///
+    /// [`Step`]: gherkin::Step
+    strict_keywords: bool,
 }
 
 impl<W: World, Before, After> Executor<W, Before, After>
@@ -1137,24 +3939,133 @@ where
             Option<&'a mut W>,
         ) -> LocalBoxFuture<'a, ()>,
 {
-    /// Creates a new [`Executor`].
-    const fn new(
+    /// Creates a new [`Executor`] out of the provided [`ExecuteConfig`].
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
+    fn new(
         collection: step::Collection<W>,
         before_hook: Option<Before>,
         after_hook: Option<After>,
-        event_sender: mpsc::UnboundedSender<
-            parser::Result<Event<event::Cucumber<W>>>,
-        >,
+        spawn_thread: Option<SpawnScenarioFn<W, Before, After>>,
+        deadline: Deadline,
+        event_sender: mpsc::Sender<parser::Result<Event<event::Cucumber<W>>>>,
         finished_sender: FinishedFeaturesSender,
         storage: Features,
+        config: ExecuteConfig<W>,
     ) -> Self {
+        let ExecuteConfig {
+            step_failed_hook,
+            fixtures,
+            before_hook_failure,
+            after_hook_failure,
+            custom_cli,
+            artifacts_root,
+            artifacts_retention,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            dump_stalled_world,
+            capture_output,
+            strict_keywords,
+            ..
+        } = config;
         Self {
             collection,
-            before_hook,
-            after_hook,
-            event_sender,
+            before_hook: before_hook.map(Arc::new),
+            after_hook: after_hook.map(Arc::new),
+            spawn_thread,
+            step_failed_hook,
+            fixtures,
+            active_fixtures: Mutex::new(HashMap::new()),
+            before_hook_failure,
+            after_hook_failure,
+            aborted: AtomicBool::new(false),
+            deadline,
+            event_sender: Mutex::new(event_sender),
             finished_sender,
             storage,
+            custom_cli,
+            artifacts_root,
+            artifacts_retention,
+            budget_policy,
+            strict_wip,
+            watchdog,
+            dump_stalled_world,
+            running_scenarios: Mutex::new(HashMap::new()),
+            reused_worlds: Mutex::new(HashMap::new()),
+            capture: capture_output
+                .then(|| capture::Capture::new().ok())
+                .flatten(),
+            strict_keywords,
+        }
+    }
+
+    /// Indicates whether the provided [`Scenario`] is work-in-progress, being
+    /// marked with a `@wip` tag, or having [`Step`]s matching [`Step`]
+    /// functions marked with a `#[wip]` attribute.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[cfg_attr(
+        not(feature = "macros"),
+        expect(clippy::unused_self, reason = "macros are disabled")
+    )]
+    fn is_wip(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> bool {
+        #[cfg(feature = "macros")]
+        let uses_wip_steps = || {
+            feature
+                .background
+                .iter()
+                .chain(rule.iter().flat_map(|r| &r.background))
+                .flat_map(|b| &b.steps)
+                .chain(&scenario.steps)
+                .filter_map(|st| self.collection.find(st).ok().flatten())
+                .filter_map(|(_, _, loc, _)| loc)
+                .any(codegen::is_wip_step)
+        };
+        #[cfg(not(feature = "macros"))]
+        let uses_wip_steps = || false;
+
+        tag::is_wip(feature, rule, scenario) || uses_wip_steps()
+    }
+
+    /// Creates a new [`World`] (see [`init_world()`]) on the provided
+    /// [`ScenarioThread`], if any.
+    async fn new_world(
+        &self,
+        thread: Option<&ScenarioThread<W, Before, After>>,
+    ) -> Result<Result<W, String>, CaughtPanic> {
+        match thread {
+            Some(t) => {
+                let cli = self.custom_cli.clone();
+                t.run(|out| Job::NewWorld { cli, out }).await
+            }
+            None => init_world(self.custom_cli.as_deref()).await,
+        }
+    }
+
+    /// Indicates whether the global timeout has been exceeded.
+    fn is_timed_out(&self) -> bool {
+        self.deadline.clone().now_or_never().is_some()
+    }
+
+    /// Runs the provided [`Future`] until its completion, unless the global
+    /// timeout is exceeded before (in which case [`None`] is returned).
+    async fn until_timed_out<T>(
+        &self,
+        fut: impl Future<Output = T>,
+    ) -> Option<T> {
+        if self.is_timed_out() {
+            return None;
+        }
+        pin_mut!(fut);
+        match future::select(fut, self.deadline.clone()).await {
+            Either::Left((out, _)) => Some(out),
+            Either::Right(((), _)) => None,
         }
     }
 
@@ -1168,10 +4079,10 @@ where
     /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
     // TODO: Needs refactoring.
-    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
-    #[cfg_attr(
-        feature = "tracing",
-        expect(clippy::too_many_arguments, reason = "needs refactoring")
+    #[expect(
+        clippy::too_many_arguments,
+        clippy::too_many_lines,
+        reason = "needs refactoring"
     )]
     async fn run_scenario(
         &self,
@@ -1181,6 +4092,7 @@ where
         scenario: Source<gherkin::Scenario>,
         scenario_ty: ScenarioType,
         retries: Option<RetryOptions>,
+        shared: shared::Scopes,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) {
         let retry_num = retries.map(|r| r.retries);
@@ -1192,49 +4104,159 @@ where
                 event::Cucumber::scenario(f, r, s, event)
             }
         };
-        let ok_capt = |e: fn(_, _, _) -> event::Scenario<W>| {
+        let ok_dur = |e: fn(_, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, dur| {
+                let (f, r, s) = (f.clone(), r.clone(), s.clone());
+                let event = e(step, dur).with_retries(retry_num);
+                event::Cucumber::scenario(f, r, s, event)
+            }
+        };
+        let ok_capt = |e: fn(_, _, _, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, cap, loc, dur| {
+                let (f, r, s) = (f.clone(), r.clone(), s.clone());
+                let event = e(step, cap, loc, dur).with_retries(retry_num);
+                event::Cucumber::scenario(f, r, s, event)
+            }
+        };
+
+        let ok_out = {
             let (f, r, s) = (&feature, &rule, &scenario);
-            move |step, cap, loc| {
+            move |out| {
                 let (f, r, s) = (f.clone(), r.clone(), s.clone());
-                let event = e(step, cap, loc).with_retries(retry_num);
+                let event =
+                    event::Scenario::Output(out).with_retries(retry_num);
                 event::Cucumber::scenario(f, r, s, event)
             }
         };
 
         let compose = |started, passed, skipped| {
-            (ok(started), ok_capt(passed), ok(skipped))
+            (ok(started), ok_capt(passed), ok_dur(skipped), ok_out)
         };
         let into_bg_step_ev = compose(
             event::Scenario::background_step_started,
             event::Scenario::background_step_passed,
             event::Scenario::background_step_skipped,
         );
+        let into_rule_bg_step_ev = compose(
+            event::Scenario::rule_background_step_started,
+            event::Scenario::rule_background_step_passed,
+            event::Scenario::rule_background_step_skipped,
+        );
         let into_step_ev = compose(
             event::Scenario::step_started,
             event::Scenario::step_passed,
             event::Scenario::step_skipped,
         );
 
+        let artifacts = artifacts::Dir::new(
+            &self.artifacts_root,
+            &feature,
+            &scenario,
+            retry_num,
+        );
+        // Stale artifacts of previous runs shouldn't be reported.
+        drop(artifacts.remove());
+        let example = Example::of(&scenario);
+        let prior_steps = PriorSteps::default();
+        let scope = (&artifacts, &shared, example.as_ref(), &prior_steps);
+
         self.send_event(event::Cucumber::scenario(
             feature.clone(),
             rule.clone(),
             scenario.clone(),
-            event::Scenario::Started.with_retries(retry_num),
-        ));
+            event::Scenario::Started(id).with_retries(retry_num),
+        ))
+        .await;
+
+        // Skipped `Scenario`s are reported as skipped on their first `Step`.
+        let skip_first_step = || async {
+            let first_step = feature
+                .background
+                .iter()
+                .flat_map(|b| &b.steps)
+                .map(|st| (st, into_bg_step_ev))
+                .chain(
+                    rule.iter()
+                        .flat_map(|r| &r.background)
+                        .flat_map(|b| &b.steps)
+                        .map(|st| (st, into_rule_bg_step_ev)),
+                )
+                .chain(scenario.steps.iter().map(|st| (st, into_step_ev)))
+                .next();
+            if let Some((step, (started, _, skipped, _))) = first_step {
+                let step = Source::new(step.clone());
+                self.send_all_events([
+                    started(step.clone()),
+                    skipped(step, Duration::ZERO),
+                ])
+                .await;
+            }
+        };
+
+        // `@ignore`d (or `@skip`ped) `Scenario`s aren't run at all.
+        if tag::is_ignored(&feature, rule.as_deref(), &scenario) {
+            skip_first_step().await;
+
+            self.send_event(event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario,
+                event::Scenario::Finished.with_retries(retry_num),
+            ))
+            .await;
+            self.scenario_finished(id, feature, rule, false, true, false);
+            return;
+        }
+
+        let is_wip = self.is_wip(&feature, rule.as_deref(), &scenario);
+        if is_wip {
+            self.send_event(event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario.clone(),
+                event::Scenario::Wip(event::Wip::Marked)
+                    .with_retries(retry_num),
+            ))
+            .await;
+        }
+
+        self.watch_scenario(
+            id,
+            feature.clone(),
+            rule.clone(),
+            scenario.clone(),
+            retry_num,
+        )
+        .await;
 
+        let thread = self.spawn_thread.as_ref().map(|spawn| spawn());
+        let thread = thread.as_ref();
+        let reused_world = self.reused_worlds.lock().await.remove(&scenario);
+        let env = EnvGuard::set(tag::env(&feature, rule.as_deref(), &scenario));
+
+        let started = Instant::now();
         let is_failed = async {
             let mut result = async {
-                let before_hook = self
-                    .run_before_hook(
-                        &feature,
-                        rule.as_ref(),
-                        &scenario,
-                        retry_num,
-                        id,
-                        #[cfg(feature = "tracing")]
-                        waiter,
-                    )
-                    .await?;
+                // `World` handed from the failed attempt has already been
+                // passed through the `HookType::Before`.
+                let before_hook = match reused_world {
+                    Some(world) => Some(world),
+                    None => {
+                        self.run_before_hook(
+                            &feature,
+                            rule.as_ref(),
+                            &scenario,
+                            retry_num,
+                            id,
+                            thread,
+                            #[cfg(feature = "tracing")]
+                            waiter,
+                        )
+                        .await?
+                    }
+                };
 
                 let feature_background = feature
                     .background
@@ -1249,9 +4271,11 @@ where
                         self.run_step(
                             world,
                             bg_step,
-                            true,
+                            StepKind::FeatureBackground,
                             into_bg_step_ev,
                             id,
+                            thread,
+                            scope,
                             #[cfg(feature = "tracing")]
                             waiter,
                         )
@@ -1279,9 +4303,11 @@ where
                         self.run_step(
                             world,
                             bg_step,
-                            true,
-                            into_bg_step_ev,
+                            StepKind::RuleBackground,
+                            into_rule_bg_step_ev,
                             id,
+                            thread,
+                            scope,
                             #[cfg(feature = "tracing")]
                             waiter,
                         )
@@ -1297,9 +4323,11 @@ where
                     self.run_step(
                         world,
                         step,
-                        false,
+                        StepKind::Scenario,
                         into_step_ev,
                         id,
+                        thread,
+                        scope,
                         #[cfg(feature = "tracing")]
                         waiter,
                     )
@@ -1309,6 +4337,43 @@ where
             }
             .await;
 
+            let failure_artifacts = match &result {
+                Err(ExecutionFailure::StepPanicked {
+                    world: Some(world),
+                    step,
+                    err,
+                    kind,
+                    ..
+                }) if self.artifacts_retention.keeps(true) => {
+                    self.run_step_failed_hook(
+                        world,
+                        artifacts::FailureInfo {
+                            feature: feature.clone(),
+                            rule: rule.clone(),
+                            scenario: scenario.clone(),
+                            step: step.clone(),
+                            is_background: kind.is_background(),
+                            error: err.clone(),
+                            retries: retry_num,
+                            prior_steps: prior_steps.snapshot(),
+                        },
+                        &artifacts,
+                    )
+                    .await
+                }
+                Ok(_) | Err(_) => vec![],
+            };
+
+            // `World` of the failed attempt is handed to the next retry
+            // attempt as is, so the `HookType::After` isn't executed on it.
+            let hands_over_world = retries
+                .is_some_and(|r| r.reuse_world && r.next_try().is_some())
+                && !self.is_timed_out()
+                && matches!(
+                    result,
+                    Err(ExecutionFailure::StepPanicked { world: Some(_), .. }),
+                );
+
             let (world, scenario_finished_ev) = match &mut result {
                 Ok(world) => {
                     (world.take(), event::ScenarioFinished::StepPassed)
@@ -1319,31 +4384,70 @@ where
                 ),
             };
 
-            let (world, after_hook_meta, after_hook_error) = self
-                .run_after_hook(
-                    world,
-                    &feature,
-                    rule.as_ref(),
-                    &scenario,
-                    scenario_finished_ev,
-                    id,
-                    #[cfg(feature = "tracing")]
-                    waiter,
-                )
-                .await
-                .map_or_else(
-                    |(w, meta, info)| (w.map(Arc::new), Some(meta), Some(info)),
-                    |(w, meta)| (w.map(Arc::new), meta, None),
-                );
+            let (world, after_hook_meta, after_hook_error, handed_world) =
+                if hands_over_world {
+                    (None, None, None, world)
+                } else {
+                    // `ScenarioThread` may still be stuck in the user code
+                    // interrupted by the global timeout, so the
+                    // `HookType::After` is run on a fresh one.
+                    let fresh_thread = self
+                        .spawn_thread
+                        .as_ref()
+                        .filter(|_| self.is_timed_out())
+                        .map(|spawn| spawn());
+                    let (w, meta, err) = self
+                        .run_after_hook(
+                            world,
+                            &feature,
+                            rule.as_ref(),
+                            &scenario,
+                            scenario_finished_ev,
+                            id,
+                            fresh_thread.as_ref().or(thread),
+                            #[cfg(feature = "tracing")]
+                            waiter,
+                        )
+                        .await
+                        .map_or_else(
+                            |(w, meta, info)| {
+                                (w.map(Arc::new), Some(meta), Some(info))
+                            },
+                            |(w, meta)| (w.map(Arc::new), meta, None),
+                        );
+                    (w, meta, err, None)
+                };
 
             let scenario_failed = match &result {
-                Ok(_) | Err(ExecutionFailure::StepSkipped(_)) => false,
+                Ok(_)
+                | Err(
+                    ExecutionFailure::BeforeHookSkipped(_)
+                    | ExecutionFailure::StepSkipped(_),
+                ) => false,
                 Err(
                     ExecutionFailure::BeforeHookPanicked { .. }
                     | ExecutionFailure::StepPanicked { .. },
                 ) => true,
             };
-            let is_failed = scenario_failed || after_hook_error.is_some();
+            let after_hook_failure = self.after_hook_failure;
+            if after_hook_error.is_some()
+                && after_hook_failure == HookFailurePolicy::AbortRun
+            {
+                self.aborted.store(true, Ordering::SeqCst);
+            }
+            let is_failed = scenario_failed
+                || (after_hook_error.is_some()
+                    && !after_hook_failure.is_ignoring());
+            let is_skipped = !is_failed
+                && matches!(
+                    result,
+                    Err(ExecutionFailure::BeforeHookSkipped(_)
+                        | ExecutionFailure::StepSkipped(_)),
+                );
+
+            if matches!(result, Err(ExecutionFailure::BeforeHookSkipped(_))) {
+                skip_first_step().await;
+            }
 
             if let Some(exec_error) = result.err() {
                 self.emit_failed_events(
@@ -1353,20 +4457,34 @@ where
                     world.clone(),
                     exec_error,
                     retry_num,
-                );
+                )
+                .await;
             }
+            self.send_all_events(failure_artifacts.iter().map(|path| {
+                event::Cucumber::scenario(
+                    feature.clone(),
+                    rule.clone(),
+                    scenario.clone(),
+                    event::Scenario::Artifact(path.clone())
+                        .with_retries(retry_num),
+                )
+            }))
+            .await;
 
-            self.emit_after_hook_events(
-                feature.clone(),
-                rule.clone(),
-                scenario.clone(),
-                world,
-                after_hook_meta,
-                after_hook_error,
-                retry_num,
-            );
+            if !hands_over_world {
+                self.emit_after_hook_events(
+                    feature.clone(),
+                    rule.clone(),
+                    scenario.clone(),
+                    world,
+                    after_hook_meta,
+                    after_hook_error,
+                    retry_num,
+                )
+                .await;
+            }
 
-            is_failed
+            (is_failed, is_skipped, failure_artifacts, handed_world)
         };
         #[cfg(feature = "tracing")]
         let (is_failed, span_id) = {
@@ -1375,24 +4493,82 @@ where
             let is_failed = tracing::Instrument::instrument(is_failed, span);
             (is_failed, span_id)
         };
-        let is_failed = is_failed.then_yield().await;
+        let (is_failed, is_skipped, failure_artifacts, handed_world) =
+            is_failed.then_yield().await;
+        drop(env);
+        self.unwatch_scenario(id).await;
 
         #[cfg(feature = "tracing")]
         if let Some((waiter, span_id)) = waiter.zip(span_id) {
             waiter.wait_for_span_close(span_id).then_yield().await;
         }
 
+        let budget = tag::budget(&feature, rule.as_deref(), &scenario)
+            .map(|limit| event::Budget {
+                limit,
+                elapsed: started.elapsed(),
+                failed: self.budget_policy == BudgetPolicy::Fail,
+            })
+            .filter(|b| b.elapsed > b.limit);
+        if let Some(budget) = budget {
+            self.send_event(event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario.clone(),
+                event::Scenario::BudgetExceeded(budget).with_retries(retry_num),
+            ))
+            .await;
+        }
+        let is_failed = is_failed || budget.is_some_and(|b| b.failed);
+
+        if is_wip && self.strict_wip && !is_failed && !is_skipped {
+            self.send_event(event::Cucumber::scenario(
+                feature.clone(),
+                rule.clone(),
+                scenario.clone(),
+                event::Scenario::Wip(event::Wip::Passed)
+                    .with_retries(retry_num),
+            ))
+            .await;
+        }
+
+        if self.artifacts_retention.keeps(is_failed) {
+            let files = artifacts.files().into_iter();
+            let files = files.filter(|p| !failure_artifacts.contains(p));
+            self.send_all_events(files.map(|path| {
+                event::Cucumber::scenario(
+                    feature.clone(),
+                    rule.clone(),
+                    scenario.clone(),
+                    event::Scenario::Artifact(path).with_retries(retry_num),
+                )
+            }))
+            .await;
+        } else {
+            drop(artifacts.remove());
+        }
+
         self.send_event(event::Cucumber::scenario(
             feature.clone(),
             rule.clone(),
             scenario.clone(),
             event::Scenario::Finished.with_retries(retry_num),
-        ));
+        ))
+        .await;
 
+        // No more attempts are started once the global timeout is exceeded.
         let next_try = retries
-            .filter(|_| is_failed)
+            .filter(|_| is_failed && !self.is_timed_out())
             .and_then(RetryOptions::next_try);
         if let Some(next_try) = next_try {
+            if let Some(world) = handed_world {
+                drop(
+                    self.reused_worlds
+                        .lock()
+                        .await
+                        .insert(scenario.clone(), world),
+                );
+            }
             self.storage
                 .insert_retried_scenario(
                     feature.clone(),
@@ -1409,18 +4585,28 @@ where
             feature,
             rule,
             is_failed,
+            is_skipped,
             next_try.is_some(),
         );
     }
 
-    /// Executes [`HookType::Before`], if present.
+    /// Executes [`HookType::Before`], if present, after setting up the
+    /// [`Fixture`]s required by the [`Scenario`].
     ///
     /// # Events
     ///
     /// - Emits all the [`HookType::Before`] events, except [`Hook::Failed`].
     ///   See [`Self::emit_failed_events()`] for more details.
     ///
+    /// [`Fixture`]: fixtures::Fixture
     /// [`Hook::Failed`]: event::Hook::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    // TODO: Needs refactoring.
+    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
+    #[cfg_attr(
+        feature = "tracing",
+        expect(clippy::too_many_arguments, reason = "needs refactoring")
+    )]
     async fn run_before_hook(
         &self,
         feature: &Source<gherkin::Feature>,
@@ -1428,14 +4614,14 @@ where
         scenario: &Source<gherkin::Scenario>,
         retries: Option<Retries>,
         scenario_id: ScenarioId,
+        thread: Option<&ScenarioThread<W, Before, After>>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<Option<W>, ExecutionFailure<W>> {
         let init_world = async {
-            AssertUnwindSafe(async { W::new().await })
-                .catch_unwind()
+            self.new_world(thread)
                 .then_yield()
                 .await
-                .map_err(Info::from)
+                .map_err(|(payload, _)| panic_into_info(payload))
                 .and_then(|r| {
                     r.map_err(|e| {
                         coerce_into_info(format!(
@@ -1446,69 +4632,166 @@ where
                 .map_err(|info| (info, None))
         };
 
-        if let Some(hook) = self.before_hook.as_ref() {
-            self.send_event(event::Cucumber::scenario(
-                feature.clone(),
-                rule.cloned(),
-                scenario.clone(),
-                event::Scenario::hook_started(HookType::Before)
-                    .with_retries(retries),
-            ));
+        let fixtures = self
+            .fixtures
+            .resolve(tag::fixtures(feature, rule.map(AsRef::as_ref), scenario))
+            .map_err(|e| {
+                coerce_into_info(format!("failed to resolve fixtures: {e}"))
+            });
+        if self.before_hook.is_none()
+            && fixtures.as_ref().is_ok_and(Vec::is_empty)
+        {
+            return Ok(None);
+        }
+
+        self.send_event(event::Cucumber::scenario(
+            feature.clone(),
+            rule.cloned(),
+            scenario.clone(),
+            event::Scenario::hook_started(HookType::Before)
+                .with_retries(retries),
+        ))
+        .await;
 
-            let fut = init_world.and_then(|mut world| async {
-                let fut = async {
-                    (hook)(
+        let fut = init_world.and_then(|mut world| async {
+            let fixtures = match fixtures {
+                Ok(f) => f,
+                Err(info) => return Err((info, Some(world))),
+            };
+            // `World` is lost, in case the `HookType::Before` run on a
+            // `ScenarioThread` is interrupted by the global timeout.
+            let hook = self.before_hook.as_ref();
+            let (world, set_up, res) = if let Some(t) = thread {
+                let sources =
+                    (feature.clone(), rule.cloned(), scenario.clone());
+                let job = t.run(|out| Job::BeforeHook {
+                    world,
+                    fixtures,
+                    hook: hook.cloned(),
+                    sources,
+                    out,
+                });
+                let Some((w, set_up, res)) = self.until_timed_out(job).await
+                else {
+                    let (payload, _) = timed_out_panic();
+                    return Err((panic_into_info(payload), None));
+                };
+                (w, set_up, res)
+            } else {
+                let set_up = set_up_scenario(
+                    &mut world,
+                    fixtures,
+                    hook.map(AsRef::as_ref),
+                    (
                         feature.as_ref(),
-                        rule.as_ref().map(AsRef::as_ref),
+                        rule.map(AsRef::as_ref),
                         scenario.as_ref(),
-                        &mut world,
-                    )
-                    .await;
-                };
-                match AssertUnwindSafe(fut).catch_unwind().await {
-                    Ok(()) => Ok(world),
-                    Err(i) => Err((Info::from(i), Some(world))),
+                    ),
+                );
+                let (set_up, res) = self
+                    .until_timed_out(set_up)
+                    .await
+                    .unwrap_or_else(|| (vec![], Err(timed_out_panic())));
+                (world, set_up, res)
+            };
+            if !set_up.is_empty() {
+                self.active_fixtures
+                    .lock()
+                    .await
+                    .entry(scenario.clone())
+                    .or_default()
+                    .extend(set_up);
+            }
+            match res {
+                Ok(Ok(())) => Ok(world),
+                Ok(Err(e)) => Err((coerce_into_info(e), Some(world))),
+                Err((payload, _)) => {
+                    Err((panic_into_info(payload), Some(world)))
                 }
-            });
+            }
+        });
 
-            #[cfg(feature = "tracing")]
-            let (fut, span_id) = {
-                let span = scenario_id.hook_span(HookType::Before);
-                let span_id = span.id();
-                let fut = tracing::Instrument::instrument(fut, span);
-                (fut, span_id)
-            };
-            #[cfg(not(feature = "tracing"))]
-            let _: ScenarioId = scenario_id;
+        #[cfg(feature = "tracing")]
+        let (fut, span_id) = {
+            let span = scenario_id.hook_span(HookType::Before);
+            let span_id = span.id();
+            let fut = tracing::Instrument::instrument(fut, span);
+            (fut, span_id)
+        };
+        #[cfg(not(feature = "tracing"))]
+        let _: ScenarioId = scenario_id;
 
-            let result = fut.then_yield().await;
+        let result = fut.then_yield().await;
 
-            #[cfg(feature = "tracing")]
-            if let Some((waiter, id)) = waiter.zip(span_id) {
-                waiter.wait_for_span_close(id).then_yield().await;
-            }
+        #[cfg(feature = "tracing")]
+        if let Some((waiter, id)) = waiter.zip(span_id) {
+            waiter.wait_for_span_close(id).then_yield().await;
+        }
 
-            match result {
-                Ok(world) => {
-                    self.send_event(event::Cucumber::scenario(
-                        feature.clone(),
-                        rule.cloned(),
-                        scenario.clone(),
-                        event::Scenario::hook_passed(HookType::Before)
-                            .with_retries(retries),
-                    ));
-                    Ok(Some(world))
+        match result {
+            Ok(world) => {
+                self.send_event(event::Cucumber::scenario(
+                    feature.clone(),
+                    rule.cloned(),
+                    scenario.clone(),
+                    event::Scenario::hook_passed(HookType::Before)
+                        .with_retries(retries),
+                ))
+                .await;
+                Ok(Some(world))
+            }
+            Err((panic_info, world)) => {
+                let policy = self.before_hook_failure;
+                if policy == HookFailurePolicy::AbortRun {
+                    self.aborted.store(true, Ordering::SeqCst);
                 }
-                Err((panic_info, world)) => {
-                    Err(ExecutionFailure::BeforeHookPanicked {
+                if !policy.is_ignoring() {
+                    return Err(ExecutionFailure::BeforeHookPanicked {
                         world,
                         panic_info,
                         meta: event::Metadata::new(()),
-                    })
+                    });
+                }
+
+                self.send_event(event::Cucumber::scenario(
+                    feature.clone(),
+                    rule.cloned(),
+                    scenario.clone(),
+                    event::Scenario::hook_ignored(HookType::Before, panic_info)
+                        .with_retries(retries),
+                ))
+                .await;
+                if policy == HookFailurePolicy::SkipScenario {
+                    Err(ExecutionFailure::BeforeHookSkipped(world))
+                } else {
+                    Ok(world)
                 }
             }
-        } else {
-            Ok(None)
+        }
+    }
+
+    /// Finds a [`Step`] function matching the provided [`gherkin::Step`], if
+    /// any.
+    ///
+    /// # Errors
+    ///
+    /// - If the [`gherkin::Step`] matches multiple [`Step`] functions.
+    /// - If the [`gherkin::Step`] matches only [`Step`] functions of another
+    ///   keyword, while [`Executor::strict_keywords`] is set.
+    fn find_step(
+        &self,
+        step: &gherkin::Step,
+    ) -> Result<Option<step::WithContext<'_, W>>, event::StepError> {
+        match self.collection.find(step) {
+            Ok(Some(f)) => Ok(Some(f)),
+            Ok(None) => self
+                .strict_keywords
+                .then(|| self.collection.find_other_kind(step))
+                .flatten()
+                .map_or(Ok(None), |ty| {
+                    Err(event::StepError::KeywordMismatch(ty))
+                }),
+            Err(e) => Err(event::StepError::AmbiguousMatch(e)),
         }
     }
 
@@ -1521,13 +4804,21 @@ where
     ///
     /// [`Step`]: gherkin::Step
     /// [`Step::Failed`]: event::Step::Failed
-    async fn run_step<St, Ps, Sk>(
+    // TODO: Needs refactoring.
+    #[expect(
+        clippy::too_many_arguments,
+        clippy::too_many_lines,
+        reason = "needs refactoring"
+    )]
+    async fn run_step<St, Ps, Sk, Out>(
         &self,
         world_opt: Option<W>,
         step: Source<gherkin::Step>,
-        is_background: bool,
-        (started, passed, skipped): (St, Ps, Sk),
+        kind: StepKind,
+        (started, passed, skipped, output): (St, Ps, Sk, Out),
         scenario_id: ScenarioId,
+        thread: Option<&ScenarioThread<W, Before, After>>,
+        (artifacts, shared, example, prior_steps): StepScope<'_>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<W, ExecutionFailure<W>>
     where
@@ -1536,52 +4827,80 @@ where
             Source<gherkin::Step>,
             CaptureLocations,
             Option<step::Location>,
+            Duration,
         ) -> event::Cucumber<W>,
-        Sk: FnOnce(Source<gherkin::Step>) -> event::Cucumber<W>,
+        Sk: FnOnce(Source<gherkin::Step>, Duration) -> event::Cucumber<W>,
+        Out: FnOnce(event::Output) -> event::Cucumber<W>,
     {
-        self.send_event(started(step.clone()));
+        let is_background = kind.is_background();
+        self.send_event(started(step.clone())).await;
+        let started_at = Instant::now();
+        self.watch_step(scenario_id, &step, is_background, world_opt.as_ref())
+            .await;
+
+        let capture = self.capture.as_ref();
 
         let run = async {
-            let (step_fn, captures, loc, ctx) =
-                match self.collection.find(&step) {
-                    Ok(Some(f)) => f,
-                    Ok(None) => return Ok((None, None, world_opt)),
-                    Err(e) => {
-                        let e = event::StepError::AmbiguousMatch(e);
-                        return Err((e, None, None, world_opt));
-                    }
-                };
+            let (step_fn, captures, loc, mut ctx) = match self.find_step(&step)
+            {
+                Ok(Some(f)) => f,
+                Ok(None) => return Ok((None, None, world_opt)),
+                Err(e) => return Err((e, None, None, world_opt)),
+            };
 
             let mut world = if let Some(w) = world_opt {
                 w
             } else {
-                match AssertUnwindSafe(async { W::new().await })
-                    .catch_unwind()
-                    .then_yield()
-                    .await
-                {
+                match self.new_world(thread).then_yield().await {
                     Ok(Ok(w)) => w,
                     Ok(Err(e)) => {
-                        let e = event::StepError::Panic(coerce_into_info(
+                        let e = event::StepError::from(coerce_into_info(
                             format!("failed to initialize `World`: {e}"),
                         ));
                         return Err((e, None, loc, None));
                     }
-                    Err(e) => {
-                        let e = event::StepError::Panic(e.into());
+                    Err((payload, backtrace)) => {
+                        let e = event::StepError::Panic(
+                            panic_into_info(payload),
+                            backtrace,
+                        );
                         return Err((e, None, loc, None));
                     }
                 }
             };
 
-            match AssertUnwindSafe(async { step_fn(&mut world, ctx).await })
-                .catch_unwind()
-                .await
-            {
-                Ok(()) => Ok((Some(captures), loc, Some(world))),
-                Err(e) => {
-                    let e = event::StepError::Panic(e.into());
-                    Err((e, Some(captures), loc, Some(world)))
+            ctx.artifacts = Some(artifacts.clone());
+            ctx.shared = shared.clone();
+            ctx.example = example.cloned();
+            prior_steps.expose_to(&mut ctx);
+
+            // `World` is lost, in case the `Step` run on a `ScenarioThread`
+            // is interrupted by the global timeout.
+            let step_fn = *step_fn;
+            let (world, res) = if let Some(t) = thread {
+                let job = t.run(|out| Job::Step {
+                    world,
+                    step_fn,
+                    ctx,
+                    out,
+                });
+                match self.until_timed_out(job).await {
+                    Some((w, res)) => (Some(w), res),
+                    None => (None, Err(timed_out_panic())),
+                }
+            } else {
+                let run_step_fn = catch_panic(step_fn(&mut world, ctx));
+                let res = match capture {
+                    Some(c) => self.until_timed_out(c.wrap(run_step_fn)).await,
+                    None => self.until_timed_out(run_step_fn).await,
+                };
+                (Some(world), res.unwrap_or_else(|| Err(timed_out_panic())))
+            };
+            match res {
+                Ok(()) => Ok((Some(captures), loc, world)),
+                Err((payload, backtrace)) => {
+                    let e = panic_into_step_error(payload, backtrace);
+                    Err((e, Some(captures), loc, world))
                 }
             }
         };
@@ -1594,37 +4913,69 @@ where
             (run, span_id)
         };
         let result = run.then_yield().await;
+        let duration = started_at.elapsed();
+        self.unwatch_step(scenario_id).await;
 
         #[cfg(feature = "tracing")]
         if let Some((waiter, id)) = waiter.zip(span_id) {
             waiter.wait_for_span_close(id).then_yield().await;
         }
-        #[cfg(not(feature = "tracing"))]
-        let _: ScenarioId = scenario_id;
+
+        if let Some(out) = capture.and_then(capture::Capture::take) {
+            self.send_event(output(out)).await;
+        }
 
         match result {
             Ok((Some(captures), loc, Some(world))) => {
-                self.send_event(passed(step, captures, loc));
+                prior_steps.record(&step, step::Status::Passed);
+                self.send_event(passed(step, captures, loc, duration)).await;
                 Ok(world)
             }
             Ok((_, _, world)) => {
-                self.send_event(skipped(step));
+                prior_steps.record(&step, step::Status::Skipped);
+                self.send_event(skipped(step, duration)).await;
                 Err(ExecutionFailure::StepSkipped(world))
             }
             Err((err, captures, loc, world)) => {
+                prior_steps.record(&step, step::Status::Failed);
                 Err(ExecutionFailure::StepPanicked {
                     world,
                     step,
                     captures,
                     loc,
                     err,
+                    duration,
                     meta: event::Metadata::new(()),
-                    is_background,
+                    kind,
                 })
             }
         }
     }
 
+    /// Executes the [`StepFailedHookFn`] (if any) on the failed [`Step`],
+    /// keeping the produced [`artifacts::Artifact`]s in the provided
+    /// [`artifacts::Dir`].
+    ///
+    /// Returns paths of the kept [`artifacts::Artifact`]s. The ones failed to
+    /// be written are silently omitted.
+    ///
+    /// [`Step`]: gherkin::Step
+    async fn run_step_failed_hook(
+        &self,
+        world: &W,
+        info: artifacts::FailureInfo,
+        artifacts: &artifacts::Dir,
+    ) -> Vec<PathBuf> {
+        let Some(hook) = self.step_failed_hook.as_ref() else {
+            return vec![];
+        };
+        hook(world, &info)
+            .await
+            .iter()
+            .filter_map(|a| artifacts.keep(a).ok())
+            .collect()
+    }
+
     /// Emits all the failure events of [`HookType::Before`] or [`Step`] after
     /// executing the [`Self::run_after_hook()`].
     ///
@@ -1643,7 +4994,7 @@ where
     ///
     /// [`Step`]: gherkin::Step
     /// [1]: crate::Runner#order-guarantees
-    fn emit_failed_events(
+    async fn emit_failed_events(
         &self,
         feature: Source<gherkin::Feature>,
         rule: Option<Source<gherkin::Rule>>,
@@ -1653,7 +5004,8 @@ where
         retries: Option<Retries>,
     ) {
         match err {
-            ExecutionFailure::StepSkipped(_) => {}
+            ExecutionFailure::BeforeHookSkipped(_)
+            | ExecutionFailure::StepSkipped(_) => {}
             ExecutionFailure::BeforeHookPanicked {
                 panic_info, meta, ..
             } => {
@@ -1670,60 +5022,53 @@ where
                         .with_retries(retries),
                     ),
                     meta,
-                );
+                )
+                .await;
             }
             ExecutionFailure::StepPanicked {
                 step,
                 captures,
                 loc,
                 err: error,
+                duration,
                 meta,
-                is_background: true,
-                ..
-            } => self.send_event_with_meta(
-                event::Cucumber::scenario(
-                    feature,
-                    rule,
-                    scenario,
-                    event::Scenario::background_step_failed(
-                        step, captures, loc, world, error,
-                    )
-                    .with_retries(retries),
-                ),
-                meta,
-            ),
-            ExecutionFailure::StepPanicked {
-                step,
-                captures,
-                loc,
-                err: error,
-                meta,
-                is_background: false,
+                kind,
                 ..
-            } => self.send_event_with_meta(
-                event::Cucumber::scenario(
-                    feature,
-                    rule,
-                    scenario,
-                    event::Scenario::step_failed(
-                        step, captures, loc, world, error,
-                    )
-                    .with_retries(retries),
-                ),
-                meta,
-            ),
+            } => {
+                let failed = match kind {
+                    StepKind::FeatureBackground => {
+                        event::Scenario::background_step_failed
+                    }
+                    StepKind::RuleBackground => {
+                        event::Scenario::rule_background_step_failed
+                    }
+                    StepKind::Scenario => event::Scenario::step_failed,
+                };
+                self.send_event_with_meta(
+                    event::Cucumber::scenario(
+                        feature,
+                        rule,
+                        scenario,
+                        failed(step, captures, loc, world, error, duration)
+                            .with_retries(retries),
+                    ),
+                    meta,
+                )
+                .await;
+            }
         }
     }
 
-    /// Executes the [`HookType::After`], if present.
+    /// Executes the [`HookType::After`], if present, along with the teardown
+    /// of the set up [`Fixture`]s and the [`World::as_teardown()`] (even if
+    /// the [`HookType::After`] panics).
     ///
     /// Doesn't emit any events, see [`Self::emit_failed_events()`] for more
     /// details.
+    ///
+    /// [`Fixture`]: fixtures::Fixture
     // TODO: Needs refactoring.
-    #[cfg_attr(
-        feature = "tracing",
-        expect(clippy::too_many_arguments, reason = "needs refactoring")
-    )]
+    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
     async fn run_after_hook(
         &self,
         mut world: Option<W>,
@@ -1732,62 +5077,100 @@ where
         scenario: &Source<gherkin::Scenario>,
         ev: event::ScenarioFinished,
         scenario_id: ScenarioId,
+        thread: Option<&ScenarioThread<W, Before, After>>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<
         (Option<W>, Option<AfterHookEventsMeta>),
         (Option<W>, AfterHookEventsMeta, Info),
     > {
-        if let Some(hook) = self.after_hook.as_ref() {
-            let fut = async {
-                (hook)(
-                    feature.as_ref(),
-                    rule.as_ref().map(AsRef::as_ref),
-                    scenario.as_ref(),
-                    &ev,
+        let fixtures = self
+            .active_fixtures
+            .lock()
+            .await
+            .remove(scenario)
+            .unwrap_or_default();
+        if fixtures.is_empty() && !self.has_after_hook(world.as_mut()) {
+            return Ok((world, None));
+        }
+
+        let fut = async {
+            let hook = self.after_hook.as_ref();
+            if let Some(t) = thread {
+                let sources =
+                    (feature.clone(), rule.cloned(), scenario.clone());
+                let ev = sendable_scenario_finished(&ev);
+                let (w, res) = t
+                    .run(|out| Job::AfterHook {
+                        world: world.take(),
+                        fixtures,
+                        hook: hook.cloned(),
+                        sources,
+                        ev,
+                        out,
+                    })
+                    .await;
+                world = w;
+                res
+            } else {
+                tear_down_scenario(
                     world.as_mut(),
+                    &fixtures,
+                    hook.map(AsRef::as_ref),
+                    (
+                        feature.as_ref(),
+                        rule.map(AsRef::as_ref),
+                        scenario.as_ref(),
+                    ),
+                    &ev,
                 )
-                .await;
-            };
+                .await
+            }
+        };
 
-            let started = event::Metadata::new(());
-            let fut = AssertUnwindSafe(fut).catch_unwind();
+        let started = event::Metadata::new(());
 
-            #[cfg(feature = "tracing")]
-            let (fut, span_id) = {
-                let span = scenario_id.hook_span(HookType::After);
-                let span_id = span.id();
-                let fut = tracing::Instrument::instrument(fut, span);
-                (fut, span_id)
-            };
-            #[cfg(not(feature = "tracing"))]
-            let _: ScenarioId = scenario_id;
+        #[cfg(feature = "tracing")]
+        let (fut, span_id) = {
+            let span = scenario_id.hook_span(HookType::After);
+            let span_id = span.id();
+            let fut = tracing::Instrument::instrument(fut, span);
+            (fut, span_id)
+        };
+        #[cfg(not(feature = "tracing"))]
+        let _: ScenarioId = scenario_id;
 
-            let res = fut.then_yield().await;
+        let res = fut.then_yield().await;
 
-            #[cfg(feature = "tracing")]
-            if let Some((waiter, id)) = waiter.zip(span_id) {
-                waiter.wait_for_span_close(id).then_yield().await;
-            }
+        #[cfg(feature = "tracing")]
+        if let Some((waiter, id)) = waiter.zip(span_id) {
+            waiter.wait_for_span_close(id).then_yield().await;
+        }
 
-            let finished = event::Metadata::new(());
-            let meta = AfterHookEventsMeta { started, finished };
+        let finished = event::Metadata::new(());
+        let meta = AfterHookEventsMeta { started, finished };
 
-            match res {
-                Ok(()) => Ok((world, Some(meta))),
-                Err(info) => Err((world, meta, info.into())),
-            }
-        } else {
-            Ok((world, None))
+        match res {
+            Ok(()) => Ok((world, Some(meta))),
+            Err((payload, _)) => Err((world, meta, panic_into_info(payload))),
         }
     }
 
+    /// Indicates whether the [`HookType::After`] should be executed, either
+    /// because it's set, or because the provided [`World`] requires a
+    /// [teardown][1].
+    ///
+    /// [1]: World::as_teardown()
+    fn has_after_hook(&self, world: Option<&mut W>) -> bool {
+        self.after_hook.is_some() || world.and_then(W::as_teardown).is_some()
+    }
+
     /// Emits all the [`HookType::After`] events.
     ///
     /// See [`Self::emit_failed_events()`] for the explanation why we don't do
     /// that inside [`Self::run_after_hook()`].
     // TODO: Needs refactoring.
     #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
-    fn emit_after_hook_events(
+    async fn emit_after_hook_events(
         &self,
         feature: Source<gherkin::Feature>,
         rule: Option<Source<gherkin::Rule>>,
@@ -1797,9 +5180,8 @@ where
         err: Option<Info>,
         retries: Option<Retries>,
     ) {
-        debug_assert_eq!(
-            self.after_hook.is_some(),
-            meta.is_some(),
+        debug_assert!(
+            self.after_hook.is_none() || meta.is_some(),
             "`AfterHookEventsMeta` is not passed, despite `self.after_hook` \
              being set",
         );
@@ -1814,15 +5196,20 @@ where
                         .with_retries(retries),
                 ),
                 meta.started,
-            );
+            )
+            .await;
 
             let ev = if let Some(err) = err {
+                let ev = if self.after_hook_failure.is_ignoring() {
+                    event::Scenario::hook_ignored(HookType::After, err)
+                } else {
+                    event::Scenario::hook_failed(HookType::After, world, err)
+                };
                 event::Cucumber::scenario(
                     feature,
                     rule,
                     scenario,
-                    event::Scenario::hook_failed(HookType::After, world, err)
-                        .with_retries(retries),
+                    ev.with_retries(retries),
                 )
             } else {
                 event::Cucumber::scenario(
@@ -1834,7 +5221,7 @@ where
                 )
             };
 
-            self.send_event_with_meta(ev, meta.finished);
+            self.send_event_with_meta(ev, meta.finished).await;
         }
     }
 
@@ -1847,77 +5234,254 @@ where
         feature: Source<gherkin::Feature>,
         rule: Option<Source<gherkin::Rule>>,
         is_failed: IsFailed,
+        is_skipped: IsSkipped,
         is_retried: IsRetried,
     ) {
         // If the receiver end is dropped, then no one listens for events
         // so we can just ignore it.
-        drop(
-            self.finished_sender
-                .unbounded_send((id, feature, rule, is_failed, is_retried)),
-        );
+        drop(self.finished_sender.unbounded_send((
+            id, feature, rule, is_failed, is_skipped, is_retried,
+        )));
     }
 
     /// Notifies with the given [`Cucumber`] event.
     ///
     /// [`Cucumber`]: event::Cucumber
-    fn send_event(&self, event: event::Cucumber<W>) {
-        // If the receiver end is dropped, then no one listens for events,
-        // so we can just ignore it.
-        drop(self.event_sender.unbounded_send(Ok(Event::new(event))));
+    async fn send_event(&self, event: event::Cucumber<W>) {
+        self.send_all_events([event]).await;
     }
 
     /// Notifies with the given [`Cucumber`] event along with its [`Metadata`].
     ///
     /// [`Cucumber`]: event::Cucumber
     /// [`Metadata`]: event::Metadata
-    fn send_event_with_meta(
+    async fn send_event_with_meta(
         &self,
         event: event::Cucumber<W>,
         meta: event::Metadata,
     ) {
         // If the receiver end is dropped, then no one listens for events,
         // so we can just ignore it.
-        drop(self.event_sender.unbounded_send(Ok(meta.wrap(event))));
+        drop(
+            self.event_sender
+                .lock()
+                .await
+                .send(Ok(meta.wrap(event)))
+                .await,
+        );
     }
 
     /// Notifies with the given [`Cucumber`] events.
     ///
     /// [`Cucumber`]: event::Cucumber
-    fn send_all_events(
+    async fn send_all_events(
         &self,
         events: impl IntoIterator<Item = event::Cucumber<W>>,
     ) {
-        for v in events {
-            // If the receiver end is dropped, then no one listens for events,
-            // so we can just stop from here.
-            if self.event_sender.unbounded_send(Ok(Event::new(v))).is_err() {
-                break;
-            }
+        send_all_events(&mut *self.event_sender.lock().await, events).await;
+    }
+
+    /// Starts watching the provided [`Scenario`] by the
+    /// [`Executor::watchdog`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn watch_scenario(
+        &self,
+        id: ScenarioId,
+        feature: Source<gherkin::Feature>,
+        rule: Option<Source<gherkin::Rule>>,
+        scenario: Source<gherkin::Scenario>,
+        retries: Option<Retries>,
+    ) {
+        if self.watchdog.is_none() {
+            return;
+        }
+        drop(self.running_scenarios.lock().await.insert(
+            id,
+            RunningScenario {
+                feature,
+                rule,
+                scenario,
+                retries,
+                step: None,
+            },
+        ));
+    }
+
+    /// Stops watching the provided [`Scenario`] by the
+    /// [`Executor::watchdog`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn unwatch_scenario(&self, id: ScenarioId) {
+        if self.watchdog.is_none() {
+            return;
+        }
+        drop(self.running_scenarios.lock().await.remove(&id));
+    }
+
+    /// Starts watching the just started [`Step`] of the watched [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    async fn watch_step(
+        &self,
+        id: ScenarioId,
+        step: &Source<gherkin::Step>,
+        is_background: bool,
+        world: Option<&W>,
+    ) {
+        if self.watchdog.is_none() {
+            return;
+        }
+        let started_at = Instant::now();
+        let world = world
+            .filter(|_| self.dump_stalled_world)
+            .and_then(W::as_snapshot)
+            .map(|w| w.snapshot(Some(step)));
+        if let Some(sc) = self.running_scenarios.lock().await.get_mut(&id) {
+            sc.step = Some(RunningStep {
+                step: step.clone(),
+                is_background,
+                started_at,
+                world,
+                stalls: 0,
+            });
+        }
+    }
+
+    /// Stops watching the finished [`Step`] of the watched [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    async fn unwatch_step(&self, id: ScenarioId) {
+        if self.watchdog.is_none() {
+            return;
         }
+        if let Some(sc) = self.running_scenarios.lock().await.get_mut(&id) {
+            sc.step = None;
+        }
+    }
+
+    /// Emits [`event::Scenario::Stalled`] for all the watched [`Step`]s making
+    /// no progress for longer than the [`Executor::watchdog`] timeout.
+    ///
+    /// [`Step`]: gherkin::Step
+    async fn emit_stalled_steps(&self) {
+        let Some(timeout) = self.watchdog else {
+            return;
+        };
+
+        // Lock is held while sending the events, so the stalled `Step` cannot
+        // emit its finishing event before them.
+        let mut running = self.running_scenarios.lock().await;
+        let stalled = running
+            .values_mut()
+            .filter_map(|sc| {
+                let st = sc.step.as_mut()?;
+                let elapsed = st.started_at.elapsed();
+                let deadline = timeout.checked_mul(st.stalls + 1)?;
+                (elapsed >= deadline).then(|| {
+                    st.stalls += 1;
+                    event::Cucumber::scenario(
+                        sc.feature.clone(),
+                        sc.rule.clone(),
+                        sc.scenario.clone(),
+                        event::Scenario::Stalled(event::Stall {
+                            step: st.step.clone(),
+                            is_background: st.is_background,
+                            elapsed,
+                            world: st.world.clone(),
+                        })
+                        .with_retries(sc.retries),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        self.send_all_events(stalled).await;
+        drop(running);
     }
 }
 
-/// ID of a [`Scenario`], uniquely identifying it.
-///
-/// **NOTE**: Retried [`Scenario`] has a different ID from a failed one.
+/// [`Scenario`] being executed and watched by the [`Executor::watchdog`].
 ///
 /// [`Scenario`]: gherkin::Scenario
-#[derive(Clone, Copy, Debug, Display, Eq, FromStr, Hash, PartialEq)]
-pub struct ScenarioId(pub(crate) u64);
+struct RunningScenario {
+    /// [`Feature`] of the [`Scenario`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    feature: Source<gherkin::Feature>,
 
-impl ScenarioId {
-    /// Creates a new unique [`ScenarioId`].
-    pub fn new() -> Self {
-        /// [`AtomicU64`] ID.
-        static ID: AtomicU64 = AtomicU64::new(0);
+    /// Optional [`Rule`] of the [`Scenario`].
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    rule: Option<Source<gherkin::Rule>>,
 
-        Self(ID.fetch_add(1, Ordering::Relaxed))
-    }
+    /// [`Scenario`] itself.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenario: Source<gherkin::Scenario>,
+
+    /// [`Retries`] of the [`Scenario`] attempt.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    retries: Option<Retries>,
+
+    /// Currently running [`Step`] of the [`Scenario`], if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    step: Option<RunningStep>,
 }
 
-impl Default for ScenarioId {
-    fn default() -> Self {
-        Self::new()
+/// [`Step`] being executed and watched by the [`Executor::watchdog`].
+///
+/// [`Step`]: gherkin::Step
+struct RunningStep {
+    /// [`Step`] itself.
+    ///
+    /// [`Step`]: gherkin::Step
+    step: Source<gherkin::Step>,
+
+    /// Indicator whether the [`Step`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    is_background: bool,
+
+    /// [`Instant`] when the [`Step`] has been started.
+    ///
+    /// [`Step`]: gherkin::Step
+    started_at: Instant,
+
+    /// [`WorldSnapshot`] taken right before the [`Step`] has been started.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`WorldSnapshot`]: crate::WorldSnapshot
+    world: Option<String>,
+
+    /// Number of [`event::Scenario::Stalled`] already emitted for the
+    /// [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    stalls: u32,
+}
+
+/// Sends the given [`Cucumber`] events via the provided [`mpsc::Sender`],
+/// waiting for the free space in its buffer, if required.
+///
+/// [`Cucumber`]: event::Cucumber
+async fn send_all_events<W>(
+    sender: &mut mpsc::Sender<parser::Result<Event<event::Cucumber<W>>>>,
+    events: impl IntoIterator<Item = event::Cucumber<W>>,
+) {
+    for v in events {
+        // If the receiver end is dropped, then no one listens for events,
+        // so we can just stop from here.
+        if sender.send(Ok(Event::new(v))).await.is_err() {
+            break;
+        }
     }
 }
 
@@ -1959,6 +5523,7 @@ type FinishedFeaturesSender = mpsc::UnboundedSender<(
     Source<gherkin::Feature>,
     Option<Source<gherkin::Rule>>,
     IsFailed,
+    IsSkipped,
     IsRetried,
 )>;
 
@@ -1971,6 +5536,7 @@ type FinishedFeaturesReceiver = mpsc::UnboundedReceiver<(
     Source<gherkin::Feature>,
     Option<Source<gherkin::Rule>>,
     IsFailed,
+    IsSkipped,
     IsRetried,
 )>;
 
@@ -2041,9 +5607,12 @@ impl FinishedRulesAndFeatures {
     }
 
     /// Marks all the unfinished [`Rule`]s and [`Feature`]s as finished, and
-    /// returns all the appropriate finished events.
+    /// returns all the appropriate finished events, preceding each
+    /// [`Feature::Finished`] with a [`Feature::Aborted`].
     ///
     /// [`Feature`]: gherkin::Feature
+    /// [`Feature::Aborted`]: event::Feature::Aborted
+    /// [`Feature::Finished`]: event::Feature::Finished
     /// [`Rule`]: gherkin::Rule
     fn finish_all_rules_and_features<W>(
         &mut self,
@@ -2054,7 +5623,14 @@ impl FinishedRulesAndFeatures {
             .chain(
                 self.features_scenarios_count
                     .drain()
-                    .map(|(feat, _)| event::Cucumber::feature_finished(feat)),
+                    .flat_map(|(feat, _)| [(feat.clone(), true), (feat, false)])
+                    .map(|(feat, aborted)| {
+                        if aborted {
+                            event::Cucumber::feature_aborted(feat)
+                        } else {
+                            event::Cucumber::feature_finished(feat)
+                        }
+                    }),
             )
     }
 
@@ -2121,31 +5697,155 @@ impl FinishedRulesAndFeatures {
     }
 }
 
-/// [`Scenario`]s storage.
-///
-/// [`Scenario`]: gherkin::Scenario
-type Scenarios = HashMap<
-    ScenarioType,
-    Vec<(
-        ScenarioId,
-        Source<gherkin::Feature>,
-        Option<Source<gherkin::Rule>>,
-        Source<gherkin::Scenario>,
-        Option<RetryOptionsWithDeadline>,
-    )>,
->;
+/// [`Scenario`]s storage.
+///
+/// [`Scenario`]: gherkin::Scenario
+type Scenarios = HashMap<
+    ScenarioType,
+    Vec<(
+        ScenarioId,
+        Source<gherkin::Feature>,
+        StoredScenario,
+        Option<RetryOptionsWithDeadline>,
+    )>,
+>;
+
+/// Alias of a [`Features::insert_scenarios()`] argument.
+type InsertedScenarios = HashMap<
+    ScenarioType,
+    Vec<(
+        ScenarioId,
+        Source<gherkin::Feature>,
+        StoredScenario,
+        Option<RetryOptions>,
+    )>,
+>;
+
+/// [`Scenario`] stored in [`Features`], which is not wrapped into a [`Source`]
+/// until it's about to run.
+///
+/// Doing so upfront would clone all the [`Scenario`]s (and [`Rule`]s) out of
+/// their [`Feature`]s at once, doubling the memory occupied by them.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone)]
+enum StoredScenario {
+    /// [`Scenario`] referred by its indices inside its [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    Indexed {
+        /// Index of the [`Rule`] in its [`Feature`], if any.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Rule`]: gherkin::Rule
+        rule: Option<usize>,
+
+        /// Index of the [`Scenario`] in its [`Rule`] or [`Feature`].
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Rule`]: gherkin::Rule
+        /// [`Scenario`]: gherkin::Scenario
+        scenario: usize,
+
+        /// [`Source`]s of all the [`Rule`]s of the [`Feature`], shared between
+        /// all its [`Scenario`]s, and created once required.
+        ///
+        /// [`Feature`]: gherkin::Feature
+        /// [`Rule`]: gherkin::Rule
+        /// [`Scenario`]: gherkin::Scenario
+        rules: Arc<[OnceLock<Source<gherkin::Rule>>]>,
+    },
+
+    /// [`Scenario`] already wrapped into a [`Source`] (being retried, for
+    /// example).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Source(Option<Source<gherkin::Rule>>, Source<gherkin::Scenario>),
+}
+
+impl StoredScenario {
+    /// Returns all the [`Scenario`]s of the provided [`Feature`] (along with
+    /// their [`Rule`]s, if any) to be stored.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    fn all_of(
+        feature: &gherkin::Feature,
+    ) -> Vec<(Option<&gherkin::Rule>, &gherkin::Scenario, Self)> {
+        let rules = feature
+            .rules
+            .iter()
+            .map(|_| OnceLock::new())
+            .collect::<Arc<[_]>>();
+        let indexed = move |rule, scenario| Self::Indexed {
+            rule,
+            scenario,
+            rules: Arc::clone(&rules),
+        };
+
+        feature
+            .scenarios
+            .iter()
+            .enumerate()
+            .map(|(i, sc)| (None, i, sc))
+            .chain(feature.rules.iter().enumerate().flat_map(|(r_i, r)| {
+                r.scenarios
+                    .iter()
+                    .enumerate()
+                    .map(move |(sc_i, sc)| (Some((r_i, r)), sc_i, sc))
+            }))
+            .map(move |(rule, i, sc)| {
+                (
+                    rule.map(|(_, r)| r),
+                    sc,
+                    indexed(rule.map(|(r_i, _)| r_i), i),
+                )
+            })
+            .collect()
+    }
+
+    /// Wraps this [`StoredScenario`] (and its [`Rule`], if any) into
+    /// [`Source`]s, using the provided [`Feature`] it belongs to.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    fn into_source(
+        self,
+        feature: &Source<gherkin::Feature>,
+    ) -> (Option<Source<gherkin::Rule>>, Source<gherkin::Scenario>) {
+        // Indices are always valid, as they're obtained from the same
+        // `Feature` in `StoredScenario::all_of()`.
+        fn invalid<T>() -> T {
+            unreachable!("invalid `StoredScenario` index")
+        }
+
+        let (rule, scenario, rules) = match self {
+            Self::Source(rule, scenario) => return (rule, scenario),
+            Self::Indexed {
+                rule,
+                scenario,
+                rules,
+            } => (rule, scenario, rules),
+        };
 
-/// Alias of a [`Features::insert_scenarios()`] argument.
-type InsertedScenarios = HashMap<
-    ScenarioType,
-    Vec<(
-        ScenarioId,
-        Source<gherkin::Feature>,
-        Option<Source<gherkin::Rule>>,
-        Source<gherkin::Scenario>,
-        Option<RetryOptions>,
-    )>,
->;
+        let rule = rule.map(|i| {
+            let r = feature.rules.get(i).unwrap_or_else(invalid);
+            let src = rules.get(i).unwrap_or_else(invalid);
+            (r, src.get_or_init(|| Source::new(r.clone())).clone())
+        });
+        let scenario = rule
+            .as_ref()
+            .map_or(&feature.scenarios, |(r, _)| &r.scenarios)
+            .get(scenario)
+            .unwrap_or_else(invalid);
+
+        (rule.map(|(_, src)| src), Source::new(scenario.clone()))
+    }
+}
 
 /// Storage sorted by [`ScenarioType`] [`Feature`]'s [`Scenario`]s.
 ///
@@ -2163,17 +5863,19 @@ struct Features {
 }
 
 impl Features {
-    /// Splits [`Feature`] into [`Scenario`]s, sorts by [`ScenarioType`] and
-    /// stores them.
+    /// Splits [`Feature`]s into [`Scenario`]s, sorts by [`ScenarioType`] and
+    /// stores them (preserving the order of the provided [`Feature`]s, unless
+    /// the provided [`Timings`] schedule the longest [`Scenario`]s first).
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Scenario`]: gherkin::Scenario
     async fn insert<Which>(
         &self,
-        feature: gherkin::Feature,
+        features: impl IntoIterator<Item = Source<gherkin::Feature>>,
         which_scenario: &Which,
         retry: &RetryOptionsFn,
         cli: &Cli,
+        timings: &Timings,
     ) where
         Which: Fn(
                 &gherkin::Feature,
@@ -2182,32 +5884,33 @@ impl Features {
             ) -> ScenarioType
             + 'static,
     {
-        let feature = Source::new(feature);
+        let features = features.into_iter().collect::<Vec<_>>();
 
-        let local = feature
-            .scenarios
+        let local = features
             .iter()
-            .map(|s| (None, s))
-            .chain(feature.rules.iter().flat_map(|r| {
-                let rule = Some(Source::new(r.clone()));
-                r.scenarios
-                    .iter()
-                    .map(|s| (rule.clone(), s))
-                    .collect::<Vec<_>>()
-            }))
-            .map(|(rule, scenario)| {
-                let retries = retry(&feature, rule.as_deref(), scenario, cli);
-                (
-                    ScenarioId::new(),
-                    feature.clone(),
-                    rule,
-                    Source::new(scenario.clone()),
-                    retries,
+            .flat_map(|feature| {
+                StoredScenario::all_of(feature).into_iter().map(
+                    move |(rule, scenario, stored)| {
+                        let retries = retry(feature, rule, scenario, cli);
+                        let id = ScenarioId::of(
+                            feature,
+                            rule,
+                            scenario,
+                            retries.map(|r| r.retries),
+                        );
+                        let which = cli
+                            .forced_scenario_type(feature, rule, scenario)
+                            .unwrap_or_else(|| {
+                                which_scenario(feature, rule, scenario)
+                            });
+                        let took = timings.get(feature, scenario);
+                        (took, which, (id, feature.clone(), stored, retries))
+                    },
                 )
             })
-            .into_group_map_by(|(_, f, r, s, _)| {
-                which_scenario(f, r.as_ref().map(AsRef::as_ref), s)
-            });
+            .sorted_by_key(|(took, ..)| cmp::Reverse(*took))
+            .map(|(_, which, scenario)| (which, scenario))
+            .into_group_map();
 
         self.insert_scenarios(local).await;
     }
@@ -2224,10 +5927,21 @@ impl Features {
         scenario_ty: ScenarioType,
         retries: Option<RetryOptions>,
     ) {
+        let id = ScenarioId::of(
+            &feature,
+            rule.as_deref(),
+            &scenario,
+            retries.map(|r| r.retries),
+        );
         self.insert_scenarios(
             iter::once((
                 scenario_ty,
-                vec![(ScenarioId::new(), feature, rule, scenario, retries)],
+                vec![(
+                    id,
+                    feature,
+                    StoredScenario::Source(rule, scenario),
+                    retries,
+                )],
             ))
             .collect(),
         )
@@ -2244,7 +5958,7 @@ impl Features {
         let mut without_retries: Scenarios = HashMap::new();
         #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
         for (which, values) in scenarios {
-            for (id, f, r, s, ret) in values {
+            for (id, f, s, ret) in values {
                 match ret {
                     ret @ (None
                     | Some(RetryOptions {
@@ -2257,14 +5971,14 @@ impl Features {
                         without_retries
                             .entry(which)
                             .or_default()
-                            .push((id, f, r, s, ret));
+                            .push((id, f, s, ret));
                     }
                     Some(ret) => {
                         let ret = ret.with_deadline(now);
                         with_retries
                             .entry(which)
                             .or_default()
-                            .push((id, f, r, s, ret));
+                            .push((id, f, s, ret));
                     }
                 }
             }
@@ -2275,8 +5989,8 @@ impl Features {
         #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
         for (which, values) in with_retries {
             let ty_storage = storage.entry(which).or_default();
-            for (id, f, r, s, ret) in values {
-                ty_storage.insert(0, (id, f, r, s, Some(ret)));
+            for (id, f, s, ret) in values {
+                ty_storage.insert(0, (id, f, s, Some(ret)));
             }
         }
 
@@ -2314,6 +6028,7 @@ impl Features {
     async fn get(
         &self,
         max_concurrent_scenarios: Option<usize>,
+        is_idle: bool,
     ) -> (
         Vec<(
             ScenarioId,
@@ -2333,45 +6048,59 @@ impl Features {
         }
 
         let mut min_dur = None;
-        let mut drain =
-            |storage: &mut Vec<(_, _, _, _, Option<WithDeadline>)>,
-             ty,
-             count: Option<usize>| {
-                let mut i = 0;
-                // TODO: Replace with `extract_if` instead of custom
-                //       `drain_filter`, once stabilized:
-                //       https://github.com/rust-lang/rust/issues/43244
-                let drained =
-                    VecExt::drain_filter(storage, |(_, _, _, _, ret)| {
-                        // Because `drain_filter` runs over entire `Vec` on
-                        // `Drop`, we can't just `.take(count)`.
-                        if count.filter(|c| i >= *c).is_some() {
-                            return false;
-                        }
+        let mut drain = |storage: &mut Vec<(_, _, _, Option<WithDeadline>)>,
+                         ty,
+                         count: Option<usize>| {
+            let mut i = 0;
+            // TODO: Replace with `extract_if` instead of custom
+            //       `drain_filter`, once stabilized:
+            //       https://github.com/rust-lang/rust/issues/43244
+            let drained = VecExt::drain_filter(storage, |(_, _, _, ret)| {
+                // Because `drain_filter` runs over entire `Vec` on
+                // `Drop`, we can't just `.take(count)`.
+                if count.filter(|c| i >= *c).is_some() {
+                    return false;
+                }
 
-                        ret.as_ref()
-                            .and_then(WithDeadline::left_until_retry)
-                            .map_or_else(
-                                || {
-                                    i += 1;
-                                    true
-                                },
-                                |left| {
-                                    min_dur = min_dur
-                                        .map(|min| cmp::min(min, left))
-                                        .or(Some(left));
-                                    false
-                                },
-                            )
-                    })
-                    .map(|(id, f, r, s, ret)| {
-                        (id, f, r, s, ty, ret.map(Into::into))
-                    })
-                    .collect::<Vec<_>>();
-                (!drained.is_empty()).then_some(drained)
-            };
+                ret.as_ref()
+                    .and_then(WithDeadline::left_until_retry)
+                    .map_or_else(
+                        || {
+                            i += 1;
+                            true
+                        },
+                        |left| {
+                            min_dur = min_dur
+                                .map(|min| cmp::min(min, left))
+                                .or(Some(left));
+                            false
+                        },
+                    )
+            })
+            .map(|(id, f, s, ret)| {
+                let (r, s) = StoredScenario::into_source(s, &f);
+                (id, f, r, s, ty, ret.map(Into::into))
+            })
+            .collect::<Vec<_>>();
+            (!drained.is_empty()).then_some(drained)
+        };
 
         let mut guard = self.scenarios.lock().await;
+
+        // `Serial` `Scenario`s are run in isolation, so the running ones should
+        // finish before any ready `Serial` one is started, and no other ones
+        // are started meanwhile.
+        let is_serial_ready = guard.get(&Serial).is_some_and(|storage| {
+            storage.iter().any(|(.., ret)| {
+                ret.as_ref()
+                    .and_then(WithDeadline::left_until_retry)
+                    .is_none()
+            })
+        });
+        if is_serial_ready && !is_idle {
+            return (Vec::new(), None);
+        }
+
         let scenarios = guard
             .get_mut(&Serial)
             .and_then(|storage| drain(storage, Serial, Some(1)))
@@ -2385,6 +6114,33 @@ impl Features {
         (scenarios, min_dur)
     }
 
+    /// Takes all the [`Scenario`]s left in this [`Features`] storage, which
+    /// are not going to be run anymore.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn take_remaining(
+        &self,
+    ) -> Vec<(
+        ScenarioId,
+        Source<gherkin::Feature>,
+        Option<Source<gherkin::Rule>>,
+        Source<gherkin::Scenario>,
+        ScenarioType,
+        Option<RetryOptions>,
+    )> {
+        let mut guard = self.scenarios.lock().await;
+        [ScenarioType::Serial, ScenarioType::Concurrent]
+            .into_iter()
+            .filter_map(|ty| guard.remove(&ty).map(|s| (ty, s)))
+            .flat_map(|(ty, storage)| {
+                storage.into_iter().map(move |(id, f, s, ret)| {
+                    let (r, s) = StoredScenario::into_source(s, &f);
+                    (id, f, r, s, ty, ret.map(Into::into))
+                })
+            })
+            .collect()
+    }
+
     /// Marks that there will be no more [`Feature`]s to execute.
     ///
     /// [`Feature`]: gherkin::Feature
@@ -2405,11 +6161,267 @@ impl Features {
     }
 }
 
+/// Original panic hook along with the number of runs relying on the one
+/// installed by [`PanicHookGuard`] instead.
+static PANIC_HOOK: SyncMutex<Option<(usize, Arc<PanicHook>)>> =
+    SyncMutex::new(None);
+
+/// Number of runs with [`PanicHookStrategy::Chain`] in progress.
+static CHAINING_PANIC_HOOKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Type of a panic hook.
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Send + Sync>;
+
+/// Guard of the panic hook installed according to a [`PanicHookStrategy`],
+/// restoring the original one once the last overlapping run drops its guard.
+#[derive(Debug)]
+struct PanicHookGuard(PanicHookStrategy);
+
+impl PanicHookGuard {
+    /// Installs the panic hook according to the provided
+    /// [`PanicHookStrategy`], unless it has been already installed by another
+    /// run.
+    fn install(strategy: PanicHookStrategy) -> Self {
+        if strategy == PanicHookStrategy::Untouched {
+            return Self(strategy);
+        }
+
+        let mut state =
+            PANIC_HOOK.lock().unwrap_or_else(PoisonError::into_inner);
+        if strategy == PanicHookStrategy::Chain {
+            _ = CHAINING_PANIC_HOOKS.fetch_add(1, Ordering::SeqCst);
+        }
+        if let Some((runs, _)) = state.as_mut() {
+            *runs += 1;
+        } else {
+            let original = Arc::new(panic::take_hook());
+            let chained = Arc::clone(&original);
+            panic::set_hook(Box::new(move |info| {
+                // Respect `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`, as capturing
+                // is expensive and panics may be expected by steps.
+                let captured = Backtrace::capture();
+                LAST_PANIC_BACKTRACE.with(|bt| {
+                    *bt.borrow_mut() = (captured.status()
+                        == BacktraceStatus::Captured)
+                        .then(|| Arc::new(captured));
+                });
+                if CHAINING_PANIC_HOOKS.load(Ordering::SeqCst) > 0 {
+                    chained(info);
+                }
+            }));
+            *state = Some((1, original));
+        }
+        drop(state);
+
+        Self(strategy)
+    }
+}
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        if self.0 == PanicHookStrategy::Untouched {
+            return;
+        }
+
+        let mut state =
+            PANIC_HOOK.lock().unwrap_or_else(PoisonError::into_inner);
+        if self.0 == PanicHookStrategy::Chain {
+            _ = CHAINING_PANIC_HOOKS.fetch_sub(1, Ordering::SeqCst);
+        }
+        let Some((runs, _)) = state.as_mut() else {
+            return;
+        };
+        *runs -= 1;
+        if *runs == 0 {
+            if let Some((_, original)) = state.take() {
+                // Dropping the installed hook releases its `Arc` of the
+                // original one.
+                drop(panic::take_hook());
+                match Arc::try_unwrap(original) {
+                    Ok(hook) => panic::set_hook(hook),
+                    Err(hook) => panic::set_hook(Box::new(move |i| hook(i))),
+                }
+            }
+        }
+    }
+}
+
+/// Guard of the environment variables declared via `@env(<KEY>=<value>)` tags
+/// of a running [`Scenario`], restoring their original values once dropped.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug)]
+struct EnvGuard(Vec<(String, Option<OsString>)>);
+
+impl EnvGuard {
+    /// Sets the provided environment variables, remembering their original
+    /// values.
+    ///
+    /// __NOTE__: Environment variables are process-wide, so [`Scenario`]s
+    ///           declaring them are always run as [`ScenarioType::Serial`]
+    ///           ones, in isolation from any other [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn set(vars: Vec<(&str, &str)>) -> Self {
+        Self(
+            vars.into_iter()
+                .map(|(key, value)| {
+                    let original = env::var_os(key);
+                    env::set_var(key, value);
+                    (key.to_owned(), original)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (key, original) in self.0.drain(..).rev() {
+            match original {
+                Some(value) => env::set_var(key, value),
+                None => env::remove_var(key),
+            }
+        }
+    }
+}
+
+/// State of a [`Scenario`] provided to its [`Step`]s: artifacts
+/// [`artifacts::Dir`], [`shared::Scopes`], [`Example`] row and
+/// [`PriorSteps`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+type StepScope<'s> = (
+    &'s artifacts::Dir,
+    &'s shared::Scopes,
+    Option<&'s Example>,
+    &'s PriorSteps,
+);
+
+/// [`step::Outcome`]s of the [`Step`]s already executed in a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Debug)]
+struct PriorSteps {
+    /// Snapshot of the [`step::Outcome`]s recorded so far, shared with every
+    /// [`step::Context`] instead of being copied into it.
+    outcomes: SyncMutex<Arc<[step::Outcome]>>,
+
+    /// Soft failures reported by the currently executed [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    soft_failures: Arc<SyncMutex<Vec<String>>>,
+}
+
+impl Default for PriorSteps {
+    fn default() -> Self {
+        Self {
+            outcomes: SyncMutex::new(Arc::new([])),
+            soft_failures: Arc::default(),
+        }
+    }
+}
+
+impl PriorSteps {
+    /// Returns the snapshot of the [`step::Outcome`]s recorded so far.
+    fn snapshot(&self) -> Arc<[step::Outcome]> {
+        Arc::clone(
+            &self.outcomes.lock().unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Exposes the [`step::Outcome`]s recorded so far to the provided
+    /// [`step::Context`], and collects the soft failures reported via it.
+    fn expose_to(&self, ctx: &mut step::Context) {
+        ctx.set_prior_steps(self.snapshot(), Arc::clone(&self.soft_failures));
+    }
+
+    /// Records the [`step::Outcome`] of the provided [`Step`], taking the soft
+    /// failures it has reported.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn record(&self, step: &Source<gherkin::Step>, status: step::Status) {
+        let soft_failures = mem::take(
+            &mut *self
+                .soft_failures
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        let mut outcomes =
+            self.outcomes.lock().unwrap_or_else(PoisonError::into_inner);
+        // `step::Outcome`s are cheap to clone, as their `Step`s are shared.
+        *outcomes = outcomes
+            .iter()
+            .cloned()
+            .chain(iter::once(step::Outcome {
+                step: step.clone(),
+                status,
+                soft_failures,
+            }))
+            .collect();
+    }
+}
+
+thread_local! {
+    /// [`Backtrace`] of the last panic happened on the current thread, captured
+    /// by the panic hook installed in [`execute()`].
+    static LAST_PANIC_BACKTRACE: RefCell<Option<Arc<Backtrace>>> =
+        const { RefCell::new(None) };
+}
+
+/// Takes the [`Backtrace`] of the last panic happened on the current thread
+/// (if it hasn't been taken yet).
+fn take_panic_backtrace() -> Option<Arc<Backtrace>> {
+    LAST_PANIC_BACKTRACE.with(|bt| bt.borrow_mut().take())
+}
+
 /// Coerces the given `value` into a type-erased [`Info`].
 fn coerce_into_info<T: Any + Send + 'static>(val: T) -> Info {
     Arc::new(val)
 }
 
+/// Creates a [`CaughtPanic`] of user code interrupted by the exceeded global
+/// timeout, declaring the [`event::FailureCategory::Timeout`].
+fn timed_out_panic() -> CaughtPanic {
+    let payload = event::CategorizedPanic {
+        category: event::FailureCategory::Timeout,
+        message: "global timeout exceeded".to_owned(),
+    };
+    (Box::new(payload), None)
+}
+
+/// Converts the provided [`catch_unwind()`] panic payload into an [`Info`],
+/// unwrapping the message of a panic declaring its [`event::FailureCategory`].
+///
+/// [`catch_unwind()`]: std::panic::catch_unwind()
+fn panic_into_info(payload: Box<dyn Any + Send>) -> Info {
+    payload
+        .downcast::<event::CategorizedPanic>()
+        .map_or_else(Info::from, |p| coerce_into_info(p.message))
+}
+
+/// Converts the provided [`catch_unwind()`] panic payload of a [`Step`] into
+/// an [`event::StepError`], preserving the [`event::FailureCategory`]
+/// declared via [`event::FailureCategory::fail()`], if any.
+///
+/// [`catch_unwind()`]: std::panic::catch_unwind()
+/// [`Step`]: gherkin::Step
+fn panic_into_step_error(
+    payload: Box<dyn Any + Send>,
+    backtrace: Option<Arc<Backtrace>>,
+) -> event::StepError {
+    let category = payload
+        .downcast_ref::<event::CategorizedPanic>()
+        .map(|p| p.category);
+    let err = event::StepError::Panic(panic_into_info(payload), backtrace);
+    match category {
+        Some(c) => event::StepError::Categorized(c, Box::new(err)),
+        None => err,
+    }
+}
+
 /// Failure encountered during execution of [`HookType::Before`] or [`Step`].
 /// See [`Executor::emit_failed_events()`] for more info.
 ///
@@ -2431,6 +6443,12 @@ enum ExecutionFailure<World> {
         meta: event::Metadata,
     },
 
+    /// [`HookType::Before`] panicked, but the [`Scenario`] is skipped
+    /// according to the [`HookFailurePolicy::SkipScenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    BeforeHookSkipped(Option<World>),
+
     /// [`Step`] was skipped.
     ///
     /// [`Step`]: gherkin::Step.
@@ -2467,19 +6485,59 @@ enum ExecutionFailure<World> {
         /// [`StepError`]: event::StepError
         err: event::StepError,
 
+        /// [`Duration`] of the [`Step`] execution.
+        ///
+        /// [`Step`]: gherkin::Step
+        duration: Duration,
+
         /// [`Metadata`] at the time when [`Step`] failed.
         ///
         /// [`Metadata`]: event::Metadata
         /// [`Step`]: gherkin::Step.
         meta: event::Metadata,
 
-        /// Indicator whether the [`Step`] was background or not.
+        /// [`StepKind`] of the [`Step`].
         ///
         /// [`Step`]: gherkin::Step
-        is_background: bool,
+        kind: StepKind,
     },
 }
 
+/// Kind of a [`Step`], determining the events it's reported with.
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StepKind {
+    /// [`Step`] of a [`Feature`]'s [`Background`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    FeatureBackground,
+
+    /// [`Step`] of a [`Rule`]'s [`Background`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
+    RuleBackground,
+
+    /// [`Step`] of a [`Scenario`] itself.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    Scenario,
+}
+
+impl StepKind {
+    /// Indicates whether this [`StepKind`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    const fn is_background(self) -> bool {
+        !matches!(self, Self::Scenario)
+    }
+}
+
 /// [`Metadata`] of [`HookType::After`] events.
 ///
 /// [`Metadata`]: event::Metadata
@@ -2500,6 +6558,7 @@ impl<W> ExecutionFailure<W> {
     fn take_world(&mut self) -> Option<W> {
         match self {
             Self::BeforeHookPanicked { world, .. }
+            | Self::BeforeHookSkipped(world)
             | Self::StepSkipped(world)
             | Self::StepPanicked { world, .. } => world.take(),
         }
@@ -2515,7 +6574,7 @@ impl<W> ExecutionFailure<W> {
             Self::BeforeHookPanicked { panic_info, .. } => {
                 BeforeHookFailed(Arc::clone(panic_info))
             }
-            Self::StepSkipped(_) => StepSkipped,
+            Self::BeforeHookSkipped(_) | Self::StepSkipped(_) => StepSkipped,
             Self::StepPanicked {
                 captures, loc, err, ..
             } => StepFailed(captures.clone(), *loc, err.clone()),
@@ -2564,6 +6623,18 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2580,6 +6651,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2590,6 +6662,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2600,6 +6673,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2610,6 +6684,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -2622,6 +6697,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: None,
                 retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2634,6 +6721,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2644,6 +6732,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2654,6 +6743,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2664,6 +6754,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2674,6 +6765,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -2686,6 +6778,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2698,6 +6802,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2708,6 +6813,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2718,6 +6824,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2728,6 +6835,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2738,6 +6846,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -2750,6 +6859,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: None,
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2766,6 +6887,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2776,6 +6898,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2786,6 +6909,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2796,6 +6920,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -2808,6 +6933,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2824,6 +6961,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2834,6 +6972,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2844,6 +6983,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2854,6 +6994,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -2915,6 +7056,18 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2941,6 +7094,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2956,6 +7110,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2971,6 +7126,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -2986,6 +7142,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3001,6 +7158,7 @@ Feature: only scenarios
                         left: 3,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3016,6 +7174,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3031,6 +7190,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3046,6 +7206,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3061,6 +7222,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -3073,6 +7235,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -3099,6 +7273,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3114,6 +7289,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3129,6 +7305,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3144,6 +7321,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3159,6 +7337,7 @@ Feature: only scenarios
                         left: 3,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3174,6 +7353,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3189,6 +7369,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3204,6 +7385,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3219,6 +7401,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -3300,6 +7483,18 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
@@ -3312,6 +7507,7 @@ Feature: only scenarios
                         left: 8,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3322,6 +7518,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3332,6 +7529,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3342,6 +7540,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3352,6 +7551,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3367,6 +7567,7 @@ Feature: only scenarios
                         left: 8,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3382,6 +7583,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3397,6 +7599,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3412,6 +7615,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3427,6 +7631,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3442,6 +7647,7 @@ Feature: only scenarios
                         left: 3,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3457,6 +7663,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3472,6 +7679,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: None,
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3487,6 +7695,7 @@ Feature: only scenarios
                         left: 1,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3502,6 +7711,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
         }
@@ -3514,6 +7724,18 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -3526,6 +7748,7 @@ Feature: only scenarios
                         left: 8,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3536,6 +7759,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3546,6 +7770,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3556,6 +7781,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3566,6 +7792,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3581,6 +7808,7 @@ Feature: only scenarios
                         left: 8,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3596,6 +7824,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3611,6 +7840,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3626,6 +7856,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3641,6 +7872,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3656,6 +7888,7 @@ Feature: only scenarios
                         left: 3,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3671,6 +7904,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3686,6 +7920,7 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(5)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3701,6 +7936,7 @@ Feature: only scenarios
                         left: 7,
                     },
                     after: Some(Duration::from_secs(3)),
+                    reuse_world: false,
                 }),
             );
             assert_eq!(
@@ -3716,6 +7952,101 @@ Feature: only scenarios
                         left: 5,
                     },
                     after: Some(Duration::from_secs(15)),
+                    reuse_world: false,
+                }),
+            );
+        }
+    }
+
+    mod reuse_world {
+        use super::*;
+
+        // language=Gherkin
+        const FEATURE: &str = r"
+Feature: only scenarios
+  @retry(3)
+  Scenario: without reuse
+    Given a step
+
+  @retry(3).reuse_world
+  Scenario: with reuse
+    Given a step
+
+  @retry.reuse_world.after(1s)
+  Scenario: with reuse before after
+    Given a step
+
+  @retry(2).after(1s).reuse_world
+  Scenario: with reuse after after
+    Given a step
+";
+
+        #[test]
+        fn parses_tags() {
+            let cli = Cli {
+                concurrency: None,
+                fail_fast: false,
+                retry: None,
+                retry_after: None,
+                retry_tag_filter: None,
+                serial_tags: None,
+                concurrent_tags: None,
+                artifacts_dir: None,
+                keep_artifacts: None,
+                budget_policy: None,
+                wip: false,
+                watchdog: None,
+                global_timeout: None,
+                capture_output: false,
+                lint_duplicate_names: false,
+                #[cfg(feature = "output-json")]
+                timings: None,
+            };
+            let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+                .expect("failed to parse feature");
+
+            assert_eq!(
+                RetryOptions::parse_from_tags(&f, None, &f.scenarios[0], &cli),
+                Some(RetryOptions {
+                    retries: Retries {
+                        current: 0,
+                        left: 3,
+                    },
+                    after: None,
+                    reuse_world: false,
+                }),
+            );
+            assert_eq!(
+                RetryOptions::parse_from_tags(&f, None, &f.scenarios[1], &cli),
+                Some(RetryOptions {
+                    retries: Retries {
+                        current: 0,
+                        left: 3,
+                    },
+                    after: None,
+                    reuse_world: true,
+                }),
+            );
+            assert_eq!(
+                RetryOptions::parse_from_tags(&f, None, &f.scenarios[2], &cli),
+                Some(RetryOptions {
+                    retries: Retries {
+                        current: 0,
+                        left: 1,
+                    },
+                    after: Some(Duration::from_secs(1)),
+                    reuse_world: true,
+                }),
+            );
+            assert_eq!(
+                RetryOptions::parse_from_tags(&f, None, &f.scenarios[3], &cli),
+                Some(RetryOptions {
+                    retries: Retries {
+                        current: 0,
+                        left: 2,
+                    },
+                    after: Some(Duration::from_secs(1)),
+                    reuse_world: true,
                 }),
             );
         }
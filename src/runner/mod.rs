@@ -16,12 +16,15 @@ pub mod basic;
 
 use futures::Stream;
 
-use crate::{event, parser, Event};
+use crate::{cli, event, parser, step, Event};
 #[cfg(doc)]
 use crate::{event::Source, Step};
 
 #[doc(inline)]
-pub use self::basic::{Basic, ScenarioType};
+pub use self::basic::{
+    Basic, BudgetPolicy, HookFailurePolicy, Order, PanicHookStrategy,
+    ScenarioType, Timings,
+};
 
 /// Executor of [`Parser`] output producing [`Cucumber`] events for [`Writer`].
 ///
@@ -90,4 +93,66 @@ pub trait Runner<World> {
     fn run<S>(self, features: S, cli: Self::Cli) -> Self::EventStream
     where
         S: Stream<Item = parser::Result<gherkin::Feature>> + 'static;
+
+    /// Resolves the [`ScenarioPlan`] of how the given [`Scenario`] would be
+    /// executed by this [`Runner`] with the provided `cli` options, without
+    /// executing it.
+    ///
+    /// Default implementation returns [`None`], meaning the [`ScenarioPlan`]
+    /// cannot be resolved upfront.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn scenario_plan(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        cli: &Self::Cli,
+    ) -> Option<ScenarioPlan> {
+        _ = (feature, rule, scenario, cli);
+        None
+    }
+
+    /// Returns [`step::Definition`]s of all the [`Step`] functions this
+    /// [`Runner`] matches [`gherkin::Step`]s with.
+    ///
+    /// Default implementation returns nothing, meaning they're unknown.
+    #[must_use]
+    fn step_definitions(&self) -> Vec<step::Definition> {
+        Vec::new()
+    }
+
+    /// Resolves the effective configuration of this [`Runner`] (its own
+    /// settings merged with the provided `cli` options) as pairs of option
+    /// names and their [`ConfigValue`]s.
+    ///
+    /// Default implementation returns nothing, meaning it's unknown.
+    ///
+    /// [`ConfigValue`]: crate::cli::ConfigValue
+    #[must_use]
+    fn resolved_config(
+        &self,
+        cli: &Self::Cli,
+    ) -> Vec<(&'static str, cli::ConfigValue)> {
+        _ = cli;
+        Vec::new()
+    }
+}
+
+/// Plan of a [`Scenario`] execution by a [`Runner`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScenarioPlan {
+    /// [`ScenarioType`] the [`Scenario`] is executed with.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub ty: ScenarioType,
+
+    /// [`RetryOptions`] of the [`Scenario`], if it's retried on failures.
+    ///
+    /// [`RetryOptions`]: basic::RetryOptions
+    /// [`Scenario`]: gherkin::Scenario
+    pub retries: Option<basic::RetryOptions>,
 }
@@ -0,0 +1,166 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! State shared between [`Scenario`]s of a whole run or of a single
+//! [`Feature`].
+//!
+//! Every [`World`] is still created per-[`Scenario`], so [`Scenario`]s stay
+//! isolated by default. However, some resources (like a database connection
+//! pool) are expensive to be re-created for each [`Scenario`]. Those may be
+//! put into a run-scoped [`State`] by a [`runner::Basic::before_all()`] hook,
+//! or into a [`Feature`]-scoped one by a [`runner::Basic::before_feature()`]
+//! hook, and then accessed (read-only) in [`Step`]s via
+//! [`step::Context::shared`].
+//!
+//! [`Feature`]: gherkin::Feature
+//! [`Scenario`]: gherkin::Scenario
+//! [`Step`]: crate::Step
+//! [`World`]: crate::World
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+#[cfg(doc)]
+use crate::{runner, step};
+
+/// Type map of values shared between [`Scenario`]s.
+///
+/// Holds at most one value of each type.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug, Default)]
+pub struct State {
+    /// Values of this [`State`] keyed by their [`TypeId`]s.
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl State {
+    /// Creates a new empty [`State`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the provided `value` into this [`State`], returning the
+    /// previous value of the same type, if any.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|v| v.downcast().ok())
+            .map(|v| *v)
+    }
+
+    /// Returns a reference to the value of type `T`, if any.
+    #[must_use]
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the value of type `T`, if any.
+    #[must_use]
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.values.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+
+    /// Removes the value of type `T` from this [`State`], returning it, if
+    /// any.
+    pub fn remove<T: Any>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|v| v.downcast().ok())
+            .map(|v| *v)
+    }
+
+    /// Indicates whether this [`State`] contains a value of type `T`.
+    #[must_use]
+    pub fn contains<T: Any>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of values in this [`State`].
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Indicates whether this [`State`] is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// [`State`]s accessible in a [`Step`].
+///
+/// [`Step`]: crate::Step
+#[derive(Clone, Debug, Default)]
+pub struct Scopes {
+    /// [`State`] shared between all the [`Scenario`]s of the run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub run: Arc<State>,
+
+    /// [`State`] shared between all the [`Scenario`]s of the [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub feature: Arc<State>,
+}
+
+impl Scopes {
+    /// Returns a reference to the value of type `T`, looking it up in the
+    /// [`Scopes::feature`] [`State`] first, and in the [`Scopes::run`] one
+    /// then.
+    #[must_use]
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.feature.get().or_else(|| self.run.get())
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::{Scopes, State};
+
+    #[test]
+    fn stores_single_value_per_type() {
+        let mut state = State::new();
+
+        assert_eq!(state.insert(1_u8), None);
+        assert_eq!(state.insert("str"), None);
+        assert_eq!(state.insert(2_u8), Some(1));
+        assert_eq!(state.len(), 2);
+
+        *state.get_mut::<u8>().unwrap() += 1;
+        assert_eq!(state.get::<u8>(), Some(&3));
+        assert_eq!(state.remove::<&str>(), Some("str"));
+        assert!(!state.contains::<&str>());
+    }
+
+    #[test]
+    fn prefers_feature_scope() {
+        let mut run = State::new();
+        _ = run.insert(1_u8);
+        _ = run.insert(1_u16);
+        let mut feature = State::new();
+        _ = feature.insert(2_u8);
+
+        let scopes = Scopes {
+            run: run.into(),
+            feature: feature.into(),
+        };
+
+        assert_eq!(scopes.get::<u8>(), Some(&2));
+        assert_eq!(scopes.get::<u16>(), Some(&1));
+        assert_eq!(scopes.get::<u32>(), None);
+    }
+}
@@ -14,18 +14,25 @@
 //! [`Step`]: gherkin::Step
 
 use std::{
+    borrow::Borrow,
     cmp::Ordering,
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
-    iter,
+    io,
+    path::Path,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
 };
 
 use derive_more::with_trait::{Debug, Deref, DerefMut, Display, Error};
 use futures::future::LocalBoxFuture;
 use gherkin::StepType;
-use itertools::Itertools as _;
-use regex::Regex;
+use itertools::{Either, Itertools as _};
+use regex::{Regex, RegexSet};
+
+#[cfg(feature = "macros")]
+use crate::codegen;
+use crate::{artifacts, event::Source, feature, shared};
 
 /// Alias for a [`gherkin::Step`] function that returns a [`LocalBoxFuture`].
 pub type Step<World> =
@@ -48,32 +55,21 @@ pub struct Collection<World> {
     /// Collection of [Given] [`Step`]s.
     ///
     /// [Given]: https://cucumber.io/docs/gherkin/reference#given
-    #[debug("{:?}",
-        given.iter()
-            .map(|(re, step)| (re, format!("{step:p}")))
-            .collect::<HashMap<_, _>>(),
-    )]
-    given: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    given: Bucket<World>,
 
     /// Collection of [When] [`Step`]s.
     ///
     /// [When]: https://cucumber.io/docs/gherkin/reference#when
-    #[debug("{:?}",
-        when.iter()
-            .map(|(re, step)| (re, format!("{step:p}")))
-            .collect::<HashMap<_, _>>(),
-    )]
-    when: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    when: Bucket<World>,
 
     /// Collection of [Then] [`Step`]s.
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference#then
-    #[debug("{:?}",
-        then.iter()
-            .map(|(re, step)| (re, format!("{step:p}")))
-            .collect::<HashMap<_, _>>(),
-    )]
-    then: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    then: Bucket<World>,
+
+    /// Indicates whether the most specific [`Step`] (with the longest literal
+    /// prefix of its [`Regex`]) wins in case of an ambiguous match.
+    most_specific_wins: bool,
 }
 
 // Implemented manually to omit redundant `World: Clone` trait bound, imposed by
@@ -84,6 +80,7 @@ impl<World> Clone for Collection<World> {
             given: self.given.clone(),
             when: self.when.clone(),
             then: self.then.clone(),
+            most_specific_wins: self.most_specific_wins,
         }
     }
 }
@@ -93,9 +90,10 @@ impl<World> Clone for Collection<World> {
 impl<World> Default for Collection<World> {
     fn default() -> Self {
         Self {
-            given: HashMap::new(),
-            when: HashMap::new(),
-            then: HashMap::new(),
+            given: Bucket::default(),
+            when: Bucket::default(),
+            then: Bucket::default(),
+            most_specific_wins: false,
         }
     }
 }
@@ -107,6 +105,18 @@ impl<World> Collection<World> {
         Self::default()
     }
 
+    /// Makes the most specific [`Step`] (with the longest literal prefix of
+    /// its [`Regex`]) win in case a [`gherkin::Step`] matches multiple ones,
+    /// instead of returning an [`AmbiguousMatchError`].
+    ///
+    /// [`AmbiguousMatchError`] is still returned if there are multiple equally
+    /// specific [`Step`]s.
+    #[must_use]
+    pub const fn most_specific_wins(mut self) -> Self {
+        self.most_specific_wins = true;
+        self
+    }
+
     /// Adds a [Given] [`Step`] matching the given `regex`.
     ///
     /// [Given]: https://cucumber.io/docs/gherkin/reference#given
@@ -117,7 +127,7 @@ impl<World> Collection<World> {
         regex: Regex,
         step: Step<World>,
     ) -> Self {
-        _ = self.given.insert((regex.into(), loc), step);
+        self.given.insert((regex.into(), loc), step);
         self
     }
 
@@ -131,7 +141,7 @@ impl<World> Collection<World> {
         regex: Regex,
         step: Step<World>,
     ) -> Self {
-        _ = self.when.insert((regex.into(), loc), step);
+        self.when.insert((regex.into(), loc), step);
         self
     }
 
@@ -145,7 +155,7 @@ impl<World> Collection<World> {
         regex: Regex,
         step: Step<World>,
     ) -> Self {
-        _ = self.then.insert((regex.into(), loc), step);
+        self.then.insert((regex.into(), loc), step);
         self
     }
 
@@ -154,102 +164,544 @@ impl<World> Collection<World> {
     ///
     /// # Errors
     ///
-    /// If the given [`gherkin::Step`] matches multiple [`Regex`]es.
+    /// If the given [`gherkin::Step`] matches multiple [`Regex`]es (and the
+    /// most specific one cannot be chosen, if
+    /// [`Collection::most_specific_wins()`] is set).
     pub fn find(
         &self,
         step: &gherkin::Step,
     ) -> Result<Option<WithContext<'_, World>>, AmbiguousMatchError> {
-        let collection = match step.ty {
+        let bucket = match step.ty {
             StepType::Given => &self.given,
             StepType::When => &self.when,
             StepType::Then => &self.then,
         };
 
-        let mut captures = collection
-            .iter()
+        let mut captures = bucket
+            .candidates(&step.value)
             .filter_map(|((re, loc), step_fn)| {
                 let mut captures = re.capture_locations();
-                let names = re.capture_names();
                 re.captures_read(&mut captures, &step.value)
-                    .map(|m| (re, loc, m, captures, names, step_fn))
+                    .map(|_| (re, loc, captures, step_fn))
             })
             .collect::<Vec<_>>();
 
-        let (_, loc, whole_match, captures, names, step_fn) =
-            match captures.len() {
-                0 => return Ok(None),
-                // Instead of `.unwrap()` to avoid documenting `# Panics`.
-                1 => captures.pop().unwrap_or_else(|| unreachable!()),
-                _ => {
-                    return Err(AmbiguousMatchError {
-                        possible_matches: captures
-                            .into_iter()
-                            .map(|(re, loc, ..)| (re.clone(), *loc))
-                            .sorted()
-                            .collect(),
-                    })
-                }
-            };
+        if captures.len() > 1 && self.most_specific_wins {
+            let specificity = |re: &Regex| literal_prefix_len(re.as_str());
+            let max = captures
+                .iter()
+                .map(|(re, ..)| specificity(re))
+                .max()
+                .unwrap_or_default();
+            let most_specific = captures
+                .iter()
+                .filter(|(re, ..)| specificity(re) == max)
+                .count();
+            if most_specific == 1 {
+                captures.retain(|(re, ..)| specificity(re) == max);
+            }
+        }
 
-        #[expect( // intentional
-            clippy::string_slice,
-            reason = "all indices are obtained from the source string"
-        )]
-        let matches = names
-            .map(|opt| opt.map(str::to_owned))
-            .zip(iter::once(whole_match.as_str().to_owned()).chain(
-                (1..captures.len()).map(|group_id| {
+        let (re, loc, captures, step_fn) = match captures.len() {
+            0 => return Ok(None),
+            // Instead of `.unwrap()` to avoid documenting `# Panics`.
+            1 => captures.pop().unwrap_or_else(|| unreachable!()),
+            _ => {
+                return Err(AmbiguousMatchError::new(
                     captures
-                        .get(group_id)
-                        .map_or("", |(s, e)| &step.value[s..e])
-                        .to_owned()
-                }),
-            ))
-            .collect();
+                        .into_iter()
+                        .map(|(re, loc, ..)| (re.clone(), *loc)),
+                ))
+            }
+        };
 
         Ok(Some((
             step_fn,
-            captures,
+            captures.clone(),
             *loc,
             Context {
                 step: step.clone(),
-                matches,
+                regex: re.0.clone(),
+                captures,
+                artifacts: None,
+                shared: shared::Scopes::default(),
+                example: None,
+                prior_steps: Arc::new([]),
+                soft_failures: Arc::default(),
             },
         )))
     }
+
+    /// Returns [`Definition`]s of all the [`Step`] functions of this
+    /// [`Collection`], ordered by their [`Location`]s.
+    #[must_use]
+    pub fn definitions(&self) -> Vec<Definition> {
+        let mut defs = [
+            (StepType::Given, &self.given),
+            (StepType::When, &self.when),
+            (StepType::Then, &self.then),
+        ]
+        .into_iter()
+        .flat_map(|(ty, bucket)| {
+            bucket.steps.keys().map(move |(re, loc)| Definition {
+                ty,
+                regex: re.0.clone(),
+                location: *loc,
+            })
+        })
+        .collect::<Vec<_>>();
+        defs.sort_by(|a, b| {
+            (a.location, a.regex.as_str()).cmp(&(b.location, b.regex.as_str()))
+        });
+        defs
+    }
+
+    /// Returns a [`StepType`] (other than the one of the given
+    /// [`gherkin::Step`]) of a [`Step`] function matching the given
+    /// [`gherkin::Step`], if any.
+    ///
+    /// Useful to detect [`gherkin::Step`]s written with a wrong keyword.
+    #[must_use]
+    pub fn find_other_kind(&self, step: &gherkin::Step) -> Option<StepType> {
+        [
+            (StepType::Given, &self.given),
+            (StepType::When, &self.when),
+            (StepType::Then, &self.then),
+        ]
+        .into_iter()
+        .filter(|(ty, _)| *ty != step.ty)
+        .find_map(|(ty, bucket)| {
+            bucket
+                .candidates(&step.value)
+                .any(|((re, _), _)| re.is_match(&step.value))
+                .then_some(ty)
+        })
+    }
 }
 
-/// Name of a capturing group inside a [`regex`].
-pub type CaptureName = Option<String>;
+/// Key of a [`Step`] inside a [`Bucket`].
+type Key = (HashableRegex, Option<Location>);
+
+/// [`Step`]s of the same [`StepType`], pre-filtered with a [`RegexSet`] on
+/// matching.
+struct Bucket<World> {
+    /// [`Step`]s by their [`Regex`]es and [`Location`]s.
+    steps: HashMap<Key, Step<World>>,
+
+    /// [`RegexSet`] of all the [`Bucket::steps`], lazily built on the first
+    /// [`Bucket::candidates()`] call.
+    ///
+    /// [`None`] if the [`RegexSet`] cannot be built (exceeds size limits, for
+    /// example), so all the [`Bucket::steps`] are tried one by one.
+    index: OnceLock<Option<(RegexSet, Vec<Key>)>>,
+}
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<World> Clone for Bucket<World> {
+    fn clone(&self) -> Self {
+        Self {
+            steps: self.steps.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+// Implemented manually to omit redundant `World: Default` trait bound, imposed
+// by `#[derive(Default)]`.
+impl<World> Default for Bucket<World> {
+    fn default() -> Self {
+        Self {
+            steps: HashMap::new(),
+            index: OnceLock::new(),
+        }
+    }
+}
+
+// Implemented manually to print `Step` function pointers, and to omit the
+// `RegexSet` duplicating the `Regex`es.
+impl<World> Debug for Bucket<World> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.steps.iter().map(|(k, step)| (k, format!("{step:p}"))),
+            )
+            .finish()
+    }
+}
+
+impl<World> Bucket<World> {
+    /// Adds the provided [`Step`], invalidating the built [`RegexSet`].
+    fn insert(&mut self, key: Key, step: Step<World>) {
+        _ = self.steps.insert(key, step);
+        self.index = OnceLock::new();
+    }
+
+    /// Returns [`Step`]s which [`Regex`]es match the provided `text`, or may
+    /// match it, if no [`RegexSet`] can be built.
+    fn candidates<'me>(
+        &'me self,
+        text: &str,
+    ) -> impl Iterator<Item = (&'me Key, &'me Step<World>)> {
+        let index = self.index.get_or_init(|| {
+            let keys = self.steps.keys().cloned().collect::<Vec<_>>();
+            RegexSet::new(keys.iter().map(|(re, _)| re.as_str()))
+                .ok()
+                .map(|set| (set, keys))
+        });
+        match index {
+            Some((set, keys)) => Either::Left(
+                set.matches(text)
+                    .into_iter()
+                    .filter_map(|i| keys.get(i))
+                    .filter_map(|k| self.steps.get_key_value(k)),
+            ),
+            None => Either::Right(self.steps.iter()),
+        }
+    }
+}
 
 /// Context for a [`Step`] function execution.
 #[derive(Clone, Debug)]
+#[expect( // intentional
+    clippy::partial_pub_fields,
+    reason = "prior steps are set by `Runner` only and exposed via methods"
+)]
 pub struct Context {
     /// [`Step`] matched to a [`Step`] function.
     ///
     /// [`Step`]: gherkin::Step
     pub step: gherkin::Step,
 
-    /// [`Regex`] matches of a [`Step::value`].
+    /// [`Regex`] the [`Context::step`] is matched with.
+    pub regex: Regex,
+
+    /// [`regex::CaptureLocations`] of the [`Context::regex`] inside a
+    /// [`Step::value`].
     ///
     /// [`Step::value`]: gherkin::Step::value
-    pub matches: Vec<(CaptureName, String)>,
+    pub captures: regex::CaptureLocations,
+
+    /// Artifacts [`Dir`] of the [`Scenario`] this [`Step`] belongs to.
+    ///
+    /// [`Dir`]: artifacts::Dir
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub artifacts: Option<artifacts::Dir>,
+
+    /// [`shared::State`]s of the run and of the [`Feature`] this [`Step`]
+    /// belongs to.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Step`]: gherkin::Step
+    pub shared: shared::Scopes,
+
+    /// [`Example`] row the [`Scenario Outline`][1] this [`Step`] belongs to
+    /// has been expanded from, if any.
+    ///
+    /// [`Example`]: feature::Example
+    /// [`Step`]: gherkin::Step
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    pub example: Option<feature::Example>,
+
+    /// [`Outcome`]s of the [`Step`]s preceding this one in its [`Scenario`]
+    /// (including the `Background` ones), in their execution order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    prior_steps: Arc<[Outcome]>,
+
+    /// Reasons of the soft failures reported by this [`Step`] via
+    /// [`Context::soft_fail()`] so far.
+    ///
+    /// [`Step`]: gherkin::Step
+    soft_failures: Arc<Mutex<Vec<String>>>,
+}
+
+impl Context {
+    /// Returns [`Regex`] matches of a [`Step::value`] along with the names of
+    /// their capturing groups (if any), starting with the whole match.
+    ///
+    /// Matches are borrowed from the [`Step::value`], so nothing is allocated
+    /// until they're converted.
+    ///
+    /// [`Step::value`]: gherkin::Step::value
+    #[must_use]
+    pub fn matches(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (Option<&str>, &str)> + Clone {
+        self.regex
+            .capture_names()
+            .enumerate()
+            .map(|(i, name)| (name, self.capture(i).unwrap_or_default()))
+    }
+
+    /// Returns the `i`th [`Regex`] match of a [`Step::value`] (the whole match
+    /// being the `0`th one), if it participated in the match.
+    ///
+    /// [`Step::value`]: gherkin::Step::value
+    #[must_use]
+    pub fn capture(&self, i: usize) -> Option<&str> {
+        #[expect( // intentional
+            clippy::string_slice,
+            reason = "all indices are obtained from the source string"
+        )]
+        self.captures.get(i).map(|(s, e)| &self.step.value[s..e])
+    }
+
+    /// Returns the [`Regex`] match of a [`Step::value`] captured by the named
+    /// group, if it participated in the match.
+    ///
+    /// [`Step::value`]: gherkin::Step::value
+    #[must_use]
+    pub fn named_capture(&self, name: &str) -> Option<&str> {
+        self.regex
+            .capture_names()
+            .position(|n| n == Some(name))
+            .and_then(|i| self.capture(i))
+    }
+
+    /// Returns the artifacts directory of the [`Scenario`] this [`Step`]
+    /// belongs to, creating it if it doesn't exist yet.
+    ///
+    /// Files written there are reported once the [`Scenario`] finishes, and
+    /// kept or removed according to the configured [`artifacts::Retention`].
+    ///
+    /// # Errors
+    ///
+    /// - If no [`Context::artifacts`] are set (by a [`Runner`]).
+    /// - If failed to create the directory.
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fn artifact_dir(&self) -> io::Result<&Path> {
+        self.artifacts
+            .as_ref()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "no artifacts directory is set for this `Step`",
+                )
+            })?
+            .create()
+    }
+
+    /// Returns the [`DocString`] of the [`Context::step`], if any, along with
+    /// its content type annotation.
+    #[must_use]
+    pub fn docstring(&self) -> Option<DocString> {
+        self.step.docstring.as_deref().map(DocString::parse)
+    }
+
+    /// Reports a soft failure of this [`Step`] with the provided `reason`.
+    ///
+    /// Unlike a panic, a soft failure doesn't fail the [`Step`] and doesn't
+    /// stop its [`Scenario`], but is exposed to the following [`Step`]s via
+    /// [`Context::prior_failures()`], so a guard [`Step`] (like `Then no
+    /// errors were logged`) may decide whether the [`Scenario`] should fail.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fn soft_fail(&self, reason: impl Display) {
+        self.soft_failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(reason.to_string());
+    }
+
+    /// Returns [`Outcome`]s of the [`Step`]s preceding this one in its
+    /// [`Scenario`] (including the `Background` ones), in their execution
+    /// order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn prior_steps(&self) -> &[Outcome] {
+        &self.prior_steps
+    }
+
+    /// Returns [`Outcome`]s of the [`Context::prior_steps()`] having failed or
+    /// reported any soft failures via [`Context::soft_fail()`].
+    pub fn prior_failures(&self) -> impl Iterator<Item = &Outcome> {
+        self.prior_steps.iter().filter(|o| o.is_failed())
+    }
+
+    /// Sets the [`Outcome`]s of the preceding [`Step`]s and the storage of
+    /// soft failures reported via [`Context::soft_fail()`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn set_prior_steps(
+        &mut self,
+        prior_steps: Arc<[Outcome]>,
+        soft_failures: Arc<Mutex<Vec<String>>>,
+    ) {
+        self.prior_steps = prior_steps;
+        self.soft_failures = soft_failures;
+    }
+}
+
+/// Outcome of a [`Step`] executed in a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    /// Executed [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub step: Source<gherkin::Step>,
+
+    /// [`Status`] the [`Step`] has finished with.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub status: Status,
+
+    /// Reasons of the soft failures reported by the [`Step`] via
+    /// [`Context::soft_fail()`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub soft_failures: Vec<String>,
+}
+
+impl Outcome {
+    /// Indicates whether the [`Step`] has failed or reported any soft
+    /// failures.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        self.status == Status::Failed || !self.soft_failures.is_empty()
+    }
+}
+
+/// Status of a [`Step`] executed in a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// [`Step`] has passed.
+    ///
+    /// [`Step`]: gherkin::Step
+    Passed,
+
+    /// [`Step`] has failed.
+    ///
+    /// [`Step`]: gherkin::Step
+    Failed,
+
+    /// [`Step`] has been skipped (no [`Step`] function matched it).
+    ///
+    /// [`Step`]: gherkin::Step
+    Skipped,
+}
+
+impl Borrow<gherkin::Step> for Context {
+    fn borrow(&self) -> &gherkin::Step {
+        &self.step
+    }
+}
+
+/// [Doc string][0] of a [`gherkin::Step`] along with its content type
+/// annotation (like ` ```json ` or `"""xml`).
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::step::DocString;
+/// #
+/// let doc = DocString::parse("json\n  {\n    \"a\": 1\n  }\n");
+///
+/// assert_eq!(doc.content_type.as_deref(), Some("json"));
+/// assert_eq!(doc.content, "{\n  \"a\": 1\n}");
+/// ```
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference#doc-strings
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocString {
+    /// Content type annotation of this [`DocString`], if any.
+    pub content_type: Option<String>,
+
+    /// Content of this [`DocString`], without the content type annotation and
+    /// the trailing newline.
+    pub content: String,
+}
+
+impl DocString {
+    /// Parses a [`DocString`] out of the raw [`gherkin::Step::docstring`].
+    ///
+    /// [`gherkin`] parser keeps the content type annotation as the first line
+    /// of the [`gherkin::Step::docstring`], not dedenting the following ones
+    /// in such case, so this is done here.
+    #[must_use]
+    pub fn parse(docstring: &str) -> Self {
+        let (first, rest) =
+            docstring.split_once('\n').unwrap_or((docstring, ""));
+        let content_type = Some(first.trim())
+            .filter(|t| !t.is_empty())
+            .map(str::to_owned);
+
+        let mut lines = rest.lines().collect::<Vec<_>>();
+        // Indentation of the closing delimiter isn't a part of the content.
+        if lines.last().is_some_and(|l| l.trim().is_empty()) {
+            _ = lines.pop();
+        }
+        let indent = lines
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.len() - l.trim_start().len())
+            .min()
+            .unwrap_or_default();
+        let content = lines
+            .into_iter()
+            .map(|l| l.get(indent..).unwrap_or_default())
+            .join("\n");
+
+        Self {
+            content_type,
+            content,
+        }
+    }
 }
 
 /// Error of a [`gherkin::Step`] matching multiple [`Step`] [`Regex`]es inside a
 /// [`Collection`].
 #[derive(Clone, Debug, Error)]
 pub struct AmbiguousMatchError {
-    /// Possible [`Regex`]es the [`gherkin::Step`] matches.
-    pub possible_matches: Vec<(HashableRegex, Option<Location>)>,
+    /// Possible [`Step`]s the [`gherkin::Step`] matches, ranked from the most
+    /// specific one.
+    pub possible_matches: Vec<PossibleMatch>,
+}
+
+impl AmbiguousMatchError {
+    /// Creates a new [`AmbiguousMatchError`] out of the provided [`Regex`]es
+    /// and [`Location`]s, ranking them by their [`PossibleMatch::specificity`].
+    #[must_use]
+    pub fn new(
+        matches: impl IntoIterator<Item = (HashableRegex, Option<Location>)>,
+    ) -> Self {
+        Self {
+            possible_matches: matches
+                .into_iter()
+                .map(|(regex, location)| PossibleMatch::new(regex, location))
+                .sorted_by(|a, b| {
+                    b.specificity
+                        .cmp(&a.specificity)
+                        .then_with(|| a.regex.cmp(&b.regex))
+                        .then_with(|| a.location.cmp(&b.location))
+                })
+                .collect(),
+        }
+    }
 }
 
 impl Display for AmbiguousMatchError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Possible matches:")?;
-        for (reg, loc_opt) in &self.possible_matches {
-            write!(f, "\n{reg}")?;
-            if let Some(loc) = loc_opt {
+        for m in &self.possible_matches {
+            write!(f, "\n{}", m.regex)?;
+            if let Some(loc) = &m.location {
                 write!(f, " --> {loc}")?;
             }
         }
@@ -257,6 +709,231 @@ impl Display for AmbiguousMatchError {
     }
 }
 
+/// [`Step`] matching a [`gherkin::Step`] along with other ones.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PossibleMatch {
+    /// [`Regex`] of the [`Step`].
+    pub regex: HashableRegex,
+
+    /// [`Location`] of the [`Step`] [`fn`], if known.
+    pub location: Option<Location>,
+
+    /// Specificity score of the [`Step`]: length of the literal prefix of its
+    /// [`Regex`].
+    pub specificity: usize,
+}
+
+impl PossibleMatch {
+    /// Creates a new [`PossibleMatch`] computing its
+    /// [`PossibleMatch::specificity`].
+    #[must_use]
+    pub fn new(regex: HashableRegex, location: Option<Location>) -> Self {
+        let specificity = literal_prefix_len(regex.as_str());
+        Self {
+            regex,
+            location,
+            specificity,
+        }
+    }
+}
+
+/// Returns the number of literal characters the provided `regex` starts with
+/// (not counting the `^` anchor).
+fn literal_prefix_len(regex: &str) -> usize {
+    let mut chars = regex.strip_prefix('^').unwrap_or(regex).chars().peekable();
+    let mut len = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(e) if !e.is_alphanumeric() => len += 1,
+                _ => break,
+            },
+            '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}'
+            | '^' | '$' => break,
+            _ => {
+                // Quantified char isn't required to be matched literally.
+                if matches!(chars.peek(), Some('?' | '*' | '{')) {
+                    break;
+                }
+                len += 1;
+            }
+        }
+    }
+    len
+}
+
+/// Definition of a [`Step`] function in a [`Collection`].
+#[derive(Clone, Debug)]
+pub struct Definition {
+    /// [`StepType`] of the [`Step`] function.
+    pub ty: StepType,
+
+    /// [`Regex`] the [`Step`] function matches [`gherkin::Step`]s with.
+    pub regex: Regex,
+
+    /// [`Location`] of the [`Step`] function, if known.
+    pub location: Option<Location>,
+}
+
+#[cfg(feature = "macros")]
+impl Definition {
+    /// Returns the [Cucumber Expression] this [`Definition`] is declared with
+    /// via a [`given`], [`when`] or [`then`] macro, if any.
+    ///
+    /// [`given`]: crate::given
+    /// [`when`]: crate::when
+    /// [`then`]: crate::then
+    /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+    #[must_use]
+    pub fn expression(&self) -> Option<&'static str> {
+        codegen::expression_of(self)
+    }
+
+    /// Returns a human-readable signature of typed parameters of this
+    /// [`Definition`] (like `word, int parameters`), if it's declared with a
+    /// [Cucumber Expression].
+    ///
+    /// See [`codegen::expression_signature()`] for details.
+    ///
+    /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+    #[must_use]
+    pub fn signature(&self) -> Option<String> {
+        self.expression().and_then(codegen::expression_signature)
+    }
+}
+
+/// Problem of registered [`Step`] functions, detected by [`validate()`].
+#[derive(Clone, Debug, Error)]
+pub enum ValidationError {
+    /// Multiple [`Step`] functions of the same [`StepType`] are registered
+    /// with the same [`Regex`], so every [`gherkin::Step`] matching it is
+    /// ambiguous.
+    Conflict {
+        /// [`StepType`] of the conflicting [`Step`] functions.
+        ty: StepType,
+
+        /// [`Regex`] of the conflicting [`Step`] functions.
+        regex: HashableRegex,
+
+        /// [`Location`]s of the conflicting [`Step`] functions, if known.
+        locations: Vec<Option<Location>>,
+    },
+
+    /// [Cucumber Expression] of a [`Step`] function cannot be expanded into
+    /// a [`Regex`] (doesn't parse, or references an unknown custom
+    /// parameter).
+    ///
+    /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+    InvalidExpression {
+        /// [`Location`] of the [`Step`] function.
+        location: Location,
+
+        /// [Cucumber Expression] of the [`Step`] function.
+        ///
+        /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+        expression: &'static str,
+
+        /// Description of the error.
+        #[error(not(source))]
+        error: String,
+    },
+
+    /// [`Regex`] of a custom parameter doesn't parse.
+    InvalidParameter {
+        /// Name of the custom parameter.
+        name: &'static str,
+
+        /// Description of the error.
+        #[error(not(source))]
+        error: String,
+    },
+
+    /// Multiple custom parameters are registered with the same name, but
+    /// different [`Regex`]es.
+    ParameterConflict {
+        /// Name of the conflicting custom parameters.
+        name: &'static str,
+
+        /// [`Regex`]es of the conflicting custom parameters.
+        regexes: Vec<&'static str>,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conflict {
+                ty,
+                regex,
+                locations,
+            } => {
+                let keyword = match ty {
+                    StepType::Given => "Given",
+                    StepType::When => "When",
+                    StepType::Then => "Then",
+                };
+                write!(f, "Conflicting {keyword} steps matching `{regex}`:")?;
+                for loc in locations {
+                    match loc {
+                        Some(loc) => write!(f, "\n--> {loc}")?,
+                        None => write!(f, "\n--> <unknown>")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::InvalidExpression {
+                location,
+                expression,
+                error,
+            } => write!(
+                f,
+                "Invalid Cucumber Expression `{expression}`: {error}\n\
+                 --> {location}",
+            ),
+            Self::InvalidParameter { name, error } => {
+                write!(f, "Invalid regex of `{{{name}}}` parameter: {error}")
+            }
+            Self::ParameterConflict { name, regexes } => write!(
+                f,
+                "Conflicting `{{{name}}}` parameters with regexes: `{}`",
+                regexes.join("`, `"),
+            ),
+        }
+    }
+}
+
+/// Validates the provided [`Definition`]s of [`Step`] functions, returning all
+/// the detected problems.
+///
+/// Detects [`Step`] functions of the same [`StepType`] registered with the
+/// same [`Regex`]. With `macros` feature enabled, additionally validates all
+/// the [Cucumber Expressions] of the [`given`], [`when`] and [`then`]
+/// attributes, along with all the custom parameters they reference.
+///
+/// [`given`]: crate::given
+/// [`then`]: crate::then
+/// [`when`]: crate::when
+/// [Cucumber Expressions]: https://cucumber.github.io/cucumber-expressions
+#[must_use]
+pub fn validate(definitions: &[Definition]) -> Vec<ValidationError> {
+    let conflicts = definitions
+        .iter()
+        .into_group_map_by(|d| (d.ty, d.regex.as_str()))
+        .into_values()
+        .filter(|defs| defs.len() > 1)
+        .sorted_by_key(|defs| (defs[0].location, defs[0].regex.as_str()))
+        .map(|defs| ValidationError::Conflict {
+            ty: defs[0].ty,
+            regex: defs[0].regex.clone().into(),
+            locations: defs.iter().map(|d| d.location).collect(),
+        });
+
+    #[cfg(feature = "macros")]
+    let conflicts = conflicts.chain(codegen::validate_expressions());
+
+    conflicts.collect()
+}
+
 /// Location of a [`Step`] [`fn`] automatically filled by a proc macro.
 #[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[display("{path}:{line}:{column}")]
@@ -306,3 +983,135 @@ impl Ord for HashableRegex {
         self.0.as_str().cmp(other.0.as_str())
     }
 }
+
+#[cfg(test)]
+mod spec {
+    use futures::FutureExt as _;
+    use gherkin::StepType;
+    use regex::Regex;
+
+    use super::{Collection, Context, DocString};
+
+    fn step(_: &mut (), _: Context) -> futures::future::LocalBoxFuture<'_, ()> {
+        async {}.boxed_local()
+    }
+
+    fn given(value: &str) -> gherkin::Step {
+        gherkin::Step {
+            keyword: "Given ".into(),
+            ty: StepType::Given,
+            value: value.into(),
+            docstring: None,
+            table: None,
+            span: gherkin::Span { start: 0, end: 0 },
+            position: gherkin::LineCol { line: 0, col: 0 },
+        }
+    }
+
+    fn collection(count: usize) -> Collection<()> {
+        (0..count).fold(Collection::new(), |c, i| {
+            c.given(None, Regex::new(&format!("^step {i}$")).unwrap(), step)
+        })
+    }
+
+    #[test]
+    fn finds_single_match() {
+        let steps = collection(100);
+
+        let (_, _, _, ctx) = steps.find(&given("step 42")).unwrap().unwrap();
+
+        assert_eq!(ctx.capture(0), Some("step 42"));
+        assert!(steps.find(&given("step 100")).unwrap().is_none());
+    }
+
+    #[test]
+    fn borrows_captures() {
+        let steps = Collection::new().given(
+            None,
+            Regex::new(r"^(?P<name>\w+) is (\d+)?(.*)$").unwrap(),
+            step,
+        );
+
+        let (_, _, _, ctx) = steps.find(&given("foo is bar")).unwrap().unwrap();
+
+        assert_eq!(ctx.named_capture("name"), Some("foo"));
+        assert_eq!(ctx.capture(2), None);
+        assert_eq!(
+            ctx.matches().collect::<Vec<_>>(),
+            [
+                (None, "foo is bar"),
+                (Some("name"), "foo"),
+                (None, ""),
+                (None, "bar"),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_ambiguous_match() {
+        let steps =
+            collection(10).given(None, Regex::new(r"^step \d$").unwrap(), step);
+
+        let err = steps.find(&given("step 3")).unwrap_err();
+
+        assert_eq!(err.possible_matches.len(), 2);
+    }
+
+    #[test]
+    fn ranks_ambiguous_matches_by_specificity() {
+        let steps = collection(10)
+            .given(None, Regex::new(r"^step \d$").unwrap(), step)
+            .given(None, Regex::new(r"^(.*)$").unwrap(), step);
+
+        let err = steps.find(&given("step 3")).unwrap_err();
+
+        assert_eq!(
+            err.possible_matches
+                .iter()
+                .map(|m| (m.regex.as_str(), m.specificity))
+                .collect::<Vec<_>>(),
+            [("^step 3$", 6), (r"^step \d$", 5), ("^(.*)$", 0)],
+        );
+    }
+
+    #[test]
+    fn resolves_most_specific_match() {
+        let steps = collection(10)
+            .given(None, Regex::new(r"^step \d$").unwrap(), step)
+            .most_specific_wins();
+
+        let (_, _, _, ctx) = steps.find(&given("step 3")).unwrap().unwrap();
+        assert_eq!(ctx.regex.as_str(), "^step 3$");
+
+        let steps = steps.given(None, Regex::new(r"^step 3\b").unwrap(), step);
+        assert!(steps.find(&given("step 3")).is_err());
+    }
+
+    #[test]
+    fn parses_docstring() {
+        assert_eq!(
+            DocString::parse("\nplain\n  text\n"),
+            DocString {
+                content_type: None,
+                content: "plain\n  text".into(),
+            },
+        );
+        assert_eq!(
+            DocString::parse("xml\n    <a>\n\n      <b/>\n    </a>\n    "),
+            DocString {
+                content_type: Some("xml".into()),
+                content: "<a>\n\n  <b/>\n</a>".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn rebuilds_index_on_insert() {
+        let steps = collection(10);
+        assert!(steps.find(&given("other")).unwrap().is_none());
+
+        let steps = steps.given(None, Regex::new("^other$").unwrap(), step);
+
+        assert!(steps.find(&given("other")).unwrap().is_some());
+    }
+}
@@ -8,9 +8,42 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Extension of a [`TagOperation`].
+//! [Tag expressions][0] and extension of a [`TagOperation`].
+//!
+//! Toolkit for filtering [`Scenario`]s by their tags: a tag [`Expr`]ession is
+//! parsed once, and then may be evaluated against any [`Feature`], [`Rule`]
+//! and [`Scenario`] triple (considering the [inherited] tags), or turned into
+//! a predicate for [`Cucumber::filter_run()`]-like methods.
+//!
+//! ```rust
+//! # use cucumber::tag;
+//! #
+//! let feature = cucumber::gherkin::Feature::parse(
+//!     "@slow\nFeature: F\n  @smoke\n  Scenario: S\n",
+//!     cucumber::gherkin::GherkinEnv::default(),
+//! )
+//! .unwrap();
+//! let scenario = &feature.scenarios[0];
+//!
+//! let expr = "@smoke and not @flaky".parse::<tag::Expr>().unwrap();
+//! assert!(expr.matches(&feature, None, scenario));
+//!
+//! let fast = "@smoke and not @slow".parse::<tag::Expr>().unwrap();
+//! let filter = fast.into_filter();
+//! assert!(!filter(&feature, None, scenario));
+//! ```
+//!
+//! [`Cucumber::filter_run()`]: crate::Cucumber::filter_run
+//! [`Feature`]: gherkin::Feature
+//! [`Rule`]: gherkin::Rule
+//! [`Scenario`]: gherkin::Scenario
+//! [0]: https://cucumber.io/docs/cucumber/api#tag-expressions
 
+use std::{fmt, iter, ops::Range, str::FromStr, time::Duration, vec};
+
+use derive_more::with_trait::{Display, Error};
 use gherkin::tagexpr::TagOperation;
+use itertools::Itertools as _;
 use sealed::sealed;
 
 /// Extension of a [`TagOperation`] allowing to evaluate it.
@@ -22,6 +55,23 @@ pub trait Ext {
     where
         S: AsRef<str>,
         I: IntoIterator<Item = S> + Clone;
+
+    /// Evaluates this [`TagOperation`] for the tags of the provided
+    /// [`Scenario`], including the ones [inherited] from its [`Feature`] and
+    /// [`Rule`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn matches(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> bool {
+        self.eval(inherited(feature, rule, scenario))
+    }
 }
 
 #[sealed]
@@ -39,3 +89,762 @@ impl Ext for TagOperation {
         }
     }
 }
+
+/// Returns all the tags of the provided [`Scenario`] along with the ones
+/// inherited from its [`Feature`] and [`Rule`] (in the `Feature` -> `Rule` ->
+/// `Scenario` order).
+///
+/// Tags of an [`Examples`] block are included into the tags of the
+/// [`Scenario`]s expanded from it, so are inherited too.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag;
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "@a\nFeature: F\n  @b\n  Rule: R\n    @c\n    Scenario: S\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+/// let rule = &feature.rules[0];
+///
+/// assert_eq!(
+///     tag::inherited(&feature, Some(rule), &rule.scenarios[0])
+///         .collect::<Vec<_>>(),
+///     ["a", "b", "c"],
+/// );
+/// ```
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+pub fn inherited<'t>(
+    feature: &'t gherkin::Feature,
+    rule: Option<&'t gherkin::Rule>,
+    scenario: &'t gherkin::Scenario,
+) -> impl Iterator<Item = &'t String> + Clone {
+    feature
+        .tags
+        .iter()
+        .chain(rule.into_iter().flat_map(|r| &r.tags))
+        .chain(&scenario.tags)
+}
+
+/// Indicates whether the provided [`Scenario`] is marked (directly or via
+/// [inheritance](inherited)) with a built-in `@ignore` or `@skip` tag, so
+/// should be reported as skipped without being run.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[must_use]
+pub fn is_ignored(
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> bool {
+    inherited(feature, rule, scenario).any(|t| t == "ignore" || t == "skip")
+}
+
+/// Indicates whether the provided [`Scenario`] is marked (directly or via
+/// [inheritance](inherited)) with a built-in `@wip` tag, so its failures
+/// shouldn't fail the run.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[must_use]
+pub fn is_wip(
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> bool {
+    inherited(feature, rule, scenario).any(|t| t == "wip")
+}
+
+/// Returns the test management key of the provided [`Scenario`].
+///
+/// The key (like a [Jira Xray] or [Zephyr] one) is set via a
+/// `@testkey(PROJ-123)` tag, preferring the most specific one (in the
+/// `Scenario` -> `Rule` -> `Feature` order).
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag;
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "@testkey(PROJ-1)\nFeature: F\n  Scenario: S1\n\n  \
+///      @testkey(PROJ-2)\n  Scenario: S2\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     tag::test_key(&feature, None, &feature.scenarios[0]),
+///     Some("PROJ-1"),
+/// );
+/// assert_eq!(
+///     tag::test_key(&feature, None, &feature.scenarios[1]),
+///     Some("PROJ-2"),
+/// );
+/// ```
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [Jira Xray]: https://www.getxray.app
+/// [Zephyr]: https://smartbear.com/test-management/zephyr
+#[must_use]
+pub fn test_key<'t>(
+    feature: &'t gherkin::Feature,
+    rule: Option<&'t gherkin::Rule>,
+    scenario: &'t gherkin::Scenario,
+) -> Option<&'t str> {
+    let parse = |tags: &'t [String]| {
+        tags.iter().find_map(|t| {
+            t.strip_prefix("testkey(")
+                .and_then(|k| k.strip_suffix(')'))
+                .filter(|k| !k.is_empty())
+        })
+    };
+    parse(&scenario.tags)
+        .or_else(|| rule.and_then(|r| parse(&r.tags)))
+        .or_else(|| parse(&feature.tags))
+}
+
+/// Returns the time budget of the provided [`Scenario`].
+///
+/// The budget is set via a `@budget(<duration>)` tag with a human-readable
+/// duration (like `@budget(2m)` or `@budget(1min30s)`), preferring the most
+/// specific one (in the `Scenario` -> `Rule` -> `Feature` order).
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// #
+/// # use cucumber::tag;
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "@budget(2m)\nFeature: F\n  Scenario: S1\n\n  \
+///      @budget(500ms)\n  Scenario: S2\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     tag::budget(&feature, None, &feature.scenarios[0]),
+///     Some(Duration::from_secs(120)),
+/// );
+/// assert_eq!(
+///     tag::budget(&feature, None, &feature.scenarios[1]),
+///     Some(Duration::from_millis(500)),
+/// );
+/// ```
+///
+/// [`Scenario`]: gherkin::Scenario
+#[must_use]
+pub fn budget(
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> Option<Duration> {
+    let parse = |tags: &[String]| {
+        tags.iter().find_map(|t| {
+            t.strip_prefix("budget(")
+                .and_then(|d| d.strip_suffix(')'))
+                .and_then(|d| humantime::parse_duration(d).ok())
+        })
+    };
+    parse(&scenario.tags)
+        .or_else(|| rule.and_then(|r| parse(&r.tags)))
+        .or_else(|| parse(&feature.tags))
+}
+
+/// Returns names of the [`Fixture`]s required by the provided [`Scenario`].
+///
+/// A [`Fixture`] is required via a `@fixture(<name>)` tag, directly or via
+/// [inheritance](inherited). Every name is returned only once, in the order
+/// of its first appearance.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag;
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "@fixture(db)\nFeature: F\n  \
+///      @fixture(http-mock) @fixture(db)\n  Scenario: S\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     tag::fixtures(&feature, None, &feature.scenarios[0])
+///         .collect::<Vec<_>>(),
+///     ["db", "http-mock"],
+/// );
+/// ```
+///
+/// [`Fixture`]: crate::fixtures::Fixture
+/// [`Scenario`]: gherkin::Scenario
+pub fn fixtures<'t>(
+    feature: &'t gherkin::Feature,
+    rule: Option<&'t gherkin::Rule>,
+    scenario: &'t gherkin::Scenario,
+) -> impl Iterator<Item = &'t str> {
+    inherited(feature, rule, scenario)
+        .filter_map(|t| {
+            t.strip_prefix("fixture(")
+                .and_then(|n| n.strip_suffix(')'))
+                .filter(|n| !n.is_empty())
+        })
+        .unique()
+}
+
+/// Returns environment variables declared for the provided [`Scenario`].
+///
+/// A variable is declared via an `@env(<KEY>=<value>)` tag, directly or via
+/// [inheritance](inherited), with the most specific declaration of the same
+/// `KEY` winning (in the `Scenario` -> `Rule` -> `Feature` order). Variables
+/// are returned in the order of their first appearance.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag;
+/// #
+/// let feature = cucumber::gherkin::Feature::parse(
+///     "@env(LANG=en) @env(TZ=UTC)\nFeature: F\n  \
+///      @env(LANG=de)\n  Scenario: S\n",
+///     cucumber::gherkin::GherkinEnv::default(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     tag::env(&feature, None, &feature.scenarios[0]),
+///     [("LANG", "de"), ("TZ", "UTC")],
+/// );
+/// ```
+///
+/// [`Scenario`]: gherkin::Scenario
+#[must_use]
+pub fn env<'t>(
+    feature: &'t gherkin::Feature,
+    rule: Option<&'t gherkin::Rule>,
+    scenario: &'t gherkin::Scenario,
+) -> Vec<(&'t str, &'t str)> {
+    let declared = inherited(feature, rule, scenario).filter_map(|t| {
+        t.strip_prefix("env(")
+            .and_then(|v| v.strip_suffix(')'))
+            .and_then(|v| v.split_once('='))
+            .filter(|(key, _)| !key.is_empty())
+    });
+
+    let mut vars = Vec::<(&str, &str)>::new();
+    for (key, value) in declared {
+        if let Some(var) = vars.iter_mut().find(|(k, _)| *k == key) {
+            var.1 = value;
+        } else {
+            vars.push((key, value));
+        }
+    }
+    vars
+}
+
+/// [Tag expression][0] (like `@a and (@b or not @c)`).
+///
+/// Implements the whole [official grammar][1]: `not`, `and` and `or`
+/// operators (in the order of their precedence), parentheses, and escaping of
+/// `(`, `)`, `\` and whitespace characters in tags with a `\`.
+///
+/// Leading `@` is insignificant when evaluating, so both `@a` and `a` match
+/// a `@a` tag.
+///
+/// # Example
+///
+/// ```rust
+/// # use cucumber::tag;
+/// #
+/// let expr = "@a and (@b or not @c)".parse::<tag::Expr>().unwrap();
+///
+/// assert!(expr.eval(["a", "b"]));
+/// assert!(expr.eval(["a"]));
+/// assert!(!expr.eval(["a", "c"]));
+/// assert_eq!(expr.to_string(), "( @a and ( @b or not ( @c ) ) )");
+/// ```
+///
+/// [0]: https://cucumber.io/docs/cucumber/api#tag-expressions
+/// [1]: https://github.com/cucumber/tag-expressions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr {
+    /// Both sub-expressions should match.
+    And(Box<Self>, Box<Self>),
+
+    /// Any of sub-expressions should match.
+    Or(Box<Self>, Box<Self>),
+
+    /// Sub-expression shouldn't match.
+    Not(Box<Self>),
+
+    /// Tag literal (unescaped), which should be present.
+    Tag(String),
+}
+
+impl Expr {
+    /// Evaluates this [`Expr`] for the given `tags`.
+    #[must_use]
+    pub fn eval<I, S>(&self, tags: I) -> bool
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S> + Clone,
+    {
+        match self {
+            Self::And(l, r) => l.eval(tags.clone()) && r.eval(tags),
+            Self::Or(l, r) => l.eval(tags.clone()) || r.eval(tags),
+            Self::Not(e) => !e.eval(tags),
+            Self::Tag(t) => {
+                let t = t.strip_prefix('@').unwrap_or(t);
+                tags.into_iter().any(|tag| {
+                    let tag = tag.as_ref();
+                    tag.strip_prefix('@').unwrap_or(tag) == t
+                })
+            }
+        }
+    }
+
+    /// Evaluates this [`Expr`] for the tags of the provided [`Scenario`],
+    /// including the ones [inherited] from its [`Feature`] and [`Rule`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn matches(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> bool {
+        self.eval(inherited(feature, rule, scenario))
+    }
+
+    /// Turns this [`Expr`] into a [`Scenario`]s predicate, suitable for
+    /// [`Cucumber::filter_run()`]-like methods.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use cucumber::{tag, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let smoke = "@smoke and not @flaky".parse::<tag::Expr>().unwrap();
+    /// MyWorld::cucumber()
+    ///     .filter_run_and_exit("tests/features", smoke.into_filter())
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`Cucumber::filter_run()`]: crate::Cucumber::filter_run
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn into_filter(
+        self,
+    ) -> impl Fn(
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ) -> bool
+           + Clone
+           + 'static {
+        move |feature, rule, scenario| self.matches(feature, rule, scenario)
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokenize(s)?.into_iter().peekable();
+        let expr = parse_or(&mut tokens, s.len())?;
+        match tokens.next() {
+            None => Ok(expr),
+            Some((Token::RParen, span)) => {
+                Err(ParseError::new(ParseErrorKind::UnmatchedRParen, span))
+            }
+            Some((_, span)) => {
+                Err(ParseError::new(ParseErrorKind::ExpectedOperator, span))
+            }
+        }
+    }
+}
+
+/// Formats in the canonical form of the [official implementation][0], with
+/// all the operations parenthesized.
+///
+/// [0]: https://github.com/cucumber/tag-expressions
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::And(l, r) => write!(f, "( {l} and {r} )"),
+            Self::Or(l, r) => write!(f, "( {l} or {r} )"),
+            Self::Not(e) => match **e {
+                Self::And(..) | Self::Or(..) => write!(f, "not {e}"),
+                Self::Not(_) | Self::Tag(_) => write!(f, "not ( {e} )"),
+            },
+            Self::Tag(t) => {
+                for c in t.chars() {
+                    if matches!(c, '(' | ')' | '\\') || c.is_whitespace() {
+                        write!(f, "\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<TagOperation> for Expr {
+    fn from(op: TagOperation) -> Self {
+        match op {
+            TagOperation::And(l, r) => {
+                Self::And(Box::new((*l).into()), Box::new((*r).into()))
+            }
+            TagOperation::Or(l, r) => {
+                Self::Or(Box::new((*l).into()), Box::new((*r).into()))
+            }
+            TagOperation::Not(e) => Self::Not(Box::new((*e).into())),
+            TagOperation::Tag(t) => Self::Tag(t),
+        }
+    }
+}
+
+/// Error of parsing an [`Expr`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+#[display("{kind} at {}..{}", span.start, span.end)]
+pub struct ParseError {
+    /// Kind of this [`ParseError`].
+    kind: ParseErrorKind,
+
+    /// Byte range of the parsed expression this [`ParseError`] relates to.
+    span: Range<usize>,
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`] of the provided `kind` at the `span`.
+    const fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Returns the [`ParseErrorKind`] of this [`ParseError`].
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the parsed expression this [`ParseError`]
+    /// relates to.
+    ///
+    /// For errors at the end of the expression, the range is empty.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+/// Possible kinds of a [`ParseError`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// Operator (`and`, `or` or `)`) is expected, but a tag, `not` or `(` is
+    /// found.
+    #[display("Expected operator")]
+    ExpectedOperator,
+
+    /// Operand (tag, `not` or `(`) is expected, but an operator or the end of
+    /// the expression is found.
+    #[display("Expected operand")]
+    ExpectedOperand,
+
+    /// `(` without the closing `)`.
+    #[display("Unmatched (")]
+    UnmatchedLParen,
+
+    /// `)` without the opening `(`.
+    #[display("Unmatched )")]
+    UnmatchedRParen,
+
+    /// `\` before a character which cannot be escaped (only `(`, `)`, `\` and
+    /// whitespace characters can).
+    #[display("Illegal escape")]
+    IllegalEscape,
+}
+
+/// Token of an [`Expr`].
+#[derive(Clone, Debug)]
+enum Token {
+    /// `(` token.
+    LParen,
+
+    /// `)` token.
+    RParen,
+
+    /// `and` operator.
+    And,
+
+    /// `or` operator.
+    Or,
+
+    /// `not` operator.
+    Not,
+
+    /// Unescaped tag literal.
+    Tag(String),
+}
+
+/// Splits the provided [`Expr`] string into [`Token`]s with their spans.
+///
+/// # Errors
+///
+/// On an [`ParseErrorKind::IllegalEscape`].
+fn tokenize(s: &str) -> Result<Vec<(Token, Range<usize>)>, ParseError> {
+    /// Finishes the currently accumulated tag literal, if any.
+    fn finish(
+        tokens: &mut Vec<(Token, Range<usize>)>,
+        literal: &mut Option<(String, bool, usize)>,
+        end: usize,
+    ) {
+        if let Some((lit, escaped, start)) = literal.take() {
+            let token = match lit.as_str() {
+                "and" if !escaped => Token::And,
+                "or" if !escaped => Token::Or,
+                "not" if !escaped => Token::Not,
+                _ => Token::Tag(lit),
+            };
+            tokens.push((token, start..end));
+        }
+    }
+
+    let mut tokens = Vec::new();
+    // Literal being accumulated, whether it contains escapes, and its start.
+    let mut literal = None::<(String, bool, usize)>;
+
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .filter(|(_, e)| {
+                        matches!(e, '(' | ')' | '\\') || e.is_whitespace()
+                    })
+                    .ok_or_else(|| {
+                        let end = chars.peek().map_or(s.len(), |(j, _)| *j);
+                        ParseError::new(ParseErrorKind::IllegalEscape, i..end)
+                    })?;
+                let lit =
+                    literal.get_or_insert_with(|| (String::new(), true, i));
+                lit.0.push(escaped.1);
+                lit.1 = true;
+            }
+            '(' | ')' => {
+                finish(&mut tokens, &mut literal, i);
+                let token = if c == '(' {
+                    Token::LParen
+                } else {
+                    Token::RParen
+                };
+                tokens.push((token, i..i + c.len_utf8()));
+            }
+            c if c.is_whitespace() => finish(&mut tokens, &mut literal, i),
+            c => literal
+                .get_or_insert_with(|| (String::new(), false, i))
+                .0
+                .push(c),
+        }
+    }
+    finish(&mut tokens, &mut literal, s.len());
+
+    Ok(tokens)
+}
+
+/// [`Iterator`] over [`Token`]s with their spans.
+type Tokens = iter::Peekable<vec::IntoIter<(Token, Range<usize>)>>;
+
+/// Parses an `or` operation (the lowest precedence one).
+///
+/// # Errors
+///
+/// If the [`Token`]s don't represent a valid [`Expr`].
+fn parse_or(tokens: &mut Tokens, len: usize) -> Result<Expr, ParseError> {
+    let mut expr = parse_and(tokens, len)?;
+    while tokens.next_if(|(t, _)| matches!(t, Token::Or)).is_some() {
+        expr = Expr::Or(Box::new(expr), Box::new(parse_and(tokens, len)?));
+    }
+    Ok(expr)
+}
+
+/// Parses an `and` operation.
+///
+/// # Errors
+///
+/// If the [`Token`]s don't represent a valid [`Expr`].
+fn parse_and(tokens: &mut Tokens, len: usize) -> Result<Expr, ParseError> {
+    let mut expr = parse_operand(tokens, len)?;
+    while tokens.next_if(|(t, _)| matches!(t, Token::And)).is_some() {
+        expr = Expr::And(Box::new(expr), Box::new(parse_operand(tokens, len)?));
+    }
+    Ok(expr)
+}
+
+/// Parses a `not` operation, a parenthesized [`Expr`] or a tag literal.
+///
+/// # Errors
+///
+/// If the [`Token`]s don't represent a valid [`Expr`].
+fn parse_operand(tokens: &mut Tokens, len: usize) -> Result<Expr, ParseError> {
+    match tokens.next() {
+        Some((Token::Tag(t), _)) => Ok(Expr::Tag(t)),
+        Some((Token::Not, _)) => {
+            Ok(Expr::Not(Box::new(parse_operand(tokens, len)?)))
+        }
+        Some((Token::LParen, paren)) => {
+            let expr = parse_or(tokens, len)?;
+            match tokens.next() {
+                Some((Token::RParen, _)) => Ok(expr),
+                Some((_, span)) => {
+                    Err(ParseError::new(ParseErrorKind::ExpectedOperator, span))
+                }
+                None => {
+                    Err(ParseError::new(ParseErrorKind::UnmatchedLParen, paren))
+                }
+            }
+        }
+        Some((Token::RParen | Token::And | Token::Or, span)) => {
+            Err(ParseError::new(ParseErrorKind::ExpectedOperand, span))
+        }
+        None => Err(ParseError::new(ParseErrorKind::ExpectedOperand, len..len)),
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    //! Conformance tests ported from the [official test data][0].
+    //!
+    //! [0]: https://github.com/cucumber/tag-expressions/tree/main/testdata
+
+    use super::{Expr, ParseErrorKind};
+
+    fn feature() -> gherkin::Feature {
+        gherkin::Feature::parse(
+            "@f\nFeature: F\n  @s1\n  Scenario: S1\n\n  @r\n  Rule: R\n    \
+             @s2\n    Scenario: S2\n",
+            gherkin::GherkinEnv::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        for (input, expected) in [
+            ("a and b", "( a and b )"),
+            ("a or b", "( a or b )"),
+            ("not a", "not ( a )"),
+            ("not not a", "not ( not ( a ) )"),
+            (
+                "( a and b ) or ( c and d )",
+                "( ( a and b ) or ( c and d ) )",
+            ),
+            (
+                "not a or b and not c or not d or e and f",
+                "( ( ( not ( a ) or ( b and not ( c ) ) ) or not ( d ) ) or \
+                 ( e and f ) )",
+            ),
+            (
+                "not a\\(\\) or b and not c or not d or e and f",
+                "( ( ( not ( a\\(\\) ) or ( b and not ( c ) ) ) or \
+                 not ( d ) ) or ( e and f ) )",
+            ),
+            ("a\\\\ and b", "( a\\\\ and b )"),
+            ("x\\ y", "x\\ y"),
+            ("a and (b or c)", "( a and ( b or c ) )"),
+            ("not (a or b)", "not ( a or b )"),
+            ("@a\\ and\\ b", "@a\\ and\\ b"),
+        ] {
+            let expr = input.parse::<Expr>().unwrap_or_else(|e| {
+                panic!("failed to parse `{input}`: {e}");
+            });
+            assert_eq!(expr.to_string(), expected, "input: `{input}`");
+        }
+    }
+
+    #[test]
+    fn evaluating() {
+        let cases: &[(&str, &[&[&str]], &[&[&str]])] = &[
+            ("not x", &[&[], &["y"]], &[&["x"]]),
+            ("x and y", &[&["x", "y"]], &[&[], &["x"], &["y"]]),
+            ("x or y", &[&["x"], &["y"], &["x", "y"]], &[&[]]),
+            ("x\\(1\\) or (y\\(2\\))", &[&["x(1)"], &["y(2)"]], &[&["x"]]),
+            ("x\\\\ or y\\\\\\)", &[&["x\\"], &["y\\)"]], &[&["x"]]),
+            ("@x and not @y", &[&["x"], &["@x"]], &[&["x", "y"]]),
+        ];
+
+        for (input, matching, non_matching) in cases {
+            let expr = input.parse::<Expr>().unwrap();
+            for tags in *matching {
+                assert!(expr.eval(*tags), "`{input}` should match {tags:?}");
+            }
+            for tags in *non_matching {
+                assert!(
+                    !expr.eval(*tags),
+                    "`{input}` shouldn't match {tags:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use ParseErrorKind as K;
+
+        for (input, kind, span) in [
+            ("@a @b or", K::ExpectedOperator, 3..5),
+            ("@a and (@b not)", K::ExpectedOperator, 11..14),
+            ("@a and (@b @c) or", K::ExpectedOperator, 11..13),
+            ("@a and or", K::ExpectedOperand, 7..9),
+            ("or or", K::ExpectedOperand, 0..2),
+            ("a and", K::ExpectedOperand, 5..5),
+            ("not", K::ExpectedOperand, 3..3),
+            ("a b", K::ExpectedOperator, 2..3),
+            ("( a and b ) )", K::UnmatchedRParen, 12..13),
+            ("( ( a and b )", K::UnmatchedLParen, 0..1),
+            ("x or \\y or z", K::IllegalEscape, 5..7),
+            ("x\\", K::IllegalEscape, 1..2),
+        ] {
+            let err = input.parse::<Expr>().unwrap_err();
+            assert_eq!(err.kind(), kind, "input: `{input}`");
+            assert_eq!(err.span(), span, "input: `{input}`");
+        }
+    }
+
+    #[test]
+    fn matching_inherited() {
+        let feature = feature();
+        let (s1, rule) = (&feature.scenarios[0], &feature.rules[0]);
+        let s2 = &rule.scenarios[0];
+
+        for (input, (m1, m2)) in [
+            ("@f", (true, true)),
+            ("@r", (false, true)),
+            ("@s1 or @s2", (true, true)),
+            ("@f and not @r", (true, false)),
+        ] {
+            let expr = input.parse::<Expr>().unwrap();
+            assert_eq!(expr.matches(&feature, None, s1), m1, "`{input}`");
+            assert_eq!(expr.matches(&feature, Some(rule), s2), m2, "`{input}`");
+
+            let filter = expr.into_filter();
+            assert_eq!(filter(&feature, None, s1), m1, "`{input}`");
+        }
+    }
+}
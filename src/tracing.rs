@@ -1,6 +1,14 @@
 //! [`tracing`] integration layer.
 
-use std::{collections::HashMap, fmt, io, iter};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt, io, iter, mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        PoisonError, RwLock,
+    },
+};
 
 use derive_more::with_trait::Debug;
 use futures::channel::{mpsc, oneshot};
@@ -17,16 +25,13 @@ use tracing_subscriber::{
         FmtContext, FormatEvent, FormatFields, MakeWriter,
     },
     layer::{self, Layer, Layered, SubscriberExt as _},
-    registry::LookupSpan,
+    registry::{LookupSpan, Registry},
     util::SubscriberInitExt as _,
 };
 
 use crate::{
-    event::{self, HookType, Source},
-    runner::{
-        self,
-        basic::{RetryOptions, ScenarioId},
-    },
+    event::{self, HookType, ScenarioId, Source},
+    runner::{self, basic::RetryOptions},
     Cucumber, Parser, Runner, ScenarioType, World, Writer,
 };
 
@@ -42,6 +47,16 @@ where
     /// Initializes a global [`tracing::Subscriber`] with a default
     /// [`fmt::Layer`] and [`LevelFilter::INFO`].
     ///
+    /// > __NOTE__: If a global [`tracing::Subscriber`] has been initialized by
+    /// >           a previous [`Cucumber`] run in the same process already, it's
+    /// >           reused, only being rewired to this run.
+    ///
+    /// # Panics
+    ///
+    /// If a previous [`Cucumber`] collecting [`tracing::Event`]s hasn't
+    /// finished its run (or hasn't been dropped) yet, as such runs cannot
+    /// overlap.
+    ///
     /// [`fmt::Layer`]: tracing_subscriber::fmt::Layer
     #[must_use]
     pub fn init_tracing(self) -> Self {
@@ -58,6 +73,17 @@ where
     /// Configures a [`fmt::Layer`], additionally wraps it (for example, into a
     /// [`LevelFilter`]), and initializes as a global [`tracing::Subscriber`].
     ///
+    /// > __NOTE__: If a global [`tracing::Subscriber`] has been initialized by
+    /// >           a previous [`Cucumber`] run in the same process already, it's
+    /// >           reused (ignoring the provided configuration), only being
+    /// >           rewired to this run.
+    ///
+    /// # Panics
+    ///
+    /// If a previous [`Cucumber`] collecting [`tracing::Event`]s hasn't
+    /// finished its run (or hasn't been dropped) yet, as such runs cannot
+    /// overlap.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -115,17 +141,174 @@ where
             >,
         ) -> Out,
     {
-        let (logs_sender, logs_receiver) = mpsc::unbounded();
-        let (span_close_sender, span_close_receiver) = mpsc::unbounded();
+        let Some((record_id, writer)) = self.install_logs_collector() else {
+            return self;
+        };
 
-        let layer = RecordScenarioId::new(span_close_sender).and_then(
+        let layer = record_id.and_then(
             tracing_subscriber::fmt::layer()
                 .fmt_fields(SkipScenarioIdSpan(fmt_fields))
                 .event_format(AppendScenarioMsg(event_format))
-                .with_writer(CollectorWriter::new(logs_sender)),
+                .with_writer(writer),
         );
         Dispatch::new(configure(layer)).init();
 
+        self
+    }
+
+    /// Initializes a global [`tracing::Subscriber`] with a fully custom
+    /// [`Layer`] built by the provided `configure` function, only injecting a
+    /// [`RecordScenarioId`] [`Layer`] below it.
+    ///
+    /// The provided [`CollectorWriter`] must be used as the output of the
+    /// built [`Layer`], and emitted [`tracing::Event`]s must be formatted via
+    /// [`AppendScenarioMsg`] wrapper, so they could be attributed to their
+    /// [`Scenario`]s. Wrapping fields formatting into [`SkipScenarioIdSpan`]
+    /// is optional, and omits technical [`Span`]s from the output.
+    ///
+    /// > __NOTE__: If a global [`tracing::Subscriber`] has been initialized by
+    /// >           a previous [`Cucumber`] run in the same process already, it's
+    /// >           reused (not calling the provided `configure` function), only
+    /// >           being rewired to this run.
+    ///
+    /// > __NOTE__: Per-[`Layer`] filters must enable [`Span`]s of the
+    /// >           `cucumber` target on [`Level::ERROR`], otherwise
+    /// >           [`tracing::Event`]s won't be attributed to their
+    /// >           [`Scenario`]s.
+    ///
+    /// # Panics
+    ///
+    /// If a previous [`Cucumber`] collecting [`tracing::Event`]s hasn't
+    /// finished its run (or hasn't been dropped) yet, as such runs cannot
+    /// overlap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use cucumber::{
+    /// #     tracing::{AppendScenarioMsg, SkipScenarioIdSpan},
+    /// #     World as _,
+    /// # };
+    /// # use tracing_subscriber::{
+    /// #     filter::{LevelFilter, Targets},
+    /// #     fmt::{self, format, format::DefaultFields},
+    /// #     Layer as _,
+    /// # };
+    /// #
+    /// # #[derive(Debug, Default, cucumber::World)]
+    /// # struct World;
+    /// #
+    /// # let _ = async {
+    /// World::cucumber()
+    ///     .with_tracing_layer(|writer| {
+    ///         fmt::layer()
+    ///             .fmt_fields(SkipScenarioIdSpan(DefaultFields::new()))
+    ///             .event_format(AppendScenarioMsg(format().compact()))
+    ///             .with_writer(writer)
+    ///             .with_filter(
+    ///                 Targets::new()
+    ///                     .with_target("cucumber", LevelFilter::ERROR)
+    ///                     .with_target("my_crate", LevelFilter::DEBUG)
+    ///                     .with_default(LevelFilter::WARN),
+    ///             )
+    ///     })
+    ///     .run_and_exit("./tests/features/doctests.feature")
+    ///     .await
+    /// # };
+    /// ```
+    ///
+    /// [`Level::ERROR`]: tracing::Level::ERROR
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn with_tracing_layer<L, Conf>(self, configure: Conf) -> Self
+    where
+        L: Layer<Layered<RecordScenarioId, Registry>> + Send + Sync + 'static,
+        Conf: FnOnce(CollectorWriter) -> L,
+    {
+        let Some((record_id, writer)) = self.install_logs_collector() else {
+            return self;
+        };
+
+        Dispatch::new(
+            tracing_subscriber::registry()
+                .with(record_id)
+                .with(configure(writer)),
+        )
+        .init();
+
+        self
+    }
+
+    /// Initializes a global [`log::Log`]ger, capturing [`log`] crate records
+    /// as [`event::Scenario::Log`]s of the [`Scenario`]s they're emitted in,
+    /// filtered by the provided `level`.
+    ///
+    /// Records emitted outside of any [`Scenario`] are propagated to every
+    /// [`Scenario`] running at the moment.
+    ///
+    /// > __NOTE__: This also initializes a global [`tracing::Subscriber`]
+    /// >           (without any output) wiring records with their
+    /// >           [`Scenario`]s, so cannot be combined with
+    /// >           [`Cucumber::init_tracing()`] and similar methods.
+    ///
+    /// > __NOTE__: If a global [`log::Log`]ger has been initialized by a
+    /// >           previous [`Cucumber`] run in the same process already, it's
+    /// >           reused, only being rewired to this run.
+    ///
+    /// # Panics
+    ///
+    /// - If a global [`log::Log`]ger or [`tracing::Subscriber`] is set already.
+    /// - If a previous [`Cucumber`] collecting [`tracing::Event`]s hasn't
+    ///   finished its run (or hasn't been dropped) yet, as such runs cannot
+    ///   overlap.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[cfg(feature = "log")]
+    #[expect(clippy::expect_used, reason = "documented panic")]
+    #[must_use]
+    pub fn init_log(self, level: log::LevelFilter) -> Self {
+        let Some((record_id, _)) = self.install_logs_collector() else {
+            return self;
+        };
+
+        tracing::dispatcher::set_global_default(Dispatch::new(
+            tracing_subscriber::registry().with(record_id),
+        ))
+        .expect("global `tracing::Subscriber` is set already");
+        log::set_boxed_logger(Box::new(LogBridge))
+            .expect("global `log::Log`ger is set already");
+        log::set_max_level(level);
+
+        self
+    }
+
+    /// Installs a new [`Collector`] into the [`runner::Basic`], returning the
+    /// [`RecordScenarioId`] [`Layer`] and the [`CollectorWriter`] feeding it,
+    /// unless they're already a part of a global [`tracing::Subscriber`]
+    /// initialized by a previous [`Cucumber`] run.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Collector`] of a previous [`Cucumber`] run is still alive.
+    fn install_logs_collector(
+        &self,
+    ) -> Option<(RecordScenarioId, CollectorWriter)> {
+        let (logs_sender, logs_receiver) = mpsc::unbounded();
+        let (span_close_sender, span_close_receiver) = mpsc::unbounded();
+        {
+            let mut senders =
+                SENDERS.write().unwrap_or_else(PoisonError::into_inner);
+            assert!(
+                senders.as_ref().is_none_or(|s| s.logs.is_closed()),
+                "`Cucumber` runs collecting `tracing` events cannot overlap: \
+                 the previous one should finish (or be dropped) first",
+            );
+            *senders = Some(Senders {
+                logs: logs_sender,
+                span_close: span_close_sender,
+            });
+        }
+
         drop(
             self.runner
                 .logs_collector
@@ -135,7 +318,54 @@ where
                 )))),
         );
 
-        self
+        (!INITIALIZED.swap(true, Ordering::SeqCst))
+            .then(|| (RecordScenarioId::new(), CollectorWriter::new()))
+    }
+}
+
+/// Indicator whether a global [`tracing::Subscriber`] has been initialized by
+/// a [`Cucumber`] run already.
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// [`Senders`] feeding the [`Collector`] of the latest [`Cucumber`] run.
+///
+/// These are global, as a global [`tracing::Subscriber`] can be initialized
+/// only once per process, so its [`RecordScenarioId`] and [`CollectorWriter`]
+/// should feed the [`Collector`]s of the subsequent [`Cucumber`] runs too.
+///
+/// __NOTE__: This is a single slot, so [`Cucumber`] runs collecting
+///           [`tracing::Event`]s may only happen sequentially, which is
+///           asserted on installing a new [`Collector`].
+static SENDERS: RwLock<Option<Senders>> = RwLock::new(None);
+
+/// Senders feeding a [`Collector`].
+#[derive(Debug)]
+struct Senders {
+    /// Sender of [`tracing::Event`]s messages with optional corresponding
+    /// [`ScenarioId`].
+    logs: mpsc::UnboundedSender<(Option<ScenarioId>, event::Log)>,
+
+    /// Sender of [`Span`] closing events.
+    span_close: mpsc::UnboundedSender<span::Id>,
+}
+
+impl Senders {
+    /// Sends the provided log message to the [`Collector`] of the latest
+    /// [`Cucumber`] run, if any.
+    fn send_log(scenario_id: Option<ScenarioId>, log: event::Log) {
+        let senders = SENDERS.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(s) = senders.as_ref() {
+            _ = s.logs.unbounded_send((scenario_id, log)).ok();
+        }
+    }
+
+    /// Sends the provided [`Span`] closing event to the [`Collector`] of the
+    /// latest [`Cucumber`] run, if any.
+    fn send_span_close(id: span::Id) {
+        let senders = SENDERS.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(s) = senders.as_ref() {
+            _ = s.span_close.unbounded_send(id).ok();
+        }
     }
 }
 
@@ -170,7 +400,7 @@ pub(crate) struct Collector {
 
     /// Receiver of [`tracing::Event`]s messages with optional corresponding
     /// [`ScenarioId`].
-    logs_receiver: mpsc::UnboundedReceiver<(Option<ScenarioId>, String)>,
+    logs_receiver: mpsc::UnboundedReceiver<(Option<ScenarioId>, event::Log)>,
 
     /// All [`Callback`]s for [`Span`]s closing events with their completion
     /// status.
@@ -189,7 +419,10 @@ pub(crate) struct Collector {
 impl Collector {
     /// Creates a new [`tracing::Event`]s [`Collector`].
     pub(crate) fn new(
-        logs_receiver: mpsc::UnboundedReceiver<(Option<ScenarioId>, String)>,
+        logs_receiver: mpsc::UnboundedReceiver<(
+            Option<ScenarioId>,
+            event::Log,
+        )>,
         span_close_receiver: mpsc::UnboundedReceiver<span::Id>,
     ) -> Self {
         let (sender, receiver) = mpsc::unbounded();
@@ -258,7 +491,7 @@ impl Collector {
             .try_next()
             .ok()
             .flatten()
-            .map(|(id, msg)| {
+            .map(|(id, log)| {
                 id.and_then(|k| self.scenarios.get(&k))
                     .map_or_else(
                         || Either::Left(self.scenarios.values()),
@@ -270,7 +503,7 @@ impl Collector {
                             r.clone(),
                             s.clone(),
                             event::RetryableScenario {
-                                event: event::Scenario::Log(msg.clone()),
+                                event: event::Scenario::Log(log.clone()),
                                 retries: opt.map(|o| o.retries),
                             },
                         )
@@ -384,19 +617,54 @@ impl SpanCloseWaiter {
     }
 }
 
+/// [`log::Log`]ger sending [`log::Record`]s to a [`Collector`] along with the
+/// [`ScenarioId`] of the current [`Span`] (if any).
+#[cfg(feature = "log")]
+#[derive(Debug)]
+struct LogBridge;
+
+#[cfg(feature = "log")]
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let scenario_id = tracing::dispatcher::get_default(|dispatch| {
+            let registry = dispatch.downcast_ref::<Registry>()?;
+            let current = dispatch.current_span();
+            registry
+                .span(current.id()?)?
+                .scope()
+                .find_map(|span| span.extensions().get::<ScenarioId>().copied())
+        });
+        let msg = format!(
+            "{:>5} {}: {}\n",
+            record.level(),
+            record.target(),
+            record.args(),
+        );
+        Senders::send_log(scenario_id, msg.into());
+    }
+
+    fn flush(&self) {}
+}
+
 /// [`Layer`] recording a [`ScenarioId`] into [`Span`]'s [`Extensions`].
 ///
 /// [`Extensions`]: tracing_subscriber::registry::Extensions
 #[derive(Debug)]
-pub struct RecordScenarioId {
-    /// Sender for [`Span`] closing events.
-    span_close_sender: mpsc::UnboundedSender<span::Id>,
-}
+#[non_exhaustive]
+pub struct RecordScenarioId;
 
 impl RecordScenarioId {
     /// Creates a new [`RecordScenarioId`] [`Layer`].
-    const fn new(span_close_sender: mpsc::UnboundedSender<span::Id>) -> Self {
-        Self { span_close_sender }
+    const fn new() -> Self {
+        Self
     }
 }
 
@@ -439,7 +707,7 @@ where
     }
 
     fn on_close(&self, id: span::Id, _ctx: layer::Context<'_, S>) {
-        _ = self.span_close_sender.unbounded_send(id).ok();
+        Senders::send_span_close(id);
     }
 }
 
@@ -490,9 +758,53 @@ impl Visit for IsScenarioIdSpan {
     }
 }
 
+thread_local! {
+    /// Structured fields of the last [`tracing::Event`] formatted by an
+    /// [`AppendScenarioMsg`] on the current thread, to be picked up by a
+    /// [`CollectorWriter`] along with its formatted message.
+    static EVENT_FIELDS: RefCell<Vec<(String, event::LogValue)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// [`Visit`]or collecting all the [`Field`]s of a [`tracing::Event`] as
+/// [`event::LogValue`]s.
+#[derive(Debug, Default)]
+struct CollectFields(Vec<(String, event::LogValue)>);
+
+impl Visit for CollectFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.push((field.name().to_owned(), value.into()));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.push((field.name().to_owned(), value.into()));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push((field.name().to_owned(), value.into()));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.push((field.name().to_owned(), value.into()));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .push((field.name().to_owned(), value.to_owned().into()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .push((field.name().to_owned(), format!("{value:?}").into()));
+    }
+}
+
 /// [`FormatEvent`] wrapper, appending [`tracing::Event`]s with some markers,
 /// to parse them later and retrieve optional [`ScenarioId`].
 ///
+/// Also, preserves structured fields of [`tracing::Event`]s, so they're
+/// available in the emitted [`event::Log`]s.
+///
 /// [`Scenario`]: gherkin::Scenario
 #[derive(Debug)]
 pub struct AppendScenarioMsg<F>(pub F);
@@ -511,6 +823,10 @@ where
     ) -> fmt::Result {
         self.0.format_event(ctx, writer.by_ref(), event)?;
 
+        let mut fields = CollectFields::default();
+        event.record(&mut fields);
+        EVENT_FIELDS.with_borrow_mut(|f| *f = fields.0);
+
         if let Some(scenario_id) = ctx.event_scope().and_then(|scope| {
             scope
                 .from_root()
@@ -551,18 +867,14 @@ mod suffix {
 }
 
 /// [`io::Write`]r sending [`tracing::Event`]s to a `Collector`.
-#[derive(Clone, Debug)]
-pub struct CollectorWriter {
-    /// Sender for notifying the [`Collector`] about [`tracing::Event`]s via.
-    sender: mpsc::UnboundedSender<(Option<ScenarioId>, String)>,
-}
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct CollectorWriter;
 
 impl CollectorWriter {
     /// Creates a new [`CollectorWriter`].
-    const fn new(
-        sender: mpsc::UnboundedSender<(Option<ScenarioId>, String)>,
-    ) -> Self {
-        Self { sender }
+    const fn new() -> Self {
+        Self
     }
 }
 
@@ -570,7 +882,7 @@ impl<'a> MakeWriter<'a> for CollectorWriter {
     type Writer = Self;
 
     fn make_writer(&'a self) -> Self::Writer {
-        self.clone()
+        *self
     }
 }
 
@@ -584,6 +896,12 @@ impl io::Write for CollectorWriter {
         // log messages will be able to interleave each other, making the result
         // unreadable.
         let msgs = String::from_utf8_lossy(buf);
+        // Structured fields belong to the first (and the only) message.
+        let mut fields = EVENT_FIELDS.with_borrow_mut(mem::take);
+        let mut log = |message: &str| event::Log {
+            message: message.to_owned(),
+            fields: mem::take(&mut fields),
+        };
         for msg in msgs.split_terminator(suffix::END) {
             if let Some((before, after)) =
                 msg.rsplit_once(suffix::NO_SCENARIO_ID)
@@ -594,17 +912,14 @@ impl io::Write for CollectorWriter {
                         "wrong separator",
                     ));
                 }
-                _ = self.sender.unbounded_send((None, before.to_owned())).ok();
+                Senders::send_log(None, log(before));
             } else if let Some((before, after)) =
                 msg.rsplit_once(suffix::BEFORE_SCENARIO_ID)
             {
                 let scenario_id = after.parse().map_err(|e| {
                     io::Error::new(io::ErrorKind::InvalidData, e)
                 })?;
-                _ = self
-                    .sender
-                    .unbounded_send((Some(scenario_id), before.to_owned()))
-                    .ok();
+                Senders::send_log(Some(scenario_id), log(before));
             } else {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidData,
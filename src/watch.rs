@@ -0,0 +1,98 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Watching of `.feature` files for changes.
+
+use std::{
+    collections::HashSet, path::PathBuf, sync::mpsc as std_mpsc, thread,
+    time::Duration,
+};
+
+use futures::{channel::mpsc, StreamExt as _};
+use notify::{EventKind, RecursiveMode, Watcher as _};
+
+/// [`Duration`] to wait for more changes after the first one, before reporting
+/// them all together.
+///
+/// Editors usually save a file with several file system events (like creating
+/// a temporary file and renaming it), which shouldn't trigger several re-runs.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Watcher of file system changes.
+#[derive(Debug)]
+pub(crate) struct Watcher {
+    /// Underlying [`notify::Watcher`], stopping watching once dropped.
+    _watcher: notify::RecommendedWatcher,
+
+    /// Receiver of debounced changed paths.
+    changes: mpsc::UnboundedReceiver<HashSet<PathBuf>>,
+}
+
+impl Watcher {
+    /// Starts watching the provided `roots` (recursively).
+    ///
+    /// # Errors
+    ///
+    /// If any of the `roots` cannot be watched.
+    pub(crate) fn new(roots: &[PathBuf]) -> notify::Result<Self> {
+        let (events_tx, events_rx) = std_mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(move |ev: notify::Result<_>| {
+                // Sending fails only if the debouncing thread is finished,
+                // meaning that nobody is interested in changes anymore.
+                drop(events_tx.send(ev));
+            })?;
+        for root in roots {
+            watcher.watch(root, RecursiveMode::Recursive)?;
+        }
+
+        let (changes_tx, changes) = mpsc::unbounded();
+        drop(thread::spawn(move || debounce(&events_rx, &changes_tx)));
+
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    /// Waits for the next batch of changed paths.
+    ///
+    /// Returns [`None`] if no more changes will be ever reported.
+    pub(crate) async fn changed(&mut self) -> Option<HashSet<PathBuf>> {
+        self.changes.next().await
+    }
+}
+
+/// Collects changed paths from the provided `events` into batches, and sends
+/// them via the provided `changes` channel once no more events happen during
+/// the [`DEBOUNCE`] period.
+fn debounce(
+    events: &std_mpsc::Receiver<notify::Result<notify::Event>>,
+    changes: &mpsc::UnboundedSender<HashSet<PathBuf>>,
+) {
+    let changed_paths = |ev: notify::Result<notify::Event>| {
+        // Errors are ignored, as there is no way to recover from them, while
+        // watching still may keep going.
+        ev.ok()
+            .filter(|e| !matches!(e.kind, EventKind::Access(_)))
+            .into_iter()
+            .flat_map(|e| e.paths)
+    };
+
+    while let Ok(ev) = events.recv() {
+        let mut batch = changed_paths(ev).collect::<HashSet<_>>();
+        while let Ok(more) = events.recv_timeout(DEBOUNCE) {
+            batch.extend(changed_paths(more));
+        }
+        if !batch.is_empty() && changes.unbounded_send(batch).is_err() {
+            break;
+        }
+    }
+}
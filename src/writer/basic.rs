@@ -15,6 +15,7 @@ use std::{
     cmp, env,
     fmt::{Debug, Display},
     io,
+    path::Path,
     str::FromStr,
     sync::LazyLock,
 };
@@ -27,11 +28,11 @@ use smart_default::SmartDefault;
 use crate::{
     cli::Colored,
     event::{self, Info, Retries},
-    parser, step,
+    mask, parser, step,
     writer::{
         self,
         out::{Styles, WriteStrExt as _},
-        Ext as _, Verbosity,
+        Ext as _, Lexicon, Verbosity,
     },
     Event, World, Writer,
 };
@@ -43,8 +44,15 @@ pub struct Cli {
     /// Verbosity of an output.
     ///
     /// `-v` is default verbosity, `-vv` additionally outputs world on failed
-    /// steps, `-vvv` additionally outputs step's doc string (if present).
-    #[arg(short, action = clap::ArgAction::Count, global = true)]
+    /// steps, `-vvv` additionally outputs step's doc string (if present),
+    /// `-vvvv` additionally outputs backtraces of panicked steps (captured only
+    /// if enabled via `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`).
+    #[arg(
+        short,
+        action = clap::ArgAction::Count,
+        env = "CUCUMBER_VERBOSE",
+        global = true
+    )]
     pub verbose: u8,
 
     /// Coloring policy for a console output.
@@ -52,10 +60,21 @@ pub struct Cli {
         long,
         value_name = "auto|always|never",
         default_value = "auto",
+        env = "CUCUMBER_COLOR",
         global = true
     )]
     #[default(Coloring::Auto)]
     pub color: Coloring,
+
+    /// Output captured stdout/stderr of passed steps too (not only of failed
+    /// ones).
+    #[arg(
+        long,
+        env = "CUCUMBER_SHOW_CAPTURED",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        global = true
+    )]
+    pub show_captured: bool,
 }
 
 impl Colored for Cli {
@@ -129,6 +148,20 @@ pub struct Basic<Out: io::Write = io::Stdout> {
 
     /// [`Verbosity`] of this [`Writer`].
     verbosity: Verbosity,
+
+    /// Indicator whether [`event::Output`]s of passed [`Step`]s should be
+    /// outputted too.
+    ///
+    /// [`Step`]: gherkin::Step
+    show_captured: bool,
+
+    /// [`event::Output`] captured for the [`Step`] being finished next.
+    ///
+    /// [`Step`]: gherkin::Step
+    captured_output: Option<event::Output>,
+
+    /// [`Lexicon`] to label synthesized entities with.
+    lexicon: Lexicon,
 }
 
 impl<W, Out> Writer<W> for Basic<Out>
@@ -149,16 +182,17 @@ where
 
         match event.map(Event::into_inner) {
             Err(err) => self.parsing_failed(&err),
+            Ok(Cucumber::DuplicateName(dup)) => self.duplicate_name(&dup),
             Ok(
                 Cucumber::Started
                 | Cucumber::ParsingFinished { .. }
-                | Cucumber::Finished,
+                | Cucumber::Finished { .. },
             ) => Ok(()),
             Ok(Cucumber::Feature(f, ev)) => match ev {
                 Feature::Started => self.feature_started(&f),
                 Feature::Scenario(sc, ev) => self.scenario(&f, &sc, &ev),
                 Feature::Rule(r, ev) => self.rule(&f, &r, ev),
-                Feature::Finished => Ok(()),
+                Feature::Aborted | Feature::Finished => Ok(()),
             },
         }
         .unwrap_or_else(|e| panic!("failed to write into terminal: {e}"));
@@ -225,23 +259,45 @@ impl<Out: io::Write> Basic<Out> {
             lines_to_clear: 0,
             re_output_after_clear: String::new(),
             verbosity: verbosity.into(),
+            show_captured: false,
+            captured_output: None,
+            lexicon: Lexicon::new(),
         };
         basic.apply_cli(Cli {
             verbose: u8::from(basic.verbosity) + 1,
             color,
+            show_captured: false,
         });
         basic
     }
 
+    /// Sets the [`Lexicon`] to label hooks and [`Background`]s with.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    ///
+    /// To use it with a [`Normalized`] [`Basic`] [`Writer`], construct it via
+    /// [`Basic::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
     /// Applies the given [`Cli`] options to this [`Basic`] [`Writer`].
     pub fn apply_cli(&mut self, cli: Cli) {
         match cli.verbose {
             0 => {}
             1 => self.verbosity = Verbosity::Default,
             2 => self.verbosity = Verbosity::ShowWorld,
-            _ => self.verbosity = Verbosity::ShowWorldAndDocString,
+            3 => self.verbosity = Verbosity::ShowWorldAndDocString,
+            _ => self.verbosity = Verbosity::ShowBacktrace,
         };
         self.styles.apply_coloring(cli.color);
+        self.show_captured |= cli.show_captured;
     }
 
     /// Clears last `n` lines if [`Coloring`] is enabled.
@@ -266,6 +322,15 @@ impl<Out: io::Write> Basic<Out> {
             .write_line(self.styles.err(format!("Failed to parse: {error}")))
     }
 
+    /// Outputs the detected [`event::DuplicateName`].
+    pub(crate) fn duplicate_name(
+        &mut self,
+        dup: &event::DuplicateName,
+    ) -> io::Result<()> {
+        self.output
+            .write_line(self.styles.skipped(format!("⚠  {dup}")))
+    }
+
     /// Outputs the [started] [`Feature`].
     ///
     /// [started]: event::Feature::Started
@@ -284,7 +349,7 @@ impl<Out: io::Write> Basic<Out> {
     /// [scenario]: event::Rule::Scenario
     /// [started]: event::Rule::Started
     /// [`Rule`]: gherkin::Rule
-    pub(crate) fn rule<W: Debug>(
+    pub(crate) fn rule<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         rule: &gherkin::Rule,
@@ -327,10 +392,10 @@ impl<Out: io::Write> Basic<Out> {
     /// Outputs the [`Scenario`]'s [started]/[background]/[step] event.
     ///
     /// [background]: event::Scenario::Background
-    /// [started]: event::Scenario::Started
+    /// [started]: event::Scenario::Started(_)
     /// [step]: event::Step
     /// [`Scenario`]: gherkin::Scenario
-    pub(crate) fn scenario<W: Debug>(
+    pub(crate) fn scenario<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         scenario: &gherkin::Scenario,
@@ -340,7 +405,7 @@ impl<Out: io::Write> Basic<Out> {
 
         let retries = ev.retries;
         match &ev.event {
-            Scenario::Started => {
+            Scenario::Started(_) => {
                 self.scenario_started(scenario, retries)?;
             }
             Scenario::Hook(_, Hook::Started) => {
@@ -352,15 +417,19 @@ impl<Out: io::Write> Basic<Out> {
                     scenario,
                     *which,
                     retries,
-                    world.as_ref(),
+                    world.as_deref(),
                     info,
                 )?;
                 self.indent = self.indent.saturating_sub(4);
             }
+            Scenario::Hook(which, Hook::Ignored(info)) => {
+                self.hook_ignored(feat, scenario, *which, info)?;
+                self.indent = self.indent.saturating_sub(4);
+            }
             Scenario::Hook(_, Hook::Passed) => {
                 self.indent = self.indent.saturating_sub(4);
             }
-            Scenario::Background(bg, ev) => {
+            Scenario::Background(bg, ev) | Scenario::RuleBackground(bg, ev) => {
                 self.background(feat, scenario, bg, ev, retries)?;
             }
             Scenario::Step(st, ev) => {
@@ -369,7 +438,15 @@ impl<Out: io::Write> Basic<Out> {
             Scenario::Finished => {
                 self.indent = self.indent.saturating_sub(2);
             }
-            Scenario::Log(msg) => self.emit_log(msg)?,
+            Scenario::Log(log) => self.emit_log(&log.message)?,
+            Scenario::Artifact(path) => self.artifact(path)?,
+            Scenario::Stalled(stall) => self.stalled(feat, scenario, stall)?,
+            Scenario::BudgetExceeded(budget) => self.budget_exceeded(budget)?,
+            Scenario::Wip(wip) => self.wip(*wip)?,
+            Scenario::NotRun => self.not_run(feat, scenario)?,
+            Scenario::Output(out) => {
+                self.captured_output = Some(out.clone());
+            }
         }
         Ok(())
     }
@@ -381,11 +458,144 @@ impl<Out: io::Write> Basic<Out> {
         self.output.write_str(msg)
     }
 
+    /// Outputs the [`event::Output`] captured for the just finished [`Step`],
+    /// if it has `failed` or [`Cli::show_captured`] is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn captured_output(&mut self, failed: bool) -> io::Result<()> {
+        let Some(out) = self.captured_output.take() else {
+            return Ok(());
+        };
+        if !failed && !self.show_captured {
+            return Ok(());
+        }
+
+        let indent = self.indent.saturating_sub(3) + 3;
+        let text = [("stdout", &out.stdout), ("stderr", &out.stderr)]
+            .into_iter()
+            .filter(|(_, captured)| !captured.is_empty())
+            .map(|(stream, captured)| {
+                format!(
+                    "{}Captured {stream}:{}",
+                    " ".repeat(indent),
+                    format_str_with_indent(captured.trim_end(), indent + 2),
+                )
+            })
+            .join("\n");
+        self.output.write_line(if failed {
+            self.styles.err(text)
+        } else {
+            self.styles.ok(text)
+        })
+    }
+
+    /// Outputs the [`event::Scenario::Artifact`].
+    pub(crate) fn artifact(&mut self, path: &Path) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(format!(
+            "{}Artifact: {}",
+            " ".repeat(self.indent.saturating_sub(3) + 3),
+            path.display(),
+        ))
+    }
+
+    /// Outputs the [`event::Scenario::Stalled`].
+    pub(crate) fn stalled(
+        &mut self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+        stall: &event::Stall,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}⚠  {stall}\n\
+             {indent}   {}: {} {}:{}:{}{}",
+            sc.keyword,
+            sc.name,
+            feat.path
+                .as_ref()
+                .and_then(|p| p.to_str().map(trim_path))
+                .unwrap_or(&feat.name),
+            stall.step.position.line,
+            stall.step.position.col,
+            stall
+                .world
+                .clone()
+                .map(|w| format_str_with_indent(
+                    mask::mask_registered(w),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .unwrap_or_default(),
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        )))
+    }
+
+    /// Outputs the [`event::Scenario::NotRun`].
+    pub(crate) fn not_run(
+        &mut self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}-  Scenario has not been run, as the execution has been \
+                stopped\n\
+             {indent}   Defined: {}:{}:{}",
+            feat.path
+                .as_ref()
+                .and_then(|p| p.to_str().map(trim_path))
+                .unwrap_or(&feat.name),
+            sc.position.line,
+            sc.position.col,
+            indent = " ".repeat(self.indent + 1),
+        )))
+    }
+
+    /// Outputs the [`event::Scenario::BudgetExceeded`].
+    pub(crate) fn budget_exceeded(
+        &mut self,
+        budget: &event::Budget,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let msg = format!(
+            "{}{}  {budget}",
+            " ".repeat(self.indent.saturating_sub(3)),
+            if budget.failed { "✘" } else { "⚠" },
+        );
+        self.output.write_line(if budget.failed {
+            self.styles.err(msg)
+        } else {
+            self.styles.skipped(msg)
+        })
+    }
+
+    /// Outputs the [`event::Scenario::Wip`].
+    ///
+    /// Only [`event::Wip::Passed`] is outputted, as work-in-progress
+    /// [`Scenario`]s are already recognizable by their tags.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn wip(&mut self, wip: event::Wip) -> io::Result<()> {
+        if wip != event::Wip::Passed {
+            return Ok(());
+        }
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(self.styles.err(format!(
+            "{}✘  {wip}",
+            " ".repeat(self.indent.saturating_sub(3)),
+        )))
+    }
+
     /// Outputs the [failed] [`Scenario`]'s hook.
     ///
     /// [failed]: event::Hook::Failed
     /// [`Scenario`]: gherkin::Scenario
-    pub(crate) fn hook_failed<W: Debug>(
+    pub(crate) fn hook_failed<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         sc: &gherkin::Scenario,
@@ -405,8 +615,9 @@ impl<Out: io::Write> Basic<Out> {
         };
 
         self.output.write_line(style(format!(
-            "{indent}✘  Scenario's {which} hook failed {}:{}:{}\n\
+            "{indent}✘  Scenario's {} failed {}:{}:{}\n\
              {indent}   Captured output: {}{}",
+            self.lexicon.hook(which),
             feat.path
                 .as_ref()
                 .and_then(|p| p.to_str().map(trim_path))
@@ -419,7 +630,7 @@ impl<Out: io::Write> Basic<Out> {
             ),
             world
                 .map(|w| format_str_with_indent(
-                    format!("{w:#?}"),
+                    format_world(w, None),
                     self.indent.saturating_sub(3) + 3,
                 ))
                 .filter(|_| self.verbosity.shows_world())
@@ -428,9 +639,40 @@ impl<Out: io::Write> Basic<Out> {
         )))
     }
 
+    /// Outputs the [ignored] failure of the [`Scenario`]'s hook.
+    ///
+    /// [ignored]: event::Hook::Ignored
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn hook_ignored(
+        &mut self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+        which: event::HookType,
+        info: &Info,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}?  Scenario's {} failed (ignored) {}:{}:{}\n\
+             {indent}   Captured output: {}",
+            self.lexicon.hook(which),
+            feat.path
+                .as_ref()
+                .and_then(|p| p.to_str().map(trim_path))
+                .unwrap_or(&feat.name),
+            sc.position.line,
+            sc.position.col,
+            format_str_with_indent(
+                coerce_error(info),
+                self.indent.saturating_sub(3) + 3
+            ),
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        )))
+    }
+
     /// Outputs the [started] [`Scenario`].
     ///
-    /// [started]: event::Scenario::Started
+    /// [started]: event::Scenario::Started(_)
     /// [`Scenario`]: gherkin::Scenario
     pub(crate) fn scenario_started(
         &mut self,
@@ -467,7 +709,7 @@ impl<Out: io::Write> Basic<Out> {
     /// [skipped]: event::Step::Skipped
     /// [started]: event::Step::Started
     /// [`Step`]: gherkin::Step
-    pub(crate) fn step<W: Debug>(
+    pub(crate) fn step<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         sc: &gherkin::Scenario,
@@ -481,24 +723,26 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.step_started(step)?;
             }
-            Step::Passed(captures, _) => {
+            Step::Passed(captures, _, _) => {
                 self.step_passed(sc, step, captures, retries)?;
+                self.captured_output(false)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Skipped => {
+            Step::Skipped(_) => {
                 self.step_skipped(feat, step)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Failed(c, loc, w, i) => {
+            Step::Failed(c, loc, w, i, _) => {
                 self.step_failed(
                     feat,
                     step,
                     c.as_ref(),
                     *loc,
                     retries,
-                    w.as_ref(),
+                    w.as_deref(),
                     i,
                 )?;
+                self.captured_output(true)?;
                 self.indent = self.indent.saturating_sub(4);
             }
         }
@@ -648,7 +892,7 @@ impl<Out: io::Write> Basic<Out> {
     /// [`Step`]: gherkin::Step
     // TODO: Needs refactoring.
     #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
-    pub(crate) fn step_failed<W: Debug>(
+    pub(crate) fn step_failed<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
@@ -692,7 +936,7 @@ impl<Out: io::Write> Basic<Out> {
         let diagnostics = style(format!(
             "{}{}\n\
              {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
+             {indent}   Defined: {}:{}:{}{}{}{}{}",
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbosity.shows_docstring().then(|| {
@@ -721,9 +965,16 @@ impl<Out: io::Write> Basic<Out> {
                 err.to_string(),
                 self.indent.saturating_sub(3) + 3,
             ),
+            err.backtrace()
+                .filter(|_| self.verbosity.shows_backtrace())
+                .map(|bt| format_str_with_indent(
+                    format!("Backtrace:\n{bt}"),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .unwrap_or_default(),
             world
                 .map(|w| format_str_with_indent(
-                    format!("{w:#?}"),
+                    format_world(w, Some(step)),
                     self.indent.saturating_sub(3) + 3,
                 ))
                 .filter(|_| self.verbosity.shows_world())
@@ -743,7 +994,7 @@ impl<Out: io::Write> Basic<Out> {
     /// [started]: event::Step::Started
     /// [`Background`]: gherkin::Background
     /// [`Step`]: gherkin::Step
-    pub(crate) fn background<W: Debug>(
+    pub(crate) fn background<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         sc: &gherkin::Scenario,
@@ -757,24 +1008,26 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.bg_step_started(bg)?;
             }
-            Step::Passed(captures, _) => {
+            Step::Passed(captures, _, _) => {
                 self.bg_step_passed(sc, bg, captures, retries)?;
+                self.captured_output(false)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Skipped => {
+            Step::Skipped(_) => {
                 self.bg_step_skipped(feat, bg)?;
                 self.indent = self.indent.saturating_sub(4);
             }
-            Step::Failed(c, loc, w, i) => {
+            Step::Failed(c, loc, w, i, _) => {
                 self.bg_step_failed(
                     feat,
                     bg,
                     c.as_ref(),
                     *loc,
                     retries,
-                    w.as_ref(),
+                    w.as_deref(),
                     i,
                 )?;
+                self.captured_output(true)?;
                 self.indent = self.indent.saturating_sub(4);
             }
         }
@@ -896,7 +1149,7 @@ impl<Out: io::Write> Basic<Out> {
         self.clear_last_lines_if_term_present()?;
         self.output.write_line(self.styles.skipped(format!(
             "{indent}?> {}{}{}{}\n\
-             {indent}   Background step failed: {}:{}:{}",
+             {indent}   {background} step failed: {}:{}:{}",
             step.keyword,
             step.value,
             step.docstring
@@ -918,6 +1171,7 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or(&feat.name),
             step.position.line,
             step.position.col,
+            background = self.lexicon.background,
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
@@ -929,7 +1183,7 @@ impl<Out: io::Write> Basic<Out> {
     /// [`Step`]: gherkin::Step
     // TODO: Needs refactoring.
     #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
-    pub(crate) fn bg_step_failed<W: Debug>(
+    pub(crate) fn bg_step_failed<W: World + Debug>(
         &mut self,
         feat: &gherkin::Feature,
         step: &gherkin::Step,
@@ -972,7 +1226,7 @@ impl<Out: io::Write> Basic<Out> {
         let diagnostics = style(format!(
             "{}{}\n\
              {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
+             {indent}   Defined: {}:{}:{}{}{}{}{}",
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbosity.shows_docstring().then(|| {
@@ -1001,9 +1255,16 @@ impl<Out: io::Write> Basic<Out> {
                 err.to_string(),
                 self.indent.saturating_sub(3) + 3,
             ),
+            err.backtrace()
+                .filter(|_| self.verbosity.shows_backtrace())
+                .map(|bt| format_str_with_indent(
+                    format!("Backtrace:\n{bt}"),
+                    self.indent.saturating_sub(3) + 3,
+                ))
+                .unwrap_or_default(),
             world
                 .map(|w| format_str_with_indent(
-                    format!("{w:#?}"),
+                    format_world(w, Some(step)),
                     self.indent.saturating_sub(3) + 3,
                 ))
                 .filter(|_| self.verbosity.shows_world())
@@ -1026,6 +1287,24 @@ pub(crate) fn coerce_error(err: &Info) -> Cow<'static, str> {
         .unwrap_or_else(|| "(Could not resolve panic payload)".into())
 }
 
+/// Formats the given [`World`] for the output, using its [`WorldSnapshot`] (if
+/// any) relevant to the provided failed [`Step`], or its [`Debug`] output
+/// otherwise, with all the registered [`mask::Secrets`] masked.
+///
+/// [`Step`]: gherkin::Step
+/// [`WorldSnapshot`]: crate::WorldSnapshot
+#[must_use]
+pub(crate) fn format_world<W: World + Debug>(
+    world: &W,
+    step: Option<&gherkin::Step>,
+) -> String {
+    mask::mask_registered(
+        world
+            .as_snapshot()
+            .map_or_else(|| format!("{world:#?}"), |s| s.snapshot(step)),
+    )
+}
+
 /// Formats the given [`str`] by adding `indent`s to each line to prettify the
 /// output.
 fn format_str_with_indent(str: impl AsRef<str>, indent: usize) -> String {
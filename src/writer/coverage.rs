@@ -0,0 +1,378 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Step coverage [`Writer`] implementation.
+
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use gherkin::StepType;
+use serde::Serialize;
+
+use crate::{
+    cli, event, parser, step,
+    writer::{self, discard, Ext as _, PathStyle},
+    Event, World, Writer,
+};
+
+/// Step coverage [`Writer`] implementation outputting a JSON report of
+/// [`step::Definition`]s matching once the run is finished.
+///
+/// The report lists which [`gherkin::Step`]s matched which
+/// [`step::Definition`]s, which [`step::Definition`]s were never matched
+/// during the run, and which [`gherkin::Step`]s matched none of them.
+/// [`step::Definition`]s declared with a [Cucumber Expression] are documented
+/// with it and the human-readable signature of its typed parameters.
+///
+/// The report has the following shape:
+/// ```json
+/// {
+///   "definitions": [{
+///     "keyword": "Given",
+///     "regex": "^foo is (\\d+)$",
+///     "location": "tests/steps.rs:12:1",
+///     "steps": [{"path": "features/foo.feature", "line": 3, "column": 5,
+///                "text": "Given foo is 0"}]
+///   }, {
+///     "keyword": "When",
+///     "regex": "...",
+///     "location": "tests/steps.rs:17:1",
+///     "expression": "{word} is {int}",
+///     "signature": "word, int parameters",
+///     "steps": []
+///   }],
+///   "unused": [{"keyword": "When", "regex": "^bar$", "location": "..."}],
+///   "undefined": [{"path": "...", "line": 4, "column": 5, "text": "..."}]
+/// }
+/// ```
+///
+/// This is what the `--step-coverage <file>` CLI option of [`Cucumber`] uses.
+///
+/// # Panics
+///
+/// If fails to write into the output.
+///
+/// [`Cucumber`]: crate::Cucumber
+/// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+#[derive(Clone, Debug)]
+pub struct Coverage<Out: io::Write> {
+    /// [`io::Write`] implementor to output the report into.
+    output: Out,
+
+    /// [`step::Definition`]s to report the coverage of, along with the
+    /// [`gherkin::Step`]s matched them.
+    definitions: Vec<(step::Definition, BTreeSet<StepRef>)>,
+
+    /// [`gherkin::Step`]s not matching any [`step::Definition`].
+    undefined: BTreeSet<StepRef>,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+}
+
+impl<W, Out> Writer<W> for Coverage<Out>
+where
+    W: World,
+    Out: io::Write,
+{
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        match event.map(Event::into_inner) {
+            Ok(Cucumber::Feature(
+                f,
+                Feature::Rule(_, Rule::Scenario(_, ev))
+                | Feature::Scenario(_, ev),
+            )) => match ev.event {
+                Scenario::Background(st, ev)
+                | Scenario::RuleBackground(st, ev)
+                | Scenario::Step(st, ev) => {
+                    self.handle_step_event(&f, &st, &ev);
+                }
+                Scenario::Started(_)
+                | Scenario::Hook(..)
+                | Scenario::Log(_)
+                | Scenario::Output(_)
+                | Scenario::Artifact(_)
+                | Scenario::Stalled(_)
+                | Scenario::BudgetExceeded(_)
+                | Scenario::Wip(_)
+                | Scenario::NotRun
+                | Scenario::Finished => {}
+            },
+            Ok(Cucumber::Finished { .. }) => self.write(),
+            Err(_)
+            | Ok(
+                Cucumber::Started
+                | Cucumber::Feature(..)
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+            ) => {}
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Coverage<O> {}
+
+impl Coverage<fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`Coverage`] [`Writer`]
+    /// reporting the coverage of the provided [`step::Definition`]s into a
+    /// newly created file at the given `path`, reported via
+    /// [`Writer::report_paths()`].
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    pub fn create(
+        path: impl AsRef<Path>,
+        definitions: impl IntoIterator<Item = step::Definition>,
+    ) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?, definitions)
+        })
+    }
+}
+
+impl<Out: io::Write> Coverage<Out> {
+    /// Creates a new [`Normalized`] [`Coverage`] [`Writer`] reporting the
+    /// coverage of the provided [`step::Definition`]s (like the ones of
+    /// [`World::collection()`]) into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`World::collection()`]: World::collection()
+    #[must_use]
+    pub fn new<W: World>(
+        output: Out,
+        definitions: impl IntoIterator<Item = step::Definition>,
+    ) -> writer::Normalize<W, Self> {
+        Self::raw(output, definitions).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Coverage`] [`Writer`] reporting the
+    /// coverage of the provided [`step::Definition`]s into the given `output`,
+    /// and suitable for feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(
+        output: Out,
+        definitions: impl IntoIterator<Item = step::Definition>,
+    ) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(output, definitions)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Coverage`] [`Writer`]
+    /// reporting the coverage of the provided [`step::Definition`]s into the
+    /// given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Coverage::new()`] which creates an already [`Normalized`] version of
+    /// [`Coverage`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(
+        output: Out,
+        definitions: impl IntoIterator<Item = step::Definition>,
+    ) -> Self {
+        Self {
+            output,
+            definitions: definitions
+                .into_iter()
+                .map(|def| (def, BTreeSet::new()))
+                .collect(),
+            undefined: BTreeSet::new(),
+            report_path: None,
+        }
+    }
+
+    /// Handles the given [`event::Step`].
+    fn handle_step_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        step: &gherkin::Step,
+        ev: &event::Step<W>,
+    ) {
+        let loc = match ev {
+            event::Step::Passed(_, loc, _)
+            | event::Step::Failed(_, loc, ..) => *loc,
+            event::Step::Skipped(_) => {
+                // Skipped `Step`s are the ones not matching any definition.
+                _ = self.undefined.insert(StepRef::new(feature, step));
+                return;
+            }
+            event::Step::Started => return,
+        };
+
+        // Definitions without a `Location` are matched by their `Regex`es.
+        let matched = self.definitions.iter_mut().find(|(def, _)| {
+            def.ty == step.ty
+                && loc.map_or_else(
+                    || def.regex.is_match(&step.value),
+                    |l| def.location == Some(l),
+                )
+        });
+        if let Some((_, steps)) = matched {
+            _ = steps.insert(StepRef::new(feature, step));
+        }
+    }
+
+    /// Writes the JSON report into the output.
+    ///
+    /// # Panics
+    ///
+    /// If fails to write into the output.
+    fn write(&mut self) {
+        let report = Report {
+            definitions: self
+                .definitions
+                .iter()
+                .map(|(def, steps)| DefinitionReport::new(def, Some(steps)))
+                .collect(),
+            unused: self
+                .definitions
+                .iter()
+                .filter(|(_, steps)| steps.is_empty())
+                .map(|(def, _)| DefinitionReport::new(def, None))
+                .collect(),
+            undefined: &self.undefined,
+        };
+        serde_json::to_writer_pretty(&mut self.output, &report)
+            .map_err(io::Error::from)
+            .and_then(|()| self.output.write_all(b"\n"))
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write step coverage: {e}"));
+    }
+}
+
+/// Root of a step coverage report.
+#[derive(Debug, Serialize)]
+struct Report<'d> {
+    /// All the [`step::Definition`]s along with the [`gherkin::Step`]s
+    /// matched them.
+    definitions: Vec<DefinitionReport<'d>>,
+
+    /// [`step::Definition`]s never matched during the run.
+    unused: Vec<DefinitionReport<'d>>,
+
+    /// [`gherkin::Step`]s not matching any [`step::Definition`].
+    undefined: &'d BTreeSet<StepRef>,
+}
+
+/// [`step::Definition`] in a step coverage report.
+#[derive(Debug, Serialize)]
+struct DefinitionReport<'d> {
+    /// Keyword of the [`step::Definition`].
+    keyword: &'static str,
+
+    /// [`Regex`] of the [`step::Definition`].
+    ///
+    /// [`Regex`]: regex::Regex
+    regex: &'d str,
+
+    /// [`step::Location`] of the [`step::Definition`], if any.
+    location: Option<String>,
+
+    /// [Cucumber Expression] the [`step::Definition`] is declared with, if
+    /// any.
+    ///
+    /// [Cucumber Expression]: https://cucumber.github.io/cucumber-expressions
+    #[cfg(feature = "macros")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expression: Option<&'static str>,
+
+    /// Human-readable signature of the typed parameters of the
+    /// [`DefinitionReport::expression`], if any.
+    #[cfg(feature = "macros")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+
+    /// [`gherkin::Step`]s matched the [`step::Definition`], if reported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<&'d BTreeSet<StepRef>>,
+}
+
+impl<'d> DefinitionReport<'d> {
+    /// Creates a new [`DefinitionReport`] of the provided
+    /// [`step::Definition`], along with the [`gherkin::Step`]s matched it, if
+    /// they should be reported.
+    fn new(
+        def: &'d step::Definition,
+        steps: Option<&'d BTreeSet<StepRef>>,
+    ) -> Self {
+        Self {
+            keyword: match def.ty {
+                StepType::Given => "Given",
+                StepType::When => "When",
+                StepType::Then => "Then",
+            },
+            regex: def.regex.as_str(),
+            location: def.location.map(|l| l.to_string()),
+            #[cfg(feature = "macros")]
+            expression: def.expression(),
+            #[cfg(feature = "macros")]
+            signature: def.signature(),
+            steps,
+        }
+    }
+}
+
+/// Reference to a [`gherkin::Step`] in a `.feature` file.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+struct StepRef {
+    /// Path of the `.feature` file, or the name of its [`gherkin::Feature`],
+    /// if it has no path.
+    path: String,
+
+    /// Line of the [`gherkin::Step`].
+    line: usize,
+
+    /// Column of the [`gherkin::Step`].
+    column: usize,
+
+    /// Keyword and value of the [`gherkin::Step`].
+    text: String,
+}
+
+impl StepRef {
+    /// Creates a new [`StepRef`] of the provided [`gherkin::Step`].
+    fn new(feature: &gherkin::Feature, step: &gherkin::Step) -> Self {
+        Self {
+            path: feature
+                .path
+                .as_deref()
+                .and_then(|p| PathStyle::Trimmed.render(p))
+                .map_or_else(|| feature.name.clone(), Into::into),
+            line: step.position.line,
+            column: step.position.col,
+            text: format!("{}{}", step.keyword, step.value),
+        }
+    }
+}
@@ -0,0 +1,606 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [CTRF][1] (Common Test Report Format) [`Writer`] implementation.
+//!
+//! [1]: https://ctrf.io
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::{
+    cli, event,
+    event::Source,
+    feature::Example,
+    parser, tag,
+    writer::{
+        self, basic::coerce_error, discard, Ext as _, Lexicon, PathStyle,
+    },
+    Event, World, Writer,
+};
+
+/// [CTRF][1] (Common Test Report Format) [`Writer`] implementation outputting
+/// a [JSON report][2] into an [`io::Write`] implementor once the run is
+/// finished.
+///
+/// Every [`Scenario`] is reported as a single test (so every row of a
+/// [`Scenario Outline`] is reported separately), along with its [`Step`]s.
+/// Only the last attempt of a retried [`Scenario`] determines its status,
+/// while the number of its retries is reported too, and it's marked as
+/// `flaky` if it has passed only after being retried. Failures of
+/// work-in-progress [`Scenario`]s are reported as `pending`, and parsing
+/// errors as failed tests.
+///
+/// # Panics
+///
+/// If fails to write into the output.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Scenario Outline`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [1]: https://ctrf.io
+/// [2]: https://ctrf.io/docs/specification/overview
+#[derive(Clone, Debug)]
+pub struct Ctrf<Out: io::Write> {
+    /// [`io::Write`] implementor to output the report into.
+    output: Out,
+
+    /// [`SystemTime`] the run has started at.
+    started_at: Option<SystemTime>,
+
+    /// [`Test`]s in order of their first start.
+    tests: Vec<Test>,
+
+    /// Indices of the [`Test`]s of the [`gherkin::Scenario`]s.
+    indices: HashMap<Source<gherkin::Scenario>, usize>,
+
+    /// [`PathStyle`] to render [`Test::file_path`]s with.
+    path_style: PathStyle,
+
+    /// [`Lexicon`] to label hooks in failure messages with.
+    lexicon: Lexicon,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+
+    /// Indicator whether the report has been written already.
+    written: bool,
+}
+
+impl<W: World + Debug, Out: io::Write> Writer<W> for Ctrf<Out> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.map(Event::split) {
+            Err(err) => self.handle_error(&err),
+            Ok((Cucumber::Started, meta)) => self.started_at = Some(meta.at),
+            Ok((
+                Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))),
+                meta,
+            )) => {
+                self.handle_scenario_event(&f, Some(&r), &sc, ev, meta.at);
+            }
+            Ok((Cucumber::Feature(f, Feature::Scenario(sc, ev)), meta)) => {
+                self.handle_scenario_event(&f, None, &sc, ev, meta.at);
+            }
+            Ok((Cucumber::Finished { .. }, meta)) => self.write(meta.at),
+            Ok((
+                Cucumber::Feature(..)
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+                _,
+            )) => {}
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+
+    async fn finalize(&mut self) {
+        if !self.written {
+            self.write(SystemTime::now());
+        }
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Ctrf<O> {}
+
+impl Ctrf<fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`Ctrf`] [`Writer`]
+    /// outputting the report into a newly created file at the given `path`,
+    /// reported via [`Writer::report_paths()`].
+    ///
+    /// To use it as a [`Normalized`] [`Writer`], call
+    /// [`WriterExt::normalized()`] on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?)
+        })
+    }
+}
+
+impl<Out: io::Write> Ctrf<Out> {
+    /// Creates a new [`Normalized`] [`Ctrf`] [`Writer`] outputting the report
+    /// into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Ctrf`] [`Writer`] outputting the
+    /// report into the given `output`, and suitable for feeding into
+    /// [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(output: Out) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(output)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Ctrf`] [`Writer`] outputting
+    /// the report into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Ctrf::new()`] which creates an already [`Normalized`] version of
+    /// [`Ctrf`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(output: Out) -> Self {
+        Self {
+            output,
+            started_at: None,
+            tests: Vec::new(),
+            indices: HashMap::new(),
+            path_style: PathStyle::Trimmed,
+            lexicon: Lexicon::new(),
+            report_path: None,
+            written: false,
+        }
+    }
+
+    /// Sets the [`PathStyle`] to render `filePath`s of tests with.
+    ///
+    /// Default is [`PathStyle::Trimmed`].
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Sets the [`Lexicon`] to label hooks in failure messages with.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
+    /// Handles the given [`parser::Error`], reporting it as a failed [`Test`].
+    fn handle_error(&mut self, err: &parser::Error) {
+        let failure = event::ParsingFailure::from(err);
+        let at = millis(SystemTime::now());
+        self.tests.push(Test {
+            name: format!(
+                "Parsing {}",
+                failure
+                    .location(self.path_style)
+                    .unwrap_or_else(|| "failed".into()),
+            ),
+            status: Status::Failed,
+            duration: 0,
+            start: at,
+            stop: at,
+            message: Some(failure.message),
+            tags: Vec::new(),
+            suite: None,
+            file_path: failure
+                .path
+                .as_deref()
+                .and_then(|p| self.path_style.render(p))
+                .map(Into::into),
+            line: failure.position.map(|p| p.line),
+            parameters: BTreeMap::new(),
+            retries: 0,
+            flaky: false,
+            steps: Vec::new(),
+            wip: false,
+        });
+    }
+
+    /// Handles the given [`event::RetryableScenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &Source<gherkin::Scenario>,
+        ev: event::RetryableScenario<W>,
+        at: SystemTime,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        if let Scenario::Started(_) = ev.event {
+            if let Some(&i) = self.indices.get(scenario) {
+                self.tests[i].retry();
+            } else {
+                _ = self.indices.insert(scenario.clone(), self.tests.len());
+                self.tests.push(Test::new(
+                    feature,
+                    rule,
+                    scenario,
+                    self.path_style,
+                    at,
+                ));
+            }
+            return;
+        }
+
+        let Some(test) = self
+            .indices
+            .get(scenario)
+            .and_then(|&i| self.tests.get_mut(i))
+        else {
+            return;
+        };
+        match ev.event {
+            Scenario::Finished => test.finish(at),
+            Scenario::Hook(which, Hook::Failed(_, info)) => {
+                test.fail(format!(
+                    "{} failed: {}",
+                    self.lexicon.hook(which),
+                    coerce_error(&info),
+                ));
+            }
+            Scenario::Background(st, ev)
+            | Scenario::RuleBackground(st, ev)
+            | Scenario::Step(st, ev) => {
+                let name = format!("{}{}", st.keyword, st.value);
+                let status = match ev {
+                    Step::Started => return,
+                    Step::Passed(..) => Status::Passed,
+                    Step::Skipped(_) => Status::Skipped,
+                    Step::Failed(_, _, _, err, _) => {
+                        test.fail(format!("{name}\n{err}"));
+                        Status::Failed
+                    }
+                };
+                test.status = test.status.max(status);
+                test.steps.push(TestStep { name, status });
+            }
+            Scenario::NotRun => test.status = test.status.max(Status::Skipped),
+            Scenario::BudgetExceeded(budget) if budget.failed => {
+                test.fail(budget.to_string());
+            }
+            Scenario::Wip(event::Wip::Marked) => test.wip = true,
+            Scenario::Wip(wip @ event::Wip::Passed) => {
+                test.fail(wip.to_string());
+                // Passed work-in-progress `Scenario` is a failure on its own.
+                test.wip = false;
+            }
+            Scenario::Started(_)
+            | Scenario::Hook(..)
+            | Scenario::Log(_)
+            | Scenario::Output(_)
+            | Scenario::Artifact(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_) => {}
+        }
+    }
+
+    /// Writes the collected [`Test`]s into the output as a [`Report`].
+    ///
+    /// # Panics
+    ///
+    /// If fails to write into the output.
+    fn write(&mut self, at: SystemTime) {
+        self.written = true;
+
+        let mut summary = Summary {
+            tests: self.tests.len(),
+            passed: 0,
+            failed: 0,
+            pending: 0,
+            skipped: 0,
+            other: 0,
+            start: millis(self.started_at.unwrap_or(at)),
+            stop: millis(at),
+        };
+        for test in &self.tests {
+            *match test.status {
+                Status::Passed => &mut summary.passed,
+                Status::Skipped => &mut summary.skipped,
+                Status::Pending => &mut summary.pending,
+                Status::Failed => &mut summary.failed,
+            } += 1;
+        }
+
+        let report = Report {
+            results: Results {
+                tool: Tool {
+                    name: "cucumber",
+                    version: env!("CARGO_PKG_VERSION"),
+                },
+                summary,
+                tests: &self.tests,
+            },
+        };
+        serde_json::to_writer(&mut self.output, &report)
+            .map_err(io::Error::from)
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("Failed to write CTRF report: {e}"));
+    }
+}
+
+/// Converts the provided [`SystemTime`] into milliseconds since
+/// [`UNIX_EPOCH`].
+fn millis(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|d| u64::try_from(d.as_millis()).ok())
+        .unwrap_or_default()
+}
+
+/// Root of a [CTRF report][1].
+///
+/// [1]: https://ctrf.io/docs/specification/overview
+#[derive(Debug, Serialize)]
+struct Report<'t> {
+    /// [`Results`] of the run.
+    results: Results<'t>,
+}
+
+/// Results of the run in a [CTRF report][1].
+///
+/// [1]: https://ctrf.io/docs/specification/overview
+#[derive(Debug, Serialize)]
+struct Results<'t> {
+    /// [`Tool`] produced the report.
+    tool: Tool,
+
+    /// [`Summary`] of the run.
+    summary: Summary,
+
+    /// Executed [`Test`]s.
+    tests: &'t [Test],
+}
+
+/// Tool produced a [CTRF report][1].
+///
+/// [1]: https://ctrf.io/docs/specification/overview
+#[derive(Debug, Serialize)]
+struct Tool {
+    /// Name of the tool.
+    name: &'static str,
+
+    /// Version of the tool.
+    version: &'static str,
+}
+
+/// Summary of the run in a [CTRF report][1].
+///
+/// [1]: https://ctrf.io/docs/specification/overview
+#[derive(Debug, Serialize)]
+struct Summary {
+    /// Total number of [`Test`]s.
+    tests: usize,
+
+    /// Number of passed [`Test`]s.
+    passed: usize,
+
+    /// Number of failed [`Test`]s.
+    failed: usize,
+
+    /// Number of pending [`Test`]s.
+    pending: usize,
+
+    /// Number of skipped [`Test`]s.
+    skipped: usize,
+
+    /// Number of [`Test`]s having other statuses.
+    other: usize,
+
+    /// Time the run has started at, in milliseconds since [`UNIX_EPOCH`].
+    start: u64,
+
+    /// Time the run has finished at, in milliseconds since [`UNIX_EPOCH`].
+    stop: u64,
+}
+
+/// Test of a [CTRF report][1], representing a [`gherkin::Scenario`].
+///
+/// [1]: https://ctrf.io/docs/specification/overview
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Test {
+    /// Name of the [`gherkin::Scenario`].
+    name: String,
+
+    /// [`Status`] of the last attempt.
+    status: Status,
+
+    /// Duration of all the attempts, in milliseconds.
+    duration: u64,
+
+    /// Time the first attempt has started at, in milliseconds since
+    /// [`UNIX_EPOCH`].
+    start: u64,
+
+    /// Time the last attempt has finished at, in milliseconds since
+    /// [`UNIX_EPOCH`].
+    stop: u64,
+
+    /// Failure messages of the last attempt, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+
+    /// Tags of the [`gherkin::Scenario`] (including the inherited ones).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+
+    /// Name of the [`gherkin::Feature`] (and [`gherkin::Rule`], if any) the
+    /// [`gherkin::Scenario`] belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suite: Option<String>,
+
+    /// Path of the [`gherkin::Feature`] file, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_path: Option<String>,
+
+    /// Line of the [`gherkin::Scenario`] in the [`gherkin::Feature`] file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+
+    /// Values of the [`Example`] row the [`gherkin::Scenario`] has been
+    /// expanded from, by their column names.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    parameters: BTreeMap<String, String>,
+
+    /// Number of retried attempts.
+    retries: usize,
+
+    /// Indicator whether the [`Test`] has passed only after being retried.
+    flaky: bool,
+
+    /// [`TestStep`]s of the last attempt.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    steps: Vec<TestStep>,
+
+    /// Indicator whether the [`gherkin::Scenario`] is work-in-progress, so
+    /// its failure is reported as [`Status::Pending`].
+    #[serde(skip)]
+    wip: bool,
+}
+
+impl Test {
+    /// Creates a new passed [`Test`] of the provided [`gherkin::Scenario`].
+    fn new(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        path_style: PathStyle,
+        at: SystemTime,
+    ) -> Self {
+        let at = millis(at);
+        Self {
+            name: scenario.name.clone(),
+            status: Status::Passed,
+            duration: 0,
+            start: at,
+            stop: at,
+            message: None,
+            tags: tag::inherited(feature, rule, scenario).cloned().collect(),
+            suite: Some(rule.map_or_else(
+                || feature.name.clone(),
+                |r| format!("{} > {}", feature.name, r.name),
+            )),
+            file_path: feature
+                .path
+                .as_deref()
+                .and_then(|p| path_style.render(p))
+                .map(Into::into),
+            line: Some(scenario.position.line),
+            parameters: Example::of(scenario)
+                .map(|ex| {
+                    ex.iter().map(|(k, v)| (k.into(), v.into())).collect()
+                })
+                .unwrap_or_default(),
+            retries: 0,
+            flaky: false,
+            steps: Vec::new(),
+            wip: false,
+        }
+    }
+
+    /// Resets this [`Test`] for its next attempt.
+    fn retry(&mut self) {
+        self.retries += 1;
+        self.status = Status::Passed;
+        self.message = None;
+        self.steps.clear();
+    }
+
+    /// Marks this [`Test`] as failed with the provided `message`.
+    fn fail(&mut self, message: String) {
+        self.status = Status::Failed;
+        self.message = Some(match self.message.take() {
+            Some(m) => format!("{m}\n\n{message}"),
+            None => message,
+        });
+    }
+
+    /// Finishes the current attempt of this [`Test`].
+    fn finish(&mut self, at: SystemTime) {
+        self.stop = millis(at);
+        self.duration = self.stop.saturating_sub(self.start);
+        if self.wip && self.status == Status::Failed {
+            self.status = Status::Pending;
+        }
+        self.flaky = self.retries > 0 && self.status == Status::Passed;
+    }
+}
+
+/// Step of a [`Test`], representing a [`gherkin::Step`].
+#[derive(Clone, Debug, Serialize)]
+struct TestStep {
+    /// Keyword and value of the [`gherkin::Step`].
+    name: String,
+
+    /// [`Status`] of the [`gherkin::Step`].
+    status: Status,
+}
+
+/// Status of a [`Test`] or a [`TestStep`].
+///
+/// Ordered by precedence, so the [`Ord::max()`] of [`TestStep`]s'
+/// [`Status`]es is the one of their [`Test`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    /// Passed.
+    Passed,
+
+    /// Skipped.
+    Skipped,
+
+    /// Failed as a work-in-progress one.
+    Pending,
+
+    /// Failed.
+    Failed,
+}
@@ -10,9 +10,16 @@
 
 //! Wrappers providing no-op implementations.
 
+use std::path::PathBuf;
+
 use derive_more::with_trait::{Deref, DerefMut};
 
-use crate::{event::Cucumber, parser, writer, Event, World, Writer};
+use crate::{
+    event::{Cucumber, Source},
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
+};
 
 /// Wrapper providing a no-op [`ArbitraryWriter`] implementation.
 ///
@@ -34,6 +41,14 @@ impl<W: World, Wr: Writer<W> + ?Sized> Writer<W> for Arbitrary<Wr> {
     ) {
         self.0.handle_event(event, cli).await;
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.0.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.0.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -78,6 +93,44 @@ where
         self.0.hook_errors()
     }
 
+    fn budget_errors(&self) -> usize {
+        self.0.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.0.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.0.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.0.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.0.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.0.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.0.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.0.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.0.scenarios_by_tag()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
@@ -119,6 +172,14 @@ impl<W: World, Wr: Writer<W> + ?Sized> Writer<W> for Stats<Wr> {
     ) {
         self.0.handle_event(event, cli).await;
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.0.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.0.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -0,0 +1,436 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Living documentation [`Writer`] implementation.
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser,
+    writer::{self, discard, Ext as _, PathStyle},
+    Event, World, Writer,
+};
+
+/// Living documentation [`Writer`] implementation exporting all the executed
+/// [`Feature`]s into a tree of Markdown files, once the run is finished.
+///
+/// Every [`Feature`] is rendered into its own page (mirroring the path of its
+/// file, like `features/animal.feature.md`), along with its description, tags,
+/// [`Rule`]s, [`Scenario`]s and [`Step`]s, and the statuses of the last run.
+/// An `index.md` page lists all the [`Feature`]s with links to their pages,
+/// along with the parsing errors, if any.
+///
+/// This is what the `--export-docs <dir>` CLI option of [`Cucumber`] uses.
+///
+/// # Panics
+///
+/// If fails to write the files.
+///
+/// [`Cucumber`]: crate::Cucumber
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct Docs {
+    /// Directory to export the documentation into.
+    dir: PathBuf,
+
+    /// Executed [`gherkin::Feature`]s, in order of their start.
+    features: Vec<Source<gherkin::Feature>>,
+
+    /// [`Status`]es of the last attempts of the executed
+    /// [`gherkin::Scenario`]s, by their positions in their
+    /// [`gherkin::Feature`]s.
+    statuses: HashMap<(Source<gherkin::Feature>, (usize, usize)), Status>,
+
+    /// Happened [`parser::Error`]s.
+    errors: Vec<String>,
+}
+
+impl<W: World> Writer<W> for Docs {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.map(Event::into_inner) {
+            Err(err) => self.errors.push(err.to_string()),
+            Ok(Cucumber::Feature(f, Feature::Started)) => self.features.push(f),
+            Ok(Cucumber::Feature(
+                f,
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev),
+            )) => self.handle_scenario_event(f, &sc, &ev.event),
+            Ok(Cucumber::Finished { .. }) => self.export(),
+            Ok(
+                Cucumber::Started
+                | Cucumber::Feature(..)
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+            ) => {}
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        vec![self.dir.join("index.md")]
+    }
+}
+
+impl writer::NonTransforming for Docs {}
+
+impl Docs {
+    /// Creates a new [`Normalized`] [`Docs`] [`Writer`] exporting the
+    /// documentation into the given `dir`ectory.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: World>(
+        dir: impl Into<PathBuf>,
+    ) -> writer::Normalize<W, Self> {
+        Self::raw(dir).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Docs`] [`Writer`] exporting the
+    /// documentation into the given `dir`ectory, and suitable for feeding into
+    /// [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(
+        dir: impl Into<PathBuf>,
+    ) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(dir)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Docs`] [`Writer`] exporting
+    /// the documentation into the given `dir`ectory.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Docs::new()`] which creates an already [`Normalized`] version of
+    /// [`Docs`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            features: vec![],
+            statuses: HashMap::new(),
+            errors: vec![],
+        }
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: Source<gherkin::Feature>,
+        scenario: &gherkin::Scenario,
+        ev: &event::Scenario<W>,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        let key = (feature, (scenario.position.line, scenario.position.col));
+        let status = match ev {
+            Scenario::Started(_) => {
+                // Retried attempts override the previous ones.
+                _ = self.statuses.insert(key, Status::Passed);
+                return;
+            }
+            Scenario::Hook(_, Hook::Failed(..))
+            | Scenario::Background(_, Step::Failed(..))
+            | Scenario::RuleBackground(_, Step::Failed(..))
+            | Scenario::Step(_, Step::Failed(..))
+            | Scenario::Wip(event::Wip::Passed)
+            | Scenario::BudgetExceeded(event::Budget {
+                failed: true, ..
+            }) => Status::Failed,
+            Scenario::Background(_, Step::Skipped(_))
+            | Scenario::RuleBackground(_, Step::Skipped(_))
+            | Scenario::Step(_, Step::Skipped(_))
+            | Scenario::NotRun => Status::Skipped,
+            Scenario::Hook(..)
+            | Scenario::Background(..)
+            | Scenario::RuleBackground(..)
+            | Scenario::Step(..)
+            | Scenario::Log(_)
+            | Scenario::Output(_)
+            | Scenario::Artifact(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_)
+            | Scenario::Finished => return,
+        };
+        if let Some(st) = self.statuses.get_mut(&key) {
+            *st = (*st).max(status);
+        }
+    }
+
+    /// Exports the documentation of all the executed [`gherkin::Feature`]s.
+    ///
+    /// # Panics
+    ///
+    /// If fails to write the files.
+    fn export(&self) {
+        let mut index = String::from("# Living documentation\n");
+
+        if !self.features.is_empty() {
+            index.push_str(
+                "\n| Feature | Status | Scenarios |\n|---|---|---|\n",
+            );
+        }
+        for (i, feature) in self.features.iter().enumerate() {
+            let page = page_path(feature, i);
+            write_file(&self.dir.join(&page), &self.render_feature(feature));
+
+            let statuses = self.scenario_statuses(feature).collect::<Vec<_>>();
+            let count =
+                |status| statuses.iter().filter(|s| **s == status).count();
+            let link = page.to_string_lossy().replace('\\', "/");
+            _ = writeln!(
+                index,
+                "| [{}]({link}) | {} | {} passed, {} skipped, {} failed |",
+                escape_cell(&format!("{}: {}", feature.keyword, feature.name)),
+                Status::label(statuses.iter().copied().max().flatten()),
+                count(Some(Status::Passed)),
+                count(Some(Status::Skipped)),
+                count(Some(Status::Failed)),
+            );
+        }
+
+        if !self.errors.is_empty() {
+            index.push_str("\n## Parsing errors\n\n");
+            for err in &self.errors {
+                _ = writeln!(index, "```\n{err}\n```");
+            }
+        }
+
+        write_file(&self.dir.join("index.md"), &index);
+    }
+
+    /// Returns the [`Status`]es of all the [`gherkin::Scenario`]s of the
+    /// provided [`gherkin::Feature`] ([`None`] for not run ones).
+    fn scenario_statuses<'s>(
+        &'s self,
+        feature: &'s Source<gherkin::Feature>,
+    ) -> impl Iterator<Item = Option<Status>> + 's {
+        feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+            .map(|sc| self.status(feature, sc))
+    }
+
+    /// Returns the [`Status`] of the provided [`gherkin::Scenario`], if it
+    /// has been run.
+    fn status(
+        &self,
+        feature: &Source<gherkin::Feature>,
+        scenario: &gherkin::Scenario,
+    ) -> Option<Status> {
+        let pos = (scenario.position.line, scenario.position.col);
+        self.statuses.get(&(feature.clone(), pos)).copied()
+    }
+
+    /// Renders the page of the provided [`gherkin::Feature`].
+    fn render_feature(&self, feature: &Source<gherkin::Feature>) -> String {
+        let mut out = format!("# {}: {}\n\n", feature.keyword, feature.name);
+        let status = self.scenario_statuses(feature).max().flatten();
+        _ = writeln!(out, "**Status:** {}\n", Status::label(status));
+        render_tags(&mut out, &feature.tags);
+        if let Some(path) = feature
+            .path
+            .as_deref()
+            .and_then(|p| PathStyle::RelativeToManifest.render(p))
+        {
+            _ = writeln!(out, "**Source:** `{path}`\n");
+        }
+        render_description(&mut out, feature.description.as_deref());
+        if let Some(bg) = &feature.background {
+            _ = writeln!(out, "## {}\n", bg.keyword);
+            render_steps(&mut out, &bg.steps);
+        }
+        for sc in &feature.scenarios {
+            self.render_scenario(&mut out, "##", feature, sc);
+        }
+
+        for rule in &feature.rules {
+            _ = writeln!(out, "## {}: {}\n", rule.keyword, rule.name);
+            render_tags(&mut out, &rule.tags);
+            render_description(&mut out, rule.description.as_deref());
+            if let Some(bg) = &rule.background {
+                _ = writeln!(out, "### {}\n", bg.keyword);
+                render_steps(&mut out, &bg.steps);
+            }
+            for sc in &rule.scenarios {
+                self.render_scenario(&mut out, "###", feature, sc);
+            }
+        }
+        out
+    }
+
+    /// Renders the provided [`gherkin::Scenario`] under the given `heading`
+    /// level.
+    fn render_scenario(
+        &self,
+        out: &mut String,
+        heading: &str,
+        feature: &Source<gherkin::Feature>,
+        scenario: &gherkin::Scenario,
+    ) {
+        _ = writeln!(
+            out,
+            "{heading} {}: {}\n",
+            scenario.keyword, scenario.name
+        );
+        let status = self.status(feature, scenario);
+        _ = writeln!(out, "**Status:** {}\n", Status::label(status));
+        render_tags(out, &scenario.tags);
+        render_description(out, scenario.description.as_deref());
+        render_steps(out, &scenario.steps);
+    }
+}
+
+/// Status of an executed [`gherkin::Scenario`].
+///
+/// Ordered by precedence, so the [`Ord::max()`] of [`Status`]es is the one of
+/// the whole [`gherkin::Feature`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Status {
+    /// Passed.
+    Passed,
+
+    /// Skipped.
+    Skipped,
+
+    /// Failed.
+    Failed,
+}
+
+impl Status {
+    /// Returns a human-readable label of the provided [`Status`] ([`None`]
+    /// meaning not run).
+    const fn label(status: Option<Self>) -> &'static str {
+        match status {
+            Some(Self::Passed) => "✔ passed",
+            Some(Self::Skipped) => "? skipped",
+            Some(Self::Failed) => "✘ failed",
+            None => "not run",
+        }
+    }
+}
+
+/// Returns the path of the page of the provided [`gherkin::Feature`], relative
+/// to the documentation directory, mirroring the path of its file, if any.
+fn page_path(feature: &gherkin::Feature, index: usize) -> PathBuf {
+    let path = feature
+        .path
+        .as_deref()
+        .and_then(|p| PathStyle::RelativeToManifest.render(p))
+        .map(|p| {
+            // Absolute paths (outside the project) are placed inside the
+            // documentation directory too.
+            Path::new(&*p)
+                .components()
+                .filter_map(|c| match c {
+                    Component::Normal(c) => Some(c),
+                    Component::Prefix(_)
+                    | Component::RootDir
+                    | Component::CurDir
+                    | Component::ParentDir => None,
+                })
+                .collect::<PathBuf>()
+        })
+        .filter(|p| p.file_name().is_some())
+        .unwrap_or_else(|| format!("feature-{index}.feature").into());
+    let mut file = path.into_os_string();
+    file.push(".md");
+    file.into()
+}
+
+/// Writes the provided `content` into the file at the given `path`, creating
+/// its parent directories, if required.
+///
+/// # Panics
+///
+/// If fails to write the file.
+fn write_file(path: &Path, content: &str) {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, content))
+        .unwrap_or_else(|e| {
+            panic!("Failed to export docs into `{}`: {e}", path.display())
+        });
+}
+
+/// Renders the provided tags, if any.
+fn render_tags(out: &mut String, tags: &[String]) {
+    if !tags.is_empty() {
+        let tags = tags.iter().map(|t| format!("`@{t}`")).collect::<Vec<_>>();
+        _ = writeln!(out, "**Tags:** {}\n", tags.join(" "));
+    }
+}
+
+/// Renders the provided description, if any.
+fn render_description(out: &mut String, description: Option<&str>) {
+    if let Some(desc) = description.map(str::trim).filter(|d| !d.is_empty()) {
+        _ = writeln!(out, "{desc}\n");
+    }
+}
+
+/// Renders the provided [`gherkin::Step`]s as a list, along with their doc
+/// strings and tables.
+fn render_steps(out: &mut String, steps: &[gherkin::Step]) {
+    if steps.is_empty() {
+        return;
+    }
+    for step in steps {
+        _ = writeln!(out, "- **{}** {}", step.keyword.trim(), step.value);
+        if let Some(doc) = &step.docstring {
+            _ = writeln!(out, "\n  ```");
+            for line in doc.lines() {
+                _ = writeln!(out, "  {line}");
+            }
+            _ = writeln!(out, "  ```\n");
+        }
+        if let Some(table) = &step.table {
+            out.push('\n');
+            for (i, row) in table.rows.iter().enumerate() {
+                let cells =
+                    row.iter().map(|c| escape_cell(c)).collect::<Vec<_>>();
+                _ = writeln!(out, "  | {} |", cells.join(" | "));
+                if i == 0 {
+                    _ = writeln!(out, "  |{}", "---|".repeat(row.len()));
+                }
+            }
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+}
+
+/// Escapes the provided `text` to be placed into a Markdown table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`]-wrapper for failing the execution on any [`parser::Error`].
+
+use std::{cmp, fmt::Write as _, path::PathBuf};
+
+use derive_more::with_trait::Deref;
+
+use crate::{
+    event::{self, Source},
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
+};
+
+/// [`Writer`]-wrapper for failing the execution on any [`parser::Error`],
+/// outputting an aggregated list of them at the end.
+///
+/// Even if the wrapped [`Writer`] doesn't count [`parser::Error`]s in its
+/// [`writer::Stats`] (or the counted number isn't checked), a mistyped
+/// `.feature` file won't be silently dropped from the execution.
+///
+/// The underlying [`Writer`] has to be an [`Arbitrary`] one, accepting
+/// [`String`]s.
+///
+/// [`Arbitrary`]: writer::Arbitrary
+#[derive(Clone, Debug, Deref)]
+pub struct FailOnParserErrors<W> {
+    /// Original [`Writer`] to pass events into.
+    #[deref]
+    writer: W,
+
+    /// Encountered [`parser::Error`]s, in order of their appearance.
+    errors: Vec<parser::Error>,
+}
+
+impl<W, Wr> Writer<W> for FailOnParserErrors<Wr>
+where
+    W: World,
+    Wr: writer::Arbitrary<W, String>,
+{
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        let is_finished = match &event {
+            Err(e) => {
+                self.errors.push(e.clone());
+                false
+            }
+            Ok(ev) => matches!(ev.value, event::Cucumber::Finished { .. }),
+        };
+
+        self.writer.handle_event(event, cli).await;
+
+        if is_finished && !self.errors.is_empty() {
+            let list = self.errors.iter().fold(String::new(), |mut out, e| {
+                _ = write!(out, "\n  - {e}");
+                out
+            });
+            self.writer
+                .write(format!(
+                    "{} parsing error{}:{list}",
+                    self.errors.len(),
+                    if self.errors.len() > 1 { "s" } else { "" },
+                ))
+                .await;
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.writer.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.writer.finalize().await;
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr, Val> writer::Arbitrary<W, Val> for FailOnParserErrors<Wr>
+where
+    W: World,
+    Self: Writer<W>,
+    Wr: writer::Arbitrary<W, Val>,
+{
+    async fn write(&mut self, val: Val) {
+        self.writer.write(val).await;
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr> writer::Stats<W> for FailOnParserErrors<Wr>
+where
+    Wr: writer::Stats<W>,
+    Self: Writer<W>,
+{
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn failed_steps(&self) -> usize {
+        self.writer.failed_steps()
+    }
+
+    fn retried_steps(&self) -> usize {
+        self.writer.retried_steps()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        // Either the original `Writer` doesn't count them, or both numbers are
+        // the same.
+        cmp::max(self.writer.parsing_errors(), self.errors.len())
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writer.hook_errors()
+    }
+
+    fn budget_errors(&self) -> usize {
+        self.writer.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.writer.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.writer.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.writer.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.writer.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.writer.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.writer.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.writer.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.writer.scenarios_by_tag()
+    }
+
+    fn execution_has_failed(&self) -> bool {
+        self.writer.execution_has_failed() || !self.errors.is_empty()
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<Wr: writer::Normalized> writer::Normalized for FailOnParserErrors<Wr> {}
+
+#[warn(clippy::missing_trait_methods)]
+impl<Wr: writer::NonTransforming> writer::NonTransforming
+    for FailOnParserErrors<Wr>
+{
+}
+
+impl<Writer> From<Writer> for FailOnParserErrors<Writer> {
+    fn from(writer: Writer) -> Self {
+        Self {
+            writer,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<Writer> FailOnParserErrors<Writer> {
+    /// Wraps the given [`Writer`] in a new [`FailOnParserErrors`] one.
+    #[must_use]
+    pub fn new(writer: Writer) -> Self {
+        Self::from(writer)
+    }
+
+    /// Returns the original [`Writer`], wrapped by this [`FailOnParserErrors`]
+    /// one.
+    #[must_use]
+    pub const fn inner_writer(&self) -> &Writer {
+        &self.writer
+    }
+
+    /// Returns all the [`parser::Error`]s encountered so far, in order of
+    /// their appearance.
+    #[must_use]
+    pub fn errors(&self) -> &[parser::Error] {
+        &self.errors
+    }
+}
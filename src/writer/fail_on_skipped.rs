@@ -14,11 +14,15 @@
 //! [`Skipped`]: event::Step::Skipped
 //! [`Step`]: gherkin::Step
 
+use std::path::PathBuf;
+
 use derive_more::with_trait::Deref;
 
 use crate::{
     event::{self, Source},
-    parser, writer, Event, World, Writer,
+    parser, tag,
+    writer::{self, summarize},
+    Event, World, Writer,
 };
 
 /// [`Writer`]-wrapper for transforming [`Skipped`] [`Step`]s into [`Failed`].
@@ -60,6 +64,7 @@ where
 {
     type Cli = Wr::Cli;
 
+    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
     async fn handle_event(
         &mut self,
         event: parser::Result<Event<event::Cucumber<W>>>,
@@ -70,22 +75,31 @@ where
             StepError::NotFound,
         };
 
-        let map_failed = |f: &Source<_>, r: &Option<_>, sc: &Source<_>| {
-            if (self.should_fail)(f, r.as_deref(), sc) {
-                Step::Failed(None, None, None, NotFound)
+        let map_failed = |f: &Source<_>, r: &Option<_>, sc: &Source<_>, dur| {
+            // Deliberately ignored `Scenario`s are never failed.
+            if !tag::is_ignored(f, r.as_deref(), sc)
+                && (self.should_fail)(f, r.as_deref(), sc)
+            {
+                Step::Failed(None, None, None, NotFound, dur)
             } else {
-                Step::Skipped
+                Step::Skipped(dur)
             }
         };
         let map_failed_bg =
-            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, ret| {
-                let ev = map_failed(&f, &r, &sc);
+            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, dur, ret| {
+                let ev = map_failed(&f, &r, &sc, dur);
                 let ev = Scenario::Background(st, ev).with_retries(ret);
                 Cucumber::scenario(f, r, sc, ev)
             };
+        let map_failed_rule_bg =
+            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, dur, ret| {
+                let ev = map_failed(&f, &r, &sc, dur);
+                let ev = Scenario::RuleBackground(st, ev).with_retries(ret);
+                Cucumber::scenario(f, r, sc, ev)
+            };
         let map_failed_step =
-            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, ret| {
-                let ev = map_failed(&f, &r, &sc);
+            |f: Source<_>, r: Option<_>, sc: Source<_>, st: _, dur, ret| {
+                let ev = map_failed(&f, &r, &sc, dur);
                 let ev = Scenario::Step(st, ev).with_retries(ret);
                 Cucumber::scenario(f, r, sc, ev)
             };
@@ -99,22 +113,26 @@ where
                         Rule::Scenario(
                             sc,
                             RetryableScenario {
-                                event: Scenario::Background(st, Step::Skipped),
+                                event:
+                                    Scenario::Background(
+                                        st,
+                                        Step::Skipped(dur),
+                                    ),
                                 retries,
                             },
                         ),
                     ),
-                ) => map_failed_bg(f, Some(r), sc, st, retries),
+                ) => map_failed_bg(f, Some(r), sc, st, dur, retries),
                 Cucumber::Feature(
                     f,
                     Feature::Scenario(
                         sc,
                         RetryableScenario {
-                            event: Scenario::Background(st, Step::Skipped),
+                            event: Scenario::Background(st, Step::Skipped(dur)),
                             retries,
                         },
                     ),
-                ) => map_failed_bg(f, None, sc, st, retries),
+                ) => map_failed_bg(f, None, sc, st, dur, retries),
                 Cucumber::Feature(
                     f,
                     Feature::Rule(
@@ -122,32 +140,58 @@ where
                         Rule::Scenario(
                             sc,
                             RetryableScenario {
-                                event: Scenario::Step(st, Step::Skipped),
+                                event:
+                                    Scenario::RuleBackground(
+                                        st,
+                                        Step::Skipped(dur),
+                                    ),
                                 retries,
                             },
                         ),
                     ),
-                ) => map_failed_step(f, Some(r), sc, st, retries),
+                ) => map_failed_rule_bg(f, Some(r), sc, st, dur, retries),
+                Cucumber::Feature(
+                    f,
+                    Feature::Rule(
+                        r,
+                        Rule::Scenario(
+                            sc,
+                            RetryableScenario {
+                                event: Scenario::Step(st, Step::Skipped(dur)),
+                                retries,
+                            },
+                        ),
+                    ),
+                ) => map_failed_step(f, Some(r), sc, st, dur, retries),
                 Cucumber::Feature(
                     f,
                     Feature::Scenario(
                         sc,
                         RetryableScenario {
-                            event: Scenario::Step(st, Step::Skipped),
+                            event: Scenario::Step(st, Step::Skipped(dur)),
                             retries,
                         },
                         ..,
                     ),
-                ) => map_failed_step(f, None, sc, st, retries),
+                ) => map_failed_step(f, None, sc, st, dur, retries),
                 Cucumber::Started
                 | Cucumber::Feature(..)
                 | Cucumber::ParsingFinished { .. }
-                | Cucumber::Finished => ev,
+                | Cucumber::DuplicateName(_)
+                | Cucumber::Finished { .. } => ev,
             })
         });
 
         self.writer.handle_event(event, cli).await;
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.writer.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.writer.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -192,6 +236,44 @@ where
         self.writer.hook_errors()
     }
 
+    fn budget_errors(&self) -> usize {
+        self.writer.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.writer.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.writer.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.writer.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.writer.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.writer.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.writer.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.writer.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.writer.scenarios_by_tag()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
@@ -205,11 +287,7 @@ impl<Writer> From<Writer> for FailOnSkipped<Writer> {
         Self {
             writer,
             should_fail: |feat, rule, sc| {
-                !sc.tags
-                    .iter()
-                    .chain(rule.iter().flat_map(|r| &r.tags))
-                    .chain(&feat.tags)
-                    .any(|t| t == "allow.skipped")
+                !tag::inherited(feat, rule, sc).any(|t| t == "allow.skipped")
             },
         }
     }
@@ -247,6 +325,39 @@ impl<Writer> FailOnSkipped<Writer> {
         }
     }
 
+    /// Wraps the given [`Writer`] in a new [`FailOnSkipped`] one, considering
+    /// [`Skipped`] [`Step`]s as [`Failed`] unless their [`Scenario`] matches
+    /// the provided `allowed` tags expression.
+    ///
+    /// Tags are inherited, so the expression is evaluated against the tags of
+    /// the [`Feature`], [`Rule`] and [`Scenario`] (including the ones of its
+    /// [`Examples`]) altogether.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Failed`]: event::Step::Failed
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn allowing(
+        writer: Writer,
+        allowed: tag::Expr,
+    ) -> FailOnSkipped<
+        Writer,
+        impl Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + Clone,
+    > {
+        Self::with(writer, move |feat, rule, sc| {
+            !allowed.matches(feat, rule, sc)
+        })
+    }
+
     /// Returns the original [`Writer`], wrapped by this [`FailOnSkipped`] one.
     #[must_use]
     pub fn inner_writer(&self) -> &Writer {
@@ -12,7 +12,14 @@
 //!
 //! [1]: https://github.com/cucumber/cucumber-json-schema
 
-use std::{fmt::Debug, io, mem, sync::LazyLock, time::SystemTime};
+use std::{
+    borrow::Cow,
+    fmt::Debug,
+    fs, io, mem,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::SystemTime,
+};
 
 use base64::Engine as _;
 use derive_more::with_trait::Display;
@@ -23,12 +30,12 @@ use serde_with::{serde_as, DisplayFromStr};
 
 use crate::{
     cli, event,
-    feature::ExpandExamplesError,
-    parser,
+    feature::{Example, ExpandExamplesError},
+    parser, step, tag,
     writer::{
         self,
         basic::{coerce_error, trim_path},
-        discard, Ext as _,
+        discard, Ext as _, PathStyle, TimestampFormat, Timezone,
     },
     Event, World, Writer,
 };
@@ -56,7 +63,7 @@ pub struct Json<Out: io::Write> {
     /// [1]: https://github.com/cucumber/cucumber-json-schema
     features: Vec<Feature>,
 
-    /// [`SystemTime`] when the current [`Hook`]/[`Step`] has started.
+    /// [`SystemTime`] when the current [`Hook`] has started.
     ///
     /// [`Hook`]: event::Hook
     started: Option<SystemTime>,
@@ -64,7 +71,35 @@ pub struct Json<Out: io::Write> {
     /// [`event::Scenario::Log`]s of the current [`Hook`]/[`Step`].
     ///
     /// [`Hook`]: event::Hook
-    logs: Vec<String>,
+    logs: Vec<event::Log>,
+
+    /// [`PathStyle`] to render [`Feature::uri`]s with.
+    path_style: PathStyle,
+
+    /// [`SystemTime`] when the current [`Scenario`] has started.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenario_started_at: Option<SystemTime>,
+
+    /// [`TimestampFormat`] to render [`Element::start_timestamp`]s in, if
+    /// they should be outputted at all.
+    timestamp_format: Option<TimestampFormat>,
+
+    /// [`Timezone`] to render [`Element::start_timestamp`]s in.
+    timezone: Timezone,
+
+    /// Indicator whether the [JSON][1] should be deterministic.
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    deterministic: bool,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+
+    /// Indicator whether the [JSON][1] has been written already.
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    written: bool,
 }
 
 impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
@@ -79,11 +114,12 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
 
         match event.map(Event::split) {
             Err(parser::Error::Parsing(e)) => {
-                let feature = Feature::parsing_err(&e);
+                let feature = Feature::parsing_err(&e, self.path_style);
                 self.features.push(feature);
             }
             Err(parser::Error::ExampleExpansion(e)) => {
-                let feature = Feature::example_expansion_err(&e);
+                let feature =
+                    Feature::example_expansion_err(&e, self.path_style);
                 self.features.push(feature);
             }
             Ok((
@@ -101,24 +137,51 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
             )) => {
                 self.handle_scenario_event(&f, Some(&r), &sc, ev.event, meta);
             }
-            Ok((Cucumber::Finished, _)) => {
-                self.output
-                    .write_all(
-                        serde_json::to_string(&self.features)
-                            .unwrap_or_else(|e| {
-                                panic!("Failed to serialize JSON: {e}")
-                            })
-                            .as_bytes(),
-                    )
-                    .unwrap_or_else(|e| panic!("Failed to write JSON: {e}"));
-            }
+            Ok((Cucumber::Finished { .. }, _)) => self.write_json(),
             _ => {}
         }
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+
+    async fn finalize(&mut self) {
+        if !self.written {
+            self.write_json();
+        }
+        self.output
+            .flush()
+            .unwrap_or_else(|e| panic!("Failed to flush JSON: {e}"));
+    }
 }
 
 impl<O: io::Write> writer::NonTransforming for Json<O> {}
 
+impl Json<fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`Json`] [`Writer`] outputting
+    /// [JSON][1] into a newly created file at the given `path`, reported via
+    /// [`Writer::report_paths()`].
+    ///
+    /// To use it as a [`Normalized`] [`Writer`], call
+    /// [`WriterExt::normalized()`] on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?)
+        })
+    }
+}
+
 impl<Out: io::Write> Json<Out> {
     /// Creates a new [`Normalized`] [`Json`] [`Writer`] outputting [JSON][1]
     /// into the given `output`.
@@ -162,7 +225,117 @@ impl<Out: io::Write> Json<Out> {
             features: vec![],
             started: None,
             logs: vec![],
+            path_style: PathStyle::Trimmed,
+            scenario_started_at: None,
+            timestamp_format: None,
+            timezone: Timezone::Utc,
+            deterministic: false,
+            report_path: None,
+            written: false,
+        }
+    }
+
+    /// Sets the [`PathStyle`] to render [`Feature::uri`]s with.
+    ///
+    /// Default is [`PathStyle::Trimmed`].
+    ///
+    /// To use it with a [`Normalized`] [`Json`] [`Writer`], construct it via
+    /// [`Json::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Makes this [`Json`] [`Writer`] output [`Element::start_timestamp`]s
+    /// (as [`cucumber-jvm`][1] does) in the given [`TimestampFormat`].
+    ///
+    /// By default, [`Element::start_timestamp`]s aren't outputted.
+    ///
+    /// To use it with a [`Normalized`] [`Json`] [`Writer`], construct it via
+    /// [`Json::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://github.com/cucumber/cucumber-jvm
+    #[must_use]
+    pub const fn timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format = Some(format);
+        self
+    }
+
+    /// Sets the [`Timezone`] to render [`Element::start_timestamp`]s in.
+    ///
+    /// Default is [`Timezone::Utc`].
+    ///
+    /// To use it with a [`Normalized`] [`Json`] [`Writer`], construct it via
+    /// [`Json::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub const fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Makes this [`Json`] [`Writer`] output a deterministic [JSON][1],
+    /// suitable for snapshot (golden file) testing.
+    ///
+    /// In this mode all the durations are set to zero and all the
+    /// [`Element::start_timestamp`]s to the [`UNIX_EPOCH`], while [`Feature`]s
+    /// are sorted by their [`uri`]s and names, and [`Element`]s by their lines.
+    ///
+    /// To use it with a [`Normalized`] [`Json`] [`Writer`], construct it via
+    /// [`Json::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`uri`]: Feature::uri
+    /// [`UNIX_EPOCH`]: SystemTime::UNIX_EPOCH
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[must_use]
+    pub const fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Writes the collected [`Feature`]s as [JSON][1] into the output.
+    ///
+    /// # Panics
+    ///
+    /// If fails to serialize or write the [JSON][1].
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    fn write_json(&mut self) {
+        self.written = true;
+
+        if self.deterministic {
+            self.features
+                .sort_by(|a, b| (&a.uri, &a.name).cmp(&(&b.uri, &b.name)));
+            for el in self.features.iter_mut().flat_map(|f| {
+                f.elements.sort_by_key(|el| el.line);
+                &mut f.elements
+            }) {
+                el.before
+                    .iter_mut()
+                    .chain(&mut el.after)
+                    .map(|h| &mut h.result)
+                    .chain(el.steps.iter_mut().map(|s| &mut s.result))
+                    .for_each(|res| res.duration = 0);
+            }
         }
+
+        self.output
+            .write_all(
+                serde_json::to_string(&self.features)
+                    .unwrap_or_else(|e| panic!("Failed to serialize JSON: {e}"))
+                    .as_bytes(),
+            )
+            .unwrap_or_else(|e| panic!("Failed to write JSON: {e}"));
     }
 
     /// Handles the given [`event::Scenario`].
@@ -177,31 +350,105 @@ impl<Out: io::Write> Json<Out> {
         use event::Scenario;
 
         match ev {
-            Scenario::Started => {}
+            Scenario::Started(_) => {
+                self.scenario_started_at = Some(if self.deterministic {
+                    SystemTime::UNIX_EPOCH
+                } else {
+                    meta.at
+                });
+            }
             Scenario::Hook(ty, ev) => {
                 self.handle_hook_event(feature, rule, scenario, ty, ev, meta);
             }
             Scenario::Background(st, ev) => {
-                self.handle_step_event(
+                let ty = ("background", None);
+                self.handle_step_event(feature, rule, scenario, ty, &st, ev);
+            }
+            Scenario::RuleBackground(st, ev) => {
+                let bg = rule.and_then(|r| r.background.as_ref());
+                let ty = ("background", bg);
+                self.handle_step_event(feature, rule, scenario, ty, &st, ev);
+            }
+            Scenario::Step(st, ev) => {
+                let ty = ("scenario", None);
+                self.handle_step_event(feature, rule, scenario, ty, &st, ev);
+            }
+            Scenario::Log(log) => {
+                self.logs.push(log);
+            }
+            Scenario::Stalled(stall) => {
+                self.logs.push(event::Log::from(stall.to_string()));
+            }
+            Scenario::Output(out) => {
+                self.logs.extend(
+                    [out.stdout, out.stderr]
+                        .into_iter()
+                        .filter(|o| !o.is_empty())
+                        .map(event::Log::from),
+                );
+            }
+            Scenario::Artifact(path) => {
+                let el = self.mut_or_insert_element(
+                    feature, rule, scenario, "scenario", None,
+                );
+                let embeddings = el
+                    .after
+                    .last_mut()
+                    .map(|h| &mut h.embeddings)
+                    .or_else(|| el.steps.last_mut().map(|s| &mut s.embeddings))
+                    .or_else(|| {
+                        el.before.last_mut().map(|h| &mut h.embeddings)
+                    });
+                if let Some(embeddings) = embeddings {
+                    embeddings.push(Embedding::from_artifact(&path));
+                }
+            }
+            // Cucumber JSON has no place for `Scenario`-level results, so an
+            // exceeded budget is reported as an `After` hook.
+            Scenario::BudgetExceeded(budget) => {
+                self.push_scenario_result(
                     feature,
                     rule,
                     scenario,
-                    "background",
-                    &st,
-                    ev,
-                    meta,
+                    budget.failed,
+                    budget.to_string(),
                 );
             }
-            Scenario::Step(st, ev) => {
-                self.handle_step_event(
-                    feature, rule, scenario, "scenario", &st, ev, meta,
-                );
+            // A passed work-in-progress `Scenario` is reported the same way.
+            Scenario::Wip(wip) => {
+                if wip == event::Wip::Passed {
+                    self.push_scenario_result(
+                        feature,
+                        rule,
+                        scenario,
+                        true,
+                        wip.to_string(),
+                    );
+                }
             }
-            Scenario::Log(msg) => {
-                self.logs.push(msg);
+            // Not run `Scenario` is reported with all its steps skipped, so
+            // it's distinguishable from the failed and skipped ones.
+            Scenario::NotRun => {
+                let el = self.mut_or_insert_element(
+                    feature, rule, scenario, "scenario", None,
+                );
+                el.steps.extend(scenario.steps.iter().map(|st| Step {
+                    keyword: st.keyword.clone(),
+                    line: st.position.line,
+                    name: st.value.clone(),
+                    hidden: false,
+                    doc_string: DocString::from_step(st),
+                    result: RunResult {
+                        status: Status::Skipped,
+                        duration: 0,
+                        error_message: Some("Scenario has not been run".into()),
+                    },
+                    embeddings: vec![],
+                }));
             }
             Scenario::Finished => {
                 self.logs.clear();
+                self.scenario_started_at = None;
             }
         }
     }
@@ -250,6 +497,17 @@ impl<Out: io::Write> Json<Out> {
                     .map(Embedding::from_log)
                     .collect(),
             },
+            Hook::Ignored(info) => HookResult {
+                result: RunResult {
+                    status: Status::Passed,
+                    duration: duration(),
+                    error_message: Some(coerce_error(&info).into_owned()),
+                },
+                embeddings: mem::take(&mut self.logs)
+                    .into_iter()
+                    .map(Embedding::from_log)
+                    .collect(),
+            },
             Hook::Failed(_, info) => HookResult {
                 result: RunResult {
                     status: Status::Failed,
@@ -263,63 +521,51 @@ impl<Out: io::Write> Json<Out> {
             },
         };
 
-        let el =
-            self.mut_or_insert_element(feature, rule, scenario, "scenario");
+        let el = self
+            .mut_or_insert_element(feature, rule, scenario, "scenario", None);
         match hook_ty {
             HookType::Before => el.before.push(res),
             HookType::After => el.after.push(res),
         }
     }
 
-    /// Handles the given [`event::Step`].
-    // TODO: Needs refactoring.
-    #[expect(clippy::too_many_arguments, reason = "needs refactoring")]
+    /// Handles the given [`event::Step`] of an element of the provided `ty`,
+    /// belonging to the provided [`Rule`]'s [`Background`], if any.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Rule`]: gherkin::Rule
     fn handle_step_event<W>(
         &mut self,
         feature: &gherkin::Feature,
         rule: Option<&gherkin::Rule>,
         scenario: &gherkin::Scenario,
-        ty: &'static str,
+        (ty, rule_bg): (&'static str, Option<&gherkin::Background>),
         step: &gherkin::Step,
         event: event::Step<W>,
-        meta: event::Metadata,
     ) {
-        let mut duration = || {
-            let started = self.started.take().unwrap_or_else(|| {
-                panic!("no `Started` event for `Step` '{}'", step.value)
-            });
-            meta.at
-                .duration_since(started)
-                .unwrap_or_else(|e| {
-                    panic!(
-                        "failed to compute duration between {:?} and \
-                         {started:?}: {e}",
-                        meta.at,
-                    );
-                })
-                .as_nanos()
-        };
-
         let result = match event {
             event::Step::Started => {
-                self.started = Some(meta.at);
-                _ = self.mut_or_insert_element(feature, rule, scenario, ty);
+                _ = self.mut_or_insert_element(
+                    feature, rule, scenario, ty, rule_bg,
+                );
                 return;
             }
-            event::Step::Passed(..) => RunResult {
+            event::Step::Passed(_, _, dur) => RunResult {
                 status: Status::Passed,
-                duration: duration(),
+                duration: dur.as_nanos(),
                 error_message: None,
             },
-            event::Step::Failed(_, loc, _, err) => {
+            event::Step::Failed(_, loc, _, err, dur) => {
                 let status = match &err {
-                    event::StepError::NotFound => Status::Undefined,
+                    event::StepError::NotFound
+                    | event::StepError::KeywordMismatch(_) => Status::Undefined,
                     event::StepError::AmbiguousMatch(..) => Status::Ambiguous,
-                    event::StepError::Panic(..) => Status::Failed,
+                    event::StepError::Panic(..)
+                    | event::StepError::Categorized(..) => Status::Failed,
                 };
                 RunResult {
                     status,
-                    duration: duration(),
+                    duration: dur.as_nanos(),
                     error_message: Some(format!(
                         "{}{err}",
                         loc.map(|l| format!(
@@ -330,9 +576,9 @@ impl<Out: io::Write> Json<Out> {
                     )),
                 }
             }
-            event::Step::Skipped => RunResult {
+            event::Step::Skipped(dur) => RunResult {
                 status: Status::Skipped,
-                duration: duration(),
+                duration: dur.as_nanos(),
                 error_message: None,
             },
         };
@@ -342,31 +588,77 @@ impl<Out: io::Write> Json<Out> {
             line: step.position.line,
             name: step.value.clone(),
             hidden: false,
+            doc_string: DocString::from_step(step),
             result,
             embeddings: mem::take(&mut self.logs)
                 .into_iter()
                 .map(Embedding::from_log)
                 .collect(),
         };
-        let el = self.mut_or_insert_element(feature, rule, scenario, ty);
+        let el =
+            self.mut_or_insert_element(feature, rule, scenario, ty, rule_bg);
         el.steps.push(step);
     }
 
+    /// Reports a [`Scenario`]-level result as an `After` hook of the provided
+    /// [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn push_scenario_result(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        failed: bool,
+        message: String,
+    ) {
+        let el = self
+            .mut_or_insert_element(feature, rule, scenario, "scenario", None);
+        el.after.push(HookResult {
+            result: RunResult {
+                status: if failed {
+                    Status::Failed
+                } else {
+                    Status::Passed
+                },
+                duration: 0,
+                error_message: Some(message),
+            },
+            embeddings: vec![],
+        });
+    }
+
     /// Inserts the given `scenario`, if not present, and then returns a mutable
     /// reference to the contained value.
+    ///
+    /// [`Rule`]'s [`Background`] [`Step`]s are kept in a separate [`Element`]
+    /// from the [`Feature`]'s [`Background`] ones, if the `rule_bg` is given.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Step`]: gherkin::Step
     fn mut_or_insert_element(
         &mut self,
         feature: &gherkin::Feature,
         rule: Option<&gherkin::Rule>,
         scenario: &gherkin::Scenario,
         ty: &'static str,
+        rule_bg: Option<&gherkin::Background>,
     ) -> &mut Element {
+        let line =
+            rule_bg.map_or(scenario.position.line, |bg| bg.position.line);
+        let start_timestamp = self
+            .timestamp_format
+            .zip(self.scenario_started_at)
+            .map(|(format, at)| Timestamp::new(format, at, self.timezone));
+
         let f_pos = self
             .features
             .iter()
-            .position(|f| f == feature)
+            .position(|f| f.is_of(feature, self.path_style))
             .unwrap_or_else(|| {
-                self.features.push(Feature::new(feature));
+                self.features.push(Feature::new(feature, self.path_style));
                 self.features.len() - 1
             });
         let f = self
@@ -385,11 +677,14 @@ impl<Out: io::Write> Json<Out> {
                             .unwrap_or_default(),
                         scenario.name,
                     )
-                    && el.line == scenario.position.line
+                    && el.line == line
                     && el.r#type == ty
             })
             .unwrap_or_else(|| {
-                f.elements.push(Element::new(feature, rule, scenario, ty));
+                f.elements.push(Element {
+                    start_timestamp,
+                    ..Element::new(feature, rule, scenario, ty, rule_bg)
+                });
                 f.elements.len() - 1
             });
         f.elements.get_mut(el_pos).unwrap_or_else(|| unreachable!())
@@ -444,7 +739,12 @@ pub struct Embedding {
 
 impl Embedding {
     /// Creates [`Embedding`] from the provided [`event::Scenario::Log`].
-    fn from_log(msg: impl AsRef<str>) -> Self {
+    ///
+    /// [Structured][1] [`event::Log`]s are embedded as JSON objects of their
+    /// fields.
+    ///
+    /// [1]: event::Log::is_structured()
+    fn from_log(log: event::Log) -> Self {
         /// [`Mime`] of the [`event::Scenario::Log`] [`Embedding`].
         static LOG_MIME: LazyLock<Mime> = LazyLock::new(|| {
             "text/x.cucumber.log+plain"
@@ -452,12 +752,51 @@ impl Embedding {
                 .unwrap_or_else(|_| unreachable!("valid MIME"))
         });
 
+        if !log.is_structured() {
+            return Self {
+                data: Base64::encode(log.message),
+                mime_type: LOG_MIME.clone(),
+                name: None,
+            };
+        }
+
+        let fields = log
+            .fields
+            .into_iter()
+            .map(|(name, val)| {
+                let val = match val {
+                    event::LogValue::Bool(b) => b.into(),
+                    event::LogValue::I64(i) => i.into(),
+                    event::LogValue::U64(u) => u.into(),
+                    event::LogValue::F64(f) => f.into(),
+                    event::LogValue::Str(s) => s.into(),
+                };
+                (name, val)
+            })
+            .collect::<serde_json::Map<_, _>>();
         Self {
-            data: Base64::encode(msg.as_ref()),
-            mime_type: LOG_MIME.clone(),
+            data: Base64::encode(serde_json::Value::Object(fields).to_string()),
+            mime_type: mime::APPLICATION_JSON,
             name: None,
         }
     }
+
+    /// Creates [`Embedding`] referencing the provided
+    /// [`event::Scenario::Artifact`] by its path.
+    fn from_artifact(path: &Path) -> Self {
+        /// [`Mime`] of the [`event::Scenario::Artifact`] [`Embedding`].
+        static ARTIFACT_MIME: LazyLock<Mime> = LazyLock::new(|| {
+            "text/uri-list"
+                .parse()
+                .unwrap_or_else(|_| unreachable!("valid MIME"))
+        });
+
+        Self {
+            data: Base64::encode(path.display().to_string()),
+            mime_type: ARTIFACT_MIME.clone(),
+            name: path.file_name().map(|n| n.to_string_lossy().into_owned()),
+        }
+    }
 }
 
 /// [`Serialize`]able tag of a [`gherkin::Feature`] or a [`gherkin::Scenario`].
@@ -473,6 +812,17 @@ pub struct Tag {
     pub line: usize,
 }
 
+/// [`Serialize`]able value of an [`Example`] row a [`gherkin::Scenario`] has
+/// been expanded from.
+#[derive(Clone, Debug, Serialize)]
+pub struct Parameter {
+    /// Name of the [`Example`] column.
+    pub name: String,
+
+    /// Value in the [`Example`] row.
+    pub value: String,
+}
+
 /// Possible statuses of running [`gherkin::Step`].
 #[derive(Clone, Copy, Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -534,6 +884,10 @@ pub struct Step {
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub hidden: bool,
 
+    /// [`DocString`] of this [`Step`], if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_string: Option<DocString>,
+
     /// [`RunResult`] of this [`Step`].
     pub result: RunResult,
 
@@ -550,6 +904,40 @@ pub struct Step {
     pub embeddings: Vec<Embedding>,
 }
 
+/// [`Serialize`]able [doc string][0] of a [`gherkin::Step`].
+///
+/// [0]: https://cucumber.io/docs/gherkin/reference#doc-strings
+#[derive(Clone, Debug, Serialize)]
+pub struct DocString {
+    /// [`step::DocString::content`].
+    pub value: String,
+
+    /// [`step::DocString::content_type`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Line number of this [`DocString`] in a `.feature` file.
+    ///
+    /// As [`gherkin`] parser omits this info, line number is taken as the
+    /// next one after the [`gherkin::Step`].
+    pub line: usize,
+}
+
+impl DocString {
+    /// Creates a [`DocString`] out of the provided [`gherkin::Step`], if it
+    /// has any.
+    fn from_step(step: &gherkin::Step) -> Option<Self> {
+        step.docstring.as_deref().map(|doc| {
+            let doc = step::DocString::parse(doc);
+            Self {
+                value: doc.content,
+                content_type: doc.content_type,
+                line: step.position.line + 1,
+            }
+        })
+    }
+}
+
 /// [`Serialize`]able result of running a [`Before`] or [`After`] hook.
 ///
 /// [`Before`]: event::HookType::Before
@@ -607,6 +995,9 @@ pub struct Element {
     pub id: String,
 
     /// [`gherkin::Scenario`] line number inside a `.feature` file.
+    ///
+    /// For a [`gherkin::Rule`]'s [`gherkin::Background`] it's the line number
+    /// of the [`gherkin::Background`] itself.
     pub line: usize,
 
     /// [`gherkin::Scenario::name`], optionally prepended with a
@@ -623,23 +1014,62 @@ pub struct Element {
 
     /// [`gherkin::Scenario`]'s [`Step`]s.
     pub steps: Vec<Step>,
+
+    /// [`Timestamp`] when the [`gherkin::Scenario`] has started.
+    ///
+    /// Only set if enabled via [`Json::timestamp_format()`].
+    ///
+    /// Doesn't appear in the [JSON schema][1], but present in the
+    /// [`cucumber-jvm`][2] output.
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    /// [2]: https://github.com/cucumber/cucumber-jvm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<Timestamp>,
+
+    /// Test management key of the [`gherkin::Scenario`], set via a
+    /// `@testkey(PROJ-123)` tag.
+    ///
+    /// Doesn't appear in the [JSON schema][1].
+    ///
+    /// See [`tag::test_key()`] for more details.
+    ///
+    /// [`tag::test_key()`]: crate::tag::test_key
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_key: Option<String>,
+
+    /// [`Parameter`]s of the [`Example`] row the [`gherkin::Scenario`] has
+    /// been expanded from, distinguishing the [`gherkin::Scenario`]s expanded
+    /// from the same [`Scenario Outline`][2].
+    ///
+    /// Doesn't appear in the [JSON schema][1].
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    /// [2]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
 }
 
 impl Element {
     /// Creates a new [`Element`] out of the given values.
+    ///
+    /// Represents the [`gherkin::Rule`]'s [`gherkin::Background`], if the
+    /// `rule_bg` is given.
     fn new(
         feature: &gherkin::Feature,
         rule: Option<&gherkin::Rule>,
         scenario: &gherkin::Scenario,
         ty: &'static str,
+        rule_bg: Option<&gherkin::Background>,
     ) -> Self {
         Self {
             after: vec![],
             before: vec![],
             keyword: (ty == "background")
-                .then(|| feature.background.as_ref().map(|bg| &bg.keyword))
+                .then(|| rule_bg.or(feature.background.as_ref()))
                 .flatten()
-                .unwrap_or(&scenario.keyword)
+                .map_or(&scenario.keyword, |bg| &bg.keyword)
                 .clone(),
             r#type: ty,
             id: format!(
@@ -649,7 +1079,7 @@ impl Element {
                     .unwrap_or_default(),
                 scenario.name.to_kebab_case(),
             ),
-            line: scenario.position.line,
+            line: rule_bg.map_or(scenario.position.line, |bg| bg.position.line),
             name: format!(
                 "{}{}",
                 rule.map(|r| format!("{} ", r.name)).unwrap_or_default(),
@@ -664,6 +1094,49 @@ impl Element {
                 })
                 .collect(),
             steps: vec![],
+            start_timestamp: None,
+            test_key: tag::test_key(feature, rule, scenario).map(Into::into),
+            parameters: (ty != "background")
+                .then(|| Example::of(scenario))
+                .flatten()
+                .map(|ex| {
+                    ex.iter()
+                        .map(|(name, value)| Parameter {
+                            name: name.into(),
+                            value: value.into(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// [`Serialize`]able [`SystemTime`] rendered in some [`TimestampFormat`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Timestamp {
+    /// [`TimestampFormat::Rfc3339`] date-time.
+    Rfc3339(String),
+
+    /// [`TimestampFormat::EpochMillis`] number.
+    EpochMillis(u128),
+}
+
+impl Timestamp {
+    /// Renders the given `time` in the provided [`TimestampFormat`] and
+    /// [`Timezone`].
+    fn new(format: TimestampFormat, time: SystemTime, tz: Timezone) -> Self {
+        match format {
+            TimestampFormat::Rfc3339 => Self::Rfc3339(
+                chrono::DateTime::<chrono::Utc>::from(time)
+                    .with_timezone(&tz.offset_at(time))
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            ),
+            TimestampFormat::EpochMillis => Self::EpochMillis(
+                time.duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |d| d.as_millis()),
+            ),
         }
     }
 }
@@ -688,14 +1161,15 @@ pub struct Feature {
 }
 
 impl Feature {
-    /// Creates a new [`Feature`] out of the given [`gherkin::Feature`].
-    fn new(feature: &gherkin::Feature) -> Self {
+    /// Creates a new [`Feature`] out of the given [`gherkin::Feature`], with
+    /// its [`Feature::uri`] rendered in the provided [`PathStyle`].
+    fn new(feature: &gherkin::Feature, path_style: PathStyle) -> Self {
         Self {
             uri: feature
                 .path
                 .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .map(str::to_owned),
+                .and_then(|p| path_style.render(p))
+                .map(Cow::into_owned),
             keyword: feature.keyword.clone(),
             name: feature.name.clone(),
             tags: feature
@@ -711,13 +1185,18 @@ impl Feature {
     }
 
     /// Creates a new [`Feature`] from the given [`ExpandExamplesError`].
-    fn example_expansion_err(err: &ExpandExamplesError) -> Self {
+    fn example_expansion_err(
+        err: &ExpandExamplesError,
+        path_style: PathStyle,
+    ) -> Self {
+        let uri = err
+            .path
+            .as_ref()
+            .and_then(|p| path_style.render(p))
+            .map(Cow::into_owned);
+
         Self {
-            uri: err
-                .path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .map(str::to_owned),
+            uri: uri.clone(),
             keyword: String::new(),
             name: String::new(),
             tags: vec![],
@@ -728,10 +1207,7 @@ impl Feature {
                 r#type: "scenario",
                 id: format!(
                     "failed-to-expand-examples{}",
-                    err.path
-                        .as_ref()
-                        .and_then(|p| p.to_str().map(trim_path))
-                        .unwrap_or_default(),
+                    uri.as_deref().unwrap_or_default(),
                 ),
                 line: 0,
                 name: String::new(),
@@ -741,6 +1217,7 @@ impl Feature {
                     line: err.pos.line,
                     name: "scenario".into(),
                     hidden: false,
+                    doc_string: None,
                     result: RunResult {
                         status: Status::Failed,
                         duration: 0,
@@ -748,19 +1225,16 @@ impl Feature {
                     },
                     embeddings: vec![],
                 }],
+                start_timestamp: None,
+                test_key: None,
+                parameters: vec![],
             }],
         }
     }
 
-    /// Creates a new [`Feature`] from the given [`gherkin::ParseFileError`].
-    fn parsing_err(err: &gherkin::ParseFileError) -> Self {
-        let path = match err {
-            gherkin::ParseFileError::Reading { path, .. }
-            | gherkin::ParseFileError::Parsing { path, .. } => path,
-        }
-        .to_str()
-        .map(trim_path)
-        .map(str::to_owned);
+    /// Creates a new [`Feature`] from the given [`parser::ParsingError`].
+    fn parsing_err(err: &parser::ParsingError, path_style: PathStyle) -> Self {
+        let path = path_style.render(err.path()).map(Cow::into_owned);
 
         Self {
             uri: path.clone(),
@@ -784,6 +1258,7 @@ impl Feature {
                     line: 0,
                     name: "scenario".into(),
                     hidden: false,
+                    doc_string: None,
                     result: RunResult {
                         status: Status::Failed,
                         duration: 0,
@@ -791,9 +1266,29 @@ impl Feature {
                     },
                     embeddings: vec![],
                 }],
+                start_timestamp: None,
+                test_key: None,
+                parameters: vec![],
             }],
         }
     }
+
+    /// Checks whether this [`Feature`] is created out of the given
+    /// [`gherkin::Feature`], with its [`Feature::uri`] rendered in the provided
+    /// [`PathStyle`].
+    fn is_of(&self, feature: &gherkin::Feature, path_style: PathStyle) -> bool {
+        self.uri
+            .as_ref()
+            .and_then(|uri| {
+                feature
+                    .path
+                    .as_ref()
+                    .and_then(|p| path_style.render(p))
+                    .map(|path| uri == &path)
+            })
+            .unwrap_or_default()
+            && self.name == feature.name
+    }
 }
 
 impl PartialEq<gherkin::Feature> for Feature {
@@ -12,20 +12,26 @@
 //!
 //! [1]: https://llg.cubic.org/docs/junit
 
-use std::{fmt::Debug, io, mem, time::SystemTime};
+use std::{
+    fmt::Debug,
+    fs, io, mem,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
 
 use junit_report::{
-    Duration, Report, TestCase, TestCaseBuilder, TestSuite, TestSuiteBuilder,
+    Duration, OffsetDateTime, Report, TestCase, TestCaseBuilder, TestSuite,
+    TestSuiteBuilder,
 };
 
 use crate::{
-    event, parser,
+    event, parser, tag,
     writer::{
         self,
-        basic::{coerce_error, trim_path, Coloring},
+        basic::{coerce_error, Coloring},
         discard,
         out::WritableString,
-        Ext as _, Verbosity,
+        Ext as _, Lexicon, PathStyle, Timezone, Verbosity,
     },
     Event, World, Writer,
 };
@@ -43,7 +49,13 @@ pub struct Cli {
     ///
     /// `0` is default verbosity, `1` additionally outputs world on failed
     /// steps.
-    #[arg(id = "junit-v", long = "junit-v", value_name = "0|1", global = true)]
+    #[arg(
+        id = "junit-v",
+        long = "junit-v",
+        value_name = "0|1",
+        env = "CUCUMBER_JUNIT_V",
+        global = true
+    )]
     pub verbose: Option<u8>,
 }
 
@@ -63,16 +75,25 @@ pub struct JUnit<W, Out: io::Write> {
     /// [`io::Write`] implementor to output XML report into.
     output: Out,
 
-    /// [JUnit XML report][1].
+    /// Finished [JUnit `testsuite`s][1] of the report, along with the
+    /// [test keys][2] of their `testcase`s, in their order.
     ///
     /// [1]: https://llg.cubic.org/docs/junit
-    report: Report,
+    /// [2]: crate::tag::test_key
+    suites: Vec<(TestSuite, Vec<Option<String>>)>,
 
     /// Current [JUnit `testsuite`][1].
     ///
     /// [1]: https://llg.cubic.org/docs/junit
     suit: Option<TestSuite>,
 
+    /// [`Scenario`] positions and [test keys][1] of the `testcase`s of the
+    /// current [`JUnit::suit`], in their order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [1]: crate::tag::test_key
+    suit_test_keys: Vec<((usize, usize), Option<String>)>,
+
     /// [`SystemTime`] when the current [`Scenario`] has started.
     ///
     /// [`Scenario`]: gherkin::Scenario
@@ -86,6 +107,34 @@ pub struct JUnit<W, Out: io::Write> {
 
     /// [`Verbosity`] of this [`Writer`].
     verbosity: Verbosity,
+
+    /// [`PathStyle`] to render [`Feature::path`]s with.
+    ///
+    /// [`Feature::path`]: gherkin::Feature::path
+    path_style: PathStyle,
+
+    /// [`Lexicon`] to label hooks and [`Background`]s with in `system-out`s.
+    ///
+    /// [`Background`]: gherkin::Background
+    lexicon: Lexicon,
+
+    /// [`Timezone`] to render [JUnit `testsuite`][1] timestamps in.
+    ///
+    /// [1]: https://llg.cubic.org/docs/junit
+    timezone: Timezone,
+
+    /// Indicator whether the [JUnit XML report][1] should be deterministic.
+    ///
+    /// [1]: https://llg.cubic.org/docs/junit
+    deterministic: bool,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+
+    /// Indicator whether the [JUnit XML report][1] has been written already.
+    ///
+    /// [1]: https://llg.cubic.org/docs/junit
+    written: bool,
 }
 
 // Implemented manually to omit redundant `World: Clone` trait bound, imposed by
@@ -94,11 +143,18 @@ impl<World, Out: Clone + io::Write> Clone for JUnit<World, Out> {
     fn clone(&self) -> Self {
         Self {
             output: self.output.clone(),
-            report: self.report.clone(),
+            suites: self.suites.clone(),
             suit: self.suit.clone(),
+            suit_test_keys: self.suit_test_keys.clone(),
             scenario_started_at: self.scenario_started_at,
             events: self.events.clone(),
             verbosity: self.verbosity,
+            path_style: self.path_style,
+            lexicon: self.lexicon.clone(),
+            timezone: self.timezone,
+            deterministic: self.deterministic,
+            report_path: self.report_path.clone(),
+            written: self.written,
         }
     }
 }
@@ -121,7 +177,12 @@ where
 
         match event.map(Event::split) {
             Err(err) => self.handle_error(&err),
-            Ok((Cucumber::Started | Cucumber::ParsingFinished { .. }, _)) => {}
+            Ok((
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+                _,
+            )) => {}
             Ok((Cucumber::Feature(feat, ev), meta)) => match ev {
                 Feature::Started => {
                     self.suit = Some(
@@ -130,15 +191,16 @@ where
                             &feat.name,
                             feat.path
                                 .as_deref()
-                                .and_then(|p| p.to_str().map(trim_path))
+                                .and_then(|p| self.path_style.render(p))
                                 .map(|path| format!(": {path}"))
                                 .unwrap_or_default(),
                         ))
-                        .set_timestamp(meta.at.into())
+                        .set_timestamp(self.timestamp(meta.at))
                         .build(),
                     );
                 }
-                Feature::Rule(_, Rule::Started | Rule::Finished) => {}
+                Feature::Aborted
+                | Feature::Rule(_, Rule::Started | Rule::Finished) => {}
                 Feature::Rule(r, Rule::Scenario(sc, ev)) => {
                     self.handle_scenario_event(&feat, Some(&r), &sc, ev, meta);
                 }
@@ -152,21 +214,61 @@ where
                             feat.name,
                         )
                     });
-                    self.report.add_testsuite(suite);
+                    self.finish_suite(suite);
                 }
             },
-            Ok((Cucumber::Finished, _)) => {
-                self.report
-                    .write_xml(&mut self.output)
-                    .unwrap_or_else(|e| panic!("failed to write XML: {e}"));
+            Ok((Cucumber::Finished { .. }, _)) => self.write_report(),
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+
+    async fn finalize(&mut self) {
+        if !self.written {
+            // The run has ended abruptly, so the `testsuite` of the
+            // unfinished `Feature` is reported as it is.
+            if let Some(suite) = self.suit.take() {
+                self.finish_suite(suite);
             }
+            self.write_report();
         }
+        self.output
+            .flush()
+            .unwrap_or_else(|e| panic!("failed to flush XML: {e}"));
     }
 }
 
 impl<W, O: io::Write> writer::NonTransforming for JUnit<W, O> {}
 
-impl<W: Debug, Out: io::Write> JUnit<W, Out> {
+impl<W: World + Debug> JUnit<W, fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`JUnit`] [`Writer`] outputting
+    /// XML report into a newly created file at the given `path`, reported via
+    /// [`Writer::report_paths()`].
+    ///
+    /// To use it as a [`Normalized`] [`Writer`], call
+    /// [`WriterExt::normalized()`] on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    pub fn create(
+        path: impl AsRef<Path>,
+        verbosity: impl Into<Verbosity>,
+    ) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?, verbosity)
+        })
+    }
+}
+
+impl<W: World + Debug, Out: io::Write> JUnit<W, Out> {
     /// Creates a new [`Normalized`] [`JUnit`] [`Writer`] outputting XML report
     /// into the given `output`.
     ///
@@ -210,14 +312,93 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
     pub fn raw(output: Out, verbosity: impl Into<Verbosity>) -> Self {
         Self {
             output,
-            report: Report::new(),
+            suites: vec![],
             suit: None,
+            suit_test_keys: vec![],
             scenario_started_at: None,
             events: vec![],
             verbosity: verbosity.into(),
+            path_style: PathStyle::Trimmed,
+            lexicon: Lexicon::new(),
+            timezone: Timezone::Utc,
+            deterministic: false,
+            report_path: None,
+            written: false,
         }
     }
 
+    /// Sets the [`PathStyle`] to render [`Feature::path`]s with in
+    /// [JUnit `testsuite`][1] and `testcase` names.
+    ///
+    /// Default is [`PathStyle::Trimmed`].
+    ///
+    /// To use it with a [`Normalized`] [`JUnit`] [`Writer`], construct it via
+    /// [`JUnit::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Feature::path`]: gherkin::Feature::path
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://llg.cubic.org/docs/junit
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Sets the [`Lexicon`] to label hooks and [`Background`]s with in
+    /// `testcase`s' `system-out`.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    ///
+    /// To use it with a [`Normalized`] [`JUnit`] [`Writer`], construct it via
+    /// [`JUnit::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
+    /// Sets the [`Timezone`] to render [JUnit `testsuite`][1] timestamps in.
+    ///
+    /// Default is [`Timezone::Utc`].
+    ///
+    /// To use it with a [`Normalized`] [`JUnit`] [`Writer`], construct it via
+    /// [`JUnit::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://llg.cubic.org/docs/junit
+    #[must_use]
+    pub const fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Makes this [`JUnit`] [`Writer`] output a deterministic
+    /// [JUnit XML report][1], suitable for snapshot (golden file) testing.
+    ///
+    /// In this mode all the `testsuite` timestamps are set to the
+    /// [`UNIX_EPOCH`] and `testcase` times to zero, while `testsuite`s are
+    /// sorted by their names and `testcase`s by their [`Scenario`] positions.
+    ///
+    /// To use it with a [`Normalized`] [`JUnit`] [`Writer`], construct it via
+    /// [`JUnit::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`UNIX_EPOCH`]: SystemTime::UNIX_EPOCH
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    /// [1]: https://llg.cubic.org/docs/junit
+    #[must_use]
+    pub const fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
     /// Applies the given [`Cli`] options to this [`JUnit`] [`Writer`].
     pub fn apply_cli(&mut self, cli: Cli) {
         match cli.verbose {
@@ -229,38 +410,19 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
 
     /// Handles the given [`parser::Error`].
     fn handle_error(&mut self, err: &parser::Error) {
-        let (name, ty) = match err {
-            parser::Error::Parsing(err) => {
-                let path = match err.as_ref() {
-                    gherkin::ParseFileError::Reading { path, .. }
-                    | gherkin::ParseFileError::Parsing { path, .. } => path,
-                };
-                (
-                    format!(
-                        "Feature{}",
-                        path.to_str()
-                            .map(|p| format!(": {}", trim_path(p)))
-                            .unwrap_or_default(),
-                    ),
-                    "Parser Error",
-                )
-            }
-            parser::Error::ExampleExpansion(err) => (
-                format!(
-                    "Feature: {}{}:{}",
-                    err.path
-                        .as_deref()
-                        .and_then(|p| p.to_str().map(trim_path))
-                        .map(|p| format!("{p}:"))
-                        .unwrap_or_default(),
-                    err.pos.line,
-                    err.pos.col,
-                ),
-                "Example Expansion Error",
-            ),
+        let ty = match err {
+            parser::Error::Parsing(_) => "Parser Error",
+            parser::Error::ExampleExpansion(_) => "Example Expansion Error",
         };
+        let name = format!(
+            "Feature{}",
+            event::ParsingFailure::from(err)
+                .location(self.path_style)
+                .map(|l| format!(": {l}"))
+                .unwrap_or_default(),
+        );
 
-        self.report.add_testsuite(
+        self.suites.push((
             TestSuiteBuilder::new("Errors")
                 .add_testcase(TestCase::failure(
                     &name,
@@ -269,7 +431,8 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                     &err.to_string(),
                 ))
                 .build(),
-        );
+            vec![None],
+        ));
     }
 
     /// Handles the given [`event::Scenario`].
@@ -284,13 +447,20 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         use event::Scenario;
 
         match &ev.event {
-            Scenario::Started => {
+            Scenario::Started(_) => {
                 self.scenario_started_at = Some(meta.at);
                 self.events.push(ev);
             }
             Scenario::Log(_)
+            | Scenario::Artifact(_)
+            | Scenario::Output(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_)
+            | Scenario::NotRun
             | Scenario::Hook(..)
             | Scenario::Background(..)
+            | Scenario::RuleBackground(..)
             | Scenario::Step(..) => {
                 self.events.push(ev);
             }
@@ -298,6 +468,10 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 let dur = self.scenario_duration(meta.at, sc);
                 let events = mem::take(&mut self.events);
                 let case = self.test_case(feat, rule, sc, &events, dur);
+                self.suit_test_keys.push((
+                    (sc.position.line, sc.position.col),
+                    tag::test_key(feat, rule, sc).map(Into::into),
+                ));
 
                 self.suit
                     .as_mut()
@@ -313,7 +487,62 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         }
     }
 
+    /// Adds the provided finished [JUnit `testsuite`][1] to the report,
+    /// sorting its `testcase`s in a [`deterministic`] mode.
+    ///
+    /// [`deterministic`]: JUnit::deterministic()
+    /// [1]: https://llg.cubic.org/docs/junit
+    fn finish_suite(&mut self, mut suite: TestSuite) {
+        let mut cases = mem::take(&mut suite.testcases)
+            .into_iter()
+            .zip(mem::take(&mut self.suit_test_keys))
+            .collect::<Vec<_>>();
+        if self.deterministic {
+            cases.sort_by_key(|(_, (pos, _))| *pos);
+        }
+        let (cases, keys): (Vec<_>, Vec<_>) = cases
+            .into_iter()
+            .map(|(case, (_, key))| (case, key))
+            .unzip();
+        suite.testcases = cases;
+        self.suites.push((suite, keys));
+    }
+
+    /// Writes the [JUnit XML report][1] into the output.
+    ///
+    /// # Panics
+    ///
+    /// If fails to write into the output.
+    ///
+    /// [1]: https://llg.cubic.org/docs/junit
+    fn write_report(&mut self) {
+        self.written = true;
+
+        let mut suites = mem::take(&mut self.suites);
+        if self.deterministic {
+            suites.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+        }
+        let mut report = Report::new();
+        let mut test_keys = vec![];
+        for (suite, mut keys) in suites {
+            report.add_testsuite(suite);
+            test_keys.append(&mut keys);
+        }
+
+        let mut xml = Vec::new();
+        report
+            .write_xml(&mut xml)
+            .map_err(io::Error::other)
+            .and_then(|()| {
+                let xml = String::from_utf8(xml).map_err(io::Error::other)?;
+                self.output
+                    .write_all(with_test_keys(&xml, &test_keys).as_bytes())
+            })
+            .unwrap_or_else(|e| panic!("failed to write XML: {e}"));
+    }
+
     /// Forms a [`TestCase`] on [`event::Scenario::Finished`].
+    #[expect(clippy::too_many_lines, reason = "needs refactoring")]
     fn test_case(
         &self,
         feat: &gherkin::Feature,
@@ -331,9 +560,14 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 !matches!(
                     ev.event,
                     Scenario::Log(_)
+                        | Scenario::Artifact(_)
+                        | Scenario::Output(_)
+                        | Scenario::Stalled(_)
+                        | Scenario::BudgetExceeded(_)
+                        | Scenario::Wip(_)
                         | Scenario::Hook(
                             HookType::After,
-                            Hook::Passed | Hook::Started,
+                            Hook::Passed | Hook::Started | Hook::Ignored(_),
                         ),
                 )
             })
@@ -351,25 +585,61 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
             sc.name,
             feat.path
                 .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
+                .and_then(|p| self.path_style.render(p))
                 .map(|path| format!("{path}:"))
                 .unwrap_or_default(),
             sc.position.line,
             sc.position.col,
         );
 
+        let failed_budget = events.iter().find_map(|ev| match &ev.event {
+            Scenario::BudgetExceeded(b) => b.failed.then_some(b),
+            Scenario::Started(_)
+            | Scenario::Hook(..)
+            | Scenario::Background(..)
+            | Scenario::RuleBackground(..)
+            | Scenario::Step(..)
+            | Scenario::Log(_)
+            | Scenario::Artifact(_)
+            | Scenario::Output(_)
+            | Scenario::Stalled(_)
+            | Scenario::Wip(_)
+            | Scenario::NotRun
+            | Scenario::Finished => None,
+        });
+
         let mut case = match &last_event.event {
-            Scenario::Started
+            Scenario::Started(_)
             | Scenario::Log(_)
-            | Scenario::Hook(_, Hook::Started | Hook::Passed)
-            | Scenario::Background(_, Step::Started | Step::Passed(_, _))
-            | Scenario::Step(_, Step::Started | Step::Passed(_, _)) => {
-                TestCaseBuilder::success(&case_name, duration).build()
-            }
-            Scenario::Background(_, Step::Skipped)
-            | Scenario::Step(_, Step::Skipped) => {
-                TestCaseBuilder::skipped(&case_name).build()
+            | Scenario::Artifact(_)
+            | Scenario::Output(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_)
+            | Scenario::Hook(
+                _,
+                Hook::Started | Hook::Passed | Hook::Ignored(_),
+            )
+            | Scenario::Background(_, Step::Started | Step::Passed(..))
+            | Scenario::RuleBackground(_, Step::Started | Step::Passed(..))
+            | Scenario::Step(_, Step::Started | Step::Passed(..)) => {
+                failed_budget.map_or_else(
+                    || TestCaseBuilder::success(&case_name, duration).build(),
+                    |b| {
+                        TestCaseBuilder::failure(
+                            &case_name,
+                            duration,
+                            "Budget Exceeded",
+                            &b.to_string(),
+                        )
+                        .build()
+                    },
+                )
             }
+            Scenario::Background(_, Step::Skipped(_))
+            | Scenario::RuleBackground(_, Step::Skipped(_))
+            | Scenario::Step(_, Step::Skipped(_))
+            | Scenario::NotRun => TestCaseBuilder::skipped(&case_name).build(),
             Scenario::Hook(_, Hook::Failed(_, e)) => TestCaseBuilder::failure(
                 &case_name,
                 duration,
@@ -377,8 +647,25 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 coerce_error(e).as_ref(),
             )
             .build(),
-            Scenario::Background(_, Step::Failed(_, _, _, e))
-            | Scenario::Step(_, Step::Failed(_, _, _, e)) => {
+            Scenario::Background(_, Step::Failed(_, _, _, e, _)) => {
+                TestCaseBuilder::failure(
+                    &case_name,
+                    duration,
+                    "Background Step Panicked",
+                    &e.to_string(),
+                )
+                .build()
+            }
+            Scenario::RuleBackground(_, Step::Failed(_, _, _, e, _)) => {
+                TestCaseBuilder::failure(
+                    &case_name,
+                    duration,
+                    "Rule Background Step Panicked",
+                    &e.to_string(),
+                )
+                .build()
+            }
+            Scenario::Step(_, Step::Failed(_, _, _, e, _)) => {
                 TestCaseBuilder::failure(
                     &case_name,
                     duration,
@@ -402,7 +689,8 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
             WritableString(String::new()),
             Coloring::Never,
             self.verbosity,
-        );
+        )
+        .lexicon(self.lexicon.clone());
         let output = events
             .iter()
             .map(|ev| {
@@ -414,13 +702,37 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 panic!("Failed to write with `writer::Basic`: {e}")
             });
 
-        case.set_system_out(&output);
+        case.set_system_out(&format!("{output}{}", attachments(events)));
 
         case
     }
 
+    /// Returns the [JUnit `testsuite`][1] timestamp of the given `time`,
+    /// rendered in the configured [`Timezone`].
+    ///
+    /// Always returns the [`UNIX_EPOCH`] in a [`deterministic`] mode.
+    ///
+    /// [`deterministic`]: JUnit::deterministic()
+    /// [`UNIX_EPOCH`]: SystemTime::UNIX_EPOCH
+    /// [1]: https://llg.cubic.org/docs/junit
+    fn timestamp(&self, time: SystemTime) -> OffsetDateTime {
+        let time = if self.deterministic {
+            SystemTime::UNIX_EPOCH
+        } else {
+            time
+        };
+        let offset = self.timezone.offset_at(time).local_minus_utc();
+        OffsetDateTime::from(time).to_offset(
+            time::UtcOffset::from_whole_seconds(offset)
+                .unwrap_or(time::UtcOffset::UTC),
+        )
+    }
+
     /// Returns [`Scenario`]'s [`Duration`] on [`event::Scenario::Finished`].
     ///
+    /// Always returns [`Duration::ZERO`] in a [`deterministic`] mode.
+    ///
+    /// [`deterministic`]: JUnit::deterministic()
     /// [`Scenario`]: gherkin::Scenario
     fn scenario_duration(
         &mut self,
@@ -433,6 +745,9 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
                 sc.name,
             )
         });
+        if self.deterministic {
+            return Duration::ZERO;
+        }
         Duration::try_from(ended.duration_since(started_at).unwrap_or_else(
             |e| {
                 panic!(
@@ -448,3 +763,60 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         })
     }
 }
+
+/// Inserts a `test_key` property of every provided [test key][1] into the
+/// `testcase` of the given XML report it belongs to (in their order), the way
+/// understood by test management tools (like Jira Xray).
+///
+/// [1]: tag::test_key
+fn with_test_keys(xml: &str, test_keys: &[Option<String>]) -> String {
+    const OPENING: &str = "<testcase ";
+
+    if test_keys.iter().all(Option::is_none) {
+        return xml.to_owned();
+    }
+
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+    let mut keys = test_keys.iter();
+    while let Some((before, (attrs, tail))) = rest
+        .split_once(OPENING)
+        .and_then(|(before, after)| Some((before, after.split_once('>')?)))
+    {
+        out.push_str(before);
+        out.push_str(OPENING);
+        out.push_str(attrs);
+        out.push('>');
+        if let Some(key) = keys.next().and_then(Option::as_deref) {
+            if !attrs.ends_with('/') {
+                out.push_str(
+                    "<properties><property name=\"test_key\" value=\"",
+                );
+                out.push_str(
+                    &key.replace('&', "&amp;")
+                        .replace('<', "&lt;")
+                        .replace('"', "&quot;"),
+                );
+                out.push_str("\"/></properties>");
+            }
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Formats [`event::Scenario::Artifact`]s of the provided `events` in a way
+/// understood by CI tools (like Jenkins JUnit Attachments plugin).
+fn attachments<W>(events: &[event::RetryableScenario<W>]) -> String {
+    events
+        .iter()
+        .filter_map(|ev| {
+            if let event::Scenario::Artifact(path) = &ev.event {
+                Some(format!("\n[[ATTACHMENT|{}]]", path.display()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -15,6 +15,7 @@
 use std::{
     fmt::Debug,
     io, iter, mem,
+    path::{self, Path},
     str::FromStr,
     time::{Duration, SystemTime},
 };
@@ -30,9 +31,9 @@ use crate::{
     parser,
     writer::{
         self,
-        basic::{coerce_error, trim_path},
+        basic::{coerce_error, format_world},
         out::WriteStrExt as _,
-        Arbitrary, Normalize, Summarize,
+        Arbitrary, Lexicon, Normalize, PathStyle, Summarize,
     },
     Event, World, Writer, WriterExt as _,
 };
@@ -42,18 +43,36 @@ use crate::{
 #[group(skip)]
 pub struct Cli {
     /// Formatting of the output.
-    #[arg(long, value_name = "json")]
+    #[arg(long, value_name = "json", env = "CUCUMBER_FORMAT")]
     pub format: Option<Format>,
 
     /// Show captured stdout of successful tests. Currently, outputs only step
     /// function location.
-    #[arg(long)]
+    #[arg(
+        long,
+        env = "CUCUMBER_SHOW_OUTPUT",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
     pub show_output: bool,
 
     /// Show execution time of each test.
-    #[arg(long, value_name = "plain|colored", default_missing_value = "plain")]
+    #[arg(
+        long,
+        value_name = "plain|colored",
+        default_missing_value = "plain",
+        env = "CUCUMBER_REPORT_TIME"
+    )]
     pub report_time: Option<ReportTime>,
 
+    /// Add IntelliJ/TeamCity `locationHint`s (`file:///path/to.feature:line`)
+    /// to test cases, so IDEs may navigate to their Gherkin lines.
+    #[arg(
+        long,
+        env = "CUCUMBER_LOCATION_HINTS",
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    pub location_hints: bool,
+
     /// Enable nightly-only flags.
     #[arg(short = 'Z')]
     pub nightly: Option<String>,
@@ -179,6 +198,30 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`Hook`]: event::Hook
     hook_errors: usize,
 
+    /// Number of [`Scenario`]s failed due to exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    budget_errors: usize,
+
+    /// Number of work-in-progress [`Scenario`]s failed due to passing.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    wip_errors: usize,
+
+    /// Indicator whether the execution has exceeded its global timeout.
+    timed_out: bool,
+
+    /// Indicator whether the currently running [`Scenario`] is
+    /// work-in-progress.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    wip: bool,
+
+    /// Number of [`Scenario`]s filtered out from running.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    filtered_out: usize,
+
     /// Number of [`Feature`]s with [`path`] set to [`None`].
     ///
     /// This value is used to generate a unique name for each [`Feature`] to
@@ -199,6 +242,14 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`Hook::Started`]: event::Hook::Started
     /// [`Step::Started`]: event::Step::Started
     step_started_at: Option<SystemTime>,
+
+    /// [`PathStyle`] to render [`Feature::path`]s with.
+    ///
+    /// [`Feature::path`]: gherkin::Feature::path
+    path_style: PathStyle,
+
+    /// [`Lexicon`] to label synthesized test cases with.
+    lexicon: Lexicon,
 }
 
 // Implemented manually to omit redundant `World: Clone` trait bound, imposed by
@@ -215,9 +266,16 @@ impl<World, Out: Clone + io::Write> Clone for Libtest<World, Out> {
             ignored: self.ignored,
             parsing_errors: self.parsing_errors,
             hook_errors: self.hook_errors,
+            budget_errors: self.budget_errors,
+            wip_errors: self.wip_errors,
+            timed_out: self.timed_out,
+            wip: self.wip,
+            filtered_out: self.filtered_out,
             features_without_path: self.features_without_path,
             started_at: self.started_at,
             step_started_at: self.step_started_at,
+            path_style: self.path_style,
+            lexicon: self.lexicon.clone(),
         }
     }
 }
@@ -324,13 +382,54 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             retried: 0,
             parsing_errors: 0,
             hook_errors: 0,
+            budget_errors: 0,
+            wip_errors: 0,
+            timed_out: false,
+            wip: false,
+            filtered_out: 0,
             ignored: 0,
             features_without_path: 0,
             started_at: None,
             step_started_at: None,
+            path_style: PathStyle::Trimmed,
+            lexicon: Lexicon::new(),
         }
     }
 
+    /// Sets the [`PathStyle`] to render [`Feature::path`]s with in test names
+    /// and outputs.
+    ///
+    /// Default is [`PathStyle::Trimmed`].
+    ///
+    /// To use it with a [`Normalized`] [`Libtest`] [`Writer`], construct it via
+    /// [`Libtest::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Feature::path`]: gherkin::Feature::path
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub const fn path_style(mut self, style: PathStyle) -> Self {
+        self.path_style = style;
+        self
+    }
+
+    /// Sets the [`Lexicon`] to label hooks and [`Background`]s with in test
+    /// names.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    ///
+    /// To use it with a [`Normalized`] [`Libtest`] [`Writer`], construct it via
+    /// [`Libtest::raw()`] and then call [`WriterExt::normalized()`] on it.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
     /// Handles the provided [`event::Cucumber`].
     ///
     /// Until [`ParsingFinished`] is received, all the events are stored inside
@@ -394,37 +493,44 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 self.started_at = Some(meta.at);
                 Vec::new()
             }
+            Ok((Cucumber::DuplicateName(_), _)) => Vec::new(),
             Ok((
                 Cucumber::ParsingFinished {
                     steps,
                     parser_errors,
+                    filtered_scenarios,
                     ..
                 },
                 _,
             )) => {
+                self.filtered_out = filtered_scenarios;
                 vec![SuiteEvent::Started {
                     test_count: steps + parser_errors,
                 }
                 .into()]
             }
-            Ok((Cucumber::Finished, meta)) => {
+            Ok((Cucumber::Finished { timed_out, .. }, meta)) => {
+                self.timed_out = timed_out;
                 let exec_time = self
                     .started_at
                     .and_then(|started| meta.at.duration_since(started).ok())
                     .as_ref()
                     .map(Duration::as_secs_f64);
 
-                let failed =
-                    self.failed + self.parsing_errors + self.hook_errors;
+                let failed = self.failed
+                    + self.parsing_errors
+                    + self.hook_errors
+                    + self.budget_errors
+                    + self.wip_errors;
                 let results = SuiteResults {
                     passed: self.passed,
                     failed,
                     ignored: self.ignored,
                     measured: 0,
-                    filtered_out: 0,
+                    filtered_out: self.filtered_out,
                     exec_time,
                 };
-                let ev = if failed == 0 {
+                let ev = if failed == 0 && !timed_out {
                     SuiteEvent::Ok { results }
                 } else {
                     SuiteEvent::Failed { results }
@@ -439,25 +545,25 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             Err(e) => {
                 self.parsing_errors += 1;
 
-                let path = match &e {
-                    parser::Error::Parsing(e) => match &**e {
-                        gherkin::ParseFileError::Parsing { path, .. }
-                        | gherkin::ParseFileError::Reading { path, .. } => {
-                            Some(path)
-                        }
-                    },
-                    parser::Error::ExampleExpansion(e) => e.path.as_ref(),
-                };
-                let name = path.and_then(|p| p.to_str()).map_or_else(
+                let failure = event::ParsingFailure::from(&e);
+                let name = failure.location(self.path_style).map_or_else(
                     || self.parsing_errors.to_string(),
-                    |p| p.escape_default().to_string(),
+                    |l| l.escape_default().to_string(),
                 );
                 let name = format!("Feature: Parsing {name}");
+                let hint = location_hint(
+                    failure.path.as_deref(),
+                    failure.position.map_or(1, |p| p.line),
+                    cli,
+                );
 
                 vec![
-                    TestEvent::started(name.clone()).into(),
+                    TestEvent::started(name.clone())
+                        .with_location_hint(hint.clone())
+                        .into(),
                     TestEvent::failed(name, None)
                         .with_stdout(e.to_string())
+                        .with_location_hint(hint)
                         .into(),
                 ]
             }
@@ -476,6 +582,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         match ev {
             Feature::Started
+            | Feature::Aborted
             | Feature::Finished
             | Feature::Rule(_, Rule::Started | Rule::Finished) => Vec::new(),
             Feature::Rule(rule, Rule::Scenario(scenario, ev)) => self
@@ -506,15 +613,19 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         let retries = ev.retries;
         match ev.event {
-            Scenario::Started | Scenario::Finished => Vec::new(),
+            Scenario::Started(_) => {
+                self.wip = false;
+                vec![]
+            }
             Scenario::Hook(ty, ev) => self.expand_hook_event(
                 feature, rule, scenario, ty, ev, retries, meta, cli,
             ),
-            Scenario::Background(step, ev) => self.expand_step_event(
-                feature, rule, scenario, &step, ev, retries, true, meta, cli,
+            Scenario::Background(step, ev)
+            | Scenario::RuleBackground(step, ev) => self.expand_step_event(
+                feature, rule, scenario, &step, ev, retries, true, cli,
             ),
             Scenario::Step(step, ev) => self.expand_step_event(
-                feature, rule, scenario, &step, ev, retries, false, meta, cli,
+                feature, rule, scenario, &step, ev, retries, false, cli,
             ),
             // We do use `print!()` intentionally here to support `libtest`
             // output capturing properly, which can only capture output from
@@ -525,8 +636,77 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 clippy::print_stdout,
                 reason = "supporting `libtest` output capturing properly"
             )]
-            Scenario::Log(msg) => {
-                print!("{msg}");
+            Scenario::Log(log) => {
+                print!("{}", log.message);
+                vec![]
+            }
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Scenario::Output(out) => {
+                print!("{}{}", out.stdout, out.stderr);
+                vec![]
+            }
+            Scenario::Artifact(_) | Scenario::Finished => vec![],
+            // Steps of a not run `Scenario` are reported as ignored tests.
+            Scenario::NotRun => scenario
+                .steps
+                .iter()
+                .flat_map(|step| {
+                    let name = self.test_case_name(
+                        feature,
+                        rule,
+                        scenario,
+                        Either::Right((step, false)),
+                        retries,
+                    );
+                    self.ignored += 1;
+                    let hint = location_hint(
+                        feature.path.as_deref(),
+                        step.position.line,
+                        cli,
+                    );
+                    [
+                        TestEvent::started(name.clone())
+                            .with_location_hint(hint.clone())
+                            .into(),
+                        TestEvent::ignored(name, None)
+                            .with_location_hint(hint)
+                            .into(),
+                    ]
+                })
+                .collect(),
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Scenario::Stalled(stall) => {
+                println!("{stall}");
+                vec![]
+            }
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Scenario::BudgetExceeded(budget) => {
+                if budget.failed && retries.is_none_or(|r| r.left == 0) {
+                    self.budget_errors += 1;
+                }
+                println!("{budget}");
+                vec![]
+            }
+            #[expect( // intentional
+                clippy::print_stdout,
+                reason = "supporting `libtest` output capturing properly"
+            )]
+            Scenario::Wip(wip) => {
+                if wip == event::Wip::Passed {
+                    self.wip_errors += 1;
+                    println!("{wip}");
+                } else {
+                    self.wip = true;
+                }
                 vec![]
             }
         }
@@ -551,7 +731,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 self.step_started_at(meta, cli);
                 Vec::new()
             }
-            event::Hook::Passed => Vec::new(),
+            event::Hook::Passed | event::Hook::Ignored(_) => Vec::new(),
             event::Hook::Failed(world, info) => {
                 self.hook_errors += 1;
 
@@ -562,15 +742,26 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     Either::Left(hook),
                     retries,
                 );
+                let hint = location_hint(
+                    feature.path.as_deref(),
+                    scenario.position.line,
+                    cli,
+                );
 
                 vec![
-                    TestEvent::started(name.clone()).into(),
+                    TestEvent::started(name.clone())
+                        .with_location_hint(hint.clone())
+                        .into(),
                     TestEvent::failed(name, self.step_exec_time(meta, cli))
+                        .with_location_hint(hint)
                         .with_stdout(format!(
                             "{}{}",
                             coerce_error(&info),
                             world
-                                .map(|w| format!("\n{w:#?}"))
+                                .map(|w| format!(
+                                    "\n{}",
+                                    format_world(&*w, None)
+                                ))
                                 .unwrap_or_default(),
                         ))
                         .into(),
@@ -591,7 +782,6 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
         ev: event::Step<W>,
         retries: Option<Retries>,
         is_background: bool,
-        meta: event::Metadata,
         cli: &Cli,
     ) -> Vec<LibTestJsonEvent> {
         use event::Step;
@@ -604,23 +794,21 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             retries,
         );
 
+        let exec_time = |dur| cli.report_time.is_some().then_some(dur);
         let ev = match ev {
-            Step::Started => {
-                self.step_started_at(meta, cli);
-                TestEvent::started(name)
-            }
-            Step::Passed(_, loc) => {
+            Step::Started => TestEvent::started(name),
+            Step::Passed(_, loc, dur) => {
                 self.passed += 1;
 
-                let event = TestEvent::ok(name, self.step_exec_time(meta, cli));
+                let event = TestEvent::ok(name, exec_time(dur));
                 if cli.show_output {
                     event.with_stdout(format!(
                         "{}:{}:{} (defined){}",
                         feature
                             .path
                             .as_ref()
-                            .and_then(|p| p.to_str().map(trim_path))
-                            .unwrap_or(&feature.name),
+                            .and_then(|p| self.path_style.render(p))
+                            .unwrap_or_else(|| feature.name.as_str().into()),
                         step.position.line,
                         step.position.col,
                         loc.map(|l| format!(
@@ -633,19 +821,18 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     event
                 }
             }
-            Step::Skipped => {
+            Step::Skipped(dur) => {
                 self.ignored += 1;
 
-                let event =
-                    TestEvent::ignored(name, self.step_exec_time(meta, cli));
+                let event = TestEvent::ignored(name, exec_time(dur));
                 if cli.show_output {
                     event.with_stdout(format!(
                         "{}:{}:{} (defined)",
                         feature
                             .path
                             .as_ref()
-                            .and_then(|p| p.to_str().map(trim_path))
-                            .unwrap_or(&feature.name),
+                            .and_then(|p| self.path_style.render(p))
+                            .unwrap_or_else(|| feature.name.as_str().into()),
                         step.position.line,
                         step.position.col,
                     ))
@@ -653,36 +840,49 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     event
                 }
             }
-            Step::Failed(_, loc, world, err) => {
+            Step::Failed(_, loc, world, err, dur) => {
                 if retries.is_some_and(|r| {
                     r.left > 0 && !matches!(err, event::StepError::NotFound)
                 }) {
                     self.retried += 1;
+                } else if self.wip {
+                    self.ignored += 1;
                 } else {
                     self.failed += 1;
                 }
 
-                TestEvent::failed(name, self.step_exec_time(meta, cli))
-                    .with_stdout(format!(
-                        "{}:{}:{} (defined){}\n{err}{}",
-                        feature
-                            .path
-                            .as_ref()
-                            .and_then(|p| p.to_str().map(trim_path))
-                            .unwrap_or(&feature.name),
-                        step.position.line,
-                        step.position.col,
-                        loc.map(|l| format!(
-                            "\n{}:{}:{} (matched)",
-                            l.path, l.line, l.column,
-                        ))
-                        .unwrap_or_default(),
-                        world.map(|w| format!("\n{w:#?}")).unwrap_or_default(),
+                // Failures of work-in-progress `Scenario`s are expected.
+                let event = if self.wip {
+                    TestEvent::ignored(name, exec_time(dur))
+                } else {
+                    TestEvent::failed(name, exec_time(dur))
+                };
+                event.with_stdout(format!(
+                    "{}:{}:{} (defined){}\n{err}{}",
+                    feature
+                        .path
+                        .as_ref()
+                        .and_then(|p| self.path_style.render(p))
+                        .unwrap_or_else(|| feature.name.as_str().into()),
+                    step.position.line,
+                    step.position.col,
+                    loc.map(|l| format!(
+                        "\n{}:{}:{} (matched)",
+                        l.path, l.line, l.column,
                     ))
+                    .unwrap_or_default(),
+                    world
+                        .map(|w| {
+                            format!("\n{}", format_world(&*w, Some(step)))
+                        })
+                        .unwrap_or_default(),
+                ))
             }
         };
 
-        vec![ev.into()]
+        let hint =
+            location_hint(feature.path.as_deref(), step.position.line, cli);
+        vec![ev.with_location_hint(hint).into()]
     }
 
     /// Generates test case name.
@@ -701,7 +901,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             feature
                 .path
                 .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
+                .and_then(|p| self.path_style.render(p))
                 .map_or_else(
                     || {
                         self.features_without_path += 1;
@@ -728,7 +928,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 .unwrap_or_default(),
         );
         let step_name = match step {
-            Either::Left(hook) => format!("{hook} hook"),
+            Either::Left(hook) => self.lexicon.hook(hook).to_owned(),
             Either::Right((step, is_bg)) => format!(
                 "{}: {} {}{}",
                 step.position.line,
@@ -736,7 +936,9 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     .then(|| feature
                         .background
                         .as_ref()
-                        .map_or("Background", |bg| bg.keyword.as_str()))
+                        .map_or(&*self.lexicon.background, |bg| {
+                            bg.keyword.as_str()
+                        }))
                     .unwrap_or_default(),
                 step.keyword,
                 step.value,
@@ -778,6 +980,23 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
     }
 }
 
+/// Formats an IntelliJ/TeamCity `locationHint` of the provided `line` in the
+/// `.feature` file of the provided `path`, if [`Cli::location_hints`] are
+/// enabled and the `path` is known.
+fn location_hint(
+    path: Option<&Path>,
+    line: usize,
+    cli: &Cli,
+) -> Option<String> {
+    if !cli.location_hints {
+        return None;
+    }
+    let path = path::absolute(path?).ok()?;
+    let path = path.to_string_lossy().replace('\\', "/");
+    let slash = if path.starts_with('/') { "" } else { "/" };
+    Some(format!("file://{slash}{path}:{line}"))
+}
+
 /// Indicator, whether a [`Step`] is [`Background`] or not.
 ///
 /// [`Background`]: event::Scenario::Background
@@ -814,6 +1033,18 @@ where
     fn hook_errors(&self) -> usize {
         self.hook_errors
     }
+
+    fn budget_errors(&self) -> usize {
+        self.budget_errors
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.wip_errors
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timed_out
+    }
 }
 
 impl<W, Val, Out> Arbitrary<W, Val> for Libtest<W, Out>
@@ -897,7 +1128,6 @@ struct SuiteResults {
     /// Number of measured benches.
     measured: usize,
 
-    // TODO: Figure out a way to actually report this.
     /// Number of filtered out test cases.
     filtered_out: usize,
 
@@ -959,12 +1189,31 @@ impl TestEvent {
             stdout.push('\n');
         }
 
+        self.map_inner(|inner| inner.with_stdout(stdout))
+    }
+
+    /// Adds a [`TestEventInner::location_hint`], if any.
+    fn with_location_hint(self, hint: Option<String>) -> Self {
+        match hint {
+            Some(hint) => {
+                self.map_inner(|inner| inner.with_location_hint(hint))
+            }
+            None => self,
+        }
+    }
+
+    /// Applies the provided function to the [`TestEventInner`] of this
+    /// [`TestEvent`].
+    fn map_inner(
+        self,
+        f: impl FnOnce(TestEventInner) -> TestEventInner,
+    ) -> Self {
         match self {
-            Self::Started(inner) => Self::Started(inner.with_stdout(stdout)),
-            Self::Ok(inner) => Self::Ok(inner.with_stdout(stdout)),
-            Self::Failed(inner) => Self::Failed(inner.with_stdout(stdout)),
-            Self::Ignored(inner) => Self::Ignored(inner.with_stdout(stdout)),
-            Self::Timeout(inner) => Self::Timeout(inner.with_stdout(stdout)),
+            Self::Started(inner) => Self::Started(f(inner)),
+            Self::Ok(inner) => Self::Ok(f(inner)),
+            Self::Failed(inner) => Self::Failed(f(inner)),
+            Self::Ignored(inner) => Self::Ignored(f(inner)),
+            Self::Timeout(inner) => Self::Timeout(f(inner)),
         }
     }
 }
@@ -993,6 +1242,13 @@ struct TestEventInner {
     /// Test case execution time.
     #[serde(skip_serializing_if = "Option::is_none")]
     exec_time: Option<f64>,
+
+    /// IntelliJ/TeamCity [`locationHint`][1] of this test case, pointing to
+    /// its Gherkin line.
+    ///
+    /// [1]: https://www.jetbrains.com/help/teamcity/service-messages.html
+    #[serde(rename = "locationHint", skip_serializing_if = "Option::is_none")]
+    location_hint: Option<String>,
 }
 
 impl TestEventInner {
@@ -1003,6 +1259,7 @@ impl TestEventInner {
             stdout: None,
             stderr: None,
             exec_time: None,
+            location_hint: None,
         }
     }
 
@@ -1017,4 +1274,10 @@ impl TestEventInner {
         self.stdout = Some(stdout);
         self
     }
+
+    /// Adds a [`TestEventInner::location_hint`].
+    fn with_location_hint(mut self, hint: String) -> Self {
+        self.location_hint = Some(hint);
+        self
+    }
 }
@@ -0,0 +1,563 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [OpenMetrics][1] [`Writer`] implementation.
+//!
+//! [1]: https://openmetrics.io
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Write as _},
+    fs, io, mem,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use derive_more::with_trait::Debug as DebugExt;
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser,
+    writer::{self, discard, Ext as _},
+    Event, World, Writer,
+};
+
+/// Upper bounds of the [`Metrics`] histogram buckets, in seconds.
+///
+/// Same as the default ones of [Prometheus] client libraries.
+///
+/// [Prometheus]: https://prometheus.io
+const BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// [OpenMetrics][1] [`Writer`] implementation outputting metrics of the whole
+/// run into an [`io::Write`] implementor once it's finished.
+///
+/// Outputs the following metrics:
+/// - `cucumber_scenarios_total` counter of the executed [`Scenario`]s by their
+///   final `status` (`passed`, `skipped` or `failed`);
+/// - `cucumber_steps_total` counter of the executed [`Step`]s by their
+///   `status`;
+/// - `cucumber_scenario_retries_total` counter of the retried [`Scenario`]
+///   attempts;
+/// - `cucumber_hook_errors_total` counter of the failed hooks;
+/// - `cucumber_exceeded_budgets_total` counter of the [`Scenario`] attempts
+///   exceeded their time budget;
+/// - `cucumber_parsing_errors_total` counter of the parsing errors;
+/// - `cucumber_step_duration_seconds` histogram of the executed [`Step`]s
+///   durations;
+/// - `cucumber_run_duration_seconds` gauge of the whole run duration.
+///
+/// Use a [`Pushgateway`] as the output to push the metrics to a [Prometheus
+/// Pushgateway][2].
+///
+/// # Panics
+///
+/// If fails to write into the output.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [1]: https://openmetrics.io
+/// [2]: https://github.com/prometheus/pushgateway
+#[derive(Clone, Debug)]
+pub struct Metrics<Out: io::Write> {
+    /// [`io::Write`] implementor to output metrics into.
+    output: Out,
+
+    /// [`Format`] to output metrics in.
+    format: Format,
+
+    /// [`Instant`] the run has started at.
+    started_at: Option<Instant>,
+
+    /// [`Status`]es of the currently running [`gherkin::Scenario`]s.
+    running: HashMap<Source<gherkin::Scenario>, Status>,
+
+    /// Number of the finished [`gherkin::Scenario`]s by their [`Status`].
+    scenarios: Counts,
+
+    /// Number of the executed [`gherkin::Step`]s by their [`Status`].
+    steps: Counts,
+
+    /// Number of the retried [`gherkin::Scenario`] attempts.
+    retries: usize,
+
+    /// Number of the failed hooks.
+    hook_errors: usize,
+
+    /// Number of the [`gherkin::Scenario`] attempts exceeded their time
+    /// budget.
+    exceeded_budgets: usize,
+
+    /// Number of the parsing errors.
+    parsing_errors: usize,
+
+    /// [`Histogram`] of the executed [`gherkin::Step`]s durations.
+    step_durations: Histogram,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+}
+
+impl<W, Out> Writer<W> for Metrics<Out>
+where
+    W: World + Debug,
+    Out: io::Write,
+{
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.map(Event::into_inner) {
+            Err(_) => self.parsing_errors += 1,
+            Ok(Cucumber::Started) => self.started_at = Some(Instant::now()),
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev),
+            )) => self.handle_scenario_event(sc, ev),
+            Ok(Cucumber::Finished { .. }) => self.write(),
+            Ok(
+                Cucumber::Feature(..)
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+            ) => {}
+        }
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Metrics<O> {}
+
+impl Metrics<fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`Metrics`] [`Writer`]
+    /// outputting metrics into a newly created file at the given `path`,
+    /// reported via [`Writer::report_paths()`].
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?)
+        })
+    }
+}
+
+impl<Out: io::Write> Metrics<Out> {
+    /// Creates a new [`Normalized`] [`Metrics`] [`Writer`] outputting metrics
+    /// into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: Debug + World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Metrics`] [`Writer`] outputting
+    /// metrics into the given `output`, and suitable for feeding into
+    /// [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(output: Out) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(output)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Metrics`] [`Writer`]
+    /// outputting metrics into the given `output`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Metrics::new()`] which creates an already [`Normalized`] version of
+    /// [`Metrics`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(output: Out) -> Self {
+        Self {
+            output,
+            format: Format::OpenMetrics,
+            started_at: None,
+            running: HashMap::new(),
+            scenarios: Counts::default(),
+            steps: Counts::default(),
+            retries: 0,
+            hook_errors: 0,
+            exceeded_budgets: 0,
+            parsing_errors: 0,
+            step_durations: Histogram::default(),
+            report_path: None,
+        }
+    }
+
+    /// Sets the [`Format`] to output metrics in.
+    ///
+    /// Default is [`Format::OpenMetrics`].
+    #[must_use]
+    pub const fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        scenario: Source<gherkin::Scenario>,
+        ev: event::RetryableScenario<W>,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        let status = match ev.event {
+            Scenario::Started(_) => {
+                if ev.retries.is_some_and(|r| r.current > 0) {
+                    self.retries += 1;
+                }
+                _ = self.running.insert(scenario, Status::Passed);
+                return;
+            }
+            Scenario::Finished => {
+                let status =
+                    self.running.remove(&scenario).unwrap_or(Status::Passed);
+                let retried = status == Status::Failed
+                    && ev.retries.is_some_and(|r| r.left > 0);
+                if !retried {
+                    self.scenarios.add(status);
+                }
+                return;
+            }
+            Scenario::Hook(_, Hook::Failed(..)) => {
+                self.hook_errors += 1;
+                Status::Failed
+            }
+            Scenario::Background(_, ev)
+            | Scenario::RuleBackground(_, ev)
+            | Scenario::Step(_, ev) => {
+                let (status, dur) = match ev {
+                    Step::Started => return,
+                    Step::Passed(.., dur) => (Status::Passed, dur),
+                    Step::Skipped(dur) => (Status::Skipped, dur),
+                    Step::Failed(.., dur) => (Status::Failed, dur),
+                };
+                self.steps.add(status);
+                if status != Status::Skipped {
+                    self.step_durations.observe(dur);
+                }
+                status
+            }
+            Scenario::BudgetExceeded(budget) => {
+                self.exceeded_budgets += 1;
+                if !budget.failed {
+                    return;
+                }
+                Status::Failed
+            }
+            Scenario::Wip(event::Wip::Passed) => Status::Failed,
+            Scenario::NotRun => Status::Skipped,
+            Scenario::Hook(..)
+            | Scenario::Wip(_)
+            | Scenario::Log(_)
+            | Scenario::Output(_)
+            | Scenario::Artifact(_)
+            | Scenario::Stalled(_) => return,
+        };
+        if let Some(st) = self.running.get_mut(&scenario) {
+            *st = (*st).max(status);
+        }
+    }
+
+    /// Writes the collected metrics into the output.
+    ///
+    /// # Panics
+    ///
+    /// If fails to write into the output.
+    fn write(&mut self) {
+        let mut out = String::new();
+
+        for (name, help, counts) in [
+            ("cucumber_scenarios", "Executed scenarios.", &self.scenarios),
+            ("cucumber_steps", "Executed steps.", &self.steps),
+        ] {
+            self.format.header(&mut out, name, "counter", help);
+            for (status, count) in counts.iter() {
+                writeln!(out, "{name}_total{{status=\"{status}\"}} {count}")
+                    .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+            }
+        }
+        for (name, help, count) in [
+            (
+                "cucumber_scenario_retries",
+                "Retried scenario attempts.",
+                self.retries,
+            ),
+            ("cucumber_hook_errors", "Failed hooks.", self.hook_errors),
+            (
+                "cucumber_exceeded_budgets",
+                "Scenario attempts exceeded their time budget.",
+                self.exceeded_budgets,
+            ),
+            (
+                "cucumber_parsing_errors",
+                "Parsing errors.",
+                self.parsing_errors,
+            ),
+        ] {
+            self.format.header(&mut out, name, "counter", help);
+            writeln!(out, "{name}_total {count}")
+                .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+        }
+
+        let histogram = "cucumber_step_duration_seconds";
+        self.format.header(
+            &mut out,
+            histogram,
+            "histogram",
+            "Steps durations.",
+        );
+        self.step_durations.write(&mut out, histogram);
+
+        let gauge = "cucumber_run_duration_seconds";
+        let run = self.started_at.map(|at| at.elapsed()).unwrap_or_default();
+        self.format
+            .header(&mut out, gauge, "gauge", "Whole run duration.");
+        writeln!(out, "{gauge} {}", run.as_secs_f64())
+            .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+
+        if self.format == Format::OpenMetrics {
+            out.push_str("# EOF\n");
+        }
+
+        self.output
+            .write_all(out.as_bytes())
+            .and_then(|()| self.output.flush())
+            .unwrap_or_else(|e| panic!("failed to write metrics: {e}"));
+    }
+}
+
+/// Text format to output [`Metrics`] in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// [OpenMetrics text format][1].
+    ///
+    /// [1]: https://github.com/prometheus/OpenMetrics/blob/main/specification/OpenMetrics.md
+    OpenMetrics,
+
+    /// [Prometheus text exposition format][1], accepted by a [`Pushgateway`].
+    ///
+    /// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+    Prometheus,
+}
+
+impl Format {
+    /// Writes `HELP` and `TYPE` lines of the given metric into the `out`put.
+    fn header(self, out: &mut String, name: &str, ty: &str, help: &str) {
+        // Prometheus format requires the names of counters to be the same as
+        // their samples' ones.
+        let suffix = match (self, ty) {
+            (Self::Prometheus, "counter") => "_total",
+            _ => "",
+        };
+        writeln!(out, "# HELP {name}{suffix} {help}")
+            .and_then(|()| writeln!(out, "# TYPE {name}{suffix} {ty}"))
+            .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+    }
+}
+
+/// Status of an executed [`gherkin::Scenario`] or [`gherkin::Step`].
+///
+/// Ordered by precedence, so the [`Ord::max()`] of [`gherkin::Step`]s'
+/// [`Status`]es is the one of their [`gherkin::Scenario`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Status {
+    /// Passed.
+    Passed,
+
+    /// Skipped.
+    Skipped,
+
+    /// Failed.
+    Failed,
+}
+
+/// Numbers of [`gherkin::Scenario`]s or [`gherkin::Step`]s by their
+/// [`Status`].
+#[derive(Clone, Copy, Debug, Default)]
+struct Counts {
+    /// Number of the passed ones.
+    passed: usize,
+
+    /// Number of the skipped ones.
+    skipped: usize,
+
+    /// Number of the failed ones.
+    failed: usize,
+}
+
+impl Counts {
+    /// Counts one more of the given [`Status`].
+    const fn add(&mut self, status: Status) {
+        match status {
+            Status::Passed => self.passed += 1,
+            Status::Skipped => self.skipped += 1,
+            Status::Failed => self.failed += 1,
+        }
+    }
+
+    /// Iterates over these [`Counts`] along with the names of their
+    /// [`Status`]es.
+    fn iter(&self) -> impl Iterator<Item = (&'static str, usize)> {
+        [
+            ("passed", self.passed),
+            ("skipped", self.skipped),
+            ("failed", self.failed),
+        ]
+        .into_iter()
+    }
+}
+
+/// Histogram of observed [`Duration`]s with the [`BUCKETS`].
+#[derive(Clone, Debug, Default)]
+struct Histogram {
+    /// Numbers of the observed [`Duration`]s falling into each of the
+    /// [`BUCKETS`] (not cumulative).
+    buckets: [usize; BUCKETS.len()],
+
+    /// Sum of the observed [`Duration`]s.
+    sum: Duration,
+
+    /// Number of the observed [`Duration`]s.
+    count: usize,
+}
+
+impl Histogram {
+    /// Observes the given [`Duration`].
+    fn observe(&mut self, dur: Duration) {
+        let secs = dur.as_secs_f64();
+        if let Some(i) = BUCKETS.iter().position(|le| secs <= *le) {
+            self.buckets[i] += 1;
+        }
+        self.sum += dur;
+        self.count += 1;
+    }
+
+    /// Writes samples of this [`Histogram`] with the given `name` into the
+    /// `out`put.
+    fn write(&self, out: &mut String, name: &str) {
+        let mut cumulative = 0;
+        for (le, count) in BUCKETS.iter().zip(self.buckets) {
+            cumulative += count;
+            writeln!(out, "{name}_bucket{{le=\"{le}\"}} {cumulative}")
+                .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+        }
+        writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", self.count)
+            .and_then(|()| {
+                writeln!(out, "{name}_sum {}", self.sum.as_secs_f64())
+            })
+            .and_then(|()| writeln!(out, "{name}_count {}", self.count))
+            .unwrap_or_else(|e| unreachable!("`String` write: {e}"));
+    }
+}
+
+/// [`io::Write`] implementor pushing the written metrics to a
+/// [Prometheus Pushgateway][1] on every [`io::Write::flush()`].
+///
+/// The whole group of the pushed metrics is replaced on every push, so should
+/// be used with [`Format::Prometheus`].
+///
+/// [1]: https://github.com/prometheus/pushgateway
+#[derive(Clone, DebugExt)]
+pub struct Pushgateway {
+    /// [`ureq::Agent`] to send requests with.
+    #[debug(ignore)]
+    agent: ureq::Agent,
+
+    /// URL of the [Prometheus Pushgateway][1] group to push metrics into.
+    ///
+    /// [1]: https://github.com/prometheus/pushgateway
+    url: String,
+
+    /// Buffered metrics to be pushed.
+    buffer: Vec<u8>,
+}
+
+impl Pushgateway {
+    /// Creates a new [`Pushgateway`] pushing metrics of the given `job` into
+    /// the [Prometheus Pushgateway][1] at the provided `endpoint` (like
+    /// `http://pushgateway.example.com:9091`).
+    ///
+    /// [1]: https://github.com/prometheus/pushgateway
+    #[must_use]
+    pub fn new(endpoint: impl AsRef<str>, job: impl AsRef<str>) -> Self {
+        Self {
+            agent: ureq::Agent::new(),
+            url: format!(
+                "{}/metrics/job/{}",
+                endpoint.as_ref().trim_end_matches('/'),
+                job.as_ref(),
+            ),
+            buffer: vec![],
+        }
+    }
+
+    /// Adds the given grouping label to the pushed metrics.
+    #[must_use]
+    pub fn grouping_label(
+        mut self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Self {
+        self.url = format!("{}/{}/{}", self.url, name.as_ref(), value.as_ref());
+        self
+    }
+
+    /// Sets the timeout of every request to the [Prometheus Pushgateway][1].
+    ///
+    /// [1]: https://github.com/prometheus/pushgateway
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+}
+
+impl io::Write for Pushgateway {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let body = mem::take(&mut self.buffer);
+        self.agent
+            .put(&self.url)
+            .set("Content-Type", "text/plain; version=0.0.4")
+            .send_bytes(&body)
+            .map(drop)
+            .map_err(io::Error::other)
+    }
+}
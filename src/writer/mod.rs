@@ -13,7 +13,13 @@
 //! [`Cucumber`]: crate::event::Cucumber
 
 pub mod basic;
+#[cfg(feature = "output-coverage")]
+pub mod coverage;
+#[cfg(feature = "output-ctrf")]
+pub mod ctrf;
 pub mod discard;
+pub mod docs;
+pub mod fail_on_parser_errors;
 pub mod fail_on_skipped;
 #[cfg(feature = "output-json")]
 pub mod json;
@@ -21,19 +27,44 @@ pub mod json;
 pub mod junit;
 #[cfg(feature = "libtest")]
 pub mod libtest;
+#[cfg(feature = "output-metrics")]
+pub mod metrics;
 pub mod normalize;
 pub mod or;
 pub mod out;
+#[cfg(feature = "record")]
+pub mod record;
 pub mod repeat;
+#[cfg(feature = "output-report-portal")]
+pub mod report_portal;
 pub mod summarize;
 pub mod tee;
+#[cfg(feature = "output-webhook")]
+pub mod webhook;
+#[cfg(feature = "output-xray")]
+pub mod xray;
+
+use std::{
+    borrow::Cow,
+    env, fs,
+    future::{self, Future},
+    path::{self, Path, PathBuf},
+    sync::LazyLock,
+};
 
-use std::future::Future;
+#[cfg(any(feature = "output-json", feature = "output-junit"))]
+use std::time::SystemTime;
 
 use sealed::sealed;
 
-use crate::{event, parser, Event};
+use crate::{event, parser, tag, Event};
 
+#[cfg(feature = "output-coverage")]
+#[doc(inline)]
+pub use self::coverage::Coverage;
+#[cfg(feature = "output-ctrf")]
+#[doc(inline)]
+pub use self::ctrf::Ctrf;
 #[cfg(feature = "output-json")]
 #[doc(inline)]
 pub use self::json::Json;
@@ -43,11 +74,28 @@ pub use self::junit::JUnit;
 #[cfg(feature = "libtest")]
 #[doc(inline)]
 pub use self::libtest::Libtest;
+#[cfg(feature = "output-metrics")]
+#[doc(inline)]
+pub use self::metrics::Metrics;
+#[cfg(feature = "record")]
+#[doc(inline)]
+pub use self::record::Record;
+#[cfg(feature = "output-report-portal")]
+#[doc(inline)]
+pub use self::report_portal::ReportPortal;
+#[cfg(feature = "output-webhook")]
+#[doc(inline)]
+pub use self::webhook::Webhook;
+#[cfg(feature = "output-xray")]
+#[doc(inline)]
+pub use self::xray::Xray;
 #[doc(inline)]
 pub use self::{
     basic::{Basic, Coloring},
+    docs::Docs,
+    fail_on_parser_errors::FailOnParserErrors,
     fail_on_skipped::FailOnSkipped,
-    normalize::{AssertNormalized, Normalize, Normalized},
+    normalize::{normalize_events, AssertNormalized, Normalize, Normalized},
     or::Or,
     repeat::Repeat,
     summarize::{Summarizable, Summarize},
@@ -89,6 +137,38 @@ pub trait Writer<World> {
         event: parser::Result<Event<event::Cucumber<World>>>,
         cli: &Self::Cli,
     ) -> impl Future<Output = ()>;
+
+    /// Returns paths of the report files produced by this [`Writer`], so they
+    /// can be processed (uploaded, published, etc.) once [`Cucumber::run()`]
+    /// is finished, without specifying the paths twice.
+    ///
+    /// Default implementation returns nothing, as a [`Writer`] isn't required
+    /// to output into files.
+    ///
+    /// [`Cucumber::run()`]: crate::Cucumber::run
+    #[must_use]
+    fn report_paths(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Finalizes this [`Writer`] once no more events are going to be handled
+    /// by it, so it can complete its output (close a document, flush a
+    /// buffer, send a request, etc.).
+    ///
+    /// [`Cucumber`] calls it once its events stream is over, even if it ends
+    /// abruptly because of a panic (in a [`Runner`] or another [`Writer`]),
+    /// so a partial output isn't left truncated. That's why it may be called
+    /// without the [`Cucumber::Finished`] event being handled before, and
+    /// should tolerate being called after it too.
+    ///
+    /// Default implementation does nothing.
+    ///
+    /// [`Cucumber`]: crate::Cucumber
+    /// [`Cucumber::Finished`]: event::Cucumber::Finished
+    /// [`Runner`]: crate::Runner
+    fn finalize(&mut self) -> impl Future<Output = ()> {
+        future::ready(())
+    }
 }
 
 /// [`Writer`] that also can output an arbitrary `Value` in addition to
@@ -145,12 +225,130 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn hook_errors(&self) -> usize;
 
+    /// Returns number of [`Scenario`]s failed due to exceeding their time
+    /// budget.
+    ///
+    /// Default implementation returns `0`, as only [`Summarize`] tracks them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn budget_errors(&self) -> usize {
+        0
+    }
+
+    /// Returns number of work-in-progress [`Scenario`]s failed due to passing
+    /// in a strict mode (see [`runner::Basic::strict_wip()`]).
+    ///
+    /// Default implementation returns `0`, as only [`Summarize`] tracks them.
+    ///
+    /// [`runner::Basic::strict_wip()`]: crate::runner::Basic::strict_wip
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn wip_errors(&self) -> usize {
+        0
+    }
+
+    /// Indicates whether the execution has been stopped due to exceeding its
+    /// global timeout (see [`runner::Basic::global_timeout()`]).
+    ///
+    /// Default implementation returns `false`, as only [`Summarize`] tracks
+    /// it.
+    ///
+    /// [`runner::Basic::global_timeout()`]: crate::runner::Basic::global_timeout
+    #[must_use]
+    fn timed_out(&self) -> bool {
+        false
+    }
+
+    /// Returns number of [`Scenario`]s passed (eventually, in case they were
+    /// retried).
+    ///
+    /// Default implementation sums up [`Stats::scenarios_by_feature()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn passed_scenarios(&self) -> usize {
+        self.scenarios_by_feature()
+            .iter()
+            .map(|(_, s)| s.passed)
+            .sum()
+    }
+
+    /// Returns number of skipped [`Scenario`]s.
+    ///
+    /// Default implementation sums up [`Stats::scenarios_by_feature()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn skipped_scenarios(&self) -> usize {
+        self.scenarios_by_feature()
+            .iter()
+            .map(|(_, s)| s.skipped)
+            .sum()
+    }
+
+    /// Returns number of failed [`Scenario`]s (after all their retry attempts,
+    /// if any).
+    ///
+    /// Default implementation sums up [`Stats::scenarios_by_feature()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn failed_scenarios(&self) -> usize {
+        self.scenarios_by_feature()
+            .iter()
+            .map(|(_, s)| s.failed)
+            .sum()
+    }
+
+    /// Returns number of flaky [`Scenario`]s, which have passed only after
+    /// being retried.
+    ///
+    /// Default implementation returns `0`, as only [`Summarize`] tracks them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn flaky_scenarios(&self) -> usize {
+        0
+    }
+
+    /// Returns [`Scenario`]s [`summarize::Stats`] grouped by their
+    /// [`Feature`]s, in order of their appearance.
+    ///
+    /// Default implementation returns nothing, as only [`Summarize`] collects
+    /// them.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(event::Source<gherkin::Feature>, summarize::Stats)> {
+        Vec::new()
+    }
+
+    /// Returns [`Scenario`]s [`summarize::Stats`] grouped by their tags
+    /// (including inherited ones), in alphabetical order.
+    ///
+    /// Default implementation returns nothing, as only [`Summarize`] collects
+    /// them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        Vec::new()
+    }
+
     /// Indicates whether there were failures/errors during execution.
     #[must_use]
     fn execution_has_failed(&self) -> bool {
         self.failed_steps() > 0
+            || self.failed_scenarios() > 0
             || self.parsing_errors() > 0
             || self.hook_errors() > 0
+            || self.budget_errors() > 0
+            || self.wip_errors() > 0
+            || self.timed_out()
     }
 }
 
@@ -215,6 +413,36 @@ pub trait Ext: Sized {
             &gherkin::Scenario,
         ) -> bool;
 
+    /// Wraps this [`Writer`] to fail on [`Skipped`] [`Step`]s if their
+    /// [`Scenario`] doesn't match the `allowed` tags expression (like
+    /// `@allow.skipped or @wip`).
+    ///
+    /// See [`FailOnSkipped::allowing()`] for more information.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn fail_on_skipped_allowing(
+        self,
+        allowed: tag::Expr,
+    ) -> FailOnSkipped<
+        Self,
+        impl Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + Clone,
+    >;
+
+    /// Wraps this [`Writer`] to fail the execution on any [`parser::Error`],
+    /// outputting an aggregated list of them at the end.
+    ///
+    /// See [`FailOnParserErrors`] for more information.
+    #[must_use]
+    fn fail_on_parser_errors(self) -> FailOnParserErrors<Self>;
+
     /// Wraps this [`Writer`] to re-output [`Skipped`] [`Step`]s at the end of
     /// an output.
     ///
@@ -232,6 +460,28 @@ pub trait Ext: Sized {
     #[must_use]
     fn repeat_failed<W>(self) -> Repeat<W, Self>;
 
+    /// Wraps this [`Writer`] to re-output the whole [`Scenario`]s failed in the
+    /// end, grouped by their [`Feature`]s and [`Rule`]s, at the end of an
+    /// output.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn repeat_failed_scenarios<W>(self) -> Repeat<W, Self>;
+
+    /// Wraps this [`Writer`] to re-output the whole [`Scenario`]s having
+    /// [`Skipped`] [`Step`]s, grouped by their [`Feature`]s and [`Rule`]s, at
+    /// the end of an output.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn repeat_skipped_scenarios<W>(self) -> Repeat<W, Self>;
+
     /// Wraps this [`Writer`] to re-output `filter`ed events at the end of an
     /// output.
     #[must_use]
@@ -296,6 +546,25 @@ impl<T> Ext for T {
         FailOnSkipped::with(self, with)
     }
 
+    fn fail_on_skipped_allowing(
+        self,
+        allowed: tag::Expr,
+    ) -> FailOnSkipped<
+        Self,
+        impl Fn(
+                &gherkin::Feature,
+                Option<&gherkin::Rule>,
+                &gherkin::Scenario,
+            ) -> bool
+            + Clone,
+    > {
+        FailOnSkipped::allowing(self, allowed)
+    }
+
+    fn fail_on_parser_errors(self) -> FailOnParserErrors<Self> {
+        FailOnParserErrors::from(self)
+    }
+
     fn repeat_skipped<W>(self) -> Repeat<W, Self> {
         Repeat::skipped(self)
     }
@@ -304,6 +573,14 @@ impl<T> Ext for T {
         Repeat::failed(self)
     }
 
+    fn repeat_failed_scenarios<W>(self) -> Repeat<W, Self> {
+        Repeat::failed_scenarios(self)
+    }
+
+    fn repeat_skipped_scenarios<W>(self) -> Repeat<W, Self> {
+        Repeat::skipped_scenarios(self)
+    }
+
     fn repeat_if<W, F>(self, filter: F) -> Repeat<W, Self, F>
     where
         F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool,
@@ -433,6 +710,18 @@ pub enum Verbosity {
     ///
     /// [Doc Strings]: https://cucumber.io/docs/gherkin/reference#doc-strings
     ShowWorldAndDocString = 2,
+
+    /// Additionally to [`Verbosity::ShowWorldAndDocString`] outputs
+    /// [`Backtrace`]s of panicked [`Step`]s.
+    ///
+    /// __NOTE__: [`Backtrace`]s are captured only if enabled via
+    ///           `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment
+    ///           variables (see [`Backtrace::capture()`] for details).
+    ///
+    /// [`Backtrace::capture()`]: std::backtrace::Backtrace::capture
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    /// [`Step`]: gherkin::Step
+    ShowBacktrace = 3,
 }
 
 impl From<u8> for Verbosity {
@@ -440,7 +729,8 @@ impl From<u8> for Verbosity {
         match v {
             0 => Self::Default,
             1 => Self::ShowWorld,
-            _ => Self::ShowWorldAndDocString,
+            2 => Self::ShowWorldAndDocString,
+            _ => Self::ShowBacktrace,
         }
     }
 }
@@ -451,6 +741,7 @@ impl From<Verbosity> for u8 {
             Verbosity::Default => 0,
             Verbosity::ShowWorld => 1,
             Verbosity::ShowWorldAndDocString => 2,
+            Verbosity::ShowBacktrace => 3,
         }
     }
 }
@@ -464,7 +755,10 @@ impl Verbosity {
     /// [`World`]: crate::World
     #[must_use]
     pub const fn shows_world(&self) -> bool {
-        matches!(self, Self::ShowWorld | Self::ShowWorldAndDocString)
+        matches!(
+            self,
+            Self::ShowWorld | Self::ShowWorldAndDocString | Self::ShowBacktrace,
+        )
     }
 
     /// Indicates whether [`Step::docstring`]s should be outputted implying this
@@ -473,6 +767,225 @@ impl Verbosity {
     /// [`Step::docstring`]: gherkin::Step::docstring
     #[must_use]
     pub const fn shows_docstring(&self) -> bool {
-        matches!(self, Self::ShowWorldAndDocString)
+        matches!(self, Self::ShowWorldAndDocString | Self::ShowBacktrace)
+    }
+
+    /// Indicates whether [`Backtrace`]s of panicked [`Step`]s should be
+    /// outputted implying this [`Verbosity`].
+    ///
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn shows_backtrace(&self) -> bool {
+        matches!(self, Self::ShowBacktrace)
+    }
+}
+
+/// Style of rendering [`Feature::path`]s in reports (like [Cucumber JSON][1]
+/// `uri`s, [JUnit `testsuite`][2] names or [`libtest`][3] test names).
+///
+/// Every style, except the [`PathStyle::Trimmed`] one, uses `/` separators on
+/// every platform, so the rendered paths are stable across machines.
+///
+/// [`Feature::path`]: gherkin::Feature::path
+/// [1]: https://github.com/cucumber/cucumber-json-schema
+/// [2]: https://llg.cubic.org/docs/junit
+/// [3]: https://doc.rust-lang.org/rustc/tests/index.html
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PathStyle {
+    /// Current project directory (`CARGO_WORKSPACE_DIR`, `CARGO_MANIFEST_DIR`
+    /// or the current working directory, whichever is set first) is trimmed
+    /// from the start of a path, if the path starts with it.
+    #[default]
+    Trimmed,
+
+    /// Absolute path.
+    Absolute,
+
+    /// Path relative to the `CARGO_MANIFEST_DIR` (or the current working
+    /// directory, if it's not set).
+    ///
+    /// Paths outside this directory are rendered as absolute ones.
+    RelativeToManifest,
+
+    /// Path relative to the root of the current [Cargo workspace][1].
+    ///
+    /// The workspace root is the `CARGO_WORKSPACE_DIR`, if set, or the closest
+    /// ancestor of the `CARGO_MANIFEST_DIR` having a `[workspace]` section in
+    /// its `Cargo.toml`, falling back to the `CARGO_MANIFEST_DIR` itself.
+    ///
+    /// Paths outside this directory are rendered as absolute ones.
+    ///
+    /// [1]: https://doc.rust-lang.org/cargo/reference/workspaces.html
+    RelativeToWorkspace,
+}
+
+impl PathStyle {
+    /// Renders the provided `path` in this [`PathStyle`].
+    ///
+    /// [`None`] is returned if the `path` isn't a valid UTF-8.
+    #[must_use]
+    pub fn render(self, path: &Path) -> Option<Cow<'_, str>> {
+        /// Path of the `CARGO_MANIFEST_DIR`.
+        static MANIFEST_DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+            env::var_os("CARGO_MANIFEST_DIR")
+                .map(PathBuf::from)
+                .or_else(|| env::current_dir().ok())
+                .unwrap_or_default()
+        });
+
+        /// Path of the current Cargo workspace root.
+        static WORKSPACE_DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+            env::var_os("CARGO_WORKSPACE_DIR").map_or_else(
+                || {
+                    MANIFEST_DIR
+                        .ancestors()
+                        .find(|dir| {
+                            fs::read_to_string(dir.join("Cargo.toml"))
+                                .is_ok_and(|toml| toml.contains("[workspace]"))
+                        })
+                        .unwrap_or(&MANIFEST_DIR)
+                        .to_path_buf()
+                },
+                PathBuf::from,
+            )
+        });
+
+        let base = match self {
+            Self::Trimmed => {
+                return path.to_str().map(|p| basic::trim_path(p).into());
+            }
+            Self::Absolute => None,
+            Self::RelativeToManifest => Some(&*MANIFEST_DIR),
+            Self::RelativeToWorkspace => Some(&*WORKSPACE_DIR),
+        };
+
+        let absolute = path::absolute(path).unwrap_or_else(|_| path.into());
+        let rendered = base
+            .and_then(|b| absolute.strip_prefix(b).ok())
+            .unwrap_or(&absolute)
+            .to_str()?
+            .replace(path::MAIN_SEPARATOR_STR, "/");
+        Some(rendered.into())
+    }
+}
+
+/// Format of rendering [`SystemTime`]s in reports (like
+/// [Cucumber JSON][1] `start_timestamp`s).
+///
+/// [JUnit `testsuite`][2] timestamps are always rendered as
+/// [`TimestampFormat::Rfc3339`] ones, as required by its schema.
+///
+/// [`SystemTime`]: std::time::SystemTime
+/// [1]: https://github.com/cucumber/cucumber-json-schema
+/// [2]: https://llg.cubic.org/docs/junit
+#[cfg(any(feature = "output-json", feature = "output-junit"))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TimestampFormat {
+    /// [RFC 3339] date-time with milliseconds precision, rendered in the
+    /// configured [`Timezone`] (like `2025-01-31T13:45:00.123Z` or
+    /// `2025-01-31T15:45:00.123+02:00`).
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    #[default]
+    Rfc3339,
+
+    /// Number of milliseconds elapsed since the [`UNIX_EPOCH`], regardless
+    /// of the configured [`Timezone`].
+    ///
+    /// [`UNIX_EPOCH`]: std::time::UNIX_EPOCH
+    EpochMillis,
+}
+
+/// Timezone to render [`SystemTime`]s in reports with.
+///
+/// [`SystemTime`]: std::time::SystemTime
+#[cfg(any(feature = "output-json", feature = "output-junit"))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Timezone {
+    /// [UTC].
+    ///
+    /// [UTC]: https://en.wikipedia.org/wiki/Coordinated_Universal_Time
+    #[default]
+    Utc,
+
+    /// Local timezone of the machine running tests (detected via the `TZ`
+    /// environment variable or the system settings).
+    Local,
+}
+
+#[cfg(any(feature = "output-json", feature = "output-junit"))]
+impl Timezone {
+    /// Returns the offset of this [`Timezone`] from [UTC] at the given `time`.
+    ///
+    /// [UTC]: https://en.wikipedia.org/wiki/Coordinated_Universal_Time
+    pub(crate) fn offset_at(self, time: SystemTime) -> chrono::FixedOffset {
+        match self {
+            Self::Utc => chrono::Offset::fix(&chrono::Utc),
+            Self::Local => {
+                *chrono::DateTime::<chrono::Local>::from(time).offset()
+            }
+        }
+    }
+}
+
+/// Labels of the entities synthesized by [`Writer`]s, which don't originate
+/// from `.feature` files, and so, cannot be localized via a `# language:`
+/// header (like [`Scenario`] hooks).
+///
+/// Defaults to English.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lexicon {
+    /// Label of a [`HookType::Before`] hook.
+    ///
+    /// Default is `Before hook`.
+    ///
+    /// [`HookType::Before`]: event::HookType::Before
+    pub before_hook: Cow<'static, str>,
+
+    /// Label of a [`HookType::After`] hook.
+    ///
+    /// Default is `After hook`.
+    ///
+    /// [`HookType::After`]: event::HookType::After
+    pub after_hook: Cow<'static, str>,
+
+    /// Label of a [`Background`], used whenever its own `keyword` isn't
+    /// available.
+    ///
+    /// Default is `Background`.
+    ///
+    /// [`Background`]: gherkin::Background
+    pub background: Cow<'static, str>,
+}
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lexicon {
+    /// Creates a new English [`Lexicon`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            before_hook: Cow::Borrowed("Before hook"),
+            after_hook: Cow::Borrowed("After hook"),
+            background: Cow::Borrowed("Background"),
+        }
+    }
+
+    /// Returns the label of the provided [`HookType`].
+    ///
+    /// [`HookType`]: event::HookType
+    #[must_use]
+    pub fn hook(&self, which: event::HookType) -> &str {
+        match which {
+            event::HookType::Before => &self.before_hook,
+            event::HookType::After => &self.after_hook,
+        }
     }
 }
@@ -10,15 +10,21 @@
 
 //! [`Writer`]-wrapper for outputting events in a normalized readable order.
 
-use std::{future::Future, hash::Hash, mem};
+use std::{
+    collections::VecDeque, future::Future, hash::Hash, mem, path::PathBuf,
+};
 
 use derive_more::with_trait::Deref;
 use either::Either;
+use futures::{stream, Stream, StreamExt as _};
 use linked_hash_map::LinkedHashMap;
 
 use crate::{
+    cli,
     event::{self, Metadata, Retries, Source},
-    parser, writer, Event, World, Writer,
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
 };
 
 /// Wrapper for a [`Writer`] implementation for outputting events corresponding
@@ -100,14 +106,18 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
         match event.map(Event::split) {
             res @ (Err(_)
             | Ok((
-                Cucumber::Started | Cucumber::ParsingFinished { .. },
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
                 _,
             ))) => {
                 self.writer
                     .handle_event(res.map(|(ev, meta)| meta.insert(ev)), cli)
                     .await;
             }
-            Ok((Cucumber::Finished, meta)) => self.queue.finished(meta),
+            Ok((ev @ Cucumber::Finished { .. }, meta)) => {
+                self.queue.finished(meta.wrap(ev));
+            }
             Ok((Cucumber::Feature(f, ev), meta)) => match ev {
                 Feature::Started => self.queue.new_feature(meta.wrap(f)),
                 Feature::Scenario(s, ev) => {
@@ -118,6 +128,7 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
                         meta.wrap(ev),
                     );
                 }
+                Feature::Aborted => self.queue.feature_aborted(meta.wrap(&f)),
                 Feature::Finished => self.queue.feature_finished(meta.wrap(&f)),
                 Feature::Rule(r, ev) => match ev {
                     Rule::Started => self.queue.new_rule(&f, meta.wrap(r)),
@@ -142,12 +153,18 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
             self.queue.remove(&feature_to_remove);
         }
 
-        if let Some(meta) = self.queue.state.take_to_emit() {
-            self.writer
-                .handle_event(Ok(meta.wrap(Cucumber::Finished)), cli)
-                .await;
+        if let Some(ev) = self.queue.state.take_to_emit() {
+            self.writer.handle_event(Ok(ev), cli).await;
         }
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.writer.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.writer.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -190,6 +207,44 @@ where
         self.writer.hook_errors()
     }
 
+    fn budget_errors(&self) -> usize {
+        self.writer.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.writer.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.writer.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.writer.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.writer.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.writer.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.writer.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.writer.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.writer.scenarios_by_tag()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
@@ -201,6 +256,62 @@ impl<W, Wr: writer::NonTransforming> writer::NonTransforming
 {
 }
 
+/// Rearranges the given [`Stream`] of [`Cucumber`] events (like the one
+/// returned by a [`Runner`]) in a [`Normalized`] readable order, the same way
+/// the [`Normalize`] wrapper does for a [`Writer`].
+///
+/// Allows to consume ordered events without implementing a [`Writer`].
+///
+/// > __NOTE__: [`Feature`]s' events are buffered until the [`Feature`] can be
+/// >           emitted uninterruptedly, so the ones still buffered when the
+/// >           given [`Stream`] ends without the [`Cucumber::Finished`] event
+/// >           are never emitted.
+///
+/// [`Cucumber`]: event::Cucumber
+/// [`Cucumber::Finished`]: event::Cucumber::Finished
+/// [`Feature`]: gherkin::Feature
+/// [`Runner`]: crate::Runner
+#[expect( // intentional
+    clippy::module_name_repetitions,
+    reason = "re-exported as `writer::normalize_events()`"
+)]
+pub fn normalize_events<W, S>(
+    events: S,
+) -> impl Stream<Item = parser::Result<Event<event::Cucumber<W>>>>
+where
+    S: Stream<Item = parser::Result<Event<event::Cucumber<W>>>>,
+{
+    let normalize = Normalize::new(Buffer(VecDeque::new()));
+    stream::unfold(
+        (Box::pin(events), normalize),
+        |(mut events, mut normalize)| async move {
+            loop {
+                if let Some(ev) = normalize.writer.0.pop_front() {
+                    return Some((ev, (events, normalize)));
+                }
+                let ev = events.next().await?;
+                normalize.handle_event(ev, &cli::Empty).await;
+            }
+        },
+    )
+}
+
+/// [`Writer`] buffering all the received events, so they can be retrieved from
+/// the [`Normalize`] wrapper in the [`normalize_events()`].
+struct Buffer<World>(VecDeque<parser::Result<Event<event::Cucumber<World>>>>);
+
+impl<W> Writer<W> for Buffer<W> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        self.0.push_back(event);
+    }
+}
+
 /// Marker indicating that a [`Writer`] can accept events in a [happened-before]
 /// order.
 ///
@@ -264,6 +375,14 @@ impl<W: World, Wr: Writer<W> + ?Sized> Writer<W> for AssertNormalized<Wr> {
     ) {
         self.0.handle_event(event, cli).await;
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.0.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.0.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -307,6 +426,44 @@ where
         self.0.hook_errors()
     }
 
+    fn budget_errors(&self) -> usize {
+        self.0.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.0.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.0.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.0.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.0.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.0.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.0.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.0.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.0.scenarios_by_tag()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
@@ -332,7 +489,7 @@ impl<Writer> Normalized for AssertNormalized<Writer> {}
 ///
 /// [`next()`]: Iterator::next()
 #[derive(Clone, Debug)]
-struct Queue<K: Eq + Hash, V> {
+struct Queue<K: Eq + Hash, V, F = Metadata> {
     /// Underlying FIFO queue of values.
     fifo: LinkedHashMap<K, V>,
 
@@ -342,16 +499,23 @@ struct Queue<K: Eq + Hash, V> {
     /// on to the inner [`Writer`] yet.
     initial: Option<Metadata>,
 
+    /// [`Metadata`] of the `Aborted` event of this [`Queue`], if it has been
+    /// encountered (only [`Feature`]s may be aborted).
+    ///
+    /// [`Feature`]: gherkin::Feature
+    aborted: Option<Metadata>,
+
     /// [`FinishedState`] of this [`Queue`].
-    state: FinishedState,
+    state: FinishedState<F>,
 }
 
-impl<K: Eq + Hash, V> Queue<K, V> {
+impl<K: Eq + Hash, V, F> Queue<K, V, F> {
     /// Creates a new normalization [`Queue`] with an initial metadata.
     fn new(initial: Metadata) -> Self {
         Self {
             fifo: LinkedHashMap::new(),
             initial: Some(initial),
+            aborted: None,
             state: FinishedState::NotFinished,
         }
     }
@@ -359,8 +523,8 @@ impl<K: Eq + Hash, V> Queue<K, V> {
     /// Marks this [`Queue`] as [`FinishedButNotEmitted`].
     ///
     /// [`FinishedButNotEmitted`]: FinishedState::FinishedButNotEmitted
-    fn finished(&mut self, meta: Metadata) {
-        self.state = FinishedState::FinishedButNotEmitted(meta);
+    fn finished(&mut self, finished: F) {
+        self.state = FinishedState::FinishedButNotEmitted(finished);
     }
 
     /// Checks whether this [`Queue`] transited to [`FinishedAndEmitted`] state.
@@ -377,8 +541,11 @@ impl<K: Eq + Hash, V> Queue<K, V> {
 }
 
 /// Finishing state of a [`Queue`].
+///
+/// Holds the [`Metadata`] of the `Finished` event, or the whole `Finished`
+/// [`Event`], in case it carries any data.
 #[derive(Clone, Copy, Debug)]
-enum FinishedState {
+enum FinishedState<F = Metadata> {
     /// `Finished` event hasn't been encountered yet.
     NotFinished,
 
@@ -386,22 +553,23 @@ enum FinishedState {
     /// [`Writer`] yet.
     ///
     /// This happens when output is busy due to outputting some other item.
-    FinishedButNotEmitted(Metadata),
+    FinishedButNotEmitted(F),
 
     /// `Finished` event has been encountered and passed to the inner
     /// [`Writer`].
     FinishedAndEmitted,
 }
 
-impl FinishedState {
-    /// Returns [`Metadata`] of this [`FinishedState::FinishedButNotEmitted`],
-    /// and makes it [`FinishedAndEmitted`].
+impl<F> FinishedState<F> {
+    /// Returns the `Finished` event of this
+    /// [`FinishedState::FinishedButNotEmitted`], and makes it
+    /// [`FinishedAndEmitted`].
     ///
     /// [`FinishedAndEmitted`]: FinishedState::FinishedAndEmitted
-    fn take_to_emit(&mut self) -> Option<Metadata> {
+    fn take_to_emit(&mut self) -> Option<F> {
         let current = mem::replace(self, Self::FinishedAndEmitted);
-        if let Self::FinishedButNotEmitted(meta) = current {
-            Some(meta)
+        if let Self::FinishedButNotEmitted(finished) = current {
+            Some(finished)
         } else {
             *self = current;
             None
@@ -467,8 +635,11 @@ trait Emitter<World> {
 }
 
 /// [`Queue`] of all incoming events.
-type CucumberQueue<World> =
-    Queue<Source<gherkin::Feature>, FeatureQueue<World>>;
+type CucumberQueue<World> = Queue<
+    Source<gherkin::Feature>,
+    FeatureQueue<World>,
+    Event<event::Cucumber<World>>,
+>;
 
 impl<World> CucumberQueue<World> {
     /// Inserts a new [`Feature`] on [`event::Feature::Started`].
@@ -479,6 +650,20 @@ impl<World> CucumberQueue<World> {
         drop(self.fifo.insert(feat, FeatureQueue::new(meta)));
     }
 
+    /// Marks a [`Feature`] as aborted on [`event::Feature::Aborted`].
+    ///
+    /// We don't emit it by the way, as it should be emitted right before the
+    /// [`event::Feature::Finished`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn feature_aborted(&mut self, feat: Event<&Source<gherkin::Feature>>) {
+        let (feat, meta) = feat.split();
+        self.fifo
+            .get_mut(feat)
+            .unwrap_or_else(|| panic!("no `Feature: {}`", feat.name))
+            .aborted = Some(meta);
+    }
+
     /// Marks a [`Feature`] as finished on [`event::Feature::Finished`].
     ///
     /// We don't emit it by the way, as there may be other in-progress
@@ -572,6 +757,16 @@ impl<'me, World> Emitter<World> for &'me mut CucumberQueue<World> {
             }
 
             if let Some(meta) = events.state.take_to_emit() {
+                if let Some(aborted) = events.aborted.take() {
+                    writer
+                        .handle_event(
+                            Ok(aborted.wrap(event::Cucumber::feature_aborted(
+                                f.clone(),
+                            ))),
+                            cli,
+                        )
+                        .await;
+                }
                 writer
                     .handle_event(
                         Ok(meta.wrap(event::Cucumber::feature_finished(
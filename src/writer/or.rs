@@ -10,7 +10,15 @@
 
 //! Passing events to one of two [`Writer`]s based on a predicate.
 
-use crate::{cli, event, parser, writer, Event, World, Writer};
+use std::path::PathBuf;
+
+use crate::{
+    cli,
+    event::{self, Source},
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
+};
 
 /// Wrapper for passing events to one of two [`Writer`]s based on a predicate.
 #[derive(Clone, Copy, Debug)]
@@ -78,6 +86,17 @@ where
             self.right.handle_event(event, &cli.right).await;
         }
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        let mut paths = self.left.report_paths();
+        paths.extend(self.right.report_paths());
+        paths
+    }
+
+    async fn finalize(&mut self) {
+        self.left.finalize().await;
+        self.right.finalize().await;
+    }
 }
 
 impl<W, L, R, F> writer::Stats<W> for Or<L, R, F>
@@ -113,6 +132,52 @@ where
     fn hook_errors(&self) -> usize {
         self.left.hook_errors() + self.right.hook_errors()
     }
+
+    fn budget_errors(&self) -> usize {
+        self.left.budget_errors() + self.right.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.left.wip_errors() + self.right.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.left.timed_out() || self.right.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.left.passed_scenarios() + self.right.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.left.skipped_scenarios() + self.right.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.left.failed_scenarios() + self.right.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.left.flaky_scenarios() + self.right.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        summarize::merge_stats(
+            self.left.scenarios_by_feature(),
+            self.right.scenarios_by_feature(),
+        )
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        let mut merged = summarize::merge_stats(
+            self.left.scenarios_by_tag(),
+            self.right.scenarios_by_tag(),
+        );
+        merged.sort_by(|(l, _), (r, _)| l.cmp(r));
+        merged
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
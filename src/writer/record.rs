@@ -0,0 +1,982 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`] recording the whole [`Cucumber`] events stream, so it can be
+//! replayed later via [`parser::Replay`].
+//!
+//! The stream is recorded in [JSON Lines][1] format: every [`gherkin`] source
+//! is written once (when first met) along with its ID, and every event refers
+//! the sources by these IDs.
+//!
+//! [`Cucumber`]: event::Cucumber
+//! [1]: https://jsonlines.org
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser, step,
+    writer::{self, basic::coerce_error, discard, Ext as _},
+    Event, World, Writer,
+};
+
+/// [`Writer`] recording all the [`Cucumber`] events into the given output, to
+/// be replayed later via [`parser::Replay`] through any other [`Writer`].
+///
+/// [`World`]s and [`Backtrace`]s of failures are not recorded, while panic
+/// payloads and [`parser::Error`]s are recorded as their messages only.
+///
+/// [`Backtrace`]: std::backtrace::Backtrace
+/// [`Cucumber`]: event::Cucumber
+#[derive(Clone, Debug)]
+pub struct Record<Out: io::Write> {
+    /// [`io::Write`] implementor to record events into.
+    output: Out,
+
+    /// IDs of the already recorded [`gherkin::Feature`]s.
+    features: HashMap<Source<gherkin::Feature>, u64>,
+
+    /// IDs of the already recorded [`gherkin::Rule`]s.
+    rules: HashMap<Source<gherkin::Rule>, u64>,
+
+    /// IDs of the already recorded [`gherkin::Scenario`]s.
+    scenarios: HashMap<Source<gherkin::Scenario>, u64>,
+
+    /// IDs of the already recorded [`gherkin::Step`]s.
+    steps: HashMap<Source<gherkin::Step>, u64>,
+
+    /// ID to be assigned to the next recorded [`gherkin`] source.
+    next_id: u64,
+
+    /// Path of the file the `output` represents, if any.
+    report_path: Option<PathBuf>,
+}
+
+impl<W: World, Out: io::Write> Writer<W> for Record<Out> {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        let line = match event {
+            Ok(ev) => {
+                #[cfg(feature = "timestamps")]
+                let at = Some(ev.at);
+                #[cfg(not(feature = "timestamps"))]
+                let at = None;
+                Line::Event {
+                    at,
+                    event: self.cucumber_event(ev.into_inner()),
+                }
+            }
+            Err(e) => Line::error(&e),
+        };
+        self.write_line(&line);
+    }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.report_path.iter().cloned().collect()
+    }
+
+    async fn finalize(&mut self) {
+        self.output
+            .flush()
+            .unwrap_or_else(|e| panic!("Failed to flush recorded events: {e}"));
+    }
+}
+
+impl<O: io::Write> writer::NonTransforming for Record<O> {}
+
+impl Record<fs::File> {
+    /// Creates a new raw and non-[`Normalized`] [`Record`] [`Writer`]
+    /// recording events into a newly created file at the given `path`,
+    /// reported via [`Writer::report_paths()`].
+    ///
+    /// To use it as a [`Normalized`] [`Writer`], call
+    /// [`WriterExt::normalized()`] on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be created.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`WriterExt::normalized()`]: crate::WriterExt::normalized
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        Ok(Self {
+            report_path: Some(path.to_owned()),
+            ..Self::raw(fs::File::create(path)?)
+        })
+    }
+}
+
+impl<Out: io::Write> Record<Out> {
+    /// Creates a new [`Normalized`] [`Record`] [`Writer`] recording events
+    /// into the given `output`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: World>(output: Out) -> writer::Normalize<W, Self> {
+        Self::raw(output).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Record`] [`Writer`] recording events
+    /// into the given `output`, and suitable for feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(output: Out) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(output)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Record`] [`Writer`]
+    /// recording events into the given `output` exactly in the order they
+    /// happen.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Record::new()`] which creates an already [`Normalized`] version of
+    /// [`Record`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(output: Out) -> Self {
+        Self {
+            output,
+            features: HashMap::new(),
+            rules: HashMap::new(),
+            scenarios: HashMap::new(),
+            steps: HashMap::new(),
+            next_id: 0,
+            report_path: None,
+        }
+    }
+
+    /// Writes the provided [`Line`] into the output.
+    fn write_line(&mut self, line: &Line) {
+        serde_json::to_writer(&mut self.output, line)
+            .map_err(io::Error::from)
+            .and_then(|()| self.output.write_all(b"\n"))
+            .unwrap_or_else(|e| panic!("Failed to record event: {e}"));
+    }
+
+    /// Returns the ID of the provided [`Source`], recording it first, if it
+    /// hasn't been recorded yet.
+    fn source_id<T>(
+        &mut self,
+        source: &Source<T>,
+        recorded: fn(&mut Self) -> &mut HashMap<Source<T>, u64>,
+        line: impl FnOnce(u64, &T) -> Line,
+    ) -> u64 {
+        if let Some(id) = recorded(self).get(source) {
+            return *id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        _ = recorded(self).insert(source.clone(), id);
+        self.write_line(&line(id, source));
+        id
+    }
+
+    /// Returns the ID of the provided [`gherkin::Feature`], recording it first,
+    /// if it hasn't been recorded yet.
+    fn feature_id(&mut self, feature: &Source<gherkin::Feature>) -> u64 {
+        self.source_id(
+            feature,
+            |s| &mut s.features,
+            |id, feature| Line::Feature {
+                id,
+                feature: feature.clone(),
+            },
+        )
+    }
+
+    /// Converts the provided [`event::Cucumber`] into a [`CucumberEvent`],
+    /// recording all the [`gherkin`] sources it refers to.
+    fn cucumber_event<W>(&mut self, ev: event::Cucumber<W>) -> CucumberEvent {
+        match ev {
+            event::Cucumber::Started => CucumberEvent::Started,
+            event::Cucumber::Feature(f, ev) => {
+                let id = self.feature_id(&f);
+                CucumberEvent::Feature(id, self.feature_event(ev))
+            }
+            event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios,
+            } => CucumberEvent::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios,
+            },
+            event::Cucumber::DuplicateName(dup) => {
+                CucumberEvent::DuplicateName(match dup {
+                    event::DuplicateName::Features(features) => {
+                        DuplicateNameEvent::Features(
+                            features
+                                .iter()
+                                .map(|f| self.feature_id(f))
+                                .collect(),
+                        )
+                    }
+                    event::DuplicateName::Scenarios {
+                        feature,
+                        name,
+                        positions,
+                    } => DuplicateNameEvent::Scenarios {
+                        feature: self.feature_id(&feature),
+                        name,
+                        positions,
+                    },
+                })
+            }
+            event::Cucumber::Finished {
+                passed,
+                failed,
+                skipped,
+                retried,
+                duration,
+                timed_out,
+            } => CucumberEvent::Finished {
+                passed,
+                failed,
+                skipped,
+                retried,
+                duration,
+                timed_out,
+            },
+        }
+    }
+
+    /// Converts the provided [`event::Feature`] into a [`FeatureEvent`].
+    fn feature_event<W>(&mut self, ev: event::Feature<W>) -> FeatureEvent {
+        match ev {
+            event::Feature::Started => FeatureEvent::Started,
+            event::Feature::Rule(r, ev) => {
+                let id = self.source_id(
+                    &r,
+                    |s| &mut s.rules,
+                    |id, rule| Line::Rule {
+                        id,
+                        rule: rule.clone(),
+                    },
+                );
+                let ev = match ev {
+                    event::Rule::Started => RuleEvent::Started,
+                    event::Rule::Scenario(sc, ev) => {
+                        let (sc_id, ev) = self.scenario_event(&sc, ev);
+                        RuleEvent::Scenario(sc_id, ev)
+                    }
+                    event::Rule::Finished => RuleEvent::Finished,
+                };
+                FeatureEvent::Rule(id, ev)
+            }
+            event::Feature::Scenario(sc, ev) => {
+                let (id, ev) = self.scenario_event(&sc, ev);
+                FeatureEvent::Scenario(id, ev)
+            }
+            event::Feature::Aborted => FeatureEvent::Aborted,
+            event::Feature::Finished => FeatureEvent::Finished,
+        }
+    }
+
+    /// Converts the provided [`event::RetryableScenario`] into a
+    /// [`ScenarioEvent`], returning it along with the [`gherkin::Scenario`]
+    /// ID.
+    fn scenario_event<W>(
+        &mut self,
+        sc: &Source<gherkin::Scenario>,
+        ev: event::RetryableScenario<W>,
+    ) -> (u64, ScenarioEvent) {
+        use event::Scenario;
+
+        let id = self.source_id(
+            sc,
+            |s| &mut s.scenarios,
+            |id, scenario| Line::Scenario {
+                id,
+                scenario: scenario.clone(),
+            },
+        );
+        let event = match ev.event {
+            Scenario::Started(sc_id) => ScenarioEventKind::Started(sc_id.0),
+            Scenario::Hook(ty, ev) => {
+                let ty = match ty {
+                    event::HookType::Before => HookType::Before,
+                    event::HookType::After => HookType::After,
+                };
+                let ev = match ev {
+                    event::Hook::Started => HookEvent::Started,
+                    event::Hook::Passed => HookEvent::Passed,
+                    event::Hook::Failed(_, info) => HookEvent::Failed {
+                        message: coerce_error(&info).into_owned(),
+                    },
+                    event::Hook::Ignored(info) => HookEvent::Ignored {
+                        message: coerce_error(&info).into_owned(),
+                    },
+                };
+                ScenarioEventKind::Hook(ty, ev)
+            }
+            Scenario::Background(st, ev) => {
+                let (step_id, ev) = self.step_event(&st, ev);
+                ScenarioEventKind::Background(step_id, ev)
+            }
+            Scenario::RuleBackground(st, ev) => {
+                let (step_id, ev) = self.step_event(&st, ev);
+                ScenarioEventKind::RuleBackground(step_id, ev)
+            }
+            Scenario::Step(st, ev) => {
+                let (step_id, ev) = self.step_event(&st, ev);
+                ScenarioEventKind::Step(step_id, ev)
+            }
+            Scenario::Log(log) => ScenarioEventKind::Log {
+                message: log.message,
+                fields: log
+                    .fields
+                    .into_iter()
+                    .map(|(name, val)| (name, val.into()))
+                    .collect(),
+            },
+            Scenario::Artifact(path) => ScenarioEventKind::Artifact(path),
+            Scenario::Output(out) => ScenarioEventKind::Output {
+                stdout: out.stdout,
+                stderr: out.stderr,
+            },
+            Scenario::Stalled(stall) => ScenarioEventKind::Stalled {
+                step: self.source_id(
+                    &stall.step,
+                    |s| &mut s.steps,
+                    |step_id, step| Line::Step {
+                        id: step_id,
+                        step: step.clone(),
+                    },
+                ),
+                is_background: stall.is_background,
+                elapsed: stall.elapsed,
+                world: stall.world,
+            },
+            Scenario::BudgetExceeded(budget) => {
+                ScenarioEventKind::BudgetExceeded {
+                    limit: budget.limit,
+                    elapsed: budget.elapsed,
+                    failed: budget.failed,
+                }
+            }
+            Scenario::Wip(wip) => ScenarioEventKind::Wip {
+                passed: wip == event::Wip::Passed,
+            },
+            Scenario::NotRun => ScenarioEventKind::NotRun,
+            Scenario::Finished => ScenarioEventKind::Finished,
+        };
+        let retries = ev.retries.map(|r| (r.current, r.left));
+        (id, ScenarioEvent { event, retries })
+    }
+
+    /// Converts the provided [`event::Step`] into a [`StepEvent`], returning
+    /// it along with the [`gherkin::Step`] ID.
+    fn step_event<W>(
+        &mut self,
+        st: &Source<gherkin::Step>,
+        ev: event::Step<W>,
+    ) -> (u64, StepEvent) {
+        let id = self.source_id(
+            st,
+            |s| &mut s.steps,
+            |id, step| Line::Step {
+                id,
+                step: step.clone(),
+            },
+        );
+        let ev = match ev {
+            event::Step::Started => StepEvent::Started,
+            event::Step::Skipped(duration) => StepEvent::Skipped { duration },
+            event::Step::Passed(captures, loc, duration) => StepEvent::Passed {
+                captures: Captures::from(&captures),
+                location: loc.map(Location::from),
+                duration,
+            },
+            event::Step::Failed(captures, loc, _, err, duration) => {
+                StepEvent::Failed {
+                    captures: captures.as_ref().map(Captures::from),
+                    location: loc.map(Location::from),
+                    error: StepError::from(&err),
+                    duration,
+                }
+            }
+        };
+        (id, ev)
+    }
+}
+
+/// Single line of a recorded events stream.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Line {
+    /// Definition of a [`gherkin::Feature`] with its ID.
+    Feature {
+        /// ID of the [`gherkin::Feature`].
+        id: u64,
+
+        /// Recorded [`gherkin::Feature`].
+        feature: gherkin::Feature,
+    },
+
+    /// Definition of a [`gherkin::Rule`] with its ID.
+    Rule {
+        /// ID of the [`gherkin::Rule`].
+        id: u64,
+
+        /// Recorded [`gherkin::Rule`].
+        rule: gherkin::Rule,
+    },
+
+    /// Definition of a [`gherkin::Scenario`] with its ID.
+    Scenario {
+        /// ID of the [`gherkin::Scenario`].
+        id: u64,
+
+        /// Recorded [`gherkin::Scenario`].
+        scenario: gherkin::Scenario,
+    },
+
+    /// Definition of a [`gherkin::Step`] with its ID.
+    Step {
+        /// ID of the [`gherkin::Step`].
+        id: u64,
+
+        /// Recorded [`gherkin::Step`].
+        step: gherkin::Step,
+    },
+
+    /// [`parser::Error::Parsing`] happened.
+    ParsingError {
+        /// Path of the [`gherkin::Feature`] failed to be parsed.
+        path: PathBuf,
+
+        /// Message of the error.
+        message: String,
+    },
+
+    /// [`parser::Error::ExampleExpansion`] happened.
+    ExampleExpansionError {
+        /// Position of the unknown template.
+        pos: gherkin::LineCol,
+
+        /// Name of the unknown template.
+        name: String,
+
+        /// Path to the `.feature` file, if present.
+        path: Option<PathBuf>,
+    },
+
+    /// [`event::Cucumber`] happened.
+    Event {
+        /// [`SystemTime`] when the event happened, if recorded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        at: Option<SystemTime>,
+
+        /// Recorded [`event::Cucumber`].
+        event: CucumberEvent,
+    },
+}
+
+impl Line {
+    /// Creates a new [`Line`] out of the provided [`parser::Error`].
+    fn error(err: &parser::Error) -> Self {
+        match err {
+            parser::Error::Parsing(e) => Self::ParsingError {
+                path: e.path().to_owned(),
+                message: e.message(),
+            },
+            parser::Error::ExampleExpansion(e) => Self::ExampleExpansionError {
+                pos: e.pos,
+                name: e.name.clone(),
+                path: e.path.clone(),
+            },
+        }
+    }
+}
+
+/// Recorded [`event::Cucumber`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CucumberEvent {
+    /// [`event::Cucumber::Started`].
+    Started,
+
+    /// [`event::Cucumber::Feature`] with the [`gherkin::Feature`] ID.
+    Feature(u64, FeatureEvent),
+
+    /// [`event::Cucumber::ParsingFinished`].
+    ParsingFinished {
+        /// Number of parsed [`gherkin::Feature`]s.
+        features: usize,
+
+        /// Number of parsed [`gherkin::Rule`]s.
+        rules: usize,
+
+        /// Number of parsed [`gherkin::Scenario`]s.
+        scenarios: usize,
+
+        /// Number of parsed [`gherkin::Step`]s.
+        steps: usize,
+
+        /// Number of happened [`Parser`] errors.
+        ///
+        /// [`Parser`]: crate::Parser
+        parser_errors: usize,
+
+        /// Number of filtered out [`gherkin::Scenario`]s.
+        #[serde(default)]
+        filtered_scenarios: usize,
+    },
+
+    /// [`event::Cucumber::DuplicateName`].
+    DuplicateName(DuplicateNameEvent),
+
+    /// [`event::Cucumber::Finished`].
+    Finished {
+        /// Number of passed [`gherkin::Scenario`]s.
+        passed: usize,
+
+        /// Number of failed [`gherkin::Scenario`]s.
+        failed: usize,
+
+        /// Number of skipped [`gherkin::Scenario`]s.
+        skipped: usize,
+
+        /// Number of happened [`gherkin::Scenario`]s' retries.
+        retried: usize,
+
+        /// Wall time of the whole execution.
+        duration: Duration,
+
+        /// Indicator whether the execution has exceeded its global timeout.
+        #[serde(default)]
+        timed_out: bool,
+    },
+}
+
+/// Recorded [`event::DuplicateName`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DuplicateNameEvent {
+    /// [`event::DuplicateName::Features`] with the [`gherkin::Feature`] IDs.
+    Features(Vec<u64>),
+
+    /// [`event::DuplicateName::Scenarios`] with the [`gherkin::Feature`] ID.
+    Scenarios {
+        /// ID of the [`gherkin::Feature`] containing the duplicates.
+        feature: u64,
+
+        /// Name shared by the [`gherkin::Scenario`]s.
+        name: String,
+
+        /// Positions of the [`gherkin::Scenario`]s.
+        positions: Vec<gherkin::LineCol>,
+    },
+}
+
+/// Recorded [`event::Feature`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FeatureEvent {
+    /// [`event::Feature::Started`].
+    Started,
+
+    /// [`event::Feature::Rule`] with the [`gherkin::Rule`] ID.
+    Rule(u64, RuleEvent),
+
+    /// [`event::Feature::Scenario`] with the [`gherkin::Scenario`] ID.
+    Scenario(u64, ScenarioEvent),
+
+    /// [`event::Feature::Aborted`].
+    Aborted,
+
+    /// [`event::Feature::Finished`].
+    Finished,
+}
+
+/// Recorded [`event::Rule`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RuleEvent {
+    /// [`event::Rule::Started`].
+    Started,
+
+    /// [`event::Rule::Scenario`] with the [`gherkin::Scenario`] ID.
+    Scenario(u64, ScenarioEvent),
+
+    /// [`event::Rule::Finished`].
+    Finished,
+}
+
+/// Recorded [`event::RetryableScenario`].
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ScenarioEvent {
+    /// Recorded [`event::Scenario`].
+    pub(crate) event: ScenarioEventKind,
+
+    /// Current attempt and left [`event::Retries`], if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) retries: Option<(usize, usize)>,
+}
+
+/// Recorded [`event::Scenario`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScenarioEventKind {
+    /// [`event::Scenario::Started`] with the [`event::ScenarioId`].
+    Started(u64),
+
+    /// [`event::Scenario::Hook`].
+    Hook(HookType, HookEvent),
+
+    /// [`event::Scenario::Background`] with the [`gherkin::Step`] ID.
+    Background(u64, StepEvent),
+
+    /// [`event::Scenario::RuleBackground`] with the [`gherkin::Step`] ID.
+    RuleBackground(u64, StepEvent),
+
+    /// [`event::Scenario::Step`] with the [`gherkin::Step`] ID.
+    Step(u64, StepEvent),
+
+    /// [`event::Scenario::Log`].
+    Log {
+        /// Fully formatted message.
+        message: String,
+
+        /// Structured fields, if any.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<(String, LogValue)>,
+    },
+
+    /// [`event::Scenario::Artifact`].
+    Artifact(PathBuf),
+
+    /// [`event::Scenario::Output`].
+    Output {
+        /// Captured stdout.
+        stdout: String,
+
+        /// Captured stderr.
+        stderr: String,
+    },
+
+    /// [`event::Scenario::Stalled`].
+    Stalled {
+        /// ID of the stuck [`gherkin::Step`].
+        step: u64,
+
+        /// Indicator whether the stuck [`gherkin::Step`] is a
+        /// [`gherkin::Background`] one.
+        is_background: bool,
+
+        /// Time passed since the [`gherkin::Step`] has been started.
+        elapsed: Duration,
+
+        /// Snapshot of the [`World`] taken right before the [`gherkin::Step`]
+        /// has been started.
+        ///
+        /// [`World`]: crate::World
+        world: Option<String>,
+    },
+
+    /// [`event::Scenario::BudgetExceeded`].
+    BudgetExceeded {
+        /// Time budget of the [`gherkin::Scenario`].
+        limit: Duration,
+
+        /// Time the [`gherkin::Scenario`] execution actually took.
+        elapsed: Duration,
+
+        /// Indicator whether exceeding the budget fails the
+        /// [`gherkin::Scenario`].
+        failed: bool,
+    },
+
+    /// [`event::Scenario::Wip`].
+    Wip {
+        /// Indicator whether this is an [`event::Wip::Passed`] event.
+        passed: bool,
+    },
+
+    /// [`event::Scenario::NotRun`].
+    NotRun,
+
+    /// [`event::Scenario::Finished`].
+    Finished,
+}
+
+/// Recorded [`event::HookType`].
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum HookType {
+    /// [`event::HookType::Before`].
+    Before,
+
+    /// [`event::HookType::After`].
+    After,
+}
+
+/// Recorded [`event::LogValue`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LogValue {
+    /// [`event::LogValue::Bool`].
+    Bool(bool),
+
+    /// [`event::LogValue::I64`].
+    I64(i64),
+
+    /// [`event::LogValue::U64`].
+    U64(u64),
+
+    /// [`event::LogValue::F64`].
+    F64(f64),
+
+    /// [`event::LogValue::Str`].
+    Str(String),
+}
+
+impl From<event::LogValue> for LogValue {
+    fn from(val: event::LogValue) -> Self {
+        match val {
+            event::LogValue::Bool(b) => Self::Bool(b),
+            event::LogValue::I64(i) => Self::I64(i),
+            event::LogValue::U64(u) => Self::U64(u),
+            event::LogValue::F64(f) => Self::F64(f),
+            event::LogValue::Str(s) => Self::Str(s),
+        }
+    }
+}
+
+impl From<LogValue> for event::LogValue {
+    fn from(val: LogValue) -> Self {
+        match val {
+            LogValue::Bool(b) => Self::Bool(b),
+            LogValue::I64(i) => Self::I64(i),
+            LogValue::U64(u) => Self::U64(u),
+            LogValue::F64(f) => Self::F64(f),
+            LogValue::Str(s) => Self::Str(s),
+        }
+    }
+}
+
+/// Recorded [`event::Hook`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum HookEvent {
+    /// [`event::Hook::Started`].
+    Started,
+
+    /// [`event::Hook::Passed`].
+    Passed,
+
+    /// [`event::Hook::Failed`].
+    Failed {
+        /// Message of the panic.
+        message: String,
+    },
+
+    /// [`event::Hook::Ignored`].
+    Ignored {
+        /// Message of the panic.
+        message: String,
+    },
+}
+
+/// Recorded [`event::Step`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StepEvent {
+    /// [`event::Step::Started`].
+    Started,
+
+    /// [`event::Step::Skipped`].
+    Skipped {
+        /// [`Duration`] of the [`gherkin::Step`].
+        duration: Duration,
+    },
+
+    /// [`event::Step::Passed`].
+    Passed {
+        /// [`Captures`] of the matched [`Regex`].
+        ///
+        /// [`Regex`]: regex::Regex
+        captures: Captures,
+
+        /// [`Location`] of the matched step function.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        location: Option<Location>,
+
+        /// [`Duration`] of the [`gherkin::Step`] execution.
+        duration: Duration,
+    },
+
+    /// [`event::Step::Failed`].
+    Failed {
+        /// [`Captures`] of the matched [`Regex`], if any.
+        ///
+        /// [`Regex`]: regex::Regex
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        captures: Option<Captures>,
+
+        /// [`Location`] of the matched step function, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        location: Option<Location>,
+
+        /// Recorded [`event::StepError`].
+        error: StepError,
+
+        /// [`Duration`] of the [`gherkin::Step`] execution.
+        duration: Duration,
+    },
+}
+
+/// Recorded [`regex::CaptureLocations`] as spans of every capturing group.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub(crate) struct Captures(pub(crate) Vec<Option<(usize, usize)>>);
+
+impl From<&regex::CaptureLocations> for Captures {
+    fn from(locs: &regex::CaptureLocations) -> Self {
+        Self((0..locs.len()).map(|i| locs.get(i)).collect())
+    }
+}
+
+/// Recorded [`step::Location`].
+#[derive(Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) struct Location {
+    /// Path to the file where the step function is located.
+    pub(crate) path: String,
+
+    /// Line of the file where the step function is located.
+    pub(crate) line: u32,
+
+    /// Column of the file where the step function is located.
+    pub(crate) column: u32,
+}
+
+impl From<step::Location> for Location {
+    fn from(loc: step::Location) -> Self {
+        Self {
+            path: loc.path.to_owned(),
+            line: loc.line,
+            column: loc.column,
+        }
+    }
+}
+
+/// Recorded [`event::StepError`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StepError {
+    /// [`event::StepError::NotFound`].
+    NotFound,
+
+    /// [`event::StepError::AmbiguousMatch`] with the possible [`Regex`]es
+    /// and [`Location`]s.
+    ///
+    /// [`Regex`]: regex::Regex
+    AmbiguousMatch(Vec<(String, Option<Location>)>),
+
+    /// [`event::StepError::KeywordMismatch`] with the matching
+    /// [`gherkin::StepType`].
+    KeywordMismatch(gherkin::StepType),
+
+    /// [`event::StepError::Panic`] with the panic message.
+    Panic(String),
+
+    /// [`event::StepError::Categorized`] with its [`FailureCategory`].
+    Categorized(FailureCategory, Box<Self>),
+}
+
+impl From<&event::StepError> for StepError {
+    fn from(err: &event::StepError) -> Self {
+        match err {
+            event::StepError::NotFound => Self::NotFound,
+            event::StepError::AmbiguousMatch(e) => Self::AmbiguousMatch(
+                e.possible_matches
+                    .iter()
+                    .map(|m| {
+                        (m.regex.to_string(), m.location.map(Location::from))
+                    })
+                    .collect(),
+            ),
+            event::StepError::KeywordMismatch(ty) => Self::KeywordMismatch(*ty),
+            event::StepError::Panic(info, _) => {
+                Self::Panic(coerce_error(info).into_owned())
+            }
+            event::StepError::Categorized(category, e) => Self::Categorized(
+                FailureCategory::from(*category),
+                Box::new(Self::from(&**e)),
+            ),
+        }
+    }
+}
+
+/// Recorded [`event::FailureCategory`].
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FailureCategory {
+    /// [`event::FailureCategory::Assertion`].
+    Assertion,
+
+    /// [`event::FailureCategory::Infrastructure`].
+    Infrastructure,
+
+    /// [`event::FailureCategory::Timeout`].
+    Timeout,
+
+    /// [`event::FailureCategory::Undefined`].
+    Undefined,
+}
+
+impl From<event::FailureCategory> for FailureCategory {
+    fn from(category: event::FailureCategory) -> Self {
+        match category {
+            event::FailureCategory::Assertion => Self::Assertion,
+            event::FailureCategory::Infrastructure => Self::Infrastructure,
+            event::FailureCategory::Timeout => Self::Timeout,
+            event::FailureCategory::Undefined => Self::Undefined,
+        }
+    }
+}
+
+impl From<FailureCategory> for event::FailureCategory {
+    fn from(category: FailureCategory) -> Self {
+        match category {
+            FailureCategory::Assertion => Self::Assertion,
+            FailureCategory::Infrastructure => Self::Infrastructure,
+            FailureCategory::Timeout => Self::Timeout,
+            FailureCategory::Undefined => Self::Undefined,
+        }
+    }
+}
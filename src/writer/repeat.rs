@@ -10,28 +10,97 @@
 
 //! [`Writer`]-wrapper for re-outputting events at the end of an output.
 
-use std::mem;
+use std::{mem, path::PathBuf};
 
 use derive_more::with_trait::Deref;
+use linked_hash_map::LinkedHashMap;
 
-use crate::{event, parser, writer, Event, World, Writer};
+use crate::{
+    event::{self, Source},
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
+};
 
 /// Alias for a [`fn`] predicate deciding whether an event should be
 /// re-outputted or not.
 pub type FilterEvent<W> =
     fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool;
 
+/// Alias for a whole path of a [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioPath = (
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+);
+
+/// Outcome of a [`Scenario`] attempt.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Outcome {
+    /// Nothing has failed or been skipped.
+    Passed,
+
+    /// Some [`Step`] has been [`Skipped`].
+    ///
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    Skipped,
+
+    /// Some [`Step`] or hook has [`Failed`], or the time budget has been
+    /// exceeded.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Step`]: gherkin::Step
+    Failed,
+}
+
+impl Outcome {
+    /// Returns the [`Outcome`] implied by the provided [`event::Scenario`].
+    const fn of<W>(ev: &event::Scenario<W>) -> Self {
+        use event::{Hook, Scenario, Step};
+
+        if matches!(
+            ev,
+            Scenario::Step(_, Step::Failed(..))
+                | Scenario::Background(_, Step::Failed(..))
+                | Scenario::RuleBackground(_, Step::Failed(..))
+                | Scenario::Hook(_, Hook::Failed(..))
+                | Scenario::BudgetExceeded(event::Budget { failed: true, .. })
+                | Scenario::Wip(event::Wip::Passed),
+        ) {
+            Self::Failed
+        } else if matches!(
+            ev,
+            Scenario::Step(_, Step::Skipped(_))
+                | Scenario::Background(_, Step::Skipped(_))
+                | Scenario::RuleBackground(_, Step::Skipped(_)),
+        ) {
+            Self::Skipped
+        } else {
+            Self::Passed
+        }
+    }
+}
+
 /// Wrapper for a [`Writer`] implementation for re-outputting events at the end
 /// of an output, based on a filter predicated.
 ///
-/// Useful for re-outputting [skipped] or [failed] [`Step`]s.
+/// Useful for re-outputting [skipped] or [failed] [`Step`]s, or the whole
+/// [failed][1] or [skipped][2] [`Scenario`]s.
 ///
 /// An underlying [`Writer`] has to be [`NonTransforming`].
 ///
 /// [failed]: crate::WriterExt::repeat_failed
 /// [skipped]: crate::WriterExt::repeat_skipped
 /// [`NonTransforming`]: writer::NonTransforming
+/// [`Scenario`]: gherkin::Scenario
 /// [`Step`]: gherkin::Step
+/// [1]: Repeat::failed_scenarios()
+/// [2]: Repeat::skipped_scenarios()
 #[derive(Debug, Deref)]
 pub struct Repeat<W, Wr, F = FilterEvent<W>> {
     /// Original [`Writer`].
@@ -43,6 +112,11 @@ pub struct Repeat<W, Wr, F = FilterEvent<W>> {
 
     /// Buffer of collected events for re-outputting.
     events: Vec<parser::Result<Event<event::Cucumber<W>>>>,
+
+    /// Collector of the whole [`Scenario`]s to be re-outputted, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: Option<Scenarios<W>>,
 }
 
 // Implemented manually to omit redundant `World: Clone` trait bound, imposed by
@@ -53,6 +127,7 @@ impl<World, Wr: Clone, F: Clone> Clone for Repeat<World, Wr, F> {
             writer: self.writer.clone(),
             filter: self.filter.clone(),
             events: self.events.clone(),
+            scenarios: self.scenarios.clone(),
         }
     }
 }
@@ -73,9 +148,12 @@ where
         if (self.filter)(&event) {
             self.events.push(event.clone());
         }
+        if let Some(scenarios) = &mut self.scenarios {
+            scenarios.collect(&event);
+        }
 
         let is_finished =
-            matches!(event.as_deref(), Ok(event::Cucumber::Finished));
+            matches!(event.as_deref(), Ok(event::Cucumber::Finished { .. }),);
 
         self.writer.handle_event(event, cli).await;
 
@@ -83,8 +161,20 @@ where
             for ev in mem::take(&mut self.events) {
                 self.writer.handle_event(ev, cli).await;
             }
+            let scenarios = self.scenarios.as_mut().map(Scenarios::take);
+            for ev in scenarios.into_iter().flatten() {
+                self.writer.handle_event(ev, cli).await;
+            }
         }
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.writer.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.writer.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -129,6 +219,44 @@ where
         self.writer.hook_errors()
     }
 
+    fn budget_errors(&self) -> usize {
+        self.writer.budget_errors()
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.writer.wip_errors()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.writer.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.writer.passed_scenarios()
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.writer.skipped_scenarios()
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.writer.failed_scenarios()
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.writer.flaky_scenarios()
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        self.writer.scenarios_by_feature()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        self.writer.scenarios_by_tag()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
@@ -149,6 +277,7 @@ impl<W, Wr, F> Repeat<W, Wr, F> {
             writer,
             filter,
             events: Vec::new(),
+            scenarios: None,
         }
     }
 }
@@ -176,10 +305,14 @@ impl<W, Wr> Repeat<W, Wr> {
                             Rule::Scenario(
                                 _,
                                 RetryableScenario {
-                                    event: Scenario::Step(_, Step::Skipped)
+                                    event: Scenario::Step(_, Step::Skipped(_))
                                         | Scenario::Background(
                                             _,
-                                            Step::Skipped
+                                            Step::Skipped(_)
+                                        )
+                                        | Scenario::RuleBackground(
+                                            _,
+                                            Step::Skipped(_)
                                         ),
                                     ..
                                 }
@@ -187,8 +320,12 @@ impl<W, Wr> Repeat<W, Wr> {
                         ) | Feature::Scenario(
                             _,
                             RetryableScenario {
-                                event: Scenario::Step(_, Step::Skipped)
-                                    | Scenario::Background(_, Step::Skipped),
+                                event: Scenario::Step(_, Step::Skipped(_))
+                                    | Scenario::Background(_, Step::Skipped(_))
+                                    | Scenario::RuleBackground(
+                                        _,
+                                        Step::Skipped(_)
+                                    ),
                                 ..
                             }
                         )
@@ -196,6 +333,7 @@ impl<W, Wr> Repeat<W, Wr> {
                 )
             },
             events: Vec::new(),
+            scenarios: None,
         }
     }
 
@@ -227,6 +365,10 @@ impl<W, Wr> Repeat<W, Wr> {
                                             _,
                                             Step::Failed(..),
                                         )
+                                        | Scenario::RuleBackground(
+                                            _,
+                                            Step::Failed(..),
+                                        )
                                         | Scenario::Hook(_, Hook::Failed(..)),
                                     ..
                                 }
@@ -236,6 +378,10 @@ impl<W, Wr> Repeat<W, Wr> {
                             RetryableScenario {
                                 event: Scenario::Step(_, Step::Failed(..))
                                     | Scenario::Background(_, Step::Failed(..))
+                                    | Scenario::RuleBackground(
+                                        _,
+                                        Step::Failed(..),
+                                    )
                                     | Scenario::Hook(_, Hook::Failed(..)),
                                 ..
                             },
@@ -244,6 +390,62 @@ impl<W, Wr> Repeat<W, Wr> {
                 )
             },
             events: Vec::new(),
+            scenarios: None,
+        }
+    }
+
+    /// Creates a [`Writer`] for re-outputting the whole [`Scenario`]s (all
+    /// their events, including the ones of previous retry attempts) failed
+    /// in the end, at the end of an output.
+    ///
+    /// Re-outputted [`Scenario`]s are grouped by their [`Feature`]s and
+    /// [`Rule`]s, surrounded with the [`Started`] and [`Finished`] events of
+    /// those.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Finished`]: event::Feature::Finished
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Started`]: event::Feature::Started
+    #[expect( // intentional
+        clippy::same_name_method,
+        reason = "constructor, unrelated to `Stats::failed_scenarios()`"
+    )]
+    #[must_use]
+    pub fn failed_scenarios(writer: Wr) -> Self {
+        Self {
+            writer,
+            filter: |_| false,
+            events: Vec::new(),
+            scenarios: Some(Scenarios::new(Outcome::Failed)),
+        }
+    }
+
+    /// Creates a [`Writer`] for re-outputting the whole [`Scenario`]s (all
+    /// their events) having [`Skipped`] [`Step`]s, at the end of an output.
+    ///
+    /// Re-outputted [`Scenario`]s are grouped by their [`Feature`]s and
+    /// [`Rule`]s, surrounded with the [`Started`] and [`Finished`] events of
+    /// those.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Finished`]: event::Feature::Finished
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Started`]: event::Feature::Started
+    /// [`Step`]: gherkin::Step
+    #[expect( // intentional
+        clippy::same_name_method,
+        reason = "constructor, unrelated to `Stats::skipped_scenarios()`"
+    )]
+    #[must_use]
+    pub fn skipped_scenarios(writer: Wr) -> Self {
+        Self {
+            writer,
+            filter: |_| false,
+            events: Vec::new(),
+            scenarios: Some(Scenarios::new(Outcome::Skipped)),
         }
     }
 
@@ -253,3 +455,138 @@ impl<W, Wr> Repeat<W, Wr> {
         &self.writer
     }
 }
+
+/// Collector of the whole [`Scenario`]s having the required [`Outcome`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug)]
+struct Scenarios<W> {
+    /// [`Outcome`] of the [`Scenario`]s to be re-outputted.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    outcome: Outcome,
+
+    /// Buffer of collected events of the [`Scenario`]s (of all their
+    /// attempts), along with the [`Outcome`] of their last attempt.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    events:
+        LinkedHashMap<ScenarioPath, (Vec<Event<event::Cucumber<W>>>, Outcome)>,
+}
+
+// Implemented manually to omit redundant `World: Clone` trait bound, imposed by
+// `#[derive(Clone)]`.
+impl<W> Clone for Scenarios<W> {
+    fn clone(&self) -> Self {
+        Self {
+            outcome: self.outcome,
+            events: self.events.clone(),
+        }
+    }
+}
+
+impl<W> Scenarios<W> {
+    /// Creates a new [`Scenarios`] collector of the ones having the provided
+    /// [`Outcome`].
+    fn new(outcome: Outcome) -> Self {
+        Self {
+            outcome,
+            events: LinkedHashMap::new(),
+        }
+    }
+
+    /// Collects the provided event, if it belongs to a [`Scenario`], dropping
+    /// the collected [`Scenario`]s which cannot have the required [`Outcome`]
+    /// anymore.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn collect(&mut self, event: &parser::Result<Event<event::Cucumber<W>>>) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        let Ok(full) = event else {
+            return;
+        };
+        let Cucumber::Feature(feature, ev) = &**full else {
+            return;
+        };
+        let (rule, scenario, ev) = match ev {
+            Feature::Rule(r, Rule::Scenario(sc, ev)) => (Some(r), sc, ev),
+            Feature::Scenario(sc, ev) => (None, sc, ev),
+            Feature::Started
+            | Feature::Aborted
+            | Feature::Finished
+            | Feature::Rule(_, Rule::Started | Rule::Finished) => return,
+        };
+        let path = (feature.clone(), rule.cloned(), scenario.clone());
+
+        let (events, outcome) = self
+            .events
+            .entry(path.clone())
+            .or_insert_with(|| (Vec::new(), Outcome::Passed));
+        if matches!(ev.event, Scenario::Started(_)) {
+            // Only the last attempt of a retried `Scenario` matters.
+            *outcome = Outcome::Passed;
+        }
+        *outcome = (*outcome).max(Outcome::of(&ev.event));
+        events.push(full.clone());
+
+        // Only `Failed` `Scenario`s having retries left may change their
+        // outcome, so the rest of them may be dropped right away.
+        let outcome = *outcome;
+        let will_retry = outcome == Outcome::Failed
+            && ev.retries.is_some_and(|r| r.left > 0);
+        if matches!(ev.event, Scenario::Finished)
+            && self.outcome != outcome
+            && !will_retry
+        {
+            drop(self.events.remove(&path));
+        }
+    }
+
+    /// Takes all the collected [`Scenario`]s having the required [`Outcome`],
+    /// grouping them by their [`Feature`]s and [`Rule`]s, and surrounding with
+    /// the [`Feature`]s and [`Rule`]s events.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    fn take(&mut self) -> Vec<parser::Result<Event<event::Cucumber<W>>>> {
+        let mut grouped = LinkedHashMap::<_, LinkedHashMap<_, Vec<_>>>::new();
+        for ((feature, rule, _), (events, outcome)) in
+            mem::take(&mut self.events)
+        {
+            if outcome == self.outcome {
+                grouped
+                    .entry(feature)
+                    .or_default()
+                    .entry(rule)
+                    .or_default()
+                    .extend(events);
+            }
+        }
+
+        let mut out = Vec::new();
+        for (feature, rules) in grouped {
+            out.push(Event::new(event::Cucumber::feature_started(
+                feature.clone(),
+            )));
+            for (rule, events) in rules {
+                if let Some(r) = &rule {
+                    out.push(Event::new(event::Cucumber::rule_started(
+                        feature.clone(),
+                        r.clone(),
+                    )));
+                }
+                out.extend(events);
+                if let Some(r) = rule {
+                    out.push(Event::new(event::Cucumber::rule_finished(
+                        feature.clone(),
+                        r,
+                    )));
+                }
+            }
+            out.push(Event::new(event::Cucumber::feature_finished(feature)));
+        }
+        out.into_iter().map(Ok).collect()
+    }
+}
@@ -0,0 +1,923 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Report Portal][1] [`Writer`] implementation.
+//!
+//! [1]: https://reportportal.io
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    time::{Duration, SystemTime},
+};
+
+use derive_more::with_trait::Debug as DebugExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser,
+    writer::{self, basic::coerce_error, discard, Ext as _, Lexicon},
+    Event, World, Writer,
+};
+
+/// [Report Portal][1] [`Writer`] implementation pushing launches, items and
+/// logs to a [Report Portal][1] server via its [REST API][2] as the run
+/// progresses.
+///
+/// Every [`Feature`] and [`Rule`] is reported as a suite item, every
+/// [`Scenario`] as a scenario item, and every [`Step`] and hook as a nested
+/// step item (not counted in statistics). Tags are reported as attributes
+/// (`@key:value` tags as key-value ones), while retried [`Scenario`]s are
+/// reported as [Report Portal][1] retries of their previous attempts.
+///
+/// __NOTE__: Requests are sent synchronously, blocking the executor thread.
+///
+/// # Ordering
+///
+/// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
+/// a [`writer::Normalize`], otherwise will panic in runtime as won't be able to
+/// form a correct items tree.
+///
+/// # Panics
+///
+/// If any request to the [Report Portal][1] server fails.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Normalized`]: writer::Normalized
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [1]: https://reportportal.io
+/// [2]: https://reportportal.io/docs/log-data-in-reportportal/ImportDataToReportPortal/ReportingAPI
+#[derive(Clone, DebugExt)]
+pub struct ReportPortal {
+    /// [`ureq::Agent`] to send requests with.
+    agent: ureq::Agent,
+
+    /// URL of the [Report Portal][1] project API
+    /// (`{endpoint}/api/v1/{project}`).
+    ///
+    /// [1]: https://reportportal.io
+    url: String,
+
+    /// API key to authorize requests with.
+    #[debug(ignore)]
+    api_key: String,
+
+    /// Name of the reported launch.
+    launch_name: String,
+
+    /// Description of the reported launch, if any.
+    launch_description: Option<String>,
+
+    /// [`Attribute`]s of the reported launch.
+    launch_attributes: Vec<Attribute>,
+
+    /// ID of the started launch.
+    launch: Option<String>,
+
+    /// IDs of the started [`gherkin::Feature`] items.
+    features: HashMap<Source<gherkin::Feature>, String>,
+
+    /// IDs of the started [`gherkin::Rule`] items.
+    rules: HashMap<Source<gherkin::Rule>, String>,
+
+    /// IDs of the last attempts of the [`gherkin::Scenario`] items, to report
+    /// their retries against.
+    attempts: HashMap<Source<gherkin::Scenario>, String>,
+
+    /// Currently running [`gherkin::Scenario`] item.
+    scenario: Option<ScenarioItem>,
+
+    /// ID of the currently running [`gherkin::Step`] or hook item.
+    step: Option<String>,
+
+    /// [`Lexicon`] to name hook items with.
+    lexicon: Lexicon,
+}
+
+/// State of the currently running [`gherkin::Scenario`] item.
+#[derive(Clone, Debug)]
+struct ScenarioItem {
+    /// ID of the item.
+    id: String,
+
+    /// [`Status`] of the item, accumulated from its [`gherkin::Step`]s and
+    /// hooks.
+    status: Status,
+}
+
+impl<W: World + Debug> Writer<W> for ReportPortal {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.map(Event::split) {
+            Err(err) => self.handle_error(&err),
+            Ok((Cucumber::Started, meta)) => self.start_launch(meta.at),
+            Ok((Cucumber::Feature(f, ev), meta)) => match ev {
+                Feature::Started => {
+                    let id =
+                        self.start_item(None, &StartItem::feature(&f, meta.at));
+                    drop(self.features.insert(f, id));
+                }
+                Feature::Rule(r, Rule::Started) => {
+                    let parent = self.features.get(&f).cloned();
+                    let id = self.start_item(
+                        parent.as_deref(),
+                        &StartItem::rule(&r, meta.at),
+                    );
+                    drop(self.rules.insert(r, id));
+                }
+                Feature::Rule(r, Rule::Scenario(sc, ev)) => {
+                    let parent = self.rules.get(&r).cloned();
+                    self.handle_scenario_event(
+                        &f,
+                        parent.as_deref(),
+                        sc,
+                        ev,
+                        meta.at,
+                    );
+                }
+                Feature::Rule(r, Rule::Finished) => {
+                    if let Some(id) = self.rules.remove(&r) {
+                        self.finish_item(&id, None, meta.at);
+                    }
+                }
+                Feature::Scenario(sc, ev) => {
+                    let parent = self.features.get(&f).cloned();
+                    self.handle_scenario_event(
+                        &f,
+                        parent.as_deref(),
+                        sc,
+                        ev,
+                        meta.at,
+                    );
+                }
+                Feature::Aborted => {}
+                Feature::Finished => {
+                    if let Some(id) = self.features.remove(&f) {
+                        self.finish_item(&id, None, meta.at);
+                    }
+                }
+            },
+            Ok((
+                Cucumber::ParsingFinished { .. } | Cucumber::DuplicateName(_),
+                _,
+            )) => {}
+            Ok((Cucumber::Finished { .. }, meta)) => {
+                self.finish_launch(meta.at);
+            }
+        }
+    }
+}
+
+impl writer::NonTransforming for ReportPortal {}
+
+impl ReportPortal {
+    /// Creates a new [`Normalized`] [`ReportPortal`] [`Writer`] reporting into
+    /// the `project` of the [Report Portal][1] server at the given `endpoint`
+    /// (like `https://reportportal.example.com`), authorized with the provided
+    /// `api_key`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://reportportal.io
+    #[must_use]
+    pub fn new<W: Debug + World>(
+        endpoint: impl AsRef<str>,
+        project: impl AsRef<str>,
+        api_key: impl Into<String>,
+    ) -> writer::Normalize<W, Self> {
+        Self::raw(endpoint, project, api_key).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`ReportPortal`] [`Writer`] reporting
+    /// into the `project` of the [Report Portal][1] server at the given
+    /// `endpoint`, and suitable for feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    /// [1]: https://reportportal.io
+    #[must_use]
+    pub fn for_tee(
+        endpoint: impl AsRef<str>,
+        project: impl AsRef<str>,
+        api_key: impl Into<String>,
+    ) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(endpoint, project, api_key)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`ReportPortal`] [`Writer`]
+    /// reporting into the `project` of the [Report Portal][1] server at the
+    /// given `endpoint`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`ReportPortal::new()`] which creates an already [`Normalized`] version
+    /// of [`ReportPortal`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://reportportal.io
+    #[must_use]
+    pub fn raw(
+        endpoint: impl AsRef<str>,
+        project: impl AsRef<str>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            agent: ureq::Agent::new(),
+            url: format!(
+                "{}/api/v1/{}",
+                endpoint.as_ref().trim_end_matches('/'),
+                project.as_ref(),
+            ),
+            api_key: api_key.into(),
+            launch_name: "cucumber".into(),
+            launch_description: None,
+            launch_attributes: vec![],
+            launch: None,
+            features: HashMap::new(),
+            rules: HashMap::new(),
+            attempts: HashMap::new(),
+            scenario: None,
+            step: None,
+            lexicon: Lexicon::new(),
+        }
+    }
+
+    /// Sets the name of the reported launch.
+    ///
+    /// Default is `cucumber`.
+    #[must_use]
+    pub fn launch_name(mut self, name: impl Into<String>) -> Self {
+        self.launch_name = name.into();
+        self
+    }
+
+    /// Sets the description of the reported launch.
+    #[must_use]
+    pub fn launch_description(
+        mut self,
+        description: impl Into<String>,
+    ) -> Self {
+        self.launch_description = Some(description.into());
+        self
+    }
+
+    /// Sets the attributes of the reported launch from the provided tags
+    /// (`key:value` tags becoming key-value attributes).
+    #[must_use]
+    pub fn launch_attributes<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.launch_attributes = tags
+            .into_iter()
+            .map(|t| Attribute::from_tag(t.as_ref()))
+            .collect();
+        self
+    }
+
+    /// Sets the timeout of every request to the [Report Portal][1] server.
+    ///
+    /// [1]: https://reportportal.io
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    /// Sets the [`Lexicon`] to name hook items with.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        parent: Option<&str>,
+        scenario: Source<gherkin::Scenario>,
+        ev: event::RetryableScenario<W>,
+        at: SystemTime,
+    ) {
+        use event::{Hook, HookType, Scenario};
+
+        match ev.event {
+            Scenario::Started(_) => {
+                let retry_of = ev
+                    .retries
+                    .filter(|r| r.current > 0)
+                    .and_then(|_| self.attempts.get(&scenario).cloned());
+                let item =
+                    StartItem::scenario(feature, &scenario, retry_of, at);
+                let id = self.start_item(parent, &item);
+                drop(self.attempts.insert(scenario, id.clone()));
+                self.scenario = Some(ScenarioItem {
+                    id,
+                    status: Status::Passed,
+                });
+            }
+            Scenario::Hook(which, ev) => {
+                let name = self.lexicon.hook(which).to_owned();
+                let ty = match which {
+                    HookType::Before => ItemType::BeforeMethod,
+                    HookType::After => ItemType::AfterMethod,
+                };
+                match ev {
+                    Hook::Started => self.start_step(name, ty, at),
+                    Hook::Passed => self.finish_step(Status::Passed, at),
+                    Hook::Ignored(info) => {
+                        self.log(LogLevel::Warn, &coerce_error(&info), at);
+                        self.finish_step(Status::Passed, at);
+                    }
+                    Hook::Failed(_, info) => {
+                        self.log(LogLevel::Error, &coerce_error(&info), at);
+                        self.finish_step(Status::Failed, at);
+                    }
+                }
+            }
+            Scenario::Background(st, ev)
+            | Scenario::RuleBackground(st, ev)
+            | Scenario::Step(st, ev) => {
+                self.handle_step_event(&st, ev, at);
+            }
+            Scenario::Log(log) => {
+                self.log(LogLevel::Info, &log.message, at);
+            }
+            Scenario::Output(out) => {
+                if !out.stdout.is_empty() {
+                    self.log(LogLevel::Info, &out.stdout, at);
+                }
+                if !out.stderr.is_empty() {
+                    self.log(LogLevel::Warn, &out.stderr, at);
+                }
+            }
+            Scenario::Artifact(path) => {
+                let msg = format!("Artifact: {}", path.display());
+                self.log(LogLevel::Info, &msg, at);
+            }
+            Scenario::Stalled(stall) => {
+                self.log(LogLevel::Warn, &stall.to_string(), at);
+            }
+            Scenario::BudgetExceeded(budget) => {
+                if budget.failed {
+                    self.log(LogLevel::Error, &budget.to_string(), at);
+                    if let Some(sc) = self.scenario.as_mut() {
+                        sc.status = Status::Failed;
+                    }
+                } else {
+                    self.log(LogLevel::Warn, &budget.to_string(), at);
+                }
+            }
+            Scenario::Wip(wip) => {
+                if wip == event::Wip::Passed {
+                    self.log(LogLevel::Error, &wip.to_string(), at);
+                    if let Some(sc) = self.scenario.as_mut() {
+                        sc.status = Status::Failed;
+                    }
+                } else {
+                    self.log(LogLevel::Info, &wip.to_string(), at);
+                }
+            }
+            Scenario::NotRun => {
+                self.log(
+                    LogLevel::Warn,
+                    "Scenario has not been run, as the execution has been \
+                     stopped",
+                    at,
+                );
+                if let Some(sc) = self.scenario.as_mut() {
+                    sc.status = Status::Skipped;
+                }
+            }
+            Scenario::Finished => {
+                if let Some(sc) = self.scenario.take() {
+                    self.finish_item(&sc.id, Some(sc.status), at);
+                }
+            }
+        }
+    }
+
+    /// Handles the given [`event::Step`].
+    fn handle_step_event<W>(
+        &mut self,
+        step: &gherkin::Step,
+        ev: event::Step<W>,
+        at: SystemTime,
+    ) {
+        match ev {
+            event::Step::Started => {
+                let name = format!("{}{}", step.keyword, step.value);
+                self.start_step(name, ItemType::Step, at);
+            }
+            event::Step::Passed(..) => self.finish_step(Status::Passed, at),
+            event::Step::Skipped(_) => self.finish_step(Status::Skipped, at),
+            event::Step::Failed(_, loc, _, err, _) => {
+                let loc = loc
+                    .map(|l| {
+                        format!("Matched: {}:{}:{}\n", l.path, l.line, l.column)
+                    })
+                    .unwrap_or_default();
+                self.log(LogLevel::Error, &format!("{loc}{err}"), at);
+                self.finish_step(Status::Failed, at);
+            }
+        }
+    }
+
+    /// Handles the given [`parser::Error`] by reporting it as a failed item.
+    fn handle_error(&self, err: &parser::Error) {
+        let at = SystemTime::now();
+        let path = match err {
+            parser::Error::Parsing(e) => Some(e.path()),
+            parser::Error::ExampleExpansion(e) => e.path.as_deref(),
+        };
+        let name = format!(
+            "Feature: {}",
+            path.map_or_else(|| "unknown".into(), |p| p.display().to_string()),
+        );
+        let item = StartItem {
+            name,
+            start_time: Timestamp::from(at),
+            r#type: ItemType::Test,
+            launch_uuid: self.launch_id().into(),
+            attributes: vec![],
+            description: None,
+            code_ref: None,
+            test_case_id: None,
+            has_stats: true,
+            retry: false,
+            retry_of: None,
+        };
+        let id = self.start_item(None, &item);
+        drop(self.send(
+            "POST",
+            "/log",
+            &Log {
+                launch_uuid: self.launch_id().into(),
+                item_uuid: id.clone(),
+                time: Timestamp::from(at),
+                message: err.to_string(),
+                level: LogLevel::Error,
+            },
+        ));
+        self.finish_item(&id, Some(Status::Failed), at);
+    }
+
+    /// Starts the launch.
+    fn start_launch(&mut self, at: SystemTime) {
+        let launch = StartLaunch {
+            name: self.launch_name.clone(),
+            start_time: Timestamp::from(at),
+            description: self.launch_description.clone(),
+            attributes: self.launch_attributes.clone(),
+        };
+        self.launch = Some(self.send("POST", "/launch", &launch).id);
+    }
+
+    /// Finishes the launch.
+    fn finish_launch(&self, at: SystemTime) {
+        let path = format!("/launch/{}/finish", self.launch_id());
+        let finish = FinishLaunch {
+            end_time: Timestamp::from(at),
+        };
+        drop(self.send("PUT", &path, &finish));
+    }
+
+    /// Returns the ID of the started launch.
+    fn launch_id(&self) -> &str {
+        self.launch
+            .as_deref()
+            .unwrap_or_else(|| panic!("no `Started` event for launch"))
+    }
+
+    /// Starts the given item as a child of the `parent` one, or as a root
+    /// one, returning its ID.
+    fn start_item(&self, parent: Option<&str>, item: &StartItem) -> String {
+        let item = StartItem {
+            launch_uuid: self.launch_id().into(),
+            ..item.clone()
+        };
+        let path =
+            parent.map_or_else(|| "/item".into(), |p| format!("/item/{p}"));
+        self.send("POST", &path, &item).id
+    }
+
+    /// Finishes the given item with the provided [`Status`], or with the one
+    /// calculated from its children.
+    fn finish_item(&self, id: &str, status: Option<Status>, at: SystemTime) {
+        let finish = FinishItem {
+            end_time: Timestamp::from(at),
+            status,
+            launch_uuid: self.launch_id().into(),
+        };
+        drop(self.send("PUT", &format!("/item/{id}"), &finish));
+    }
+
+    /// Starts a nested step item of the currently running
+    /// [`gherkin::Scenario`] item.
+    fn start_step(&mut self, name: String, ty: ItemType, at: SystemTime) {
+        let parent = self.scenario_id().to_owned();
+        let item = StartItem {
+            name,
+            start_time: Timestamp::from(at),
+            r#type: ty,
+            launch_uuid: String::new(),
+            attributes: vec![],
+            description: None,
+            code_ref: None,
+            test_case_id: None,
+            has_stats: false,
+            retry: false,
+            retry_of: None,
+        };
+        self.step = Some(self.start_item(Some(&parent), &item));
+    }
+
+    /// Finishes the currently running nested step item with the provided
+    /// [`Status`], propagating it to its [`gherkin::Scenario`] item.
+    fn finish_step(&mut self, status: Status, at: SystemTime) {
+        if let Some(sc) = self.scenario.as_mut() {
+            sc.status = sc.status.max(status);
+        }
+        if let Some(id) = self.step.take() {
+            self.finish_item(&id, Some(status), at);
+        }
+    }
+
+    /// Returns the ID of the currently running [`gherkin::Scenario`] item.
+    fn scenario_id(&self) -> &str {
+        self.scenario.as_ref().map_or_else(
+            || panic!("no `Started` event for `Scenario`"),
+            |sc| sc.id.as_str(),
+        )
+    }
+
+    /// Logs the provided `message` into the currently running item.
+    fn log(&self, level: LogLevel, message: &str, at: SystemTime) {
+        let item = self.step.as_deref().unwrap_or_else(|| self.scenario_id());
+        let log = Log {
+            launch_uuid: self.launch_id().into(),
+            item_uuid: item.into(),
+            time: Timestamp::from(at),
+            message: message.into(),
+            level,
+        };
+        drop(self.send("POST", "/log", &log));
+    }
+
+    /// Sends a request with the provided JSON `body` to the given `path` of
+    /// the [Report Portal][1] project API.
+    ///
+    /// # Panics
+    ///
+    /// If the request fails.
+    ///
+    /// [1]: https://reportportal.io
+    fn send(&self, method: &str, path: &str, body: &impl Serialize) -> Created {
+        let body = serde_json::to_string(body)
+            .unwrap_or_else(|e| panic!("Failed to serialize JSON: {e}"));
+        self.agent
+            .request(method, &format!("{}{path}", self.url))
+            .set("Authorization", &format!("Bearer {}", self.api_key))
+            .set("Content-Type", "application/json")
+            .send_string(&body)
+            .map_err(|e| e.to_string())
+            .and_then(|resp| resp.into_string().map_err(|e| e.to_string()))
+            .map_or_else(
+                |e| panic!("Failed to report to Report Portal: {e}"),
+                |resp| serde_json::from_str(&resp).unwrap_or_default(),
+            )
+    }
+}
+
+/// Response of the [Report Portal][1] API on a created entity.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Default, Deserialize)]
+struct Created {
+    /// ID of the created entity.
+    #[serde(default)]
+    id: String,
+}
+
+/// Time in milliseconds since [`SystemTime::UNIX_EPOCH`].
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(transparent)]
+struct Timestamp(u128);
+
+impl From<SystemTime> for Timestamp {
+    fn from(at: SystemTime) -> Self {
+        Self(
+            at.duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis(),
+        )
+    }
+}
+
+/// Attribute of a [Report Portal][1] launch or item.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+struct Attribute {
+    /// Key of this [`Attribute`], if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+
+    /// Value of this [`Attribute`].
+    value: String,
+}
+
+impl Attribute {
+    /// Creates a new [`Attribute`] out of the given tag, splitting `key:value`
+    /// tags into key-value ones.
+    fn from_tag(tag: &str) -> Self {
+        let tag = tag.strip_prefix('@').unwrap_or(tag);
+        tag.split_once(':').map_or_else(
+            || Self {
+                key: None,
+                value: tag.into(),
+            },
+            |(k, v)| Self {
+                key: Some(k.into()),
+                value: v.into(),
+            },
+        )
+    }
+}
+
+/// Request starting a [Report Portal][1] launch.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StartLaunch {
+    /// Name of the launch.
+    name: String,
+
+    /// Time the launch has started at.
+    start_time: Timestamp,
+
+    /// Description of the launch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// [`Attribute`]s of the launch.
+    attributes: Vec<Attribute>,
+}
+
+/// Request finishing a [Report Portal][1] launch.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FinishLaunch {
+    /// Time the launch has finished at.
+    end_time: Timestamp,
+}
+
+/// Type of a [Report Portal][1] item.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum ItemType {
+    /// [`gherkin::Feature`] or [`gherkin::Rule`].
+    Suite,
+
+    /// [`gherkin::Scenario`].
+    Scenario,
+
+    /// [`parser::Error`].
+    Test,
+
+    /// [`gherkin::Step`].
+    Step,
+
+    /// [`HookType::Before`].
+    ///
+    /// [`HookType::Before`]: event::HookType::Before
+    BeforeMethod,
+
+    /// [`HookType::After`].
+    ///
+    /// [`HookType::After`]: event::HookType::After
+    AfterMethod,
+}
+
+/// Request starting a [Report Portal][1] item.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StartItem {
+    /// Name of the item.
+    name: String,
+
+    /// Time the item has started at.
+    start_time: Timestamp,
+
+    /// [`ItemType`] of the item.
+    r#type: ItemType,
+
+    /// ID of the launch the item belongs to.
+    launch_uuid: String,
+
+    /// [`Attribute`]s of the item.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+
+    /// Description of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Reference to the source code of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_ref: Option<String>,
+
+    /// ID of the test case, stable between launches and retries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    test_case_id: Option<String>,
+
+    /// Indicator whether the item is counted in statistics.
+    has_stats: bool,
+
+    /// Indicator whether the item is a retry of another one.
+    retry: bool,
+
+    /// ID of the item this one is a retry of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_of: Option<String>,
+}
+
+impl StartItem {
+    /// Creates a new [`StartItem`] of the provided [`gherkin::Feature`].
+    fn feature(feature: &gherkin::Feature, at: SystemTime) -> Self {
+        Self {
+            name: format!("{}: {}", feature.keyword, feature.name),
+            start_time: Timestamp::from(at),
+            r#type: ItemType::Suite,
+            launch_uuid: String::new(),
+            attributes: feature
+                .tags
+                .iter()
+                .map(|t| Attribute::from_tag(t))
+                .collect(),
+            description: feature.description.clone(),
+            code_ref: feature.path.as_ref().map(|p| p.display().to_string()),
+            test_case_id: None,
+            has_stats: true,
+            retry: false,
+            retry_of: None,
+        }
+    }
+
+    /// Creates a new [`StartItem`] of the provided [`gherkin::Rule`].
+    fn rule(rule: &gherkin::Rule, at: SystemTime) -> Self {
+        Self {
+            name: format!("{}: {}", rule.keyword, rule.name),
+            start_time: Timestamp::from(at),
+            r#type: ItemType::Suite,
+            launch_uuid: String::new(),
+            attributes: rule
+                .tags
+                .iter()
+                .map(|t| Attribute::from_tag(t))
+                .collect(),
+            description: rule.description.clone(),
+            code_ref: None,
+            test_case_id: None,
+            has_stats: true,
+            retry: false,
+            retry_of: None,
+        }
+    }
+
+    /// Creates a new [`StartItem`] of the provided [`gherkin::Scenario`],
+    /// being a retry of the `retry_of` item, if any.
+    fn scenario(
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        retry_of: Option<String>,
+        at: SystemTime,
+    ) -> Self {
+        let code_ref = format!(
+            "{}:{}",
+            feature.path.as_ref().map_or_else(
+                || feature.name.clone(),
+                |p| p.display().to_string()
+            ),
+            scenario.position.line,
+        );
+        Self {
+            name: format!("{}: {}", scenario.keyword, scenario.name),
+            start_time: Timestamp::from(at),
+            r#type: ItemType::Scenario,
+            launch_uuid: String::new(),
+            attributes: scenario
+                .tags
+                .iter()
+                .map(|t| Attribute::from_tag(t))
+                .collect(),
+            description: scenario.description.clone(),
+            code_ref: Some(code_ref.clone()),
+            test_case_id: Some(code_ref),
+            has_stats: true,
+            retry: retry_of.is_some(),
+            retry_of,
+        }
+    }
+}
+
+/// Status of a finished [Report Portal][1] item.
+///
+/// Ordered by precedence, so the [`Ord::max()`] of children's [`Status`]es is
+/// the one of their parent.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Status {
+    /// Item has passed.
+    Passed,
+
+    /// Item has been skipped.
+    Skipped,
+
+    /// Item has failed.
+    Failed,
+}
+
+/// Request finishing a [Report Portal][1] item.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FinishItem {
+    /// Time the item has finished at.
+    end_time: Timestamp,
+
+    /// [`Status`] of the item, calculated from its children, if [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+
+    /// ID of the launch the item belongs to.
+    launch_uuid: String,
+}
+
+/// Level of a [`Log`].
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum LogLevel {
+    /// [`event::Scenario::Log`]s, stdout and artifacts.
+    Info,
+
+    /// Stderr and ignored hook failures.
+    Warn,
+
+    /// Failures.
+    Error,
+}
+
+/// Request creating a [Report Portal][1] log entry.
+///
+/// [1]: https://reportportal.io
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Log {
+    /// ID of the launch the log entry belongs to.
+    launch_uuid: String,
+
+    /// ID of the item the log entry belongs to.
+    item_uuid: String,
+
+    /// Time of the log entry.
+    time: Timestamp,
+
+    /// Message of the log entry.
+    message: String,
+
+    /// [`LogLevel`] of the log entry.
+    level: LogLevel,
+}
@@ -10,15 +10,22 @@
 
 //! [`Writer`]-wrapper for collecting a summary of execution.
 
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write as _,
+    ops::AddAssign,
+    path::PathBuf,
+};
 
 use derive_more::with_trait::Deref;
 use itertools::Itertools as _;
+use linked_hash_map::LinkedHashMap;
 
 use crate::{
     cli::Colored,
     event::{self, Retries, Source},
-    parser,
+    parser, tag,
     writer::{self, out::Styles},
     Event, World, Writer,
 };
@@ -26,7 +33,7 @@ use crate::{
 /// Execution statistics.
 ///
 /// [`Step`]: gherkin::Step
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Stats {
     /// Number of passed [`Step`]s (or [`Scenario`]s).
     ///
@@ -67,6 +74,31 @@ impl Stats {
     }
 }
 
+impl AddAssign for Stats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.passed += rhs.passed;
+        self.skipped += rhs.skipped;
+        self.failed += rhs.failed;
+        self.retried += rhs.retried;
+    }
+}
+
+/// Merges the provided grouped [`Stats`] by summing up the ones of the same
+/// keys, preserving the order of their appearance.
+pub(crate) fn merge_stats<K: PartialEq>(
+    mut left: Vec<(K, Stats)>,
+    right: Vec<(K, Stats)>,
+) -> Vec<(K, Stats)> {
+    for (key, stats) in right {
+        if let Some((_, existing)) = left.iter_mut().find(|(k, _)| *k == key) {
+            *existing += stats;
+        } else {
+            left.push((key, stats));
+        }
+    }
+    left
+}
+
 /// Alias for [`fn`] used to determine should [`Skipped`] test considered as
 /// [`Failed`] or not.
 ///
@@ -75,7 +107,7 @@ impl Stats {
 pub type SkipFn =
     fn(&gherkin::Feature, Option<&gherkin::Rule>, &gherkin::Scenario) -> bool;
 
-/// Indicator of a [`Failed`], [`Skipped`] or retried [`Scenario`].
+/// Indicator of a [`Failed`], [`Skipped`], retried or not run [`Scenario`].
 ///
 /// [`Failed`]: event::Step::Failed
 /// [`Scenario`]: gherkin::Scenario
@@ -99,6 +131,18 @@ enum Indicator {
     /// [`Scenario`]: gherkin::Scenario
     /// [`Skipped`]: event::Step::Skipped
     Retried,
+
+    /// [`Scenario`] not run due to the execution being stopped.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    NotRun,
+
+    /// Work-in-progress [`Scenario`] with a [`Failed`] [`Step`].
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    Wip,
 }
 
 /// Possible states of a [`Summarize`] [`Writer`].
@@ -156,6 +200,25 @@ pub struct Summarize<Writer> {
     /// [`Step`]: gherkin::Step
     steps: Stats,
 
+    /// [`Scenario`]s [`Stats`] grouped by their [`Feature`]s, in order of
+    /// their appearance.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    features_stats: LinkedHashMap<Source<gherkin::Feature>, Stats>,
+
+    /// [`Scenario`]s [`Stats`] grouped by their tags (including inherited
+    /// ones).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    tags_stats: BTreeMap<String, Stats>,
+
+    /// Number of flaky [`Scenario`]s, which have passed only after being
+    /// retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    flaky_scenarios: usize,
+
     /// Number of [`Parser`] errors.
     ///
     /// [`Parser`]: crate::Parser
@@ -166,6 +229,44 @@ pub struct Summarize<Writer> {
     /// [`Scenario`]: gherkin::Scenario
     failed_hooks: usize,
 
+    /// Number of [`Scenario`]s exceeded their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    exceeded_budgets: usize,
+
+    /// Number of [`Scenario`]s failed due to exceeding their time budget.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    failed_budgets: usize,
+
+    /// Number of [`Scenario`]s not run due to the execution being stopped.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    not_run_scenarios: usize,
+
+    /// Number of work-in-progress [`Scenario`]s failed as expected.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    wip_scenarios: usize,
+
+    /// Number of work-in-progress [`Scenario`]s failed due to passing in a
+    /// strict mode.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    wip_errors: usize,
+
+    /// Number of detected [`event::DuplicateName`]s.
+    duplicate_names: usize,
+
+    /// Indicator whether the execution has been stopped due to exceeding its
+    /// global timeout.
+    timed_out: bool,
+
+    /// Currently running work-in-progress [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    running_wip: HashSet<ScenarioPath>,
+
     /// Current [`State`] of this [`Writer`].
     state: State,
 
@@ -182,14 +283,18 @@ pub struct Summarize<Writer> {
 /// [`Feature`]: gherkin::Feature
 /// [`Rule`]: gherkin::Rule
 /// [`Scenario`]: gherkin::Scenario
-type HandledScenarios = HashMap<
-    (
-        Source<gherkin::Feature>,
-        Option<Source<gherkin::Rule>>,
-        Source<gherkin::Scenario>,
-    ),
-    Indicator,
->;
+type HandledScenarios = HashMap<ScenarioPath, Indicator>;
+
+/// Whole path of a [`Scenario`] along with its [`Feature`] and [`Rule`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioPath = (
+    Source<gherkin::Feature>,
+    Option<Source<gherkin::Rule>>,
+    Source<gherkin::Scenario>,
+);
 
 impl<W, Wr> Writer<W> for Summarize<Wr>
 where
@@ -234,11 +339,15 @@ where
                             ev,
                         );
                     }
-                    Feature::Finished | Feature::Rule(..) => {}
+                    Feature::Aborted
+                    | Feature::Finished
+                    | Feature::Rule(..) => {}
                 },
-                Ok(Cucumber::Finished) => {
+                Ok(Cucumber::Finished { timed_out, .. }) => {
+                    self.timed_out = *timed_out;
                     self.state = State::FinishedButNotOutput;
                 }
+                Ok(Cucumber::DuplicateName(_)) => self.duplicate_names += 1,
                 Ok(Cucumber::Started | Cucumber::ParsingFinished { .. }) => {}
             };
         }
@@ -253,6 +362,14 @@ where
             self.writer.write(styles.summary(self)).await;
         }
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        self.writer.report_paths()
+    }
+
+    async fn finalize(&mut self) {
+        self.writer.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -295,6 +412,48 @@ where
     fn hook_errors(&self) -> usize {
         self.failed_hooks
     }
+
+    fn budget_errors(&self) -> usize {
+        self.failed_budgets
+    }
+
+    fn wip_errors(&self) -> usize {
+        self.wip_errors
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        self.scenarios.passed
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        self.scenarios.skipped
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        self.scenarios.failed
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        self.flaky_scenarios
+    }
+
+    fn scenarios_by_feature(&self) -> Vec<(Source<gherkin::Feature>, Stats)> {
+        self.features_stats
+            .iter()
+            .map(|(f, stats)| (f.clone(), *stats))
+            .collect()
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, Stats)> {
+        self.tags_stats
+            .iter()
+            .map(|(t, stats)| (t.clone(), *stats))
+            .collect()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -321,8 +480,19 @@ impl<Writer> From<Writer> for Summarize<Writer> {
                 failed: 0,
                 retried: 0,
             },
+            features_stats: LinkedHashMap::new(),
+            tags_stats: BTreeMap::new(),
+            flaky_scenarios: 0,
             parsing_errors: 0,
             failed_hooks: 0,
+            exceeded_budgets: 0,
+            failed_budgets: 0,
+            not_run_scenarios: 0,
+            wip_scenarios: 0,
+            wip_errors: 0,
+            duplicate_names: 0,
+            timed_out: false,
+            running_wip: HashSet::new(),
             state: State::InProgress,
             handled_scenarios: HashMap::new(),
         }
@@ -357,14 +527,21 @@ impl<Writer> Summarize<Writer> {
                         .remove(&(feature, rule, scenario));
                 }
             }
-            Step::Skipped => {
+            Step::Skipped(_) => {
                 self.steps.skipped += 1;
-                self.scenarios.skipped += 1;
+                self.update_scenarios(
+                    &feature,
+                    rule.as_ref(),
+                    &scenario,
+                    |s| {
+                        s.skipped += 1;
+                    },
+                );
                 _ = self
                     .handled_scenarios
                     .insert((feature, rule, scenario), Skipped);
             }
-            Step::Failed(_, _, _, err) => {
+            Step::Failed(_, _, _, err, _) => {
                 if retries
                     .filter(|r| {
                         r.left > 0 && !matches!(err, event::StepError::NotFound)
@@ -373,16 +550,43 @@ impl<Writer> Summarize<Writer> {
                 {
                     self.steps.retried += 1;
 
+                    let inserted_before = self.handled_scenarios.insert(
+                        (feature.clone(), rule.clone(), scenario.clone()),
+                        Retried,
+                    );
+
+                    if inserted_before.is_none() {
+                        self.update_scenarios(
+                            &feature,
+                            rule.as_ref(),
+                            &scenario,
+                            |s| s.retried += 1,
+                        );
+                    }
+                } else if self.running_wip.contains(&(
+                    feature.clone(),
+                    rule.clone(),
+                    scenario.clone(),
+                )) {
+                    // Failures of work-in-progress `Scenario`s are expected,
+                    // so they're counted separately, not failing the run.
+                    self.steps.skipped += 1;
                     let inserted_before = self
                         .handled_scenarios
-                        .insert((feature, rule, scenario), Retried);
-
+                        .insert((feature, rule, scenario), Indicator::Wip);
                     if inserted_before.is_none() {
-                        self.scenarios.retried += 1;
+                        self.wip_scenarios += 1;
                     }
                 } else {
                     self.steps.failed += 1;
-                    self.scenarios.failed += 1;
+                    self.update_scenarios(
+                        &feature,
+                        rule.as_ref(),
+                        &scenario,
+                        |s| {
+                            s.failed += 1;
+                        },
+                    );
 
                     _ = self
                         .handled_scenarios
@@ -408,35 +612,62 @@ impl<Writer> Summarize<Writer> {
 
         let ret = ev.retries;
         match &ev.event {
-            Scenario::Started
-            | Scenario::Hook(_, Hook::Passed | Hook::Started)
-            | Scenario::Log(_) => {}
+            Scenario::Started(_)
+            | Scenario::Hook(
+                _,
+                Hook::Passed | Hook::Started | Hook::Ignored(_),
+            )
+            | Scenario::Log(_)
+            | Scenario::Artifact(_)
+            | Scenario::Output(_)
+            | Scenario::Stalled(_) => {}
             Scenario::Hook(_, Hook::Failed(..)) => {
-                // - If Scenario's last Step failed and then After Hook failed
-                //   too, we don't need to track second failure;
-                // - If Scenario's last Step was skipped and then After Hook
-                //   failed, we need to override skipped Scenario with failed;
-                // - If Scenario executed no Steps and then Hook failed, we
-                //   track Scenario as failed.
-                match self.handled_scenarios.get(&path) {
-                    Some(Indicator::Failed | Indicator::Retried) => {}
-                    Some(Indicator::Skipped) => {
-                        self.scenarios.skipped -= 1;
-                        self.scenarios.failed += 1;
-                    }
-                    None => {
-                        self.scenarios.failed += 1;
-                        _ = self
-                            .handled_scenarios
-                            .insert(path, Indicator::Failed);
+                self.fail_scenario(path);
+                self.failed_hooks += 1;
+            }
+            Scenario::BudgetExceeded(budget) => {
+                self.exceeded_budgets += 1;
+                if !budget.failed {
+                    return;
+                }
+                if ret.is_some_and(|r| r.left > 0) {
+                    let inserted_before = self
+                        .handled_scenarios
+                        .insert(path.clone(), Indicator::Retried);
+                    if inserted_before.is_none() {
+                        self.update_scenarios(
+                            &path.0,
+                            path.1.as_ref(),
+                            &path.2,
+                            |s| s.retried += 1,
+                        );
                     }
+                } else {
+                    self.fail_scenario(path);
+                    self.failed_budgets += 1;
                 }
-                self.failed_hooks += 1;
             }
-            Scenario::Background(st, ev) | Scenario::Step(st, ev) => {
+            Scenario::Background(st, ev)
+            | Scenario::RuleBackground(st, ev)
+            | Scenario::Step(st, ev) => {
                 self.handle_step(path.0, path.1, path.2, st.as_ref(), ev, ret);
             }
+            Scenario::Wip(event::Wip::Marked) => {
+                _ = self.running_wip.insert(path);
+            }
+            Scenario::Wip(event::Wip::Passed) => {
+                self.fail_scenario(path);
+                self.wip_errors += 1;
+            }
+            Scenario::NotRun => {
+                // Not run `Scenario`s are counted separately, so they're not
+                // confused with the skipped ones.
+                self.not_run_scenarios += 1;
+                _ = self.handled_scenarios.insert(path, Indicator::NotRun);
+            }
             Scenario::Finished => {
+                _ = self.running_wip.remove(&path);
+
                 // We don't remove retried `Scenario`s immediately, because we
                 // want to deduplicate. For example if some `Scenario` is
                 // retried 3 times, we'll see in summary 1 retried `Scenario`
@@ -448,11 +679,81 @@ impl<Writer> Summarize<Writer> {
 
                 if !is_retried && self.handled_scenarios.remove(&path).is_none()
                 {
-                    self.scenarios.passed += 1;
+                    self.update_scenarios(
+                        &path.0,
+                        path.1.as_ref(),
+                        &path.2,
+                        |s| s.passed += 1,
+                    );
+                    if ret.is_some_and(|r| r.current > 0) {
+                        self.flaky_scenarios += 1;
+                    }
                 }
             }
         }
     }
+
+    /// Tracks the [`Scenario`] as failed by something other than its
+    /// [`Step`]s (like a hook).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn fail_scenario(
+        &mut self,
+        path: (
+            Source<gherkin::Feature>,
+            Option<Source<gherkin::Rule>>,
+            Source<gherkin::Scenario>,
+        ),
+    ) {
+        // - If Scenario's last Step failed and then After Hook failed too, we
+        //   don't need to track second failure;
+        // - If Scenario's last Step was skipped and then After Hook failed, we
+        //   need to override skipped Scenario with failed;
+        // - If Scenario executed no Steps and then Hook failed, we track
+        //   Scenario as failed.
+        match self.handled_scenarios.get(&path) {
+            Some(
+                Indicator::Failed
+                | Indicator::Retried
+                | Indicator::NotRun
+                | Indicator::Wip,
+            ) => {}
+            Some(Indicator::Skipped) => {
+                self.update_scenarios(&path.0, path.1.as_ref(), &path.2, |s| {
+                    s.skipped -= 1;
+                    s.failed += 1;
+                });
+            }
+            None => {
+                self.update_scenarios(&path.0, path.1.as_ref(), &path.2, |s| {
+                    s.failed += 1
+                });
+                _ = self.handled_scenarios.insert(path, Indicator::Failed);
+            }
+        }
+    }
+
+    /// Updates [`Scenario`]s [`Stats`] (along with the ones of its [`Feature`]
+    /// and tags) with the provided function.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn update_scenarios(
+        &mut self,
+        feature: &Source<gherkin::Feature>,
+        rule: Option<&Source<gherkin::Rule>>,
+        scenario: &Source<gherkin::Scenario>,
+        update: impl Fn(&mut Stats),
+    ) {
+        update(&mut self.scenarios);
+        update(self.features_stats.entry(feature.clone()).or_default());
+        for t in
+            tag::inherited(feature, rule.map(AsRef::as_ref), scenario).unique()
+        {
+            update(self.tags_stats.entry(t.clone()).or_default());
+        }
+    }
 }
 
 impl<Writer> Summarize<Writer> {
@@ -484,6 +785,43 @@ impl<Writer> Summarize<Writer> {
     pub const fn steps_stats(&self) -> &Stats {
         &self.steps
     }
+
+    /// Returns number of [`Scenario`]s not run by this [`Summarize`]d
+    /// [`Writer`] due to the execution being stopped (in a fail-fast mode,
+    /// for example).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn not_run_scenarios(&self) -> usize {
+        self.not_run_scenarios
+    }
+
+    /// Returns number of [`event::DuplicateName`]s detected by this
+    /// [`Summarize`]d [`Writer`].
+    #[must_use]
+    pub const fn duplicate_names(&self) -> usize {
+        self.duplicate_names
+    }
+
+    /// Returns collected [`Scenario`]s [`Stats`] of this [`Summarize`]d
+    /// [`Writer`] grouped by their [`Feature`]s, in order of their appearance.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn features_stats(
+        &self,
+    ) -> impl Iterator<Item = (&Source<gherkin::Feature>, &Stats)> {
+        self.features_stats.iter()
+    }
+
+    /// Returns collected [`Scenario`]s [`Stats`] of this [`Summarize`]d
+    /// [`Writer`] grouped by their tags (including inherited ones), in
+    /// alphabetical order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn tags_stats(&self) -> impl Iterator<Item = (&String, &Stats)> {
+        self.tags_stats.iter()
+    }
 }
 
 /// Marker indicating that a [`Writer`] can be wrapped into a [`Summarize`].
@@ -560,6 +898,7 @@ impl Styles {
     #[must_use]
     pub fn summary<W>(&self, summary: &Summarize<W>) -> String {
         let features = self.maybe_plural("feature", summary.features);
+        let features_table = self.features_table(summary);
 
         let rules = (summary.rules > 0)
             .then(|| format!("{}\n", self.maybe_plural("rule", summary.rules)))
@@ -586,19 +925,118 @@ impl Styles {
             })
             .unwrap_or_default();
 
-        let comma = (!parsing_errors.is_empty() && !hook_errors.is_empty())
-            .then(|| self.err(", "))
+        let exceeded_budgets = (summary.exceeded_budgets > 0)
+            .then(|| {
+                let msg = self
+                    .maybe_plural("exceeded budget", summary.exceeded_budgets);
+                if summary.failed_budgets > 0 {
+                    self.err(msg)
+                } else {
+                    self.skipped(msg)
+                }
+            })
             .unwrap_or_default();
 
+        let not_run = if summary.not_run_scenarios > 0 {
+            self.skipped(format!(
+                "{} not run",
+                self.maybe_plural("scenario", summary.not_run_scenarios),
+            ))
+        } else {
+            Cow::default()
+        };
+
+        let wip = if summary.wip_scenarios > 0 || summary.wip_errors > 0 {
+            let msg = [
+                (summary.wip_scenarios, "failed"),
+                (summary.wip_errors, "passed"),
+            ]
+            .into_iter()
+            .filter(|(n, _)| *n > 0)
+            .map(|(n, status)| {
+                format!(
+                    "{} {status}",
+                    self.maybe_plural("work-in-progress scenario", n),
+                )
+            })
+            .join(", ");
+            if summary.wip_errors > 0 {
+                self.err(msg)
+            } else {
+                self.skipped(msg)
+            }
+        } else {
+            Cow::default()
+        };
+
+        let duplicate_names = if summary.duplicate_names > 0 {
+            self.skipped(
+                self.maybe_plural("duplicate name", summary.duplicate_names),
+            )
+        } else {
+            Cow::default()
+        };
+
+        let timed_out = if summary.timed_out {
+            self.err("execution timed out")
+        } else {
+            Cow::default()
+        };
+
+        let errors = [
+            parsing_errors,
+            hook_errors,
+            exceeded_budgets,
+            not_run,
+            wip,
+            duplicate_names,
+            timed_out,
+        ]
+        .into_iter()
+        .filter(|e| !e.is_empty())
+        .join(&self.err(", "));
+
         format!(
-            "{summary}\n{features}\n{rules}{scenarios}{scenarios_stats}\n\
-             {steps}{steps_stats}\n{parsing_errors}{comma}{hook_errors}",
+            "{summary}\n{features}\n{features_table}{rules}{scenarios}\
+             {scenarios_stats}\n\
+             {steps}{steps_stats}\n{errors}",
             summary = self.bold(self.header("[Summary]")),
         )
         .trim_end_matches('\n')
         .to_owned()
     }
 
+    /// Generates a formatted table of [`Scenario`]s [`Stats`] per each
+    /// [`Feature`], if there are more than one [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn features_table<W>(&self, summary: &Summarize<W>) -> String {
+        if summary.features_stats.len() < 2 {
+            return String::new();
+        }
+
+        let width = summary
+            .features_stats
+            .keys()
+            .map(|f| f.name.chars().count())
+            .max()
+            .unwrap_or_default();
+        summary.features_stats.iter().fold(
+            String::new(),
+            |mut out, (f, stats)| {
+                _ = writeln!(
+                    out,
+                    "  {:<width$}  {}{}",
+                    f.name,
+                    self.maybe_plural("scenario", stats.total()),
+                    self.format_stats(*stats),
+                );
+                out
+            },
+        )
+    }
+
     /// Formats [`Stats`] for a terminal output.
     #[must_use]
     pub fn format_stats(&self, stats: Stats) -> Cow<'static, str> {
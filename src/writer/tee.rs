@@ -10,11 +10,17 @@
 
 //! Passing events to multiple terminating [`Writer`]s simultaneously.
 
-use std::cmp;
+use std::{cmp, path::PathBuf};
 
 use futures::future;
 
-use crate::{cli, event, parser, writer, Event, World, Writer};
+use crate::{
+    cli,
+    event::{self, Source},
+    parser,
+    writer::{self, summarize},
+    Event, World, Writer,
+};
 
 /// Wrapper for passing events to multiple terminating [`Writer`]s
 /// simultaneously.
@@ -69,6 +75,17 @@ where
         )
         .await;
     }
+
+    fn report_paths(&self) -> Vec<PathBuf> {
+        let mut paths = self.left.report_paths();
+        paths.extend(self.right.report_paths());
+        paths
+    }
+
+    async fn finalize(&mut self) {
+        self.left.finalize().await;
+        self.right.finalize().await;
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -119,6 +136,65 @@ where
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.hook_errors(), self.right.hook_errors())
     }
+
+    fn budget_errors(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.budget_errors(), self.right.budget_errors())
+    }
+
+    fn wip_errors(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.wip_errors(), self.right.wip_errors())
+    }
+
+    fn timed_out(&self) -> bool {
+        self.left.timed_out() || self.right.timed_out()
+    }
+
+    fn passed_scenarios(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.passed_scenarios(), self.right.passed_scenarios())
+    }
+
+    fn skipped_scenarios(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(
+            self.left.skipped_scenarios(),
+            self.right.skipped_scenarios(),
+        )
+    }
+
+    fn failed_scenarios(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.failed_scenarios(), self.right.failed_scenarios())
+    }
+
+    fn flaky_scenarios(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.flaky_scenarios(), self.right.flaky_scenarios())
+    }
+
+    fn scenarios_by_feature(
+        &self,
+    ) -> Vec<(Source<gherkin::Feature>, summarize::Stats)> {
+        // Either one of them is empty, or both are the same.
+        let left = self.left.scenarios_by_feature();
+        if left.is_empty() {
+            self.right.scenarios_by_feature()
+        } else {
+            left
+        }
+    }
+
+    fn scenarios_by_tag(&self) -> Vec<(String, summarize::Stats)> {
+        // Either one of them is empty, or both are the same.
+        let left = self.left.scenarios_by_tag();
+        if left.is_empty() {
+            self.right.scenarios_by_tag()
+        } else {
+            left
+        }
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -0,0 +1,379 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Webhook [`Writer`] implementation.
+
+use std::{collections::HashMap, fmt::Debug, time::Duration};
+
+use derive_more::with_trait::Debug as DebugExt;
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser,
+    writer::{self, discard, Ext as _},
+    Event, World, Writer,
+};
+
+/// Default [`Webhook::template()`], compatible with [Slack incoming
+/// webhooks][1].
+///
+/// [1]: https://api.slack.com/messaging/webhooks
+pub const DEFAULT_TEMPLATE: &str = "{\"text\": \"Cucumber run {status}: \
+    {passed} passed, {skipped} skipped, {failed} failed of {total} scenarios, \
+    {parsing_errors} parsing errors.\\n{failed_scenarios}\\n{report_url}\"}";
+
+/// Webhook [`Writer`] implementation posting a templated JSON payload with the
+/// run results to the configured URL.
+///
+/// The payload is rendered from the [`Webhook::template()`] by replacing the
+/// following placeholders with JSON-escaped values (so they should be placed
+/// inside JSON strings):
+/// - `{status}`: `passed` or `failed`;
+/// - `{total}`, `{passed}`, `{skipped}`, `{failed}`: numbers of the finished
+///   [`Scenario`]s (retried ones are counted once, by their last attempt);
+/// - `{parsing_errors}`: number of the parsing errors;
+/// - `{failed_scenarios}`: names of the failed [`Scenario`]s (prefixed with
+///   their [`Feature`]s' ones), one per line;
+/// - `{report_url}`: the [`Webhook::report_url()`], if any.
+///
+/// __NOTE__: The request is sent synchronously, blocking the executor thread.
+///
+/// # Panics
+///
+/// If the request fails.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, DebugExt)]
+pub struct Webhook {
+    /// [`ureq::Agent`] to send requests with.
+    agent: ureq::Agent,
+
+    /// URL to post the payload to.
+    url: String,
+
+    /// Additional headers of the request.
+    #[debug(ignore)]
+    headers: Vec<(String, String)>,
+
+    /// Template of the payload.
+    template: String,
+
+    /// URL of the run report to be referenced in the payload, if any.
+    report_url: Option<String>,
+
+    /// [`Trigger`] of posting the payload.
+    trigger: Trigger,
+
+    /// Indicator whether the payload has been posted already.
+    posted: bool,
+
+    /// Names and [`Status`]es of the currently running [`gherkin::Scenario`]s.
+    running: HashMap<Source<gherkin::Scenario>, (String, Status)>,
+
+    /// Number of the passed [`gherkin::Scenario`]s.
+    passed: usize,
+
+    /// Number of the skipped [`gherkin::Scenario`]s.
+    skipped: usize,
+
+    /// Names of the failed [`gherkin::Scenario`]s.
+    failed: Vec<String>,
+
+    /// Number of the parsing errors.
+    parsing_errors: usize,
+}
+
+/// Trigger of posting a [`Webhook`] payload.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Trigger {
+    /// Post once the run is finished.
+    #[default]
+    Finished,
+
+    /// Post as soon as the first [`Scenario`] fails (or a parsing error
+    /// occurs), or once the run is finished, if nothing has failed.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    FirstFailure,
+}
+
+impl<W: World + Debug> Writer<W> for Webhook {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.map(Event::into_inner) {
+            Err(_) => {
+                self.parsing_errors += 1;
+                self.post_on_failure();
+            }
+            Ok(Cucumber::Feature(
+                f,
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev),
+            )) => self.handle_scenario_event(&f, sc, &ev),
+            Ok(Cucumber::Finished { .. }) => {
+                if !self.posted {
+                    self.post();
+                }
+            }
+            Ok(
+                Cucumber::Started
+                | Cucumber::Feature(..)
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateName(_),
+            ) => {}
+        }
+    }
+
+    async fn finalize(&mut self) {
+        if !self.posted {
+            self.post();
+        }
+    }
+}
+
+impl writer::NonTransforming for Webhook {}
+
+impl Webhook {
+    /// Creates a new [`Normalized`] [`Webhook`] [`Writer`] posting to the
+    /// given `url`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn new<W: Debug + World>(
+        url: impl Into<String>,
+    ) -> writer::Normalize<W, Self> {
+        Self::raw(url).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Webhook`] [`Writer`] posting to the
+    /// given `url`, and suitable for feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    #[must_use]
+    pub fn for_tee(
+        url: impl Into<String>,
+    ) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(url)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Webhook`] [`Writer`] posting
+    /// to the given `url`.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Webhook::new()`] which creates an already [`Normalized`] version of
+    /// [`Webhook`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    #[must_use]
+    pub fn raw(url: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::Agent::new(),
+            url: url.into(),
+            headers: vec![],
+            template: DEFAULT_TEMPLATE.into(),
+            report_url: None,
+            trigger: Trigger::Finished,
+            posted: false,
+            running: HashMap::new(),
+            passed: 0,
+            skipped: 0,
+            failed: vec![],
+            parsing_errors: 0,
+        }
+    }
+
+    /// Sets the template of the posted JSON payload.
+    ///
+    /// Default is [`DEFAULT_TEMPLATE`].
+    #[must_use]
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    /// Sets the URL of the run report to be referenced in the payload.
+    #[must_use]
+    pub fn report_url(mut self, url: impl Into<String>) -> Self {
+        self.report_url = Some(url.into());
+        self
+    }
+
+    /// Sets the [`Trigger`] of posting the payload.
+    ///
+    /// Default is [`Trigger::Finished`].
+    #[must_use]
+    pub const fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Adds the given header to the request (like an `Authorization` one).
+    #[must_use]
+    pub fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the timeout of the request.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: Source<gherkin::Scenario>,
+        ev: &event::RetryableScenario<W>,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        let status = match &ev.event {
+            Scenario::Started(_) => {
+                let name = format!("{}: {}", feature.name, scenario.name);
+                drop(self.running.insert(scenario, (name, Status::Passed)));
+                return;
+            }
+            Scenario::Hook(_, Hook::Failed(..))
+            | Scenario::Background(_, Step::Failed(..))
+            | Scenario::RuleBackground(_, Step::Failed(..))
+            | Scenario::Step(_, Step::Failed(..))
+            | Scenario::Wip(event::Wip::Passed)
+            | Scenario::BudgetExceeded(event::Budget {
+                failed: true, ..
+            }) => Status::Failed,
+            Scenario::Background(_, Step::Skipped(_))
+            | Scenario::RuleBackground(_, Step::Skipped(_))
+            | Scenario::Step(_, Step::Skipped(_))
+            | Scenario::NotRun => Status::Skipped,
+            Scenario::Finished => {
+                let Some((name, status)) = self.running.remove(&scenario)
+                else {
+                    return;
+                };
+                match status {
+                    Status::Passed => self.passed += 1,
+                    Status::Skipped => self.skipped += 1,
+                    Status::Failed => {
+                        if ev.retries.is_none_or(|r| r.left == 0) {
+                            self.failed.push(name);
+                            self.post_on_failure();
+                        }
+                    }
+                }
+                return;
+            }
+            Scenario::Hook(..)
+            | Scenario::Background(..)
+            | Scenario::RuleBackground(..)
+            | Scenario::Step(..)
+            | Scenario::Log(_)
+            | Scenario::Output(_)
+            | Scenario::Artifact(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_) => return,
+        };
+        if let Some((_, st)) = self.running.get_mut(&scenario) {
+            *st = (*st).max(status);
+        }
+    }
+
+    /// Posts the payload, if the [`Trigger::FirstFailure`] is set and it hasn't
+    /// been posted yet.
+    fn post_on_failure(&mut self) {
+        if self.trigger == Trigger::FirstFailure && !self.posted {
+            self.post();
+        }
+    }
+
+    /// Renders the [`Webhook::template()`] with the current results.
+    fn render(&self) -> String {
+        let failed = !self.failed.is_empty() || self.parsing_errors > 0;
+        let total = self.passed + self.skipped + self.failed.len();
+        [
+            (
+                "status",
+                if failed { "failed" } else { "passed" }.to_owned(),
+            ),
+            ("total", total.to_string()),
+            ("passed", self.passed.to_string()),
+            ("skipped", self.skipped.to_string()),
+            ("failed", self.failed.len().to_string()),
+            ("parsing_errors", self.parsing_errors.to_string()),
+            ("failed_scenarios", self.failed.join("\n")),
+            ("report_url", self.report_url.clone().unwrap_or_default()),
+        ]
+        .into_iter()
+        .fold(self.template.clone(), |payload, (name, value)| {
+            let value = serde_json::to_string(&value)
+                .unwrap_or_else(|e| panic!("Failed to serialize JSON: {e}"));
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(&value);
+            payload.replace(&format!("{{{name}}}"), value)
+        })
+    }
+
+    /// Posts the payload.
+    ///
+    /// # Panics
+    ///
+    /// If the request fails.
+    fn post(&mut self) {
+        self.posted = true;
+        let req = self
+            .headers
+            .iter()
+            .fold(self.agent.post(&self.url), |req, (name, value)| {
+                req.set(name, value)
+            })
+            .set("Content-Type", "application/json");
+        drop(
+            req.send_string(&self.render())
+                .unwrap_or_else(|e| panic!("Failed to post webhook: {e}")),
+        );
+    }
+}
+
+/// Status of a finished [`gherkin::Scenario`].
+///
+/// Ordered by precedence, so the [`Ord::max()`] of [`gherkin::Step`]s'
+/// [`Status`]es is the one of their [`gherkin::Scenario`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Status {
+    /// Passed.
+    Passed,
+
+    /// Skipped.
+    Skipped,
+
+    /// Failed.
+    Failed,
+}
@@ -0,0 +1,468 @@
+// Copyright (c) 2018-2025  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Jira Xray][1] [`Writer`] implementation.
+//!
+//! [1]: https://www.getxray.app
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    time::{Duration, SystemTime},
+};
+
+use derive_more::with_trait::Debug as DebugExt;
+use serde::Serialize;
+
+use crate::{
+    cli, event,
+    event::Source,
+    parser, tag,
+    writer::{self, basic::coerce_error, discard, Ext as _, Lexicon},
+    Event, World, Writer,
+};
+
+/// [Jira Xray][1] [`Writer`] implementation importing execution results.
+///
+/// Results of the [`Scenario`]s, marked with [test keys][2] (like
+/// `@testkey(PROJ-123)`), are imported into [Jira Xray][1] via its
+/// [REST API][3] once the run is finished.
+///
+/// Every [test key][2] is reported as a single test run, aggregated from all
+/// the [`Scenario`]s marked with it (so a [`Scenario Outline`] is reported
+/// once): it's failed if any of them has failed, `TODO` if any of them has
+/// been skipped, and passed otherwise. Only the last attempt of a retried
+/// [`Scenario`] is taken into account.
+///
+/// __NOTE__: The request is sent synchronously, blocking the executor thread.
+///
+/// # Panics
+///
+/// If the request to the [Jira Xray][1] server fails.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Scenario Outline`]: gherkin::Scenario
+/// [1]: https://www.getxray.app
+/// [2]: tag::test_key
+/// [3]: https://docs.getxray.app/display/XRAYCLOUD/Import+Execution+Results+-+REST+v2
+#[derive(Clone, DebugExt)]
+pub struct Xray {
+    /// [`ureq::Agent`] to send requests with.
+    agent: ureq::Agent,
+
+    /// URL of the [Jira Xray][1] execution results import endpoint.
+    ///
+    /// [1]: https://www.getxray.app
+    url: String,
+
+    /// Token to authorize requests with.
+    #[debug(ignore)]
+    token: String,
+
+    /// Summary of the created Test Execution.
+    summary: String,
+
+    /// Key of the Jira project to create the Test Execution in, if any.
+    project_key: Option<String>,
+
+    /// Key of the existing Test Execution to import results into, if any.
+    test_execution_key: Option<String>,
+
+    /// [`SystemTime`] the run has started at.
+    started_at: Option<SystemTime>,
+
+    /// [`TestRun`]s of the currently running [`gherkin::Scenario`]s.
+    running: HashMap<Source<gherkin::Scenario>, TestRun>,
+
+    /// [`TestRun`]s of the last attempts of the finished
+    /// [`gherkin::Scenario`]s.
+    finished: HashMap<Source<gherkin::Scenario>, TestRun>,
+
+    /// [`Lexicon`] to label hooks in failure comments with.
+    lexicon: Lexicon,
+}
+
+impl<W: World + Debug> Writer<W> for Xray {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        let Ok((ev, meta)) = event.map(Event::split) else {
+            // Parsing errors have no test keys to be reported against.
+            return;
+        };
+        match ev {
+            Cucumber::Started => self.started_at = Some(meta.at),
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+                self.handle_scenario_event(&f, Some(&r), sc, ev.event, meta.at);
+            }
+            Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                self.handle_scenario_event(&f, None, sc, ev.event, meta.at);
+            }
+            Cucumber::Finished { .. } => self.import(meta.at),
+            Cucumber::Feature(..)
+            | Cucumber::ParsingFinished { .. }
+            | Cucumber::DuplicateName(_) => {}
+        }
+    }
+}
+
+impl writer::NonTransforming for Xray {}
+
+impl Xray {
+    /// Creates a new [`Normalized`] [`Xray`] [`Writer`] importing execution
+    /// results via the given `url` of the [Jira Xray][1] server (like
+    /// `https://xray.cloud.getxray.app/api/v2/import/execution`), authorized
+    /// with the provided bearer `token`.
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://www.getxray.app
+    #[must_use]
+    pub fn new<W: Debug + World>(
+        url: impl Into<String>,
+        token: impl Into<String>,
+    ) -> writer::Normalize<W, Self> {
+        Self::raw(url, token).normalized()
+    }
+
+    /// Creates a new non-[`Normalized`] [`Xray`] [`Writer`] importing
+    /// execution results via the given `url` of the [Jira Xray][1] server, and
+    /// suitable for feeding into [`tee()`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [`tee()`]: crate::WriterExt::tee
+    /// [1]: https://www.getxray.app
+    #[must_use]
+    pub fn for_tee(
+        url: impl Into<String>,
+        token: impl Into<String>,
+    ) -> discard::Arbitrary<discard::Stats<Self>> {
+        Self::raw(url, token)
+            .discard_stats_writes()
+            .discard_arbitrary_writes()
+    }
+
+    /// Creates a new raw and non-[`Normalized`] [`Xray`] [`Writer`] importing
+    /// execution results via the given `url` of the [Jira Xray][1] server.
+    ///
+    /// Use it only if you know what you're doing. Otherwise, consider using
+    /// [`Xray::new()`] which creates an already [`Normalized`] version of
+    /// [`Xray`] [`Writer`].
+    ///
+    /// [`Normalized`]: writer::Normalized
+    /// [1]: https://www.getxray.app
+    #[must_use]
+    pub fn raw(url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::Agent::new(),
+            url: url.into(),
+            token: token.into(),
+            summary: "cucumber".into(),
+            project_key: None,
+            test_execution_key: None,
+            started_at: None,
+            running: HashMap::new(),
+            finished: HashMap::new(),
+            lexicon: Lexicon::new(),
+        }
+    }
+
+    /// Sets the summary of the created Test Execution.
+    ///
+    /// Default is `cucumber`.
+    #[must_use]
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = summary.into();
+        self
+    }
+
+    /// Sets the key of the Jira project to create the Test Execution in.
+    #[must_use]
+    pub fn project_key(mut self, key: impl Into<String>) -> Self {
+        self.project_key = Some(key.into());
+        self
+    }
+
+    /// Sets the key of the existing Test Execution to import results into,
+    /// instead of creating a new one.
+    #[must_use]
+    pub fn test_execution_key(mut self, key: impl Into<String>) -> Self {
+        self.test_execution_key = Some(key.into());
+        self
+    }
+
+    /// Sets the [`Lexicon`] to label hooks in failure comments with.
+    ///
+    /// Default is an English [`Lexicon::new()`].
+    #[must_use]
+    pub fn lexicon(mut self, lexicon: Lexicon) -> Self {
+        self.lexicon = lexicon;
+        self
+    }
+
+    /// Sets the timeout of the request to the [Jira Xray][1] server.
+    ///
+    /// [1]: https://www.getxray.app
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    /// Handles the given [`event::Scenario`].
+    fn handle_scenario_event<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: Source<gherkin::Scenario>,
+        ev: event::Scenario<W>,
+        at: SystemTime,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        if let Scenario::Started(_) = ev {
+            if let Some(key) = tag::test_key(feature, rule, &scenario) {
+                let run = TestRun::new(key.into(), at);
+                drop(self.running.insert(scenario, run));
+            }
+            return;
+        }
+        if matches!(ev, Scenario::Finished) {
+            if let Some(mut run) = self.running.remove(&scenario) {
+                run.finish = Timestamp::from(at);
+                drop(self.finished.insert(scenario, run));
+            }
+            return;
+        }
+
+        let Some(run) = self.running.get_mut(&scenario) else {
+            return;
+        };
+        match ev {
+            Scenario::Hook(which, Hook::Failed(_, info)) => {
+                run.fail(format!(
+                    "{} failed: {}",
+                    self.lexicon.hook(which),
+                    coerce_error(&info),
+                ));
+            }
+            Scenario::Background(st, Step::Failed(_, _, _, err, _))
+            | Scenario::RuleBackground(st, Step::Failed(_, _, _, err, _))
+            | Scenario::Step(st, Step::Failed(_, _, _, err, _)) => {
+                run.fail(format!("{}{}\n{err}", st.keyword, st.value));
+            }
+            Scenario::Background(_, Step::Skipped(_))
+            | Scenario::RuleBackground(_, Step::Skipped(_))
+            | Scenario::Step(_, Step::Skipped(_))
+            | Scenario::NotRun => {
+                run.status = run.status.max(Status::Todo);
+            }
+            Scenario::BudgetExceeded(budget) if budget.failed => {
+                run.fail(budget.to_string());
+            }
+            Scenario::Wip(wip @ event::Wip::Passed) => {
+                run.fail(wip.to_string());
+            }
+            Scenario::Started(_)
+            | Scenario::Hook(..)
+            | Scenario::Background(..)
+            | Scenario::RuleBackground(..)
+            | Scenario::Step(..)
+            | Scenario::Log(_)
+            | Scenario::Output(_)
+            | Scenario::Artifact(_)
+            | Scenario::Stalled(_)
+            | Scenario::BudgetExceeded(_)
+            | Scenario::Wip(_)
+            | Scenario::Finished => {}
+        }
+    }
+
+    /// Imports the collected [`TestRun`]s, aggregated by their test keys, into
+    /// [Jira Xray][1].
+    ///
+    /// # Panics
+    ///
+    /// If the request fails.
+    ///
+    /// [1]: https://www.getxray.app
+    fn import(&mut self, at: SystemTime) {
+        let mut tests = BTreeMap::<String, TestRun>::new();
+        for run in self.finished.drain().map(|(_, run)| run) {
+            if let Some(test) = tests.get_mut(&run.test_key) {
+                test.merge(run);
+            } else {
+                drop(tests.insert(run.test_key.clone(), run));
+            }
+        }
+
+        let import = Import {
+            test_execution_key: self.test_execution_key.clone(),
+            info: Info {
+                summary: self.summary.clone(),
+                project: self.project_key.clone(),
+                start_date: Timestamp::from(self.started_at.unwrap_or(at)),
+                finish_date: Timestamp::from(at),
+            },
+            tests: tests.into_values().collect(),
+        };
+        let body = serde_json::to_string(&import)
+            .unwrap_or_else(|e| panic!("Failed to serialize JSON: {e}"));
+        drop(
+            self.agent
+                .post(&self.url)
+                .set("Authorization", &format!("Bearer {}", self.token))
+                .set("Content-Type", "application/json")
+                .send_string(&body)
+                .unwrap_or_else(|e| panic!("Failed to import into Xray: {e}")),
+        );
+    }
+}
+
+/// Date and time in [RFC 3339] format.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+#[derive(Clone, Copy, Debug)]
+struct Timestamp(SystemTime);
+
+impl From<SystemTime> for Timestamp {
+    fn from(at: SystemTime) -> Self {
+        Self(at)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&humantime::format_rfc3339_seconds(self.0))
+    }
+}
+
+/// Request importing execution results into [Jira Xray][1].
+///
+/// [1]: https://www.getxray.app
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Import {
+    /// Key of the existing Test Execution to import results into.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    test_execution_key: Option<String>,
+
+    /// [`Info`] of the Test Execution.
+    info: Info,
+
+    /// [`TestRun`]s of the Test Execution.
+    tests: Vec<TestRun>,
+}
+
+/// Information about a [Jira Xray][1] Test Execution.
+///
+/// [1]: https://www.getxray.app
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Info {
+    /// Summary of the Test Execution.
+    summary: String,
+
+    /// Key of the Jira project of the Test Execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+
+    /// Time the Test Execution has started at.
+    start_date: Timestamp,
+
+    /// Time the Test Execution has finished at.
+    finish_date: Timestamp,
+}
+
+/// [Jira Xray][1] test run of a [test key][2].
+///
+/// [1]: https://www.getxray.app
+/// [2]: tag::test_key
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TestRun {
+    /// Key of the test this run belongs to.
+    test_key: String,
+
+    /// Time the run has started at.
+    start: Timestamp,
+
+    /// Time the run has finished at.
+    finish: Timestamp,
+
+    /// [`Status`] of the run.
+    status: Status,
+
+    /// Failure messages of the run, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+impl TestRun {
+    /// Creates a new passed [`TestRun`] of the provided `test_key`.
+    const fn new(test_key: String, at: SystemTime) -> Self {
+        Self {
+            test_key,
+            start: Timestamp(at),
+            finish: Timestamp(at),
+            status: Status::Passed,
+            comment: None,
+        }
+    }
+
+    /// Marks this [`TestRun`] as failed with the provided `message`.
+    fn fail(&mut self, message: String) {
+        self.status = Status::Failed;
+        self.add_comment(message);
+    }
+
+    /// Appends the provided `comment` to the ones of this [`TestRun`].
+    fn add_comment(&mut self, comment: String) {
+        self.comment = Some(match self.comment.take() {
+            Some(c) => format!("{c}\n\n{comment}"),
+            None => comment,
+        });
+    }
+
+    /// Merges the `other` [`TestRun`] of the same test key into this one.
+    fn merge(&mut self, other: Self) {
+        self.start = Timestamp(self.start.0.min(other.start.0));
+        self.finish = Timestamp(self.finish.0.max(other.finish.0));
+        self.status = self.status.max(other.status);
+        if let Some(comment) = other.comment {
+            self.add_comment(comment);
+        }
+    }
+}
+
+/// Status of a [`TestRun`].
+///
+/// Ordered by precedence, so the [`Ord::max()`] of [`Status`]es of all the
+/// [`gherkin::Scenario`]s marked with the same test key is the one reported.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Status {
+    /// Test has passed.
+    Passed,
+
+    /// Test has been skipped.
+    Todo,
+
+    /// Test has failed.
+    Failed,
+}
@@ -0,0 +1,107 @@
+use std::fs;
+
+use cucumber::{
+    artifacts::{Artifact, Retention},
+    given, parser,
+    step::Context,
+    then, writer, World as _,
+};
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "a screenshot {word}")]
+fn screenshot(_: &mut World, #[step] ctx: &Context, name: String) {
+    let dir = ctx.artifact_dir().expect("failed to create artifacts dir");
+    fs::write(dir.join(name), b"png").expect("failed to write artifact");
+}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FEATURE: &str = "\
+Feature: Artifacts
+  Scenario: passing
+    Given a screenshot passed.png
+
+  Scenario: failing
+    Given a screenshot failed.png
+    Then it fails
+";
+
+async fn run(root: &TempDir, retention: Retention) -> String {
+    let mut output = Vec::new();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(writer::JUnit::new(&mut output, 0))
+        .artifacts_dir(root.path())
+        .keep_artifacts(retention)
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+    String::from_utf8(output).unwrap()
+}
+
+#[tokio::test]
+async fn keeps_only_failed_artifacts() {
+    let root = TempDir::new().unwrap();
+    let output = run(&root, Retention::Failed).await;
+
+    let failed = root.path().join("Artifacts/failing_5/failed.png");
+    assert!(failed.exists());
+    assert!(!root.path().join("Artifacts/passing_2").exists());
+    assert!(output.contains(&format!("[[ATTACHMENT|{}]]", failed.display())));
+    assert!(!output.contains("passing_2"));
+}
+
+#[tokio::test]
+async fn keeps_all_artifacts() {
+    let root = TempDir::new().unwrap();
+    let output = run(&root, Retention::All).await;
+
+    assert!(root.path().join("Artifacts/passing_2/passed.png").exists());
+    assert!(root.path().join("Artifacts/failing_5/failed.png").exists());
+    assert!(output.contains("passing_2/passed.png"));
+}
+
+#[tokio::test]
+async fn keeps_no_artifacts() {
+    let root = TempDir::new().unwrap();
+    let output = run(&root, Retention::None).await;
+
+    assert_eq!(fs::read_dir(root.path()).unwrap().count(), 1);
+    assert!(!root.path().join("Artifacts/failing_5").exists());
+    assert!(!output.contains("[[ATTACHMENT|"));
+}
+
+#[tokio::test]
+async fn keeps_artifacts_of_step_failed_hook() {
+    let root = TempDir::new().unwrap();
+    let mut output = Vec::new();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(writer::JUnit::new(&mut output, 0))
+        .artifacts_dir(root.path())
+        .keep_artifacts(Retention::Failed)
+        .on_step_failed(|_, info| {
+            let content = info.step.value.clone();
+            Box::pin(async move { vec![Artifact::new("screen/shot", content)] })
+        })
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+    let output = String::from_utf8(output).unwrap();
+
+    let shot = root.path().join("Artifacts/failing_5/screen_shot");
+    assert_eq!(fs::read_to_string(&shot).unwrap(), "it fails");
+    assert!(root.path().join("Artifacts/failing_5/failed.png").exists());
+    assert_eq!(
+        output
+            .matches(&format!("[[ATTACHMENT|{}]]", shot.display()))
+            .count(),
+        1,
+    );
+}
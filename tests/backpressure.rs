@@ -0,0 +1,70 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use cucumber::{
+    cli, event, given, parser, Event, World as _, Writer, WriterExt as _,
+};
+use tokio::time;
+
+/// Number of events handled by the [`SlowWriter`].
+static HANDLED: AtomicUsize = AtomicUsize::new(0);
+
+/// Numbers of events handled by the [`SlowWriter`] at the moments of [`Step`]s
+/// execution.
+///
+/// [`Step`]: cucumber::gherkin::Step
+static SEEN: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("it records handled events")]
+fn records(_: &mut World) {
+    SEEN.lock().unwrap().push(HANDLED.load(Ordering::SeqCst));
+}
+
+/// [`Writer`] taking some time to handle every event.
+struct SlowWriter;
+
+impl Writer<World> for SlowWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        _: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        time::sleep(Duration::from_millis(1)).await;
+        _ = HANDLED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn slow_writer_pauses_execution() {
+    let feature = (0..10).fold("Feature: Backpressure\n".to_owned(), |f, i| {
+        f + &format!("  Scenario: {i}\n    Given it records handled events\n")
+    });
+
+    _ = World::cucumber::<String>()
+        .with_parser(parser::FromString::new())
+        .with_writer(SlowWriter.assert_normalized())
+        .max_buffered_events(1)
+        .with_default_cli()
+        .run([feature])
+        .await;
+
+    // All the `Scenario`s are started concurrently, so without backpressure
+    // their `Step`s are executed long before the `Writer` handles the events
+    // emitted by the previous ones (around 16 of 40 ones).
+    let seen = SEEN.lock().unwrap().clone();
+    assert_eq!(seen.len(), 10);
+    assert!(
+        seen.iter().max() >= Some(&30),
+        "writer didn't keep up: {seen:?}"
+    );
+}
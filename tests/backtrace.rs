@@ -0,0 +1,68 @@
+use std::{env, io};
+
+use cucumber::{given, parser, writer, World as _, WriterExt as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a panicking step")]
+fn panicking_step(_: &mut World) {
+    panic!("boom");
+}
+
+#[derive(Default)]
+struct Output(Vec<u8>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+const FEATURE: &str = "\
+Feature: Panicking
+  Scenario: panic
+    Given a panicking step
+";
+
+async fn run(verbosity: writer::Verbosity) -> String {
+    env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    let mut output = Output::default();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(&mut output, writer::Coloring::Never, verbosity)
+                .discard_stats_writes()
+                .normalized(),
+        )
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+    String::from_utf8(output.0).unwrap()
+}
+
+#[tokio::test]
+async fn shows_backtrace_on_max_verbosity() {
+    let output = run(writer::Verbosity::ShowBacktrace).await;
+
+    assert!(output.contains("boom"), "no panic message:\n{output}");
+    assert!(output.contains("Backtrace:"), "no backtrace:\n{output}");
+    assert!(
+        output.contains("panicking_step"),
+        "no panicked step function in backtrace:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn hides_backtrace_by_default() {
+    let output = run(writer::Verbosity::ShowWorldAndDocString).await;
+
+    assert!(output.contains("boom"), "no panic message:\n{output}");
+    assert!(!output.contains("Backtrace:"), "backtrace shown:\n{output}");
+}
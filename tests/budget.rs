@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use cucumber::{given, parser, runner, ScenarioFailure, World as _};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"^sleeping for (\d+)ms$")]
+async fn sleep(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+// language=Gherkin
+const FEATURE: &str = "@budget(1m)
+Feature: Budgeted
+
+  @budget(10ms)
+  Scenario: slow
+    Given sleeping for 50ms
+
+  Scenario: fast
+    Given sleeping for 0ms
+";
+
+#[tokio::test]
+async fn fails_scenarios_exceeding_budget() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run_with_result([FEATURE])
+        .await;
+
+    assert!(res.execution_has_failed());
+    assert_eq!(res.steps.passed, 2);
+    assert_eq!(res.steps.failed, 0);
+    assert_eq!(res.budget_errors, 1);
+    assert_eq!(res.failed_scenarios.len(), 1);
+
+    let failed = &res.failed_scenarios[0];
+    assert_eq!(failed.scenario.name, "slow");
+    let ScenarioFailure::Budget(budget) = failed.error else {
+        panic!("unexpected failure: {:?}", failed.error);
+    };
+    assert_eq!(budget.limit, Duration::from_millis(10));
+    assert!(budget.elapsed >= Duration::from_millis(50), "{budget:?}");
+    assert!(
+        failed
+            .error
+            .to_string()
+            .starts_with("Scenario exceeded its budget of 10ms, taking ",),
+        "{}",
+        failed.error,
+    );
+}
+
+#[tokio::test]
+async fn only_warns_with_warn_policy() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .budget_policy(runner::BudgetPolicy::Warn)
+        .run_with_result([FEATURE])
+        .await;
+
+    assert!(!res.execution_has_failed());
+    assert_eq!(res.steps.passed, 2);
+    assert_eq!(res.budget_errors, 0);
+    assert!(res.failed_scenarios.is_empty());
+}
@@ -0,0 +1,83 @@
+use std::io;
+
+use cucumber::{cli, given, parser, writer, World as _, WriterExt as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "a {word} step")]
+fn step(_: &mut World, kind: String) {
+    println!("out: {kind}");
+    eprintln!("err: {kind}");
+    assert_eq!(kind, "passing", "step failed");
+}
+
+#[derive(Default)]
+struct Output(Vec<u8>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+const FEATURE: &str = "\
+Feature: Capturing
+  Scenario: passing
+    Given a passing step
+
+  Scenario: failing
+    Given a failing step
+";
+
+async fn run(show_captured: bool) -> String {
+    let mut output = Output::default();
+    let mut opts = cli::Opts::<_, _, writer::basic::Cli, cli::Empty>::default();
+    opts.writer.show_captured = show_captured;
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(&mut output, writer::Coloring::Never, 0)
+                .discard_stats_writes()
+                .normalized(),
+        )
+        .capture_output()
+        .with_cli(opts)
+        .run([FEATURE])
+        .await;
+    String::from_utf8(output.0).unwrap()
+}
+
+#[tokio::main]
+async fn main() {
+    let output = run(false).await;
+    assert!(
+        output.contains(
+            "      Captured stdout:\n        out: failing\n      \
+             Captured stderr:\n        err: failing",
+        ),
+        "no output of the failed step:\n{output}",
+    );
+    assert!(
+        !output.contains("out: passing"),
+        "output of the passed step:\n{output}",
+    );
+
+    let output = run(true).await;
+    assert!(
+        output.contains(
+            "      Captured stdout:\n        out: passing\n      \
+             Captured stderr:\n        err: passing",
+        ),
+        "no output of the passed step:\n{output}",
+    );
+    assert!(
+        output.contains("out: failing"),
+        "no output of the failed step:\n{output}",
+    );
+}
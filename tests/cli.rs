@@ -1,7 +1,9 @@
 use std::panic::AssertUnwindSafe;
 
 use clap::Parser;
-use cucumber::{cli, given, World as _};
+use cucumber::{
+    cli, given, parser, runner, then, writer, StatsWriter as _, World as _,
+};
 use futures::FutureExt as _;
 
 #[derive(cli::Args)]
@@ -29,6 +31,31 @@ fn invalid_step(_world: &mut World) {
     assert!(false);
 }
 
+#[derive(cli::Args, Clone)]
+struct TargetCli {
+    #[arg(long)]
+    target: String,
+}
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(from_cli = Self::from_target_cli)]
+struct TargetWorld {
+    target: String,
+}
+
+impl TargetWorld {
+    fn from_target_cli(cli: &TargetCli) -> Self {
+        Self {
+            target: cli.target.clone(),
+        }
+    }
+}
+
+#[then(expr = "the target is {word}")]
+fn target_is(world: &mut TargetWorld, target: String) {
+    assert_eq!(world.target, target);
+}
+
 // This test uses a subcommand with the global option `--tags` to filter on two
 // failing tests and verifies that the error output contains 2 failing steps.
 #[tokio::test]
@@ -101,3 +128,233 @@ async fn tags_option_filters_scenario1_no_subcommand() {
 
     assert_eq!(err, "1 step failed");
 }
+
+// This test verifies that the global option `--scenario-name` may be combined
+// with the `--tags` option and filters scenarios by their name.
+#[tokio::test]
+async fn scenario_name_option_composes_with_tags() {
+    let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
+        "test",
+        "--tags=@all",
+        "--scenario-name=One",
+    ])
+    .expect("Invalid command line");
+
+    let res = World::cucumber()
+        .with_cli(cli)
+        .run_and_exit("tests/features/cli");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "1 step failed");
+}
+
+// This test verifies that `--select PATH:LINE` selectors filter scenarios by
+// their location, including the lines of their steps.
+#[tokio::test]
+async fn line_selectors_filter_scenarios() {
+    let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
+        "test",
+        "--select",
+        "./features/cli/subcomand_global_option.feature:4",
+        "--select=subcomand_global_option.feature:10",
+    ])
+    .expect("Invalid command line");
+
+    let res = World::cucumber()
+        .with_cli(cli)
+        .run_and_exit("tests/features/cli");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "2 steps failed");
+}
+
+// This test verifies that `--select PATH:LINE` selectors pointing to an
+// `Examples` block select all the scenarios expanded from its rows.
+#[tokio::test]
+async fn line_selectors_select_examples() {
+    let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
+        "test",
+        "--select=outline.feature:6:13",
+    ])
+    .expect("Invalid command line");
+
+    let res = World::cucumber()
+        .with_cli(cli)
+        .run_and_exit("tests/features/select");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "3 steps failed");
+}
+
+// This test verifies that arguments after the `--` separator are left to an
+// application, while the preceding ones are parsed as `cli::Opts`.
+#[tokio::test]
+async fn leaves_rest_args_after_separator() {
+    let (cli, rest) =
+        cli::Opts::<_, _, _, CustomCli>::try_parse_with_rest_from([
+            "test",
+            "--tags=@scenario-1",
+            "--",
+            "--target=http://localhost",
+        ])
+        .expect("Invalid command line");
+
+    assert_eq!(rest, ["--target=http://localhost"]);
+
+    let res = World::cucumber()
+        .with_cli(cli)
+        .run_and_exit("tests/features/cli");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "1 step failed");
+}
+
+// This test verifies that `cli::Opts` can be embedded into an application's
+// own `clap::Command` and extracted back.
+#[tokio::test]
+async fn augments_user_command() {
+    type Opts = cli::Opts<
+        cucumber::parser::basic::Cli,
+        runner::basic::Cli,
+        writer::basic::Cli,
+    >;
+
+    let cmd = clap::Command::new("test")
+        .arg(clap::Arg::new("target").long("target").required(true));
+    let matches = Opts::augment(cmd)
+        .try_get_matches_from(["test", "--target=localhost", "--tags=@all"])
+        .expect("Invalid command line");
+
+    assert_eq!(
+        matches.get_one::<String>("target").map(String::as_str),
+        Some("localhost"),
+    );
+    let cli = Opts::from_matches(&matches).expect("Invalid matches");
+
+    let res = World::cucumber()
+        .with_cli(cli)
+        .run_and_exit("tests/features/cli");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "2 steps failed");
+}
+
+// This test verifies that `World` is created from the custom CLI options, once
+// they're exposed via `Cucumber::custom_cli()`.
+#[tokio::test]
+async fn world_is_created_from_custom_cli() {
+    let cli = cli::Opts::<_, _, _, TargetCli>::try_parse_from([
+        "test",
+        "--target=localhost",
+    ])
+    .expect("Invalid command line");
+
+    let feature = "Feature: CLI\n\
+                   \x20 Scenario: target\n\
+                   \x20   Then the target is localhost\n";
+    let writer = TargetWorld::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .custom_cli(cli.custom.clone())
+        .with_cli(cli)
+        .run([feature])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+// This test verifies that the `--list` option only prints the execution plan,
+// without running any scenarios.
+#[tokio::test]
+async fn list_option_runs_nothing() {
+    let cli =
+        cli::Opts::<_, _, _, CustomCli>::try_parse_from(["test", "--list"])
+            .expect("Invalid command line");
+
+    let writer = World::cucumber()
+        .with_cli(cli)
+        .run("tests/features/cli")
+        .await;
+
+    assert!(!writer.execution_has_failed());
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+// This test verifies that the `--print-config` option only prints the resolved
+// configuration, without running any scenarios.
+#[tokio::test]
+async fn print_config_option_runs_nothing() {
+    let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from([
+        "test",
+        "--print-config",
+        "--retry=3",
+    ])
+    .expect("Invalid command line");
+
+    let writer = World::cucumber()
+        .retries(1)
+        .with_cli(cli)
+        .run("tests/features/cli")
+        .await;
+
+    assert!(!writer.execution_has_failed());
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+// This test verifies that only the options specified in the command line
+// override the provided defaults.
+#[test]
+fn parses_over_defaults() {
+    type Opts = cli::Opts<cli::Empty, runner::basic::Cli, writer::basic::Cli>;
+
+    let mut defaults = Opts::default();
+    defaults.runner.fail_fast = true;
+    defaults.runner.concurrency = Some(4);
+    defaults.writer.verbose = 2;
+    defaults.writer.color = writer::Coloring::Never;
+
+    let opts = defaults
+        .clone()
+        .try_parse_over_from(["test", "--concurrency=8", "-v"])
+        .expect("Invalid command line");
+
+    assert!(opts.runner.fail_fast);
+    assert_eq!(opts.runner.concurrency, Some(8));
+    assert_eq!(opts.writer.verbose, 1);
+    assert!(matches!(opts.writer.color, writer::Coloring::Never));
+
+    let opts = defaults
+        .try_parse_over_from(["test"])
+        .expect("Invalid command line");
+
+    assert!(opts.runner.fail_fast);
+    assert_eq!(opts.runner.concurrency, Some(4));
+    assert_eq!(opts.writer.verbose, 2);
+}
@@ -0,0 +1,131 @@
+use std::fs;
+
+use cucumber::{cli, given, parser, then, when, World as _};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn foo(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+#[when("nothing happens")]
+fn nothing(_: &mut World) {}
+
+#[then("never checked")]
+fn never(_: &mut World) {}
+
+#[when(expr = "{word} equals {int}")]
+fn equals(_: &mut World, name: String, num: usize) {
+    assert_eq!((name.as_str(), num), ("bar", 1));
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Covered
+  Scenario: matched
+    Given foo is 0
+    When nothing happens
+    Then something undefined
+
+  Scenario: failing
+    Given foo is 1
+
+  Scenario: typed
+    When bar equals 1
+";
+
+#[tokio::test]
+async fn reports_matched_and_unused_definitions() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("coverage.json");
+
+    let cli = cli::Opts {
+        step_coverage: Some(path.clone()),
+        ..cli::Opts::<_, _, _, cli::Empty>::default()
+    };
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_cli(cli)
+            .run([FEATURE])
+            .await,
+    );
+
+    let report: Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let definition = |line: usize| {
+        let location = format!("tests/coverage.rs:{line}:1");
+        report["definitions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|d| d["location"] == location.as_str())
+            .unwrap_or_else(|| panic!("no `{location}` in:\n{report:#}"))
+    };
+
+    assert_eq!(
+        *definition(10),
+        json!({
+            "keyword": "Given",
+            "regex": r"foo is (\d+)",
+            "location": "tests/coverage.rs:10:1",
+            "steps": [{
+                "path": "Covered",
+                "line": 3,
+                "column": 5,
+                "text": "Given foo is 0",
+            }, {
+                "path": "Covered",
+                "line": 8,
+                "column": 5,
+                "text": "Given foo is 1",
+            }],
+        }),
+    );
+    assert_eq!(definition(15)["regex"], "^nothing happens$");
+    assert_eq!(
+        *definition(18),
+        json!({
+            "keyword": "Then",
+            "regex": "^never checked$",
+            "location": "tests/coverage.rs:18:1",
+            "steps": [],
+        }),
+    );
+    assert_eq!(
+        *definition(21),
+        json!({
+            "keyword": "When",
+            "regex": r"^([^\s]+) equals ((?:-?\d+)|(?:\d+))$",
+            "location": "tests/coverage.rs:21:1",
+            "expression": "{word} equals {int}",
+            "signature": "word, int parameters",
+            "steps": [{
+                "path": "Covered",
+                "line": 11,
+                "column": 5,
+                "text": "When bar equals 1",
+            }],
+        }),
+    );
+    assert_eq!(
+        report["unused"],
+        json!([{
+            "keyword": "Then",
+            "regex": "^never checked$",
+            "location": "tests/coverage.rs:18:1",
+        }]),
+    );
+    assert_eq!(
+        report["undefined"],
+        json!([{
+            "path": "Covered",
+            "line": 5,
+            "column": 5,
+            "text": "Then something undefined",
+        }]),
+    );
+}
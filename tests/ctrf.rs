@@ -0,0 +1,127 @@
+use std::{
+    fs,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use cucumber::{given, parser, writer, World as _, WriterExt as _};
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+static FLAKED: AtomicBool = AtomicBool::new(false);
+
+#[given("flaky")]
+fn flaky(_: &mut World) {
+    assert!(FLAKED.swap(true, Ordering::SeqCst), "first attempt");
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Reported
+  Scenario: passing
+    Given foo is 0
+
+  @retry(1)
+  Scenario: failing
+    Given foo is 1
+
+  Rule: rule
+    Scenario: skipped
+      Given bar
+
+    @retry(2)
+    Scenario: flaky
+      Given flaky
+";
+
+// language=Gherkin
+const MISTYPED: &str = "Featur: Mistyped
+  Scenario: passing
+    Given foo is 0
+";
+
+async fn run(feature: &str) -> Value {
+    let file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(writer::Ctrf::for_tee(
+                        file.reopen().unwrap(),
+                    ))
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run([feature])
+            .await,
+    );
+
+    serde_json::from_str(&fs::read_to_string(file.path()).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_report() {
+    let report = run(FEATURE).await;
+    let results = &report["results"];
+
+    assert_eq!(results["tool"]["name"], "cucumber");
+    let summary = &results["summary"];
+    assert_eq!(summary["tests"], 4);
+    assert_eq!(summary["passed"], 2);
+    assert_eq!(summary["failed"], 1);
+    assert_eq!(summary["skipped"], 1);
+    assert!(summary["start"].as_u64() <= summary["stop"].as_u64());
+
+    let mut tests = results["tests"].as_array().unwrap().clone();
+    assert_eq!(tests.len(), 4, "{tests:?}");
+    tests.sort_by_key(|t| t["line"].as_u64());
+
+    assert_eq!(tests[0]["name"], "passing");
+    assert_eq!(tests[0]["status"], "passed");
+    assert_eq!(tests[0]["suite"], "Reported");
+    assert_eq!(tests[0]["line"], 2);
+    assert_eq!(tests[0]["retries"], 0);
+    assert_eq!(tests[0]["flaky"], false);
+    assert_eq!(tests[0]["steps"][0]["name"], "Given foo is 0");
+    assert_eq!(tests[0]["steps"][0]["status"], "passed");
+    assert!(tests[0].get("message").is_none());
+
+    assert_eq!(tests[1]["name"], "failing");
+    assert_eq!(tests[1]["status"], "failed");
+    assert_eq!(tests[1]["retries"], 1);
+    assert_eq!(tests[1]["flaky"], false);
+    assert_eq!(tests[1]["tags"], serde_json::json!(["retry(1)"]));
+    assert_eq!(tests[1]["steps"].as_array().unwrap().len(), 1);
+    let message = tests[1]["message"].as_str().unwrap();
+    assert!(message.starts_with("Given foo is 1\n"), "{message}");
+
+    assert_eq!(tests[2]["name"], "skipped");
+    assert_eq!(tests[2]["status"], "skipped");
+    assert_eq!(tests[2]["suite"], "Reported > rule");
+    assert_eq!(tests[2]["steps"][0]["status"], "skipped");
+
+    assert_eq!(tests[3]["name"], "flaky");
+    assert_eq!(tests[3]["status"], "passed");
+    assert_eq!(tests[3]["retries"], 1);
+    assert_eq!(tests[3]["flaky"], true);
+    assert!(tests[3].get("message").is_none());
+}
+
+#[tokio::test]
+async fn reports_parsing_errors() {
+    let report = run(MISTYPED).await;
+    let results = &report["results"];
+
+    assert_eq!(results["summary"]["tests"], 1);
+    assert_eq!(results["summary"]["failed"], 1);
+    let name = results["tests"][0]["name"].as_str().unwrap();
+    assert!(name.starts_with("Parsing"), "{name}");
+}
@@ -0,0 +1,67 @@
+use std::fs;
+
+use cucumber::{cli, given, parser, World as _};
+use tempfile::TempDir;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+// language=Gherkin
+const FEATURE: &str = "@docs
+Feature: Documented
+  Describes the documented behaviour.
+
+  Scenario: passing
+    Given foo is 0
+
+  Rule: rule
+    @slow
+    Scenario: failing
+      Given foo is 1
+        | a | b |
+        | 1 | 2 |
+";
+
+#[tokio::test]
+async fn exports_markdown_tree() {
+    let dir = TempDir::new().unwrap();
+
+    let cli = cli::Opts {
+        export_docs: Some(dir.path().into()),
+        ..cli::Opts::<_, _, _, cli::Empty>::default()
+    };
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_cli(cli)
+            .run([FEATURE])
+            .await,
+    );
+
+    let index = fs::read_to_string(dir.path().join("index.md")).unwrap();
+    assert!(
+        index.contains(
+            "| [Feature: Documented](feature-0.feature.md) | ✘ failed | \
+             1 passed, 0 skipped, 1 failed |",
+        ),
+        "{index}",
+    );
+
+    let page =
+        fs::read_to_string(dir.path().join("feature-0.feature.md")).unwrap();
+    for part in [
+        "# Feature: Documented\n\n**Status:** ✘ failed\n\n**Tags:** `@docs`",
+        "Describes the documented behaviour.",
+        "## Scenario: passing\n\n**Status:** ✔ passed\n\n- **Given** foo is 0",
+        "## Rule: rule",
+        "### Scenario: failing\n\n**Status:** ✘ failed\n\n**Tags:** `@slow`",
+        "  | a | b |\n  |---|---|\n  | 1 | 2 |",
+    ] {
+        assert!(page.contains(part), "no `{part}` in:\n{page}");
+    }
+}
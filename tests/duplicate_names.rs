@@ -0,0 +1,105 @@
+use cucumber::{
+    cli, event, given, parser, Event, StatsWriter as _, World as _, Writer,
+    WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+// language=Gherkin
+const FIRST: &str = "\
+Feature: Duplicates
+  Scenario: same
+    Given a step
+
+  Scenario: unique
+    Given a step
+
+  Rule: rule
+    Scenario: same
+      Given a step
+
+  Scenario Outline: outline
+    Given a step
+
+    Examples:
+      | row |
+      | 1   |
+      | 2   |
+";
+
+// language=Gherkin
+const SECOND: &str = "\
+Feature: Duplicates
+  Scenario: same
+    Given a step
+";
+
+/// [`Writer`] collecting all the detected [`event::DuplicateName`]s.
+#[derive(Default)]
+struct DuplicatesWriter(Vec<String>);
+
+impl Writer<World> for DuplicatesWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::DuplicateName(dup)) =
+            ev.map(Event::into_inner)
+        {
+            self.0.push(dup.to_string());
+        }
+    }
+}
+
+#[tokio::test]
+async fn reports_duplicates() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(DuplicatesWriter::default().normalized())
+        .lint_duplicate_names()
+        .with_default_cli()
+        .run([FIRST, SECOND])
+        .await;
+
+    assert_eq!(
+        res.inner_writer().0,
+        [
+            "Feature name `Duplicates` is shared by: Duplicates:1:1, \
+             Duplicates:1:1",
+            "Scenario name `same` is shared in feature `Duplicates` by: \
+             Duplicates:2:3, Duplicates:9:5",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn does_nothing_by_default() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(DuplicatesWriter::default().normalized())
+        .with_default_cli()
+        .run([FIRST, SECOND])
+        .await;
+
+    assert!(res.inner_writer().0.is_empty());
+}
+
+#[tokio::test]
+async fn counts_in_summary() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .lint_duplicate_names()
+        .with_default_cli()
+        .run([FIRST, SECOND])
+        .await;
+
+    assert_eq!(writer.duplicate_names(), 2);
+    assert!(!writer.execution_has_failed());
+}
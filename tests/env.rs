@@ -0,0 +1,42 @@
+use std::env;
+
+use clap::Parser as _;
+use cucumber::{cli, parser, runner, writer};
+
+type Opts =
+    cli::Opts<parser::basic::Cli, runner::basic::Cli, writer::basic::Cli>;
+
+// This test sets process-wide environment variables, so it's kept in a separate
+// test binary to not affect other tests.
+#[test]
+fn cli_options_are_configurable_via_env_vars() {
+    env::set_var("CUCUMBER_TAGS", "@smoke");
+    env::set_var("CUCUMBER_CONCURRENCY", "3");
+    env::set_var("CUCUMBER_FAIL_FAST", "1");
+    env::set_var("CUCUMBER_RETRY", "2");
+    env::set_var("CUCUMBER_VERBOSE", "2");
+
+    let opts = Opts::try_parse_from(["test"]).expect("Invalid environment");
+
+    assert!(opts.tags_filter.unwrap().eval(["smoke"]));
+    assert_eq!(opts.runner.concurrency, Some(3));
+    assert!(opts.runner.fail_fast);
+    assert_eq!(opts.runner.retry, Some(2));
+    assert_eq!(opts.writer.verbose, 2);
+
+    env::remove_var("CUCUMBER_FAIL_FAST");
+    let opts = Opts::try_parse_from([
+        "test",
+        "--ff",
+        "--concurrency=5",
+        "--tags=@wip",
+    ])
+    .expect("Invalid command line");
+
+    assert!(
+        opts.tags_filter.unwrap().eval(["wip"]),
+        "command line should take precedence over environment",
+    );
+    assert_eq!(opts.runner.concurrency, Some(5));
+    assert!(opts.runner.fail_fast);
+}
@@ -0,0 +1,98 @@
+use std::{
+    env,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use cucumber::{given, parser, then, StatsWriter as _, World as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a slow step")]
+async fn slow(_: &mut World) {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[given("a very slow step")]
+async fn very_slow(_: &mut World) {
+    tokio::time::sleep(Duration::from_millis(200)).await;
+}
+
+#[given("a step failing once")]
+fn failing_once(_: &mut World) {
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    assert!(FAILED.swap(true, Ordering::SeqCst), "first attempt");
+}
+
+#[then(expr = "{word} is {string}")]
+fn var_is(_: &mut World, key: String, value: String) {
+    assert_eq!(env::var(key).ok(), Some(value));
+}
+
+#[then(expr = "{word} is unset")]
+fn var_is_unset(_: &mut World, key: String) {
+    assert!(env::var_os(&key).is_none(), "`{key}` is set");
+}
+
+// language=Gherkin
+const CONCURRENT: &str = "Feature: Concurrent
+  Scenario: short
+    Given a slow step
+
+  Scenario: untouched
+    Given a very slow step
+    Then CUCUMBER_ENV_TAGS_MODE is unset
+    And CUCUMBER_ENV_TAGS_LANG is unset
+";
+
+// language=Gherkin
+const FEATURE: &str = "@env(CUCUMBER_ENV_TAGS_LANG=en)
+Feature: Environment
+  @env(CUCUMBER_ENV_TAGS_MODE=first)
+  Scenario: first
+    Given a slow step
+    Then CUCUMBER_ENV_TAGS_MODE is \"first\"
+    And CUCUMBER_ENV_TAGS_LANG is \"en\"
+
+  @env(CUCUMBER_ENV_TAGS_MODE=second) @env(CUCUMBER_ENV_TAGS_LANG=de)
+  Scenario: second
+    Given a slow step
+    Then CUCUMBER_ENV_TAGS_MODE is \"second\"
+    And CUCUMBER_ENV_TAGS_LANG is \"de\"
+
+  Rule: inherited
+    @env(CUCUMBER_ENV_TAGS_EMPTY=)
+    Scenario: third
+      Given a slow step
+      Then CUCUMBER_ENV_TAGS_MODE is unset
+      And CUCUMBER_ENV_TAGS_EMPTY is \"\"
+      And CUCUMBER_ENV_TAGS_LANG is \"en\"
+
+    @retry(1).after(30ms) @env(CUCUMBER_ENV_TAGS_MODE=retried)
+    Scenario: retried while others are running
+      Given a step failing once
+      And a very slow step
+      Then CUCUMBER_ENV_TAGS_MODE is \"retried\"
+";
+
+#[tokio::test]
+async fn sets_and_restores_env_vars() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([CONCURRENT, FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 17);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.retried_steps(), 1);
+    for key in [
+        "CUCUMBER_ENV_TAGS_LANG",
+        "CUCUMBER_ENV_TAGS_MODE",
+        "CUCUMBER_ENV_TAGS_EMPTY",
+    ] {
+        assert!(env::var_os(key).is_none(), "`{key}` is not restored");
+    }
+}
@@ -0,0 +1,61 @@
+use cucumber::{
+    given, parser, step::Context, then, StatsWriter as _, World as _,
+};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "{int} apples")]
+fn apples(_: &mut World, #[step] ctx: &Context, count: usize) {
+    let example = ctx.example.as_ref().expect("no `Examples` row");
+    assert_eq!(example.get("count"), Some(count.to_string().as_str()));
+    assert_eq!(example.get("absent"), None);
+}
+
+#[then(expr = "row {int} of {string}")]
+fn row(_: &mut World, #[step] ctx: &Context, index: usize, name: String) {
+    let example = ctx.example.as_ref().expect("no `Examples` row");
+    assert_eq!(example.index, index);
+    assert_eq!(example.name.as_deref(), Some(name.as_str()));
+    assert_eq!(
+        example.iter().map(|(h, _)| h).collect::<Vec<_>>(),
+        ["count", "index", "name"],
+    );
+}
+
+#[given("no examples")]
+fn no_examples(_: &mut World, #[step] ctx: &Context) {
+    assert!(ctx.example.is_none(), "{:?}", ctx.example);
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Examples metadata
+  Scenario Outline: outline
+    Given <count> apples
+    Then row <index> of \"<name>\"
+
+    Examples: small
+      | count | index | name  |
+      | 1     | 0     | small |
+      | 2     | 1     | small |
+
+    Examples: large
+      | count | index | name  |
+      | 100   | 0     | large |
+
+  Scenario: plain
+    Given no examples
+";
+
+#[tokio::test]
+async fn exposes_examples_row() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 7);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.skipped_steps(), 0);
+}
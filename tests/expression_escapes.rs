@@ -0,0 +1,41 @@
+use cucumber::{given, parser, then, StatsWriter as _, World as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World(Vec<String>);
+
+#[given(expr = r"a \{literal\} {int} value")]
+fn literal_braces(w: &mut World, value: i32) {
+    w.0.push(format!("{{literal}} {value}"));
+}
+
+#[given(expr = r"a \(literal\) {word} value and a/an alternation(s)")]
+fn literal_parentheses(w: &mut World, value: String) {
+    w.0.push(format!("(literal) {value}"));
+}
+
+#[then(expr = r"{int} value(s) escaped")]
+fn escaped(w: &mut World, count: usize) {
+    assert_eq!(w.0.len(), count, "{:?}", w.0);
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Escapes
+  Scenario: escaped
+    Given a {literal} 42 value
+    And a (literal) word value and an alternation
+    And a (literal) word value and a alternations
+    Then 3 values escaped
+";
+
+#[tokio::test]
+async fn matches_escaped_characters() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.skipped_steps(), 0);
+}
@@ -1,4 +1,7 @@
-use cucumber::{runner, then, writer::summarize::Stats, World as _};
+use cucumber::{
+    cli, event, parser, runner, then, writer::summarize::Stats, Event,
+    World as _, Writer, WriterExt as _,
+};
 
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World;
@@ -15,10 +18,10 @@ fn nothing_happens(_: &mut World) {
 
 #[tokio::test]
 async fn correct_stats() {
-    for (feat, (p_sc, f_sc, r_sc, p_st, f_st, r_st)) in [
-        ("no_retry", (0, 1, 0, 0, 1, 0)),
-        ("retry", (0, 1, 1, 0, 1, 2)),
-        ("retry_delayed", (1, 1, 1, 1, 1, 2)),
+    for (feat, (p_sc, f_sc, r_sc, p_st, f_st, r_st), not_run) in [
+        ("no_retry", (0, 1, 0, 0, 1, 0), 1),
+        ("retry", (0, 1, 1, 0, 1, 2), 1),
+        ("retry_delayed", (1, 1, 1, 1, 1, 2), 0),
     ] {
         let writer = World::cucumber()
             .with_runner(
@@ -51,5 +54,96 @@ async fn correct_stats() {
             },
             "Wrong `Stats` for `Step`s in `{feat}`",
         );
+        assert_eq!(
+            writer.not_run_scenarios(),
+            not_run,
+            "Wrong number of not run `Scenario`s in `{feat}`",
+        );
+    }
+}
+
+/// [`Writer`] collecting names of all the emitted [`event::Feature`]s and
+/// [`event::Scenario`]s.
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl<W: 'static> Writer<W> for Events {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Feature, Scenario};
+
+        let Ok(event::Cucumber::Feature(_, ev)) = ev.map(Event::into_inner)
+        else {
+            return;
+        };
+        self.0.push(match ev {
+            Feature::Started => "Feature::Started".into(),
+            Feature::Aborted => "Feature::Aborted".into(),
+            Feature::Finished => "Feature::Finished".into(),
+            Feature::Rule(..) => return,
+            Feature::Scenario(sc, ev) => match ev.event {
+                Scenario::Started(_) => format!("{}: Started", sc.name),
+                Scenario::NotRun => format!("{}: NotRun", sc.name),
+                Scenario::Finished => format!("{}: Finished", sc.name),
+                _ => return,
+            },
+        });
     }
 }
+
+#[tokio::test]
+async fn emits_not_run_scenarios() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .steps(World::collection())
+                .max_concurrent_scenarios(1)
+                .fail_fast(),
+        )
+        .with_writer(Events::default().normalized())
+        .with_default_cli()
+        .run("tests/features/fail_fast/no_retry.feature")
+        .await;
+
+    assert_eq!(
+        writer.0,
+        [
+            "Feature::Started",
+            "1: Started",
+            "1: Finished",
+            "2: Started",
+            "2: NotRun",
+            "2: Finished",
+            "Feature::Aborted",
+            "Feature::Finished",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn does_not_abort_fully_run_features() {
+    let writer = World::cucumber()
+        .with_runner(
+            runner::Basic::default()
+                .steps(World::collection())
+                .max_concurrent_scenarios(1),
+        )
+        .with_writer(Events::default().normalized())
+        .with_default_cli()
+        .run("tests/features/fail_fast/no_retry.feature")
+        .await;
+
+    assert!(
+        !writer
+            .0
+            .iter()
+            .any(|e| e.contains("NotRun") || e.contains("Aborted")),
+        "unexpected events: {:?}",
+        writer.0,
+    );
+}
@@ -0,0 +1,71 @@
+use cucumber::{
+    given, parser, writer, writer::Coloring, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+// language=Gherkin
+const VALID: &str = "Feature: Valid
+
+  Scenario: passing
+    Given a passing step
+";
+
+// language=Gherkin
+const MISTYPED: &str = "Featur: Mistyped
+
+  Scenario: passing
+    Given a passing step
+";
+
+#[tokio::test]
+async fn fails_even_if_errors_are_not_counted() {
+    let mut out = Vec::<u8>::new();
+
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(&mut out, Coloring::Never, 0)
+                .discard_stats_writes()
+                .fail_on_parser_errors()
+                .normalized(),
+        )
+        .with_default_cli()
+        .run([VALID, MISTYPED])
+        .await;
+
+    assert!(res.execution_has_failed());
+    assert_eq!(res.parsing_errors(), 1);
+    assert_eq!(res.errors().len(), 1);
+    drop(res);
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(
+        out.contains("1 parsing error:\n  - Failed to parse feature: "),
+        "{out}",
+    );
+}
+
+#[tokio::test]
+async fn passes_without_errors() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(Vec::<u8>::new(), Coloring::Never, 0)
+                .summarized()
+                .fail_on_parser_errors()
+                .normalized(),
+        )
+        .with_default_cli()
+        .run([VALID])
+        .await;
+
+    assert!(!res.execution_has_failed());
+    assert_eq!(res.parsing_errors(), 0);
+    assert_eq!(res.passed_steps(), 1);
+}
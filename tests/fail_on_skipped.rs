@@ -0,0 +1,17 @@
+use cucumber::{StatsWriter as _, World as _};
+
+#[derive(cucumber::World, Clone, Copy, Debug, Default)]
+struct World;
+
+#[tokio::test]
+async fn allows_skipped_by_tags() {
+    let res = World::cucumber()
+        .fail_on_skipped_allowing("@wip or @allow.skipped".parse().unwrap())
+        .with_default_cli()
+        .run("tests/features/fail_on_skipped")
+        .await;
+
+    assert_eq!(res.passed_steps(), 0);
+    assert_eq!(res.skipped_steps(), 2);
+    assert_eq!(res.failed_steps(), 1);
+}
@@ -0,0 +1,138 @@
+use std::panic;
+
+use cucumber::{
+    event::{FailureCategory, StepError},
+    given, parser, ExitCodes, ScenarioFailure, World as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a database")]
+fn database(_: &mut World) {
+    FailureCategory::Infrastructure.fail("connection refused");
+}
+
+#[given("a slow service")]
+fn slow_service(_: &mut World) {
+    FailureCategory::Timeout.fail("no response in 5s");
+}
+
+#[given("a custom payload")]
+fn custom_payload(_: &mut World) {
+    panic::panic_any(Payload(42));
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Payload(u8);
+
+#[given("a wrong answer")]
+fn wrong_answer(_: &mut World) {
+    assert_eq!(2 + 2, 5, "math is broken");
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Categories
+  Scenario: infrastructure
+    Given a database
+
+  Scenario: timeout
+    Given a slow service
+
+  Scenario: assertion
+    Given a wrong answer
+";
+
+const CODES: ExitCodes = ExitCodes {
+    parsing_errors: 1,
+    hook_errors: 1,
+    step_failures: 2,
+    timeouts: 3,
+    infrastructure_failures: 4,
+    undefined_steps: 0,
+};
+
+#[tokio::test]
+async fn categorizes_failures() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run_with_result([FEATURE])
+        .await;
+
+    assert_eq!(res.steps.failed, 3);
+    let mut failures = res
+        .failed_scenarios
+        .iter()
+        .map(|f| (f.scenario.name.as_str(), f.error.category()))
+        .collect::<Vec<_>>();
+    failures.sort_unstable();
+    assert_eq!(
+        failures,
+        [
+            ("assertion", Some(FailureCategory::Assertion)),
+            ("infrastructure", Some(FailureCategory::Infrastructure)),
+            ("timeout", Some(FailureCategory::Timeout)),
+        ],
+    );
+
+    let infra = res
+        .failed_scenarios
+        .iter()
+        .find(|f| f.scenario.name == "infrastructure")
+        .unwrap();
+    let ScenarioFailure::Step(err @ StepError::Categorized(_, inner)) =
+        &infra.error
+    else {
+        panic!("wrong error: {:?}", infra.error);
+    };
+    assert!(matches!(**inner, StepError::Panic(..)), "{inner:?}");
+    assert_eq!(
+        err.to_string(),
+        "[infrastructure] Step panicked. Captured output: connection refused",
+    );
+
+    assert_eq!(res.exit_code(CODES), 2);
+}
+
+#[tokio::test]
+async fn uses_exit_code_of_category() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .filter_run_with_result([FEATURE], |_, _, sc| sc.name != "assertion")
+        .await;
+
+    assert_eq!(res.exit_code(CODES), 3);
+
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .filter_run_with_result([FEATURE], |_, _, sc| {
+            sc.name == "infrastructure"
+        })
+        .await;
+
+    assert_eq!(res.exit_code(CODES), 4);
+    assert_eq!(res.exit_code(ExitCodes::default()), 1);
+}
+
+#[tokio::test]
+async fn preserves_custom_panic_payload() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run_with_result(["Feature: Payload
+  Scenario: custom
+    Given a custom payload
+"])
+        .await;
+
+    let [failed] = res.failed_scenarios.as_slice() else {
+        panic!("wrong failures: {:?}", res.failed_scenarios);
+    };
+    let ScenarioFailure::Step(StepError::Panic(info, _)) = &failed.error else {
+        panic!("wrong error: {:?}", failed.error);
+    };
+    assert_eq!(info.downcast_ref::<Payload>(), Some(&Payload(42)));
+}
@@ -0,0 +1,113 @@
+use std::{iter, sync::LazyLock, time::Duration};
+
+use cucumber::{
+    given, parser,
+    runner::{Order, Timings},
+    World as _,
+};
+use futures::FutureExt as _;
+use itertools::Itertools as _;
+use tokio::sync::Mutex;
+
+static SCHEDULED: LazyLock<Mutex<Vec<String>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+fn feature(name: &str, scenarios: usize) -> String {
+    iter::once(format!("Feature: {name}\n"))
+        .chain(
+            (0..scenarios)
+                .map(|i| format!("  Scenario: {i}\n    Given a step\n")),
+        )
+        .collect()
+}
+
+/// Runs `Feature`s named with the provided `prefix` in the given [`Order`] and
+/// [`Timings`], returning names of the `Feature`s in order their `Scenario`s
+/// were started.
+async fn run(prefix: &str, order: Order, timings: Timings) -> Vec<String> {
+    let features =
+        [("b", 1), ("a", 2), ("d", 3), ("c", 1)].map(|(name, scenarios)| {
+            feature(&format!("{prefix}{name}"), scenarios)
+        });
+
+    drop(
+        World::cucumber::<String>()
+            .with_parser(parser::FromString::new())
+            .max_concurrent_scenarios(1)
+            .feature_order(order)
+            .timings(timings)
+            .before(|f, _, _, _| {
+                async {
+                    SCHEDULED.lock().await.push(f.name.clone());
+                }
+                .boxed_local()
+            })
+            .with_default_cli()
+            .run(features)
+            .await,
+    );
+
+    SCHEDULED
+        .lock()
+        .await
+        .iter()
+        .filter_map(|name| name.strip_prefix(prefix))
+        .dedup()
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+#[tokio::test]
+async fn as_discovered() {
+    assert_eq!(
+        run("discovered-", Order::AsDiscovered, Timings::new()).await,
+        ["b", "a", "d", "c"],
+    );
+}
+
+#[tokio::test]
+async fn alphabetical() {
+    assert_eq!(
+        run("alphabetical-", Order::Alphabetical, Timings::new()).await,
+        ["a", "b", "c", "d"],
+    );
+}
+
+#[tokio::test]
+async fn largest_first() {
+    assert_eq!(
+        run("largest-", Order::LargestFirst, Timings::new()).await,
+        ["d", "a", "b", "c"],
+    );
+}
+
+#[tokio::test]
+async fn custom() {
+    assert_eq!(
+        run(
+            "custom-",
+            Order::Custom(|a, b| b.name.cmp(&a.name)),
+            Timings::new(),
+        )
+        .await,
+        ["d", "c", "b", "a"],
+    );
+}
+
+#[tokio::test]
+async fn longest_scenarios_first() {
+    // `Scenario`s are located on the even lines, starting from the 2nd one.
+    let sec = Duration::from_secs(1);
+    let timings = Timings::new().with(None, 4, 2 * sec).with(None, 6, 5 * sec);
+
+    assert_eq!(
+        run("timings-", Order::AsDiscovered, timings).await,
+        ["d", "a", "d", "b", "a", "d", "c"],
+    );
+}
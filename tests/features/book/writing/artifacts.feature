@@ -0,0 +1,4 @@
+Feature: Login
+
+  Scenario: opening login page
+    Given a login page
@@ -0,0 +1,15 @@
+Feature: Examples tags
+
+  Scenario Outline: Outline scenario
+    Given foo is <num>
+
+    @smoke
+    Examples:
+      | num |
+      | 0   |
+
+    @regression
+    Examples:
+      | num |
+      | 0   |
+      | 0   |
@@ -0,0 +1,14 @@
+Feature: Allowing skipped steps by tags
+
+  @wip
+  Scenario: Work in progress
+    Given an undefined step
+
+  Scenario: Accidentally undefined
+    Given an undefined step
+
+  @wip
+  Rule: Rule in progress
+
+    Scenario: Inheriting rule tags
+      Given an undefined step
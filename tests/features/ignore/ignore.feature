@@ -0,0 +1,18 @@
+Feature: Ignored scenarios
+
+  Scenario: Regular
+    Given a passing step
+
+  @ignore
+  Scenario: Ignored
+    Given a failing step
+    And a failing step
+
+  @skip
+  Rule: Skipped rule
+
+    Background:
+      Given a failing step
+
+    Scenario: Inheriting skip
+      Given a passing step
@@ -0,0 +1,3 @@
+Feature: api orders
+  Scenario: orders
+    Given a step
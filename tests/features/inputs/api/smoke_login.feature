@@ -0,0 +1,3 @@
+Feature: api smoke login
+  Scenario: smoke_login
+    Given a step
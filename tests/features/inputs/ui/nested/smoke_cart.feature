@@ -0,0 +1,3 @@
+Feature: ui nested smoke cart
+  Scenario: smoke_cart
+    Given a step
@@ -0,0 +1,3 @@
+Feature: ui smoke home
+  Scenario: smoke_home
+    Given a step
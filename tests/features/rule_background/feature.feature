@@ -0,0 +1,10 @@
+Feature: Feature background
+  Background:
+    Given 1 sec
+    And 1 sec
+    And 1 sec
+    And 1 sec
+
+  Rule: rule
+    Scenario: scenario
+      Given 1 sec
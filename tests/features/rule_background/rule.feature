@@ -0,0 +1,12 @@
+Feature: Rule background
+  Background:
+    Given 1 sec
+
+  Rule: rule
+    Background:
+      Given 1 sec
+      And 1 sec
+      And 1 sec
+
+    Scenario: scenario
+      Given 1 sec
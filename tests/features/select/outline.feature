@@ -0,0 +1,13 @@
+Feature: Selectors of an outline
+
+  Scenario Outline: outline <n>
+    Given an invalid step
+
+    Examples: first
+      | n |
+      | 1 |
+      | 2 |
+
+    Examples: second
+      | n |
+      | 3 |
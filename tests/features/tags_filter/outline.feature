@@ -0,0 +1,24 @@
+Feature: Parse-time tags filtering
+
+  @smoke
+  Scenario: Plain scenario
+    Given foo is 0
+
+  Scenario Outline: Outline scenario
+    Given foo is <num>
+
+    @smoke
+    Examples:
+      | num |
+      | 0   |
+      | 0   |
+
+    @broken
+    Examples:
+      | unknown |
+      | 0       |
+
+  Rule: Rule without matching scenarios
+
+    Scenario: Untagged scenario
+      Given foo is 0
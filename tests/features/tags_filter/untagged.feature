@@ -0,0 +1,4 @@
+Feature: Feature without matching scenarios
+
+  Scenario: Untagged scenario
+    Given foo is 0
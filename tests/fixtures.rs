@@ -0,0 +1,110 @@
+use std::sync::Mutex;
+
+use cucumber::{
+    fixtures::{self, Fixture},
+    given, parser, then, StatsWriter as _, World as _,
+};
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[derive(cucumber::World, Debug, Default)]
+struct World {
+    set_up: Vec<&'static str>,
+}
+
+struct Logged {
+    name: &'static str,
+    deps: &'static [&'static str],
+    fails: bool,
+}
+
+impl Fixture<World> for Logged {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn dependencies(&self) -> &[&str] {
+        self.deps
+    }
+
+    async fn setup(&self, world: &mut World) -> Result<(), fixtures::Error> {
+        if self.fails {
+            return Err("connection refused".into());
+        }
+        world.set_up.push(self.name);
+        LOG.lock().unwrap().push(format!("setup {}", self.name));
+        Ok(())
+    }
+
+    async fn teardown(&self, world: &mut World) {
+        assert_eq!(world.set_up.pop(), Some(self.name), "wrong teardown order");
+        LOG.lock().unwrap().push(format!("teardown {}", self.name));
+    }
+}
+
+#[given("a world")]
+fn world(_: &mut World) {}
+
+#[then(expr = "fixtures {string} are set up")]
+fn are_set_up(w: &mut World, names: String) {
+    assert_eq!(w.set_up.join(", "), names);
+}
+
+const FEATURE: &str = "Feature: Fixtures
+  @fixture(api)
+  Scenario: with dependencies
+    Given a world
+    Then fixtures \"db, api\" are set up
+
+  @fixture(broken)
+  Scenario: failing setup
+    Given a world
+
+  @fixture(unknown)
+  Scenario: unknown fixture
+    Given a world
+
+  Scenario: no fixtures
+    Then fixtures \"\" are set up
+";
+
+#[tokio::test]
+async fn sets_up_and_tears_down_in_dependency_order() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .max_concurrent_scenarios(1)
+        .fixture(Logged {
+            name: "db",
+            deps: &[],
+            fails: false,
+        })
+        .fixture(Logged {
+            name: "api",
+            deps: &["db"],
+            fails: false,
+        })
+        .fixture(Logged {
+            name: "broken",
+            deps: &["db"],
+            fails: true,
+        })
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.hook_errors(), 2);
+    assert_eq!(writer.failed_scenarios(), 2);
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        [
+            "setup db",
+            "setup api",
+            "teardown api",
+            "teardown db",
+            "setup db",
+            "teardown db",
+        ],
+    );
+}
@@ -0,0 +1,89 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use cucumber::{given, parser, then, writer::Stats as _, World as _};
+use futures::FutureExt as _;
+
+#[derive(cucumber::World, Debug, Default)]
+struct World {
+    value: usize,
+}
+
+#[given(expr = "the value is {int}")]
+fn value_is(world: &mut World, value: usize) {
+    world.value = value;
+}
+
+#[then(expr = "the value equals {int}")]
+fn value_equals(world: &mut World, value: usize) {
+    assert_eq!(world.value, value);
+}
+
+/// Generates a `.feature` source checking the provided `value`.
+fn feature(value: usize) -> String {
+    format!(
+        "Feature: Generated {value}\n\
+         \x20 Scenario: value\n\
+         \x20   Given the value is {value}\n\
+         \x20   Then the value equals {value}\n",
+    )
+}
+
+#[tokio::test]
+async fn runs_raw_sources() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run((0..3).map(feature).collect::<Vec<_>>())
+        .await;
+
+    assert_eq!(writer.passed_steps(), 6);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.parsing_errors(), 0);
+}
+
+#[tokio::test]
+async fn uses_names_as_feature_paths() {
+    let paths = Arc::new(Mutex::new(Vec::new()));
+
+    let collected = Arc::clone(&paths);
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .before(move |f, _, _, _| {
+            collected.lock().unwrap().push(f.path.clone());
+            async {}.boxed_local()
+        })
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run([
+            ("generated/first.feature", feature(1)),
+            ("second", feature(2)),
+        ])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(
+        *paths.lock().unwrap(),
+        [
+            Some(PathBuf::from("generated/first.feature")),
+            Some(PathBuf::from("second")),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn reports_invalid_sources_as_parsing_errors() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([
+            ("valid.feature", feature(1)),
+            ("invalid.feature", "Featur: Mistyped\n".into()),
+        ])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.parsing_errors(), 1);
+}
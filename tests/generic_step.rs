@@ -0,0 +1,84 @@
+use cucumber::{parser, StatsWriter as _, World as _};
+
+use self::api::HasCounter;
+
+mod api {
+    use cucumber::{given, then, when};
+
+    pub trait HasCounter {
+        fn counter(&mut self) -> &mut u32;
+    }
+
+    #[given(expr = "a counter of {int}")]
+    pub fn counter_of<W: HasCounter>(w: &mut W, value: u32) {
+        *w.counter() = value;
+    }
+
+    #[when("the counter is incremented")]
+    pub async fn increment<W>(w: &mut W)
+    where
+        W: HasCounter,
+    {
+        *w.counter() += 1;
+    }
+
+    #[then(expr = "the counter is {int}")]
+    pub fn counter_is<W: HasCounter>(w: &mut W, expected: u32) {
+        assert_eq!(*w.counter(), expected);
+    }
+}
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(steps(api::counter_of, api::increment, api::counter_is))]
+struct World(u32);
+
+impl HasCounter for World {
+    fn counter(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+}
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(step = api::counter_of, step = api::counter_is)]
+struct Another {
+    count: u32,
+}
+
+impl HasCounter for Another {
+    fn counter(&mut self) -> &mut u32 {
+        &mut self.count
+    }
+}
+
+const FEATURE: &str = "Feature: Generic steps
+  Scenario: counter
+    Given a counter of 2
+    When the counter is incremented
+    Then the counter is 3
+";
+
+#[tokio::test]
+async fn monomorphizes_generic_steps() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn registers_only_listed_generic_steps() {
+    let writer = Another::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+}
@@ -0,0 +1,153 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use cucumber::{
+    event::ScenarioFinished,
+    fixtures::{self, Fixture},
+    given, parser, StatsWriter as _, World as _, WorldTeardown,
+};
+use futures::FutureExt as _;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"^sleeping for (\d+)ms$")]
+async fn sleep(_: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+}
+
+// language=Gherkin
+const FEATURE: &str = "\
+Feature: Global timeout
+  Scenario: fast
+    Given sleeping for 0ms
+
+  Scenario: hanging
+    Given sleeping for 60000ms
+
+  Scenario: never started
+    Given sleeping for 0ms
+
+  Rule: rule
+    Scenario: never started either
+      Given sleeping for 0ms
+";
+
+#[tokio::test]
+async fn interrupts_running_and_reports_remaining_scenarios_as_not_run() {
+    let started = Instant::now();
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .max_concurrent_scenarios(1)
+        .global_timeout(Duration::from_millis(500))
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert!(started.elapsed() < Duration::from_secs(30), "not cancelled");
+    assert!(writer.timed_out());
+    assert!(writer.execution_has_failed());
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.not_run_scenarios(), 2);
+}
+
+#[tokio::test]
+async fn fails_run_result() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .global_timeout(Duration::from_millis(500))
+        .with_default_cli()
+        .run_with_result([FEATURE])
+        .await;
+
+    assert!(res.timed_out);
+    assert!(res.execution_has_failed());
+    assert_eq!(res.exit_code(cucumber::ExitCodes::default()), 1);
+}
+
+#[tokio::test]
+async fn does_nothing_if_not_exceeded() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .global_timeout(Duration::from_secs(60))
+        .with_default_cli()
+        .run_with_result([FEATURE.replace("60000ms", "0ms").as_str()])
+        .await;
+
+    assert!(!res.timed_out);
+    assert!(!res.execution_has_failed());
+    assert_eq!(res.steps.passed, 4);
+}
+
+static TORN_DOWN: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(teardown)]
+struct TornDownWorld;
+
+impl WorldTeardown for TornDownWorld {
+    async fn teardown(&mut self) {
+        TORN_DOWN.lock().unwrap().push("world");
+    }
+}
+
+struct Resource;
+
+impl Fixture<TornDownWorld> for Resource {
+    fn name(&self) -> &str {
+        "resource"
+    }
+
+    async fn setup(
+        &self,
+        _: &mut TornDownWorld,
+    ) -> Result<(), fixtures::Error> {
+        Ok(())
+    }
+
+    async fn teardown(&self, _: &mut TornDownWorld) {
+        TORN_DOWN.lock().unwrap().push("fixture");
+    }
+}
+
+#[given("a hanging step")]
+async fn hanging(_: &mut TornDownWorld) {
+    tokio::time::sleep(Duration::from_secs(60)).await;
+}
+
+// language=Gherkin
+const HANGING_FEATURE: &str = "\
+Feature: Global timeout
+  @fixture(resource)
+  Scenario: hanging
+    Given a hanging step
+";
+
+#[tokio::test]
+async fn tears_down_interrupted_scenarios() {
+    let writer = TornDownWorld::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .fixture(Resource)
+        .after(|_, _, _, ev, world| {
+            assert!(matches!(ev, ScenarioFinished::StepFailed(..)), "{ev:?}");
+            assert!(world.is_some(), "`World` is lost");
+            TORN_DOWN.lock().unwrap().push("after hook");
+            async {}.boxed_local()
+        })
+        .global_timeout(Duration::from_millis(500))
+        .with_default_cli()
+        .run([HANGING_FEATURE])
+        .await;
+
+    assert!(writer.timed_out());
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.hook_errors(), 0);
+    assert_eq!(writer.not_run_scenarios(), 0);
+    assert_eq!(
+        *TORN_DOWN.lock().unwrap(),
+        ["after hook", "fixture", "world"]
+    );
+}
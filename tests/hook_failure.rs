@@ -0,0 +1,162 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cucumber::{
+    given, parser, runner::HookFailurePolicy, then, writer, StatsWriter as _,
+    World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a world")]
+fn world(_: &mut World) {}
+
+#[then("it passes")]
+fn passes(_: &mut World) {}
+
+const FEATURE: &str = "Feature: Hook failure
+  Scenario: first
+    Given a world
+    Then it passes
+
+  Scenario: second
+    Given a world
+    Then it passes
+";
+
+#[tokio::test]
+async fn fails_scenario_by_default() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .after(|_, _, _, _, _| async { panic!("upload failed") }.boxed_local())
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.hook_errors(), 2);
+    assert!(writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn ignores_after_hook_failure() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .after(|_, _, _, _, _| async { panic!("upload failed") }.boxed_local())
+        .after_hook_failure(HookFailurePolicy::Ignore)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.hook_errors(), 0);
+    assert!(!writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn runs_steps_on_ignored_before_hook_failure() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .before(|_, _, _, _| async { panic!("setup failed") }.boxed_local())
+        .before_hook_failure(HookFailurePolicy::Ignore)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.hook_errors(), 0);
+    assert!(!writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn skips_scenario_on_before_hook_failure() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .before(|_, _, _, _| async { panic!("setup failed") }.boxed_local())
+        .before_hook_failure(HookFailurePolicy::SkipScenario)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.skipped_steps(), 2);
+    assert_eq!(writer.hook_errors(), 0);
+    assert!(!writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn retries_scenario_on_hook_failure() {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .before(|_, _, sc, _| {
+            async move {
+                let run = RUNS.fetch_add(1, Ordering::SeqCst);
+                assert!(sc.name != "first" || run > 0, "flaky setup failed",);
+            }
+            .boxed_local()
+        })
+        .before_hook_failure(HookFailurePolicy::RetryScenario)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.hook_errors(), 1);
+}
+
+#[tokio::test]
+async fn aborts_run_on_hook_failure() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .before(|_, _, _, _| async { panic!("setup failed") }.boxed_local())
+        .before_hook_failure(HookFailurePolicy::AbortRun)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.hook_errors(), 1);
+    assert!(writer.execution_has_failed());
+    assert_eq!(writer.passed_steps(), 0);
+}
+
+#[tokio::test]
+async fn labels_hooks_with_lexicon() {
+    let mut output = Vec::new();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(&mut output, writer::Coloring::Never, 0)
+                .lexicon(writer::Lexicon {
+                    after_hook: "Nach-Hook".into(),
+                    ..writer::Lexicon::default()
+                })
+                .discard_stats_writes()
+                .normalized(),
+        )
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .after(|_, _, _, _, _| async { panic!("upload failed") }.boxed_local())
+        .run([FEATURE])
+        .await;
+
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(
+        output.contains("Scenario's Nach-Hook failed"),
+        "no localized hook label in output:\n{output}",
+    );
+    assert!(
+        !output.contains("After hook"),
+        "English hook label in output:\n{output}",
+    );
+}
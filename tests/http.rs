@@ -0,0 +1,68 @@
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    net::TcpListener,
+    thread,
+};
+
+use cucumber::{given, parser, StatsWriter as _, World as _};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+/// Spawns an HTTP server serving a single `/foo.feature` file, responding with
+/// `404 Not Found` on any other path, and returns its base URL.
+fn serve(requests: usize) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    _ = thread::spawn(move || {
+        for stream in listener.incoming().take(requests) {
+            let mut stream = stream.unwrap();
+
+            let mut request_line = String::new();
+            _ = BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .unwrap();
+
+            let (status, body) =
+                if request_line.starts_with("GET /foo.feature ") {
+                    (
+                    "200 OK",
+                    "Feature: Remote\n  Scenario: foo\n    Given foo is 0\n",
+                )
+                } else {
+                    ("404 Not Found", "")
+                };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\n\
+                 Content-Length: {}\r\n\
+                 Connection: close\r\n\r\n\
+                 {body}",
+                body.len(),
+            )
+            .unwrap();
+        }
+    });
+
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn parses_downloaded_features() {
+    let url = serve(2);
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::Http::new())
+        .with_default_cli()
+        .run([format!("{url}/foo.feature"), format!("{url}/bar.feature")])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.parsing_errors(), 1);
+}
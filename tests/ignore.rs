@@ -0,0 +1,40 @@
+use cucumber::{given, writer::summarize::Stats, World as _};
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("ignored scenarios shouldn't be run");
+}
+
+#[derive(Clone, Copy, Debug, Default, cucumber::World)]
+struct World;
+
+#[tokio::test]
+async fn reports_ignored_as_skipped() {
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/ignore")
+        .await;
+
+    assert_eq!(
+        *writer.scenarios_stats(),
+        Stats {
+            passed: 1,
+            skipped: 2,
+            failed: 0,
+            retried: 0
+        },
+    );
+    assert_eq!(
+        *writer.steps_stats(),
+        Stats {
+            passed: 1,
+            skipped: 2,
+            failed: 0,
+            retried: 0
+        },
+    );
+}
@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+
+use cucumber::{
+    given, parser::basic::IntoInputs, writer::Stats as _, World as _,
+};
+use futures::FutureExt as _;
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+/// Runs the provided `input`, returning names of the `Feature`s in order their
+/// `Scenario`s were started.
+async fn run(input: impl IntoInputs) -> Vec<String> {
+    let started = Arc::new(Mutex::new(Vec::new()));
+
+    let names = Arc::clone(&started);
+    drop(
+        World::cucumber()
+            .max_concurrent_scenarios(1)
+            .before(move |f, _, _, _| {
+                names.lock().unwrap().push(f.name.clone());
+                async {}.boxed_local()
+            })
+            .with_default_cli()
+            .run(input.into_inputs())
+            .await,
+    );
+
+    let started = started.lock().unwrap().clone();
+    started
+}
+
+#[tokio::test]
+async fn matches_glob_patterns() {
+    assert_eq!(
+        run(["tests/features/inputs/**/smoke_*.feature"]).await,
+        ["api smoke login", "ui nested smoke cart", "ui smoke home"],
+    );
+}
+
+#[tokio::test]
+async fn keeps_order_of_inputs() {
+    assert_eq!(
+        run(["tests/features/inputs/ui", "tests/features/inputs/api"]).await,
+        [
+            "ui nested smoke cart",
+            "ui smoke home",
+            "api orders",
+            "api smoke login",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn dedups_overlapping_inputs() {
+    assert_eq!(
+        run(vec![
+            "tests/features/inputs/ui/smoke_home.feature",
+            "tests/features/inputs",
+            "tests/features/inputs/**/*.feature",
+        ])
+        .await,
+        [
+            "ui smoke home",
+            "api orders",
+            "api smoke login",
+            "ui nested smoke cart",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn runs_arrays_of_inputs() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run(["tests/features/inputs/api", "tests/features/inputs/ui"])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+}
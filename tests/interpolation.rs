@@ -0,0 +1,58 @@
+use std::env;
+
+use cucumber::{
+    feature::Interpolation, gherkin::Step, given, parser, StatsWriter as _,
+    World as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "I open {word}")]
+fn open(_: &mut World, url: String) {
+    assert_eq!(url, "http://localhost:8080/login");
+}
+
+#[given(expr = "I sign in as {word}")]
+fn sign_in(_: &mut World, user: String, step: &Step) {
+    let table = step.table.as_ref().expect("no data table");
+
+    assert_eq!(user, "admin");
+    assert_eq!(table.rows[0][0], "admin");
+    assert_eq!(table.rows[0][1], "${CUCUMBER_TEST_UNKNOWN}");
+}
+
+const FEATURE: &str = "\
+Feature: Interpolation
+  Scenario: login
+    Given I open ${CUCUMBER_TEST_BASE_URL}/login
+    And I sign in as {{config.user}}
+      | {{ config.user }} | ${CUCUMBER_TEST_UNKNOWN} |
+";
+
+#[tokio::test]
+async fn interpolates_env_and_config() {
+    env::set_var("CUCUMBER_TEST_BASE_URL", "http://localhost:8080");
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(
+            parser::FromString::new()
+                .interpolate(Interpolation::new().config("user", "admin")),
+        )
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn does_not_interpolate_by_default() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 1);
+}
@@ -1,6 +1,9 @@
 use std::{fs, io::Read as _};
 
-use cucumber::{given, then, when, writer, World as _};
+use cucumber::{
+    given, parser, runner, then, when, writer, World as _, Writer as _,
+    WriterExt as _,
+};
 use futures::FutureExt as _;
 use regex::RegexBuilder;
 use tempfile::NamedTempFile;
@@ -83,5 +86,244 @@ async fn test() {
     );
 }
 
+#[tokio::test]
+async fn renders_uri_in_path_style() {
+    for (style, uri) in [
+        (
+            writer::PathStyle::RelativeToWorkspace,
+            "tests/features/wait/outline.feature".to_owned(),
+        ),
+        (
+            writer::PathStyle::RelativeToManifest,
+            "tests/features/wait/outline.feature".to_owned(),
+        ),
+        (
+            writer::PathStyle::Absolute,
+            format!(
+                "{}/tests/features/wait/outline.feature",
+                env!("CARGO_MANIFEST_DIR").replace('\\', "/"),
+            ),
+        ),
+    ] {
+        let mut file = NamedTempFile::new().unwrap();
+        drop(
+            World::cucumber()
+                .with_writer(
+                    writer::Json::raw(file.reopen().unwrap())
+                        .path_style(style)
+                        .normalized(),
+                )
+                .with_default_cli()
+                .run("tests/features/wait/outline.feature")
+                .await,
+        );
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).unwrap();
+
+        assert!(
+            buffer.contains(&format!("\"uri\":\"{uri}\"")),
+            "no `{uri}` URI for `{style:?}` in: {buffer}",
+        );
+    }
+}
+
+#[tokio::test]
+async fn reports_created_file_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.json");
+
+    let writer = World::cucumber()
+        .with_writer(writer::Json::create(&path).unwrap().normalized())
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/wait/outline.feature")
+        .await;
+
+    assert_eq!(writer.report_paths(), [path.clone()]);
+    assert!(
+        fs::read_to_string(&path).unwrap().contains("\"uri\""),
+        "no report written into `{}`",
+        path.display(),
+    );
+}
+
+#[tokio::test]
+async fn deterministic() {
+    async fn run(features: [&str; 2]) -> String {
+        let mut file = NamedTempFile::new().unwrap();
+        drop(
+            Golden::cucumber::<&str>()
+                .with_parser(parser::FromString::new())
+                .with_writer(
+                    writer::Json::raw(file.reopen().unwrap())
+                        .deterministic(true)
+                        .normalized(),
+                )
+                .with_default_cli()
+                .run(features)
+                .await,
+        );
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).unwrap();
+        buffer
+    }
+
+    let first = "\
+Feature: First
+  Scenario: one
+    Given golden
+
+  Scenario: two
+    Given golden
+";
+    let second = "\
+Feature: Second
+  Scenario: three
+    Given golden
+";
+
+    let output = run([first, second]).await;
+
+    assert_eq!(output, run([second, first]).await);
+    assert!(
+        !RegexBuilder::new("\"duration\":[1-9]")
+            .build()
+            .unwrap()
+            .is_match(&output),
+        "{output}",
+    );
+    assert!(
+        output.find("First").unwrap() < output.find("Second").unwrap(),
+        "{output}",
+    );
+}
+
+#[tokio::test]
+async fn separates_rule_background() {
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(writer::Json::raw(file.reopen().unwrap()).normalized())
+            .with_default_cli()
+            .run("tests/features/rule_background/rule.feature")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+    let output = RegexBuilder::new(",\"duration\":\\d+")
+        .build()
+        .unwrap()
+        .replace_all(&buffer, "");
+
+    assert!(
+        output.contains(
+            "{\"keyword\":\"Background\",\"type\":\"background\",\
+             \"id\":\"rule-background/rule/scenario\",\"line\":11,\
+             \"name\":\"rule scenario\",\"tags\":[],\"steps\":[{\
+             \"keyword\":\"Given \",\"line\":3,\"name\":\"1 sec\",\
+             \"result\":{\"status\":\"passed\"}}]}",
+        ),
+        "`Feature`'s `Background` contains foreign steps: {output}",
+    );
+    assert!(
+        output.contains(
+            "{\"keyword\":\"Background\",\"type\":\"background\",\
+             \"id\":\"rule-background/rule/scenario\",\"line\":6,\
+             \"name\":\"rule scenario\",\"tags\":[],\"steps\":[{\
+             \"keyword\":\"Given \",\"line\":7,",
+        ),
+        "no separate `Rule`'s `Background`: {output}",
+    );
+}
+
+#[tokio::test]
+async fn renders_start_timestamps_in_format() {
+    for (format, deterministic, timestamp) in [
+        (
+            writer::TimestampFormat::Rfc3339,
+            false,
+            r#""start_timestamp":"\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d\.\d{3}Z""#,
+        ),
+        (
+            writer::TimestampFormat::Rfc3339,
+            true,
+            r#""start_timestamp":"1970-01-01T00:00:00\.000Z""#,
+        ),
+        (
+            writer::TimestampFormat::EpochMillis,
+            false,
+            r#""start_timestamp":[1-9]\d{12}[,}]"#,
+        ),
+        (
+            writer::TimestampFormat::EpochMillis,
+            true,
+            r#""start_timestamp":0[,}]"#,
+        ),
+    ] {
+        let mut file = NamedTempFile::new().unwrap();
+        drop(
+            World::cucumber()
+                .with_writer(
+                    writer::Json::raw(file.reopen().unwrap())
+                        .timestamp_format(format)
+                        .timezone(writer::Timezone::Utc)
+                        .deterministic(deterministic)
+                        .normalized(),
+                )
+                .with_default_cli()
+                .run("tests/features/wait/outline.feature")
+                .await,
+        );
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).unwrap();
+
+        assert!(
+            RegexBuilder::new(timestamp)
+                .build()
+                .unwrap()
+                .is_match(&buffer),
+            "no `{timestamp}` timestamp for `{format:?}` in: {buffer}",
+        );
+        assert_eq!(
+            buffer.matches("\"start_timestamp\"").count(),
+            buffer.matches("\"type\":").count(),
+            "not every element has a `start_timestamp`: {buffer}",
+        );
+    }
+}
+
+#[tokio::test]
+async fn feeds_timings_back_into_runner() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.json");
+
+    drop(
+        World::cucumber()
+            .with_writer(writer::Json::create(&path).unwrap().normalized())
+            .with_default_cli()
+            .run("tests/features/wait/outline.feature")
+            .await,
+    );
+
+    let timings = runner::Timings::from_json_report(&path).unwrap();
+    assert!(!timings.is_empty(), "no timings read from the report");
+
+    let feature = "tests/features/wait/outline.feature";
+    assert!(
+        runner::Timings::from_json_report(feature).is_err(),
+        "timings read from a non-report file",
+    );
+}
+
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World(usize);
+
+#[given("golden")]
+fn golden(_: &mut Golden) {}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct Golden;
@@ -1,6 +1,9 @@
-use std::{fs, io::Read as _};
+use std::{fs, io::Read as _, panic::AssertUnwindSafe};
 
-use cucumber::{given, then, when, writer, World as _};
+use cucumber::{
+    cli, event, given, parser, then, when, writer, Event, World as _, Writer,
+    WriterExt as _,
+};
 use futures::FutureExt as _;
 use regex::RegexBuilder;
 use tempfile::NamedTempFile;
@@ -72,3 +75,175 @@ async fn output() {
 
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World(usize);
+
+#[given("golden")]
+fn golden(_: &mut Golden) {}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct Golden;
+
+#[tokio::test]
+async fn deterministic() {
+    async fn run(features: [&str; 2]) -> String {
+        let mut file = NamedTempFile::new().unwrap();
+        drop(
+            Golden::cucumber::<&str>()
+                .with_parser(parser::FromString::new())
+                .with_writer(
+                    writer::JUnit::raw(file.reopen().unwrap(), 0)
+                        .deterministic(true)
+                        .normalized(),
+                )
+                .with_default_cli()
+                .run(features)
+                .await,
+        );
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).unwrap();
+        buffer
+    }
+
+    // language=Gherkin
+    let first = "\
+Feature: First
+  Scenario: one
+    Given golden
+
+  Scenario: two
+    Given golden
+";
+    // language=Gherkin
+    let second = "\
+Feature: Second
+  Scenario: three
+    Given golden
+";
+
+    let output = run([first, second]).await;
+
+    assert_eq!(output, run([second, first]).await);
+    assert!(
+        output.contains("timestamp=\"1970-01-01T00:00:00Z\""),
+        "{output}",
+    );
+    assert!(!output.contains("time=\"0."), "{output}");
+    assert!(
+        output.find("First").unwrap() < output.find("Second").unwrap(),
+        "{output}",
+    );
+}
+
+#[tokio::test]
+async fn test_keys() {
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(writer::JUnit::new(file.reopen().unwrap(), 0))
+            .with_default_cli()
+            .run([
+                // language=Gherkin
+                "@testkey(PROJ-1)
+Feature: Keyed
+  Scenario: inherited
+    Given 1 sec
+
+  @testkey(PROJ-2)
+  Scenario: own
+    Given 1 sec
+",
+            ])
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    for key in ["PROJ-1", "PROJ-2"] {
+        assert!(
+            buffer.contains(&format!(
+                "<properties>\
+                 <property name=\"test_key\" value=\"{key}\"/>\
+                 </properties>",
+            )),
+            "no `{key}` in: {buffer}",
+        );
+    }
+}
+
+#[tokio::test]
+async fn distinguishes_background_failures() {
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(writer::JUnit::new(file.reopen().unwrap(), 0))
+            .with_default_cli()
+            .run("tests/features/rule_background")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    assert!(
+        buffer.contains("type=\"Background Step Panicked\""),
+        "no `Feature`'s `Background` failure in: {buffer}",
+    );
+    assert!(
+        buffer.contains("type=\"Rule Background Step Panicked\""),
+        "no `Rule`'s `Background` failure in: {buffer}",
+    );
+}
+
+/// [`Writer`] panicking on [`event::Cucumber::Finished`].
+struct PanicOnFinished;
+
+impl<W: 'static> Writer<W> for PanicOnFinished {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Finished { .. }) = ev.map(Event::into_inner)
+        {
+            panic!("writer failed");
+        }
+    }
+}
+
+#[tokio::test]
+async fn finalizes_report_on_panic_in_another_writer() {
+    let mut file = NamedTempFile::new().unwrap();
+    let res = AssertUnwindSafe(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                PanicOnFinished
+                    .tee::<World, _>(writer::JUnit::raw(
+                        file.reopen().unwrap(),
+                        0,
+                    ))
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run([
+                // language=Gherkin
+                "Feature: Finalized
+  Scenario: passing
+    Given 1 sec
+",
+            ]),
+    )
+    .catch_unwind()
+    .await;
+    assert!(res.is_err(), "`Cucumber::run()` hasn't panicked");
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    assert!(buffer.contains("name=\"Feature: Finalized"), "{buffer}");
+    assert!(buffer.trim_end().ends_with("</testsuites>"), "{buffer}");
+}
@@ -85,5 +85,43 @@ async fn output_report_time() {
     );
 }
 
+#[tokio::test]
+async fn output_location_hints() {
+    let mut cli = cli::Opts::<_, _, writer::libtest::Cli>::default();
+    cli.writer.location_hints = true;
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(
+                writer::Libtest::new(file.reopen().unwrap()).normalized(),
+            )
+            .fail_on_skipped()
+            .with_cli(cli)
+            .run("tests/features/wait")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    let hint = Regex::new(
+        "\"locationHint\":\"file:///[^\"]*/tests/features/wait/\
+         [A-z1-9-_/]*\\.feature:\\d+\"",
+    )
+    .unwrap();
+    let tests = buffer.lines().filter(|l| l.contains("\"type\":\"test\""));
+
+    let mut count = 0;
+    for line in tests {
+        assert!(hint.is_match(line), "no `locationHint` in: {line}");
+        count += 1;
+    }
+    assert!(count > 0, "no test events in output:\n{buffer}");
+    assert!(
+        buffer.contains("outline.feature:5\""),
+        "no `locationHint` of a step line in output:\n{buffer}",
+    );
+}
+
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World(usize);
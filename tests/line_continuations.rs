@@ -0,0 +1,76 @@
+use cucumber::{
+    given, parser, step::Context, then, StatsWriter as _, World as _,
+};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "a user {string} with the {word} role in the {word} tenant")]
+fn user(_: &mut World, #[step] ctx: &Context) {
+    assert_eq!(ctx.step.position.line, 3);
+    assert_eq!(
+        ctx.step.value,
+        "a user \"alice\" with the admin role in the default tenant",
+    );
+}
+
+#[then("the doc string is intact")]
+fn docstring(_: &mut World, #[step] ctx: &Context) {
+    assert_eq!(ctx.step.position.line, 7);
+    assert_eq!(
+        ctx.step.docstring.as_deref(),
+        Some("\nends with \\\nbackslash\n"),
+    );
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Continuations
+  Scenario: wrapped
+    Given a user \"alice\" \\
+      with the admin role \\
+      in the default tenant
+    # comment ending with \\
+    Then the doc string is intact
+      \"\"\"
+      ends with \\
+      backslash
+      \"\"\"
+";
+
+#[tokio::test]
+async fn joins_continued_lines() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new().line_continuations(true))
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.parsing_errors(), 0);
+}
+
+#[tokio::test]
+async fn fails_without_opting_in() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.parsing_errors(), 1);
+}
+
+#[tokio::test]
+async fn joins_continued_lines_of_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("wrapped.feature"), FEATURE).unwrap();
+
+    let writer = World::cucumber::<&std::path::Path>()
+        .with_parser(parser::Basic::new().line_continuations(true))
+        .with_default_cli()
+        .run(dir.path())
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.parsing_errors(), 0);
+}
@@ -0,0 +1,43 @@
+use std::io;
+
+use cucumber::{given, writer, writer::Coloring, World as _, WriterExt as _};
+
+#[tokio::main]
+async fn main() {
+    let mut out = Vec::<u8>::new();
+
+    let writer = writer::Basic::raw(&mut out, Coloring::Never, 0)
+        .discard_stats_writes()
+        .tee::<World, _>(
+            writer::Basic::raw(io::stdout(), Coloring::Never, 0).summarized(),
+        )
+        .normalized();
+    World::cucumber()
+        .with_writer(writer)
+        .with_default_cli()
+        .init_log(log::LevelFilter::Info)
+        .run("tests/features/wait/nested/rule.feature")
+        .await;
+
+    let out = String::from_utf8_lossy(&out);
+    assert_eq!(
+        out.matches(" INFO log: kept: 1").count(),
+        5,
+        "output:\n{out}"
+    );
+    assert_eq!(
+        out.matches(" INFO log: kept: 2").count(),
+        1,
+        "output:\n{out}"
+    );
+    assert!(!out.contains("filtered"), "output:\n{out}");
+}
+
+#[given(regex = r"(\d+) secs?")]
+async fn step(_: &mut World, secs: u64) {
+    log::info!("kept: {secs}");
+    log::debug!("filtered: {secs}");
+}
+
+#[derive(Clone, cucumber::World, Debug, Default)]
+struct World;
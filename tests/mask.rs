@@ -0,0 +1,74 @@
+use std::fs;
+
+use cucumber::{given, mask, parser, then, writer, World as _, WriterExt as _};
+use regex::Regex;
+use tempfile::NamedTempFile;
+
+const API_KEY: &str = "s3cr3t-k3y";
+
+#[derive(Clone, cucumber::World, Debug, Default)]
+struct World {
+    key: String,
+}
+
+#[given(expr = "the API key {string}")]
+fn api_key(w: &mut World, key: String) {
+    w.key = key;
+}
+
+#[then("the request is authorized")]
+fn authorized(w: &mut World) {
+    panic!(
+        "unauthorized with `{}` key and `Bearer abc.def` token",
+        w.key
+    );
+}
+
+const FEATURE: &str = "\
+Feature: Secrets
+  Scenario: masked
+    Given the API key \"s3cr3t-k3y\"
+    Then the request is authorized
+      \"\"\"
+      Authorization: Bearer abc.def
+      \"\"\"
+";
+
+#[tokio::test]
+async fn masks_secrets() {
+    let file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::raw(
+                    file.reopen().unwrap(),
+                    writer::Coloring::Never,
+                    writer::Verbosity::ShowWorldAndDocString,
+                )
+                .summarized()
+                .normalized(),
+            )
+            .mask_secret(API_KEY)
+            .mask_secret(Regex::new(r"Bearer \S+").unwrap())
+            .with_default_cli()
+            .run([FEATURE])
+            .await,
+    );
+
+    let output = fs::read_to_string(file.path()).unwrap();
+
+    assert!(!output.contains(API_KEY), "secret is not masked:\n{output}");
+    assert!(
+        !output.contains("abc.def"),
+        "token is not masked:\n{output}"
+    );
+    assert!(
+        output.contains(&format!("Given the API key \"{}\"", mask::MASK)),
+        "step is not masked:\n{output}",
+    );
+    assert!(
+        output.contains(&format!("key: \"{}\"", mask::MASK)),
+        "`World` is not masked:\n{output}",
+    );
+}
@@ -0,0 +1,145 @@
+use std::{
+    fs,
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::mpsc,
+    thread,
+};
+
+use cucumber::{
+    given, parser,
+    writer::{self, metrics},
+    World as _, WriterExt as _,
+};
+use tempfile::NamedTempFile;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Measured
+  Scenario: passing
+    Given foo is 0
+
+  @retry(1)
+  Scenario: failing
+    Given foo is 1
+
+  Scenario: skipped
+    Given bar
+";
+
+#[tokio::test]
+async fn outputs_open_metrics() {
+    let file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(
+                        writer::Metrics::raw(file.reopen().unwrap())
+                            .discard_stats_writes()
+                            .discard_arbitrary_writes(),
+                    )
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run([FEATURE])
+            .await,
+    );
+
+    let out = fs::read_to_string(file.path()).unwrap();
+    for line in [
+        "# TYPE cucumber_scenarios counter",
+        r#"cucumber_scenarios_total{status="passed"} 1"#,
+        r#"cucumber_scenarios_total{status="skipped"} 1"#,
+        r#"cucumber_scenarios_total{status="failed"} 1"#,
+        r#"cucumber_steps_total{status="failed"} 2"#,
+        "cucumber_scenario_retries_total 1",
+        "cucumber_hook_errors_total 0",
+        "# TYPE cucumber_step_duration_seconds histogram",
+        r#"cucumber_step_duration_seconds_bucket{le="+Inf"} 3"#,
+        "cucumber_step_duration_seconds_count 3",
+        "# TYPE cucumber_run_duration_seconds gauge",
+    ] {
+        assert!(out.lines().any(|l| l == line), "no `{line}` in:\n{out}");
+    }
+    assert!(out.ends_with("# EOF\n"), "{out}");
+}
+
+#[tokio::test]
+async fn pushes_to_pushgateway() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+    _ = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+
+        let mut request_line = String::new();
+        _ = reader.read_line(&mut request_line).unwrap();
+        let mut len = 0;
+        loop {
+            let mut header = String::new();
+            _ = reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    len = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body).unwrap();
+        tx.send((request_line, String::from_utf8(body).unwrap()))
+            .unwrap();
+
+        write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").unwrap();
+    });
+
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(
+                        writer::Metrics::raw(
+                            metrics::Pushgateway::new(
+                                format!("http://{addr}"),
+                                "nightly",
+                            )
+                            .grouping_label("suite", "measured"),
+                        )
+                        .format(metrics::Format::Prometheus)
+                        .discard_stats_writes()
+                        .discard_arbitrary_writes(),
+                    )
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run([FEATURE])
+            .await,
+    );
+
+    let (request_line, body) = rx.recv().unwrap();
+    assert!(
+        request_line.starts_with("PUT /metrics/job/nightly/suite/measured "),
+        "{request_line}",
+    );
+    assert!(
+        body.lines()
+            .any(|l| l == "# TYPE cucumber_scenarios_total counter"),
+        "{body}",
+    );
+    assert!(!body.contains("# EOF"), "{body}");
+}
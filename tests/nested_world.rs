@@ -0,0 +1,82 @@
+use cucumber::{given, parser, then, when, StatsWriter as _, World as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct Counter(u32);
+
+#[given(expr = "a counter of {int}")]
+fn counter_of(c: &mut Counter, value: u32) {
+    c.0 = value;
+}
+
+#[when("the counter is incremented")]
+fn increment(c: &mut Counter) {
+    c.0 += 1;
+}
+
+#[then(expr = "the counter is {int}")]
+fn counter_is(c: &mut Counter, expected: u32) {
+    assert_eq!(c.0, expected);
+}
+
+#[derive(cucumber::World, Debug, Default)]
+struct World {
+    #[world(nested)]
+    counter: Counter,
+
+    name: String,
+}
+
+#[given(expr = "a name {word}")]
+fn named(w: &mut World, name: String) {
+    w.name = name;
+}
+
+#[then(expr = "the name is {word}")]
+fn name_is(w: &mut World, expected: String) {
+    assert_eq!(w.name, expected);
+}
+
+#[derive(cucumber::World, Debug, Default)]
+struct Tuple(#[world(nested)] Counter);
+
+const FEATURE: &str = "Feature: Nested worlds
+  Scenario: composite
+    Given a counter of 2
+    And a name foo
+    When the counter is incremented
+    Then the counter is 3
+    And the name is foo
+";
+
+const TUPLE_FEATURE: &str = "Feature: Nested worlds
+  Scenario: tuple
+    Given a counter of 2
+    When the counter is incremented
+    Then the counter is 3
+";
+
+#[tokio::test]
+async fn adapts_steps_of_nested_world() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 5);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn adapts_steps_of_nested_tuple_field() {
+    let writer = Tuple::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([TUPLE_FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
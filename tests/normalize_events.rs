@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use cucumber::{
+    event::{Cucumber, Feature, Scenario, ScenarioId, Source},
+    gherkin::{self, GherkinEnv},
+    writer, Event,
+};
+use futures::{stream, StreamExt as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+// language=Gherkin
+const FIRST: &str = "\
+Feature: First
+  Scenario: one
+    Given a step
+
+  Scenario: two
+    Given a step
+";
+
+// language=Gherkin
+const SECOND: &str = "\
+Feature: Second
+  Scenario: three
+    Given a step
+";
+
+fn describe(ev: &Cucumber<World>) -> String {
+    match ev {
+        Cucumber::Started => "started".into(),
+        Cucumber::ParsingFinished { .. } => "parsed".into(),
+        Cucumber::DuplicateName(dup) => format!("{} duplicated", dup.name()),
+        Cucumber::Finished { .. } => "finished".into(),
+        Cucumber::Feature(f, ev) => match ev {
+            Feature::Started => format!("{} started", f.name),
+            Feature::Aborted => format!("{} aborted", f.name),
+            Feature::Finished => format!("{} finished", f.name),
+            Feature::Rule(r, _) => format!("{} rule", r.name),
+            Feature::Scenario(s, ev) => {
+                if matches!(ev.event, Scenario::Started(_)) {
+                    format!("{} started", s.name)
+                } else {
+                    format!("{} finished", s.name)
+                }
+            }
+        },
+    }
+}
+
+#[tokio::test]
+async fn reorders_raw_stream() {
+    let parse = |s| {
+        Source::new(
+            gherkin::Feature::parse(s, GherkinEnv::default())
+                .expect("failed to parse feature"),
+        )
+    };
+    let (first, second) = (parse(FIRST), parse(SECOND));
+    let (one, two) = (
+        Source::new(first.scenarios[0].clone()),
+        Source::new(first.scenarios[1].clone()),
+    );
+    let three = Source::new(second.scenarios[0].clone());
+    let scenario = |f: &Source<gherkin::Feature>,
+                    s: &Source<gherkin::Scenario>,
+                    started| {
+        let ev = if started {
+            Scenario::Started(ScenarioId::of(f, None, s, None))
+        } else {
+            Scenario::Finished
+        };
+        Cucumber::scenario(
+            f.clone(),
+            None::<Source<gherkin::Rule>>,
+            s.clone(),
+            ev.with_retries(None),
+        )
+    };
+
+    let events = [
+        Cucumber::Started,
+        Cucumber::feature_started(first.clone()),
+        scenario(&first, &one, true),
+        Cucumber::feature_started(second.clone()),
+        scenario(&second, &three, true),
+        scenario(&first, &two, true),
+        scenario(&first, &two, false),
+        scenario(&second, &three, false),
+        Cucumber::feature_finished(second.clone()),
+        scenario(&first, &one, false),
+        Cucumber::feature_finished(first.clone()),
+        Cucumber::Finished {
+            passed: 3,
+            failed: 0,
+            skipped: 0,
+            retried: 0,
+            duration: Duration::ZERO,
+            timed_out: false,
+        },
+    ];
+
+    let normalized = writer::normalize_events(stream::iter(
+        events.into_iter().map(|ev| Ok(Event::new(ev))),
+    ))
+    .map(|ev| describe(&ev.expect("no parsing errors").value))
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(
+        normalized,
+        [
+            "started",
+            "First started",
+            "one started",
+            "one finished",
+            "two started",
+            "two finished",
+            "First finished",
+            "Second started",
+            "three started",
+            "three finished",
+            "Second finished",
+            "finished",
+        ],
+    );
+}
@@ -0,0 +1,113 @@
+use cucumber::{
+    cli, gherkin::Step, given, parser, writer, StatsWriter as _, World as _,
+};
+use regex::Regex;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(expr = "the substituted payload for {int}")]
+fn substituted_payload(_: &mut World, id: u32, step: &Step) {
+    let expected = format!(r#"{{"id": {id}}}"#);
+
+    assert_eq!(step.docstring.as_deref().map(str::trim), Some(&*expected));
+}
+
+#[given(expr = "the substituted row for {int}")]
+fn substituted_row(_: &mut World, id: u32, step: &Step) {
+    let table = step.table.as_ref().expect("no data table");
+
+    assert_eq!(table.rows[1][0], id.to_string());
+}
+
+#[given(expr = "the raw payload for {int}")]
+fn raw_payload(_: &mut World, _id: u32, step: &Step) {
+    assert_eq!(
+        step.docstring.as_deref().map(str::trim),
+        Some(r#"{"id": <id>}"#),
+    );
+}
+
+#[given(expr = "the raw row for {int}")]
+fn raw_row(_: &mut World, _id: u32, step: &Step) {
+    let table = step.table.as_ref().expect("no data table");
+
+    assert_eq!(table.rows[1][0], "<id>");
+}
+
+/// Returns a `Scenario Outline` templating a doc string and a data table of
+/// the given `kind` of steps.
+fn feature(kind: &str) -> String {
+    format!(
+        r#"Feature: Outline
+  Scenario Outline: payload <id>
+    Given the {kind} payload for <id>
+      """
+      {{"id": <id>}}
+      """
+    And the {kind} row for <id>
+      | id   |
+      | <id> |
+
+    Examples:
+      | id |
+      | 1  |
+      | 2  |
+"#,
+    )
+}
+
+#[tokio::test]
+async fn substitutes_step_arguments() {
+    let writer = World::cucumber::<String>()
+        .with_parser(parser::FromString::new())
+        .run([feature("substituted")])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn does_not_substitute_step_arguments_if_disabled() {
+    let writer = World::cucumber::<String>()
+        .with_parser(parser::FromString::new().substitute_step_arguments(false))
+        .run([feature("raw")])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn expands_examples_with_custom_expansion() {
+    let writer = World::cucumber::<String>()
+        .with_parser(parser::FromString::new().example_expansion(
+            |_, _, mut examples| {
+                if let Some(table) = examples.table.as_mut() {
+                    table.rows.push(vec!["3".into()]);
+                }
+                examples
+            },
+        ))
+        .run([feature("substituted")])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 6);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn expands_only_examples_matching_name_filter() {
+    let mut opts = cli::Opts::<_, _, writer::basic::Cli, cli::Empty>::default();
+    opts.re_filter = Some(Regex::new("^payload 2$").unwrap());
+
+    let writer = World::cucumber::<String>()
+        .with_parser(parser::FromString::new())
+        .with_cli(opts)
+        .run([feature("substituted")])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt::Debug, mem, sync::LazyLock};
 
 use cucumber::{cli, event, given, parser, then, when, Event, Writer};
-use regex::Regex;
+use regex::{Captures, Regex};
 
 #[derive(cucumber::World, Debug, Default)]
 struct World(usize);
@@ -37,11 +37,22 @@ impl<World: 'static + Debug> Writer<World> for DebugWriter {
         };
 
         let without_span = SPAN_OR_PATH_RE.replace_all(ev.as_ref(), "");
+        let without_id = SCENARIO_ID_RE.replace_all(without_span.as_ref(), "");
+        let without_duration =
+            DURATION_RE.replace_all(without_id.as_ref(), |c: &Captures| {
+                if c[0].starts_with(", duration") {
+                    " }"
+                } else if c[0].starts_with(',') {
+                    ")"
+                } else {
+                    ""
+                }
+            });
 
         if mem::replace(&mut self.first_line_printed, true) {
             self.output.push('\n');
         }
-        self.output.push_str(without_span.as_ref());
+        self.output.push_str(without_duration.as_ref());
     }
 }
 
@@ -58,6 +69,27 @@ static SPAN_OR_PATH_RE: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// [`Regex`] to omit [`ScenarioId`]s (depending on absolute file paths) for
+/// tests.
+///
+/// [`ScenarioId`]: event::ScenarioId
+static SCENARIO_ID_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("\\(ScenarioId\\(\\d+\\)\\)").unwrap());
+
+/// [`Regex`] to omit non-deterministic [`Step`]s' and [`Finished`] run's
+/// [`Duration`]s for tests.
+///
+/// [`Duration`]: std::time::Duration
+/// [`Finished`]: event::Cucumber::Finished
+/// [`Step`]: event::Step
+static DURATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        "(, |\\()\\d+(\\.\\d+)?(ns|µs|ms|s)\\)\
+         |, duration: \\d+(\\.\\d+)?(ns|µs|ms|s) \\}",
+    )
+    .unwrap()
+});
+
 #[cfg(test)]
 mod spec {
     use std::{fmt, fs, io, sync::LazyLock};
@@ -0,0 +1,59 @@
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{given, parser, runner::PanicHookStrategy, World as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a panicking step")]
+fn panicking_step(_: &mut World) {
+    panic!("expected");
+}
+
+/// Number of times the custom panic hook has been called.
+static CALLED: AtomicUsize = AtomicUsize::new(0);
+
+const FEATURE: &str = "Feature: Panic hook
+  Scenario: panics
+    Given a panicking step
+";
+
+/// Runs the [`FEATURE`] with the provided [`PanicHookStrategy`] and returns
+/// the number of times the custom panic hook has been called during the run.
+async fn run(strategy: PanicHookStrategy) -> usize {
+    let before = CALLED.load(Ordering::SeqCst);
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .panic_hook(strategy)
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+    CALLED.load(Ordering::SeqCst) - before
+}
+
+/// Asserts the custom panic hook is restored after a run.
+fn assert_restored() {
+    let before = CALLED.load(Ordering::SeqCst);
+    _ = panic::catch_unwind(|| panic!("expected"));
+    assert_eq!(CALLED.load(Ordering::SeqCst), before + 1);
+}
+
+// All the strategies are checked in a single test, as the panic hook is global.
+#[tokio::test]
+async fn applies_strategy_and_restores_hook() {
+    panic::set_hook(Box::new(|_| {
+        _ = CALLED.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    assert_eq!(run(PanicHookStrategy::Suppress).await, 0);
+    assert_restored();
+
+    assert_eq!(run(PanicHookStrategy::Chain).await, 1);
+    assert_restored();
+
+    assert_eq!(run(PanicHookStrategy::Untouched).await, 1);
+    assert_restored();
+}
@@ -0,0 +1,91 @@
+use std::sync::{Arc, Mutex};
+
+use cucumber::{
+    given, parser,
+    step::{Context, Status},
+    then, when, StatsWriter as _, World as _,
+};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a clean log")]
+fn clean_log(_: &mut World, #[step] ctx: &Context) {
+    assert!(ctx.prior_steps().is_empty(), "{:?}", ctx.prior_steps());
+}
+
+#[when(expr = "{string} is logged")]
+fn logged(_: &mut World, #[step] ctx: &Context, error: String) {
+    ctx.soft_fail(error);
+}
+
+#[then(expr = "{int} error(s) were logged")]
+fn errors_logged(_: &mut World, #[step] ctx: &Context, count: usize) {
+    let errors = ctx
+        .prior_failures()
+        .flat_map(|o| &o.soft_failures)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), count, "{errors:?}");
+}
+
+#[then(expr = "{int} steps preceded")]
+fn preceded(_: &mut World, #[step] ctx: &Context, count: usize) {
+    assert_eq!(ctx.prior_steps().len(), count);
+    assert!(ctx.prior_steps().iter().all(|o| o.status == Status::Passed));
+    assert!(ctx
+        .prior_steps()
+        .iter()
+        .any(|o| o.step.value == "a clean log"));
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Prior steps
+  Background:
+    Given a clean log
+
+  Scenario: soft failures
+    When \"timeout\" is logged
+    And \"refused\" is logged
+    Then 2 errors were logged
+    And 4 steps preceded
+
+  Scenario: no failures
+    Then 0 errors were logged
+    And 2 steps preceded
+
+  Scenario: failure
+    When \"timeout\" is logged
+    Then 0 errors were logged
+";
+
+#[tokio::test]
+async fn exposes_prior_steps() {
+    let seen = Arc::new(Mutex::new(vec![]));
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .on_step_failed({
+            let seen = Arc::clone(&seen);
+            move |_, info| {
+                seen.lock().unwrap().extend(
+                    info.prior_steps
+                        .iter()
+                        .map(|o| (o.step.value.clone(), o.status)),
+                );
+                Box::pin(async { vec![] })
+            }
+        })
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 10);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [
+            ("a clean log".to_owned(), Status::Passed),
+            ("\"timeout\" is logged".to_owned(), Status::Passed),
+            ("0 errors were logged".to_owned(), Status::Failed),
+        ],
+    );
+}
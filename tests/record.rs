@@ -0,0 +1,91 @@
+use std::{fs, io};
+
+use cucumber::{
+    given, then, when, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+use tempfile::NamedTempFile;
+
+#[given(regex = r"(\d+) secs?")]
+#[when(regex = r"(\d+) secs?")]
+#[then(regex = r"(\d+) secs?")]
+fn step(world: &mut World) {
+    world.0 += 1;
+    assert!(world.0 < 4, "Too much!");
+}
+
+#[tokio::test]
+async fn replays_recorded_events() {
+    let output = NamedTempFile::new().unwrap();
+    let recorded = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .after(|_, _, sc, _, _| {
+                async {
+                    assert!(!sc.tags.iter().any(|t| t == "fail_after"), "Tag!");
+                }
+                .boxed_local()
+            })
+            .with_writer(
+                writer::Basic::raw(
+                    output.reopen().unwrap(),
+                    writer::Coloring::Never,
+                    0,
+                )
+                .tee::<World, _>(writer::Record::for_tee(
+                    recorded.reopen().unwrap(),
+                ))
+                .summarized()
+                .normalized(),
+            )
+            .with_default_cli()
+            .run("tests/features/wait")
+            .await,
+    );
+
+    let replayed = NamedTempFile::new().unwrap();
+    let writer = World::cucumber::<&str>()
+        .with_writer(
+            writer::Basic::raw(
+                replayed.reopen().unwrap(),
+                writer::Coloring::Never,
+                0,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .replay(io::BufReader::new(recorded.reopen().unwrap()))
+        .await
+        .unwrap();
+
+    assert!(writer.execution_has_failed());
+    assert_eq!(writer.parsing_errors(), 1);
+
+    // Parsing errors are replayed without their diagnostics, so are omitted
+    // from the comparison.
+    let (output, replayed) = (
+        fs::read_to_string(output.path()).unwrap(),
+        fs::read_to_string(replayed.path()).unwrap(),
+    );
+    let (output, replayed) = (
+        output.split_once("\nFeature:").unwrap().1,
+        replayed.split_once("\nFeature:").unwrap().1,
+    );
+    assert_eq!(replayed, output);
+}
+
+#[tokio::test]
+async fn errors_on_unknown_source() {
+    let recorded = r#"{"event":{"event":{"feature":[0,"started"]}}}"#;
+
+    let res = World::cucumber::<&str>()
+        .with_default_cli()
+        .replay(io::BufReader::new(recorded.as_bytes()))
+        .await;
+
+    assert!(res.is_err(), "replayed unknown `Feature`");
+}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World(usize);
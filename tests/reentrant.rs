@@ -0,0 +1,85 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{
+    given, parser, writer, writer::Coloring, World as _, WriterExt as _,
+};
+
+/// Number of calls of the panic hook installed before running [`Cucumber`].
+static HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Re-entrant
+
+  Scenario: logging
+    Given a logging step
+
+  @wip
+  Scenario: panicking
+    Given a panicking step
+";
+
+#[tokio::main]
+async fn main() {
+    panic::set_hook(Box::new(|_| {
+        _ = HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    // Mimics a meta-framework running a smoke subset first, and then the full
+    // suite.
+    for run in ["smoke", "full"] {
+        let mut out = Vec::<u8>::new();
+        let res = World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::raw(&mut out, Coloring::Never, 0)
+                    .summarized()
+                    .normalized(),
+            )
+            .with_default_cli()
+            .init_tracing()
+            .run_with_result([FEATURE])
+            .await;
+
+        assert!(!res.execution_has_failed(), "{run} run failed: {res:?}");
+        assert_eq!(res.steps.passed, 1, "{run} run");
+        assert_eq!(res.steps.failed, 0, "{run} run");
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("logged in scenario"), "{run} run:\n{out}");
+    }
+
+    assert_eq!(
+        HOOK_CALLS.load(Ordering::SeqCst),
+        0,
+        "panics of `Scenario`s shouldn't reach the original panic hook",
+    );
+    _ = panic::catch_unwind(|| panic!("after the runs"));
+    assert_eq!(
+        HOOK_CALLS.load(Ordering::SeqCst),
+        1,
+        "original panic hook should be restored",
+    );
+
+    let pending = World::cucumber::<&str>().init_tracing();
+    let overlapping = panic::catch_unwind(AssertUnwindSafe(|| {
+        World::cucumber::<&str>().init_tracing()
+    }));
+    assert!(overlapping.is_err(), "overlapping runs should be rejected");
+    drop(pending);
+    drop(World::cucumber::<&str>().init_tracing());
+}
+
+#[given("a logging step")]
+fn logging(_: &mut World) {
+    tracing::info!("logged in scenario");
+}
+
+#[given("a panicking step")]
+fn panicking(_: &mut World) {
+    panic!("work in progress");
+}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
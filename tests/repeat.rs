@@ -0,0 +1,93 @@
+use std::fs;
+
+use cucumber::{given, parser, writer, World as _, WriterExt as _};
+use tempfile::NamedTempFile;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("it passes")]
+fn passes(_: &mut World) {}
+
+#[given("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FIRST: &str = "\
+Feature: First
+  Scenario: passing
+    Given it passes
+
+  Rule: Nested
+    Scenario: failing
+      Given it passes
+      And it fails
+
+    Scenario: skipped
+      Given it is not implemented
+";
+
+const SECOND: &str = "\
+Feature: Second
+  Scenario: also passing
+    Given it passes
+";
+
+async fn run(
+    repeat: fn(
+        writer::Basic<fs::File>,
+    ) -> writer::Repeat<World, writer::Basic<fs::File>>,
+) -> (String, String) {
+    let file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                repeat(writer::Basic::raw(
+                    file.reopen().unwrap(),
+                    writer::Coloring::Never,
+                    writer::Verbosity::Default,
+                ))
+                .summarized()
+                .normalized(),
+            )
+            .with_default_cli()
+            .run([FIRST, SECOND])
+            .await,
+    );
+
+    let output = fs::read_to_string(file.path()).unwrap();
+    let (_, repeated) = output
+        .split_once("[Summary]")
+        .map(|(o, _)| o.split_at(o.rfind("Feature: Second").unwrap()))
+        .unwrap();
+    let repeated = repeated
+        .split_once('\n')
+        .map(|(_, r)| r.to_owned())
+        .unwrap();
+    (output, repeated)
+}
+
+#[tokio::test]
+async fn repeats_failed_scenarios() {
+    let (output, repeated) = run(writer::Repeat::failed_scenarios).await;
+
+    assert!(repeated.contains("Feature: First"), "output:\n{output}");
+    assert!(repeated.contains("Rule: Nested"), "output:\n{output}");
+    assert!(repeated.contains("Scenario: failing"), "output:\n{output}");
+    assert!(repeated.contains("Given it passes"), "output:\n{output}");
+    assert!(!repeated.contains("Scenario: skipped"), "output:\n{output}");
+    assert!(!repeated.contains("Scenario: passing"), "output:\n{output}");
+}
+
+#[tokio::test]
+async fn repeats_skipped_scenarios() {
+    let (output, repeated) = run(writer::Repeat::skipped_scenarios).await;
+
+    assert!(repeated.contains("Feature: First"), "output:\n{output}");
+    assert!(repeated.contains("Rule: Nested"), "output:\n{output}");
+    assert!(repeated.contains("Scenario: skipped"), "output:\n{output}");
+    assert!(!repeated.contains("Scenario: failing"), "output:\n{output}");
+    assert!(!repeated.contains("Feature: Second"), "output:\n{output}");
+}
@@ -0,0 +1,162 @@
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use cucumber::{
+    given, parser, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+/// Spawns an HTTP server mocking Report Portal API, responding with a new ID
+/// on every request, and returns its base URL along with the received
+/// requests (as `METHOD /path body` lines).
+fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    _ = thread::spawn(move || {
+        for (id, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+
+            let mut request_line = String::new();
+            _ = reader.read_line(&mut request_line).unwrap();
+            let mut len = 0;
+            loop {
+                let mut header = String::new();
+                _ = reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        len = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut parts = request_line.split_whitespace();
+            received.lock().unwrap().push(format!(
+                "{} {} {}",
+                parts.next().unwrap(),
+                parts.next().unwrap(),
+                String::from_utf8(body).unwrap(),
+            ));
+
+            let resp = format!(r#"{{"id":"id{id}"}}"#);
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\n\
+                 Content-Type: application/json\r\n\
+                 Content-Length: {}\r\n\
+                 Connection: close\r\n\r\n\
+                 {resp}",
+                resp.len(),
+            )
+            .unwrap();
+        }
+    });
+
+    (format!("http://{addr}"), requests)
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Reported
+  Rule: rule
+    @key:value
+    Scenario: passing
+      Given foo is 0
+
+    @retry(1)
+    Scenario: failing
+      Given foo is 1
+";
+
+#[tokio::test]
+async fn reports_items_tree() {
+    let (url, requests) = serve();
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::stdout()
+                .summarized()
+                .tee::<World, _>(
+                    writer::ReportPortal::raw(url, "proj", "secret")
+                        .launch_name("nightly")
+                        .launch_attributes(["build:42", "smoke"])
+                        .discard_stats_writes()
+                        .discard_arbitrary_writes(),
+                )
+                .normalized(),
+        )
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.retried_steps(), 1);
+
+    let requests = requests.lock().unwrap();
+    let request = |prefix: &str| {
+        requests
+            .iter()
+            .find(|r| r.starts_with(prefix))
+            .unwrap_or_else(|| {
+                panic!("no `{prefix}` request in: {requests:#?}")
+            })
+    };
+
+    let launch = request("POST /api/v1/proj/launch ");
+    assert!(launch.contains(r#""name":"nightly""#), "{launch}");
+    assert!(
+        launch.contains(r#"{"key":"build","value":"42"},{"value":"smoke"}"#),
+        "{launch}",
+    );
+
+    let rule = request("POST /api/v1/proj/item/id1 ");
+    assert!(rule.contains(r#""name":"Rule: rule""#), "{rule}");
+
+    let passing =
+        request(r#"POST /api/v1/proj/item/id2 {"name":"Scenario: passing""#);
+    assert!(
+        passing.contains(r#""attributes":[{"key":"key","value":"value"}]"#),
+        "{passing}",
+    );
+
+    let retried = requests
+        .iter()
+        .filter(|r| r.contains(r#""name":"Scenario: failing""#))
+        .collect::<Vec<_>>();
+    assert_eq!(retried.len(), 2, "{retried:#?}");
+    assert!(retried[0].contains(r#""retry":false"#), "{}", retried[0]);
+    assert!(
+        retried[1].contains(r#""retry":true,"retryOf":"id7""#),
+        "{}",
+        retried[1],
+    );
+
+    assert!(
+        requests
+            .last()
+            .unwrap()
+            .starts_with("PUT /api/v1/proj/launch/id0/finish "),
+        "{requests:#?}",
+    );
+}
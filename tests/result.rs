@@ -1,4 +1,10 @@
-use cucumber::{given, then, when, StatsWriter as _, World};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use cucumber::{
+    event, given, parser, runner, then, when, ExitCodes, ScenarioFailure,
+    StatsWriter as _, World,
+};
+use futures::FutureExt as _;
 
 #[given("ok")]
 #[when("ok")]
@@ -31,3 +37,127 @@ async fn fails() {
     assert_eq!(writer.parsing_errors(), 0);
     assert_eq!(writer.hook_errors(), 0);
 }
+
+#[tokio::test]
+async fn keeps_steps_with_custom_runner() {
+    let writer = W::cucumber_with_runner(
+        runner::Basic::default().max_concurrent_scenarios(1),
+    )
+    .with_default_cli()
+    .run("tests/features/result")
+    .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.failed_steps(), 3);
+}
+
+#[tokio::test]
+async fn collects_run_result() {
+    let res = W::cucumber()
+        .with_default_cli()
+        .run_with_result("tests/features/result")
+        .await;
+
+    assert_eq!(res.steps.passed, 3);
+    assert_eq!(res.steps.failed, 3);
+    assert_eq!(res.parsing_errors, 0);
+    assert_eq!(res.hook_errors, 0);
+    let mut failed = res
+        .failed_scenarios
+        .iter()
+        .map(|f| f.scenario.name.as_str())
+        .collect::<Vec<_>>();
+    failed.sort_unstable();
+    assert_eq!(failed, ["Given error", "Then error", "When error"]);
+    assert!(res.execution_has_failed());
+
+    assert_eq!(res.exit_code(ExitCodes::default()), 1);
+    let codes = ExitCodes {
+        step_failures: 4,
+        ..ExitCodes::default()
+    };
+    assert_eq!(res.exit_code(codes), 4);
+}
+
+#[tokio::test]
+async fn returns_run_result_from_world() {
+    let res =
+        W::run_with("tests/features/result", |c| c.with_default_cli()).await;
+
+    assert_eq!(res.steps.passed, 3);
+    assert_eq!(res.steps.failed, 3);
+    assert_eq!(res.failed_scenarios.len(), 3);
+    assert!(res.execution_has_failed());
+}
+
+#[tokio::test]
+async fn collects_failed_scenarios() {
+    let (writer, failed) = W::cucumber()
+        .with_default_cli()
+        .run_and_collect("tests/features/result")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 3);
+    assert_eq!(failed.len(), 3);
+    for f in failed {
+        assert_eq!(f.feature.name, "Result");
+        assert!(f.rule.is_none());
+        assert_eq!(f.step.as_ref().map(|s| s.value.as_str()), Some("error"));
+        assert!(f.retries.is_none());
+        assert!(
+            matches!(
+                f.error,
+                ScenarioFailure::Step(event::StepError::Panic(..)),
+            ),
+            "wrong error: {:?}",
+            f.error,
+        );
+        assert!(
+            f.error.to_string().contains("error"),
+            "wrong error: {}",
+            f.error,
+        );
+    }
+}
+
+// language=Gherkin
+const RETRIED_HOOKS: &str = "\
+Feature: Retried hooks
+  @retry(1)
+  Scenario: flaky
+    Given ok
+
+  @retry(1)
+  Scenario: broken
+    Given ok
+";
+
+#[tokio::test]
+async fn collects_only_finally_failed_hooks() {
+    static FAILED_ONCE: AtomicBool = AtomicBool::new(false);
+
+    let (writer, failed) = W::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .before(|_, _, sc, _| {
+            let fails = sc.name == "broken"
+                || !FAILED_ONCE.swap(true, Ordering::SeqCst);
+            async move { assert!(!fails, "hook failed") }.boxed_local()
+        })
+        .with_default_cli()
+        .run_and_collect([RETRIED_HOOKS])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    let [f] = failed.as_slice() else {
+        panic!("wrong failed scenarios: {failed:?}");
+    };
+    assert_eq!(f.scenario.name, "broken");
+    assert!(
+        matches!(f.error, ScenarioFailure::Hook(event::HookType::Before, _)),
+        "wrong error: {:?}",
+        f.error,
+    );
+    assert_eq!(f.retries.map(|r| r.left), Some(0));
+}
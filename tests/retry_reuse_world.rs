@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use cucumber::{given, parser, StatsWriter as _, World as _};
+use futures::FutureExt as _;
+use tokio::sync::Mutex;
+
+static HOOK_RUNS: LazyLock<Mutex<HashMap<(&str, String), usize>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(cucumber::World, Debug, Default)]
+struct World {
+    attempts: usize,
+}
+
+#[given(expr = "fail on first {int} attempt(s) of the same World")]
+fn fail(w: &mut World, num: usize) {
+    w.attempts += 1;
+    assert!(w.attempts > num, "attempt {} of the same World", w.attempts);
+}
+
+// language=Gherkin
+const FEATURE: &str = "\
+Feature: Reusing World on retries
+  @retry(3).reuse_world
+  Scenario: reused
+    Given fail on first 2 attempts of the same World
+
+  @retry(3)
+  Scenario: reconstructed
+    Given fail on first 2 attempts of the same World
+";
+
+async fn hook_runs(hook: &'static str, scenario: &str) -> usize {
+    HOOK_RUNS
+        .lock()
+        .await
+        .get(&(hook, scenario.to_owned()))
+        .copied()
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn hands_world_to_next_attempt() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .before(|_, _, sc, _| {
+            async {
+                *HOOK_RUNS
+                    .lock()
+                    .await
+                    .entry(("before", sc.name.clone()))
+                    .or_default() += 1;
+            }
+            .boxed_local()
+        })
+        .after(|_, _, sc, _, _| {
+            async {
+                *HOOK_RUNS
+                    .lock()
+                    .await
+                    .entry(("after", sc.name.clone()))
+                    .or_default() += 1;
+            }
+            .boxed_local()
+        })
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.retried_steps(), 5);
+
+    assert_eq!(hook_runs("before", "reused").await, 1);
+    assert_eq!(hook_runs("after", "reused").await, 1);
+    assert_eq!(hook_runs("before", "reconstructed").await, 4);
+    assert_eq!(hook_runs("after", "reconstructed").await, 4);
+}
@@ -0,0 +1,99 @@
+use cucumber::{
+    cli, event, given, parser, Event, World as _, Writer, WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = "(feature|rule|scenario) step")]
+fn step(_: &mut World) {}
+
+#[given("failing rule step")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FEATURE: &str = "\
+Feature: Rule background
+  Background:
+    Given feature step
+
+  Rule: passing
+    Background:
+      Given rule step
+
+    Scenario: first
+      Given scenario step
+
+  Rule: failing
+    Background:
+      Given failing rule step
+
+    Scenario: second
+      Given scenario step
+";
+
+/// [`Writer`] collecting kinds of all the finished [`Step`]s.
+///
+/// [`Step`]: cucumber::gherkin::Step
+#[derive(Default)]
+struct StepsWriter(Vec<String>);
+
+impl Writer<World> for StepsWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario, Step};
+
+        let Ok(Cucumber::Feature(_, ev)) = ev.map(Event::into_inner) else {
+            return;
+        };
+        let ev = match ev {
+            Feature::Scenario(_, ev)
+            | Feature::Rule(_, Rule::Scenario(_, ev)) => ev.event,
+            Feature::Started
+            | Feature::Aborted
+            | Feature::Finished
+            | Feature::Rule(..) => return,
+        };
+        let (kind, step, ev) = match ev {
+            Scenario::Background(st, ev) => ("feature background", st, ev),
+            Scenario::RuleBackground(st, ev) => ("rule background", st, ev),
+            Scenario::Step(st, ev) => ("scenario", st, ev),
+            _ => return,
+        };
+        let outcome = match ev {
+            Step::Started => return,
+            Step::Passed(..) => "passed",
+            Step::Skipped(_) => "skipped",
+            Step::Failed(..) => "failed",
+        };
+        self.0.push(format!("{kind}: {} {outcome}", step.value));
+    }
+}
+
+#[tokio::test]
+async fn emits_distinct_events() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(StepsWriter::default().normalized())
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(
+        res.inner_writer().0,
+        [
+            "feature background: feature step passed",
+            "rule background: rule step passed",
+            "scenario: scenario step passed",
+            "feature background: feature step passed",
+            "rule background: failing rule step failed",
+        ],
+    );
+}
@@ -0,0 +1,102 @@
+use cucumber::{
+    cli, event, given, parser, Event, World as _, Writer, WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("it passes")]
+fn passes(_: &mut World) {}
+
+#[given("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FEATURE: &str = "\
+Feature: Scenario IDs
+  Scenario: passing
+    Given it passes
+
+  Rule: rule
+    Scenario: failing
+      Given it fails
+";
+
+/// [`Writer`] collecting [`event::ScenarioId`]s of all the started
+/// [`Scenario`]s, along with the ones computed via [`event::ScenarioId::of()`].
+///
+/// [`Scenario`]: cucumber::gherkin::Scenario
+#[derive(Default)]
+struct IdsWriter(Vec<(String, Option<usize>, event::ScenarioId)>);
+
+impl Writer<World> for IdsWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<World>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, RetryableScenario, Rule, Scenario};
+
+        let Ok(Cucumber::Feature(f, ev)) = ev.map(Event::into_inner) else {
+            return;
+        };
+        let (r, sc, RetryableScenario { event, retries }) = match ev {
+            Feature::Scenario(sc, ev) => (None, sc, ev),
+            Feature::Rule(r, Rule::Scenario(sc, ev)) => (Some(r), sc, ev),
+            Feature::Started
+            | Feature::Aborted
+            | Feature::Finished
+            | Feature::Rule(..) => return,
+        };
+        if let Scenario::Started(id) = event {
+            assert_eq!(
+                id,
+                event::ScenarioId::of(&f, r.as_deref(), &sc, retries),
+                "`ScenarioId` of `{}` doesn't match `ScenarioId::of()`",
+                sc.name,
+            );
+            self.0
+                .push((sc.name.clone(), retries.map(|r| r.current), id));
+        }
+    }
+}
+
+async fn run() -> Vec<(String, Option<usize>, event::ScenarioId)> {
+    let mut ids = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(IdsWriter::default().normalized())
+        .retries(1)
+        .with_default_cli()
+        .run([FEATURE])
+        .await
+        .inner_writer()
+        .0
+        .clone();
+    ids.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+    ids
+}
+
+#[tokio::test]
+async fn ids_are_deterministic() {
+    let first = run().await;
+
+    assert_eq!(first, run().await);
+}
+
+#[tokio::test]
+async fn retried_scenario_has_different_id() {
+    let ids = run().await;
+
+    let failing = ids
+        .iter()
+        .filter(|(name, ..)| name == "failing")
+        .collect::<Vec<_>>();
+    assert_eq!(failing.len(), 2);
+    assert_ne!(failing[0].2, failing[1].2);
+
+    let passing = ids.iter().find(|(name, ..)| name == "passing").unwrap();
+    assert!(failing.iter().all(|(.., id)| *id != passing.2));
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use clap::Parser as _;
+use cucumber::{cli, given, parser, World as _};
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+#[given("working")]
+async fn working(_: &mut World) {
+    let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+    _ = MAX_RUNNING.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    _ = RUNNING.fetch_sub(1, Ordering::SeqCst);
+}
+
+// language=Gherkin
+const FEATURE: &str = "\
+Feature: Concurrency overrides
+  @db
+  Scenario: first
+    Given working
+
+  @db
+  Scenario: second
+    Given working
+
+  @db @serial
+  Scenario: third
+    Given working
+
+  @db @serial
+  Scenario: fourth
+    Given working
+";
+
+/// Runs the [`FEATURE`] with the provided CLI arguments, returning the maximum
+/// number of concurrently running `Scenario`s.
+async fn max_running(args: &[&str]) -> usize {
+    MAX_RUNNING.store(0, Ordering::SeqCst);
+
+    let cli = cli::Opts::<_, _, _>::try_parse_from(
+        ["test"].iter().chain(args).copied(),
+    )
+    .expect("Invalid command line");
+    World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_cli(cli)
+        .run_and_exit([FEATURE])
+        .await;
+
+    MAX_RUNNING.load(Ordering::SeqCst)
+}
+
+// All the runs are kept in a single test, as they share the global counters.
+#[tokio::test]
+async fn overrides_scenario_type_via_cli() {
+    assert_eq!(
+        max_running(&["--serial-tags=@db"]).await,
+        1,
+        "`--serial-tags` should serialize matching scenarios",
+    );
+    assert_eq!(
+        max_running(&["--serial-tags=@db", "--concurrent-tags=@db"]).await,
+        1,
+        "`--serial-tags` should take precedence over `--concurrent-tags`",
+    );
+    assert_eq!(
+        max_running(&["--concurrent-tags=@db", "--tags=@serial"]).await,
+        2,
+        "`--concurrent-tags` should make `@serial` scenarios concurrent",
+    );
+    assert_eq!(
+        max_running(&["--tags=@serial"]).await,
+        1,
+        "`@serial` scenarios should run sequentially by default",
+    );
+}
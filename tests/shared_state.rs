@@ -0,0 +1,90 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use cucumber::{
+    given, parser, step::Context, then, StatsWriter as _, World as _,
+};
+use futures::FutureExt as _;
+
+#[derive(cucumber::World, Debug, Default)]
+struct World;
+
+/// Run-scoped counter of started `Scenario`s.
+#[derive(Debug, Default)]
+struct Started(AtomicUsize);
+
+/// Run-scoped counter of `before_feature` hook invocations.
+#[derive(Debug, Default)]
+struct Features(AtomicUsize);
+
+/// Feature-scoped name of the `Feature`.
+#[derive(Debug)]
+struct FeatureName(String);
+
+#[given("a scenario")]
+fn scenario(_: &mut World, #[step] ctx: &Context) {
+    let started = ctx.shared.get::<Arc<Started>>().expect("no run state");
+    _ = started.0.fetch_add(1, Ordering::SeqCst);
+}
+
+#[then(expr = "the feature is {word}")]
+fn feature_is(_: &mut World, #[step] ctx: &Context, name: String) {
+    let feature = ctx.shared.get::<FeatureName>().expect("no feature state");
+    assert_eq!(feature.0, name);
+    assert!(ctx.shared.feature.get::<Arc<Started>>().is_none());
+}
+
+const FIRST: &str = "Feature: first
+  Scenario: one
+    Given a scenario
+    Then the feature is first
+
+  Scenario: two
+    Given a scenario
+    Then the feature is first
+";
+
+const SECOND: &str = "Feature: second
+  Scenario: three
+    Given a scenario
+    Then the feature is second
+";
+
+#[tokio::test]
+async fn shares_run_and_feature_state() {
+    let started = Arc::new(Started::default());
+    let features = Arc::new(Features::default());
+
+    let (started_state, features_state) =
+        (Arc::clone(&started), Arc::clone(&features));
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .before_all(move |state| {
+            let (started, features) =
+                (Arc::clone(&started_state), Arc::clone(&features_state));
+            async {
+                _ = state.insert(started);
+                _ = state.insert(features);
+            }
+            .boxed_local()
+        })
+        .before_feature(|feature, run, state| {
+            async {
+                let features =
+                    run.get::<Arc<Features>>().expect("no run state");
+                _ = features.0.fetch_add(1, Ordering::SeqCst);
+                _ = state.insert(FeatureName(feature.name.clone()));
+            }
+            .boxed_local()
+        })
+        .with_default_cli()
+        .run([FIRST, SECOND])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 6);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(started.0.load(Ordering::SeqCst), 3);
+    assert_eq!(features.0.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,133 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use cucumber::{given, parser, runner, then, writer::Stats as _, World as _};
+use futures::FutureExt as _;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("all the scenarios are blocked")]
+fn blocked(_: &mut World) {
+    static BLOCKED: AtomicUsize = AtomicUsize::new(0);
+
+    // Blocks the thread until all the `Scenario`s reach this `Step`, which is
+    // possible only if they're run on different threads.
+    _ = BLOCKED.fetch_add(1, Ordering::SeqCst);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while BLOCKED.load(Ordering::SeqCst) < 3 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(
+        BLOCKED.load(Ordering::SeqCst),
+        3,
+        "scenarios are not parallel"
+    );
+}
+
+#[given("a hooked scenario")]
+fn hooked(_: &mut World) {}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FEATURE: &str = "\
+Feature: Blocking
+  Scenario: first
+    Given all the scenarios are blocked
+
+  Scenario: second
+    Given all the scenarios are blocked
+
+  Scenario: third
+    Given all the scenarios are blocked
+    Then it fails
+";
+
+#[tokio::test]
+async fn runs_blocking_scenarios_on_dedicated_threads() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_blocking_scenarios()
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+const HOOKED_FEATURE: &str = "\
+Feature: Hooked
+  Scenario: hooked
+    Given a hooked scenario
+";
+
+#[tokio::test]
+async fn keeps_spawning_when_hooks_are_set_afterwards() {
+    static HOOKED_ON: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_blocking_scenarios()
+        .before(|_, _, _, _| {
+            HOOKED_ON.lock().unwrap().push(thread::current().id());
+            async {}.boxed_local()
+        })
+        .after(|_, _, _, _, _| {
+            HOOKED_ON.lock().unwrap().push(thread::current().id());
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run([HOOKED_FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    let hooked_on = HOOKED_ON.lock().unwrap();
+    assert_eq!(hooked_on.len(), 2, "hooks are not run");
+    assert!(
+        !hooked_on.contains(&thread::current().id()),
+        "hooks are run on the `Runner` thread",
+    );
+}
+
+const THREADED_FEATURE: &str = "\
+Feature: Threaded
+  Scenario: threaded
+    Given a step on a dedicated thread
+";
+
+static RUN_ON: Mutex<Option<ThreadId>> = Mutex::new(None);
+
+#[given("a step on a dedicated thread")]
+fn threaded(_: &mut World) {
+    *RUN_ON.lock().unwrap() = Some(thread::current().id());
+}
+
+#[tokio::test]
+async fn spawns_with_custom_runner() {
+    let writer = World::cucumber_with_runner::<&str, _, _, _, _>(
+        runner::Basic::default().spawn_blocking_scenarios(),
+    )
+    .with_parser(parser::FromString::new())
+    .with_default_cli()
+    .run([THREADED_FEATURE])
+    .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    let run_on = *RUN_ON.lock().unwrap();
+    assert!(run_on.is_some(), "step is not run");
+    assert_ne!(
+        run_on,
+        Some(thread::current().id()),
+        "step is run on the `Runner` thread",
+    );
+}
@@ -0,0 +1,217 @@
+use std::{
+    fs, io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use cucumber::{
+    cli, event, given, parser, writer, writer::Stats as _, Event, World as _,
+    Writer, WriterExt as _,
+};
+use tempfile::NamedTempFile;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("it passes")]
+fn passes(_: &mut World) {}
+
+#[given("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+#[given("it fails once")]
+fn fails_once(_: &mut World) {
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    assert!(FAILED.swap(true, Ordering::SeqCst), "failed once");
+}
+
+const FIRST: &str = "\
+@api
+Feature: First
+  Scenario: passing
+    Given it passes
+
+  @slow
+  Scenario: failing
+    Given it fails
+";
+
+const SECOND: &str = "\
+Feature: Second
+  @slow
+  Scenario: skipped
+    Given it is not implemented
+";
+
+const THIRD: &str = "\
+Feature: Third
+  @retry(1)
+  Scenario: retried
+    Given it fails
+";
+
+const FOURTH: &str = "\
+Feature: Fourth
+  @retry(1)
+  Scenario: flaky
+    Given it fails once
+";
+
+/// [`Writer`] remembering the counts of the [`event::Cucumber::Finished`].
+#[derive(Default)]
+struct Totals(Option<[usize; 4]>);
+
+impl<W: 'static> Writer<W> for Totals {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Finished {
+            passed,
+            failed,
+            skipped,
+            retried,
+            ..
+        }) = ev.map(Event::into_inner)
+        {
+            self.0 = Some([passed, failed, skipped, retried]);
+        }
+    }
+}
+
+#[tokio::test]
+async fn finished_event_carries_totals() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(Totals::default().normalized())
+        .with_default_cli()
+        .run([FIRST, SECOND, THIRD])
+        .await;
+
+    assert_eq!(writer.0, Some([1, 2, 1, 1]));
+}
+
+#[tokio::test]
+async fn groups_scenarios_by_feature_and_tag() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run([FIRST, SECOND])
+        .await;
+
+    let by_feature = writer
+        .scenarios_by_feature()
+        .into_iter()
+        .map(|(f, s)| (f.name.clone(), (s.passed, s.skipped, s.failed)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        by_feature,
+        [("First".into(), (1, 0, 1)), ("Second".into(), (0, 1, 0))],
+    );
+
+    let by_tag = writer
+        .scenarios_by_tag()
+        .into_iter()
+        .map(|(t, s)| (t, (s.passed, s.skipped, s.failed)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        by_tag,
+        [("api".into(), (1, 0, 1)), ("slow".into(), (0, 1, 1))],
+    );
+}
+
+#[tokio::test]
+async fn prints_features_table() {
+    let file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::raw(
+                    file.reopen().unwrap(),
+                    writer::Coloring::Never,
+                    writer::Verbosity::Default,
+                )
+                .summarized()
+                .normalized(),
+            )
+            .with_default_cli()
+            .run([FIRST, SECOND])
+            .await,
+    );
+
+    let output = fs::read_to_string(file.path()).unwrap();
+
+    assert!(
+        output.contains(
+            "  First   2 scenarios (1 passed, 1 failed)\n  \
+                Second  1 scenario (1 skipped)\n",
+        ),
+        "no features table:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn counts_scenarios() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run([FIRST, SECOND, THIRD, FOURTH])
+        .await;
+
+    assert_eq!(writer.passed_scenarios(), 2);
+    assert_eq!(writer.skipped_scenarios(), 1);
+    assert_eq!(writer.failed_scenarios(), 2);
+    assert_eq!(writer.flaky_scenarios(), 1);
+}
+
+#[tokio::test]
+async fn run_result_carries_scenarios_stats() {
+    let result = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run_with_result([FIRST, SECOND])
+        .await;
+
+    assert_eq!(
+        (
+            result.scenarios.passed,
+            result.scenarios.skipped,
+            result.scenarios.failed,
+        ),
+        (1, 1, 1),
+    );
+    assert!(result.execution_has_failed());
+}
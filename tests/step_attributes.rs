@@ -0,0 +1,79 @@
+#![deny(deprecated)]
+
+use std::str::FromStr;
+
+use cucumber::{
+    given, parser, step::Context, then, when, StatsWriter as _, World as _,
+};
+use tracing_subscriber::util::SubscriberInitExt as _;
+
+#[derive(cucumber::World, Debug, Default)]
+struct World(u32);
+
+#[deprecated]
+#[derive(Debug)]
+struct Legacy(u32);
+
+#[expect(deprecated, reason = "testing deprecated type")]
+impl FromStr for Legacy {
+    type Err = <u32 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+fn current_span() -> Option<&'static str> {
+    tracing::Span::current()
+        .metadata()
+        .map(tracing::Metadata::name)
+}
+
+#[given(expr = "{int} apples")]
+#[allow(deprecated)]
+#[tracing::instrument(skip(w))]
+async fn apples(w: &mut World, count: Legacy) {
+    assert_eq!(current_span(), Some("apples"));
+    w.0 = count.0;
+}
+
+#[tracing::instrument(skip_all, fields(text = %ctx.step.value))]
+#[when("the apples are counted")]
+fn counted(w: &mut World, #[step] ctx: &Context) {
+    assert_eq!(current_span(), Some("counted"));
+    assert_eq!(ctx.step.value, "the apples are counted");
+    assert!(w.0 > 0);
+}
+
+#[then(expr = "{int} apple(s) is/are left")]
+#[tracing::instrument(skip(w), ret)]
+#[then(expr = "{int} apple(s) remain(s)")]
+fn left(w: &mut World, count: u32) -> Result<(), String> {
+    assert_eq!(current_span(), Some("left"));
+    (w.0 == count)
+        .then_some(())
+        .ok_or_else(|| format!("{} apples are left", w.0))
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Attributes
+  Scenario: composed
+    Given 2 apples
+    When the apples are counted
+    Then 2 apples are left
+    And 2 apples remain
+";
+
+#[tokio::test]
+async fn composes_with_other_attributes() {
+    let _guard = tracing_subscriber::registry().set_default();
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.failed_steps(), 0);
+}
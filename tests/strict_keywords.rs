@@ -0,0 +1,65 @@
+use cucumber::{given, parser, then, when, StatsWriter as _, World as _};
+
+#[derive(cucumber::World, Debug, Default)]
+struct World(u32);
+
+#[given("a value")]
+fn a_value(w: &mut World) {
+    w.0 = 2;
+}
+
+#[when("it's doubled")]
+fn doubled(w: &mut World) {
+    w.0 *= 2;
+}
+
+#[then(expr = "it's {int}")]
+fn equals(w: &mut World, expected: u32) {
+    assert_eq!(w.0, expected);
+}
+
+#[then("it's positive")]
+fn positive(w: &mut World) {
+    assert!(w.0 > 0);
+}
+
+const FEATURE: &str = "Feature: Strict keywords
+  Scenario: bullets
+    Given a value
+    When it's doubled
+    * it's doubled
+    And it's doubled
+    Then it's 16
+    * it's positive
+
+  Scenario: mismatch
+    Given a value
+    Then it's doubled
+";
+
+#[tokio::test]
+async fn honors_bullets_and_fails_on_keyword_mismatch() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .strict_keywords()
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 7);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+#[tokio::test]
+async fn skips_keyword_mismatch_by_default() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
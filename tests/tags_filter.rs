@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use clap::Parser as _;
+use cucumber::{
+    cli, event, given, parser, tag, Event, World as _, Writer, WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+/// [`Writer`] collecting [`event::Cucumber::ParsingFinished`] counts and
+/// parsing errors.
+#[derive(Default)]
+struct ParsingWriter {
+    finished: Option<(usize, usize, usize, usize, usize)>,
+    filtered: usize,
+    errors: usize,
+}
+
+impl<W: 'static> Writer<W> for ParsingWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        match ev.map(Event::into_inner) {
+            Ok(event::Cucumber::ParsingFinished {
+                features,
+                rules,
+                scenarios,
+                steps,
+                parser_errors,
+                filtered_scenarios,
+            }) => {
+                self.finished =
+                    Some((features, rules, scenarios, steps, parser_errors));
+                self.filtered = filtered_scenarios;
+            }
+            Ok(_) => {}
+            Err(_) => self.errors += 1,
+        }
+    }
+}
+
+#[tokio::test]
+async fn does_not_expand_filtered_out_examples() {
+    let cli = cli::Opts::<_, _, _>::try_parse_from(["test", "--tags=@smoke"])
+        .expect("Invalid command line");
+
+    let writer = World::cucumber()
+        .with_writer(ParsingWriter::default().normalized())
+        .with_cli(cli)
+        .run("tests/features/tags_filter")
+        .await;
+
+    assert_eq!(writer.errors, 0, "filtered out `Examples` were expanded");
+    assert_eq!(writer.finished, Some((1, 0, 3, 3, 0)));
+}
+
+#[tokio::test]
+async fn expands_all_examples_without_filter() {
+    let writer = World::cucumber()
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .run("tests/features/tags_filter")
+        .await;
+
+    assert_eq!(writer.errors, 1);
+    assert_eq!(writer.finished, Some((1, 0, 1, 1, 1)));
+}
+
+#[tokio::test]
+async fn expanded_scenarios_keep_their_examples() {
+    let writer = World::cucumber()
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .filter_run("tests/features/examples_tags", |_, _, sc| {
+            assert!(
+                sc.examples.len() <= 1,
+                "expanded `Scenario` keeps foreign `Examples`",
+            );
+            sc.examples
+                .iter()
+                .all(|ex| ex.tags.iter().any(|t| t == "smoke"))
+                && sc.tags.iter().any(|t| t == "smoke")
+        })
+        .await;
+
+    assert_eq!(writer.finished, Some((1, 0, 1, 1, 0)));
+    assert_eq!(writer.filtered, 2);
+}
+
+#[tokio::test]
+async fn filters_with_async_predicate() {
+    let writer = World::cucumber()
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .filter_run_async("tests/features/examples_tags", |_, _, sc| {
+            let tags = sc.examples.first().map(|ex| ex.tags.clone());
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                tags.is_some_and(|t| t.iter().any(|t| t == "regression"))
+            }
+        })
+        .await;
+
+    assert_eq!(writer.finished, Some((1, 0, 2, 2, 0)));
+    assert_eq!(writer.filtered, 1);
+}
+
+#[tokio::test]
+async fn filters_with_tag_expression() {
+    let expr = "@regression and not @smoke".parse::<tag::Expr>().unwrap();
+
+    let writer = World::cucumber()
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .filter_run("tests/features/examples_tags", expr.into_filter())
+        .await;
+
+    assert_eq!(writer.finished, Some((1, 0, 2, 2, 0)));
+    assert_eq!(writer.filtered, 1);
+}
+
+// language=Gherkin
+const EMPTY: &str = "\
+Feature: Empty
+
+  Rule: Empty
+";
+
+// language=Gherkin
+const MIXED: &str = "\
+Feature: Mixed
+  @smoke
+  Scenario: tagged
+    Given foo is 0
+
+  Rule: Untagged
+    Scenario: untagged
+      Given foo is 0
+";
+
+#[tokio::test]
+async fn keeps_empty_features_and_rules_without_filter() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .run([EMPTY])
+        .await;
+
+    assert_eq!(writer.finished, Some((1, 1, 0, 0, 0)));
+}
+
+#[tokio::test]
+async fn omits_only_filtered_out_features_and_rules() {
+    let cli = cli::Opts::<_, _, _>::try_parse_from(["test", "--tags=@smoke"])
+        .expect("Invalid command line");
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(ParsingWriter::default().normalized())
+        .with_cli(cli)
+        .run([EMPTY, MIXED])
+        .await;
+
+    assert_eq!(writer.finished, Some((2, 1, 1, 1, 0)));
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(ParsingWriter::default().normalized())
+        .with_default_cli()
+        .filter_run([EMPTY, MIXED], |_, _, sc| sc.name == "tagged")
+        .await;
+
+    assert_eq!(writer.finished, Some((2, 1, 1, 1, 0)));
+    assert_eq!(writer.filtered, 1);
+}
@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cucumber::{
+    given, parser, then, StatsWriter as _, World as _, WorldTeardown,
+};
+
+static TORN_DOWN: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(teardown)]
+struct World {
+    panics_on_teardown: bool,
+}
+
+impl WorldTeardown for World {
+    async fn teardown(&mut self) {
+        _ = TORN_DOWN.fetch_add(1, Ordering::SeqCst);
+        assert!(!self.panics_on_teardown, "teardown failed");
+    }
+}
+
+#[given("a world")]
+fn world(_: &mut World) {}
+
+#[given("a world failing on teardown")]
+fn failing_teardown(w: &mut World) {
+    w.panics_on_teardown = true;
+}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("step failed");
+}
+
+const FEATURE: &str = "Feature: Teardown
+  Scenario: passing
+    Given a world
+
+  Scenario: failing
+    Given a world
+    Then it fails
+
+  Scenario: failing teardown
+    Given a world failing on teardown
+
+  Scenario: no world
+    Then it's not initialized
+";
+
+#[tokio::test]
+async fn tears_down_worlds_even_on_failures() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.hook_errors(), 1);
+    assert_eq!(TORN_DOWN.load(Ordering::SeqCst), 3);
+}
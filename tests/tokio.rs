@@ -0,0 +1,162 @@
+use std::{
+    cell::Cell,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use cucumber::{given, parser, then, writer::Stats as _, World as _};
+use futures::FutureExt as _;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[derive(cucumber::World, Debug, Default)]
+struct NonSyncWorld {
+    counter: Cell<usize>,
+}
+
+#[given("a non-`Sync` world")]
+fn non_sync(world: &mut NonSyncWorld) {
+    world.counter.set(world.counter.get() + 1);
+}
+
+#[then("it's counted once")]
+fn counted(world: &mut NonSyncWorld) {
+    assert_eq!(world.counter.get(), 1);
+}
+
+#[given("all the scenarios are busy")]
+fn busy(_: &mut World) {
+    static BUSY: AtomicUsize = AtomicUsize::new(0);
+
+    // Blocks the thread until all the `Scenario`s reach this `Step`, which is
+    // possible only if they're run on different threads.
+    _ = BUSY.fetch_add(1, Ordering::SeqCst);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while BUSY.load(Ordering::SeqCst) < 3 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(BUSY.load(Ordering::SeqCst), 3, "scenarios are not parallel");
+}
+
+#[then("it sleeps")]
+async fn sleeps(_: &mut World) {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+}
+
+#[given("a hooked scenario")]
+fn hooked(_: &mut World) {}
+
+#[then("it fails")]
+fn fails(_: &mut World) {
+    panic!("failed");
+}
+
+const FEATURE: &str = "\
+Feature: Tokio
+  Scenario: first
+    Given all the scenarios are busy
+    Then it sleeps
+
+  Scenario: second
+    Given all the scenarios are busy
+    Then it sleeps
+
+  Scenario: third
+    Given all the scenarios are busy
+    Then it fails
+";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn runs_scenarios_on_tokio_threads() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_tokio_scenarios()
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 5);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+const HOOKED_FEATURE: &str = "\
+Feature: Hooked
+  Scenario: hooked
+    Given a hooked scenario
+    Then it sleeps
+";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keeps_spawning_when_hooks_are_set_afterwards() {
+    static HOOKED_ON: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_tokio_scenarios()
+        .before(|_, _, _, _| {
+            HOOKED_ON.lock().unwrap().push(thread::current().id());
+            async {}.boxed_local()
+        })
+        .after(|_, _, _, _, _| {
+            HOOKED_ON.lock().unwrap().push(thread::current().id());
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run([HOOKED_FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    let hooked_on = HOOKED_ON.lock().unwrap();
+    assert_eq!(hooked_on.len(), 2, "hooks are not run");
+    assert!(
+        !hooked_on.contains(&thread::current().id()),
+        "hooks are run on the `Runner` thread",
+    );
+}
+
+const NON_SYNC_FEATURE: &str = "\
+Feature: Non-Sync
+  Scenario: non-sync
+    Given a non-`Sync` world
+    Then it's counted once
+";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn runs_non_sync_worlds() {
+    let writer = NonSyncWorld::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_tokio_scenarios()
+        .with_default_cli()
+        .run([NON_SYNC_FEATURE])
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn panics_outside_multi_threaded_runtime() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .spawn_tokio_scenarios()
+        .with_default_cli()
+        .run([HOOKED_FEATURE]);
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should panic");
+    let err = err
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| err.downcast_ref::<&str>().copied())
+        .unwrap();
+
+    assert!(err.contains("multi-threaded `tokio` runtime"), "{err}");
+}
@@ -0,0 +1,66 @@
+use base64::Engine as _;
+use cucumber::{given, parser, writer, World as _};
+use tracing_subscriber::{
+    filter::LevelFilter,
+    fmt::format::{DefaultFields, Format},
+    layer::SubscriberExt as _,
+    Layer as _,
+};
+
+#[given(regex = r"^a user (\w+)$")]
+fn user(_: &mut World, name: String) {
+    tracing::info!(user = %name, attempt = 3_u64, admin = false, "logged in");
+    tracing::info!("plain");
+}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[tokio::main]
+async fn main() {
+    let mut out = Vec::<u8>::new();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(writer::Json::new(&mut out))
+        .with_default_cli()
+        .configure_and_init_tracing(
+            DefaultFields::new(),
+            Format::default().with_ansi(false).without_time(),
+            |layer| {
+                tracing_subscriber::registry()
+                    .with(LevelFilter::INFO.and_then(layer))
+            },
+        )
+        .run(["Feature: Logs\n  Scenario: login\n    Given a user bob\n"])
+        .await;
+
+    let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let embeddings = report
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|f| f["elements"].as_array().unwrap())
+        .flat_map(|sc| sc["steps"].as_array().unwrap())
+        .flat_map(|st| st["embeddings"].as_array().unwrap())
+        .map(|e| {
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(e["data"].as_str().unwrap())
+                .unwrap();
+            (e["mime_type"].clone(), String::from_utf8(data).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(embeddings.len(), 2, "embeddings: {embeddings:?}");
+    assert_eq!(embeddings[0].0, "application/json");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&embeddings[0].1).unwrap(),
+        serde_json::json!({
+            "message": "logged in",
+            "user": "bob",
+            "attempt": 3,
+            "admin": false,
+        }),
+    );
+    assert_eq!(embeddings[1].0, "text/x.cucumber.log+plain");
+    assert!(embeddings[1].1.ends_with("plain\n"), "{embeddings:?}");
+}
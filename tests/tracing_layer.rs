@@ -0,0 +1,60 @@
+use std::io;
+
+use cucumber::{
+    given,
+    tracing::{AppendScenarioMsg, SkipScenarioIdSpan},
+    writer,
+    writer::Coloring,
+    World as _, WriterExt as _,
+};
+use tracing_subscriber::{
+    filter::{LevelFilter, Targets},
+    fmt::{self, format},
+    Layer as _,
+};
+
+#[tokio::main]
+async fn main() {
+    let mut out = Vec::<u8>::new();
+
+    let writer = writer::Basic::raw(&mut out, Coloring::Never, 0)
+        .discard_stats_writes()
+        .tee::<World, _>(
+            writer::Basic::raw(io::stdout(), Coloring::Never, 0).summarized(),
+        )
+        .normalized();
+    World::cucumber()
+        .with_writer(writer)
+        .with_default_cli()
+        .with_tracing_layer(|writer| {
+            fmt::layer()
+                .fmt_fields(SkipScenarioIdSpan(format::DefaultFields::new()))
+                .event_format(AppendScenarioMsg(
+                    format().with_ansi(false).without_time().compact(),
+                ))
+                .with_writer(writer)
+                .with_filter(
+                    Targets::new()
+                        .with_target("cucumber", LevelFilter::ERROR)
+                        .with_target("tracing_layer", LevelFilter::INFO)
+                        .with_default(LevelFilter::OFF),
+                )
+        })
+        .run("tests/features/wait/nested/rule.feature")
+        .await;
+
+    let out = String::from_utf8_lossy(&out);
+    assert!(out.contains("kept: 1"), "output:\n{out}");
+    assert_eq!(out.matches("kept: 2").count(), 1, "output:\n{out}");
+    assert!(!out.contains("filtered"), "output:\n{out}");
+    assert!(!out.contains("__cucumber_scenario_id"), "output:\n{out}");
+}
+
+#[given(regex = r"(\d+) secs?")]
+async fn step(_: &mut World, secs: u64) {
+    tracing::info!("kept: {secs}");
+    tracing::debug!("filtered: {secs}");
+}
+
+#[derive(Clone, cucumber::World, Debug, Default)]
+struct World;
@@ -0,0 +1,90 @@
+use std::panic::AssertUnwindSafe;
+
+use clap::Parser as _;
+use cucumber::{
+    cli, given, step, then, when, Parameter, StatsWriter as _, World as _,
+};
+use derive_more::with_trait::{Deref, FromStr};
+use futures::FutureExt as _;
+
+#[derive(Deref, FromStr, Parameter)]
+#[param(regex = "cat|dog", name = "animal")]
+struct Animal(String);
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct ConflictingWorld;
+
+#[given("an animal")]
+fn first(_: &mut ConflictingWorld) {}
+
+#[given("an animal")]
+fn second(_: &mut ConflictingWorld) {}
+
+#[when(expr = "the {animal} runs")]
+fn runs(_: &mut ConflictingWorld, animal: Animal) {
+    drop(animal);
+}
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct ValidWorld;
+
+#[given("an animal")]
+fn animal(_: &mut ValidWorld) {}
+
+#[then(expr = "the {animal} sleeps {int} hour(s)")]
+fn sleeps(_: &mut ValidWorld, animal: Animal, hours: u8) {
+    drop((animal, hours));
+}
+
+#[tokio::test]
+async fn validates_without_running() {
+    let writer = ValidWorld::cucumber()
+        .with_cli(
+            cli::Opts::<_, _, _, cli::Empty>::try_parse_from([
+                "test",
+                "--validate",
+            ])
+            .expect("Invalid command line"),
+        )
+        .run("tests/features/wait")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn fails_on_conflicting_steps() {
+    let res = ConflictingWorld::cucumber()
+        .with_cli(
+            cli::Opts::<_, _, _, cli::Empty>::try_parse_from([
+                "test",
+                "--validate",
+            ])
+            .expect("Invalid command line"),
+        )
+        .run("tests/features/wait");
+
+    let err = AssertUnwindSafe(res)
+        .catch_unwind()
+        .await
+        .expect_err("should err");
+    let err = err.downcast_ref::<String>().unwrap();
+
+    assert_eq!(err, "Step definitions validation failed: 1 problem found");
+}
+
+#[test]
+fn detects_conflicts() {
+    let errors = step::validate(&ConflictingWorld::collection().definitions());
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    let step::ValidationError::Conflict {
+        regex, locations, ..
+    } = &errors[0]
+    else {
+        panic!("unexpected error: {}", errors[0]);
+    };
+    assert_eq!(regex.as_str(), "^an animal$");
+    assert_eq!(locations.len(), 2);
+}
@@ -0,0 +1,99 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use cucumber::{cli, given, writer, World as _, WriterExt as _};
+use futures::future;
+use tokio::time;
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("it passes")]
+fn passes(_: &mut World) {}
+
+type Writer =
+    writer::Normalize<World, writer::Summarize<writer::Basic<fs::File>>>;
+
+/// Creates a new [`Writer`] appending its output to the provided `file`.
+fn writer(file: &Path) -> Writer {
+    writer::Basic::raw(
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file)
+            .unwrap(),
+        writer::Coloring::Never,
+        writer::Verbosity::Default,
+    )
+    .summarized()
+    .normalized()
+}
+
+/// Waits until the provided `file` contains the `expected` text.
+async fn wait_for(file: &Path, expected: &str) {
+    for _ in 0..200 {
+        if fs::read_to_string(file)
+            .unwrap_or_default()
+            .contains(expected)
+        {
+            return;
+        }
+        time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!(
+        "no `{expected}` in output:\n{}",
+        fs::read_to_string(file).unwrap_or_default(),
+    );
+}
+
+#[tokio::test]
+async fn reruns_changed_features() {
+    let dir = tempfile::tempdir().unwrap();
+    let features = dir.path().join("features");
+    fs::create_dir(&features).unwrap();
+    fs::write(
+        features.join("a.feature"),
+        "Feature: A\n  Scenario: a\n    Given it passes\n",
+    )
+    .unwrap();
+    fs::write(
+        features.join("b.feature"),
+        "Feature: B\n  Scenario: b\n    Given it passes\n",
+    )
+    .unwrap();
+    let output = dir.path().join("output.txt");
+
+    let cli = cli::Opts {
+        watch: true,
+        ..cli::Opts::<_, _, _, cli::Empty>::default()
+    };
+    let watch = World::cucumber::<PathBuf>()
+        .with_writer(writer(&output))
+        .with_cli(cli)
+        .run_and_watch(features.clone(), || writer(&output));
+    let check = async {
+        wait_for(&output, "Feature: B").await;
+        // Give the watcher some time to start watching.
+        time::sleep(Duration::from_millis(500)).await;
+
+        fs::write(
+            features.join("b.feature"),
+            "Feature: B changed\n  Scenario: b\n    Given it passes\n",
+        )
+        .unwrap();
+        wait_for(&output, "Feature: B changed").await;
+    };
+    futures::pin_mut!(watch, check);
+    future::select(watch, check).await;
+
+    let out = fs::read_to_string(&output).unwrap();
+    assert_eq!(out.matches("Feature: A").count(), 1, "output:\n{out}");
+    assert_eq!(
+        out.matches("Feature: B changed").count(),
+        1,
+        "output:\n{out}"
+    );
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use cucumber::{
+    cli, event, gherkin, given, parser, Event, World as _, WorldSnapshot,
+    Writer, WriterExt as _,
+};
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(snapshot)]
+struct World {
+    slept: u64,
+}
+
+impl WorldSnapshot for World {
+    fn snapshot(&self, _: Option<&gherkin::Step>) -> String {
+        format!("slept: {}ms", self.slept)
+    }
+}
+
+#[given(regex = r"^sleeping for (\d+)ms$")]
+async fn sleep(w: &mut World, ms: u64) {
+    tokio::time::sleep(Duration::from_millis(ms)).await;
+    w.slept += ms;
+}
+
+// language=Gherkin
+const FEATURE: &str = "\
+Feature: Watched
+  Scenario: stuck
+    Given sleeping for 10ms
+    And sleeping for 500ms
+
+  Scenario: fast
+    Given sleeping for 0ms
+";
+
+/// [`Writer`] collecting all the emitted [`event::Stall`]s.
+#[derive(Default)]
+struct Stalls(Vec<(String, event::Stall)>);
+
+impl<W: 'static> Writer<W> for Stalls {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        if let Ok(event::Cucumber::Feature(
+            _,
+            event::Feature::Scenario(
+                sc,
+                event::RetryableScenario {
+                    event: event::Scenario::Stalled(stall),
+                    ..
+                },
+            ),
+        )) = ev.map(Event::into_inner)
+        {
+            self.0.push((sc.name.clone(), stall));
+        }
+    }
+}
+
+#[tokio::test]
+async fn reports_stalled_steps() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(Stalls::default().normalized())
+        .with_default_cli()
+        .watchdog(Duration::from_millis(200))
+        .run([FEATURE])
+        .await;
+
+    assert!(!writer.0.is_empty(), "no stalls reported");
+    for (scenario, stall) in &writer.0 {
+        assert_eq!(scenario, "stuck");
+        assert_eq!(stall.step.value, "sleeping for 500ms");
+        assert!(!stall.is_background);
+        assert!(stall.elapsed >= Duration::from_millis(200), "{stall:?}");
+        assert_eq!(stall.world, None);
+    }
+    assert!(
+        writer.0[0].1.to_string().starts_with(
+            "Step `And sleeping for 500ms` made no progress for ",
+        ),
+        "{}",
+        writer.0[0].1,
+    );
+}
+
+#[tokio::test]
+async fn dumps_world_of_stalled_steps() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(Stalls::default().normalized())
+        .with_default_cli()
+        .watchdog(Duration::from_millis(200))
+        .dump_stalled_world(true)
+        .run([FEATURE])
+        .await;
+
+    assert!(!writer.0.is_empty(), "no stalls reported");
+    for (_, stall) in &writer.0 {
+        assert_eq!(stall.world.as_deref(), Some("slept: 10ms"));
+    }
+}
+
+#[tokio::test]
+async fn does_not_watch_by_default() {
+    let writer = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(Stalls::default().normalized())
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    assert!(writer.0.is_empty(), "unexpected stalls: {:?}", writer.0);
+}
@@ -0,0 +1,140 @@
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::mpsc,
+    thread,
+};
+
+use cucumber::{
+    given, parser,
+    writer::{self, webhook},
+    World as _, WriterExt as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+/// Spawns an HTTP server mocking a webhook, and returns its URL along with the
+/// receiver of the received requests' bodies.
+fn serve() -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    _ = thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+
+            let mut len = 0;
+            loop {
+                let mut header = String::new();
+                _ = reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        len = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).unwrap();
+            tx.send(String::from_utf8(body).unwrap()).unwrap();
+
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        }
+    });
+
+    (format!("http://{addr}/hook"), rx)
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Notified
+  Scenario: passing
+    Given foo is 0
+
+  Scenario: \"failing\"
+    Given foo is 1
+
+  Scenario: skipped
+    Given bar
+
+  Scenario: failing again
+    Given foo is 2
+";
+
+#[tokio::test]
+async fn posts_on_finish() {
+    let (url, bodies) = serve();
+
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(
+                        writer::Webhook::raw(url)
+                            .report_url("https://ci.example.com/1")
+                            .discard_stats_writes()
+                            .discard_arbitrary_writes(),
+                    )
+                    .normalized(),
+            )
+            .with_default_cli()
+            .max_concurrent_scenarios(1)
+            .run([FEATURE])
+            .await,
+    );
+
+    let body = bodies.recv().unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&body).unwrap()["text"],
+        "Cucumber run failed: 1 passed, 1 skipped, 2 failed of 4 scenarios, \
+         0 parsing errors.\n\
+         Notified: \"failing\"\n\
+         Notified: failing again\n\
+         https://ci.example.com/1",
+    );
+    assert!(bodies.try_recv().is_err(), "posted more than once");
+}
+
+#[tokio::test]
+async fn posts_on_first_failure() {
+    let (url, bodies) = serve();
+
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(
+                        writer::Webhook::raw(url)
+                            .template(r#"{"failed": "{failed_scenarios}"}"#)
+                            .trigger(webhook::Trigger::FirstFailure)
+                            .discard_stats_writes()
+                            .discard_arbitrary_writes(),
+                    )
+                    .normalized(),
+            )
+            .with_default_cli()
+            .max_concurrent_scenarios(1)
+            .run([FEATURE])
+            .await,
+    );
+
+    assert_eq!(
+        bodies.recv().unwrap(),
+        r#"{"failed": "Notified: \"failing\""}"#
+    );
+    assert!(bodies.try_recv().is_err(), "posted more than once");
+}
@@ -0,0 +1,100 @@
+use cucumber::{given, parser, ScenarioFailure, World as _};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[given("an unfinished step")]
+#[wip]
+fn unfinished(_: &mut World) {
+    unimplemented!("not yet");
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Work in progress
+
+  Scenario: regular
+    Given a passing step
+
+  @wip
+  Scenario: tagged failing
+    Given a failing step
+
+  Scenario: using unfinished step
+    Given a passing step
+    And an unfinished step
+
+  @wip
+  Scenario: tagged passing
+    Given a passing step
+";
+
+#[tokio::test]
+async fn failures_dont_fail_run() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run_with_result([FEATURE])
+        .await;
+
+    assert!(!res.execution_has_failed(), "{res:?}");
+    assert_eq!(res.steps.passed, 3);
+    assert_eq!(res.steps.failed, 0);
+    assert_eq!(res.scenarios.passed, 2);
+    assert_eq!(res.scenarios.failed, 0);
+    assert_eq!(res.wip_errors, 0);
+    assert!(res.failed_scenarios.is_empty());
+}
+
+#[tokio::test]
+async fn passing_fails_run_in_strict_mode() {
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .strict_wip()
+        .run_with_result([FEATURE])
+        .await;
+
+    assert!(res.execution_has_failed());
+    assert_eq!(res.steps.failed, 0);
+    assert_eq!(res.scenarios.passed, 1);
+    assert_eq!(res.scenarios.failed, 1);
+    assert_eq!(res.wip_errors, 1);
+    assert_eq!(res.failed_scenarios.len(), 1);
+
+    let failed = &res.failed_scenarios[0];
+    assert_eq!(failed.scenario.name, "tagged passing");
+    assert!(
+        matches!(failed.error, ScenarioFailure::Wip(_)),
+        "unexpected failure: {:?}",
+        failed.error,
+    );
+    assert_eq!(failed.error.to_string(), "Work-in-progress scenario passed");
+}
+
+#[tokio::test]
+async fn regular_failures_still_fail_run() {
+    // language=Gherkin
+    let feature = "Feature: Regular
+
+  Scenario: failing
+    Given a failing step
+";
+
+    let res = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_default_cli()
+        .run_with_result([feature])
+        .await;
+
+    assert!(res.execution_has_failed());
+    assert_eq!(res.steps.failed, 1);
+    assert_eq!(res.failed_scenarios.len(), 1);
+}
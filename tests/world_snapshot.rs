@@ -0,0 +1,77 @@
+use std::io;
+
+use cucumber::{
+    gherkin, given, parser, then, writer, World as _, WorldSnapshot,
+    WriterExt as _,
+};
+
+#[derive(cucumber::World, Debug, Default)]
+#[world(snapshot)]
+struct World {
+    cart: Vec<String>,
+    noise: Vec<u64>,
+}
+
+impl WorldSnapshot for World {
+    fn snapshot(&self, step: Option<&gherkin::Step>) -> String {
+        let step = step.map_or("<hook>", |s| s.value.as_str());
+        format!("snapshot for `{step}`: cart = {:?}", self.cart)
+    }
+}
+
+#[given(expr = "{word} in the cart")]
+fn add_to_cart(world: &mut World, item: String) {
+    world.cart.push(item);
+    world.noise.extend(0..100);
+}
+
+#[then(expr = "the cart has {int} item(s)")]
+fn cart_has(world: &mut World, count: usize) {
+    assert_eq!(world.cart.len(), count);
+}
+
+#[derive(Default)]
+struct Output(Vec<u8>);
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+const FEATURE: &str = "\
+Feature: Snapshot
+  Scenario: cart
+    Given apple in the cart
+    Then the cart has 2 items
+";
+
+#[tokio::test]
+async fn shows_snapshot_instead_of_debug() {
+    let mut output = Output::default();
+    _ = World::cucumber::<&str>()
+        .with_parser(parser::FromString::new())
+        .with_writer(
+            writer::Basic::raw(&mut output, writer::Coloring::Never, 2)
+                .discard_stats_writes()
+                .normalized(),
+        )
+        .with_default_cli()
+        .run([FEATURE])
+        .await;
+
+    let output = String::from_utf8(output.0).unwrap();
+
+    assert!(
+        output.contains(
+            "snapshot for `the cart has 2 items`: cart = [\"apple\"]",
+        ),
+        "no snapshot in output:\n{output}",
+    );
+    assert!(!output.contains("noise"), "`Debug` in output:\n{output}");
+}
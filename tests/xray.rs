@@ -0,0 +1,129 @@
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::TcpListener,
+    sync::mpsc,
+    thread,
+};
+
+use cucumber::{given, parser, writer, World as _, WriterExt as _};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given(regex = r"foo is (\d+)")]
+fn step(_: &mut World, num: usize) {
+    assert_eq!(num, 0);
+}
+
+/// Spawns an HTTP server mocking Jira Xray API, and returns its URL along with
+/// the receiver of the first request (as `METHOD /path body` line).
+fn serve() -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    _ = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+
+        let mut request_line = String::new();
+        _ = reader.read_line(&mut request_line).unwrap();
+        let mut len = 0;
+        loop {
+            let mut header = String::new();
+            _ = reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    len = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body).unwrap();
+
+        let mut parts = request_line.split_whitespace();
+        tx.send(format!(
+            "{} {} {}",
+            parts.next().unwrap(),
+            parts.next().unwrap(),
+            String::from_utf8(body).unwrap(),
+        ))
+        .unwrap();
+
+        let resp = r#"{"key":"PROJ-100"}"#;
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\n\
+             Content-Type: application/json\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n\
+             {resp}",
+            resp.len(),
+        )
+        .unwrap();
+    });
+
+    (format!("http://{addr}/api/v2/import/execution"), rx)
+}
+
+// language=Gherkin
+const FEATURE: &str = "Feature: Imported
+  @testkey(PROJ-1)
+  Scenario: passing
+    Given foo is 0
+
+  @testkey(PROJ-2)
+  Scenario Outline: partially failing
+    Given foo is <num>
+
+    Examples:
+      | num |
+      | 0   |
+      | 1   |
+
+  Scenario: not keyed
+    Given foo is 0
+";
+
+#[tokio::test]
+async fn imports_test_runs() {
+    let (url, request) = serve();
+
+    drop(
+        World::cucumber::<&str>()
+            .with_parser(parser::FromString::new())
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<World, _>(
+                        writer::Xray::raw(url, "secret")
+                            .summary("nightly")
+                            .project_key("PROJ")
+                            .discard_stats_writes()
+                            .discard_arbitrary_writes(),
+                    )
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run([FEATURE])
+            .await,
+    );
+
+    let request = request.recv().unwrap();
+    assert!(
+        request.starts_with("POST /api/v2/import/execution "),
+        "{request}",
+    );
+    assert!(
+        request.contains(r#""info":{"summary":"nightly","project":"PROJ""#),
+        "{request}",
+    );
+    assert!(request.contains(r#"{"testKey":"PROJ-1","#), "{request}");
+    assert!(request.contains(r#""status":"PASSED"}"#), "{request}");
+    assert!(request.contains(r#"{"testKey":"PROJ-2","#), "{request}");
+    assert!(request.contains(r#""status":"FAILED","#), "{request}");
+    assert_eq!(request.matches("testKey").count(), 2, "{request}");
+}